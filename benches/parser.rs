@@ -0,0 +1,143 @@
+use criterion::black_box;
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::BenchmarkId;
+use criterion::Criterion;
+use criterion::Throughput;
+
+use ara_parser::lexer;
+use ara_parser::parser;
+use ara_parser::traverser::visitor::NodeVisitor;
+use ara_parser::traverser::TreeTraverser;
+use ara_parser::tree::Node;
+use ara_source::source::Source;
+use ara_source::source::SourceKind;
+
+static SMALL: &str = include_str!("corpus/small.ara");
+static MEDIUM: &str = include_str!("corpus/medium.ara");
+
+/// The number of classes, and functions, in the generated corpus.
+const LARGE_SIZE: usize = 500;
+
+/// The corpora to benchmark, from a short script, to a few thousand lines of generated code.
+fn corpora() -> Vec<(&'static str, String)> {
+    vec![
+        ("small", SMALL.to_string()),
+        ("medium", MEDIUM.to_string()),
+        ("large", generate(LARGE_SIZE)),
+    ]
+}
+
+fn generate(size: usize) -> String {
+    let mut code = String::from("namespace App\\Generated;\n\n");
+    for index in 0..size {
+        code.push_str(&format!(
+            r#"
+final class Service{index}<T> {{
+    public function __construct(
+        private readonly vec<T> $items,
+        private int $counter = {index},
+    ) {{}}
+
+    public function process(Closure<(T), bool> $predicate): dict<int, T> {{
+        $result = dict[];
+        foreach ($this->items as $key => $item) {{
+            if ($predicate($item) && $key % {modulo} === 0) {{
+                $result[$key] = $item;
+            }} else {{
+                $this->counter += $key * 2 - 1;
+            }}
+        }}
+
+        return $result;
+    }}
+}}
+
+function run{index}(Service{index}<string> $service): int {{
+    $items = $service->process(fn(string $item): bool => Str\length($item) > {index});
+    $total = 0;
+    while ($total < {index}) {{
+        $total += Dict\count($items) ?? 1;
+    }}
+
+    return $total;
+}}
+"#,
+            modulo = index % 7 + 1,
+        ));
+    }
+
+    code
+}
+
+struct Counter(usize);
+
+impl NodeVisitor<()> for Counter {
+    fn visit(&mut self, _: &str, _: &dyn Node, _: Option<&dyn Node>) -> Result<(), ()> {
+        self.0 += 1;
+
+        Ok(())
+    }
+}
+
+fn lexer_benchmark(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("lex");
+    for (name, code) in corpora() {
+        let source = Source::inline(SourceKind::Script, code);
+
+        group.throughput(Throughput::Bytes(source.content.len() as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(name),
+            &source,
+            |bencher, source| bencher.iter(|| black_box(lexer::lex(black_box(source)))),
+        );
+    }
+
+    group.finish();
+}
+
+fn parser_benchmark(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("parse");
+    for (name, code) in corpora() {
+        let source = Source::inline(SourceKind::Script, code);
+        let tokens = lexer::lex(&source).unwrap();
+
+        group.throughput(Throughput::Bytes(source.content.len() as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(name),
+            &tokens,
+            |bencher, tokens| {
+                bencher.iter(|| black_box(parser::construct(black_box(&source), black_box(tokens))))
+            },
+        );
+    }
+
+    group.finish();
+}
+
+fn traverser_benchmark(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("traverse");
+    for (name, code) in corpora() {
+        let source = Source::inline(SourceKind::Script, code);
+        let tree = parser::parse(&source).unwrap();
+
+        group.throughput(Throughput::Bytes(source.content.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(name), &tree, |bencher, tree| {
+            bencher.iter(|| {
+                let mut traverser = TreeTraverser::new(vec![Box::new(Counter(0))]);
+
+                black_box(traverser.traverse_tree(black_box(tree)))
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    lexer_benchmark,
+    parser_benchmark,
+    traverser_benchmark
+);
+criterion_main!(benches);
@@ -0,0 +1,278 @@
+use std::env;
+use std::fmt::Write;
+use std::path::Path;
+use std::process::ExitCode;
+
+use ara_parser::parser;
+use ara_parser::tree::Node;
+use ara_parser::tree::Tree;
+use ara_reporting::builder::ReportBuilder;
+use ara_reporting::Report;
+use ara_source::source::Source;
+use ara_source::source::SourceKind;
+use ara_source::SourceMap;
+
+const USAGE: &str =
+    "usage: ara-parse [--format json|sexp|dot|none] [--diagnostics human|json] <path|glob>...
+
+Parse the given files, directories, and glob patterns, printing the tree of each file to the
+standard output, and the issues of the files that failed to parse to the standard error.
+
+options:
+    --format <format>         the format of the trees: `sexp` (default), `json`, `dot`, or `none`
+    --diagnostics <format>    the format of the issues: `human` (default), or `json`
+    --help                    print this message";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Json,
+    Sexp,
+    Dot,
+    None,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Diagnostics {
+    Human,
+    Json,
+}
+
+#[derive(Debug)]
+struct Arguments {
+    format: Format,
+    diagnostics: Diagnostics,
+    patterns: Vec<String>,
+}
+
+fn main() -> ExitCode {
+    let arguments = match arguments(env::args().skip(1)) {
+        Ok(Some(arguments)) => arguments,
+        Ok(None) => {
+            println!("{USAGE}");
+
+            return ExitCode::SUCCESS;
+        }
+        Err(error) => {
+            eprintln!("error: {error}\n\n{USAGE}");
+
+            return ExitCode::from(2);
+        }
+    };
+
+    let map = match sources(&arguments.patterns) {
+        Ok(map) => map,
+        Err(error) => {
+            eprintln!("error: {error}");
+
+            return ExitCode::from(2);
+        }
+    };
+
+    let mut failed = false;
+    for source in &map.sources {
+        match parser::parse(source) {
+            Ok(tree) => match arguments.format {
+                Format::Json => println!(
+                    "{}",
+                    serde_json::to_string(&tree).expect("a tree is always serializable")
+                ),
+                Format::Sexp => print!("{}", sexp(&tree, &source.content)),
+                Format::Dot => print!("{}", dot(&tree, &source.content)),
+                Format::None => {}
+            },
+            Err(report) => {
+                failed = true;
+
+                if let Err(error) = diagnose(&map, &report, arguments.diagnostics) {
+                    eprintln!(
+                        "error: failed to print the issues of `{}`: {error}",
+                        source.name()
+                    );
+                }
+            }
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Parse the given command line arguments, `None` if help was requested.
+fn arguments<I: Iterator<Item = String>>(mut args: I) -> Result<Option<Arguments>, String> {
+    let mut arguments = Arguments {
+        format: Format::Sexp,
+        diagnostics: Diagnostics::Human,
+        patterns: vec![],
+    };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--help" | "-h" => return Ok(None),
+            "--format" => {
+                arguments.format = match args.next().as_deref() {
+                    Some("json") => Format::Json,
+                    Some("sexp") => Format::Sexp,
+                    Some("dot") => Format::Dot,
+                    Some("none") => Format::None,
+                    Some(format) => return Err(format!("unknown tree format `{format}`")),
+                    None => return Err("missing tree format".to_string()),
+                }
+            }
+            "--diagnostics" => {
+                arguments.diagnostics = match args.next().as_deref() {
+                    Some("human") => Diagnostics::Human,
+                    Some("json") => Diagnostics::Json,
+                    Some(format) => return Err(format!("unknown diagnostics format `{format}`")),
+                    None => return Err("missing diagnostics format".to_string()),
+                }
+            }
+            _ if arg.starts_with("--") => return Err(format!("unknown option `{arg}`")),
+            _ => arguments.patterns.push(arg),
+        }
+    }
+
+    if arguments.patterns.is_empty() {
+        return Err("missing path".to_string());
+    }
+
+    Ok(Some(arguments))
+}
+
+/// Load the files matching the given paths and glob patterns, directories match every `.ara`
+/// file within them.
+fn sources(patterns: &[String]) -> Result<SourceMap, String> {
+    let mut paths = vec![];
+    for pattern in patterns {
+        let pattern = if Path::new(pattern).is_dir() {
+            format!("{}/**/*.ara", pattern.trim_end_matches('/'))
+        } else {
+            pattern.clone()
+        };
+
+        let matches = glob::glob(&pattern)
+            .map_err(|error| format!("invalid pattern `{pattern}`: {error}"))?
+            .filter_map(Result::ok)
+            .filter(|path| path.is_file())
+            .collect::<Vec<_>>();
+
+        if matches.is_empty() {
+            return Err(format!("no files match `{pattern}`"));
+        }
+
+        paths.extend(matches);
+    }
+
+    let mut sources = vec![];
+    for path in paths {
+        let name = path.to_string_lossy().to_string();
+        let content = std::fs::read_to_string(&path)
+            .map_err(|error| format!("failed to read `{name}`: {error}"))?;
+        let kind = if name.ends_with(".d.ara") {
+            SourceKind::Definition
+        } else {
+            SourceKind::Script
+        };
+
+        sources.push(Source::new(kind, name, content));
+    }
+
+    Ok(SourceMap::new(sources))
+}
+
+fn diagnose(map: &SourceMap, report: &Report, format: Diagnostics) -> Result<(), String> {
+    match format {
+        Diagnostics::Human => ReportBuilder::new(map)
+            .eprint(report)
+            .map_err(|error| format!("{error:?}")),
+        Diagnostics::Json => {
+            let json = serde_json::to_string(&report.issues).map_err(|error| error.to_string())?;
+            eprintln!("{json}");
+
+            Ok(())
+        }
+    }
+}
+
+/// The given tree as an s-expression, one node per line, e.g. `(Identifier 9..12 "foo")`.
+///
+/// Each child is preceded by the name of the field holding it, and the text of each leaf is
+/// included.
+fn sexp(tree: &Tree, source: &str) -> String {
+    let mut output = format!("; {}\n", tree.source);
+    sexp_node(&mut output, source, None, &tree.definitions, 0);
+    output.push('\n');
+
+    output
+}
+
+fn sexp_node(
+    output: &mut String,
+    source: &str,
+    field: Option<&str>,
+    node: &dyn Node,
+    depth: usize,
+) {
+    let span = node.span();
+
+    output.push_str(&"  ".repeat(depth));
+    if let Some(field) = field {
+        let _ = write!(output, "{field}: ");
+    }
+
+    let _ = write!(output, "({:?} {}..{}", node.kind(), span.start, span.end);
+
+    let children = node.named_children();
+    if children.is_empty() {
+        let text = source.get(span.start..span.end).unwrap_or_default();
+        let _ = write!(output, " {text:?}");
+    }
+
+    for (field, child) in children {
+        output.push('\n');
+        sexp_node(output, source, Some(field), child, depth + 1);
+    }
+
+    output.push(')');
+}
+
+/// The given tree as a graphviz graph, each node labeled with its kind and the text of leaves,
+/// and each edge labeled with the name of the field holding the child.
+fn dot(tree: &Tree, source: &str) -> String {
+    let mut output = format!("digraph {:?} {{\n    node [shape=box];\n", tree.source);
+
+    let mut count = 0;
+    let mut stack: Vec<(Option<usize>, &str, &dyn Node)> = vec![(None, "", &tree.definitions)];
+    while let Some((parent, field, node)) = stack.pop() {
+        let id = count;
+        count += 1;
+
+        let span = node.span();
+        let children = node.named_children();
+        let label = if children.is_empty() {
+            let text = source.get(span.start..span.end).unwrap_or_default();
+
+            format!("{:?}\n{}", node.kind(), text)
+        } else {
+            format!("{:?}", node.kind())
+        };
+
+        let _ = writeln!(output, "    n{id} [label={label:?}];");
+        if let Some(parent) = parent {
+            let _ = writeln!(output, "    n{parent} -> n{id} [label={field:?}];");
+        }
+
+        stack.extend(
+            children
+                .into_iter()
+                .rev()
+                .map(|(field, child)| (Some(id), field, child)),
+        );
+    }
+
+    output.push_str("}\n");
+
+    output
+}
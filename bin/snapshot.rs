@@ -24,7 +24,11 @@ fn main() -> io::Result<()> {
 
     let loader = FileSourceLoader::new(&root);
     for entry in entries {
-        let code_filename = entry.join("code.ara");
+        let code_filename = if entry.join("code.d.ara").exists() {
+            entry.join("code.d.ara")
+        } else {
+            entry.join("code.ara")
+        };
         let tree_filename = entry.join("tree.txt");
         let error_filename = entry.join("error.txt");
 
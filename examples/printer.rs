@@ -0,0 +1,37 @@
+use std::env;
+
+use ara_parser::parser;
+use ara_parser::printer;
+use ara_parser::printer::PrinterOptions;
+use ara_reporting::builder::CharSet;
+use ara_reporting::builder::ColorChoice;
+use ara_reporting::builder::ReportBuilder;
+use ara_reporting::error::Error;
+use ara_source::loader::FileSourceLoader;
+use ara_source::loader::SourceLoader;
+
+fn main() -> Result<(), Error> {
+    let cargo_manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+
+    let loader = FileSourceLoader::new(&cargo_manifest_dir);
+
+    let source_map = loader.load(&"examples/project/format.ara").unwrap();
+
+    match parser::parse_map(&source_map) {
+        Ok(tree_map) => tree_map.trees.iter().for_each(|tree| {
+            let options = PrinterOptions {
+                indentation: "  ".to_string(),
+            };
+
+            println!("{}", printer::print_with_options(tree, options));
+        }),
+        Err(report) => {
+            ReportBuilder::new(&source_map)
+                .with_charset(CharSet::Unicode)
+                .with_colors(ColorChoice::Always)
+                .print(report.as_ref())?;
+        }
+    }
+
+    Ok(())
+}
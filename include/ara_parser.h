@@ -0,0 +1,30 @@
+#ifndef ARA_PARSER_H
+#define ARA_PARSER_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/*
+ * Parse the given code, returning the tree and the issues reported while parsing it as a
+ * NUL-terminated JSON object of the form `{"tree": ..., "issues": [...]}`.
+ *
+ * The returned JSON is owned by the caller, and must be freed using `ara_free`.
+ *
+ * Available when the crate is built with the `ffi` feature.
+ */
+char *ara_parse(const uint8_t *code, size_t length);
+
+/*
+ * Free the given JSON, as returned by `ara_parse`.
+ */
+void ara_free(char *json);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
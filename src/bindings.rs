@@ -0,0 +1,28 @@
+use ara_reporting::issue::Issue;
+use ara_source::source::Source;
+use serde::Serialize;
+
+use crate::parser;
+use crate::tree::Tree;
+
+/// The outcome of parsing a source, as handed to other languages.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+struct Output {
+    /// The tree of the source, empty if parsing could not recover at all.
+    tree: Tree,
+    /// All issues reported while parsing the source, regardless of their severity.
+    issues: Vec<Issue>,
+}
+
+/// Parse the given source, returning the tree and the issues reported while parsing it as a
+/// JSON object of the form `{"tree": ..., "issues": [...]}`.
+///
+/// Parsing recovers from as many issues as possible, see `parser::parse_lenient`, so that the
+/// tree of a source that is still being written can be shown along with its issues.
+pub(crate) fn parse(source: &Source) -> String {
+    let (tree, issues) = parser::parse_lenient(source);
+
+    serde_json::to_string(&Output { tree, issues })
+        .expect("a tree, and its issues, are always serializable")
+}
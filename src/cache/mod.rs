@@ -0,0 +1,110 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use bincode::Decode;
+use bincode::Encode;
+
+use crate::tree::Tree;
+
+/// The header of a cache file, written before the encoded tree.
+#[derive(Debug, PartialEq, Eq, Clone, Encode, Decode)]
+struct Header {
+    version: String,
+    source_hash: u64,
+}
+
+/// Hash the given source content.
+///
+/// Unlike the standard library hashers, this hash is stable across builds and platforms,
+/// so it can be persisted alongside a tree.
+pub fn hash(content: &str) -> u64 {
+    // 64-bit FNV-1a.
+    content.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// Store the given tree at the given path, using the bincode encoding.
+pub fn store<P: AsRef<Path>>(path: P, tree: &Tree) -> io::Result<()> {
+    let config = bincode::config::standard();
+    let header = Header {
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        source_hash: tree.source_hash,
+    };
+
+    let mut bytes = bincode::encode_to_vec(&header, config).map_err(invalid_data)?;
+    bytes.extend(bincode::encode_to_vec(tree, config).map_err(invalid_data)?);
+
+    if let Some(parent) = path.as_ref().parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    fs::write(path, bytes)
+}
+
+/// Load the tree stored at the given path, if it was parsed from a source with the given
+/// content hash, by the same version of the parser.
+///
+/// Returns `None` if there is no tree stored at the given path, or if the stored tree is stale.
+pub fn load_if_fresh<P: AsRef<Path>>(path: P, content_hash: u64) -> io::Result<Option<Tree>> {
+    let bytes = match fs::read(path) {
+        Ok(bytes) => bytes,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(error) => return Err(error),
+    };
+
+    let config = bincode::config::standard();
+    let (header, read) = match bincode::decode_from_slice::<Header, _>(&bytes, config) {
+        Ok(decoded) => decoded,
+        Err(_) => return Ok(None),
+    };
+
+    if header.version != env!("CARGO_PKG_VERSION") || header.source_hash != content_hash {
+        return Ok(None);
+    }
+
+    match bincode::decode_from_slice::<Tree, _>(&bytes[read..], config) {
+        Ok((tree, _)) => Ok(Some(tree)),
+        Err(_) => Ok(None),
+    }
+}
+
+fn invalid_data<E: std::error::Error + Send + Sync + 'static>(error: E) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ara_source::source::Source;
+    use ara_source::source::SourceKind;
+
+    use crate::parser;
+
+    #[test]
+    fn test_cache() {
+        let code = "function foo(): void {}";
+        let source = Source::new(SourceKind::Script, "foo.ara", code);
+        let tree = parser::parse(&source).unwrap();
+
+        assert_eq!(tree.source_hash, hash(code));
+        assert_ne!(hash(code), hash("function bar(): void {}"));
+
+        let path = std::env::temp_dir()
+            .join(format!("ara-parser-cache-{}", std::process::id()))
+            .join("foo.ara.bin");
+
+        assert!(load_if_fresh(&path, hash(code)).unwrap().is_none());
+
+        store(&path, &tree).unwrap();
+
+        let loaded = load_if_fresh(&path, hash(code)).unwrap().unwrap();
+        assert_eq!(loaded.source, tree.source);
+        assert_eq!(loaded.definitions, tree.definitions);
+
+        assert!(load_if_fresh(&path, hash("")).unwrap().is_none());
+
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
@@ -0,0 +1,96 @@
+use std::io;
+use std::io::Write;
+
+use crate::tree::TreeMap;
+
+/// Write the given tree map as JSON Lines, one tree per line, in the order of their sources.
+///
+/// Each tree is serialized as soon as it is reached, so the whole map is never held in
+/// memory as a single document.
+#[cfg(feature = "jsonl")]
+pub fn json_lines<W: Write>(map: &TreeMap, mut writer: W) -> io::Result<()> {
+    for tree in map.iter() {
+        serde_json::to_writer(&mut writer, tree)?;
+        writer.write_all(b"\n")?;
+    }
+
+    writer.flush()
+}
+
+/// Write the given tree map as a stream of MessagePack values, one tree per value, in the
+/// order of their sources.
+///
+/// Structs are encoded as maps keyed by field name, matching the JSON representation.
+#[cfg(feature = "msgpack")]
+pub fn msgpack<W: Write>(map: &TreeMap, mut writer: W) -> io::Result<()> {
+    for tree in map.iter() {
+        rmp_serde::encode::write_named(&mut writer, tree)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+    }
+
+    writer.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ara_source::source::Source;
+    use ara_source::source::SourceKind;
+    use ara_source::SourceMap;
+
+    use crate::parser;
+    use crate::tree::Tree;
+
+    fn map() -> TreeMap {
+        let map = SourceMap::new(vec![
+            Source::new(SourceKind::Script, "foo.ara", "function foo(): void {}\n"),
+            Source::new(SourceKind::Script, "bar.ara", "type Bar = int;\n"),
+        ]);
+
+        parser::parse_map(&map).unwrap()
+    }
+
+    #[cfg(feature = "jsonl")]
+    #[test]
+    fn test_json_lines() {
+        let map = map();
+
+        let mut output = vec![];
+        json_lines(&map, &mut output).unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+        let trees = output
+            .lines()
+            .map(|line| serde_json::from_str::<Tree>(line).unwrap())
+            .collect::<Vec<Tree>>();
+
+        assert_eq!(trees.len(), 2);
+        assert_eq!(trees[0].source, "bar.ara");
+        assert_eq!(trees[1].source, "foo.ara");
+        assert_eq!(
+            trees[1].definitions,
+            map.get("foo.ara").unwrap().definitions
+        );
+    }
+
+    #[cfg(feature = "msgpack")]
+    #[test]
+    fn test_msgpack() {
+        let map = map();
+
+        let mut output = vec![];
+        msgpack(&map, &mut output).unwrap();
+
+        let mut deserializer = rmp_serde::Deserializer::new(output.as_slice());
+        let mut trees = vec![];
+        while trees.len() < map.len() {
+            trees.push(<Tree as serde::Deserialize>::deserialize(&mut deserializer).unwrap());
+        }
+
+        assert_eq!(trees[0].source, "bar.ara");
+        assert_eq!(
+            trees[1].definitions,
+            map.get("foo.ara").unwrap().definitions
+        );
+    }
+}
@@ -0,0 +1,89 @@
+use std::ffi::c_char;
+use std::ffi::CString;
+
+use ara_source::source::Source;
+use ara_source::source::SourceKind;
+
+use crate::bindings;
+
+/// Parse the given code, returning the tree and the issues reported while parsing it as a
+/// NUL-terminated JSON object of the form `{"tree": ..., "issues": [...]}`.
+///
+/// The code is parsed as a script, invalid UTF-8 sequences are replaced with the replacement
+/// character, the returned JSON is owned by the caller, and must be freed using `ara_free`.
+///
+/// # Safety
+///
+/// `code` must point to `length` readable bytes, it may only be null if `length` is zero.
+#[no_mangle]
+pub unsafe extern "C" fn ara_parse(code: *const u8, length: usize) -> *mut c_char {
+    let code = if length == 0 {
+        &[]
+    } else {
+        std::slice::from_raw_parts(code, length)
+    };
+
+    let source = Source::inline(SourceKind::Script, String::from_utf8_lossy(code));
+    let json = bindings::parse(&source);
+
+    CString::new(json)
+        .expect("JSON escapes NUL characters within strings")
+        .into_raw()
+}
+
+/// Free the given JSON, as returned by `ara_parse`.
+///
+/// # Safety
+///
+/// `json` must either be null, or have been returned by `ara_parse`, and not freed already.
+#[no_mangle]
+pub unsafe extern "C" fn ara_free(json: *mut c_char) {
+    if !json.is_null() {
+        drop(CString::from_raw(json));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CStr;
+
+    use serde_json::Value;
+
+    fn parse(code: &[u8]) -> Value {
+        unsafe {
+            let json = ara_parse(code.as_ptr(), code.len());
+            let value = serde_json::from_slice(CStr::from_ptr(json).to_bytes()).unwrap();
+            ara_free(json);
+
+            value
+        }
+    }
+
+    #[test]
+    fn test_ara_parse() {
+        let output = parse(b"function foo(): void {}");
+        assert_eq!(
+            output["tree"]["definitions"]["definitions"]
+                .as_array()
+                .unwrap()
+                .len(),
+            1
+        );
+        assert_eq!(output["issues"], Value::Array(vec![]));
+
+        let output = parse(b"function foo(): void { $a = \"\0\xff\"; }");
+        assert_eq!(output["issues"], Value::Array(vec![]));
+
+        let output = unsafe {
+            let json = ara_parse(std::ptr::null(), 0);
+            let value: Value = serde_json::from_slice(CStr::from_ptr(json).to_bytes()).unwrap();
+            ara_free(json);
+
+            value
+        };
+        assert_eq!(output["issues"], Value::Array(vec![]));
+
+        unsafe { ara_free(std::ptr::null_mut()) };
+    }
+}
@@ -0,0 +1,250 @@
+use arbitrary::Result;
+use arbitrary::Unstructured;
+
+/// The maximum nesting of generated expressions, statements, and definitions.
+pub const MAX_DEPTH: usize = 4;
+
+const FUNCTIONS: [&str; 4] = ["foo", "bar", "baz", "qux"];
+const CLASSES: [&str; 4] = ["Foo", "Bar", "Baz", "Qux"];
+const CONSTANTS: [&str; 3] = ["FOO", "BAR", "BAZ"];
+const VARIABLES: [&str; 4] = ["$a", "$b", "$c", "$d"];
+const TYPES: [&str; 8] = [
+    "int",
+    "string",
+    "float",
+    "bool",
+    "mixed",
+    "vec<int>",
+    "dict<string, int>",
+    "Foo",
+];
+
+const BINARY_OPERATORS: [&str; 21] = [
+    "+", "-", "*", "/", "%", "**", ".", "==", "!=", "===", "!==", "<", "<=", ">", ">=", "&&", "||",
+    "??", "&", "|", "<<",
+];
+const UNARY_OPERATORS: [&str; 3] = ["-", "!", "~"];
+
+/// Generate the source code of a random expression.
+pub fn expression(u: &mut Unstructured<'_>, depth: usize) -> Result<String> {
+    if depth == 0 || u.is_empty() {
+        return leaf(u);
+    }
+
+    let depth = depth - 1;
+    let code = match u.choose_index(16)? {
+        0 => leaf(u)?,
+        1 => format!(
+            "({}) {} ({})",
+            expression(u, depth)?,
+            u.choose(&BINARY_OPERATORS)?,
+            expression(u, depth)?
+        ),
+        2 => format!("{}({})", u.choose(&UNARY_OPERATORS)?, expression(u, depth)?),
+        3 => format!("{}({})", u.choose(&FUNCTIONS)?, arguments(u, depth)?),
+        4 => format!(
+            "{}->{}({})",
+            u.choose(&VARIABLES)?,
+            u.choose(&FUNCTIONS)?,
+            arguments(u, depth)?
+        ),
+        5 => format!("{}->{}", u.choose(&VARIABLES)?, u.choose(&FUNCTIONS)?),
+        6 => format!(
+            "{}::{}({})",
+            u.choose(&CLASSES)?,
+            u.choose(&FUNCTIONS)?,
+            arguments(u, depth)?
+        ),
+        7 => format!("{}[{}]", u.choose(&VARIABLES)?, expression(u, depth)?),
+        8 => format!("new {}({})", u.choose(&CLASSES)?, arguments(u, depth)?),
+        9 => format!("vec[{}]", arguments(u, depth)?),
+        10 => {
+            let mut entries = vec![];
+            for _ in 0..u.int_in_range(0..=3)? {
+                entries.push(format!(
+                    "{} => {}",
+                    expression(u, depth)?,
+                    expression(u, depth)?
+                ));
+            }
+
+            format!("dict[{}]", entries.join(", "))
+        }
+        11 => format!(
+            "({}) ? ({}) : ({})",
+            expression(u, depth)?,
+            expression(u, depth)?,
+            expression(u, depth)?
+        ),
+        12 => format!("{} = {}", u.choose(&VARIABLES)?, expression(u, depth)?),
+        13 => format!(
+            "fn({} {}): {} => {}",
+            u.choose(&TYPES)?,
+            u.choose(&VARIABLES)?,
+            u.choose(&TYPES)?,
+            expression(u, depth)?
+        ),
+        14 => format!("({})", expression(u, depth)?),
+        _ => format!("{} is {}", u.choose(&VARIABLES)?, u.choose(&TYPES)?),
+    };
+
+    Ok(code)
+}
+
+/// Generate the source code of a random statement.
+pub fn statement(u: &mut Unstructured<'_>, depth: usize) -> Result<String> {
+    if depth == 0 || u.is_empty() {
+        return Ok(format!("{};", leaf(u)?));
+    }
+
+    let depth = depth - 1;
+    let code = match u.choose_index(10)? {
+        0 => format!("{};", expression(u, depth)?),
+        1 => format!("return {};", expression(u, depth)?),
+        2 => {
+            let mut code = format!("if ({}) {}", expression(u, depth)?, block(u, depth)?);
+            for _ in 0..u.int_in_range(0..=2)? {
+                code.push_str(&format!(
+                    " elseif ({}) {}",
+                    expression(u, depth)?,
+                    block(u, depth)?
+                ));
+            }
+
+            if u.arbitrary()? {
+                code.push_str(&format!(" else {}", block(u, depth)?));
+            }
+
+            code
+        }
+        3 => format!("while ({}) {}", expression(u, depth)?, block(u, depth)?),
+        4 => format!("do {} while ({});", block(u, depth)?, expression(u, depth)?),
+        5 => {
+            let value = if u.arbitrary()? {
+                format!("{} => {}", u.choose(&VARIABLES)?, u.choose(&VARIABLES)?)
+            } else {
+                u.choose(&VARIABLES)?.to_string()
+            };
+
+            format!(
+                "foreach ({} as {}) {}",
+                expression(u, depth)?,
+                value,
+                block(u, depth)?
+            )
+        }
+        6 => format!(
+            "try {} catch ({} {}) {}",
+            block(u, depth)?,
+            u.choose(&CLASSES)?,
+            u.choose(&VARIABLES)?,
+            block(u, depth)?
+        ),
+        7 => block(u, depth)?,
+        8 => (*u.choose(&["break;", "continue;"])?).to_string(),
+        _ => format!("{} = {};", u.choose(&VARIABLES)?, expression(u, depth)?),
+    };
+
+    Ok(code)
+}
+
+/// Generate the source code of a random definition.
+pub fn definition(u: &mut Unstructured<'_>, depth: usize) -> Result<String> {
+    let depth = depth.saturating_sub(1);
+    let code = match u.choose_index(6)? {
+        0 => format!(
+            "function {}({}): {} {}",
+            u.choose(&FUNCTIONS)?,
+            parameters(u)?,
+            u.choose(&TYPES)?,
+            block(u, depth)?
+        ),
+        1 => format!(
+            "const int {} = {};",
+            u.choose(&CONSTANTS)?,
+            u.int_in_range(0..=1000u32)?
+        ),
+        2 => format!(
+            "type {} = {}|{};",
+            u.choose(&CLASSES)?,
+            u.choose(&TYPES)?,
+            u.choose(&TYPES)?
+        ),
+        3 => {
+            let mut members = vec![];
+            for _ in 0..u.int_in_range(0..=3)? {
+                members.push(if u.arbitrary()? {
+                    format!("private {} {};", u.choose(&TYPES)?, u.choose(&VARIABLES)?)
+                } else {
+                    format!(
+                        "public function {}({}): {} {}",
+                        u.choose(&FUNCTIONS)?,
+                        parameters(u)?,
+                        u.choose(&TYPES)?,
+                        block(u, depth)?
+                    )
+                });
+            }
+
+            format!(
+                "final class {} {{ {} }}",
+                u.choose(&CLASSES)?,
+                members.join(" ")
+            )
+        }
+        4 => format!(
+            "interface {} {{ public function {}({}): {}; }}",
+            u.choose(&CLASSES)?,
+            u.choose(&FUNCTIONS)?,
+            parameters(u)?,
+            u.choose(&TYPES)?
+        ),
+        _ => format!(
+            "enum {} {{ case {}; }}",
+            u.choose(&CLASSES)?,
+            u.choose(&CLASSES)?
+        ),
+    };
+
+    Ok(code)
+}
+
+fn leaf(u: &mut Unstructured<'_>) -> Result<String> {
+    let code = match u.choose_index(7)? {
+        0 => u.int_in_range(0..=1000u32)?.to_string(),
+        1 => format!("{}.5", u.int_in_range(0..=1000u32)?),
+        2 => format!("'{}'", u.choose(&FUNCTIONS)?),
+        3 => u.choose(&CONSTANTS)?.to_string(),
+        4 => (*u.choose(&["true", "false", "null"])?).to_string(),
+        _ => u.choose(&VARIABLES)?.to_string(),
+    };
+
+    Ok(code)
+}
+
+fn arguments(u: &mut Unstructured<'_>, depth: usize) -> Result<String> {
+    let mut arguments = vec![];
+    for _ in 0..u.int_in_range(0..=3)? {
+        arguments.push(expression(u, depth)?);
+    }
+
+    Ok(arguments.join(", "))
+}
+
+fn parameters(u: &mut Unstructured<'_>) -> Result<String> {
+    let mut parameters = vec![];
+    for variable in VARIABLES.iter().take(u.int_in_range(0..=3)?) {
+        parameters.push(format!("{} {}", u.choose(&TYPES)?, variable));
+    }
+
+    Ok(parameters.join(", "))
+}
+
+fn block(u: &mut Unstructured<'_>, depth: usize) -> Result<String> {
+    let mut statements = vec![];
+    for _ in 0..u.int_in_range(0..=3)? {
+        statements.push(statement(u, depth)?);
+    }
+
+    Ok(format!("{{ {} }}", statements.join(" ")))
+}
@@ -0,0 +1,141 @@
+use std::fmt::Display;
+
+use ara_reporting::Report;
+use arbitrary::Arbitrary;
+use arbitrary::Unstructured;
+
+use crate::parser;
+use crate::printer;
+use crate::printer::Print;
+use crate::printer::PrinterOptions;
+use crate::tree::comment::CommentAttachments;
+use crate::tree::definition::Definition;
+use crate::tree::expression::Expression;
+use crate::tree::statement::Statement;
+use crate::tree::Node;
+
+mod generator;
+
+/// A node that can be parsed on its own, and printed back to source code.
+pub trait RoundTrip: Node + Print + Sized {
+    fn parse(code: &str) -> Result<Self, Box<Report>>;
+}
+
+impl RoundTrip for Definition {
+    fn parse(code: &str) -> Result<Self, Box<Report>> {
+        parser::parse_definition(code).map(|fragment| fragment.node)
+    }
+}
+
+impl RoundTrip for Statement {
+    fn parse(code: &str) -> Result<Self, Box<Report>> {
+        parser::parse_statement(code).map(|fragment| fragment.node)
+    }
+}
+
+impl RoundTrip for Expression {
+    fn parse(code: &str) -> Result<Self, Box<Report>> {
+        parser::parse_expression(code).map(|fragment| fragment.node)
+    }
+}
+
+/// An error found by printing a node, and parsing the printed code back.
+#[derive(Debug)]
+pub enum RoundTripError {
+    /// The printed code failed to parse.
+    Parse { code: String, report: Box<Report> },
+    /// The printed code parsed into a node with a different structure, which prints as `reprinted`.
+    Mismatch { code: String, reprinted: String },
+}
+
+impl Display for RoundTripError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Parse { code, .. } => write!(f, "printed code failed to parse: `{code}`"),
+            Self::Mismatch { code, reprinted } => write!(
+                f,
+                "printed code `{code}` parsed into a different node, printed as `{reprinted}`"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RoundTripError {}
+
+/// Print the given node, parse the printed code back, and check that the parsed node has the
+/// same structure as the given one, ignoring positions and comments.
+pub fn round_trip<T: RoundTrip>(node: &T) -> Result<T, RoundTripError> {
+    let comments = CommentAttachments::default();
+    let code = printer::print_node(node, &comments, PrinterOptions::default());
+
+    let parsed = match T::parse(&code) {
+        Ok(parsed) => parsed,
+        Err(report) => return Err(RoundTripError::Parse { code, report }),
+    };
+
+    if parsed.structural_hash() != node.structural_hash() {
+        let reprinted = printer::print_node(&parsed, &comments, PrinterOptions::default());
+
+        return Err(RoundTripError::Mismatch { code, reprinted });
+    }
+
+    Ok(parsed)
+}
+
+/// Parse code produced by the generator, which only produces valid code.
+fn generated<T: RoundTrip>(code: String) -> T {
+    T::parse(&code).unwrap_or_else(|_| panic!("generated code failed to parse: `{code}`"))
+}
+
+impl<'a> Arbitrary<'a> for Definition {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        generator::definition(u, generator::MAX_DEPTH).map(generated)
+    }
+}
+
+impl<'a> Arbitrary<'a> for Statement {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        generator::statement(u, generator::MAX_DEPTH).map(generated)
+    }
+}
+
+impl<'a> Arbitrary<'a> for Expression {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        generator::expression(u, generator::MAX_DEPTH).map(generated)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Generate a node of the given type from each of a number of pseudo-random inputs,
+    /// and check that it round trips.
+    fn check<T: RoundTrip + for<'a> Arbitrary<'a>>() {
+        let mut state: u64 = 0x2545f4914f6cdd1d;
+        for _ in 0..200 {
+            let bytes = (0..256)
+                .map(|_| {
+                    // xorshift64
+                    state ^= state << 13;
+                    state ^= state >> 7;
+                    state ^= state << 17;
+
+                    state as u8
+                })
+                .collect::<Vec<u8>>();
+
+            let node = T::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+            if let Err(error) = round_trip(&node) {
+                panic!("{error}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        check::<Expression>();
+        check::<Statement>();
+        check::<Definition>();
+    }
+}
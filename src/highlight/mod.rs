@@ -0,0 +1,406 @@
+use std::collections::HashMap;
+
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::highlight::theme::Theme;
+use crate::lexer::token::Token;
+use crate::lexer::token::TokenKind;
+use crate::tree::definition::attribute::AttributeDefinition;
+use crate::tree::definition::class::ClassDefinition;
+use crate::tree::definition::function::FunctionDefinition;
+use crate::tree::definition::function::MethodDefinition;
+use crate::tree::definition::interface::InterfaceDefinition;
+use crate::tree::definition::r#enum::BackedEnumDefinition;
+use crate::tree::definition::r#enum::UnitEnumDefinition;
+use crate::tree::downcast;
+use crate::tree::expression::operator::ClassOperationExpression;
+use crate::tree::expression::operator::ClassOperationInitializationClassExpression;
+use crate::tree::expression::operator::FunctionOperationExpression;
+use crate::tree::expression::operator::ObjectOperationExpression;
+use crate::tree::expression::Expression;
+use crate::tree::identifier::TemplatedIdentifier;
+use crate::tree::kind::NodeKind;
+use crate::tree::span::Span;
+use crate::tree::Node;
+use crate::tree::Tree;
+
+pub mod theme;
+
+/// The kind of highlighting of a span of source code.
+#[derive(
+    Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash, Deserialize, Serialize, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Highlight {
+    Keyword,
+    Type,
+    Function,
+    Variable,
+    String,
+    Number,
+    Constant,
+    Comment,
+    Attribute,
+    Identifier,
+    Operator,
+    Punctuation,
+}
+
+impl Highlight {
+    /// The name of the highlight, e.g. `keyword`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Keyword => "keyword",
+            Self::Type => "type",
+            Self::Function => "function",
+            Self::Variable => "variable",
+            Self::String => "string",
+            Self::Number => "number",
+            Self::Constant => "constant",
+            Self::Comment => "comment",
+            Self::Attribute => "attribute",
+            Self::Identifier => "identifier",
+            Self::Operator => "operator",
+            Self::Punctuation => "punctuation",
+        }
+    }
+}
+
+/// A span of source code, and its highlight.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct HighlightedSpan {
+    pub span: Span,
+    pub highlight: Highlight,
+}
+
+/// Highlight the given tokens, in order, as lexed from a source.
+///
+/// When given the tree parsed from the same source, identifiers are refined based on their role
+/// in the tree, e.g. the names of types, and functions.
+pub fn highlight(tokens: &[Token], tree: Option<&Tree>) -> Vec<HighlightedSpan> {
+    let semantics = tree.map(semantics).unwrap_or_default();
+
+    tokens
+        .iter()
+        .filter(|token| token.kind != TokenKind::Eof)
+        .map(|token| {
+            let lexical = lexical(&token.kind);
+            let highlight = match lexical {
+                Highlight::Identifier | Highlight::Keyword => {
+                    semantics.get(&token.start()).copied().unwrap_or(lexical)
+                }
+                _ => lexical,
+            };
+
+            HighlightedSpan {
+                span: Span::new(token.start(), token.end()),
+                highlight,
+            }
+        })
+        .collect()
+}
+
+/// Render the given source as HTML, wrapping each highlighted span in a `<span>` element with the
+/// class given by the theme.
+///
+/// The output is not wrapped in any element, e.g. `<pre>`, so that it can be embedded as needed.
+pub fn html(source: &str, highlights: &[HighlightedSpan], theme: &dyn Theme) -> String {
+    render(source, highlights, |output, text, highlight| {
+        let class = highlight.and_then(|highlight| theme.class(highlight));
+        if let Some(class) = &class {
+            output.push_str("<span class=\"");
+            output.push_str(&escape(class));
+            output.push_str("\">");
+        }
+
+        output.push_str(&escape(text));
+
+        if class.is_some() {
+            output.push_str("</span>");
+        }
+    })
+}
+
+/// Render the given source for a terminal, styling each highlighted span with the ANSI escape
+/// sequences given by the theme.
+pub fn ansi(source: &str, highlights: &[HighlightedSpan], theme: &dyn Theme) -> String {
+    render(
+        source,
+        highlights,
+        |output, text, highlight| match highlight.and_then(|highlight| theme.ansi(highlight)) {
+            Some(style) => {
+                output.push_str("\x1b[");
+                output.push_str(style);
+                output.push('m');
+                output.push_str(text);
+                output.push_str("\x1b[0m");
+            }
+            None => output.push_str(text),
+        },
+    )
+}
+
+/// Render the given source, calling `span` for the text of each highlighted span, and of each gap
+/// between them.
+fn render<F>(source: &str, highlights: &[HighlightedSpan], mut span: F) -> String
+where
+    F: FnMut(&mut String, &str, Option<Highlight>),
+{
+    let text = |from: usize, to: usize| source.get(from..to).unwrap_or_default();
+
+    let mut output = String::with_capacity(source.len());
+    let mut position = 0;
+    for highlighted in highlights {
+        if highlighted.span.start < position {
+            continue;
+        }
+
+        span(&mut output, text(position, highlighted.span.start), None);
+        span(
+            &mut output,
+            text(highlighted.span.start, highlighted.span.end),
+            Some(highlighted.highlight),
+        );
+
+        position = highlighted.span.end;
+    }
+
+    span(&mut output, text(position, source.len()), None);
+
+    output
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// The highlight of a token of the given kind, regardless of its role in the tree.
+fn lexical(kind: &TokenKind) -> Highlight {
+    match kind {
+        TokenKind::Identifier
+        | TokenKind::QualifiedIdentifier
+        | TokenKind::FullyQualifiedIdentifier => Highlight::Identifier,
+        TokenKind::Variable => Highlight::Variable,
+        TokenKind::LiteralString | TokenKind::StringPart | TokenKind::DoubleQuote => {
+            Highlight::String
+        }
+        TokenKind::LiteralInteger | TokenKind::LiteralFloat => Highlight::Number,
+        TokenKind::SingleLineComment
+        | TokenKind::HashMarkComment
+        | TokenKind::MultiLineComment
+        | TokenKind::DocumentComment => Highlight::Comment,
+        TokenKind::True
+        | TokenKind::False
+        | TokenKind::Null
+        | TokenKind::ClassConstant
+        | TokenKind::FunctionConstant
+        | TokenKind::MethodConstant
+        | TokenKind::LineConstant
+        | TokenKind::FileConstant
+        | TokenKind::DirConstant
+        | TokenKind::NamespaceConstant
+        | TokenKind::TraitConstant
+        | TokenKind::HaltCompilerOffsetConstant => Highlight::Constant,
+        TokenKind::Attribute => Highlight::Attribute,
+        TokenKind::LeftParen
+        | TokenKind::RightParen
+        | TokenKind::LeftBracket
+        | TokenKind::RightBracket
+        | TokenKind::LeftBrace
+        | TokenKind::RightBrace
+        | TokenKind::Comma
+        | TokenKind::SemiColon
+        | TokenKind::Colon
+        | TokenKind::DoubleColon
+        | TokenKind::Generic
+        | TokenKind::NamespaceSeparator => Highlight::Punctuation,
+        // every other token of a fixed text is an operator, the rest are keywords.
+        kind if kind.text().is_some() => Highlight::Operator,
+        _ => Highlight::Keyword,
+    }
+}
+
+/// The highlights of identifiers, and keywords, based on their role in the given tree, by the
+/// position of their token.
+fn semantics(tree: &Tree) -> HashMap<usize, Highlight> {
+    let mut highlights = HashMap::new();
+    let mut mark = |node: &dyn Node, highlight: Highlight| {
+        highlights.insert(node.initial_position(), highlight);
+    };
+
+    let mut stack: Vec<&dyn Node> = vec![&tree.definitions];
+    while let Some(node) = stack.pop() {
+        match node.kind() {
+            NodeKind::TypeDefinition => {
+                // every identifier, and keyword, within a type is a type name, e.g. `vec`.
+                let mut types = vec![node];
+                while let Some(node) = types.pop() {
+                    if let NodeKind::Identifier | NodeKind::Keyword = node.kind() {
+                        mark(node, Highlight::Type);
+                    }
+
+                    types.extend(node.children());
+                }
+
+                continue;
+            }
+            NodeKind::TemplatedIdentifier => {
+                if let Some(identifier) = downcast::<TemplatedIdentifier>(node) {
+                    mark(&identifier.name, Highlight::Type);
+                }
+            }
+            NodeKind::ClassDefinition => {
+                if let Some(class) = downcast::<ClassDefinition>(node) {
+                    mark(&class.name, Highlight::Type);
+                }
+            }
+            NodeKind::InterfaceDefinition => {
+                if let Some(interface) = downcast::<InterfaceDefinition>(node) {
+                    mark(&interface.name, Highlight::Type);
+                }
+            }
+            NodeKind::UnitEnumDefinition => {
+                if let Some(r#enum) = downcast::<UnitEnumDefinition>(node) {
+                    mark(&r#enum.name, Highlight::Type);
+                }
+            }
+            NodeKind::BackedEnumDefinition => {
+                if let Some(r#enum) = downcast::<BackedEnumDefinition>(node) {
+                    mark(&r#enum.name, Highlight::Type);
+                }
+            }
+            NodeKind::FunctionDefinition => {
+                if let Some(function) = downcast::<FunctionDefinition>(node) {
+                    mark(&function.name, Highlight::Function);
+                }
+            }
+            NodeKind::MethodDefinition => {
+                if let Some(method) = downcast::<MethodDefinition>(node) {
+                    mark(&method.name, Highlight::Function);
+                }
+            }
+            NodeKind::AttributeDefinition => {
+                if let Some(attribute) = downcast::<AttributeDefinition>(node) {
+                    mark(&attribute.name, Highlight::Attribute);
+                }
+            }
+            NodeKind::FunctionOperationExpression => {
+                if let Some(
+                    FunctionOperationExpression::Call { function, .. }
+                    | FunctionOperationExpression::ClosureCreation { function, .. },
+                ) = downcast::<FunctionOperationExpression>(node)
+                {
+                    if let Expression::Identifier(identifier) = function.as_ref() {
+                        mark(identifier, Highlight::Function);
+                    }
+                }
+            }
+            NodeKind::ObjectOperationExpression => {
+                if let Some(
+                    ObjectOperationExpression::MethodCall { method, .. }
+                    | ObjectOperationExpression::NullsafeMethodCall { method, .. }
+                    | ObjectOperationExpression::MethodClosureCreation { method, .. },
+                ) = downcast::<ObjectOperationExpression>(node)
+                {
+                    mark(method, Highlight::Function);
+                }
+            }
+            NodeKind::ClassOperationExpression => {
+                match downcast::<ClassOperationExpression>(node) {
+                    Some(ClassOperationExpression::Initialization {
+                        class: ClassOperationInitializationClassExpression::Identifier(identifier),
+                        ..
+                    }) => mark(identifier, Highlight::Type),
+                    Some(
+                        ClassOperationExpression::StaticMethodCall { class, method, .. }
+                        | ClassOperationExpression::StaticMethodClosureCreation {
+                            class, method, ..
+                        },
+                    ) => {
+                        mark(method, Highlight::Function);
+
+                        if let Expression::Identifier(identifier) = class.as_ref() {
+                            mark(identifier, Highlight::Type);
+                        }
+                    }
+                    Some(
+                        ClassOperationExpression::StaticPropertyFetch { class, .. }
+                        | ClassOperationExpression::ConstantFetch { class, .. },
+                    ) => {
+                        if let Expression::Identifier(identifier) = class.as_ref() {
+                            mark(identifier, Highlight::Type);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+
+        stack.extend(node.children());
+    }
+
+    highlights
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ara_source::source::Source;
+    use ara_source::source::SourceKind;
+
+    use crate::highlight::theme::DefaultTheme;
+    use crate::lexer;
+    use crate::parser;
+
+    #[test]
+    fn test_highlight() {
+        let code = "// sum\nfunction sum(vec<int> $values): int {\n    return Math::sum($values) + 1;\n}\n";
+        let source = Source::inline(SourceKind::Script, code);
+
+        let tokens = lexer::lex(&source).unwrap();
+        let tree = parser::parse(&source).unwrap();
+
+        let highlighted = |highlights: &[HighlightedSpan], text: &str| {
+            let start = code.find(text).unwrap();
+
+            highlights
+                .iter()
+                .find(|highlighted| highlighted.span.start == start)
+                .map(|highlighted| highlighted.highlight)
+        };
+
+        let lexical = highlight(&tokens, None);
+        assert_eq!(highlighted(&lexical, "// sum"), Some(Highlight::Comment));
+        assert_eq!(highlighted(&lexical, "function"), Some(Highlight::Keyword));
+        assert_eq!(highlighted(&lexical, "sum("), Some(Highlight::Identifier));
+        assert_eq!(highlighted(&lexical, "int>"), Some(Highlight::Identifier));
+        assert_eq!(highlighted(&lexical, "$values"), Some(Highlight::Variable));
+        assert_eq!(highlighted(&lexical, "+"), Some(Highlight::Operator));
+        assert_eq!(highlighted(&lexical, "1;"), Some(Highlight::Number));
+
+        let semantic = highlight(&tokens, Some(&tree));
+        assert_eq!(highlighted(&semantic, "sum("), Some(Highlight::Function));
+        assert_eq!(highlighted(&semantic, "vec"), Some(Highlight::Type));
+        assert_eq!(highlighted(&semantic, "int>"), Some(Highlight::Type));
+        assert_eq!(highlighted(&semantic, "Math"), Some(Highlight::Type));
+        assert_eq!(highlighted(&semantic, "sum($"), Some(Highlight::Function));
+
+        let html = html(code, &semantic, &DefaultTheme);
+        assert!(html.starts_with(
+            "<span class=\"ara-comment\">// sum</span>\n<span class=\"ara-keyword\">function</span> "
+        ));
+        assert!(html.contains(
+            "<span class=\"ara-type\">vec</span><span class=\"ara-operator\">&lt;</span>"
+        ));
+
+        let ansi = ansi(code, &semantic, &DefaultTheme);
+        assert!(ansi.starts_with("\x1b[2m// sum\x1b[0m\n\x1b[35mfunction\x1b[0m "));
+    }
+}
@@ -0,0 +1,37 @@
+use crate::highlight::Highlight;
+
+/// The styles of highlighted source code.
+pub trait Theme {
+    /// The ANSI SGR parameters styling text with the given highlight, e.g. `1;34` for bold blue,
+    /// `None` to leave it unstyled.
+    fn ansi(&self, highlight: Highlight) -> Option<&str>;
+
+    /// The CSS class of HTML text with the given highlight, `None` to leave it unstyled.
+    ///
+    /// Defaults to the name of the highlight prefixed with `ara-`, e.g. `ara-keyword`.
+    fn class(&self, highlight: Highlight) -> Option<String> {
+        Some(format!("ara-{}", highlight.name()))
+    }
+}
+
+/// A theme using the basic ANSI colors, which terminals adapt to their own palette.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct DefaultTheme;
+
+impl Theme for DefaultTheme {
+    fn ansi(&self, highlight: Highlight) -> Option<&str> {
+        let style = match highlight {
+            Highlight::Keyword => "35",
+            Highlight::Type => "36",
+            Highlight::Function => "34",
+            Highlight::Variable => "31",
+            Highlight::String => "32",
+            Highlight::Number | Highlight::Constant => "33",
+            Highlight::Comment => "2",
+            Highlight::Attribute => "1;36",
+            Highlight::Identifier | Highlight::Operator | Highlight::Punctuation => return None,
+        };
+
+        Some(style)
+    }
+}
@@ -0,0 +1,288 @@
+use serde_json::Value;
+
+use crate::import::elements;
+use crate::import::issue;
+use crate::import::kind;
+use crate::import::name;
+use crate::import::Importer;
+
+impl Importer<'_> {
+    /// Write the given top-level statements, each followed by an empty line.
+    pub(crate) fn definitions(&mut self, statements: &[Value]) {
+        for statement in statements {
+            match kind(statement) {
+                "Stmt_Nop" => continue,
+                // `declare(strict_types=1);`, Ara is always strict.
+                "Stmt_Declare" if statement["stmts"].is_null() => continue,
+                "Stmt_Namespace" if statement["name"].is_null() => {
+                    self.definitions(elements(&statement["stmts"]));
+
+                    continue;
+                }
+                "Stmt_Namespace" if !self.namespaced => {
+                    self.namespaced = true;
+
+                    self.write("namespace ");
+                    self.name(&statement["name"]);
+                    self.write(";");
+                    self.new_line();
+                    self.new_line();
+
+                    self.definitions(elements(&statement["stmts"]));
+
+                    continue;
+                }
+                "Stmt_Use" => self.uses(statement, "", &statement["uses"]),
+                "Stmt_GroupUse" => {
+                    let prefix = name(&statement["prefix"]).unwrap_or_default() + "\\";
+
+                    self.uses(statement, &prefix, &statement["uses"]);
+                }
+                "Stmt_Function" => self.function(statement),
+                "Stmt_Class" => self.class(statement),
+                "Stmt_Interface" => self.interface(statement),
+                "Stmt_Enum" => self.r#enum(statement),
+                "Stmt_Const" => {
+                    for (index, constant) in elements(&statement["consts"]).iter().enumerate() {
+                        if index > 0 {
+                            self.new_line();
+                        }
+
+                        self.constant(constant);
+                    }
+                }
+                // Ara sources only contain definitions.
+                _ => self.unsupported_statement(statement),
+            }
+
+            self.new_line();
+            self.new_line();
+        }
+    }
+
+    fn uses(&mut self, statement: &Value, prefix: &str, uses: &Value) {
+        for (index, r#use) in elements(uses).iter().enumerate() {
+            if index > 0 {
+                self.new_line();
+            }
+
+            self.write("use ");
+
+            let r#type = match r#use["type"].as_u64() {
+                Some(0) | None => statement["type"].as_u64(),
+                r#type => r#type,
+            };
+            match r#type {
+                Some(2) => self.write("function "),
+                Some(3) => self.write("const "),
+                _ => {}
+            }
+
+            self.write(prefix);
+            self.name(&r#use["name"]);
+            if !r#use["alias"].is_null() {
+                self.write(" as ");
+                self.name(&r#use["alias"]);
+            }
+            self.write(";");
+        }
+    }
+
+    fn function(&mut self, function: &Value) {
+        self.attributes(&function["attrGroups"], false);
+
+        let from = self.code.len();
+        self.write("function ");
+        self.name(&function["name"]);
+        if function["byRef"].as_bool() == Some(true) {
+            self.unsupported_since(function, from);
+        }
+
+        self.parameters(&function["params"], false);
+        self.return_type(&function["returnType"], function, false);
+        self.write(" ");
+        self.block(&function["stmts"]);
+    }
+
+    fn class(&mut self, class: &Value) {
+        self.attributes(&class["attrGroups"], false);
+        self.modifiers(&class["flags"], false);
+        self.write("class ");
+        self.name(&class["name"]);
+        self.class_header(class);
+        self.write(" ");
+        self.members(&class["stmts"]);
+    }
+
+    /// Write the `extends`, and `implements`, clauses of the given class.
+    pub(crate) fn class_header(&mut self, class: &Value) {
+        if !class["extends"].is_null() {
+            self.write(" extends ");
+            self.name(&class["extends"]);
+        }
+
+        self.names(" implements ", &class["implements"]);
+    }
+
+    fn interface(&mut self, interface: &Value) {
+        self.attributes(&interface["attrGroups"], false);
+        self.write("interface ");
+        self.name(&interface["name"]);
+        self.names(" extends ", &interface["extends"]);
+        self.write(" ");
+        self.members(&interface["stmts"]);
+    }
+
+    fn r#enum(&mut self, r#enum: &Value) {
+        self.attributes(&r#enum["attrGroups"], false);
+        self.write("enum ");
+        self.name(&r#enum["name"]);
+        if !r#enum["scalarType"].is_null() {
+            self.write(": ");
+            self.name(&r#enum["scalarType"]);
+        }
+        self.names(" implements ", &r#enum["implements"]);
+        self.write(" ");
+        self.members(&r#enum["stmts"]);
+    }
+
+    /// Write the given comma separated names, preceded by the given keyword, if any.
+    fn names(&mut self, keyword: &str, names: &Value) {
+        for (index, name) in elements(names).iter().enumerate() {
+            self.write(if index == 0 { keyword } else { ", " });
+            self.name(name);
+        }
+    }
+
+    /// Write the members of a class, an interface, or an enum, braced.
+    pub(crate) fn members(&mut self, members: &Value) {
+        let members = elements(members);
+        if members.is_empty() {
+            self.write("{}");
+
+            return;
+        }
+
+        self.write("{");
+        self.indentation += 1;
+        for member in members {
+            self.new_line();
+            match kind(member) {
+                "Stmt_ClassMethod" => self.method(member),
+                "Stmt_Property" => {
+                    for (index, property) in elements(&member["props"]).iter().enumerate() {
+                        if index > 0 {
+                            self.new_line();
+                        }
+
+                        self.attributes(&member["attrGroups"], false);
+                        self.modifiers(&member["flags"], true);
+                        self.type_definition(&member["type"], member);
+                        self.write(" $");
+                        self.name(&property["name"]);
+                        if !property["default"].is_null() {
+                            self.write(" = ");
+                            self.expression(&property["default"]);
+                        }
+                        self.write(";");
+                    }
+                }
+                "Stmt_ClassConst" => {
+                    for (index, constant) in elements(&member["consts"]).iter().enumerate() {
+                        if index > 0 {
+                            self.new_line();
+                        }
+
+                        self.attributes(&member["attrGroups"], false);
+                        self.modifiers(&member["flags"], true);
+                        self.constant(constant);
+                    }
+                }
+                "Stmt_EnumCase" => {
+                    self.attributes(&member["attrGroups"], false);
+                    self.write("case ");
+                    self.name(&member["name"]);
+                    if !member["expr"].is_null() {
+                        self.write(" = ");
+                        self.expression(&member["expr"]);
+                    }
+                    self.write(";");
+                }
+                // trait uses, and unknown members.
+                _ => self.unsupported_statement(member),
+            }
+        }
+        self.indentation -= 1;
+        self.new_line();
+        self.write("}");
+    }
+
+    fn method(&mut self, method: &Value) {
+        self.attributes(&method["attrGroups"], false);
+        self.modifiers(&method["flags"], true);
+
+        let from = self.code.len();
+        self.write("function ");
+        self.name(&method["name"]);
+        if method["byRef"].as_bool() == Some(true) {
+            self.unsupported_since(method, from);
+        }
+
+        // constructors, and destructors, have no return type.
+        let magic = matches!(
+            method["name"]["name"]
+                .as_str()
+                .map(str::to_ascii_lowercase)
+                .as_deref(),
+            Some("__construct" | "__destruct")
+        );
+
+        self.parameters(&method["params"], true);
+        self.return_type(&method["returnType"], method, magic);
+        if method["stmts"].is_null() {
+            self.write(";");
+        } else {
+            self.write(" ");
+            self.block(&method["stmts"]);
+        }
+    }
+
+    /// Write the given constant, typed after its value, as PHP constants are untyped.
+    fn constant(&mut self, constant: &Value) {
+        self.write("const ");
+
+        let mut value = &constant["value"];
+        while matches!(kind(value), "Expr_UnaryMinus" | "Expr_UnaryPlus") {
+            value = &value["expr"];
+        }
+
+        let r#type = match kind(value) {
+            "Scalar_LNumber" | "Scalar_Int" => Some("int"),
+            "Scalar_DNumber" | "Scalar_Float" => Some("float"),
+            "Scalar_String" | "Scalar_Encapsed" | "Scalar_InterpolatedString" => Some("string"),
+            "Expr_ConstFetch" => match name(&value["name"]).map(|n| n.to_ascii_lowercase()) {
+                Some(name) if name == "true" || name == "false" => Some("bool"),
+                Some(name) if name == "null" => Some("null"),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        match r#type {
+            Some(r#type) => self.write(r#type),
+            None => {
+                let from = self.code.len();
+                self.write("mixed");
+
+                let issue = issue::missing_type(self, constant, from, self.code.len());
+                self.issues.push(issue);
+            }
+        }
+
+        self.write(" ");
+        self.name(&constant["name"]);
+        self.write(" = ");
+        self.expression(&constant["value"]);
+        self.write(";");
+    }
+}
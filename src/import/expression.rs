@@ -0,0 +1,499 @@
+use serde_json::Value;
+
+use crate::import::elements;
+use crate::import::kind;
+use crate::import::name;
+use crate::import::Importer;
+
+impl Importer<'_> {
+    pub(crate) fn expression(&mut self, expression: &Value) {
+        let node_type = kind(expression);
+
+        if let Some(operator) = node_type.strip_prefix("Expr_BinaryOp_") {
+            match binary_operator(operator) {
+                Some(operator) => {
+                    self.operand(&expression["left"]);
+                    self.write(" ");
+                    self.write(operator);
+                    self.write(" ");
+                    self.operand(&expression["right"]);
+                }
+                // `xor`.
+                None => self.unsupported(expression, "null"),
+            }
+
+            return;
+        }
+
+        if let Some(operator) = node_type.strip_prefix("Expr_AssignOp_") {
+            match binary_operator(operator) {
+                Some(operator) => {
+                    self.expression(&expression["var"]);
+                    self.write(" ");
+                    self.write(operator);
+                    self.write("= ");
+                    self.expression(&expression["expr"]);
+                }
+                None => self.unsupported(expression, "null"),
+            }
+
+            return;
+        }
+
+        if let Some(cast) = node_type.strip_prefix("Expr_Cast_") {
+            let r#type = match cast {
+                "Int" => "int",
+                "Double" => "float",
+                "String" => "string",
+                "Bool" => "bool",
+                // `(array)`, `(object)`, and `(unset)`.
+                _ => {
+                    self.unsupported(expression, "null");
+
+                    return;
+                }
+            };
+
+            self.write("(");
+            self.operand(&expression["expr"]);
+            self.write(" into ");
+            self.write(r#type);
+            self.write(")");
+
+            return;
+        }
+
+        if let Some(constant) = node_type.strip_prefix("Scalar_MagicConst_") {
+            match constant {
+                "Class" | "Dir" | "File" | "Function" | "Line" | "Method" | "Namespace" => {
+                    self.write("__");
+                    self.write(&constant.to_ascii_uppercase());
+                    self.write("__");
+                }
+                // `__TRAIT__`, and `__PROPERTY__`.
+                _ => self.unsupported(expression, "null"),
+            }
+
+            return;
+        }
+
+        match node_type {
+            "Expr_Variable" => self.variable(expression),
+            "Scalar_String" => {
+                self.string(expression["value"].as_str().unwrap_or_default());
+            }
+            "Scalar_LNumber" | "Scalar_Int" => match &expression["value"] {
+                Value::Number(number) => self.write(&number.to_string()),
+                _ => self.unsupported(expression, "0"),
+            },
+            "Scalar_DNumber" | "Scalar_Float" => match expression["value"].as_f64() {
+                Some(value) => self.write(&format!("{value:?}")),
+                // `INF`, and `NAN`.
+                None => self.unsupported(expression, "0.0"),
+            },
+            "Scalar_Encapsed" | "Scalar_InterpolatedString" => {
+                self.write("(");
+                for (index, part) in elements(&expression["parts"]).iter().enumerate() {
+                    if index > 0 {
+                        self.write(" . ");
+                    }
+
+                    match kind(part) {
+                        "Scalar_EncapsedStringPart" | "InterpolatedStringPart" => {
+                            self.string(part["value"].as_str().unwrap_or_default());
+                        }
+                        _ => self.operand(part),
+                    }
+                }
+                self.write(")");
+            }
+            "Expr_ConstFetch" => match name(&expression["name"]) {
+                Some(constant)
+                    if matches!(
+                        constant.to_ascii_lowercase().as_str(),
+                        "true" | "false" | "null"
+                    ) =>
+                {
+                    self.write(&constant.to_ascii_lowercase());
+                }
+                _ => self.name(&expression["name"]),
+            },
+            "Expr_Assign" => {
+                self.expression(&expression["var"]);
+                self.write(" = ");
+                self.expression(&expression["expr"]);
+            }
+            "Expr_BooleanNot" => self.prefix("!", &expression["expr"]),
+            "Expr_BitwiseNot" => self.prefix("~", &expression["expr"]),
+            "Expr_UnaryMinus" => self.prefix("-", &expression["expr"]),
+            "Expr_UnaryPlus" => self.prefix("+", &expression["expr"]),
+            "Expr_PreInc" => self.prefix("++", &expression["var"]),
+            "Expr_PreDec" => self.prefix("--", &expression["var"]),
+            "Expr_PostInc" => {
+                self.operand(&expression["var"]);
+                self.write("++");
+            }
+            "Expr_PostDec" => {
+                self.operand(&expression["var"]);
+                self.write("--");
+            }
+            "Expr_Clone" => self.prefix("clone ", &expression["expr"]),
+            "Expr_Throw" => self.prefix("throw ", &expression["expr"]),
+            "Expr_YieldFrom" => self.prefix("yield from ", &expression["expr"]),
+            "Expr_Yield" => {
+                self.write("yield");
+                if !expression["key"].is_null() {
+                    self.write(" ");
+                    self.operand(&expression["key"]);
+                    self.write(" =>");
+                }
+                if !expression["value"].is_null() {
+                    self.write(" ");
+                    self.operand(&expression["value"]);
+                }
+            }
+            "Expr_Exit" => {
+                self.write("exit");
+                if !expression["expr"].is_null() {
+                    self.write("(");
+                    self.expression(&expression["expr"]);
+                    self.write(")");
+                }
+            }
+            "Expr_Isset" => {
+                for (index, variable) in elements(&expression["vars"]).iter().enumerate() {
+                    if index > 0 {
+                        self.write(" && ");
+                    }
+
+                    self.write("(isset ");
+                    self.operand(variable);
+                    self.write(")");
+                }
+            }
+            "Expr_Instanceof" => {
+                self.operand(&expression["expr"]);
+                self.write(" instanceof ");
+                self.name(&expression["class"]);
+            }
+            "Expr_Ternary" => {
+                self.operand(&expression["cond"]);
+                if expression["if"].is_null() {
+                    self.write(" ?: ");
+                } else {
+                    self.write(" ? ");
+                    self.operand(&expression["if"]);
+                    self.write(" : ");
+                }
+                self.operand(&expression["else"]);
+            }
+            "Expr_ArrayDimFetch" => {
+                self.operand(&expression["var"]);
+                self.write("[");
+                if !expression["dim"].is_null() {
+                    self.expression(&expression["dim"]);
+                }
+                self.write("]");
+            }
+            "Expr_PropertyFetch" | "Expr_NullsafePropertyFetch" => {
+                self.operand(&expression["var"]);
+                self.write(if node_type == "Expr_PropertyFetch" {
+                    "->"
+                } else {
+                    "?->"
+                });
+                self.name(&expression["name"]);
+            }
+            "Expr_MethodCall" | "Expr_NullsafeMethodCall" => {
+                self.operand(&expression["var"]);
+                self.write(if node_type == "Expr_MethodCall" {
+                    "->"
+                } else {
+                    "?->"
+                });
+                self.name(&expression["name"]);
+                self.arguments(&expression["args"]);
+            }
+            "Expr_StaticCall" => {
+                self.callee(&expression["class"]);
+                self.write("::");
+                self.name(&expression["name"]);
+                self.arguments(&expression["args"]);
+            }
+            "Expr_StaticPropertyFetch" => {
+                self.callee(&expression["class"]);
+                self.write("::$");
+                self.name(&expression["name"]);
+            }
+            "Expr_ClassConstFetch" => {
+                self.callee(&expression["class"]);
+                self.write("::");
+                self.name(&expression["name"]);
+            }
+            "Expr_FuncCall" => {
+                self.callee(&expression["name"]);
+                self.arguments(&expression["args"]);
+            }
+            "Expr_New" => {
+                let class = &expression["class"];
+                if kind(class) == "Stmt_Class" {
+                    self.attributes(&class["attrGroups"], true);
+                    self.write("new class");
+                    self.arguments(&expression["args"]);
+                    self.class_header(class);
+                    self.write(" ");
+                    self.members(&class["stmts"]);
+                } else {
+                    self.write("new ");
+                    match kind(class) {
+                        "Expr_Variable" => self.variable(class),
+                        _ => self.name(class),
+                    }
+                    self.arguments(&expression["args"]);
+                }
+            }
+            "Expr_Array" => self.array(expression),
+            "Expr_Match" => {
+                self.write("match (");
+                self.expression(&expression["cond"]);
+                self.write(") {");
+                self.indentation += 1;
+                for arm in elements(&expression["arms"]) {
+                    self.new_line();
+                    if arm["conds"].is_null() {
+                        self.write("default");
+                    } else {
+                        self.expressions(&arm["conds"]);
+                    }
+                    self.write(" => ");
+                    self.expression(&arm["body"]);
+                    self.write(",");
+                }
+                self.indentation -= 1;
+                self.new_line();
+                self.write("}");
+            }
+            "Expr_Closure" => {
+                self.attributes(&expression["attrGroups"], true);
+                if expression["static"].as_bool() == Some(true) {
+                    self.write("static ");
+                }
+
+                let from = self.code.len();
+                self.write("function");
+                if expression["byRef"].as_bool() == Some(true) {
+                    self.unsupported_since(expression, from);
+                }
+
+                self.parameters(&expression["params"], false);
+
+                let uses = elements(&expression["uses"]);
+                if !uses.is_empty() {
+                    self.write(" use (");
+                    for (index, r#use) in uses.iter().enumerate() {
+                        if index > 0 {
+                            self.write(", ");
+                        }
+
+                        let from = self.code.len();
+                        self.variable(&r#use["var"]);
+                        if r#use["byRef"].as_bool() == Some(true) {
+                            self.unsupported_since(r#use, from);
+                        }
+                    }
+                    self.write(")");
+                }
+
+                self.return_type(&expression["returnType"], expression, false);
+                self.write(" ");
+                self.block(&expression["stmts"]);
+            }
+            "Expr_ArrowFunction" => {
+                self.attributes(&expression["attrGroups"], true);
+                if expression["static"].as_bool() == Some(true) {
+                    self.write("static ");
+                }
+
+                let from = self.code.len();
+                self.write("fn");
+                if expression["byRef"].as_bool() == Some(true) {
+                    self.unsupported_since(expression, from);
+                }
+
+                self.parameters(&expression["params"], false);
+                self.return_type(&expression["returnType"], expression, false);
+                self.write(" => ");
+                self.expression(&expression["expr"]);
+            }
+            // `list()`, `empty()`, `eval()`, `include`, `print`, references, etc.
+            _ => self.unsupported(expression, "null"),
+        }
+    }
+
+    /// Write the given comma separated expressions.
+    pub(crate) fn expressions(&mut self, expressions: &Value) {
+        for (index, expression) in elements(expressions).iter().enumerate() {
+            if index > 0 {
+                self.write(", ");
+            }
+
+            self.expression(expression);
+        }
+    }
+
+    /// Write the given arguments, parenthesized.
+    pub(crate) fn arguments(&mut self, arguments: &Value) {
+        self.write("(");
+        for (index, argument) in elements(arguments).iter().enumerate() {
+            if index > 0 {
+                self.write(", ");
+            }
+
+            if kind(argument) == "VariadicPlaceholder" {
+                self.write("...");
+
+                continue;
+            }
+
+            let from = self.code.len();
+            if argument["unpack"].as_bool() == Some(true) {
+                self.write("...");
+            }
+            self.expression(&argument["value"]);
+
+            // named arguments, and arguments passed by reference, are passed by position, and
+            // by value.
+            if !argument["name"].is_null() || argument["byRef"].as_bool() == Some(true) {
+                self.unsupported_since(argument, from);
+            }
+        }
+        self.write(")");
+    }
+
+    /// Write the given expression, parenthesized unless it is a primary expression, so it keeps
+    /// its meaning regardless of the surrounding operators.
+    fn operand(&mut self, expression: &Value) {
+        let primary = matches!(
+            kind(expression),
+            "Expr_Variable"
+                | "Expr_ConstFetch"
+                | "Expr_ClassConstFetch"
+                | "Expr_PropertyFetch"
+                | "Expr_NullsafePropertyFetch"
+                | "Expr_StaticPropertyFetch"
+                | "Expr_MethodCall"
+                | "Expr_NullsafeMethodCall"
+                | "Expr_StaticCall"
+                | "Expr_FuncCall"
+                | "Expr_ArrayDimFetch"
+                | "Expr_Array"
+                | "Expr_Match"
+                | "Scalar_String"
+                | "Scalar_LNumber"
+                | "Scalar_Int"
+                | "Scalar_DNumber"
+                | "Scalar_Float"
+                | "Scalar_Encapsed"
+                | "Scalar_InterpolatedString"
+        ) || kind(expression).starts_with("Expr_Cast_")
+            || kind(expression).starts_with("Scalar_MagicConst_");
+
+        if primary {
+            self.expression(expression);
+        } else {
+            self.write("(");
+            self.expression(expression);
+            self.write(")");
+        }
+    }
+
+    fn prefix(&mut self, operator: &str, expression: &Value) {
+        self.write(operator);
+        self.operand(expression);
+    }
+
+    /// Write the class of a static operation, or the function of a call, either a name, or an
+    /// expression.
+    fn callee(&mut self, callee: &Value) {
+        match name(callee) {
+            Some(name) => self.write(&name),
+            None => self.operand(callee),
+        }
+    }
+
+    /// Write the given array as a `vec` literal, or as a `dict` literal if any of its items has
+    /// a key.
+    fn array(&mut self, array: &Value) {
+        let items = elements(&array["items"])
+            .iter()
+            .filter(|item| !item.is_null())
+            .collect::<Vec<_>>();
+
+        let keyed = items.iter().any(|item| !item["key"].is_null());
+        if keyed && items.iter().any(|item| item["key"].is_null()) {
+            // a mix of keyed, and positional, items.
+            self.unsupported(array, "dict[]");
+
+            return;
+        }
+
+        self.write(if keyed { "dict[" } else { "vec[" });
+        for (index, item) in items.into_iter().enumerate() {
+            if index > 0 {
+                self.write(", ");
+            }
+
+            let from = self.code.len();
+            if keyed {
+                self.expression(&item["key"]);
+                self.write(" => ");
+            }
+            self.expression(&item["value"]);
+
+            // spread items, and items assigned by reference.
+            if item["unpack"].as_bool() == Some(true) || item["byRef"].as_bool() == Some(true) {
+                self.unsupported_since(item, from);
+            }
+        }
+        self.write("]");
+    }
+
+    /// Write the given string as a single quoted string literal.
+    fn string(&mut self, value: &str) {
+        self.write("'");
+        self.write(&value.replace('\\', "\\\\").replace('\'', "\\'"));
+        self.write("'");
+    }
+}
+
+/// The PHP operator of a binary operation of the given type, `None` if Ara has no equivalent
+/// operator.
+fn binary_operator(operation: &str) -> Option<&'static str> {
+    Some(match operation {
+        "Plus" => "+",
+        "Minus" => "-",
+        "Mul" => "*",
+        "Div" => "/",
+        "Mod" => "%",
+        "Pow" => "**",
+        "Concat" => ".",
+        "BooleanAnd" | "LogicalAnd" => "&&",
+        "BooleanOr" | "LogicalOr" => "||",
+        "BitwiseAnd" => "&",
+        "BitwiseOr" => "|",
+        "BitwiseXor" => "^",
+        "ShiftLeft" => "<<",
+        "ShiftRight" => ">>",
+        "Equal" => "==",
+        "NotEqual" => "!=",
+        "Identical" => "===",
+        "NotIdentical" => "!==",
+        "Smaller" => "<",
+        "SmallerOrEqual" => "<=",
+        "Greater" => ">",
+        "GreaterOrEqual" => ">=",
+        "Spaceship" => "<=>",
+        "Coalesce" => "??",
+        _ => return None,
+    })
+}
@@ -0,0 +1,151 @@
+use ara_reporting::issue::Issue;
+use serde_json::Value;
+
+use crate::import::Importer;
+
+/// The code of an issue reported when importing a PHP syntax tree, displayed as `I` followed by
+/// its number, e.g. `I0001`.
+///
+/// Codes are stable, the number of a code never changes, and new codes are only ever appended.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum ImportIssueCode {
+    /// The given input is not a JSON dump of a syntax tree produced by `nikic/php-parser`.
+    InvalidDump = 0,
+
+    /// A PHP construct that has no equivalent in Ara was encountered, it is replaced with a
+    /// placeholder, e.g. a comment in place of a statement, or `null` in place of an expression.
+    ///
+    /// Example:
+    ///
+    /// ```php
+    /// function foo() {
+    ///     goto end;
+    ///     end:
+    /// }
+    /// ```
+    UnsupportedConstruct = 1,
+
+    /// A declaration with no type was encountered, Ara requires parameters, properties,
+    /// constants, and functions to be typed, `mixed` is used instead.
+    ///
+    /// Example:
+    ///
+    /// ```php
+    /// function foo($bar) {
+    /// }
+    /// ```
+    MissingType = 2,
+
+    /// A PHP type that has no equivalent in Ara was encountered, the closest Ara type is used
+    /// instead, e.g. `dict<int|string, mixed>` for `array`.
+    ///
+    /// Example:
+    ///
+    /// ```php
+    /// function foo(array $bar): callable {
+    /// }
+    /// ```
+    ApproximatedType = 3,
+}
+
+pub(crate) fn invalid_dump(message: impl Into<String>) -> Issue {
+    Issue::error(ImportIssueCode::InvalidDump, message).with_note(
+        "the dump is expected to be a JSON array of statements, as encoded by `json_encode($statements)`.",
+    )
+}
+
+pub(crate) fn unsupported_construct(
+    importer: &Importer,
+    node: &Value,
+    from: usize,
+    to: usize,
+) -> Issue {
+    with_line(
+        Issue::error(
+            ImportIssueCode::UnsupportedConstruct,
+            format!("PHP construct `{}` can not be imported", kind(node)),
+        )
+        .with_source(importer.name, from, to),
+        node,
+    )
+}
+
+pub(crate) fn missing_type(importer: &Importer, node: &Value, from: usize, to: usize) -> Issue {
+    with_line(
+        Issue::warning(
+            ImportIssueCode::MissingType,
+            "missing type, `mixed` is used instead",
+        )
+        .with_source(importer.name, from, to),
+        node,
+    )
+}
+
+pub(crate) fn approximated_type(
+    importer: &Importer,
+    node: &Value,
+    r#type: &str,
+    from: usize,
+    to: usize,
+) -> Issue {
+    with_line(
+        Issue::warning(
+            ImportIssueCode::ApproximatedType,
+            format!(
+                "PHP type `{}` has no equivalent in Ara, `{}` is used instead",
+                node["name"].as_str().unwrap_or_else(|| kind(node)),
+                r#type,
+            ),
+        )
+        .with_source(importer.name, from, to),
+        node,
+    )
+}
+
+fn kind(node: &Value) -> &str {
+    node["nodeType"].as_str().unwrap_or("unknown")
+}
+
+fn with_line(issue: Issue, node: &Value) -> Issue {
+    match node["attributes"]["startLine"].as_u64() {
+        Some(line) => issue.with_note(format!(
+            "the construct starts on line {line} of the PHP source."
+        )),
+        None => issue,
+    }
+}
+
+impl ImportIssueCode {
+    /// All codes, in order of their numbers.
+    pub const ALL: [Self; 4] = [
+        Self::InvalidDump,
+        Self::UnsupportedConstruct,
+        Self::MissingType,
+        Self::ApproximatedType,
+    ];
+
+    /// The code displayed as the given string, e.g. `I0001`.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|candidate| candidate.to_string() == code)
+    }
+
+    /// The code of the given issue, if it was reported when importing a PHP syntax tree.
+    pub fn of(issue: &Issue) -> Option<Self> {
+        issue.code.as_deref().and_then(Self::from_code)
+    }
+}
+
+impl ::std::fmt::Display for ImportIssueCode {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        write!(f, "I{:04}", *self as u8)
+    }
+}
+
+impl From<ImportIssueCode> for String {
+    fn from(code: ImportIssueCode) -> String {
+        format!("{code}")
+    }
+}
@@ -0,0 +1,583 @@
+use ara_reporting::issue::Issue;
+use ara_reporting::Report;
+use ara_reporting::ReportFooter;
+use ara_source::source::Source;
+use ara_source::source::SourceKind;
+use serde_json::Value;
+
+use crate::parser;
+use crate::tree::Tree;
+
+pub mod issue;
+
+pub(crate) mod definition;
+pub(crate) mod expression;
+pub(crate) mod statement;
+
+/// A tree imported from a PHP syntax tree, see `import`.
+#[derive(Debug)]
+pub struct Import {
+    /// The Ara source code generated from the PHP syntax tree, the tree is parsed from it, and
+    /// issues point to it.
+    pub source: Source,
+    pub tree: Tree,
+    /// The PHP constructs that were approximated, or could not be imported at all.
+    pub issues: Vec<Issue>,
+}
+
+/// Import the given JSON dump of a PHP syntax tree, as produced by `nikic/php-parser` (4.x, or 5.x),
+/// into an approximate Ara tree.
+///
+/// The PHP syntax tree is converted to Ara source code, which is then parsed, as follows:
+///
+/// - namespaces, use statements, functions, classes, interfaces, enums, and constants are kept.
+/// - arrays are converted to `vec` literals, or to `dict` literals if any of their items has a key.
+/// - casts to scalar types are converted to `into` operations.
+/// - missing types are declared as `mixed`, and PHP types with no Ara equivalent, such as `array`,
+///   or `callable`, are replaced with the closest Ara type.
+///
+/// Constructs that have no equivalent in Ara, such as statements outside of functions, `switch`
+/// statements, or references, are replaced with a placeholder, and reported as an issue.
+///
+/// The import fails if the dump is invalid, or if the generated source code can not be parsed.
+pub fn import(name: &str, dump: &str) -> Result<Import, Box<Report>> {
+    let statements = match serde_json::from_str::<Value>(dump) {
+        Ok(Value::Array(statements)) => statements,
+        Ok(_) => {
+            return Err(failed(
+                name,
+                vec![issue::invalid_dump("expected an array of statements")],
+            ))
+        }
+        Err(error) => return Err(failed(name, vec![issue::invalid_dump(error.to_string())])),
+    };
+
+    let mut importer = Importer {
+        name,
+        code: String::new(),
+        indentation: 0,
+        namespaced: false,
+        issues: vec![],
+    };
+
+    importer.definitions(&statements);
+
+    let Importer { code, issues, .. } = importer;
+    let source = Source::new(SourceKind::Script, name, code.trim_end().to_string() + "\n");
+
+    match parser::parse(&source) {
+        Ok(tree) => Ok(Import {
+            source,
+            tree,
+            issues,
+        }),
+        Err(report) => {
+            let mut report_issues = report.issues;
+            report_issues.extend(issues);
+
+            Err(failed(name, report_issues))
+        }
+    }
+}
+
+fn failed(name: &str, issues: Vec<Issue>) -> Box<Report> {
+    Box::new(Report {
+        issues,
+        footer: Some(ReportFooter::new(format!(
+            "failed to import \"{name}\" due to the above issue(s)"
+        ))),
+    })
+}
+
+/// The state of converting a PHP syntax tree to Ara source code.
+#[derive(Debug)]
+pub(crate) struct Importer<'a> {
+    name: &'a str,
+    code: String,
+    indentation: usize,
+    /// Whether a namespace was declared, Ara sources can only declare one.
+    namespaced: bool,
+    issues: Vec<Issue>,
+}
+
+impl Importer<'_> {
+    fn write(&mut self, code: &str) {
+        self.code.push_str(code);
+    }
+
+    fn new_line(&mut self) {
+        self.code.push('\n');
+        for _ in 0..self.indentation {
+            self.code.push_str("    ");
+        }
+    }
+
+    /// Write the given placeholder in place of the given node, and report it as unsupported.
+    fn unsupported(&mut self, node: &Value, placeholder: &str) {
+        let from = self.code.len();
+        self.write(placeholder);
+
+        let issue = issue::unsupported_construct(self, node, from, self.code.len());
+        self.issues.push(issue);
+    }
+
+    /// Write a comment in place of the given statement, and report it as unsupported.
+    fn unsupported_statement(&mut self, statement: &Value) {
+        let placeholder = format!("/* unsupported: {} */", kind(statement));
+
+        self.unsupported(statement, &placeholder);
+    }
+
+    /// Report the given node as unsupported, pointing at the code written since the given offset.
+    fn unsupported_since(&mut self, node: &Value, from: usize) {
+        let issue = issue::unsupported_construct(self, node, from, self.code.len());
+
+        self.issues.push(issue);
+    }
+
+    /// Write a name, or an identifier.
+    fn name(&mut self, node: &Value) {
+        match name(node) {
+            Some(name) => self.write(&name),
+            None => self.unsupported(node, "unsupported"),
+        }
+    }
+
+    fn variable(&mut self, node: &Value) {
+        match node["name"].as_str() {
+            Some(name) if kind(node) == "Expr_Variable" => {
+                self.write("$");
+                self.write(name);
+            }
+            _ => self.unsupported(node, "$_"),
+        }
+    }
+
+    /// Write the given type, declared by the given owner, `mixed` if the type is missing.
+    fn type_definition(&mut self, node: &Value, owner: &Value) {
+        let from = self.code.len();
+
+        match kind(node) {
+            "" => {
+                self.write("mixed");
+
+                let issue = issue::missing_type(self, owner, from, self.code.len());
+                self.issues.push(issue);
+            }
+            "NullableType" => {
+                self.write("?");
+                self.type_definition(&node["type"], owner);
+            }
+            "UnionType" | "IntersectionType" => {
+                let separator = if kind(node) == "UnionType" { "|" } else { "&" };
+                for (index, inner) in elements(&node["types"]).iter().enumerate() {
+                    if index > 0 {
+                        self.write(separator);
+                    }
+
+                    if kind(inner) == "IntersectionType" {
+                        self.write("(");
+                        self.type_definition(inner, owner);
+                        self.write(")");
+                    } else {
+                        self.type_definition(inner, owner);
+                    }
+                }
+            }
+            "Identifier" => {
+                let name = node["name"]
+                    .as_str()
+                    .unwrap_or_default()
+                    .to_ascii_lowercase();
+                let approximation = match name.as_str() {
+                    "array" => "dict<int|string, mixed>",
+                    "callable" => "mixed",
+                    "iterable" => {
+                        self.write("iterable<mixed, mixed>");
+
+                        return;
+                    }
+                    _ => {
+                        self.write(&name);
+
+                        return;
+                    }
+                };
+
+                self.write(approximation);
+
+                let issue =
+                    issue::approximated_type(self, node, approximation, from, self.code.len());
+                self.issues.push(issue);
+            }
+            _ => self.name(node),
+        }
+    }
+
+    /// Write the return type of a function-like declaration, omitted if the type is missing, and
+    /// the function-like can omit it.
+    fn return_type(&mut self, node: &Value, owner: &Value, optional: bool) {
+        if node.is_null() && optional {
+            return;
+        }
+
+        self.write(": ");
+        self.type_definition(node, owner);
+    }
+
+    /// Write the given attribute groups, each followed by a new line, or a space if inline.
+    fn attributes(&mut self, groups: &Value, inline: bool) {
+        for group in elements(groups) {
+            self.write("#[");
+            for (index, attribute) in elements(&group["attrs"]).iter().enumerate() {
+                if index > 0 {
+                    self.write(", ");
+                }
+
+                self.name(&attribute["name"]);
+                if !elements(&attribute["args"]).is_empty() {
+                    self.arguments(&attribute["args"]);
+                }
+            }
+            self.write("]");
+
+            if inline {
+                self.write(" ");
+            } else {
+                self.new_line();
+            }
+        }
+    }
+
+    /// Write the modifiers of the given flags, as used by `nikic/php-parser`.
+    ///
+    /// Members with no visibility are public in PHP, and declared as such, if `public` is set.
+    fn modifiers(&mut self, flags: &Value, public: bool) {
+        let flags = flags.as_u64().unwrap_or(0);
+
+        let mut modifiers = vec![];
+        if flags & 16 != 0 {
+            modifiers.push("abstract");
+        }
+        if flags & 32 != 0 {
+            modifiers.push("final");
+        }
+        if flags & 1 != 0 || (public && flags & 7 == 0) {
+            modifiers.push("public");
+        }
+        if flags & 2 != 0 {
+            modifiers.push("protected");
+        }
+        if flags & 4 != 0 {
+            modifiers.push("private");
+        }
+        if flags & 8 != 0 {
+            modifiers.push("static");
+        }
+        if flags & 64 != 0 {
+            modifiers.push("readonly");
+        }
+
+        for modifier in modifiers {
+            self.write(modifier);
+            self.write(" ");
+        }
+    }
+
+    /// Write the given parameters, parenthesized, parameters of methods can be promoted.
+    fn parameters(&mut self, parameters: &Value, method: bool) {
+        self.write("(");
+        for (index, parameter) in elements(parameters).iter().enumerate() {
+            if index > 0 {
+                self.write(", ");
+            }
+
+            self.attributes(&parameter["attrGroups"], true);
+            if method {
+                self.modifiers(&parameter["flags"], false);
+            }
+
+            self.type_definition(&parameter["type"], parameter);
+            self.write(" ");
+
+            let from = self.code.len();
+            if parameter["variadic"].as_bool() == Some(true) {
+                self.write("...");
+            }
+            self.variable(&parameter["var"]);
+            if parameter["byRef"].as_bool() == Some(true) {
+                self.unsupported_since(parameter, from);
+            }
+
+            if !parameter["default"].is_null() {
+                self.write(" = ");
+                self.expression(&parameter["default"]);
+            }
+        }
+        self.write(")");
+    }
+}
+
+/// The type of the given node, e.g. `Stmt_Function`, empty if the value is not a node.
+fn kind(node: &Value) -> &str {
+    node["nodeType"].as_str().unwrap_or_default()
+}
+
+/// The elements of the given list, empty if the value is not a list.
+fn elements(list: &Value) -> &[Value] {
+    list.as_array().map(Vec::as_slice).unwrap_or_default()
+}
+
+/// The name of the given name, or identifier, node, `None` if the node is neither.
+fn name(node: &Value) -> Option<String> {
+    let name = match &node["parts"] {
+        Value::Array(parts) => parts
+            .iter()
+            .filter_map(Value::as_str)
+            .collect::<Vec<_>>()
+            .join("\\"),
+        _ => node["name"].as_str()?.to_string(),
+    };
+
+    match kind(node) {
+        "Name_FullyQualified" => Some(format!("\\{name}")),
+        "Name" | "Name_Relative" | "Identifier" | "VarLikeIdentifier" => Some(name),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::import::issue::ImportIssueCode;
+
+    const DUMP: &str = r#"[
+        {"nodeType": "Stmt_Declare", "declares": [], "stmts": null},
+        {
+            "nodeType": "Stmt_Namespace",
+            "name": {"nodeType": "Name", "parts": ["App"]},
+            "stmts": [
+                {
+                    "nodeType": "Stmt_Use",
+                    "type": 1,
+                    "uses": [{
+                        "nodeType": "Stmt_UseUse",
+                        "type": 0,
+                        "name": {"nodeType": "Name", "parts": ["Foo", "Bar"]},
+                        "alias": {"nodeType": "Identifier", "name": "Baz"}
+                    }]
+                },
+                {
+                    "nodeType": "Stmt_Class",
+                    "attrGroups": [],
+                    "flags": 32,
+                    "name": {"nodeType": "Identifier", "name": "Counter"},
+                    "extends": null,
+                    "implements": [{"nodeType": "Name", "parts": ["Baz"]}],
+                    "stmts": [
+                        {
+                            "nodeType": "Stmt_ClassConst",
+                            "attrGroups": [],
+                            "flags": 0,
+                            "consts": [{
+                                "nodeType": "Const",
+                                "name": {"nodeType": "Identifier", "name": "STEP"},
+                                "value": {"nodeType": "Scalar_LNumber", "value": 1}
+                            }]
+                        },
+                        {
+                            "nodeType": "Stmt_Property",
+                            "attrGroups": [],
+                            "flags": 4,
+                            "type": null,
+                            "props": [{
+                                "nodeType": "Stmt_PropertyProperty",
+                                "name": {"nodeType": "VarLikeIdentifier", "name": "items"},
+                                "default": {"nodeType": "Expr_Array", "items": []}
+                            }]
+                        },
+                        {
+                            "nodeType": "Stmt_ClassMethod",
+                            "attrGroups": [],
+                            "flags": 1,
+                            "byRef": false,
+                            "name": {"nodeType": "Identifier", "name": "add"},
+                            "params": [{
+                                "nodeType": "Param",
+                                "attrGroups": [],
+                                "flags": 0,
+                                "type": {"nodeType": "Identifier", "name": "array"},
+                                "byRef": false,
+                                "variadic": false,
+                                "var": {"nodeType": "Expr_Variable", "name": "values"},
+                                "default": null
+                            }],
+                            "returnType": {"nodeType": "Identifier", "name": "int"},
+                            "stmts": [
+                                {
+                                    "nodeType": "Stmt_Foreach",
+                                    "expr": {"nodeType": "Expr_Variable", "name": "values"},
+                                    "keyVar": {"nodeType": "Expr_Variable", "name": "key"},
+                                    "byRef": false,
+                                    "valueVar": {"nodeType": "Expr_Variable", "name": "value"},
+                                    "stmts": [{
+                                        "nodeType": "Stmt_Expression",
+                                        "expr": {
+                                            "nodeType": "Expr_Assign",
+                                            "var": {
+                                                "nodeType": "Expr_ArrayDimFetch",
+                                                "var": {
+                                                    "nodeType": "Expr_PropertyFetch",
+                                                    "var": {"nodeType": "Expr_Variable", "name": "this"},
+                                                    "name": {"nodeType": "Identifier", "name": "items"}
+                                                },
+                                                "dim": null
+                                            },
+                                            "expr": {
+                                                "nodeType": "Expr_BinaryOp_Mul",
+                                                "left": {
+                                                    "nodeType": "Expr_BinaryOp_Plus",
+                                                    "left": {"nodeType": "Expr_Cast_Int", "expr": {"nodeType": "Expr_Variable", "name": "value"}},
+                                                    "right": {
+                                                        "nodeType": "Expr_ClassConstFetch",
+                                                        "class": {"nodeType": "Name", "parts": ["self"]},
+                                                        "name": {"nodeType": "Identifier", "name": "STEP"}
+                                                    }
+                                                },
+                                                "right": {"nodeType": "Scalar_LNumber", "value": 2}
+                                            }
+                                        }
+                                    }]
+                                },
+                                {
+                                    "nodeType": "Stmt_Echo",
+                                    "attributes": {"startLine": 12},
+                                    "exprs": [{"nodeType": "Scalar_String", "value": "done"}]
+                                },
+                                {
+                                    "nodeType": "Stmt_Return",
+                                    "expr": {
+                                        "nodeType": "Expr_FuncCall",
+                                        "name": {"nodeType": "Name_FullyQualified", "parts": ["count"]},
+                                        "args": [{
+                                            "nodeType": "Arg",
+                                            "name": null,
+                                            "value": {
+                                                "nodeType": "Expr_PropertyFetch",
+                                                "var": {"nodeType": "Expr_Variable", "name": "this"},
+                                                "name": {"nodeType": "Identifier", "name": "items"}
+                                            },
+                                            "byRef": false,
+                                            "unpack": false
+                                        }]
+                                    }
+                                }
+                            ]
+                        }
+                    ]
+                },
+                {
+                    "nodeType": "Stmt_Function",
+                    "attrGroups": [],
+                    "byRef": false,
+                    "name": {"nodeType": "Identifier", "name": "greet"},
+                    "params": [{
+                        "nodeType": "Param",
+                        "attrGroups": [],
+                        "flags": 0,
+                        "type": null,
+                        "byRef": false,
+                        "variadic": false,
+                        "var": {"nodeType": "Expr_Variable", "name": "name"},
+                        "default": {"nodeType": "Scalar_String", "value": "it's"}
+                    }],
+                    "returnType": {"nodeType": "Identifier", "name": "string"},
+                    "stmts": [{
+                        "nodeType": "Stmt_Return",
+                        "expr": {
+                            "nodeType": "Scalar_Encapsed",
+                            "parts": [
+                                {"nodeType": "Scalar_EncapsedStringPart", "value": "Hello, "},
+                                {"nodeType": "Expr_Variable", "name": "name"},
+                                {"nodeType": "Scalar_EncapsedStringPart", "value": "!"}
+                            ]
+                        }
+                    }]
+                }
+            ]
+        }
+    ]"#;
+
+    #[test]
+    fn test_import() {
+        let import = import("app.ara", DUMP).unwrap();
+
+        pretty_assertions::assert_eq!(
+            import.source.content,
+            r#"namespace App;
+
+use Foo\Bar as Baz;
+
+final class Counter implements Baz {
+    public const int STEP = 1;
+    private mixed $items = vec[];
+    public function add(dict<int|string, mixed> $values): int {
+        foreach ($values as $key => $value) {
+            $this->items[] = (($value into int) + self::STEP) * 2;
+        }
+        /* unsupported: Stmt_Echo */
+        return \count($this->items);
+    }
+}
+
+function greet(mixed $name = 'it\'s'): string {
+    return ('Hello, ' . $name . '!');
+}
+"#
+        );
+
+        assert_eq!(import.tree.source, "app.ara");
+        assert_eq!(
+            import
+                .issues
+                .iter()
+                .map(|issue| ImportIssueCode::of(issue).unwrap())
+                .collect::<Vec<_>>(),
+            vec![
+                ImportIssueCode::MissingType,
+                ImportIssueCode::ApproximatedType,
+                ImportIssueCode::UnsupportedConstruct,
+                ImportIssueCode::MissingType,
+            ]
+        );
+
+        let (_, from, to) = import.issues[2].source.clone().unwrap();
+        assert_eq!(
+            &import.source.content[from..to],
+            "/* unsupported: Stmt_Echo */"
+        );
+        assert_eq!(
+            import.issues[2].notes,
+            vec!["the construct starts on line 12 of the PHP source.".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_import_invalid_dump() {
+        let report = import("app.ara", r#"{"nodeType": "Stmt_Nop"}"#).unwrap_err();
+
+        assert_eq!(
+            ImportIssueCode::of(&report.issues[0]),
+            Some(ImportIssueCode::InvalidDump)
+        );
+    }
+
+    #[test]
+    fn test_issue_codes() {
+        for (number, code) in ImportIssueCode::ALL.into_iter().enumerate() {
+            assert_eq!(code as usize, number);
+            assert_eq!(ImportIssueCode::from_code(&code.to_string()), Some(code));
+        }
+    }
+}
@@ -0,0 +1,165 @@
+use serde_json::Value;
+
+use crate::import::elements;
+use crate::import::kind;
+use crate::import::Importer;
+
+impl Importer<'_> {
+    /// Write the given statements, braced.
+    pub(crate) fn block(&mut self, statements: &Value) {
+        let statements = elements(statements)
+            .iter()
+            .filter(|statement| kind(statement) != "Stmt_Nop")
+            .collect::<Vec<_>>();
+
+        if statements.is_empty() {
+            self.write("{}");
+
+            return;
+        }
+
+        self.write("{");
+        self.indentation += 1;
+        for statement in statements {
+            self.new_line();
+            self.statement(statement);
+        }
+        self.indentation -= 1;
+        self.new_line();
+        self.write("}");
+    }
+
+    fn statement(&mut self, statement: &Value) {
+        match kind(statement) {
+            "Stmt_Expression" => {
+                self.expression(&statement["expr"]);
+                self.write(";");
+            }
+            "Stmt_Return" => {
+                self.write("return");
+                if !statement["expr"].is_null() {
+                    self.write(" ");
+                    self.expression(&statement["expr"]);
+                }
+                self.write(";");
+            }
+            "Stmt_Throw" => {
+                self.write("throw ");
+                self.expression(&statement["expr"]);
+                self.write(";");
+            }
+            "Stmt_If" => {
+                self.write("if (");
+                self.expression(&statement["cond"]);
+                self.write(") ");
+                self.block(&statement["stmts"]);
+
+                for elseif in elements(&statement["elseifs"]) {
+                    self.write(" else if (");
+                    self.expression(&elseif["cond"]);
+                    self.write(") ");
+                    self.block(&elseif["stmts"]);
+                }
+
+                if !statement["else"].is_null() {
+                    self.write(" else ");
+                    self.block(&statement["else"]["stmts"]);
+                }
+            }
+            "Stmt_While" => {
+                self.write("while (");
+                self.expression(&statement["cond"]);
+                self.write(") ");
+                self.block(&statement["stmts"]);
+            }
+            "Stmt_Do" => {
+                self.write("do ");
+                self.block(&statement["stmts"]);
+                self.write(" while (");
+                self.expression(&statement["cond"]);
+                self.write(");");
+            }
+            "Stmt_For" => {
+                self.write("for (");
+                self.expressions(&statement["init"]);
+                self.write("; ");
+                self.expressions(&statement["cond"]);
+                self.write("; ");
+                self.expressions(&statement["loop"]);
+                self.write(") ");
+                self.block(&statement["stmts"]);
+            }
+            "Stmt_Foreach" => {
+                self.write("foreach (");
+                self.expression(&statement["expr"]);
+                self.write(" as ");
+                if !statement["keyVar"].is_null() {
+                    self.variable(&statement["keyVar"]);
+                    self.write(" => ");
+                }
+
+                let from = self.code.len();
+                self.variable(&statement["valueVar"]);
+                if statement["byRef"].as_bool() == Some(true) {
+                    self.unsupported_since(statement, from);
+                }
+
+                self.write(") ");
+                self.block(&statement["stmts"]);
+            }
+            "Stmt_Break" | "Stmt_Continue" => {
+                self.write(if kind(statement) == "Stmt_Break" {
+                    "break"
+                } else {
+                    "continue"
+                });
+
+                if !statement["num"].is_null() {
+                    self.write(" ");
+                    self.expression(&statement["num"]);
+                }
+                self.write(";");
+            }
+            "Stmt_TryCatch" => {
+                self.write("try ");
+                self.block(&statement["stmts"]);
+
+                for catch in elements(&statement["catches"]) {
+                    self.write(" catch (");
+                    for (index, r#type) in elements(&catch["types"]).iter().enumerate() {
+                        if index > 0 {
+                            self.write(" | ");
+                        }
+
+                        self.name(r#type);
+                    }
+                    if !catch["var"].is_null() {
+                        self.write(" ");
+                        self.variable(&catch["var"]);
+                    }
+                    self.write(") ");
+                    self.block(&catch["stmts"]);
+                }
+
+                if !statement["finally"].is_null() {
+                    self.write(" finally ");
+                    self.block(&statement["finally"]["stmts"]);
+                }
+            }
+            "Stmt_Unset" => {
+                for (index, variable) in elements(&statement["vars"]).iter().enumerate() {
+                    if index > 0 {
+                        self.new_line();
+                    }
+
+                    self.write("unset ");
+                    self.expression(variable);
+                    self.write(";");
+                }
+            }
+            "Stmt_Block" => self.block(&statement["stmts"]),
+            // `switch`, `global`, `static`, `echo`, `goto`, nested definitions, etc.
+            _ => self.unsupported_statement(statement),
+        }
+    }
+}
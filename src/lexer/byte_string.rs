@@ -1,8 +1,19 @@
+use bincode::de::BorrowDecoder;
+use bincode::de::Decoder;
+use bincode::enc::Encoder;
+use bincode::error::DecodeError;
+use bincode::error::EncodeError;
+use bincode::BorrowDecode;
 use bincode::Decode;
 use bincode::Encode;
+use std::cmp::Ordering;
+use std::hash::Hash;
+use std::hash::Hasher;
 use std::ops::Deref;
 use std::ops::DerefMut;
+use std::ops::Range;
 use std::str::from_utf8;
+use std::sync::Arc;
 
 use schemars::JsonSchema;
 use serde::Deserialize;
@@ -13,17 +24,131 @@ use serde::Serialize;
 ///
 /// The Trunk lexer and parser work mainly with byte strings because
 /// valid PHP code is not required to be valid UTF-8.
-#[derive(PartialOrd, PartialEq, Eq, Clone, Hash, Encode, Decode)]
+///
+/// A byte string either owns its bytes, or shares them with the buffer of the source it was
+/// lexed from, see [`ByteString::shared`], in which case cloning it does not copy its bytes.
+#[derive(Clone)]
 pub struct ByteString {
-    pub bytes: Vec<u8>,
-    pub length: usize,
+    storage: Storage,
+}
+
+#[derive(Clone)]
+enum Storage {
+    Owned(Vec<u8>),
+    Shared {
+        buffer: Arc<[u8]>,
+        start: u32,
+        end: u32,
+    },
 }
 
 impl ByteString {
     pub fn new(bytes: Vec<u8>) -> Self {
-        let length = bytes.len();
+        ByteString {
+            storage: Storage::Owned(bytes),
+        }
+    }
+
+    /// A byte string holding the given range of the given buffer, without copying it.
+    ///
+    /// The buffer is kept alive for as long as the byte string, or any of its clones.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of the bounds of the buffer, or if the buffer is larger than
+    /// `u32::MAX` bytes.
+    pub fn shared(buffer: &Arc<[u8]>, range: Range<usize>) -> Self {
+        assert!(range.start <= range.end && range.end <= buffer.len());
+
+        ByteString {
+            storage: Storage::Shared {
+                buffer: buffer.clone(),
+                start: u32::try_from(range.start).unwrap(),
+                end: u32::try_from(range.end).unwrap(),
+            },
+        }
+    }
+
+    /// Whether the byte string shares its bytes with a source buffer.
+    pub fn is_shared(&self) -> bool {
+        matches!(self.storage, Storage::Shared { .. })
+    }
+
+    /// The bytes of the byte string.
+    pub fn as_bytes(&self) -> &[u8] {
+        match &self.storage {
+            Storage::Owned(bytes) => bytes,
+            Storage::Shared { buffer, start, end } => &buffer[*start as usize..*end as usize],
+        }
+    }
+
+    /// The bytes of the byte string, copying them out of the source buffer if they are shared.
+    pub fn to_mut(&mut self) -> &mut Vec<u8> {
+        if let Storage::Shared { .. } = self.storage {
+            self.storage = Storage::Owned(self.as_bytes().to_vec());
+        }
+
+        match &mut self.storage {
+            Storage::Owned(bytes) => bytes,
+            Storage::Shared { .. } => unreachable!(),
+        }
+    }
+
+    /// The byte string, copied out of the source buffer if it is shared, so that it no longer
+    /// keeps the buffer alive.
+    pub fn into_owned(mut self) -> Self {
+        self.to_mut();
+
+        self
+    }
+}
 
-        ByteString { bytes, length }
+impl PartialEq for ByteString {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+}
+
+impl Eq for ByteString {}
+
+impl PartialOrd for ByteString {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.as_bytes().cmp(other.as_bytes()))
+    }
+}
+
+impl Hash for ByteString {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_bytes().hash(state);
+    }
+}
+
+// the encoding of a byte string is its bytes followed by their length, as it was when the
+// bytes were held in a public field next to a public length field.
+impl Encode for ByteString {
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        self.as_bytes().encode(encoder)?;
+        self.len().encode(encoder)
+    }
+}
+
+impl<Context> Decode<Context> for ByteString {
+    fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        let bytes = Vec::decode(decoder)?;
+        usize::decode(decoder)?;
+
+        Ok(ByteString::new(bytes))
+    }
+}
+
+impl<'de, Context> BorrowDecode<'de, Context> for ByteString {
+    fn borrow_decode<D: BorrowDecoder<'de, Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, DecodeError> {
+        let bytes = Vec::borrow_decode(decoder)?;
+        usize::borrow_decode(decoder)?;
+
+        Ok(ByteString::new(bytes))
     }
 }
 
@@ -69,7 +194,7 @@ impl JsonSchema for ByteString {
 
 impl std::fmt::Display for ByteString {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for &b in &self.bytes {
+        for &b in self.as_bytes() {
             match b {
                 0 => write!(f, "\\0")?,
                 b'\n' | b'\r' | b'\t' => write!(f, "{}", b.escape_ascii())?,
@@ -93,7 +218,7 @@ impl std::str::FromStr for ByteString {
 impl std::fmt::Debug for ByteString {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "\"")?;
-        for &b in &self.bytes {
+        for &b in self.as_bytes() {
             match b {
                 0 => write!(f, "\\0")?,
                 b'\n' | b'\r' | b'\t' => write!(f, "{}", b.escape_ascii())?,
@@ -108,13 +233,13 @@ impl std::fmt::Debug for ByteString {
 
 impl<const N: usize> PartialEq<&[u8; N]> for ByteString {
     fn eq(&self, other: &&[u8; N]) -> bool {
-        &self.bytes == other
+        self.as_bytes() == *other
     }
 }
 
 impl<const N: usize> PartialEq<&[u8; N]> for &ByteString {
     fn eq(&self, other: &&[u8; N]) -> bool {
-        &self.bytes == other
+        self.as_bytes() == *other
     }
 }
 
@@ -156,21 +281,21 @@ impl From<String> for ByteString {
 
 impl From<ByteString> for String {
     fn from(bytes: ByteString) -> Self {
-        String::from(from_utf8(&bytes.bytes).unwrap())
+        String::from(from_utf8(bytes.as_bytes()).unwrap())
     }
 }
 
 impl Deref for ByteString {
-    type Target = Vec<u8>;
+    type Target = [u8];
 
-    fn deref(&self) -> &Vec<u8> {
-        &self.bytes
+    fn deref(&self) -> &[u8] {
+        self.as_bytes()
     }
 }
 
 impl DerefMut for ByteString {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.bytes
+    fn deref_mut(&mut self) -> &mut [u8] {
+        self.to_mut()
     }
 }
 
@@ -190,4 +315,19 @@ mod tests {
             r#""\x01\x10\x7f\xff""#
         );
     }
+
+    #[test]
+    fn test_byte_string_shared() {
+        let buffer: Arc<[u8]> = Arc::from(&b"foo bar"[..]);
+        let mut shared = ByteString::shared(&buffer, 4..7);
+
+        assert!(shared.is_shared());
+        assert_eq!(shared, ByteString::from("bar"));
+        assert_eq!(Arc::strong_count(&buffer), 2);
+
+        shared.to_mut().push(b's');
+        assert!(!shared.is_shared());
+        assert_eq!(shared, b"bars");
+        assert_eq!(Arc::strong_count(&buffer), 1);
+    }
 }
@@ -142,6 +142,8 @@ pub fn to_keyword(ident: &[u8]) -> Option<TokenKind> {
         b"__halt_compiler_offset__" => TokenKind::HaltCompilerOffsetConstant,
         b"while" => TokenKind::While,
         b"where" => TokenKind::Where,
+        b"permits" => TokenKind::Permits,
+        b"pragma" => TokenKind::Pragma,
         b"insteadof" => TokenKind::Insteadof,
         b"list" => TokenKind::List,
         b"empty" => TokenKind::Empty,
@@ -17,84 +17,84 @@ pub fn tokenize(state: &mut State) -> SyntaxResult<Token> {
         [b'!', b'=', b'='] => {
             state.bytes.skip(3);
 
-            (TokenKind::BangDoubleEquals, b"!==".into())
+            (TokenKind::BangDoubleEquals, ByteString::default())
         }
         [b'?', b'?', b'='] => {
             state.bytes.skip(3);
-            (TokenKind::DoubleQuestionEquals, b"??=".into())
+            (TokenKind::DoubleQuestionEquals, ByteString::default())
         }
         [b'?', b'-', b'>'] => {
             state.bytes.skip(3);
-            (TokenKind::QuestionArrow, b"?->".into())
+            (TokenKind::QuestionArrow, ByteString::default())
         }
         [b'=', b'=', b'='] => {
             state.bytes.skip(3);
-            (TokenKind::TripleEquals, b"===".into())
+            (TokenKind::TripleEquals, ByteString::default())
         }
         [b'.', b'.', b'.'] => {
             state.bytes.skip(3);
-            (TokenKind::Ellipsis, b"...".into())
+            (TokenKind::Ellipsis, ByteString::default())
         }
         [b':', b':', b'<'] => {
             state.bytes.skip(3);
-            (TokenKind::Generic, b"::<".into())
+            (TokenKind::Generic, ByteString::default())
         }
         [b'@', ..] => {
             state.bytes.next();
-            (TokenKind::At, b"@".into())
+            (TokenKind::At, ByteString::default())
         }
         [b'!', b'=', ..] => {
             state.bytes.skip(2);
-            (TokenKind::BangEquals, b"!=".into())
+            (TokenKind::BangEquals, ByteString::default())
         }
         [b'!', ..] => {
             state.bytes.next();
-            (TokenKind::Bang, b"!".into())
+            (TokenKind::Bang, ByteString::default())
         }
         [b'&', b'&', ..] => {
             state.bytes.skip(2);
-            (TokenKind::BooleanAnd, b"&&".into())
+            (TokenKind::BooleanAnd, ByteString::default())
         }
         [b'&', b'=', ..] => {
             state.bytes.skip(2);
-            (TokenKind::AmpersandEquals, b"&=".into())
+            (TokenKind::AmpersandEquals, ByteString::default())
         }
         [b'&', ..] => {
             state.bytes.next();
-            (TokenKind::Ampersand, b"&".into())
+            (TokenKind::Ampersand, ByteString::default())
         }
         [b'?', b'>', ..] => {
             state.bytes.skip(2);
 
-            (TokenKind::CloseTag, b"?>".into())
+            (TokenKind::CloseTag, ByteString::default())
         }
         [b'?', b'?', ..] => {
             state.bytes.skip(2);
-            (TokenKind::DoubleQuestion, b"??".into())
+            (TokenKind::DoubleQuestion, ByteString::default())
         }
         [b'?', b':', ..] => {
             state.bytes.skip(2);
-            (TokenKind::QuestionColon, b"?:".into())
+            (TokenKind::QuestionColon, ByteString::default())
         }
         [b'?', ..] => {
             state.bytes.next();
-            (TokenKind::Question, b"?".into())
+            (TokenKind::Question, ByteString::default())
         }
         [b'=', b'>', ..] => {
             state.bytes.skip(2);
-            (TokenKind::DoubleArrow, b"=>".into())
+            (TokenKind::DoubleArrow, ByteString::default())
         }
         [b'=', b'=', ..] => {
             state.bytes.skip(2);
-            (TokenKind::DoubleEquals, b"==".into())
+            (TokenKind::DoubleEquals, ByteString::default())
         }
         [b'=', ..] => {
             state.bytes.next();
-            (TokenKind::Equals, b"=".into())
+            (TokenKind::Equals, ByteString::default())
         }
         [b'<', b'?', b'=', ..] => {
             state.bytes.skip(3);
-            (TokenKind::OpenTag(OpenTagKind::Echo), b"<?=".into())
+            (TokenKind::OpenTag(OpenTagKind::Echo), ByteString::default())
         }
         [b'<', b'?', b'p', ..] if state.bytes.at_case_insensitive(b"<?php", 5) => {
             state.bytes.skip(5);
@@ -102,7 +102,10 @@ pub fn tokenize(state: &mut State) -> SyntaxResult<Token> {
         }
         [b'<', b'?', ..] => {
             state.bytes.skip(2);
-            (TokenKind::OpenTag(OpenTagKind::Short), b"<?".into())
+            (
+                TokenKind::OpenTag(OpenTagKind::Short),
+                ByteString::default(),
+            )
         }
         // Single quoted string.
         [b'\'', ..] => {
@@ -128,21 +131,21 @@ pub fn tokenize(state: &mut State) -> SyntaxResult<Token> {
         [b'$', ident_start!(), ..] => variable::tokenize(state),
         [b'$', ..] => {
             state.bytes.next();
-            (TokenKind::Dollar, b"$".into())
+            (TokenKind::Dollar, ByteString::default())
         }
         [b'.', b'=', ..] => {
             state.bytes.skip(2);
-            (TokenKind::DotEquals, b".=".into())
+            (TokenKind::DotEquals, ByteString::default())
         }
         [b'0'..=b'9', ..] => number::tokenize(state)?,
         [b'.', b'0'..=b'9', ..] => number::tokenize(state)?,
         [b'.', b'.', ..] => {
             state.bytes.skip(2);
-            (TokenKind::DoubleDot, b"..".into())
+            (TokenKind::DoubleDot, ByteString::default())
         }
         [b'.', ..] => {
             state.bytes.next();
-            (TokenKind::Dot, b".".into())
+            (TokenKind::Dot, ByteString::default())
         }
         [b'\\', ident_start!(), ..] => {
             state.bytes.next();
@@ -153,11 +156,10 @@ pub fn tokenize(state: &mut State) -> SyntaxResult<Token> {
                     value,
                     ..
                 } => {
-                    let mut bytes = value;
+                    let mut bytes = value.to_vec();
                     bytes.insert(0, b'\\');
-                    bytes.length += 1;
 
-                    (TokenKind::FullyQualifiedIdentifier, bytes)
+                    (TokenKind::FullyQualifiedIdentifier, bytes.into())
                 }
                 Token {
                     kind: TokenKind::True,
@@ -178,7 +180,7 @@ pub fn tokenize(state: &mut State) -> SyntaxResult<Token> {
         }
         [b'\\', ..] => {
             state.bytes.next();
-            (TokenKind::NamespaceSeparator, b"\\".into())
+            (TokenKind::NamespaceSeparator, ByteString::default())
         }
         [b'/', b'*', ..] => {
             state.bytes.next();
@@ -209,7 +211,7 @@ pub fn tokenize(state: &mut State) -> SyntaxResult<Token> {
         }
         [b'#', b'[', ..] => {
             state.bytes.skip(2);
-            (TokenKind::Attribute, b"#[".into())
+            (TokenKind::Attribute, ByteString::default())
         }
         [ch @ b'/', b'/', ..] | [ch @ b'#', ..] => {
             let mut buffer = if *ch == b'/' {
@@ -242,168 +244,168 @@ pub fn tokenize(state: &mut State) -> SyntaxResult<Token> {
         }
         [b'/', b'=', ..] => {
             state.bytes.skip(2);
-            (TokenKind::SlashEquals, b"/=".into())
+            (TokenKind::SlashEquals, ByteString::default())
         }
         [b'/', ..] => {
             state.bytes.next();
-            (TokenKind::Slash, b"/".into())
+            (TokenKind::Slash, ByteString::default())
         }
         [b'*', b'*', b'=', ..] => {
             state.bytes.skip(3);
-            (TokenKind::PowEquals, b"**=".into())
+            (TokenKind::PowEquals, ByteString::default())
         }
         [b'<', b'<', b'='] => {
             state.bytes.skip(3);
 
-            (TokenKind::LeftShiftEquals, b"<<=".into())
+            (TokenKind::LeftShiftEquals, ByteString::default())
         }
         [b'<', b'=', b'>'] => {
             state.bytes.skip(3);
-            (TokenKind::Spaceship, b"<=>".into())
+            (TokenKind::Spaceship, ByteString::default())
         }
         [b'>', b'>', b'='] => {
             state.bytes.skip(3);
-            (TokenKind::RightShiftEquals, b">>=".into())
+            (TokenKind::RightShiftEquals, ByteString::default())
         }
         [b'*', b'*', ..] => {
             state.bytes.skip(2);
-            (TokenKind::Pow, b"**".into())
+            (TokenKind::Pow, ByteString::default())
         }
         [b'*', b'=', ..] => {
             state.bytes.skip(2);
-            (TokenKind::AsteriskEquals, b"*=".into())
+            (TokenKind::AsteriskEquals, ByteString::default())
         }
         [b'*', ..] => {
             state.bytes.next();
-            (TokenKind::Asterisk, b"*".into())
+            (TokenKind::Asterisk, ByteString::default())
         }
         [b'|', b'|', ..] => {
             state.bytes.skip(2);
-            (TokenKind::BooleanOr, b"||".into())
+            (TokenKind::BooleanOr, ByteString::default())
         }
         [b'|', b'=', ..] => {
             state.bytes.skip(2);
-            (TokenKind::PipeEquals, b"|=".into())
+            (TokenKind::PipeEquals, ByteString::default())
         }
         [b'|', ..] => {
             state.bytes.next();
-            (TokenKind::Pipe, b"|".into())
+            (TokenKind::Pipe, ByteString::default())
         }
         [b'^', b'=', ..] => {
             state.bytes.skip(2);
-            (TokenKind::CaretEquals, b"^=".into())
+            (TokenKind::CaretEquals, ByteString::default())
         }
         [b'^', ..] => {
             state.bytes.next();
-            (TokenKind::Caret, b"^".into())
+            (TokenKind::Caret, ByteString::default())
         }
         [b'{', ..] => {
             state.bytes.next();
-            (TokenKind::LeftBrace, b"{".into())
+            (TokenKind::LeftBrace, ByteString::default())
         }
         [b'}', ..] => {
             state.bytes.next();
-            (TokenKind::RightBrace, b"}".into())
+            (TokenKind::RightBrace, ByteString::default())
         }
         [b'(', ..] => {
             state.bytes.next();
-            (TokenKind::LeftParen, b"(".into())
+            (TokenKind::LeftParen, ByteString::default())
         }
         [b')', ..] => {
             state.bytes.next();
-            (TokenKind::RightParen, b")".into())
+            (TokenKind::RightParen, ByteString::default())
         }
         [b';', ..] => {
             state.bytes.next();
-            (TokenKind::SemiColon, b";".into())
+            (TokenKind::SemiColon, ByteString::default())
         }
         [b'+', b'+', ..] => {
             state.bytes.skip(2);
-            (TokenKind::Increment, b"++".into())
+            (TokenKind::Increment, ByteString::default())
         }
         [b'+', b'=', ..] => {
             state.bytes.skip(2);
-            (TokenKind::PlusEquals, b"+=".into())
+            (TokenKind::PlusEquals, ByteString::default())
         }
         [b'+', ..] => {
             state.bytes.next();
-            (TokenKind::Plus, b"+".into())
+            (TokenKind::Plus, ByteString::default())
         }
         [b'%', b'=', ..] => {
             state.bytes.skip(2);
-            (TokenKind::PercentEquals, b"%=".into())
+            (TokenKind::PercentEquals, ByteString::default())
         }
         [b'%', ..] => {
             state.bytes.next();
-            (TokenKind::Percent, b"%".into())
+            (TokenKind::Percent, ByteString::default())
         }
         [b'-', b'-', ..] => {
             state.bytes.skip(2);
-            (TokenKind::Decrement, b"--".into())
+            (TokenKind::Decrement, ByteString::default())
         }
         [b'-', b'>', ..] => {
             state.bytes.skip(2);
-            (TokenKind::Arrow, b"->".into())
+            (TokenKind::Arrow, ByteString::default())
         }
         [b'-', b'=', ..] => {
             state.bytes.skip(2);
-            (TokenKind::MinusEquals, b"-=".into())
+            (TokenKind::MinusEquals, ByteString::default())
         }
         [b'-', ..] => {
             state.bytes.next();
-            (TokenKind::Minus, b"-".into())
+            (TokenKind::Minus, ByteString::default())
         }
         [b'<', b'<', ..] => {
             state.bytes.skip(2);
-            (TokenKind::LeftShift, b"<<".into())
+            (TokenKind::LeftShift, ByteString::default())
         }
         [b'<', b'=', ..] => {
             state.bytes.skip(2);
-            (TokenKind::LessThanEquals, b"<=".into())
+            (TokenKind::LessThanEquals, ByteString::default())
         }
         [b'<', ..] => {
             state.bytes.next();
-            (TokenKind::LessThan, b"<".into())
+            (TokenKind::LessThan, ByteString::default())
         }
         [b'>', b'>', ..] => {
             state.bytes.skip(2);
-            (TokenKind::RightShift, b">>".into())
+            (TokenKind::RightShift, ByteString::default())
         }
         [b'>', b'=', ..] => {
             state.bytes.skip(2);
-            (TokenKind::GreaterThanEquals, b">=".into())
+            (TokenKind::GreaterThanEquals, ByteString::default())
         }
         [b'>', ..] => {
             state.bytes.next();
-            (TokenKind::GreaterThan, b">".into())
+            (TokenKind::GreaterThan, ByteString::default())
         }
         [b',', ..] => {
             state.bytes.next();
-            (TokenKind::Comma, b",".into())
+            (TokenKind::Comma, ByteString::default())
         }
         [b'[', ..] => {
             state.bytes.next();
-            (TokenKind::LeftBracket, b"[".into())
+            (TokenKind::LeftBracket, ByteString::default())
         }
         [b']', ..] => {
             state.bytes.next();
-            (TokenKind::RightBracket, b"]".into())
+            (TokenKind::RightBracket, ByteString::default())
         }
         [b':', b':', ..] => {
             state.bytes.skip(2);
-            (TokenKind::DoubleColon, b"::".into())
+            (TokenKind::DoubleColon, ByteString::default())
         }
         [b':', ..] => {
             state.bytes.next();
-            (TokenKind::Colon, b":".into())
+            (TokenKind::Colon, ByteString::default())
         }
         [b'~', ..] => {
             state.bytes.next();
-            (TokenKind::BitwiseNot, b"~".into())
+            (TokenKind::BitwiseNot, ByteString::default())
         }
         [b'`', ..] => {
             state.bytes.next();
-            (TokenKind::Backtick, b"`".into())
+            (TokenKind::Backtick, ByteString::default())
         }
         [_, ..] => {
             let (qualified, buffer) = identifier::tokenize(state)?;
@@ -422,9 +424,17 @@ pub fn tokenize(state: &mut State) -> SyntaxResult<Token> {
         ),
     };
 
+    let end = state.bytes.position();
+    let value = match &state.buffer {
+        Some(buffer) if !value.is_empty() && value[..] == buffer[position..end] => {
+            ByteString::shared(buffer, position..end)
+        }
+        _ => value,
+    };
+
     Ok(Token {
         kind,
-        position,
+        position: position as u32,
         value,
     })
 }
@@ -4,7 +4,10 @@ use ara_reporting::issue::Issue;
 
 use crate::lexer::state::State;
 
-#[derive(Debug, Copy, Clone)]
+/// The code of an issue reported by the lexer, displayed as `L` followed by its number, e.g. `L0001`.
+///
+/// Codes are stable, the number of a code never changes, and new codes are only ever appended.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 #[repr(u8)]
 pub enum LexerIssueCode {
     /// An unreachable code was encountered.
@@ -45,6 +48,9 @@ pub enum LexerIssueCode {
 
     /// An unrecognizable token was encountered.
     UnrecognizableToken = 4,
+
+    /// The source is too large to be lexed, sources are limited to `u32::MAX` bytes.
+    SourceTooLarge = 5,
 }
 
 pub(crate) fn unreachable_code<M: Into<String>>(state: &State, message: M) -> Issue {
@@ -91,6 +97,45 @@ pub(crate) fn unrecognizable_token(state: &State) -> Issue {
     )
 }
 
+pub(crate) fn source_too_large(state: &State) -> Issue {
+    Issue::error(
+        LexerIssueCode::SourceTooLarge,
+        format!(
+            "Source is too large, sources are limited to {} bytes",
+            u32::MAX
+        ),
+    )
+    .with_source(
+        state.source.name(),
+        u32::MAX as usize,
+        state.source.content.len(),
+    )
+}
+
+impl LexerIssueCode {
+    /// All codes, in order of their numbers.
+    pub const ALL: [Self; 6] = [
+        Self::UnreachableCode,
+        Self::UnclosedStringLiteral,
+        Self::InvalidUnicodeEscapeSequence,
+        Self::InvalidOctalEscapeSequence,
+        Self::UnrecognizableToken,
+        Self::SourceTooLarge,
+    ];
+
+    /// The code displayed as the given string, e.g. `L0001`.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|candidate| candidate.to_string() == code)
+    }
+
+    /// The code of the given issue, if it was reported by the lexer.
+    pub fn of(issue: &Issue) -> Option<Self> {
+        issue.code.as_deref().and_then(Self::from_code)
+    }
+}
+
 impl ::std::fmt::Display for LexerIssueCode {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         write!(f, "L{:04}", *self as u8)
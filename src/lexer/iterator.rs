@@ -1,8 +1,6 @@
 use crate::lexer::token::Token;
 use crate::lexer::token::TokenKind;
 
-use crate::tree::comment::Comment;
-use crate::tree::comment::CommentFormat;
 use crate::tree::comment::CommentGroup;
 
 /// Token stream.
@@ -10,20 +8,20 @@ use crate::tree::comment::CommentGroup;
 pub struct TokenIterator<'a> {
     tokens: &'a [Token],
     length: usize,
-    comments: Vec<&'a Token>,
+    comments: CommentGroup,
     cursor: usize,
     collect_comments: bool,
 }
 
 /// Token stream.
 impl<'a> TokenIterator<'a> {
-    pub fn new(tokens: &'a [Token]) -> TokenIterator {
+    pub fn new(tokens: &'a [Token]) -> TokenIterator<'a> {
         let length = tokens.len();
 
         let mut stream = TokenIterator {
             tokens,
             length,
-            comments: vec![],
+            comments: CommentGroup::default(),
             cursor: 0,
             collect_comments: true,
         };
@@ -36,7 +34,7 @@ impl<'a> TokenIterator<'a> {
     /// Reset the cursor.
     pub fn reset(&mut self) {
         self.cursor = 0;
-        self.comments.clear();
+        self.comments = CommentGroup::default();
     }
 
     /// Reset the cursor, and disable comment collection.
@@ -69,11 +67,33 @@ impl<'a> TokenIterator<'a> {
         &self.tokens[position]
     }
 
-    /// Get previous token.
+    /// Get previous token, skipping comments if they are being collected.
     pub const fn previous(&self) -> &'a Token {
-        let position = if self.cursor == 0 { 0 } else { self.cursor - 1 };
+        let mut cursor = if self.cursor > self.length {
+            self.length
+        } else {
+            self.cursor
+        };
 
-        &self.tokens[position]
+        while cursor > 0 {
+            cursor -= 1;
+
+            let previous = &self.tokens[cursor];
+
+            if !self.collect_comments
+                || !matches!(
+                    previous.kind,
+                    TokenKind::SingleLineComment
+                        | TokenKind::MultiLineComment
+                        | TokenKind::HashMarkComment
+                        | TokenKind::DocumentComment
+                )
+            {
+                return previous;
+            }
+        }
+
+        &self.tokens[0]
     }
 
     /// lookahead to the n'th token.
@@ -122,54 +142,7 @@ impl<'a> TokenIterator<'a> {
     ///
     /// If comment collection is disabled, this will return an empty `CommentGroup`.
     pub fn comments(&mut self) -> CommentGroup {
-        let mut comments = vec![];
-
-        std::mem::swap(&mut self.comments, &mut comments);
-
-        CommentGroup {
-            comments: comments
-                .iter()
-                .map(|token| match token {
-                    Token {
-                        kind: TokenKind::SingleLineComment,
-                        position,
-                        value,
-                    } => Comment {
-                        position: *position,
-                        format: CommentFormat::SingleLine,
-                        content: value.clone(),
-                    },
-                    Token {
-                        kind: TokenKind::MultiLineComment,
-                        position,
-                        value,
-                    } => Comment {
-                        position: *position,
-                        format: CommentFormat::MultiLine,
-                        content: value.clone(),
-                    },
-                    Token {
-                        kind: TokenKind::HashMarkComment,
-                        position,
-                        value,
-                    } => Comment {
-                        position: *position,
-                        format: CommentFormat::HashMark,
-                        content: value.clone(),
-                    },
-                    Token {
-                        kind: TokenKind::DocumentComment,
-                        position,
-                        value,
-                    } => Comment {
-                        position: *position,
-                        format: CommentFormat::Document,
-                        content: value.clone(),
-                    },
-                    _ => unreachable!(),
-                })
-                .collect(),
-        }
+        std::mem::take(&mut self.comments)
     }
 
     fn collect_comments(&mut self) {
@@ -194,7 +167,11 @@ impl<'a> TokenIterator<'a> {
                 break;
             }
 
-            self.comments.push(current);
+            if self.comments.is_empty() {
+                self.comments.start = current.start();
+            }
+
+            self.comments.end = current.end();
             self.cursor += 1;
         }
     }
@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use ara_reporting::issue::Issue;
 use ara_source::source::Source;
 
@@ -17,8 +19,42 @@ pub(in crate::lexer) mod result;
 pub(in crate::lexer) mod state;
 
 pub fn lex(source: &Source) -> Result<Vec<Token>, Box<Issue>> {
-    let mut state = State::new(source);
     let mut tokens = Vec::new();
+    tokenize(State::new(source), &mut tokens)?;
+
+    Ok(tokens)
+}
+
+/// Lex the given source, with the values of the tokens sharing a single copy of the source
+/// buffer, rather than each holding a copy of their bytes, see [`ByteString::shared`].
+pub fn lex_shared(source: &Source) -> Result<Vec<Token>, Box<Issue>> {
+    let mut tokens = Vec::new();
+    lex_into(source, true, &mut tokens)?;
+
+    Ok(tokens)
+}
+
+/// Lex the given source into the given tokens, replacing any tokens it holds, while reusing its
+/// allocation.
+pub(crate) fn lex_into(
+    source: &Source,
+    shared: bool,
+    tokens: &mut Vec<Token>,
+) -> Result<(), Box<Issue>> {
+    let mut state = State::new(source);
+    if shared {
+        state.buffer = Some(Arc::from(source.content.as_bytes()));
+    }
+
+    tokens.clear();
+    tokenize(state, tokens)
+}
+
+fn tokenize(mut state: State, tokens: &mut Vec<Token>) -> Result<(), Box<Issue>> {
+    let source = state.source;
+    if u32::try_from(source.content.len()).is_err() {
+        crate::lexer_bail!(&state, source_too_large);
+    }
 
     while !state.bytes.eof() {
         while let Some(true) = state.bytes.current().map(|u: &u8| u.is_ascii_whitespace()) {
@@ -35,9 +71,9 @@ pub fn lex(source: &Source) -> Result<Vec<Token>, Box<Issue>> {
 
     tokens.push(Token {
         kind: TokenKind::Eof,
-        position: state.bytes.position(),
+        position: state.bytes.position() as u32,
         value: ByteString::default(),
     });
 
-    Ok(tokens)
+    Ok(())
 }
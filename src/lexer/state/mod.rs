@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use ara_source::source::Source;
 
 use crate::lexer::state::source_bytes::SourceBytes;
@@ -8,6 +10,8 @@ pub mod source_bytes;
 pub struct State<'a> {
     pub source: &'a Source,
     pub bytes: SourceBytes<'a>,
+    /// The buffer shared by the values of the tokens, if they share the source buffer.
+    pub buffer: Option<Arc<[u8]>>,
 }
 
 impl<'a> State<'a> {
@@ -15,6 +19,7 @@ impl<'a> State<'a> {
         Self {
             source,
             bytes: SourceBytes::new(source.content.as_bytes()),
+            buffer: None,
         }
     }
 }
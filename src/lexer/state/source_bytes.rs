@@ -7,7 +7,6 @@ pub struct SourceBytes<'a> {
 
 impl<'a> SourceBytes<'a> {
     pub fn new(input: &'a [u8]) -> Self {
-        let input = input;
         let length = input.len();
 
         Self {
@@ -168,6 +168,8 @@ pub enum TokenKind {
     While,
     BitwiseNot,
     Where,
+    Permits,
+    Pragma,
     Async,
     Await,
     Concurrently,
@@ -203,14 +205,117 @@ pub enum TokenKind {
     TraitConstant,
 }
 
+impl TokenKind {
+    /// The text of every token of this kind, if all tokens of this kind have the same text.
+    ///
+    /// Keywords are not included, as keywords are case-insensitive.
+    pub fn text(&self) -> Option<&'static str> {
+        let text = match self {
+            Self::Ampersand => "&",
+            Self::AmpersandEquals => "&=",
+            Self::Arrow => "->",
+            Self::Asterisk => "*",
+            Self::AsteriskEquals => "*=",
+            Self::At => "@",
+            Self::Attribute => "#[",
+            Self::Backtick => "`",
+            Self::Bang => "!",
+            Self::BangDoubleEquals => "!==",
+            Self::BangEquals => "!=",
+            Self::BitwiseNot => "~",
+            Self::BooleanAnd => "&&",
+            Self::BooleanOr => "||",
+            Self::Caret => "^",
+            Self::CaretEquals => "^=",
+            Self::CloseTag => "?>",
+            Self::Colon => ":",
+            Self::Comma => ",",
+            Self::Decrement => "--",
+            Self::Dollar => "$",
+            Self::Dot => ".",
+            Self::DotEquals => ".=",
+            Self::DoubleArrow => "=>",
+            Self::DoubleColon => "::",
+            Self::DoubleDot => "..",
+            Self::DoubleEquals => "==",
+            Self::DoubleQuestion => "??",
+            Self::DoubleQuestionEquals => "??=",
+            Self::Ellipsis => "...",
+            Self::Equals => "=",
+            Self::Generic => "::<",
+            Self::GreaterThan => ">",
+            Self::GreaterThanEquals => ">=",
+            Self::Increment => "++",
+            Self::LeftBrace => "{",
+            Self::LeftBracket => "[",
+            Self::LeftParen => "(",
+            Self::LeftShift => "<<",
+            Self::LeftShiftEquals => "<<=",
+            Self::LessThan => "<",
+            Self::LessThanEquals => "<=",
+            Self::Minus => "-",
+            Self::MinusEquals => "-=",
+            Self::NamespaceSeparator => "\\",
+            Self::OpenTag(OpenTagKind::Echo) => "<?=",
+            Self::OpenTag(OpenTagKind::Short) => "<?",
+            Self::Percent => "%",
+            Self::PercentEquals => "%=",
+            Self::Pipe => "|",
+            Self::PipeEquals => "|=",
+            Self::Plus => "+",
+            Self::PlusEquals => "+=",
+            Self::Pow => "**",
+            Self::PowEquals => "**=",
+            Self::Question => "?",
+            Self::QuestionArrow => "?->",
+            Self::QuestionColon => "?:",
+            Self::RightBrace => "}",
+            Self::RightBracket => "]",
+            Self::RightParen => ")",
+            Self::RightShift => ">>",
+            Self::RightShiftEquals => ">>=",
+            Self::SemiColon => ";",
+            Self::Slash => "/",
+            Self::SlashEquals => "/=",
+            Self::Spaceship => "<=>",
+            Self::TripleEquals => "===",
+            _ => return None,
+        };
+
+        Some(text)
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub struct Token {
     pub kind: TokenKind,
-    pub position: usize,
+    /// The offset of the token in the source, sources are limited to `u32::MAX` bytes.
+    pub position: u32,
+    /// The value of the token, empty for tokens of a kind with a fixed text, see [`Token::text`].
     pub value: ByteString,
 }
 
+impl Token {
+    /// The offset of the start of the token in the source.
+    pub fn start(&self) -> usize {
+        self.position as usize
+    }
+
+    /// The offset right after the end of the token in the source.
+    pub fn end(&self) -> usize {
+        self.start() + self.text().len()
+    }
+
+    /// The text of the token, as it occurs in the source.
+    pub fn text(&self) -> &[u8] {
+        match self.kind.text() {
+            Some(text) => text.as_bytes(),
+            None => &self.value,
+        }
+    }
+}
+
 impl Default for Token {
     fn default() -> Self {
         Self {
@@ -223,7 +328,10 @@ impl Default for Token {
 
 impl Display for Token {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.value)
+        match self.kind.text() {
+            Some(text) => write!(f, "{text}"),
+            None => write!(f, "{}", self.value),
+        }
     }
 }
 
@@ -382,6 +490,8 @@ impl Display for TokenKind {
                 return write!(f, "{self:?}");
             }
             Self::Where => "where",
+            Self::Permits => "permits",
+            Self::Pragma => "pragma",
             Self::Async => "async",
             Self::Await => "await",
             Self::In => "in",
@@ -425,3 +535,30 @@ impl Display for TokenKind {
         write!(f, "{s}")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexer;
+    use ara_source::source::Source;
+    use ara_source::source::SourceKind;
+
+    #[test]
+    fn test_token_text() {
+        let code = "$a ??= FOO::bar(1);";
+        let tokens = lexer::lex(&Source::inline(SourceKind::Script, code)).unwrap();
+
+        for token in &tokens {
+            assert_eq!(token.text(), &code.as_bytes()[token.start()..token.end()]);
+            assert_eq!(token.to_string().as_bytes(), token.text());
+            if token.kind.text().is_some() {
+                assert!(token.value.is_empty());
+            }
+        }
+
+        assert_eq!(tokens[1].kind, TokenKind::DoubleQuestionEquals);
+        assert_eq!(tokens[1].text(), b"??=");
+        assert_eq!(tokens[3].kind.text(), Some("::"));
+        assert_eq!(tokens[4].kind.text(), None);
+    }
+}
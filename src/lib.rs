@@ -1,4 +1,22 @@
+#[cfg(any(feature = "wasm", feature = "ffi"))]
+mod bindings;
+pub mod cache;
+#[cfg(any(feature = "jsonl", feature = "msgpack"))]
+pub mod export;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "arbitrary")]
+pub mod fuzz;
+pub mod highlight;
+pub mod import;
 pub mod lexer;
+#[cfg(feature = "lsp")]
+pub mod lsp;
 pub mod parser;
+pub mod printer;
+pub mod quote;
+pub mod transpiler;
 pub mod traverser;
 pub mod tree;
+#[cfg(feature = "wasm")]
+pub mod wasm;
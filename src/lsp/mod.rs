@@ -0,0 +1,301 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use ara_reporting::annotation::AnnotationType;
+use ara_reporting::issue::Issue;
+use ara_reporting::issue::IssueSeverity;
+use ara_reporting::Report;
+use ara_source::SourceMap;
+use lsp_types::CodeAction;
+use lsp_types::CodeActionKind;
+use lsp_types::Diagnostic;
+use lsp_types::DiagnosticRelatedInformation;
+use lsp_types::DiagnosticSeverity;
+use lsp_types::DocumentSymbol;
+use lsp_types::Location;
+use lsp_types::NumberOrString;
+use lsp_types::Position;
+use lsp_types::Range;
+use lsp_types::SymbolKind;
+use lsp_types::TextEdit;
+use lsp_types::Url;
+use lsp_types::WorkspaceEdit;
+
+use crate::parser::issue::fixes;
+use crate::tree::outline::OutlineItem;
+use crate::tree::outline::OutlineKind;
+
+/// The name of the source of the diagnostics.
+const SOURCE: &str = "ara";
+
+/// Convert the given issue into a diagnostic, `None` if the issue has no source, or if its
+/// source is not part of the given map.
+///
+/// The notes of the issue are appended to the message, and secondary annotations become related
+/// information, as long as the name of their source is a URL, or an absolute path, see `url`.
+pub fn diagnostic(map: &SourceMap, issue: &Issue) -> Option<Diagnostic> {
+    let (name, from, to) = issue.source.as_ref()?;
+    let source = map.named(name).ok()?;
+
+    let mut message = issue.message.clone();
+    for note in &issue.notes {
+        message.push_str("\n\nnote: ");
+        message.push_str(note);
+    }
+
+    let related = issue
+        .annotations
+        .iter()
+        .filter(|annotation| annotation.r#type == AnnotationType::Secondary)
+        .filter_map(|annotation| {
+            let source = map.named(&annotation.origin).ok()?;
+
+            Some(DiagnosticRelatedInformation {
+                location: Location {
+                    uri: url(&annotation.origin)?,
+                    range: range(&source.content, annotation.from, annotation.to),
+                },
+                message: annotation
+                    .message
+                    .clone()
+                    .unwrap_or_else(|| issue.message.clone()),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    Some(Diagnostic {
+        range: range(&source.content, *from, *to),
+        severity: Some(severity(issue.severity)),
+        code: issue.code.clone().map(NumberOrString::String),
+        source: Some(SOURCE.to_string()),
+        message,
+        related_information: if related.is_empty() {
+            None
+        } else {
+            Some(related)
+        },
+        ..Diagnostic::default()
+    })
+}
+
+/// Convert the issues of the given report into diagnostics, grouped by the name of their source.
+///
+/// Issues without a source are omitted, see `diagnostic`.
+pub fn diagnostics(map: &SourceMap, report: &Report) -> BTreeMap<String, Vec<Diagnostic>> {
+    let mut diagnostics: BTreeMap<String, Vec<Diagnostic>> = BTreeMap::new();
+    for issue in &report.issues {
+        if let (Some((name, ..)), Some(diagnostic)) = (&issue.source, diagnostic(map, issue)) {
+            diagnostics
+                .entry(name.clone())
+                .or_default()
+                .push(diagnostic);
+        }
+    }
+
+    diagnostics
+}
+
+/// Convert the fix of the given issue into a quick fix code action, `None` if the issue has no
+/// fix, see `crate::parser::issue::fixes`, or if the name of its source is not a URL, nor an
+/// absolute path.
+pub fn code_action(map: &SourceMap, issue: &Issue) -> Option<CodeAction> {
+    let edits = fixes(issue);
+    if edits.is_empty() {
+        return None;
+    }
+
+    let (name, ..) = issue.source.as_ref()?;
+    let source = map.named(name).ok()?;
+    let edits = edits
+        .into_iter()
+        .map(|edit| TextEdit {
+            range: range(&source.content, edit.start, edit.end),
+            new_text: edit.replacement,
+        })
+        .collect();
+
+    Some(CodeAction {
+        title: format!("fix: {}", issue.message),
+        kind: Some(CodeActionKind::QUICKFIX),
+        diagnostics: diagnostic(map, issue).map(|diagnostic| vec![diagnostic]),
+        edit: Some(WorkspaceEdit {
+            changes: Some([(url(name)?, edits)].into_iter().collect()),
+            ..WorkspaceEdit::default()
+        }),
+        is_preferred: Some(true),
+        ..CodeAction::default()
+    })
+}
+
+/// Convert the given outline items, see `crate::tree::outline::outline`, into document symbols,
+/// positioned within the given content.
+pub fn document_symbols(content: &str, items: &[OutlineItem]) -> Vec<DocumentSymbol> {
+    items
+        .iter()
+        .map(|item| {
+            #[allow(deprecated)]
+            DocumentSymbol {
+                name: item.name.clone(),
+                detail: None,
+                kind: match item.kind {
+                    OutlineKind::Namespace => SymbolKind::NAMESPACE,
+                    OutlineKind::Class => SymbolKind::CLASS,
+                    OutlineKind::Interface => SymbolKind::INTERFACE,
+                    OutlineKind::Enum => SymbolKind::ENUM,
+                    OutlineKind::Function => SymbolKind::FUNCTION,
+                    OutlineKind::Constant | OutlineKind::ClassConstant => SymbolKind::CONSTANT,
+                    OutlineKind::TypeAlias => SymbolKind::TYPE_PARAMETER,
+                    OutlineKind::Method => SymbolKind::METHOD,
+                    OutlineKind::Property => SymbolKind::PROPERTY,
+                    OutlineKind::Case => SymbolKind::ENUM_MEMBER,
+                },
+                tags: None,
+                deprecated: None,
+                range: range(content, item.span.start, item.span.end),
+                selection_range: range(content, item.name_span.start, item.name_span.end),
+                children: if item.children.is_empty() {
+                    None
+                } else {
+                    Some(document_symbols(content, &item.children))
+                },
+            }
+        })
+        .collect()
+}
+
+/// The severity of a diagnostic reporting an issue of the given severity.
+pub fn severity(severity: IssueSeverity) -> DiagnosticSeverity {
+    match severity {
+        IssueSeverity::Bug | IssueSeverity::Error => DiagnosticSeverity::ERROR,
+        IssueSeverity::Warning => DiagnosticSeverity::WARNING,
+        IssueSeverity::Note => DiagnosticSeverity::INFORMATION,
+        IssueSeverity::Help => DiagnosticSeverity::HINT,
+    }
+}
+
+/// The position of the given byte offset in the given content, offsets past the end of the
+/// content are positioned at its end.
+///
+/// Both the line and the character start at 0, and the character counts UTF-16 code units, as
+/// required by the protocol.
+pub fn position(content: &str, offset: usize) -> Position {
+    let mut offset = offset.min(content.len());
+    while !content.is_char_boundary(offset) {
+        offset -= 1;
+    }
+
+    let prefix = &content[..offset];
+    let line_start = prefix.rfind('\n').map(|index| index + 1).unwrap_or(0);
+
+    Position {
+        line: prefix.matches('\n').count() as u32,
+        character: prefix[line_start..].encode_utf16().count() as u32,
+    }
+}
+
+/// The range of the given byte offsets in the given content, see `position`.
+pub fn range(content: &str, from: usize, to: usize) -> Range {
+    Range {
+        start: position(content, from),
+        end: position(content, to),
+    }
+}
+
+/// The URL of the source with the given name, `None` unless the name is either a URL, or an
+/// absolute path.
+pub fn url(name: &str) -> Option<Url> {
+    if Path::new(name).is_absolute() {
+        return Url::from_file_path(name).ok();
+    }
+
+    Url::parse(name).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ara_source::source::Source;
+    use ara_source::source::SourceKind;
+
+    use crate::parser;
+    use crate::parser::issue::ParserIssueCode;
+
+    #[test]
+    fn test_position() {
+        let content = "foo\n𝕒bc $d\n";
+
+        assert_eq!(position(content, 0), Position::new(0, 0));
+        assert_eq!(position(content, 4), Position::new(1, 0));
+        assert_eq!(position(content, 8), Position::new(1, 2));
+        assert_eq!(position(content, 6), Position::new(1, 0));
+        assert_eq!(position(content, 100), Position::new(2, 0));
+    }
+
+    #[test]
+    fn test_diagnostics() {
+        let map = SourceMap::new(vec![Source::new(
+            SourceKind::Script,
+            "/project/src/foo.ara",
+            "function foo(): void {\n    $a = 1\n    $b = 2;\n}\n",
+        )]);
+
+        let report = parser::parse(&map.sources[0]).unwrap_err();
+        let diagnostics = diagnostics(&map, &report);
+        let diagnostic = &diagnostics["/project/src/foo.ara"][0];
+
+        assert_eq!(diagnostic.range.start, Position::new(1, 9));
+        assert_eq!(diagnostic.severity, Some(DiagnosticSeverity::ERROR));
+        assert_eq!(
+            diagnostic.code,
+            Some(NumberOrString::String(
+                ParserIssueCode::MissingSemicolon.to_string()
+            ))
+        );
+        assert_eq!(diagnostic.source.as_deref(), Some("ara"));
+
+        assert_eq!(
+            url("/project/src/foo.ara").unwrap().as_str(),
+            "file:///project/src/foo.ara"
+        );
+        assert_eq!(url("foo.ara"), None);
+    }
+
+    #[test]
+    fn test_code_action() {
+        let map = SourceMap::new(vec![Source::new(
+            SourceKind::Script,
+            "/project/src/foo.ara",
+            "function foo(): void {\n    $a = 1\n    $b = 2;\n}\n",
+        )]);
+
+        let report = parser::parse(&map.sources[0]).unwrap_err();
+        let action = code_action(&map, &report.issues[0]).unwrap();
+        let changes = action.edit.unwrap().changes.unwrap();
+
+        assert_eq!(action.kind, Some(CodeActionKind::QUICKFIX));
+        assert_eq!(
+            changes[&url("/project/src/foo.ara").unwrap()],
+            vec![TextEdit {
+                range: Range::new(Position::new(1, 10), Position::new(1, 10)),
+                new_text: ";".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_document_symbols() {
+        let code = "final class Foo {\n    public function bar(): void {}\n}\n";
+        let tree = parser::parse(&Source::inline(SourceKind::Script, code)).unwrap();
+
+        let symbols = document_symbols(code, &crate::tree::outline::outline(&tree));
+        let method = &symbols[0].children.as_ref().unwrap()[0];
+
+        assert_eq!(symbols[0].kind, SymbolKind::CLASS);
+        assert_eq!(method.name, "bar");
+        assert_eq!(method.kind, SymbolKind::METHOD);
+        assert_eq!(
+            method.selection_range,
+            Range::new(Position::new(1, 20), Position::new(1, 23))
+        );
+    }
+}
@@ -0,0 +1,117 @@
+use std::fmt::Display;
+
+/// An edit of the content of a source, replacing the bytes within `start..end` with `replacement`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Edit {
+    pub start: usize,
+    pub end: usize,
+    pub replacement: String,
+}
+
+impl Edit {
+    pub fn new<S: Into<String>>(start: usize, end: usize, replacement: S) -> Self {
+        Self {
+            start,
+            end,
+            replacement: replacement.into(),
+        }
+    }
+
+    /// The content resulting from applying the edit to the given content.
+    ///
+    /// Fails if the range of the edit is out of bounds, or does not lie on character boundaries.
+    pub fn apply(&self, content: &str) -> Result<String, EditError> {
+        self.validate(content)?;
+
+        let mut content = content.to_string();
+        content.replace_range(self.start..self.end, &self.replacement);
+
+        Ok(content)
+    }
+
+    /// Check that the edit can be applied to the given content, see `apply`.
+    pub fn validate(&self, content: &str) -> Result<(), EditError> {
+        if self.start > self.end || self.end > content.len() {
+            return Err(EditError::OutOfBounds {
+                start: self.start,
+                end: self.end,
+                length: content.len(),
+            });
+        }
+
+        match [self.start, self.end]
+            .into_iter()
+            .find(|position| !content.is_char_boundary(*position))
+        {
+            Some(position) => Err(EditError::NotCharBoundary { position }),
+            None => Ok(()),
+        }
+    }
+
+    /// The number of bytes by which the edit moves the content following it.
+    pub fn offset(&self) -> isize {
+        self.replacement.len() as isize - (self.end - self.start) as isize
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum EditError {
+    /// The range of the edit is reversed, or ends past the end of the content.
+    OutOfBounds {
+        start: usize,
+        end: usize,
+        length: usize,
+    },
+    /// A bound of the range of the edit lies within a character.
+    NotCharBoundary { position: usize },
+}
+
+impl Display for EditError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::OutOfBounds { start, end, length } => write!(
+                f,
+                "the edit of {start}..{end} is out of the bounds of the content of length {length}"
+            ),
+            Self::NotCharBoundary { position } => write!(
+                f,
+                "the edit is not on a character boundary at position {position}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for EditError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply() {
+        assert_eq!(Edit::new(1, 2, "xy").apply("abc").unwrap(), "axyc");
+        assert_eq!(Edit::new(3, 3, "d").apply("abc").unwrap(), "abcd");
+
+        assert_eq!(
+            Edit::new(2, 4, "").apply("abc"),
+            Err(EditError::OutOfBounds {
+                start: 2,
+                end: 4,
+                length: 3
+            })
+        );
+        assert_eq!(
+            Edit::new(2, 1, "").apply("abc"),
+            Err(EditError::OutOfBounds {
+                start: 2,
+                end: 1,
+                length: 3
+            })
+        );
+        assert_eq!(Edit::new(0, 2, "").apply("éa"), Ok("a".to_string()));
+        assert_eq!(
+            Edit::new(1, 2, "").apply("éa"),
+            Err(EditError::NotCharBoundary { position: 1 })
+        );
+    }
+}
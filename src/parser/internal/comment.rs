@@ -0,0 +1,143 @@
+use ara_source::source::Source;
+
+use crate::lexer::token::Token;
+use crate::lexer::token::TokenKind;
+use crate::tree::comment::Comment;
+use crate::tree::comment::CommentAttachments;
+use crate::tree::comment::CommentFormat;
+use crate::tree::comment::CommentGroup;
+
+/// Attach the comments of the given source to the tokens surrounding them.
+///
+/// See `CommentAttachments` for the attachment policy.
+pub fn attachments(source: &Source, tokens: &[Token]) -> CommentAttachments {
+    attachments_from(source, tokens, 0, None)
+}
+
+/// Attach the comments among the given tokens, which start at the given offset of the source,
+/// right after the token ending at `trailed`, if any.
+pub fn attachments_from(
+    source: &Source,
+    tokens: &[Token],
+    offset: usize,
+    trailed: Option<usize>,
+) -> CommentAttachments {
+    let content = source.content.as_bytes();
+
+    let mut attachments = CommentAttachments::default();
+
+    // the end of the last token that is not a comment, as long as no line break followed it.
+    let mut trailed = trailed;
+    let mut leading = CommentGroup::default();
+    let mut offset = offset;
+    for token in tokens {
+        if content[offset..token.start()].contains(&b'\n') {
+            trailed = None;
+        }
+
+        offset = token.end();
+
+        let format = match token.kind {
+            TokenKind::SingleLineComment => CommentFormat::SingleLine,
+            TokenKind::MultiLineComment => CommentFormat::MultiLine,
+            TokenKind::HashMarkComment => CommentFormat::HashMark,
+            TokenKind::DocumentComment => CommentFormat::Document,
+            _ => {
+                if !leading.is_empty() {
+                    attachments
+                        .leading
+                        .push((token.start(), std::mem::take(&mut leading)));
+                }
+
+                trailed = Some(offset);
+
+                continue;
+            }
+        };
+
+        attachments.comments.push(Comment {
+            position: token.start(),
+            format,
+            content: token.value.clone(),
+        });
+
+        let group = match trailed {
+            Some(end) => match attachments.trailing.last_mut() {
+                Some((key, group)) if *key == end => group,
+                _ => {
+                    attachments.trailing.push((end, CommentGroup::default()));
+
+                    &mut attachments.trailing.last_mut().unwrap().1
+                }
+            },
+            None => &mut leading,
+        };
+
+        if group.is_empty() {
+            group.start = token.start();
+        }
+
+        group.end = offset;
+    }
+
+    attachments
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser;
+    use crate::tree::Node;
+    use ara_source::source::SourceKind;
+
+    #[test]
+    fn test_comment_attachments() {
+        let code = "// foo\nfunction foo(): void { // bar\n    /* baz */ baz(); /* qux */ # quux\n}\n// end\n";
+        let source = Source::inline(SourceKind::Script, code);
+
+        let tree = parser::parse(&source).unwrap();
+        let contents = |comments: &[Comment]| {
+            comments
+                .iter()
+                .map(|comment| comment.content.to_string())
+                .collect::<Vec<String>>()
+        };
+
+        let function = &tree.definitions.definitions[0];
+        assert_eq!(contents(function.leading_comments(&tree)), vec!["// foo"]);
+        assert!(function.trailing_comments(&tree).is_empty());
+
+        assert_eq!(
+            contents(tree.comments.trailing_at(code.find('{').unwrap() + 1)),
+            vec!["// bar"]
+        );
+
+        let call = tree
+            .definitions
+            .descendants()
+            .find(|node| node.get_description() == "expression statement")
+            .unwrap();
+
+        assert_eq!(contents(call.leading_comments(&tree)), vec!["/* baz */"]);
+        assert_eq!(
+            contents(call.trailing_comments(&tree)),
+            vec!["/* qux */", "# quux"]
+        );
+
+        assert_eq!(
+            contents(tree.comments.leading_at(tree.definitions.eof)),
+            vec!["// end"]
+        );
+
+        let groups = tree
+            .definitions
+            .descendants()
+            .filter_map(|node| node.comments())
+            .filter(|group| !group.is_empty())
+            .map(|group| contents(tree.comments.group(group)))
+            .collect::<Vec<Vec<String>>>();
+
+        assert_eq!(tree.comments.comments.len(), 6);
+        assert_eq!(groups, vec![vec!["// foo"], vec!["// bar", "/* baz */"]]);
+    }
+}
@@ -6,6 +6,8 @@ use crate::parser::result::ParseResult;
 use crate::parser::state::State;
 use crate::tree::definition::attribute::AttributeDefinition;
 use crate::tree::definition::attribute::AttributeGroupDefinition;
+use crate::tree::expression::argument::ArgumentExpression;
+use crate::tree::expression::argument::ArgumentListExpression;
 
 pub fn gather(state: &mut State) -> ParseResult<bool> {
     if state.iterator.current().kind != TokenKind::Attribute {
@@ -20,7 +22,11 @@ pub fn gather(state: &mut State) -> ParseResult<bool> {
                 Ok(AttributeDefinition {
                     name: identifier::fully_qualified_type_identifier_including_self(state)?,
                     arguments: if state.iterator.current().kind == TokenKind::LeftParen {
-                        Some(argument::argument_list_expression(state)?)
+                        let arguments = argument::argument_list_expression(state)?;
+
+                        validate_arguments(state, &arguments);
+
+                        Some(arguments)
                     } else {
                         None
                     },
@@ -36,3 +42,20 @@ pub fn gather(state: &mut State) -> ParseResult<bool> {
     // recursive, looking for multiple attribute brackets after each other.
     gather(state).map(|_| true)
 }
+
+/// Report the arguments of an attribute that are not constant, as attributes are evaluated
+/// without runtime context.
+fn validate_arguments(state: &mut State, arguments: &ArgumentListExpression) {
+    for argument in &arguments.arguments.inner {
+        let value = match argument {
+            ArgumentExpression::Value { value, .. }
+            | ArgumentExpression::Spread { value, .. }
+            | ArgumentExpression::ReverseSpread { value, .. }
+            | ArgumentExpression::Named { value, .. } => value,
+        };
+
+        if !value.is_constant(true) {
+            crate::parser_report!(state, attribute_argument_must_be_constant(argument));
+        }
+    }
+}
@@ -14,6 +14,10 @@ use crate::tree::definition::class::ClassDefinitionBody;
 use crate::tree::definition::class::ClassDefinitionExtends;
 use crate::tree::definition::class::ClassDefinitionImplements;
 use crate::tree::definition::class::ClassDefinitionMember;
+use crate::tree::definition::class::ClassDefinitionPermits;
+use crate::tree::identifier::Identifier;
+use crate::tree::token::Keyword;
+use crate::tree::utils::CommaSeparated;
 
 pub fn class_definition(state: &mut State) -> ParseResult<ClassDefinition> {
     let attributes = state.get_attributes();
@@ -36,6 +40,7 @@ pub fn class_definition(state: &mut State) -> ParseResult<ClassDefinition> {
         modifiers,
         extends: class_definition_extends(state)?,
         implements: class_definition_implements(state)?,
+        permits: class_definition_permits(state)?,
         attributes,
         body: class_definition_body(state)?,
     })
@@ -58,8 +63,7 @@ pub fn class_definition_member(state: &mut State) -> ParseResult<ClassDefinition
 }
 
 pub fn class_definition_extends(state: &mut State) -> ParseResult<Option<ClassDefinitionExtends>> {
-    let current = state.iterator.current();
-    if current.kind == TokenKind::Extends {
+    if utils::at(state, TokenKind::Extends) {
         let extends = utils::skip_keyword(state, TokenKind::Extends)?;
         let parent = identifier::fully_qualified_templated_identifier(state)?;
 
@@ -72,8 +76,7 @@ pub fn class_definition_extends(state: &mut State) -> ParseResult<Option<ClassDe
 pub fn class_definition_implements(
     state: &mut State,
 ) -> ParseResult<Option<ClassDefinitionImplements>> {
-    let current = state.iterator.current();
-    if current.kind == TokenKind::Implements {
+    if utils::at(state, TokenKind::Implements) {
         let implements = utils::skip_keyword(state, TokenKind::Implements)?;
         let interfaces = utils::at_least_one_comma_separated(
             state,
@@ -90,6 +93,25 @@ pub fn class_definition_implements(
     }
 }
 
+pub fn class_definition_permits(state: &mut State) -> ParseResult<Option<ClassDefinitionPermits>> {
+    Ok(permits(state)?.map(|(permits, permitted)| ClassDefinitionPermits { permits, permitted }))
+}
+
+pub fn permits(state: &mut State) -> ParseResult<Option<(Keyword, CommaSeparated<Identifier>)>> {
+    if utils::at(state, TokenKind::Permits) {
+        let permits = utils::skip_keyword(state, TokenKind::Permits)?;
+        let permitted = utils::at_least_one_comma_separated(
+            state,
+            &identifier::fully_qualified_type_identifier,
+            TokenKind::LeftBrace,
+        )?;
+
+        Ok(Some((permits, permitted)))
+    } else {
+        Ok(None)
+    }
+}
+
 pub fn class_definition_body(state: &mut State) -> ParseResult<ClassDefinitionBody> {
     Ok(ClassDefinitionBody {
         left_brace: utils::skip_left_brace(state)?,
@@ -16,7 +16,7 @@ pub fn constant_definition(state: &mut State) -> ParseResult<ConstantDefinition>
         type_definition: r#type::type_definition(state)?,
         name: identifier::constant_identifier(state)?,
         equals: utils::skip(state, TokenKind::Equals)?,
-        value: expression::create(state)?,
+        value: expression::constant(state)?,
         semicolon: utils::skip_semicolon(state)?,
     })
 }
@@ -33,7 +33,7 @@ pub fn classish_constant_definition(
         type_definition: r#type::type_definition(state)?,
         name: identifier::constant_identifier(state)?,
         equals: utils::skip(state, TokenKind::Equals)?,
-        value: expression::create(state)?,
+        value: expression::constant(state)?,
         semicolon: utils::skip_semicolon(state)?,
     })
 }
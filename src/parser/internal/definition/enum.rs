@@ -46,8 +46,7 @@ pub fn enum_definition(state: &mut State) -> ParseResult<EnumDefinition> {
             None
         };
 
-    let current = state.iterator.current();
-    let implements = if current.kind == TokenKind::Implements {
+    let implements = if utils::at(state, TokenKind::Implements) {
         let implements = utils::skip_keyword(state, TokenKind::Implements)?;
         let interfaces = utils::at_least_one_comma_separated(
             state,
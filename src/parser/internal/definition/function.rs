@@ -1,3 +1,5 @@
+use ara_source::source::SourceKind;
+
 use crate::lexer::token::TokenKind;
 use crate::parser::internal::definition::modifier;
 use crate::parser::internal::definition::parameter;
@@ -8,6 +10,7 @@ use crate::parser::internal::statement::block;
 use crate::parser::internal::utils;
 use crate::parser::result::ParseResult;
 use crate::parser::state::State;
+use crate::tree::definition::function::FunctionBodyDefinition;
 use crate::tree::definition::function::FunctionDefinition;
 use crate::tree::definition::function::FunctionLikeReturnTypeDefinition;
 use crate::tree::definition::function::MethodBodyDefinition;
@@ -15,13 +18,14 @@ use crate::tree::definition::function::MethodDefinition;
 use crate::tree::definition::function::MethodTypeConstraintDefinition;
 use crate::tree::definition::function::MethodTypeConstraintGroupDefinition;
 use crate::tree::definition::modifier::ModifierGroupDefinition;
+use crate::tree::token::Keyword;
 
 pub fn function_definition(state: &mut State) -> ParseResult<FunctionDefinition> {
-    Ok(FunctionDefinition {
+    let function = FunctionDefinition {
         comments: state.iterator.comments(),
         attributes: state.get_attributes(),
         modifiers: modifier::collect(state)?,
-        function: utils::skip_keyword(state, TokenKind::Function)?,
+        function: function_keyword(state, TokenKind::Function)?,
         name: identifier::identifier_maybe_soft_reserved(state)?,
         templates: if state.iterator.current().kind == TokenKind::LessThan {
             Some(template::template_group_definition(state)?)
@@ -33,8 +37,24 @@ pub fn function_definition(state: &mut State) -> ParseResult<FunctionDefinition>
             colon: utils::skip_colon(state)?,
             type_definition: r#type::type_definition(state)?,
         },
-        body: block::block_statement(state)?,
-    })
+        constraints: method_type_constraint_group_definition(state, TokenKind::LeftBrace)?,
+        body: if state.iterator.current().kind == TokenKind::SemiColon {
+            FunctionBodyDefinition::Declaration(utils::skip_semicolon(state)?)
+        } else {
+            FunctionBodyDefinition::Concrete(block::block_statement(state)?)
+        },
+    };
+
+    if matches!(function.body, FunctionBodyDefinition::Declaration(_))
+        && state.kind() != SourceKind::Definition
+    {
+        crate::parser_report!(
+            state,
+            function_without_body_can_only_be_used_in_definition_source(&function)
+        );
+    }
+
+    Ok(function)
 }
 
 pub fn method_definition(
@@ -45,7 +65,7 @@ pub fn method_definition(
         comments: state.iterator.comments(),
         attributes: state.get_attributes(),
         modifiers,
-        function: utils::skip_keyword(state, TokenKind::Function)?,
+        function: function_keyword(state, TokenKind::Function)?,
         name: identifier::identifier_maybe_reserved(state)?,
         templates: if state.iterator.current().kind == TokenKind::LessThan {
             Some(template::template_group_definition(state)?)
@@ -61,26 +81,7 @@ pub fn method_definition(
         } else {
             None
         },
-        constraints: if state.iterator.current().kind == TokenKind::Where {
-            Some(MethodTypeConstraintGroupDefinition {
-                comments: state.iterator.comments(),
-                r#where: utils::skip_keyword(state, TokenKind::Where)?,
-                constraints: utils::comma_separated(
-                    state,
-                    &|state| {
-                        Ok(MethodTypeConstraintDefinition {
-                            comments: state.iterator.comments(),
-                            identifier: identifier::identifier_maybe_reserved(state)?,
-                            r#is: utils::skip_keyword(state, TokenKind::Is)?,
-                            type_definition: r#type::type_definition(state)?,
-                        })
-                    },
-                    TokenKind::LeftBrace,
-                )?,
-            })
-        } else {
-            None
-        },
+        constraints: method_type_constraint_group_definition(state, TokenKind::LeftBrace)?,
         body: if state.iterator.current().kind == TokenKind::SemiColon {
             MethodBodyDefinition::Abstract(utils::skip_semicolon(state)?)
         } else {
@@ -88,3 +89,44 @@ pub fn method_definition(
         },
     })
 }
+
+/// Skip the `function`, or `fn`, keyword, reporting a by-reference `&` following it, as in PHP's
+/// `function &foo()`.
+pub fn function_keyword(state: &mut State, kind: TokenKind) -> ParseResult<Keyword> {
+    let keyword = utils::skip_keyword(state, kind)?;
+
+    let current = state.iterator.current();
+    if current.kind == TokenKind::Ampersand {
+        state.iterator.next();
+
+        crate::parser_report!(state, function_cannot_return_by_reference(current.start()));
+    }
+
+    Ok(keyword)
+}
+
+pub fn method_type_constraint_group_definition(
+    state: &mut State,
+    until: TokenKind,
+) -> ParseResult<Option<MethodTypeConstraintGroupDefinition>> {
+    if !utils::at(state, TokenKind::Where) {
+        return Ok(None);
+    }
+
+    Ok(Some(MethodTypeConstraintGroupDefinition {
+        comments: state.iterator.comments(),
+        r#where: utils::skip_keyword(state, TokenKind::Where)?,
+        constraints: utils::comma_separated(
+            state,
+            &|state| {
+                Ok(MethodTypeConstraintDefinition {
+                    comments: state.iterator.comments(),
+                    identifier: identifier::identifier_maybe_reserved(state)?,
+                    r#is: utils::skip_keyword(state, TokenKind::Is)?,
+                    bounds: template::template_bounds_definition(state)?,
+                })
+            },
+            until,
+        )?,
+    }))
+}
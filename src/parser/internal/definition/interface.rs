@@ -1,8 +1,10 @@
 use crate::lexer::token::TokenKind;
 use crate::parser::internal::definition::attribute;
+use crate::parser::internal::definition::class;
 use crate::parser::internal::definition::constant;
 use crate::parser::internal::definition::function::method_definition;
 use crate::parser::internal::definition::modifier;
+use crate::parser::internal::definition::property;
 use crate::parser::internal::definition::template;
 use crate::parser::internal::identifier;
 use crate::parser::internal::utils;
@@ -12,6 +14,9 @@ use crate::tree::definition::interface::InterfaceDefinition;
 use crate::tree::definition::interface::InterfaceDefinitionBody;
 use crate::tree::definition::interface::InterfaceDefinitionExtends;
 use crate::tree::definition::interface::InterfaceDefinitionMember;
+use crate::tree::definition::interface::InterfaceDefinitionPermits;
+use crate::tree::definition::modifier::ModifierDefinition;
+use crate::tree::identifier::Identifier;
 
 pub fn interface_definition(state: &mut State) -> ParseResult<InterfaceDefinition> {
     let comments = state.iterator.comments();
@@ -23,8 +28,7 @@ pub fn interface_definition(state: &mut State) -> ParseResult<InterfaceDefinitio
         None
     };
 
-    let current = state.iterator.current();
-    let extends = if current.kind == TokenKind::Extends {
+    let extends = if utils::at(state, TokenKind::Extends) {
         let extends = utils::skip_keyword(state, TokenKind::Extends)?;
         let parents = utils::at_least_one_comma_separated(
             state,
@@ -37,6 +41,9 @@ pub fn interface_definition(state: &mut State) -> ParseResult<InterfaceDefinitio
         None
     };
 
+    let permits = class::permits(state)?
+        .map(|(permits, permitted)| InterfaceDefinitionPermits { permits, permitted });
+
     let attributes = state.get_attributes();
 
     let body = InterfaceDefinitionBody {
@@ -44,7 +51,9 @@ pub fn interface_definition(state: &mut State) -> ParseResult<InterfaceDefinitio
         members: {
             let mut members = Vec::new();
             while state.iterator.current().kind != TokenKind::RightBrace {
-                members.push(interface_definition_member(state)?);
+                if let Some(member) = interface_definition_member(state, &name)? {
+                    members.push(member);
+                }
             }
 
             members
@@ -59,19 +68,46 @@ pub fn interface_definition(state: &mut State) -> ParseResult<InterfaceDefinitio
         name,
         templates,
         extends,
+        permits,
         body,
     })
 }
 
-fn interface_definition_member(state: &mut State) -> ParseResult<InterfaceDefinitionMember> {
+fn interface_definition_member(
+    state: &mut State,
+    interface: &Identifier,
+) -> ParseResult<Option<InterfaceDefinitionMember>> {
     attribute::gather(state)?;
 
     let modifiers = modifier::collect(state)?;
 
-    if state.iterator.current().kind == TokenKind::Const {
-        constant::classish_constant_definition(state, modifiers)
-            .map(InterfaceDefinitionMember::Constant)
-    } else {
-        method_definition(state, modifiers).map(InterfaceDefinitionMember::Method)
+    let current = state.iterator.current();
+    if current.kind == TokenKind::Const {
+        let constant = constant::classish_constant_definition(state, modifiers)?;
+
+        for modifier in &constant.modifiers.modifiers {
+            if let ModifierDefinition::Final(_) = modifier {
+                crate::parser_report!(
+                    state,
+                    interface_constant_cannot_be_final(interface, &constant, modifier)
+                );
+            }
+        }
+
+        return Ok(Some(InterfaceDefinitionMember::Constant(constant)));
+    }
+
+    if current.kind == TokenKind::Function {
+        return method_definition(state, modifiers)
+            .map(|method| Some(InterfaceDefinitionMember::Method(method)));
     }
+
+    let property = property::property_definition(state, modifiers)?;
+
+    crate::parser_report!(
+        state,
+        interface_cannot_contain_properties(interface, &property)
+    );
+
+    Ok(None)
 }
@@ -1,4 +1,6 @@
 use crate::lexer::token::TokenKind;
+use crate::parser::internal::recovery;
+use crate::parser::internal::suggestion;
 use crate::parser::result::ParseResult;
 use crate::parser::state::State;
 use crate::tree::definition::Definition;
@@ -13,6 +15,7 @@ pub mod interface;
 pub mod modifier;
 pub mod namespace;
 pub mod parameter;
+pub mod pragma;
 pub mod property;
 pub mod template;
 pub mod r#type;
@@ -21,13 +24,26 @@ pub mod r#use;
 pub fn tree(state: &mut State) -> ParseResult<DefinitionTree> {
     let mut definitions = Vec::new();
 
+    while state.iterator.current().kind == TokenKind::Pragma {
+        definitions.push(Definition::Pragma(Box::new(pragma::pragma_definition(
+            state,
+        )?)));
+    }
+
     while !state.iterator.is_eof() {
-        definitions.push(definition(state)?);
+        let start = state.iterator.current().start();
+        match definition(state) {
+            Ok(definition) => definitions.push(definition),
+            Err(report) => {
+                recovery::recover(state, *report)?;
+                recovery::skip_to_definition(state, start);
+            }
+        }
     }
 
     Ok(DefinitionTree {
         definitions,
-        eof: state.iterator.current().position,
+        eof: state.iterator.current().start(),
     })
 }
 
@@ -36,11 +52,24 @@ pub fn definition(state: &mut State) -> ParseResult<Definition> {
     if matches!(current.kind, TokenKind::OpenTag(_)) {
         state.iterator.next();
 
-        crate::parser_report!(state, php_opening_tag_not_supported(current));
+        if !state.options.allow_php_tags {
+            crate::parser_report!(state, php_opening_tag_not_supported(current));
+        }
 
         return definition(state);
     }
 
+    if current.kind == TokenKind::Pragma {
+        let pragma = pragma::pragma_definition(state)?;
+
+        crate::parser_report!(
+            state,
+            pragma_must_be_declared_at_the_top_of_the_file(&pragma)
+        );
+
+        return Ok(Definition::Pragma(Box::new(pragma)));
+    }
+
     if current.kind == TokenKind::Namespace {
         return Ok(Definition::Namespace(Box::new(
             namespace::namespace_definition(state)?,
@@ -57,7 +86,7 @@ pub fn definition(state: &mut State) -> ParseResult<Definition> {
         )));
     }
 
-    if current.kind == TokenKind::Type {
+    if current.kind == TokenKind::Type || modifier::is_type_alias_with_modifiers(state) {
         return Ok(Definition::TypeAlias(Box::new(
             r#type::type_alias_definition(state)?,
         )));
@@ -93,5 +122,10 @@ pub fn definition(state: &mut State) -> ParseResult<Definition> {
         crate::parser_report!(state, missing_item_definition_after_attributes);
     }
 
+    if let Some(keyword) = suggestion::misspelled_keyword(current, suggestion::DEFINITION_KEYWORDS)
+    {
+        crate::parser_bail!(state, unknown_definition_keyword(current, keyword));
+    }
+
     crate::parser_bail!(state, unexpected_token(vec!["a definition"], current));
 }
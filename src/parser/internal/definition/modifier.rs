@@ -1,28 +1,33 @@
+use crate::lexer::token::Token;
 use crate::lexer::token::TokenKind;
 use crate::parser::internal::utils;
 use crate::parser::result::ParseResult;
 use crate::parser::state::State;
 use crate::tree::definition::modifier::{ModifierDefinition, ModifierGroupDefinition};
+use crate::tree::token::Keyword;
+
+fn is_modifier(kind: &TokenKind) -> bool {
+    matches!(
+        kind,
+        TokenKind::Private
+            | TokenKind::Protected
+            | TokenKind::Public
+            | TokenKind::Final
+            | TokenKind::Abstract
+            | TokenKind::Static
+            | TokenKind::Readonly
+            | TokenKind::Async
+    )
+}
 
 pub fn collect(state: &mut State) -> ParseResult<ModifierGroupDefinition> {
     let mut modifiers: Vec<ModifierDefinition> = vec![];
 
-    let collectable_tokens = vec![
-        TokenKind::Private,
-        TokenKind::Protected,
-        TokenKind::Public,
-        TokenKind::Final,
-        TokenKind::Abstract,
-        TokenKind::Static,
-        TokenKind::Readonly,
-        TokenKind::Async,
-    ];
-
     let mut current = state.iterator.current().clone();
+    let mut current_position = current.start();
     let mut current_kind = current.kind;
-    let mut current_position = current.position;
 
-    while collectable_tokens.contains(&current_kind) {
+    while is_modifier(&current_kind) {
         modifiers.push(match current_kind {
             TokenKind::Private => {
                 ModifierDefinition::Private(utils::skip_keyword(state, TokenKind::Private)?)
@@ -52,8 +57,8 @@ pub fn collect(state: &mut State) -> ParseResult<ModifierGroupDefinition> {
         });
 
         current = state.iterator.current().clone();
+        current_position = current.start();
         current_kind = current.kind;
-        current_position = current.position;
     }
 
     Ok(ModifierGroupDefinition {
@@ -61,3 +66,65 @@ pub fn collect(state: &mut State) -> ParseResult<ModifierGroupDefinition> {
         modifiers,
     })
 }
+
+/// Collect the modifiers of a type alias, which include `internal`, reporting the modifiers
+/// that can not be used on a type alias, i.e. any but a single `public`, or `internal`.
+pub fn collect_type_alias(state: &mut State) -> ParseResult<ModifierGroupDefinition> {
+    let mut modifiers: Vec<ModifierDefinition> = vec![];
+
+    loop {
+        let current = state.iterator.current();
+        if is_internal(current) {
+            state.iterator.next();
+
+            modifiers.push(ModifierDefinition::Internal(Keyword::new(
+                current.value.clone(),
+                current.start(),
+            )));
+
+            continue;
+        }
+
+        let group = collect(state)?;
+        if group.modifiers.is_empty() {
+            break;
+        }
+
+        modifiers.extend(group.modifiers);
+    }
+
+    let mut visibility: Option<&ModifierDefinition> = None;
+    for modifier in &modifiers {
+        match modifier {
+            ModifierDefinition::Public(_) | ModifierDefinition::Internal(_) => {
+                if visibility.is_some() {
+                    crate::parser_report!(state, invalid_type_alias_modifier(modifier, visibility));
+                }
+
+                visibility = Some(modifier);
+            }
+            _ => crate::parser_report!(state, invalid_type_alias_modifier(modifier, None)),
+        }
+    }
+
+    Ok(ModifierGroupDefinition {
+        position: state.iterator.current().start(),
+        modifiers,
+    })
+}
+
+/// Whether the current tokens start a type alias with modifiers, e.g. `internal type`.
+pub fn is_type_alias_with_modifiers(state: &State) -> bool {
+    let mut token = state.iterator.current();
+    let mut offset = 0;
+    while is_internal(token) || is_modifier(&token.kind) {
+        offset += 1;
+        token = state.iterator.lookahead(offset);
+    }
+
+    offset > 0 && token.kind == TokenKind::Type
+}
+
+fn is_internal(token: &Token) -> bool {
+    token.kind == TokenKind::Identifier && token.value.eq_ignore_ascii_case(b"internal")
+}
@@ -1,6 +1,7 @@
 use crate::lexer::token::TokenKind;
 use crate::parser::internal::definition;
 use crate::parser::internal::identifier;
+use crate::parser::internal::recovery;
 use crate::parser::internal::utils;
 use crate::parser::result::ParseResult;
 use crate::parser::state::State;
@@ -15,7 +16,14 @@ pub fn namespace_definition(state: &mut State) -> ParseResult<NamespaceDefinitio
 
     let mut definitions = Vec::new();
     while state.iterator.current().kind != TokenKind::Namespace && !state.iterator.is_eof() {
-        definitions.push(definition::definition(state)?);
+        let start = state.iterator.current().start();
+        match definition::definition(state) {
+            Ok(definition) => definitions.push(definition),
+            Err(report) => {
+                recovery::recover(state, *report)?;
+                recovery::skip_to_definition(state, start);
+            }
+        }
     }
 
     Ok(NamespaceDefinition {
@@ -12,6 +12,10 @@ use crate::tree::definition::function::FunctionLikeParameterDefinition;
 use crate::tree::definition::function::FunctionLikeParameterListDefinition;
 use crate::tree::definition::function::MethodParameterDefinition;
 use crate::tree::definition::function::MethodParameterListDefinition;
+use crate::tree::definition::modifier::ModifierDefinition;
+use crate::tree::definition::modifier::ModifierGroupDefinition;
+use crate::tree::definition::r#type::TypeDefinition;
+use crate::tree::variable::Variable;
 
 pub fn function_like_parameter_list_definition(
     state: &mut State,
@@ -23,27 +27,29 @@ pub fn function_like_parameter_list_definition(
         &|state| {
             attribute::gather(state)?;
 
-            let type_definition = r#type::type_definition(state)?;
+            let modifiers = readonly_modifier_group_definition(state)?;
+            let type_definition = parameter_type_definition(state)?;
+            let ampersand = ampersand(state);
 
             let current = state.iterator.current();
             let ellipsis = if current.kind == TokenKind::Ellipsis {
                 state.iterator.next();
 
-                Some(current.position)
+                Some(current.start())
             } else {
                 None
             };
 
             // 2. Then expect a variable.
-            let variable = variable::parse(state)?;
+            let variable = parameter_variable(state, ampersand)?;
 
             let current = state.iterator.current();
             let default = if current.kind == TokenKind::Equals {
                 state.iterator.next();
 
                 Some(FunctionLikeParameterDefaultValueDefinition {
-                    equals: current.position,
-                    value: expression::create(state)?,
+                    equals: current.start(),
+                    value: expression::constant(state)?,
                 })
             } else {
                 None
@@ -53,6 +59,7 @@ pub fn function_like_parameter_list_definition(
                 comments: state.iterator.comments(),
                 variable,
                 attributes: state.get_attributes(),
+                modifiers,
                 type_definition,
                 ellipsis,
                 default,
@@ -83,15 +90,16 @@ pub fn method_parameter_list_definition(
             attribute::gather(state)?;
 
             let modifiers = modifier::collect(state)?;
-            let type_definition = r#type::type_definition(state)?;
+            let type_definition = parameter_type_definition(state)?;
+            let ampersand = ampersand(state);
             let current = state.iterator.current();
             let (ellipsis, variable) = if matches!(current.kind, TokenKind::Ellipsis) {
                 state.iterator.next();
-                let variable = variable::parse(state)?;
+                let variable = parameter_variable(state, ampersand)?;
 
-                (Some(current.position), variable)
+                (Some(current.start()), variable)
             } else {
-                (None, variable::parse(state)?)
+                (None, parameter_variable(state, ampersand)?)
             };
 
             let current = state.iterator.current();
@@ -99,8 +107,8 @@ pub fn method_parameter_list_definition(
                 state.iterator.next();
 
                 Some(FunctionLikeParameterDefaultValueDefinition {
-                    equals: current.position,
-                    value: expression::create(state)?,
+                    equals: current.start(),
+                    value: expression::constant(state)?,
                 })
             } else {
                 None
@@ -130,3 +138,59 @@ pub fn method_parameter_list_definition(
         right_parenthesis,
     })
 }
+
+fn readonly_modifier_group_definition(state: &mut State) -> ParseResult<ModifierGroupDefinition> {
+    let mut modifiers = vec![];
+
+    let mut current = state.iterator.current();
+    while current.kind == TokenKind::Readonly {
+        modifiers.push(ModifierDefinition::Readonly(utils::skip_keyword(
+            state,
+            TokenKind::Readonly,
+        )?));
+
+        current = state.iterator.current();
+    }
+
+    Ok(ModifierGroupDefinition {
+        position: current.start(),
+        modifiers,
+    })
+}
+
+/// Parse the type of a parameter, reporting a `never` type, as no argument could be passed for it.
+fn parameter_type_definition(state: &mut State) -> ParseResult<TypeDefinition> {
+    let type_definition = r#type::type_definition(state)?;
+
+    if let TypeDefinition::Never(keyword) = &type_definition {
+        crate::parser_report!(state, never_type_cannot_be_used_for_parameter(keyword));
+    }
+
+    Ok(type_definition)
+}
+
+/// Skip a by-reference `&` marker, returning its position.
+fn ampersand(state: &mut State) -> Option<usize> {
+    let current = state.iterator.current();
+    if current.kind != TokenKind::Ampersand {
+        return None;
+    }
+
+    state.iterator.next();
+
+    Some(current.start())
+}
+
+/// Parse the parameter variable, reporting a by-reference parameter if an `&` preceded it.
+fn parameter_variable(state: &mut State, ampersand: Option<usize>) -> ParseResult<Variable> {
+    let variable = variable::parse(state)?;
+
+    if let Some(ampersand) = ampersand {
+        crate::parser_report!(
+            state,
+            parameter_cannot_be_passed_by_reference(ampersand, &variable)
+        );
+    }
+
+    Ok(variable)
+}
@@ -0,0 +1,15 @@
+use crate::lexer::token::TokenKind;
+use crate::parser::internal::identifier;
+use crate::parser::internal::utils;
+use crate::parser::result::ParseResult;
+use crate::parser::state::State;
+use crate::tree::definition::pragma::PragmaDefinition;
+
+pub fn pragma_definition(state: &mut State) -> ParseResult<PragmaDefinition> {
+    Ok(PragmaDefinition {
+        comments: state.iterator.comments(),
+        pragma: utils::skip_keyword(state, TokenKind::Pragma)?,
+        name: identifier::identifier_maybe_soft_reserved(state)?,
+        semicolon: utils::skip_semicolon(state)?,
+    })
+}
@@ -1,15 +1,24 @@
+use ara_source::source::SourceKind;
+use smallvec::SmallVec;
+
 use crate::lexer::token::TokenKind;
 use crate::parser::internal::definition::r#type;
 use crate::parser::internal::identifier;
 use crate::parser::internal::utils;
 use crate::parser::result::ParseResult;
 use crate::parser::state::State;
+use crate::tree::definition::r#type::TypeDefinition;
+use crate::tree::definition::template::ConstTemplateDefinition;
+use crate::tree::definition::template::TemplateBoundsDefinition;
 use crate::tree::definition::template::TemplateDefinition;
 use crate::tree::definition::template::TemplateDefinitionTypeConstraint;
 use crate::tree::definition::template::TemplateDefinitionVariance;
 use crate::tree::definition::template::TemplateGroupDefinition;
+use crate::tree::definition::template::TemplateGroupDefinitionMember;
 use crate::tree::definition::template::TypeTemplateGroupDefinition;
+use crate::tree::token::Keyword;
 use crate::tree::utils::CommaSeparated;
+use crate::tree::Node;
 
 pub fn template_group_definition(state: &mut State) -> ParseResult<TemplateGroupDefinition> {
     Ok(TemplateGroupDefinition {
@@ -17,37 +26,74 @@ pub fn template_group_definition(state: &mut State) -> ParseResult<TemplateGroup
         less_than: utils::skip(state, TokenKind::LessThan)?,
         members: {
             let mut inner = vec![];
-            let mut commas = vec![];
+            let mut commas = SmallVec::new();
 
             let mut current = state.iterator.current();
             while current.kind != TokenKind::GreaterThan && current.kind != TokenKind::RightShift {
-                inner.push({
+                inner.push(if state.iterator.current().kind == TokenKind::Const {
+                    TemplateGroupDefinitionMember::Const(ConstTemplateDefinition {
+                        r#const: utils::skip_keyword(state, TokenKind::Const)?,
+                        name: identifier::classname_identifier(state)?,
+                        colon: utils::skip_colon(state)?,
+                        type_definition: r#type::type_definition(state)?,
+                    })
+                } else {
                     let current = state.iterator.current();
                     let variance = match &current.kind {
                         TokenKind::Plus => {
                             state.iterator.next();
 
-                            TemplateDefinitionVariance::Covariance(current.position)
+                            TemplateDefinitionVariance::Covariance(current.start())
                         }
                         _ => TemplateDefinitionVariance::Invaraint,
                     };
 
+                    let current = state.iterator.current();
+                    let ellipsis = if current.kind == TokenKind::Ellipsis {
+                        state.iterator.next();
+
+                        Some(current.start())
+                    } else {
+                        None
+                    };
+
                     let name = identifier::classname_identifier(state)?;
 
                     let current = state.iterator.current();
                     let constraint = match &current.kind {
                         TokenKind::As => TemplateDefinitionTypeConstraint::SubType(
                             utils::skip_keyword(state, TokenKind::As)?,
-                            r#type::type_definition(state)?,
+                            template_bounds_definition(state)?,
                         ),
+                        TokenKind::Identifier if current.value.eq_ignore_ascii_case(b"super") => {
+                            state.iterator.next();
+
+                            TemplateDefinitionTypeConstraint::SuperType(
+                                Keyword::new(current.value.clone(), current.start()),
+                                template_bounds_definition(state)?,
+                            )
+                        }
                         _ => TemplateDefinitionTypeConstraint::None,
                     };
 
-                    TemplateDefinition {
+                    let template = TemplateDefinition {
                         name,
                         variance,
+                        ellipsis,
                         constraint,
+                    };
+
+                    if ellipsis.is_some() && state.kind() != SourceKind::Definition {
+                        crate::parser_report!(
+                            state,
+                            variadic_template_can_only_be_used_in_definition_source(
+                                template.initial_position(),
+                                template.final_position(),
+                            )
+                        );
                     }
+
+                    TemplateGroupDefinitionMember::Type(template)
                 });
 
                 current = state.iterator.current();
@@ -55,7 +101,7 @@ pub fn template_group_definition(state: &mut State) -> ParseResult<TemplateGroup
                     break;
                 }
 
-                commas.push(current.position);
+                commas.push(current.start());
 
                 state.iterator.next();
                 current = state.iterator.current();
@@ -63,44 +109,60 @@ pub fn template_group_definition(state: &mut State) -> ParseResult<TemplateGroup
 
             CommaSeparated { inner, commas }
         },
-        greater_than: {
-            let current = state.iterator.current();
+        greater_than: greater_than(state)?,
+        docblock: None,
+    })
+}
 
-            if let Some(token) = state.ignored_shift_at {
-                utils::skip(state, TokenKind::RightShift)?;
-                state.ignored_shift_at = None;
-                token.position + 1
-            } else if current.kind == TokenKind::RightShift {
-                state.ignored_shift_at = Some(current);
+/// Parse the bounds of a template constraint, separated by `+`, e.g. `Countable + Stringable`.
+pub fn template_bounds_definition(state: &mut State) -> ParseResult<TemplateBoundsDefinition> {
+    let mut inner = vec![r#type::type_definition(state)?];
+    let mut pluses = vec![];
 
-                current.position
-            } else {
-                utils::skip(state, TokenKind::GreaterThan)?
-            }
-        },
-    })
+    while state.iterator.current().kind == TokenKind::Plus {
+        pluses.push(utils::skip(state, TokenKind::Plus)?);
+        inner.push(r#type::type_definition(state)?);
+    }
+
+    Ok(TemplateBoundsDefinition { inner, pluses })
 }
 
 pub fn type_template_group_definition(
     state: &mut State,
 ) -> ParseResult<TypeTemplateGroupDefinition> {
+    template_group(state, false)
+}
+
+/// Parse the templates of a closure type, where the first template is the parameter list of
+/// the closure, see `type::callable_parameters`.
+pub fn closure_type_template_group_definition(
+    state: &mut State,
+) -> ParseResult<TypeTemplateGroupDefinition> {
+    template_group(state, true)
+}
+
+fn template_group(state: &mut State, closure: bool) -> ParseResult<TypeTemplateGroupDefinition> {
     let comments = state.iterator.comments();
     let less_than = utils::skip(state, TokenKind::LessThan)?;
 
     let members = {
         let mut inner = vec![];
-        let mut commas = vec![];
+        let mut commas = SmallVec::new();
 
         let mut current = state.iterator.current();
         while current.kind != TokenKind::GreaterThan && current.kind != TokenKind::RightShift {
-            inner.push(r#type::type_definition(state)?);
+            if closure && inner.is_empty() && current.kind == TokenKind::LeftParen {
+                inner.push(r#type::callable_parameters(state)?);
+            } else {
+                inner.push(type_template_group_member(state)?);
+            }
 
             current = state.iterator.current();
             if current.kind != TokenKind::Comma {
                 break;
             }
 
-            commas.push(current.position);
+            commas.push(current.start());
 
             state.iterator.next();
             current = state.iterator.current();
@@ -109,21 +171,7 @@ pub fn type_template_group_definition(
         CommaSeparated { inner, commas }
     };
 
-    let greater_than = {
-        let current = state.iterator.current();
-
-        if let Some(token) = state.ignored_shift_at {
-            utils::skip(state, TokenKind::RightShift)?;
-            state.ignored_shift_at = None;
-            token.position + 1
-        } else if current.kind == TokenKind::RightShift {
-            state.ignored_shift_at = Some(current);
-
-            current.position
-        } else {
-            utils::skip(state, TokenKind::GreaterThan)?
-        }
-    };
+    let greater_than = greater_than(state)?;
 
     Ok(TypeTemplateGroupDefinition {
         comments,
@@ -132,3 +180,46 @@ pub fn type_template_group_definition(
         greater_than,
     })
 }
+
+/// Skip the `>` closing a group, e.g. of templates, which may be the first half of a `>>`
+/// closing a nested group as well, returning its position.
+pub fn greater_than(state: &mut State) -> ParseResult<usize> {
+    let current = state.iterator.current();
+
+    if let Some(token) = state.ignored_shift_at {
+        utils::skip(state, TokenKind::RightShift)?;
+        state.ignored_shift_at = None;
+
+        Ok(token.start() + 1)
+    } else if current.kind == TokenKind::RightShift {
+        state.ignored_shift_at = Some(current);
+
+        Ok(current.start())
+    } else {
+        utils::skip(state, TokenKind::GreaterThan)
+    }
+}
+
+fn type_template_group_member(state: &mut State) -> ParseResult<TypeDefinition> {
+    let current = state.iterator.current();
+    if current.kind != TokenKind::Ellipsis {
+        return r#type::type_definition(state);
+    }
+
+    state.iterator.next();
+
+    let type_definition =
+        TypeDefinition::Variadic(current.start(), Box::new(r#type::type_definition(state)?));
+
+    if state.kind() != SourceKind::Definition {
+        crate::parser_report!(
+            state,
+            variadic_template_can_only_be_used_in_definition_source(
+                type_definition.initial_position(),
+                type_definition.final_position(),
+            )
+        );
+    }
+
+    Ok(type_definition)
+}
@@ -1,11 +1,20 @@
+use smallvec::SmallVec;
+
 use crate::lexer::token::TokenKind;
+use crate::parser::internal::definition::function;
+use crate::parser::internal::definition::modifier;
 use crate::parser::internal::definition::template;
 use crate::parser::internal::identifier;
 use crate::parser::internal::utils;
+use crate::parser::internal::variable;
 use crate::parser::result::ParseResult;
 use crate::parser::state::State;
 use crate::tree::definition::r#type::FloatingPointTypeDefinition;
+use crate::tree::definition::r#type::IntegerRangeBoundTypeDefinition;
+use crate::tree::definition::r#type::ShapeFieldTypeDefinition;
 use crate::tree::definition::r#type::SignedIntegerTypeDefinition;
+use crate::tree::definition::r#type::TupleElementLabelDefinition;
+use crate::tree::definition::r#type::TupleElementTypeDefinition;
 use crate::tree::definition::r#type::TypeAliasDefinition;
 use crate::tree::definition::r#type::TypeDefinition;
 use crate::tree::definition::r#type::UnsignedIntegerTypeDefinition;
@@ -18,19 +27,30 @@ use crate::tree::expression::literal::LiteralString;
 use crate::tree::expression::literal::LiteralTrue;
 use crate::tree::token::Keyword;
 use crate::tree::utils::CommaSeparated;
+use crate::tree::Node;
 
 pub fn type_alias_definition(state: &mut State) -> ParseResult<TypeAliasDefinition> {
     Ok(TypeAliasDefinition {
+        modifiers: modifier::collect_type_alias(state)?,
         r#type: utils::skip_keyword(state, TokenKind::Type)?,
         name: identifier::type_identifier(state)?,
         equals: utils::skip(state, TokenKind::Equals)?,
+        new: if state.iterator.current().kind == TokenKind::New {
+            Some(utils::skip_keyword(state, TokenKind::New)?)
+        } else {
+            None
+        },
         type_definition: type_definition(state)?,
+        constraints: function::method_type_constraint_group_definition(
+            state,
+            TokenKind::SemiColon,
+        )?,
         semicolon: utils::skip_semicolon(state)?,
     })
 }
 
 pub fn type_definition(state: &mut State) -> ParseResult<TypeDefinition> {
-    atomic(state)
+    utils::nested(state, atomic)
 }
 
 fn atomic(state: &mut State) -> ParseResult<TypeDefinition> {
@@ -45,13 +65,18 @@ fn atomic(state: &mut State) -> ParseResult<TypeDefinition> {
         single(state)?
     };
 
+    let type_definition = indexed_access(state, type_definition)?;
+
     let current = state.iterator.current();
     if current.kind == TokenKind::Pipe {
         return union(state, type_definition);
     }
 
     if current.kind == TokenKind::Ampersand
-        && !matches!(state.iterator.lookahead(1).kind, TokenKind::Ampersand)
+        && !matches!(
+            state.iterator.lookahead(1).kind,
+            TokenKind::Ampersand | TokenKind::Variable | TokenKind::Ellipsis
+        )
     {
         return intersection(state, type_definition);
     }
@@ -62,12 +87,16 @@ fn atomic(state: &mut State) -> ParseResult<TypeDefinition> {
 fn parenthesized(state: &mut State) -> ParseResult<TypeDefinition> {
     let left_parenthesis = utils::skip(state, TokenKind::LeftParen)?;
     if state.iterator.current().kind == TokenKind::RightParen {
+        return Ok(TypeDefinition::Unit {
+            left_parenthesis,
+            right_parenthesis: utils::skip_right_parenthesis(state)?,
+        });
+    }
+
+    if is_tuple_element_label(state) {
         return Ok(TypeDefinition::Tuple {
             left_parenthesis,
-            type_definitions: CommaSeparated {
-                inner: Vec::new(),
-                commas: Vec::new(),
-            },
+            elements: utils::comma_separated(state, &tuple_element, TokenKind::RightParen)?,
             right_parenthesis: utils::skip_right_parenthesis(state)?,
         });
     }
@@ -84,10 +113,74 @@ fn parenthesized(state: &mut State) -> ParseResult<TypeDefinition> {
     }
 }
 
+fn integer_range(state: &mut State) -> ParseResult<TypeDefinition> {
+    let current = state.iterator.current();
+    state.iterator.next();
+
+    Ok(TypeDefinition::IntegerRange {
+        int: Keyword::new(current.value.clone(), current.start()),
+        less_than: utils::skip(state, TokenKind::LessThan)?,
+        min: integer_range_bound(state, b"min")?,
+        comma: utils::skip(state, TokenKind::Comma)?,
+        max: integer_range_bound(state, b"max")?,
+        greater_than: template::greater_than(state)?,
+    })
+}
+
+/// Parse a bound of an integer range, either an integer, or the given keyword, i.e. `min` for
+/// the lower bound, and `max` for the upper bound.
+fn integer_range_bound(
+    state: &mut State,
+    unbounded: &[u8],
+) -> ParseResult<IntegerRangeBoundTypeDefinition> {
+    let current = state.iterator.current();
+    if current.kind == TokenKind::Identifier && current.value.eq_ignore_ascii_case(unbounded) {
+        state.iterator.next();
+
+        return Ok(IntegerRangeBoundTypeDefinition::Unbounded(Keyword::new(
+            current.value.clone(),
+            current.start(),
+        )));
+    }
+
+    let minus = if current.kind == TokenKind::Minus {
+        state.iterator.next();
+
+        Some(current.start())
+    } else {
+        None
+    };
+
+    let current = state.iterator.current();
+    if current.kind != TokenKind::LiteralInteger {
+        let expected = if minus.is_some() {
+            vec!["an integer literal".to_string()]
+        } else {
+            vec![
+                "an integer literal".to_string(),
+                String::from_utf8_lossy(unbounded).to_string(),
+            ]
+        };
+
+        crate::parser_bail!(state, unexpected_token(expected, current));
+    }
+
+    state.iterator.next();
+
+    Ok(IntegerRangeBoundTypeDefinition::Integer {
+        minus,
+        value: LiteralInteger {
+            comments: state.iterator.comments(),
+            value: current.value.clone(),
+            position: current.start(),
+        },
+    })
+}
+
 fn single(state: &mut State) -> ParseResult<TypeDefinition> {
     let current = state.iterator.current();
 
-    let position = current.position;
+    let position = current.start();
     let name = &current.value[..];
     let lowered_name = name.to_ascii_lowercase();
     let value = lowered_name.as_slice();
@@ -144,10 +237,22 @@ fn single(state: &mut State) -> ParseResult<TypeDefinition> {
                 position,
             })))
         }
+        _ if value == b"shape" && state.iterator.lookahead(1).kind == TokenKind::LeftParen => {
+            shape(state)
+        }
+        _ if value == b"keyof" && is_type_start(&state.iterator.lookahead(1).kind) => {
+            state.iterator.next();
+
+            let keyword = Keyword::new(name.into(), current.start());
+            let type_definition = single(state)?;
+            let type_definition = indexed_access(state, type_definition)?;
+
+            Ok(TypeDefinition::KeyOf(keyword, Box::new(type_definition)))
+        }
         _ if value == b"iterable" => {
             state.iterator.next();
 
-            let keyword = Keyword::new(name.into(), current.position);
+            let keyword = Keyword::new(name.into(), current.start());
             let templates = template::type_template_group_definition(state)?;
 
             Ok(TypeDefinition::Iterable(keyword, templates))
@@ -155,28 +260,31 @@ fn single(state: &mut State) -> ParseResult<TypeDefinition> {
         _ if value == b"void" => {
             state.iterator.next();
 
-            let keyword = Keyword::new(name.into(), current.position);
+            let keyword = Keyword::new(name.into(), current.start());
 
             Ok(TypeDefinition::Void(keyword))
         }
-        _ if value == b"never" => {
+        _ if value == b"never" || value == b"noreturn" => {
             state.iterator.next();
 
-            let keyword = Keyword::new(name.into(), current.position);
+            let keyword = Keyword::new(name.into(), current.start());
 
             Ok(TypeDefinition::Never(keyword))
         }
         _ if value == b"bool" => {
             state.iterator.next();
 
-            let keyword = Keyword::new(name.into(), current.position);
+            let keyword = Keyword::new(name.into(), current.start());
 
             Ok(TypeDefinition::Boolean(keyword))
         }
+        _ if value == b"int" && state.iterator.lookahead(1).kind == TokenKind::LessThan => {
+            integer_range(state)
+        }
         _ if value == b"int" => {
             state.iterator.next();
 
-            let keyword = Keyword::new(name.into(), current.position);
+            let keyword = Keyword::new(name.into(), current.start());
 
             Ok(TypeDefinition::SignedInteger(
                 SignedIntegerTypeDefinition::Default(keyword),
@@ -185,7 +293,7 @@ fn single(state: &mut State) -> ParseResult<TypeDefinition> {
         _ if value == b"i128" => {
             state.iterator.next();
 
-            let keyword = Keyword::new(name.into(), current.position);
+            let keyword = Keyword::new(name.into(), current.start());
 
             Ok(TypeDefinition::SignedInteger(
                 SignedIntegerTypeDefinition::I128(keyword),
@@ -194,7 +302,7 @@ fn single(state: &mut State) -> ParseResult<TypeDefinition> {
         _ if value == b"i64" => {
             state.iterator.next();
 
-            let keyword = Keyword::new(name.into(), current.position);
+            let keyword = Keyword::new(name.into(), current.start());
 
             Ok(TypeDefinition::SignedInteger(
                 SignedIntegerTypeDefinition::I64(keyword),
@@ -203,7 +311,7 @@ fn single(state: &mut State) -> ParseResult<TypeDefinition> {
         _ if value == b"i32" => {
             state.iterator.next();
 
-            let keyword = Keyword::new(name.into(), current.position);
+            let keyword = Keyword::new(name.into(), current.start());
 
             Ok(TypeDefinition::SignedInteger(
                 SignedIntegerTypeDefinition::I32(keyword),
@@ -212,7 +320,7 @@ fn single(state: &mut State) -> ParseResult<TypeDefinition> {
         _ if value == b"i16" => {
             state.iterator.next();
 
-            let keyword = Keyword::new(name.into(), current.position);
+            let keyword = Keyword::new(name.into(), current.start());
 
             Ok(TypeDefinition::SignedInteger(
                 SignedIntegerTypeDefinition::I16(keyword),
@@ -221,7 +329,7 @@ fn single(state: &mut State) -> ParseResult<TypeDefinition> {
         _ if value == b"i8" => {
             state.iterator.next();
 
-            let keyword = Keyword::new(name.into(), current.position);
+            let keyword = Keyword::new(name.into(), current.start());
 
             Ok(TypeDefinition::SignedInteger(
                 SignedIntegerTypeDefinition::I8(keyword),
@@ -230,7 +338,7 @@ fn single(state: &mut State) -> ParseResult<TypeDefinition> {
         _ if value == b"uint" => {
             state.iterator.next();
 
-            let keyword = Keyword::new(name.into(), current.position);
+            let keyword = Keyword::new(name.into(), current.start());
 
             Ok(TypeDefinition::UnsignedInteger(
                 UnsignedIntegerTypeDefinition::Default(keyword),
@@ -239,7 +347,7 @@ fn single(state: &mut State) -> ParseResult<TypeDefinition> {
         _ if value == b"u32" => {
             state.iterator.next();
 
-            let keyword = Keyword::new(name.into(), current.position);
+            let keyword = Keyword::new(name.into(), current.start());
 
             Ok(TypeDefinition::UnsignedInteger(
                 UnsignedIntegerTypeDefinition::U32(keyword),
@@ -248,7 +356,7 @@ fn single(state: &mut State) -> ParseResult<TypeDefinition> {
         _ if value == b"u16" => {
             state.iterator.next();
 
-            let keyword = Keyword::new(name.into(), current.position);
+            let keyword = Keyword::new(name.into(), current.start());
 
             Ok(TypeDefinition::UnsignedInteger(
                 UnsignedIntegerTypeDefinition::U16(keyword),
@@ -257,7 +365,7 @@ fn single(state: &mut State) -> ParseResult<TypeDefinition> {
         _ if value == b"u8" => {
             state.iterator.next();
 
-            let keyword = Keyword::new(name.into(), current.position);
+            let keyword = Keyword::new(name.into(), current.start());
 
             Ok(TypeDefinition::UnsignedInteger(
                 UnsignedIntegerTypeDefinition::U8(keyword),
@@ -266,7 +374,7 @@ fn single(state: &mut State) -> ParseResult<TypeDefinition> {
         _ if value == b"float" => {
             state.iterator.next();
 
-            let keyword = Keyword::new(name.into(), current.position);
+            let keyword = Keyword::new(name.into(), current.start());
 
             Ok(TypeDefinition::FloatingPoint(
                 FloatingPointTypeDefinition::Default(keyword),
@@ -275,7 +383,7 @@ fn single(state: &mut State) -> ParseResult<TypeDefinition> {
         _ if value == b"f64" => {
             state.iterator.next();
 
-            let keyword = Keyword::new(name.into(), current.position);
+            let keyword = Keyword::new(name.into(), current.start());
 
             Ok(TypeDefinition::FloatingPoint(
                 FloatingPointTypeDefinition::F64(keyword),
@@ -284,7 +392,7 @@ fn single(state: &mut State) -> ParseResult<TypeDefinition> {
         _ if value == b"f32" => {
             state.iterator.next();
 
-            let keyword = Keyword::new(name.into(), current.position);
+            let keyword = Keyword::new(name.into(), current.start());
 
             Ok(TypeDefinition::FloatingPoint(
                 FloatingPointTypeDefinition::F32(keyword),
@@ -293,46 +401,58 @@ fn single(state: &mut State) -> ParseResult<TypeDefinition> {
         _ if value == b"string" => {
             state.iterator.next();
 
-            let keyword = Keyword::new(name.into(), current.position);
+            let keyword = Keyword::new(name.into(), current.start());
 
             Ok(TypeDefinition::String(keyword))
         }
         _ if value == b"object" => {
             state.iterator.next();
 
-            let keyword = Keyword::new(name.into(), current.position);
+            let keyword = Keyword::new(name.into(), current.start());
 
             Ok(TypeDefinition::Object(keyword))
         }
         _ if value == b"mixed" => {
             state.iterator.next();
 
-            let keyword = Keyword::new(name.into(), current.position);
+            let keyword = Keyword::new(name.into(), current.start());
 
             Ok(TypeDefinition::Mixed(keyword))
         }
         _ if value == b"nonnull" => {
             state.iterator.next();
 
-            let keyword = Keyword::new(name.into(), current.position);
+            let keyword = Keyword::new(name.into(), current.start());
 
             Ok(TypeDefinition::NonNull(keyword))
         }
         _ if value == b"resource" => {
             state.iterator.next();
 
-            let keyword = Keyword::new(name.into(), current.position);
+            let keyword = Keyword::new(name.into(), current.start());
 
             Ok(TypeDefinition::Resource(keyword))
         }
         TokenKind::Class => Ok(TypeDefinition::Class(
-            utils::skip_keyword(state, TokenKind::Class)?,
+            string_keyword(state, TokenKind::Class)?,
             template::type_template_group_definition(state)?,
         )),
         TokenKind::Interface => Ok(TypeDefinition::Interface(
-            utils::skip_keyword(state, TokenKind::Interface)?,
+            string_keyword(state, TokenKind::Interface)?,
             template::type_template_group_definition(state)?,
         )),
+        TokenKind::Self_ => Ok(TypeDefinition::Self_(utils::skip_keyword(
+            state,
+            TokenKind::Self_,
+        )?)),
+        TokenKind::Static => Ok(TypeDefinition::Static(utils::skip_keyword(
+            state,
+            TokenKind::Static,
+        )?)),
+        TokenKind::Parent => Ok(TypeDefinition::Parent(utils::skip_keyword(
+            state,
+            TokenKind::Parent,
+        )?)),
         TokenKind::Identifier
         | TokenKind::QualifiedIdentifier
         | TokenKind::FullyQualifiedIdentifier => {
@@ -349,15 +469,157 @@ fn single(state: &mut State) -> ParseResult<TypeDefinition> {
     }
 }
 
+fn shape(state: &mut State) -> ParseResult<TypeDefinition> {
+    let current = state.iterator.current();
+    state.iterator.next();
+
+    let shape = Keyword::new(current.value.clone(), current.start());
+    let left_parenthesis = utils::skip_left_parenthesis(state)?;
+
+    let mut inner = vec![];
+    let mut commas = SmallVec::new();
+    let mut ellipsis = None;
+
+    let mut current = state.iterator.current();
+    while current.kind != TokenKind::RightParen {
+        if current.kind == TokenKind::Ellipsis {
+            state.iterator.next();
+            ellipsis = Some(current.start());
+
+            break;
+        }
+
+        inner.push(shape_field(state)?);
+
+        current = state.iterator.current();
+        if current.kind != TokenKind::Comma {
+            break;
+        }
+
+        commas.push(current.start());
+
+        state.iterator.next();
+        current = state.iterator.current();
+    }
+
+    Ok(TypeDefinition::Shape {
+        shape,
+        left_parenthesis,
+        fields: CommaSeparated { inner, commas },
+        ellipsis,
+        right_parenthesis: utils::skip_right_parenthesis(state)?,
+    })
+}
+
+fn shape_field(state: &mut State) -> ParseResult<ShapeFieldTypeDefinition> {
+    let current = state.iterator.current();
+    let question = if current.kind == TokenKind::Question {
+        state.iterator.next();
+
+        Some(current.start())
+    } else {
+        None
+    };
+
+    let current = state.iterator.current();
+    let key = match &current.kind {
+        TokenKind::LiteralString => {
+            state.iterator.next();
+
+            Literal::String(LiteralString {
+                comments: state.iterator.comments(),
+                value: current.value.clone(),
+                position: current.start(),
+            })
+        }
+        TokenKind::LiteralInteger => {
+            state.iterator.next();
+
+            Literal::Integer(LiteralInteger {
+                comments: state.iterator.comments(),
+                value: current.value.clone(),
+                position: current.start(),
+            })
+        }
+        _ => {
+            crate::parser_bail!(
+                state,
+                unexpected_token(vec!["a string literal", "an integer literal"], current)
+            );
+        }
+    };
+
+    Ok(ShapeFieldTypeDefinition {
+        question,
+        key,
+        double_arrow: utils::skip(state, TokenKind::DoubleArrow)?,
+        type_definition: type_definition(state)?,
+    })
+}
+
+fn indexed_access(
+    state: &mut State,
+    type_definition: TypeDefinition,
+) -> ParseResult<TypeDefinition> {
+    let mut type_definition = type_definition;
+
+    while state.iterator.current().kind == TokenKind::LeftBracket {
+        type_definition = TypeDefinition::IndexedAccess {
+            type_definition: Box::new(type_definition),
+            left_bracket: utils::skip(state, TokenKind::LeftBracket)?,
+            index: Box::new(atomic(state)?),
+            right_bracket: utils::skip(state, TokenKind::RightBracket)?,
+        };
+    }
+
+    Ok(type_definition)
+}
+
+/// Skip the given keyword, along with a directly following `-string`, if any, e.g. `class-string`.
+fn string_keyword(state: &mut State, kind: TokenKind) -> ParseResult<Keyword> {
+    let keyword = utils::skip_keyword(state, kind)?;
+
+    let minus = state.iterator.current();
+    let string = state.iterator.lookahead(1);
+    if minus.kind == TokenKind::Minus
+        && minus.start() == keyword.final_position()
+        && string.kind == TokenKind::Identifier
+        && string.value.eq_ignore_ascii_case(b"string")
+        && string.start() == minus.end()
+    {
+        state.iterator.next();
+        state.iterator.next();
+
+        let mut value = keyword.value.to_vec();
+        value.push(b'-');
+        value.extend_from_slice(&string.value);
+
+        return Ok(Keyword::new(value.into(), keyword.position));
+    }
+
+    Ok(keyword)
+}
+
+fn is_type_start(kind: &TokenKind) -> bool {
+    matches!(
+        kind,
+        TokenKind::Identifier
+            | TokenKind::QualifiedIdentifier
+            | TokenKind::FullyQualifiedIdentifier
+            | TokenKind::LeftParen
+    ) || identifier::is_reserved_identifier(kind)
+}
+
 fn nullable(state: &mut State) -> ParseResult<TypeDefinition> {
     let current = state.iterator.current();
 
     state.iterator.next();
 
     let type_definition = single(state)?;
+    let type_definition = indexed_access(state, type_definition)?;
 
     Ok(TypeDefinition::Nullable(
-        current.position,
+        current.start(),
         Box::new(type_definition),
     ))
 }
@@ -388,9 +650,14 @@ fn tuple(
 
         return Ok(TypeDefinition::Tuple {
             left_parenthesis,
-            type_definitions: CommaSeparated {
-                inner: vec![initial_type_definition],
-                commas: Vec::new(),
+            elements: CommaSeparated {
+                inner: vec![TupleElementTypeDefinition {
+                    label: None,
+                    optional: None,
+                    type_definition: initial_type_definition,
+                    variable: None,
+                }],
+                commas: SmallVec::new(),
             },
             right_parenthesis,
         });
@@ -398,33 +665,131 @@ fn tuple(
 
     let comma = utils::skip(state, TokenKind::Comma)?;
 
-    let mut previous_type_definitions = [initial_type_definition];
-    let mut previous_commas = [comma];
+    let mut previous_elements = [TupleElementTypeDefinition {
+        label: None,
+        optional: None,
+        type_definition: initial_type_definition,
+        variable: None,
+    }];
+    let previous_commas = [comma];
 
-    let mut type_definitions =
-        utils::comma_separated(state, &type_definition, TokenKind::RightParen)?;
+    let mut elements = utils::comma_separated(state, &tuple_element, TokenKind::RightParen)?;
 
-    type_definitions.inner = [
-        previous_type_definitions.as_mut_slice(),
-        type_definitions.inner.as_mut_slice(),
+    elements.inner = [
+        previous_elements.as_mut_slice(),
+        elements.inner.as_mut_slice(),
     ]
     .concat();
 
-    type_definitions.commas = [
-        previous_commas.as_mut_slice(),
-        type_definitions.commas.as_mut_slice(),
-    ]
-    .concat();
+    elements.commas.insert_many(0, previous_commas);
+
+    let right_parenthesis = utils::skip_right_parenthesis(state)?;
+
+    Ok(TypeDefinition::Tuple {
+        left_parenthesis,
+        elements,
+        right_parenthesis,
+    })
+}
+
+fn tuple_element(state: &mut State) -> ParseResult<TupleElementTypeDefinition> {
+    Ok(TupleElementTypeDefinition {
+        label: tuple_element_label(state)?,
+        optional: None,
+        type_definition: type_definition(state)?,
+        variable: None,
+    })
+}
+
+fn tuple_element_label(state: &mut State) -> ParseResult<Option<TupleElementLabelDefinition>> {
+    if is_tuple_element_label(state) {
+        Ok(Some(TupleElementLabelDefinition {
+            name: identifier::identifier_maybe_soft_reserved(state)?,
+            colon: utils::skip_colon(state)?,
+        }))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Parse the parameter list of a closure type, e.g. `(int $limit, optional ?string $cursor)`
+/// in `Closure<(int $limit, optional ?string $cursor), Page>`.
+///
+/// Unlike other tuples, the elements of a parameter list may be named using a variable, and
+/// marked as optional using `optional`, an unqualified `optional` at the start of an element
+/// is always the marker, and never the name of a type.
+pub fn callable_parameters(state: &mut State) -> ParseResult<TypeDefinition> {
+    let left_parenthesis = utils::skip(state, TokenKind::LeftParen)?;
+    if state.iterator.current().kind == TokenKind::RightParen {
+        return Ok(TypeDefinition::Unit {
+            left_parenthesis,
+            right_parenthesis: utils::skip_right_parenthesis(state)?,
+        });
+    }
 
+    let mut elements = utils::comma_separated(state, &callable_parameter, TokenKind::RightParen)?;
     let right_parenthesis = utils::skip_right_parenthesis(state)?;
 
+    if elements.commas.is_empty() {
+        if let [TupleElementTypeDefinition {
+            label: None,
+            optional: None,
+            type_definition: TypeDefinition::Union(_) | TypeDefinition::Intersection(_),
+            variable: None,
+        }] = elements.inner.as_slice()
+        {
+            return Ok(TypeDefinition::Parenthesized {
+                left_parenthesis,
+                type_definition: Box::new(elements.inner.remove(0).type_definition),
+                right_parenthesis,
+            });
+        }
+    }
+
     Ok(TypeDefinition::Tuple {
         left_parenthesis,
-        type_definitions,
+        elements,
         right_parenthesis,
     })
 }
 
+fn callable_parameter(state: &mut State) -> ParseResult<TupleElementTypeDefinition> {
+    let label = tuple_element_label(state)?;
+
+    let current = state.iterator.current();
+    let optional = if current.kind == TokenKind::Identifier
+        && current.value.eq_ignore_ascii_case(b"optional")
+    {
+        state.iterator.next();
+
+        Some(Keyword::new(current.value.clone(), current.start()))
+    } else {
+        None
+    };
+
+    let type_definition = type_definition(state)?;
+    let variable = if state.iterator.current().kind == TokenKind::Variable {
+        Some(variable::parse(state)?)
+    } else {
+        None
+    };
+
+    Ok(TupleElementTypeDefinition {
+        label,
+        optional,
+        type_definition,
+        variable,
+    })
+}
+
+fn is_tuple_element_label(state: &State) -> bool {
+    let current = state.iterator.current();
+
+    (current.kind == TokenKind::Identifier
+        || identifier::is_soft_reserved_identifier(&current.kind))
+        && state.iterator.lookahead(1).kind == TokenKind::Colon
+}
+
 fn union(state: &mut State, type_definition: TypeDefinition) -> ParseResult<TypeDefinition> {
     utils::skip(state, TokenKind::Pipe)?;
 
@@ -433,7 +798,7 @@ fn union(state: &mut State, type_definition: TypeDefinition) -> ParseResult<Type
     loop {
         let current = state.iterator.current();
         let type_definition = if current.kind == TokenKind::LeftParen {
-            let left_parenthesis = current.position;
+            let left_parenthesis = current.start();
             state.iterator.next();
             let other = atomic(state)?;
 
@@ -470,7 +835,7 @@ fn intersection(state: &mut State, type_definition: TypeDefinition) -> ParseResu
     loop {
         let current = state.iterator.current();
         let type_definition = if current.kind == TokenKind::LeftParen {
-            let left_parenthesis = current.position;
+            let left_parenthesis = current.start();
             state.iterator.next();
             let other = atomic(state)?;
 
@@ -490,7 +855,10 @@ fn intersection(state: &mut State, type_definition: TypeDefinition) -> ParseResu
         type_definitions.push(type_definition);
 
         if state.iterator.current().kind == TokenKind::Ampersand
-            && !matches!(state.iterator.lookahead(1).kind, TokenKind::Ampersand)
+            && !matches!(
+                state.iterator.lookahead(1).kind,
+                TokenKind::Ampersand | TokenKind::Variable | TokenKind::Ellipsis
+            )
         {
             utils::skip(state, TokenKind::Ampersand)?;
         } else {
@@ -4,6 +4,7 @@ use crate::parser::internal::utils;
 use crate::parser::result::ParseResult;
 use crate::parser::state::State;
 use crate::tree::definition::r#use::UseDefinition;
+use crate::tree::definition::r#use::UseDefinitionGroupMember;
 use crate::tree::definition::r#use::UseDefinitionSymbolAlias;
 
 pub fn use_definition(state: &mut State) -> ParseResult<UseDefinition> {
@@ -16,42 +17,73 @@ pub fn use_definition(state: &mut State) -> ParseResult<UseDefinition> {
             r#use,
             function: utils::skip_keyword(state, TokenKind::Function)?,
             name: identifier::fully_qualified_type_identifier(state)?,
-            alias: if state.iterator.current().kind == TokenKind::As {
-                Some(UseDefinitionSymbolAlias {
-                    r#as: utils::skip_keyword(state, TokenKind::As)?,
-                    alias: identifier::classname_identifier(state)?,
-                })
-            } else {
-                None
-            },
+            alias: use_definition_symbol_alias(state)?,
             semicolon: utils::skip_semicolon(state)?,
         }),
         TokenKind::Const => Ok(UseDefinition::Constant {
             r#use,
             r#const: utils::skip_keyword(state, TokenKind::Const)?,
             name: identifier::fully_qualified_type_identifier(state)?,
-            alias: if state.iterator.current().kind == TokenKind::As {
-                Some(UseDefinitionSymbolAlias {
-                    r#as: utils::skip_keyword(state, TokenKind::As)?,
-                    alias: identifier::classname_identifier(state)?,
-                })
-            } else {
-                None
-            },
+            alias: use_definition_symbol_alias(state)?,
             semicolon: utils::skip_semicolon(state)?,
         }),
-        _ => Ok(UseDefinition::Default {
-            r#use,
-            name: identifier::fully_qualified_type_identifier(state)?,
-            alias: if state.iterator.current().kind == TokenKind::As {
-                Some(UseDefinitionSymbolAlias {
-                    r#as: utils::skip_keyword(state, TokenKind::As)?,
-                    alias: identifier::classname_identifier(state)?,
-                })
-            } else {
-                None
-            },
-            semicolon: utils::skip_semicolon(state)?,
+        _ => {
+            let name = identifier::fully_qualified_type_identifier(state)?;
+
+            if name.value.ends_with(b"\\") && state.iterator.current().kind == TokenKind::LeftBrace
+            {
+                return Ok(UseDefinition::Group {
+                    r#use,
+                    prefix: name,
+                    left_brace: utils::skip_left_brace(state)?,
+                    members: utils::at_least_one_comma_separated(
+                        state,
+                        &use_definition_group_member,
+                        TokenKind::RightBrace,
+                    )?,
+                    right_brace: utils::skip_right_brace(state)?,
+                    semicolon: utils::skip_semicolon(state)?,
+                });
+            }
+
+            Ok(UseDefinition::Default {
+                r#use,
+                name,
+                alias: use_definition_symbol_alias(state)?,
+                semicolon: utils::skip_semicolon(state)?,
+            })
+        }
+    }
+}
+
+fn use_definition_group_member(state: &mut State) -> ParseResult<UseDefinitionGroupMember> {
+    let current = state.iterator.current();
+
+    match current.kind {
+        TokenKind::Function => Ok(UseDefinitionGroupMember::Function {
+            function: utils::skip_keyword(state, TokenKind::Function)?,
+            name: identifier::namespace_identifier(state)?,
+            alias: use_definition_symbol_alias(state)?,
+        }),
+        TokenKind::Const => Ok(UseDefinitionGroupMember::Constant {
+            r#const: utils::skip_keyword(state, TokenKind::Const)?,
+            name: identifier::namespace_identifier(state)?,
+            alias: use_definition_symbol_alias(state)?,
+        }),
+        _ => Ok(UseDefinitionGroupMember::Default {
+            name: identifier::namespace_identifier(state)?,
+            alias: use_definition_symbol_alias(state)?,
         }),
     }
 }
+
+fn use_definition_symbol_alias(state: &mut State) -> ParseResult<Option<UseDefinitionSymbolAlias>> {
+    if state.iterator.current().kind != TokenKind::As {
+        return Ok(None);
+    }
+
+    Ok(Some(UseDefinitionSymbolAlias {
+        r#as: utils::skip_keyword(state, TokenKind::As)?,
+        alias: identifier::classname_identifier(state)?,
+    }))
+}
@@ -0,0 +1,311 @@
+use ara_source::source::Source;
+use smallvec::SmallVec;
+
+use crate::lexer;
+use crate::lexer::byte_string::ByteString;
+use crate::lexer::iterator::TokenIterator;
+use crate::parser::internal::definition::r#type;
+use crate::parser::options::ParserOptions;
+use crate::parser::state::State;
+use crate::tree::comment::Comment;
+use crate::tree::comment::CommentAttachments;
+use crate::tree::comment::CommentFormat;
+use crate::tree::comment::CommentGroup;
+use crate::tree::definition::class::ClassDefinitionMember;
+use crate::tree::definition::function::MethodDefinition;
+use crate::tree::definition::interface::InterfaceDefinitionMember;
+use crate::tree::definition::r#enum::BackedEnumMemberDefinition;
+use crate::tree::definition::r#enum::EnumDefinition;
+use crate::tree::definition::r#enum::UnitEnumMemberDefinition;
+use crate::tree::definition::r#type::TypeDefinition;
+use crate::tree::definition::template::TemplateBoundsDefinition;
+use crate::tree::definition::template::TemplateDefinition;
+use crate::tree::definition::template::TemplateDefinitionTypeConstraint;
+use crate::tree::definition::template::TemplateDefinitionVariance;
+use crate::tree::definition::template::TemplateGroupDefinition;
+use crate::tree::definition::template::TemplateGroupDefinitionMember;
+use crate::tree::definition::Definition;
+use crate::tree::identifier::Identifier;
+use crate::tree::span::Span;
+use crate::tree::token::Keyword;
+use crate::tree::utils::CommaSeparated;
+use crate::tree::Node;
+use crate::tree::Tree;
+
+pub fn templates(source: &Source, tree: &mut Tree) {
+    definitions(source, &tree.comments, &mut tree.definitions.definitions);
+
+    tree.refresh_ids();
+}
+
+fn definitions(source: &Source, table: &CommentAttachments, definitions: &mut [Definition]) {
+    for definition in definitions {
+        match definition {
+            Definition::Namespace(namespace) => {
+                self::definitions(source, table, &mut namespace.definitions);
+            }
+            Definition::Function(function) if function.templates.is_none() => {
+                function.templates = template_group_definition(
+                    source,
+                    table.group(&function.comments),
+                    function.name.final_position(),
+                );
+            }
+            Definition::Interface(interface) => {
+                if interface.templates.is_none() {
+                    interface.templates = template_group_definition(
+                        source,
+                        table.group(&interface.comments),
+                        interface.name.final_position(),
+                    );
+                }
+
+                for member in &mut interface.body.members {
+                    if let InterfaceDefinitionMember::Method(method) = member {
+                        method_definition(source, table, method);
+                    }
+                }
+            }
+            Definition::Class(class) => {
+                if class.templates.is_none() {
+                    class.templates = template_group_definition(
+                        source,
+                        table.group(&class.comments),
+                        class.name.final_position(),
+                    );
+                }
+
+                for member in &mut class.body.members {
+                    if let ClassDefinitionMember::Method(method) = member {
+                        method_definition(source, table, method);
+                    }
+                }
+            }
+            Definition::Enum(r#enum) => match r#enum.as_mut() {
+                EnumDefinition::Unit(r#enum) => {
+                    for member in &mut r#enum.body.members {
+                        if let UnitEnumMemberDefinition::Method(method) = member {
+                            method_definition(source, table, method);
+                        }
+                    }
+                }
+                EnumDefinition::Backed(r#enum) => {
+                    for member in &mut r#enum.body.members {
+                        if let BackedEnumMemberDefinition::Method(method) = member {
+                            method_definition(source, table, method);
+                        }
+                    }
+                }
+            },
+            _ => {}
+        }
+    }
+}
+
+fn method_definition(source: &Source, table: &CommentAttachments, method: &mut MethodDefinition) {
+    if method.templates.is_none() {
+        method.templates = template_group_definition(
+            source,
+            table.group(&method.comments),
+            method.name.final_position(),
+        );
+    }
+}
+
+/// Build a template group from the `@template` tags of the last docblock in the given comments.
+///
+/// The synthesized group is placed at the given position, right after the name of its definition,
+/// where a declared group would be, and tags that cannot be understood are skipped.
+fn template_group_definition(
+    source: &Source,
+    comments: &[Comment],
+    position: usize,
+) -> Option<TemplateGroupDefinition> {
+    let docblock = comments
+        .iter()
+        .rev()
+        .find(|comment| comment.format == CommentFormat::Document)?;
+
+    let mut inner = vec![];
+    let mut offset = docblock.position;
+    for line in docblock.content.split(|byte| *byte == b'\n') {
+        if let Some(template) = template_definition(source, line, offset) {
+            inner.push(TemplateGroupDefinitionMember::Type(template));
+        }
+
+        offset += line.len() + 1;
+    }
+
+    if inner.is_empty() {
+        return None;
+    }
+
+    Some(TemplateGroupDefinition {
+        comments: CommentGroup::default(),
+        less_than: position,
+        members: CommaSeparated {
+            inner,
+            commas: SmallVec::new(),
+        },
+        greater_than: position,
+        docblock: Some(Span::new(
+            docblock.position,
+            docblock.position + docblock.content.len(),
+        )),
+    })
+}
+
+/// Parse a single docblock line such as ` * @template-covariant T of Foo`.
+fn template_definition(source: &Source, line: &[u8], offset: usize) -> Option<TemplateDefinition> {
+    let tag_start = line.iter().position(|byte| *byte == b'@')?;
+    if !line[..tag_start]
+        .iter()
+        .all(|byte| matches!(byte, b' ' | b'\t' | b'\r' | b'*' | b'/'))
+    {
+        return None;
+    }
+
+    let tag_end = word_end(line, tag_start);
+    let variance = match &line[tag_start..tag_end] {
+        b"@template" | b"@psalm-template" | b"@phpstan-template" => {
+            TemplateDefinitionVariance::Invaraint
+        }
+        b"@template-covariant" | b"@psalm-template-covariant" | b"@phpstan-template-covariant" => {
+            TemplateDefinitionVariance::Covariance(offset + tag_start)
+        }
+        _ => return None,
+    };
+
+    let name_start = whitespace_end(line, tag_end);
+    let name_end = identifier_end(line, name_start);
+    if name_start == name_end || line[name_start].is_ascii_digit() {
+        return None;
+    }
+
+    let name = Identifier {
+        position: offset + name_start,
+        value: ByteString::from(&line[name_start..name_end]),
+    };
+
+    let keyword_start = whitespace_end(line, name_end);
+    let keyword_end = word_end(line, keyword_start);
+    let constraint = match &line[keyword_start..keyword_end] {
+        value @ (b"of" | b"as") => {
+            let type_start = whitespace_end(line, keyword_end);
+
+            TemplateDefinitionTypeConstraint::SubType(
+                Keyword::new(ByteString::from(value), offset + keyword_start),
+                TemplateBoundsDefinition {
+                    inner: vec![type_definition(
+                        source,
+                        &line[type_start..],
+                        offset + type_start,
+                    )?],
+                    pluses: vec![],
+                },
+            )
+        }
+        _ => TemplateDefinitionTypeConstraint::None,
+    };
+
+    Some(TemplateDefinition {
+        variance,
+        ellipsis: None,
+        name,
+        constraint,
+    })
+}
+
+/// Parse the type at the start of the given docblock fragment, ignoring any trailing description.
+fn type_definition(source: &Source, fragment: &[u8], offset: usize) -> Option<TypeDefinition> {
+    let fragment = Source::inline(source.kind, std::str::from_utf8(fragment).ok()?);
+    let mut tokens = lexer::lex(&fragment).ok()?;
+    for token in &mut tokens {
+        token.position += offset as u32;
+    }
+
+    let mut iterator = TokenIterator::new(&tokens);
+    let mut state = State::new(source, &mut iterator, ParserOptions::default());
+
+    let type_definition = r#type::type_definition(&mut state).ok()?;
+    if !state.issues.is_empty() || state.ignored_shift_at.is_some() {
+        return None;
+    }
+
+    Some(type_definition)
+}
+
+fn whitespace_end(line: &[u8], start: usize) -> usize {
+    let mut end = start;
+    while end < line.len() && matches!(line[end], b' ' | b'\t' | b'\r') {
+        end += 1;
+    }
+
+    end
+}
+
+fn word_end(line: &[u8], start: usize) -> usize {
+    let mut end = start;
+    while end < line.len() && !matches!(line[end], b' ' | b'\t' | b'\r') {
+        end += 1;
+    }
+
+    end
+}
+
+fn identifier_end(line: &[u8], start: usize) -> usize {
+    let mut end = start;
+    while end < line.len() && matches!(line[end], crate::ident!()) {
+        end += 1;
+    }
+
+    end
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser;
+    use crate::tree::locate;
+    use crate::tree::validate;
+    use ara_source::source::SourceKind;
+
+    #[test]
+    fn test_docblock_templates_are_synthesized() {
+        let source = Source::inline(
+            SourceKind::Script,
+            "/**\n * @template-covariant T of Foo the item\n * @template U\n */\nfunction foo(): void {}\n\n/**\n * @template V\n */\nfunction bar<W>(): void {}\n",
+        );
+
+        let mut tree = parser::parse(&source).unwrap();
+        templates(&source, &mut tree);
+
+        let (foo, bar) = match &tree.definitions.definitions[..] {
+            [Definition::Function(foo), Definition::Function(bar)] => (foo, bar),
+            _ => unreachable!(),
+        };
+
+        let templates = foo.templates.as_ref().unwrap();
+        assert_eq!(templates.docblock, Some(Span::new(0, 63)));
+        assert_eq!(templates.to_string(), "<+T of Foo, U>");
+        assert_eq!(templates.span(), Span::new(76, 76));
+
+        let bar_templates = bar.templates.as_ref().unwrap();
+        assert!(bar_templates.docblock.is_none());
+        assert_eq!(bar_templates.to_string(), "<W>");
+
+        assert!(validate(&tree).is_ok());
+        assert_eq!(
+            locate(&tree, 75)
+                .iter()
+                .map(|node| node.get_description())
+                .collect::<Vec<String>>(),
+            vec![
+                "tree definition",
+                "function definition",
+                "function definition",
+                "identifier",
+            ]
+        );
+    }
+}
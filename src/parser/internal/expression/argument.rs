@@ -6,6 +6,7 @@ use crate::parser::result::ParseResult;
 use crate::parser::state::State;
 use crate::tree::expression::argument::ArgumentExpression;
 use crate::tree::expression::argument::ArgumentListExpression;
+use crate::tree::expression::Expression;
 
 pub fn argument_list_expression(state: &mut State) -> ParseResult<ArgumentListExpression> {
     Ok(ArgumentListExpression {
@@ -25,7 +26,7 @@ fn argument_expression(state: &mut State) -> ParseResult<ArgumentExpression> {
     {
         let name = identifier::identifier_maybe_reserved(state)?;
         let colon = utils::skip(state, TokenKind::Colon)?;
-        let value = expression::create(state)?;
+        let value = argument_value(state)?;
 
         return Ok(ArgumentExpression::Named {
             comments,
@@ -36,9 +37,9 @@ fn argument_expression(state: &mut State) -> ParseResult<ArgumentExpression> {
     }
 
     if current.kind == TokenKind::Ellipsis {
-        let ellipsis = current.position;
+        let ellipsis = current.start();
         state.iterator.next();
-        let value = expression::create(state)?;
+        let value = argument_value(state)?;
 
         Ok(ArgumentExpression::Spread {
             comments,
@@ -46,11 +47,11 @@ fn argument_expression(state: &mut State) -> ParseResult<ArgumentExpression> {
             value,
         })
     } else {
-        let value = expression::create(state)?;
+        let value = argument_value(state)?;
         let current = state.iterator.current();
 
         if current.kind == TokenKind::Ellipsis {
-            let ellipsis = current.position;
+            let ellipsis = current.start();
             state.iterator.next();
 
             Ok(ArgumentExpression::ReverseSpread {
@@ -63,3 +64,19 @@ fn argument_expression(state: &mut State) -> ParseResult<ArgumentExpression> {
         }
     }
 }
+
+/// Parse the value of an argument, reporting a by-reference `&` preceding it, as in PHP's
+/// `foo(&$bar)`.
+fn argument_value(state: &mut State) -> ParseResult<Expression> {
+    let current = state.iterator.current();
+    if current.kind == TokenKind::Ampersand {
+        state.iterator.next();
+
+        crate::parser_report!(
+            state,
+            argument_cannot_be_passed_by_reference(current.start())
+        );
+    }
+
+    expression::create(state)
+}
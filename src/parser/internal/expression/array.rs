@@ -1,17 +1,24 @@
 use crate::lexer::token::TokenKind;
 use crate::parser::internal::expression;
+use crate::parser::internal::expression::generic;
 use crate::parser::internal::utils;
 use crate::parser::result::ParseResult;
 use crate::parser::state::State;
+use crate::tree::downcast;
 use crate::tree::expression::array::DictElementExpression;
 use crate::tree::expression::array::DictExpression;
 use crate::tree::expression::array::VecElementExpression;
 use crate::tree::expression::array::VecExpression;
+use crate::tree::expression::Expression;
+use crate::tree::token::Keyword;
+use crate::tree::utils::CommaSeparated;
+use crate::tree::Node;
 
 pub fn vec_expression(state: &mut State) -> ParseResult<VecExpression> {
     Ok(VecExpression {
         comments: state.iterator.comments(),
         vec: utils::skip_keyword(state, TokenKind::Vec)?,
+        generics: generic::literal_generic_group(state)?,
         left_bracket: utils::skip(state, TokenKind::LeftBracket)?,
         elements: utils::comma_separated(
             state,
@@ -30,18 +37,125 @@ pub fn dict_expression(state: &mut State) -> ParseResult<DictExpression> {
     Ok(DictExpression {
         comments: state.iterator.comments(),
         dict: utils::skip_keyword(state, TokenKind::Dict)?,
+        generics: generic::literal_generic_group(state)?,
         left_bracket: utils::skip(state, TokenKind::LeftBracket)?,
-        elements: utils::comma_separated(
+        elements: utils::comma_separated(state, &dict_element_expression, TokenKind::RightBracket)?,
+        right_bracket: utils::skip(state, TokenKind::RightBracket)?,
+    })
+}
+
+/// Parse a PHP `array(...)` literal, which is not supported, as a `vec[...]` literal, or as a
+/// `dict[...]` literal if its first element has a key, reporting an issue with a fix.
+pub fn array_literal_expression(state: &mut State) -> ParseResult<Expression> {
+    let comments = state.iterator.comments();
+    let array = state.iterator.current();
+    state.iterator.next();
+    let left_parenthesis = utils::skip_left_parenthesis(state)?;
+
+    let first = if state.iterator.current().kind == TokenKind::RightParen {
+        None
+    } else {
+        Some(expression::create(state)?)
+    };
+
+    let expression = if state.iterator.current().kind == TokenKind::DoubleArrow {
+        let first = DictElementExpression {
+            // the key is present, as `=>` can not start an expression.
+            key: first.unwrap(),
+            double_arrow: utils::skip(state, TokenKind::DoubleArrow)?,
+            value: expression::create(state)?,
+        };
+
+        let elements = rest(state, first, &dict_element_expression)?;
+        let right_parenthesis = utils::skip_right_parenthesis(state)?;
+
+        crate::parser_report!(
             state,
-            &|state| {
-                Ok(DictElementExpression {
-                    key: expression::create(state)?,
-                    double_arrow: utils::skip(state, TokenKind::DoubleArrow)?,
+            array_literal_not_supported(array, left_parenthesis, right_parenthesis, true)
+        );
+
+        Expression::Dict(Box::new(DictExpression {
+            comments,
+            dict: Keyword::new("dict".into(), array.start()),
+            generics: None,
+            left_bracket: left_parenthesis,
+            elements,
+            right_bracket: right_parenthesis,
+        }))
+    } else {
+        let elements = match first {
+            Some(value) => rest(state, VecElementExpression { value }, &|state| {
+                Ok(VecElementExpression {
                     value: expression::create(state)?,
                 })
+            })?,
+            None => CommaSeparated {
+                inner: vec![],
+                commas: Default::default(),
             },
-            TokenKind::RightBracket,
-        )?,
-        right_bracket: utils::skip(state, TokenKind::RightBracket)?,
+        };
+        let right_parenthesis = utils::skip_right_parenthesis(state)?;
+
+        crate::parser_report!(
+            state,
+            array_literal_not_supported(array, left_parenthesis, right_parenthesis, false)
+        );
+
+        Expression::Vec(Box::new(VecExpression {
+            comments,
+            vec: Keyword::new("vec".into(), array.start()),
+            generics: None,
+            left_bracket: left_parenthesis,
+            elements,
+            right_bracket: right_parenthesis,
+        }))
+    };
+
+    Ok(expression)
+}
+
+/// Report the keys of the dict literals within the given node, which is part of a constant
+/// expression, that are neither constant, nor variables.
+pub fn validate_keys(state: &mut State, node: &dyn Node) {
+    if let Some(dict) = downcast::<DictExpression>(node) {
+        for element in &dict.elements.inner {
+            if !element.key.is_constant(false) && !matches!(element.key, Expression::Variable(_)) {
+                crate::parser_report!(state, dict_key_must_be_constant(&element.key));
+            }
+        }
+    }
+
+    for child in node.children() {
+        validate_keys(state, child);
+    }
+}
+
+fn dict_element_expression(state: &mut State) -> ParseResult<DictElementExpression> {
+    Ok(DictElementExpression {
+        key: expression::create(state)?,
+        double_arrow: utils::skip(state, TokenKind::DoubleArrow)?,
+        value: expression::create(state)?,
     })
 }
+
+/// Parse the elements of an `array(...)` literal following its already parsed first element.
+fn rest<T: Node>(
+    state: &mut State,
+    first: T,
+    func: &dyn Fn(&mut State) -> ParseResult<T>,
+) -> ParseResult<CommaSeparated<T>> {
+    if state.iterator.current().kind != TokenKind::Comma {
+        return Ok(CommaSeparated {
+            inner: vec![first],
+            commas: Default::default(),
+        });
+    }
+
+    let comma = utils::skip(state, TokenKind::Comma)?;
+    let mut elements = utils::comma_separated(state, func, TokenKind::RightParen)?;
+
+    elements.inner.insert(0, first);
+    elements.commas.insert(0, comma);
+
+    Ok(elements)
+}
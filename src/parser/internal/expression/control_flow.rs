@@ -1,3 +1,5 @@
+use smallvec::SmallVec;
+
 use crate::lexer::token::TokenKind;
 use crate::parser::internal::expression;
 use crate::parser::internal::utils;
@@ -26,7 +28,7 @@ pub fn match_expression(state: &mut State) -> ParseResult<MatchExpression> {
             left_brace: utils::skip_left_brace(state)?,
             arms: {
                 let mut items = Vec::new();
-                let mut commas = Vec::new();
+                let mut commas = SmallVec::new();
 
                 while state.iterator.current().kind != TokenKind::RightBrace {
                     let current = state.iterator.current();
@@ -54,7 +56,7 @@ pub fn match_expression(state: &mut State) -> ParseResult<MatchExpression> {
                     let current = state.iterator.current();
                     if current.kind == TokenKind::Comma {
                         state.iterator.next();
-                        commas.push(current.position);
+                        commas.push(current.start());
                     } else {
                         break;
                     }
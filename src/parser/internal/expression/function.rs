@@ -1,4 +1,5 @@
 use crate::lexer::token::TokenKind;
+use crate::parser::internal::definition::function;
 use crate::parser::internal::definition::modifier;
 use crate::parser::internal::definition::parameter;
 use crate::parser::internal::definition::r#type;
@@ -21,7 +22,7 @@ pub fn anonymous_function_expression(
     let attributes = state.get_attributes();
     let modifiers = modifier::collect(state)?;
 
-    let function = utils::skip_keyword(state, TokenKind::Function)?;
+    let function = function::function_keyword(state, TokenKind::Function)?;
     let parameters = parameter::function_like_parameter_list_definition(state)?;
 
     let current = state.iterator.current();
@@ -73,7 +74,7 @@ pub fn arrow_function_expression(state: &mut State) -> ParseResult<ArrowFunction
         comments,
         attributes,
         modifiers,
-        r#fn: utils::skip_keyword(state, TokenKind::Fn)?,
+        r#fn: function::function_keyword(state, TokenKind::Fn)?,
         parameters: parameter::function_like_parameter_list_definition(state)?,
         return_type: FunctionLikeReturnTypeDefinition {
             colon: utils::skip_colon(state)?,
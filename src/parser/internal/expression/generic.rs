@@ -1,3 +1,5 @@
+use smallvec::SmallVec;
+
 use crate::lexer::token::TokenKind;
 use crate::parser::internal::definition::r#type;
 use crate::parser::internal::utils;
@@ -7,15 +9,25 @@ use crate::tree::expression::generic::GenericGroupExpression;
 use crate::tree::utils::CommaSeparated;
 
 pub fn generic_group(state: &mut State) -> ParseResult<Option<GenericGroupExpression>> {
-    if state.iterator.current().kind != TokenKind::Generic {
+    group(state, TokenKind::Generic)
+}
+
+/// Parse the generic arguments of a `vec`, or `dict`, literal, e.g. `<int>` in `vec<int>[1]`,
+/// which are not preceded by `::`.
+pub fn literal_generic_group(state: &mut State) -> ParseResult<Option<GenericGroupExpression>> {
+    group(state, TokenKind::LessThan)
+}
+
+fn group(state: &mut State, opening: TokenKind) -> ParseResult<Option<GenericGroupExpression>> {
+    if state.iterator.current().kind != opening {
         return Ok(None);
     }
 
     Ok(Some(GenericGroupExpression {
-        double_colon_less_than: utils::skip(state, TokenKind::Generic)?,
+        double_colon_less_than: utils::skip(state, opening)?,
         types: {
             let mut inner = vec![];
-            let mut commas = vec![];
+            let mut commas = SmallVec::new();
 
             let mut current = state.iterator.current();
             while current.kind != TokenKind::GreaterThan && current.kind != TokenKind::RightShift {
@@ -26,7 +38,7 @@ pub fn generic_group(state: &mut State) -> ParseResult<Option<GenericGroupExpres
                     break;
                 }
 
-                commas.push(current.position);
+                commas.push(current.start());
 
                 state.iterator.next();
                 current = state.iterator.current();
@@ -40,11 +52,11 @@ pub fn generic_group(state: &mut State) -> ParseResult<Option<GenericGroupExpres
             if let Some(token) = state.ignored_shift_at {
                 utils::skip(state, TokenKind::RightShift)?;
                 state.ignored_shift_at = None;
-                token.position + 1
+                token.start() + 1
             } else if current.kind == TokenKind::RightShift {
                 state.ignored_shift_at = Some(current);
 
-                current.position
+                current.start()
             } else {
                 utils::skip(state, TokenKind::GreaterThan)?
             }
@@ -29,7 +29,7 @@ pub fn infix(
     let comments = state.iterator.comments();
 
     let current = state.iterator.current();
-    let position = current.position;
+    let position = current.start();
     state.iterator.next();
     let op = state.iterator.current();
 
@@ -43,7 +43,7 @@ pub fn infix(
                     comments,
                     condition: Box::new(left),
                     question: position,
-                    colon: op.position,
+                    colon: op.start(),
                     if_false: Box::new(expression::create(state)?),
                 })
             } else {
@@ -65,30 +65,32 @@ pub fn infix(
                 if_false: Box::new(expression::create(state)?),
             })
         }
-        TokenKind::Into => Expression::TypeOperation(TypeOperationExpression::Into {
+        TokenKind::Into => Expression::TypeOperation(Box::new(TypeOperationExpression::Into {
             comments,
             left: Box::new(left),
             into: Keyword::new(current.value.clone(), position),
             right: r#type::type_definition(state)?,
-        }),
-        TokenKind::Is => Expression::TypeOperation(TypeOperationExpression::Is {
+        })),
+        TokenKind::Is => Expression::TypeOperation(Box::new(TypeOperationExpression::Is {
             comments,
             left: Box::new(left),
             is: Keyword::new(current.value.clone(), position),
             right: r#type::type_definition(state)?,
-        }),
-        TokenKind::As => Expression::TypeOperation(TypeOperationExpression::As {
+        })),
+        TokenKind::As => Expression::TypeOperation(Box::new(TypeOperationExpression::As {
             comments,
             left: Box::new(left),
             r#as: Keyword::new(current.value.clone(), position),
             right: r#type::type_definition(state)?,
-        }),
-        TokenKind::Instanceof => Expression::TypeOperation(TypeOperationExpression::Instanceof {
-            comments,
-            left: Box::new(left),
-            r#instanceof: Keyword::new(current.value.clone(), position),
-            right: identifier::fully_qualified_type_identifier_including_self(state)?,
-        }),
+        })),
+        TokenKind::Instanceof => {
+            Expression::TypeOperation(Box::new(TypeOperationExpression::Instanceof {
+                comments,
+                left: Box::new(left),
+                r#instanceof: Keyword::new(current.value.clone(), position),
+                right: identifier::fully_qualified_type_identifier_including_self(state)?,
+            }))
+        }
         TokenKind::In => Expression::ArrayOperation(ArrayOperationExpression::In {
             comments,
             item: Box::new(left),
@@ -97,7 +99,7 @@ pub fn infix(
         }),
         TokenKind::DoubleDot => {
             if op.kind == TokenKind::Equals {
-                let equals = op.position;
+                let equals = op.start();
                 state.iterator.next();
                 let expr = expression::for_precedence(state, Precedence::Range)?;
 
@@ -128,7 +130,7 @@ pub fn infix(
             let left = Box::new(left);
 
             if op.kind == TokenKind::GreaterThan {
-                let greater_than = op.position;
+                let greater_than = op.start();
                 state.iterator.next();
                 let right = Box::new(expression::for_precedence(state, right_precedence)?);
 
@@ -448,7 +450,14 @@ pub fn infix(
 #[inline(always)]
 pub fn is_infix(state: &mut State, t: &TokenKind) -> bool {
     if t == &TokenKind::As {
-        return state.iterator.lookahead(1).kind != TokenKind::Variable;
+        // `as` followed by a variable, or by a tuple pattern, e.g. `($a, $b)`, is part of a
+        // `foreach` iterator, rather than a type operation.
+        let mut n = 1;
+        while state.iterator.lookahead(n).kind == TokenKind::LeftParen {
+            n += 1;
+        }
+
+        return state.iterator.lookahead(n).kind != TokenKind::Variable;
     }
 
     matches!(
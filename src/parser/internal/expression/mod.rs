@@ -47,11 +47,30 @@ pub fn create(state: &mut State) -> ParseResult<Expression> {
     for_precedence(state, Precedence::Lowest)
 }
 
+/// Parse an expression in a context requiring a constant expression, e.g. the value of a
+/// constant, reporting the keys of its dict literals that are not constant, see `array::validate_keys`.
+pub fn constant(state: &mut State) -> ParseResult<Expression> {
+    let expression = create(state)?;
+
+    array::validate_keys(state, &expression);
+
+    Ok(expression)
+}
+
 pub fn null_coalesce_precedence(state: &mut State) -> ParseResult<Expression> {
     for_precedence(state, Precedence::NullCoalesce)
 }
 
 pub fn for_precedence(state: &mut State, precedence: Precedence) -> ParseResult<Expression> {
+    utils::nested(state, |state| {
+        for_precedence_within_depth(state, precedence)
+    })
+}
+
+fn for_precedence_within_depth(
+    state: &mut State,
+    precedence: Precedence,
+) -> ParseResult<Expression> {
     let mut left = left(state, &precedence)?;
 
     loop {
@@ -90,8 +109,10 @@ pub fn for_precedence(state: &mut State, precedence: Precedence) -> ParseResult<
             if right_precedence == precedence
                 && matches!(right_precedence.associativity(), Some(Associativity::Non))
             {
-                let expected: Vec<String> = vec![];
-                crate::parser_bail!(state, unexpected_token(expected, current));
+                crate::parser_bail!(
+                    state,
+                    unexpected_token(vec!["an operator of higher precedence"], current)
+                );
             }
 
             left = infix::infix(state, left, kind, right_precedence)?;
@@ -99,6 +120,8 @@ pub fn for_precedence(state: &mut State, precedence: Precedence) -> ParseResult<
             continue;
         }
 
+        state.expect("an operator");
+
         break;
     }
 
@@ -113,7 +136,7 @@ pub fn left(state: &mut State, precedence: &Precedence) -> ParseResult<Expressio
         );
     }
 
-    attributes(state, precedence)
+    left_expression(state, precedence)
 }
 
 macro_rules! expressions {
@@ -121,23 +144,26 @@ macro_rules! expressions {
         using($state:ident):
 
         $(
-            #[before($else:ident), $(precedence($precedence:expr),)? current($(|)? $( $current:pat_param )|+) $(, peek($(|)? $( $peek:pat_param )|+))?]
+            #[$(precedence($precedence:expr),)? current($(|)? $( $current:pat_param )|+) $(, peek($(|)? $( $peek:pat_param )|+))?]
             $expr:ident($out:tt)
         )+
     ) => {
-        $(
-            pub fn $expr($state: &mut State, precedence: &Precedence) -> ParseResult<Expression> {
-                $(
-                    if &$precedence < precedence {
-                        return $else($state, precedence);
-                    }
-                )?
-
-                match &$state.iterator.current().kind {
-                    $( $current )|+ $( if matches!(&$state.iterator.lookahead(1).kind, $( $peek )|+ ))? => $out,
-                    _ => $else($state, precedence),
+        /// Parse the expression starting at the current token, trying each kind of expression in
+        /// turn, within a single stack frame rather than one stack frame per kind.
+        fn left_expression($state: &mut State, precedence: &Precedence) -> ParseResult<Expression> {
+            let current = &$state.iterator.current().kind;
+
+            $(
+                if $( &$precedence >= precedence && )? matches!(current, $( $current )|+) $( && matches!(&$state.iterator.lookahead(1).kind, $( $peek )|+) )? {
+                    return $expr($state);
                 }
-            }
+            )+
+
+            unexpected_token($state)
+        }
+
+        $(
+            pub fn $expr($state: &mut State) -> ParseResult<Expression> $out
         )+
     };
 }
@@ -145,7 +171,7 @@ macro_rules! expressions {
 expressions! {
     using(state):
 
-    #[before(functional_expression), current(TokenKind::Attribute)]
+    #[current(TokenKind::Attribute)]
     attributes({
         attribute::gather(state)?;
 
@@ -153,16 +179,16 @@ expressions! {
 
         match &current.kind {
             TokenKind::Static if state.iterator.lookahead(1).kind == TokenKind::Function => {
-                Ok(Expression::AnonymousFunction(function::anonymous_function_expression(state)?))
+                Ok(Expression::AnonymousFunction(Box::new(function::anonymous_function_expression(state)?)))
             }
             TokenKind::Static if state.iterator.lookahead(1).kind == TokenKind::Fn => {
-                Ok(Expression::ArrowFunction(function::arrow_function_expression(state)?))
+                Ok(Expression::ArrowFunction(Box::new(function::arrow_function_expression(state)?)))
             }
             TokenKind::Function => {
-                Ok(Expression::AnonymousFunction(function::anonymous_function_expression(state)?))
+                Ok(Expression::AnonymousFunction(Box::new(function::anonymous_function_expression(state)?)))
             }
             TokenKind::Fn => {
-                Ok(Expression::ArrowFunction(function::arrow_function_expression(state)?))
+                Ok(Expression::ArrowFunction(Box::new(function::arrow_function_expression(state)?)))
             }
             _ => {
                 crate::parser_report!(state, missing_item_expression_after_attributes);
@@ -175,9 +201,9 @@ expressions! {
         }
     })
 
-    #[before(static_arrow_function), current(TokenKind::Dollar), peek(TokenKind::Generic | TokenKind::LeftParen)]
+    #[current(TokenKind::Dollar), peek(TokenKind::Generic | TokenKind::LeftParen)]
     functional_expression({
-        let dollar = state.iterator.current().position;
+        let dollar = state.iterator.current().start();
         state.iterator.next();
         Ok(Expression::FunctionalOperation(FunctionalOperationExpression::Expression {
             comments: state.iterator.comments(),
@@ -189,27 +215,27 @@ expressions! {
         }))
     })
 
-    #[before(static_anonymous_function), current(TokenKind::Static), peek(TokenKind::Fn)]
+    #[current(TokenKind::Static), peek(TokenKind::Fn)]
     static_arrow_function({
-        Ok(Expression::ArrowFunction(function::arrow_function_expression(state)?))
+        Ok(Expression::ArrowFunction(Box::new(function::arrow_function_expression(state)?)))
     })
 
-    #[before(arrow_function), current(TokenKind::Static), peek(TokenKind::Function)]
+    #[current(TokenKind::Static), peek(TokenKind::Function)]
     static_anonymous_function({
-        Ok(Expression::AnonymousFunction(function::anonymous_function_expression(state)?))
+        Ok(Expression::AnonymousFunction(Box::new(function::anonymous_function_expression(state)?)))
     })
 
-    #[before(anonymous_function), current(TokenKind::Fn)]
+    #[current(TokenKind::Fn)]
     arrow_function({
-        Ok(Expression::ArrowFunction(function::arrow_function_expression(state)?))
+        Ok(Expression::ArrowFunction(Box::new(function::arrow_function_expression(state)?)))
     })
 
-    #[before(exit), current(TokenKind::Function)]
+    #[current(TokenKind::Function)]
     anonymous_function({
-        Ok(Expression::AnonymousFunction(function::anonymous_function_expression(state)?))
+        Ok(Expression::AnonymousFunction(Box::new(function::anonymous_function_expression(state)?)))
     })
 
-    #[before(reserved_identifier_function_call), current(TokenKind::Exit)]
+    #[current(TokenKind::Exit)]
     exit({
         let exit = utils::skip_keyword(state, TokenKind::Exit)?;
         if state.iterator.current().kind == TokenKind::LeftParen {
@@ -232,14 +258,10 @@ expressions! {
         }
     })
 
-    #[before(reserved_identifier_static_call), precedence(Precedence::CallDim), current(
+    #[precedence(Precedence::CallDim), current(
         | TokenKind::True       | TokenKind::False | TokenKind::Null
         | TokenKind::Readonly   | TokenKind::Self_ | TokenKind::Parent
-        | TokenKind::Enum       | TokenKind::From  | TokenKind::Where
-        | TokenKind::Type       | TokenKind::Dict  | TokenKind::Vec
-        | TokenKind::Async      | TokenKind::Await | TokenKind::Concurrently
-        | TokenKind::Is         | TokenKind::In    | TokenKind::Into
-        | TokenKind::Using
+        | identifier::expression_keyword!()
     ), peek(TokenKind::LeftParen | TokenKind::Generic)]
     reserved_identifier_function_call({
         let ident = identifier::identifier_maybe_soft_reserved(state)?;
@@ -249,13 +271,7 @@ expressions! {
         postfix::postfix(state, lhs, op)
     })
 
-    #[before(isset), current(
-        | TokenKind::Enum   | TokenKind::From   | TokenKind::Where
-        | TokenKind::Type   | TokenKind::Vec    | TokenKind::Dict
-        | TokenKind::Async  | TokenKind::Await  | TokenKind::Concurrently
-        | TokenKind::Is     | TokenKind::In     | TokenKind::Into
-        | TokenKind::Using
-    ), peek(TokenKind::DoubleColon)]
+    #[current(identifier::expression_keyword!()), peek(TokenKind::DoubleColon)]
     reserved_identifier_static_call({
         let ident = identifier::classname_identifier(state)?;
         let lhs = Expression::Identifier(ident);
@@ -263,7 +279,7 @@ expressions! {
         postfix::postfix(state, lhs, &TokenKind::DoubleColon)
     })
 
-    #[before(unset), current(TokenKind::Isset)]
+    #[current(TokenKind::Isset)]
     isset({
         Ok(Expression::ArrayOperation(ArrayOperationExpression::Isset {
             comments: state.iterator.comments(),
@@ -272,7 +288,7 @@ expressions! {
         }))
     })
 
-    #[before(anonymous_class), current(TokenKind::Unset)]
+    #[current(TokenKind::Unset)]
     unset({
         Ok(Expression::ArrayOperation(ArrayOperationExpression::Unset {
             comments: state.iterator.comments(),
@@ -281,16 +297,16 @@ expressions! {
         }))
     })
 
-    #[before(new), current(TokenKind::New), peek(TokenKind::Class | TokenKind::Attribute)]
+    #[current(TokenKind::New), peek(TokenKind::Class | TokenKind::Attribute)]
     anonymous_class({
-        Ok(Expression::ClassOperation(
+        Ok(Expression::ClassOperation(Box::new(
             class::anonymous_initialization_class_operation_expression(state)?,
-        ))
+        )))
     })
 
-    #[before(throw), current(TokenKind::New)]
+    #[current(TokenKind::New)]
     new({
-        Ok(Expression::ClassOperation(
+        Ok(Expression::ClassOperation(Box::new(
             ClassOperationExpression::Initialization {
                 comments: state.iterator.comments(),
                 new: utils::skip_keyword(state, TokenKind::New)?,
@@ -305,10 +321,10 @@ expressions! {
                 generics: generic::generic_group(state)?,
                 arguments: argument::argument_list_expression(state)?
             }
-        ))
+        )))
     })
 
-    #[before(r#async), current(TokenKind::Throw)]
+    #[current(TokenKind::Throw)]
     throw({
         Ok(Expression::ExceptionOperation(ExceptionOperationExpression::Throw {
             comments: state.iterator.comments(),
@@ -317,36 +333,36 @@ expressions! {
         }))
     })
 
-    #[before(r#await), current(TokenKind::Async)]
+    #[current(TokenKind::Async)]
     r#async({
-        Ok(Expression::AsyncOperation(AsyncOperationExpression::Async {
+        Ok(Expression::AsyncOperation(Box::new(AsyncOperationExpression::Async {
             comments: state.iterator.comments(),
             r#async: utils::skip_keyword(state, TokenKind::Async)?,
             expression: Box::new(for_precedence(state, Precedence::Lowest)?)
-        }))
+        })))
     })
 
-    #[before(concurrently), current(TokenKind::Await)]
+    #[current(TokenKind::Await)]
     r#await({
-        Ok(Expression::AsyncOperation(AsyncOperationExpression::Await {
+        Ok(Expression::AsyncOperation(Box::new(AsyncOperationExpression::Await {
             comments: state.iterator.comments(),
             r#await: utils::skip_keyword(state, TokenKind::Await)?,
             expression: Box::new(for_precedence(state, Precedence::Lowest)?)
-        }))
+        })))
     })
 
-    #[before(r#yield), current(TokenKind::Concurrently)]
+    #[current(TokenKind::Concurrently)]
     concurrently({
-        Ok(Expression::AsyncOperation(AsyncOperationExpression::Concurrently {
+        Ok(Expression::AsyncOperation(Box::new(AsyncOperationExpression::Concurrently {
             comments: state.iterator.comments(),
             concurrently: utils::skip_keyword(state, TokenKind::Concurrently)?,
             left_brace: utils::skip_left_brace(state)?,
             expressions: utils::comma_separated(state, &create, TokenKind::RightBrace)?,
             right_brace: utils::skip_right_brace(state)?,
-        }))
+        })))
     })
 
-    #[before(clone), current(TokenKind::Yield)]
+    #[current(TokenKind::Yield)]
     r#yield({
         let r#yield = utils::skip_keyword(state, TokenKind::Yield)?;
         let comments = state.iterator.comments();
@@ -384,7 +400,7 @@ expressions! {
                     comments,
                     r#yield,
                     key,
-                    double_arrow: current.position,
+                    double_arrow: current.start(),
                     value,
                 }))
             } else {
@@ -397,16 +413,16 @@ expressions! {
         }
     })
 
-    #[before(r#true), current(TokenKind::Clone)]
+    #[current(TokenKind::Clone)]
     clone({
-        Ok(Expression::ObjectOperation(ObjectOperationExpression::Clone {
+        Ok(Expression::ObjectOperation(Box::new(ObjectOperationExpression::Clone {
             comments: state.iterator.comments(),
             clone: utils::skip_keyword(state, TokenKind::Clone)?,
             object: Box::new(for_precedence(state, Precedence::Clone)?),
-        }))
+        })))
     })
 
-    #[before(r#false), current(TokenKind::True)]
+    #[current(TokenKind::True)]
     r#true({
         Ok(Expression::Literal(Literal::True(
             LiteralTrue {
@@ -416,7 +432,7 @@ expressions! {
         )))
     })
 
-    #[before(null), current(TokenKind::False)]
+    #[current(TokenKind::False)]
     r#false({
         Ok(Expression::Literal(Literal::False(
             LiteralFalse {
@@ -426,7 +442,7 @@ expressions! {
         )))
     })
 
-    #[before(literal_integer), current(TokenKind::Null)]
+    #[current(TokenKind::Null)]
     null({
         Ok(Expression::Literal(Literal::Null(
             LiteralNull {
@@ -436,7 +452,7 @@ expressions! {
         )))
     })
 
-    #[before(literal_float), current(TokenKind::LiteralInteger)]
+    #[current(TokenKind::LiteralInteger)]
     literal_integer({
         let current = state.iterator.current();
         state.iterator.next();
@@ -444,13 +460,13 @@ expressions! {
         Ok(Expression::Literal(Literal::Integer(
             LiteralInteger {
                 comments: state.iterator.comments(),
-                position: current.position,
+                position: current.start(),
                 value: current.value.clone()
             }
         )))
     })
 
-    #[before(literal_string), current(TokenKind::LiteralFloat)]
+    #[current(TokenKind::LiteralFloat)]
     literal_float({
         let current = state.iterator.current();
         state.iterator.next();
@@ -458,13 +474,13 @@ expressions! {
         Ok(Expression::Literal(
             Literal::Float(LiteralFloat {
                 comments: state.iterator.comments(),
-                position: current.position,
+                position: current.start(),
                 value: current.value.clone()
             })
         ))
     })
 
-    #[before(dict), current(TokenKind::LiteralString)]
+    #[current(TokenKind::LiteralString)]
     literal_string({
         let current = state.iterator.current();
         state.iterator.next();
@@ -472,34 +488,35 @@ expressions! {
         Ok(Expression::Literal(
             Literal::String(LiteralString {
                 comments: state.iterator.comments(),
-                position: current.position,
+                position: current.start(),
                 value: current.value.clone()
             })
         ))
     })
 
-    #[before(vec), current(TokenKind::Dict), peek(TokenKind::LeftBracket)]
+    #[current(TokenKind::Dict), peek(TokenKind::LeftBracket | TokenKind::LessThan)]
     dict({
-        Ok(Expression::Dict(array::dict_expression(state)?))
+        Ok(Expression::Dict(Box::new(array::dict_expression(state)?)))
     })
 
-    #[before(identifier), current(TokenKind::Vec), peek(TokenKind::LeftBracket)]
+    #[current(TokenKind::Vec), peek(TokenKind::LeftBracket | TokenKind::LessThan)]
     vec({
-        Ok(Expression::Vec(array::vec_expression(state)?))
+        Ok(Expression::Vec(Box::new(array::vec_expression(state)?)))
+    })
+
+    #[current(TokenKind::Array), peek(TokenKind::LeftParen)]
+    array_literal({
+        array::array_literal_expression(state)
     })
 
-    #[before(reserved_identifier), current(TokenKind::Identifier | TokenKind::QualifiedIdentifier | TokenKind::FullyQualifiedIdentifier)]
+    #[current(TokenKind::Identifier | TokenKind::QualifiedIdentifier | TokenKind::FullyQualifiedIdentifier)]
     identifier({
         Ok(Expression::Identifier(identifier::fully_qualified_type_identifier(state)?))
     })
 
-    #[before(left_parenthesis), current(
+    #[current(
         | TokenKind::Self_  | TokenKind::Parent | TokenKind::Static
-        | TokenKind::From   | TokenKind::Enum   | TokenKind::Where
-        | TokenKind::Type   | TokenKind::Vec    | TokenKind::Dict
-        | TokenKind::Async  | TokenKind::Await  | TokenKind::Concurrently
-        | TokenKind::Is     | TokenKind::In     | TokenKind::Into
-        | TokenKind::Using
+        | identifier::expression_keyword!()
     )]
     reserved_identifier({
         let current = state.iterator.current();
@@ -507,15 +524,54 @@ expressions! {
         state.iterator.next();
 
         Ok(Expression::Identifier(Identifier {
-            position: current.position,
+            position: current.start(),
             value: current.value.clone(),
         }))
     })
 
-    #[before(r#match), current(TokenKind::LeftParen)]
+    #[current(TokenKind::LeftParen), peek(TokenKind::Identifier)]
+    legacy_cast({
+        let position = state.iterator.current().start();
+        let r#type = state.iterator.lookahead(1);
+
+        let cast = matches!(
+            r#type.value.to_ascii_lowercase().as_slice(),
+            b"int" | b"integer" | b"float" | b"double" | b"string" | b"binary" | b"bool" | b"boolean" | b"object"
+        ) && state.iterator.lookahead(2).kind == TokenKind::RightParen
+            && matches!(
+                state.iterator.lookahead(3).kind,
+                | TokenKind::Variable
+                | TokenKind::LiteralInteger
+                | TokenKind::LiteralFloat
+                | TokenKind::LiteralString
+                | TokenKind::True
+                | TokenKind::False
+                | TokenKind::Null
+                | TokenKind::LeftParen
+                | TokenKind::Identifier
+                | TokenKind::QualifiedIdentifier
+                | TokenKind::FullyQualifiedIdentifier
+            );
+
+        if !cast {
+            return left_parenthesis(state);
+        }
+
+        state.iterator.next();
+        state.iterator.next();
+        state.iterator.next();
+
+        let operand = for_precedence(state, Precedence::Prefix)?;
+
+        crate::parser_report!(state, legacy_cast_not_supported(position, r#type, &operand));
+
+        Ok(operand)
+    })
+
+    #[current(TokenKind::LeftParen)]
     left_parenthesis({
         let comments = state.iterator.comments();
-        let left_parenthesis = state.iterator.current().position;
+        let left_parenthesis = state.iterator.current().start();
         state.iterator.next();
         let expression = create(state)?;
 
@@ -527,7 +583,7 @@ expressions! {
                 comments,
                 left_parenthesis,
                 expression: Box::new(expression),
-                right_parenthesis: current.position,
+                right_parenthesis: current.start(),
             }))
         } else {
             let comma = utils::skip(state, TokenKind::Comma)?;
@@ -539,7 +595,7 @@ expressions! {
                 let mut elements = utils::at_least_one_comma_separated(state, &create, TokenKind::RightParen)?;
 
                 elements.inner = [expressions.as_slice(), elements.inner.as_slice()].concat();
-                elements.commas = [commas.as_slice(), elements.commas.as_slice()].concat();
+                elements.commas.insert_many(0, commas);
 
                 elements
             };
@@ -555,15 +611,15 @@ expressions! {
         }
     })
 
-    #[before(directory_magic_constant), current(TokenKind::Match)]
+    #[current(TokenKind::Match)]
     r#match({
-        Ok(Expression::Match(control_flow::match_expression(state)?))
+        Ok(Expression::Match(Box::new(control_flow::match_expression(state)?)))
     })
 
-    #[before(file_magic_constant), current(TokenKind::DirConstant)]
+    #[current(TokenKind::DirConstant)]
     directory_magic_constant({
         let current = state.iterator.current();
-        let position = current.position;
+        let position = current.start();
         let value = current.value.clone();
 
         state.iterator.next();
@@ -574,10 +630,10 @@ expressions! {
         }))
     })
 
-    #[before(line_magic_constant), current(TokenKind::FileConstant)]
+    #[current(TokenKind::FileConstant)]
     file_magic_constant({
         let current = state.iterator.current();
-        let position = current.position;
+        let position = current.start();
         let value = current.value.clone();
 
         state.iterator.next();
@@ -588,10 +644,10 @@ expressions! {
         }))
     })
 
-    #[before(function_magic_constant), current(TokenKind::LineConstant)]
+    #[current(TokenKind::LineConstant)]
     line_magic_constant({
         let current = state.iterator.current();
-        let position = current.position;
+        let position = current.start();
         let value = current.value.clone();
 
         state.iterator.next();
@@ -602,10 +658,10 @@ expressions! {
         }))
     })
 
-    #[before(class_magic_constant), current(TokenKind::FunctionConstant)]
+    #[current(TokenKind::FunctionConstant)]
     function_magic_constant({
         let current = state.iterator.current();
-        let position = current.position;
+        let position = current.start();
         let value = current.value.clone();
 
         state.iterator.next();
@@ -616,10 +672,10 @@ expressions! {
         }))
     })
 
-    #[before(method_magic_constant), current(TokenKind::ClassConstant)]
+    #[current(TokenKind::ClassConstant)]
     class_magic_constant({
         let current = state.iterator.current();
-        let position = current.position;
+        let position = current.start();
         let value = current.value.clone();
 
         state.iterator.next();
@@ -630,10 +686,10 @@ expressions! {
         }))
     })
 
-    #[before(namespace_magic_constant), current(TokenKind::MethodConstant)]
+    #[current(TokenKind::MethodConstant)]
     method_magic_constant({
         let current = state.iterator.current();
-        let position = current.position;
+        let position = current.start();
         let value = current.value.clone();
 
         state.iterator.next();
@@ -644,10 +700,10 @@ expressions! {
         }))
     })
 
-    #[before(numeric_prefix), current(TokenKind::NamespaceConstant)]
+    #[current(TokenKind::NamespaceConstant)]
     namespace_magic_constant({
         let current = state.iterator.current();
-        let position = current.position;
+        let position = current.start();
         let value = current.value.clone();
 
         state.iterator.next();
@@ -658,11 +714,11 @@ expressions! {
         }))
     })
 
-    #[before(bang_prefix), current(TokenKind::Decrement | TokenKind::Increment | TokenKind::Minus | TokenKind::Plus)]
+    #[current(TokenKind::Decrement | TokenKind::Increment | TokenKind::Minus | TokenKind::Plus)]
     numeric_prefix({
         let current = state.iterator.current();
 
-        let position = current.position;
+        let position = current.start();
         let op = current.kind.clone();
 
         state.iterator.next();
@@ -694,7 +750,7 @@ expressions! {
         Ok(expr)
     })
 
-    #[before(bitwise_prefix), current(TokenKind::Bang)]
+    #[current(TokenKind::Bang)]
     bang_prefix({
         Ok(Expression::LogicalOperation(LogicalOperationExpression::Not {
             comments: state.iterator.comments(),
@@ -703,7 +759,7 @@ expressions! {
         }))
     })
 
-    #[before(variable), current(TokenKind::BitwiseNot)]
+    #[current(TokenKind::BitwiseNot)]
     bitwise_prefix({
         Ok(Expression::BitwiseOperation(BitwiseOperationExpression::Not {
             comments: state.iterator.comments(),
@@ -712,21 +768,21 @@ expressions! {
         }))
     })
 
-    #[before(range_to), current(TokenKind::Variable)]
+    #[current(TokenKind::Variable)]
     variable({
         Ok(Expression::Variable(variable::parse(state)?))
     })
 
-    #[before(unexpected_token), current(TokenKind::DoubleDot)]
+    #[current(TokenKind::DoubleDot)]
     range_to({
         let comments = state.iterator.comments();
         let current = state.iterator.current();
-        let position = current.position;
+        let position = current.start();
         state.iterator.next();
 
         let current = state.iterator.current();
         if current.kind == TokenKind::Equals {
-            let equals = current.position;
+            let equals = current.start();
             state.iterator.next();
             let expr = for_precedence(state, Precedence::Range)?;
 
@@ -752,7 +808,7 @@ expressions! {
     })
 }
 
-fn unexpected_token(state: &mut State, _precedence: &Precedence) -> ParseResult<Expression> {
+fn unexpected_token(state: &mut State) -> ParseResult<Expression> {
     crate::parser_bail!(
         state,
         unexpected_token(vec!["an expression"], state.iterator.current())
@@ -20,7 +20,7 @@ use crate::tree::identifier::Identifier;
 pub fn postfix(state: &mut State, left: Expression, kind: &TokenKind) -> ParseResult<Expression> {
     Ok(match kind {
         TokenKind::DoubleQuestion => {
-            let double_question = state.iterator.current().position;
+            let double_question = state.iterator.current().start();
             state.iterator.next();
 
             let comments = state.iterator.comments();
@@ -42,26 +42,28 @@ pub fn postfix(state: &mut State, left: Expression, kind: &TokenKind) -> ParseRe
             if state.iterator.lookahead(1).kind == TokenKind::Ellipsis
                 && state.iterator.lookahead(2).kind == TokenKind::RightParen
             {
-                Expression::FunctionOperation(FunctionOperationExpression::ClosureCreation {
-                    comments,
-                    function: Box::new(left),
-                    generics,
-                    placeholder: ArgumentPlaceholderExpression {
-                        left_parenthesis: utils::skip(state, TokenKind::LeftParen)?,
-                        ellipsis: utils::skip(state, TokenKind::Ellipsis)?,
-                        right_parenthesis: utils::skip(state, TokenKind::RightParen)?,
-                        comments: state.iterator.comments(),
+                Expression::FunctionOperation(Box::new(
+                    FunctionOperationExpression::ClosureCreation {
+                        comments,
+                        function: Box::new(left),
+                        generics,
+                        placeholder: ArgumentPlaceholderExpression {
+                            left_parenthesis: utils::skip(state, TokenKind::LeftParen)?,
+                            ellipsis: utils::skip(state, TokenKind::Ellipsis)?,
+                            right_parenthesis: utils::skip(state, TokenKind::RightParen)?,
+                            comments: state.iterator.comments(),
+                        },
                     },
-                })
+                ))
             } else {
                 let arguments = argument::argument_list_expression(state)?;
 
-                Expression::FunctionOperation(FunctionOperationExpression::Call {
+                Expression::FunctionOperation(Box::new(FunctionOperationExpression::Call {
                     comments,
                     function: Box::new(left),
                     generics,
                     arguments,
-                })
+                }))
             }
         }
         TokenKind::LeftBracket => {
@@ -71,7 +73,7 @@ pub fn postfix(state: &mut State, left: Expression, kind: &TokenKind) -> ParseRe
             let current = state.iterator.current();
             if current.kind == TokenKind::RightBracket {
                 state.iterator.next();
-                let right_bracket = current.position;
+                let right_bracket = current.start();
 
                 Expression::ArrayOperation(ArrayOperationExpression::Push {
                     comments,
@@ -97,14 +99,14 @@ pub fn postfix(state: &mut State, left: Expression, kind: &TokenKind) -> ParseRe
             let current = state.iterator.current();
 
             match current.kind {
-                TokenKind::Variable => {
-                    Expression::ClassOperation(ClassOperationExpression::StaticPropertyFetch {
+                TokenKind::Variable => Expression::ClassOperation(Box::new(
+                    ClassOperationExpression::StaticPropertyFetch {
                         comments: state.iterator.comments(),
                         class: Box::new(left),
                         double_colon: position,
                         property: variable::parse(state)?,
-                    })
-                }
+                    },
+                )),
                 _ if identifier::is_identifier_maybe_reserved(&state.iterator.current().kind) => {
                     let identifier = identifier::identifier_maybe_reserved(state)?;
                     let comments = state.iterator.comments();
@@ -116,7 +118,7 @@ pub fn postfix(state: &mut State, left: Expression, kind: &TokenKind) -> ParseRe
                         if state.iterator.lookahead(1).kind == TokenKind::Ellipsis
                             && state.iterator.lookahead(2).kind == TokenKind::RightParen
                         {
-                            Expression::ClassOperation(
+                            Expression::ClassOperation(Box::new(
                                 ClassOperationExpression::StaticMethodClosureCreation {
                                     comments,
                                     class: Box::new(left),
@@ -133,38 +135,42 @@ pub fn postfix(state: &mut State, left: Expression, kind: &TokenKind) -> ParseRe
                                         comments: state.iterator.comments(),
                                     },
                                 },
-                            )
+                            ))
                         } else {
-                            Expression::ClassOperation(ClassOperationExpression::StaticMethodCall {
+                            Expression::ClassOperation(Box::new(
+                                ClassOperationExpression::StaticMethodCall {
+                                    comments,
+                                    class: Box::new(left),
+                                    double_colon: position,
+                                    method: identifier,
+                                    generics,
+                                    arguments: argument::argument_list_expression(state)?,
+                                },
+                            ))
+                        }
+                    } else {
+                        Expression::ClassOperation(Box::new(
+                            ClassOperationExpression::ConstantFetch {
                                 comments,
                                 class: Box::new(left),
                                 double_colon: position,
-                                method: identifier,
-                                generics,
-                                arguments: argument::argument_list_expression(state)?,
-                            })
-                        }
-                    } else {
-                        Expression::ClassOperation(ClassOperationExpression::ConstantFetch {
-                            comments,
-                            class: Box::new(left),
-                            double_colon: position,
-                            constant: identifier,
-                        })
+                                constant: identifier,
+                            },
+                        ))
                     }
                 }
                 TokenKind::Class => {
                     state.iterator.next();
 
-                    Expression::ClassOperation(ClassOperationExpression::ConstantFetch {
+                    Expression::ClassOperation(Box::new(ClassOperationExpression::ConstantFetch {
                         comments: state.iterator.comments(),
                         class: Box::new(left),
                         double_colon: position,
                         constant: Identifier {
-                            position: current.position,
+                            position: current.start(),
                             value: current.value.clone(),
                         },
-                    })
+                    }))
                 }
                 _ => {
                     crate::parser_bail!(
@@ -175,7 +181,7 @@ pub fn postfix(state: &mut State, left: Expression, kind: &TokenKind) -> ParseRe
             }
         }
         TokenKind::Arrow | TokenKind::QuestionArrow => {
-            let position = state.iterator.current().position;
+            let position = state.iterator.current().start();
             state.iterator.next();
 
             let identifier = identifier::identifier_maybe_reserved(state)?;
@@ -188,20 +194,22 @@ pub fn postfix(state: &mut State, left: Expression, kind: &TokenKind) -> ParseRe
                 if kind == &TokenKind::QuestionArrow {
                     let arguments = argument::argument_list_expression(state)?;
 
-                    Expression::ObjectOperation(ObjectOperationExpression::NullsafeMethodCall {
-                        comments,
-                        object: Box::new(left),
-                        method: identifier,
-                        generics,
-                        question_arrow: position,
-                        arguments,
-                    })
+                    Expression::ObjectOperation(Box::new(
+                        ObjectOperationExpression::NullsafeMethodCall {
+                            comments,
+                            object: Box::new(left),
+                            method: identifier,
+                            generics,
+                            question_arrow: position,
+                            arguments,
+                        },
+                    ))
                 } else {
                     // `(...)` closure creation
                     if state.iterator.lookahead(1).kind == TokenKind::Ellipsis
                         && state.iterator.lookahead(2).kind == TokenKind::RightParen
                     {
-                        Expression::ObjectOperation(
+                        Expression::ObjectOperation(Box::new(
                             ObjectOperationExpression::MethodClosureCreation {
                                 comments,
                                 object: Box::new(left),
@@ -215,38 +223,42 @@ pub fn postfix(state: &mut State, left: Expression, kind: &TokenKind) -> ParseRe
                                     comments: state.iterator.comments(),
                                 },
                             },
-                        )
+                        ))
                     } else {
                         let arguments = argument::argument_list_expression(state)?;
 
-                        Expression::ObjectOperation(ObjectOperationExpression::MethodCall {
-                            comments,
-                            object: Box::new(left),
-                            method: identifier,
-                            generics,
-                            arrow: position,
-                            arguments,
-                        })
+                        Expression::ObjectOperation(Box::new(
+                            ObjectOperationExpression::MethodCall {
+                                comments,
+                                object: Box::new(left),
+                                method: identifier,
+                                generics,
+                                arrow: position,
+                                arguments,
+                            },
+                        ))
                     }
                 }
             } else if kind == &TokenKind::QuestionArrow {
-                Expression::ObjectOperation(ObjectOperationExpression::NullsafePropertyFetch {
-                    comments,
-                    object: Box::new(left),
-                    question_arrow: position,
-                    property: identifier,
-                })
+                Expression::ObjectOperation(Box::new(
+                    ObjectOperationExpression::NullsafePropertyFetch {
+                        comments,
+                        object: Box::new(left),
+                        question_arrow: position,
+                        property: identifier,
+                    },
+                ))
             } else {
-                Expression::ObjectOperation(ObjectOperationExpression::PropertyFetch {
+                Expression::ObjectOperation(Box::new(ObjectOperationExpression::PropertyFetch {
                     comments,
                     object: Box::new(left),
                     arrow: position,
                     property: identifier,
-                })
+                }))
             }
         }
         TokenKind::Increment => {
-            let position = state.iterator.current().position;
+            let position = state.iterator.current().start();
             state.iterator.next();
 
             Expression::ArithmeticOperation(ArithmeticOperationExpression::PostIncrement {
@@ -255,7 +267,7 @@ pub fn postfix(state: &mut State, left: Expression, kind: &TokenKind) -> ParseRe
             })
         }
         TokenKind::Decrement => {
-            let position = state.iterator.current().position;
+            let position = state.iterator.current().start();
             state.iterator.next();
 
             Expression::ArithmeticOperation(ArithmeticOperationExpression::PostDecrement {
@@ -5,6 +5,76 @@ use crate::parser::state::State;
 use crate::tree::identifier::Identifier;
 use crate::tree::identifier::TemplatedIdentifier;
 
+// The keywords that may be used as names, in addition to plain identifiers, are listed here, and
+// only here, so that a new keyword can be introduced as a name first, without breaking existing
+// code using it as one, and be reserved later on by removing it from these lists.
+
+/// The keywords that may be used as the name of a type, such as a type alias or a constant.
+macro_rules! type_name_keyword {
+    () => {
+        TokenKind::Enum
+            | TokenKind::From
+            | TokenKind::Where
+            | TokenKind::Type
+            | TokenKind::In
+            | TokenKind::Into
+            | TokenKind::Using
+            | TokenKind::Permits
+            | TokenKind::Pragma
+    };
+}
+
+/// The keywords that may be used as the name of a class, an interface, or an enum, and
+/// referenced as such.
+macro_rules! class_name_keyword {
+    () => {
+        $crate::parser::internal::identifier::type_name_keyword!()
+            | TokenKind::Dict
+            | TokenKind::Vec
+            | TokenKind::Async
+            | TokenKind::Await
+            | TokenKind::Concurrently
+    };
+}
+
+/// The keywords that may be used as an identifier within an expression, such as a class name
+/// in a static method call.
+macro_rules! expression_keyword {
+    () => {
+        $crate::parser::internal::identifier::class_name_keyword!() | TokenKind::Is
+    };
+}
+
+/// The keywords that may be used as the name of a function, a method, or a pragma.
+macro_rules! soft_reserved_keyword {
+    () => {
+        TokenKind::Parent
+            | TokenKind::Self_
+            | TokenKind::True
+            | TokenKind::False
+            | TokenKind::Type
+            | TokenKind::In
+            | TokenKind::Into
+            | TokenKind::Using
+            | TokenKind::Is
+            | TokenKind::List
+            | TokenKind::Null
+            | TokenKind::Concurrently
+            | TokenKind::Async
+            | TokenKind::Await
+            | TokenKind::Where
+            | TokenKind::Permits
+            | TokenKind::Pragma
+            | TokenKind::Enum
+            | TokenKind::From
+            | TokenKind::Readonly
+    };
+}
+
+pub(crate) use class_name_keyword;
+pub(crate) use expression_keyword;
+pub(crate) use type_name_keyword;
+
 /// Expect an identifier such as `Foo`, `Foo\Bar` for a namespace name.
 ///
 /// The identifier can be either a simple identifier or a qualified identifier.
@@ -15,7 +85,7 @@ pub fn namespace_identifier(state: &mut State) -> ParseResult<Identifier> {
 
     match &current.kind {
         TokenKind::Identifier | TokenKind::QualifiedIdentifier => {
-            let position = current.position;
+            let position = current.start();
 
             state.iterator.next();
 
@@ -26,7 +96,7 @@ pub fn namespace_identifier(state: &mut State) -> ParseResult<Identifier> {
         }
         _ if is_reserved_identifier(&current.kind) => {
             let name = current.to_string().into();
-            let position = current.position;
+            let position = current.start();
             state.iterator.next();
 
             Ok(Identifier {
@@ -46,25 +116,12 @@ pub fn namespace_identifier(state: &mut State) -> ParseResult<Identifier> {
 /// Expect an unqualified identifier such as Foo or Bar for a `class`, `interface`, or an `enum` name.
 ///
 /// The identifier can only be a simple identifier.
-/// The identifier may be one of the following reserved keywords:
-///
-/// - `enum`
-/// - `from`
-/// - `vec`
-/// - `dict`
-/// - `where`
-/// - `type`
-/// - `in`
-/// - `into`
-/// - `using`
-/// - `async`
-/// - `await`
-/// - `concurrently`
+/// The identifier may be one of the keywords listed in `class_name_keyword!`.
 pub fn classname_identifier(state: &mut State) -> ParseResult<Identifier> {
     let current = state.iterator.current();
     match &current.kind {
         TokenKind::Identifier => {
-            let position = current.position;
+            let position = current.start();
 
             state.iterator.next();
 
@@ -73,19 +130,8 @@ pub fn classname_identifier(state: &mut State) -> ParseResult<Identifier> {
                 value: current.value.clone(),
             })
         }
-        TokenKind::Enum
-        | TokenKind::From
-        | TokenKind::Where
-        | TokenKind::Type
-        | TokenKind::In
-        | TokenKind::Into
-        | TokenKind::Using
-        | TokenKind::Dict
-        | TokenKind::Vec
-        | TokenKind::Async
-        | TokenKind::Await
-        | TokenKind::Concurrently => {
-            let position = current.position;
+        class_name_keyword!() => {
+            let position = current.start();
             let name = current.to_string().into();
 
             state.iterator.next();
@@ -99,7 +145,7 @@ pub fn classname_identifier(state: &mut State) -> ParseResult<Identifier> {
             state.iterator.next();
 
             let identifier = Identifier {
-                position: current.position,
+                position: current.start(),
                 value: current.to_string().into(),
             };
 
@@ -133,7 +179,7 @@ pub fn constant_identifier(state: &mut State) -> ParseResult<Identifier> {
     let current = state.iterator.current();
     match &current.kind {
         TokenKind::Identifier => {
-            let position = current.position;
+            let position = current.start();
 
             state.iterator.next();
 
@@ -143,7 +189,7 @@ pub fn constant_identifier(state: &mut State) -> ParseResult<Identifier> {
             })
         }
         TokenKind::Class => {
-            let position = current.position;
+            let position = current.start();
             let name = current.to_string().into();
 
             state.iterator.next();
@@ -164,7 +210,7 @@ pub fn constant_identifier(state: &mut State) -> ParseResult<Identifier> {
             state.iterator.next();
 
             let identifier = Identifier {
-                position: current.position,
+                position: current.start(),
                 value: current.to_string().into(),
             };
 
@@ -182,15 +228,7 @@ pub fn constant_identifier(state: &mut State) -> ParseResult<Identifier> {
 /// Expect an unqualified identifier such as FOO or BAR for a `type` name.
 ///
 /// The identifier can only be a simple identifier.
-/// The identifier may be one of the following reserved keywords:
-///
-/// - `enum`
-/// - `from`
-/// - `where`
-/// - `type`
-/// - `in`
-/// - `into`
-/// - `using`
+/// The identifier may be one of the keywords listed in `type_name_keyword!`.
 ///
 /// Unlike `constant` name, `type` name can be templated ( e.g `Callback<U, V>` )
 #[inline(always)]
@@ -201,21 +239,15 @@ pub fn type_identifier(state: &mut State) -> ParseResult<TemplatedIdentifier> {
             state.iterator.next();
 
             Identifier {
-                position: current.position,
+                position: current.start(),
                 value: current.value.clone(),
             }
         }
-        TokenKind::Enum
-        | TokenKind::From
-        | TokenKind::Where
-        | TokenKind::Type
-        | TokenKind::In
-        | TokenKind::Into
-        | TokenKind::Using => {
+        type_name_keyword!() => {
             state.iterator.next();
 
             Identifier {
-                position: current.position,
+                position: current.start(),
                 value: current.value.clone(),
             }
         }
@@ -223,7 +255,7 @@ pub fn type_identifier(state: &mut State) -> ParseResult<TemplatedIdentifier> {
             state.iterator.next();
 
             let identifier = Identifier {
-                position: current.position,
+                position: current.start(),
                 value: current.to_string().into(),
             };
 
@@ -267,7 +299,11 @@ pub fn fully_qualified_templated_identifier_including_self(
 ) -> ParseResult<TemplatedIdentifier> {
     let name = fully_qualified_type_identifier_including_self(state)?;
     let templates = if state.iterator.current().kind == TokenKind::LessThan {
-        Some(template::type_template_group_definition(state)?)
+        if is_closure(&name) {
+            Some(template::closure_type_template_group_definition(state)?)
+        } else {
+            Some(template::type_template_group_definition(state)?)
+        }
     } else {
         None
     };
@@ -275,11 +311,17 @@ pub fn fully_qualified_templated_identifier_including_self(
     Ok(TemplatedIdentifier { name, templates })
 }
 
+fn is_closure(name: &Identifier) -> bool {
+    let value = name.value.strip_prefix(b"\\").unwrap_or(&name.value);
+
+    value.eq_ignore_ascii_case(b"Closure")
+}
+
 /// Expect an unqualified identifier such as Foo or Bar.
 pub fn identifier(state: &mut State) -> ParseResult<Identifier> {
     let current = state.iterator.current();
     if let TokenKind::Identifier = &current.kind {
-        let position = current.position;
+        let position = current.start();
 
         state.iterator.next();
 
@@ -302,7 +344,7 @@ pub fn fully_qualified_type_identifier(state: &mut State) -> ParseResult<Identif
         TokenKind::Identifier
         | TokenKind::QualifiedIdentifier
         | TokenKind::FullyQualifiedIdentifier => {
-            let position = current.position;
+            let position = current.start();
 
             state.iterator.next();
 
@@ -311,19 +353,8 @@ pub fn fully_qualified_type_identifier(state: &mut State) -> ParseResult<Identif
                 value: current.value.clone(),
             })
         }
-        TokenKind::Enum
-        | TokenKind::From
-        | TokenKind::Where
-        | TokenKind::Type
-        | TokenKind::In
-        | TokenKind::Into
-        | TokenKind::Using
-        | TokenKind::Dict
-        | TokenKind::Vec
-        | TokenKind::Async
-        | TokenKind::Await
-        | TokenKind::Concurrently => {
-            let position = current.position;
+        class_name_keyword!() => {
+            let position = current.start();
             let name = current.to_string().into();
 
             state.iterator.next();
@@ -337,7 +368,7 @@ pub fn fully_qualified_type_identifier(state: &mut State) -> ParseResult<Identif
             state.iterator.next();
 
             let identifier = Identifier {
-                position: current.position,
+                position: current.start(),
                 value: current.to_string().into(),
             };
 
@@ -349,7 +380,7 @@ pub fn fully_qualified_type_identifier(state: &mut State) -> ParseResult<Identif
             state.iterator.next();
 
             let identifier = Identifier {
-                position: current.position,
+                position: current.start(),
                 value: current.to_string().into(),
             };
 
@@ -376,7 +407,7 @@ pub fn fully_qualified_type_identifier_including_self(
         TokenKind::Identifier
         | TokenKind::QualifiedIdentifier
         | TokenKind::FullyQualifiedIdentifier => {
-            let position = current.position;
+            let position = current.start();
 
             state.iterator.next();
 
@@ -385,22 +416,8 @@ pub fn fully_qualified_type_identifier_including_self(
                 value: current.value.clone(),
             })
         }
-        TokenKind::Enum
-        | TokenKind::From
-        | TokenKind::Self_
-        | TokenKind::Static
-        | TokenKind::Parent
-        | TokenKind::Type
-        | TokenKind::In
-        | TokenKind::Into
-        | TokenKind::Using
-        | TokenKind::Where
-        | TokenKind::Dict
-        | TokenKind::Vec
-        | TokenKind::Async
-        | TokenKind::Await
-        | TokenKind::Concurrently => {
-            let position = current.position;
+        TokenKind::Self_ | TokenKind::Static | TokenKind::Parent | class_name_keyword!() => {
+            let position = current.start();
             let name = current.to_string().into();
 
             state.iterator.next();
@@ -414,7 +431,7 @@ pub fn fully_qualified_type_identifier_including_self(
             state.iterator.next();
 
             let identifier = Identifier {
-                position: current.position,
+                position: current.start(),
                 value: current.to_string().into(),
             };
 
@@ -437,7 +454,7 @@ pub fn identifier_maybe_reserved(state: &mut State) -> ParseResult<Identifier> {
 
     if is_reserved_identifier(&current.kind) {
         let name = current.to_string().into();
-        let position = current.position;
+        let position = current.start();
         state.iterator.next();
 
         Ok(Identifier {
@@ -454,7 +471,7 @@ pub fn identifier_maybe_soft_reserved(state: &mut State) -> ParseResult<Identifi
 
     if is_soft_reserved_identifier(&current.kind) {
         let name = current.to_string().into();
-        let position = current.position;
+        let position = current.start();
         state.iterator.next();
 
         Ok(Identifier {
@@ -475,23 +492,7 @@ pub fn is_identifier_maybe_reserved(kind: &TokenKind) -> bool {
 }
 
 pub fn is_soft_reserved_identifier(kind: &TokenKind) -> bool {
-    matches!(kind, |TokenKind::Parent| TokenKind::Self_
-        | TokenKind::True
-        | TokenKind::False
-        | TokenKind::Type
-        | TokenKind::In
-        | TokenKind::Into
-        | TokenKind::Using
-        | TokenKind::Is
-        | TokenKind::List
-        | TokenKind::Null
-        | TokenKind::Concurrently
-        | TokenKind::Async
-        | TokenKind::Await
-        | TokenKind::Where
-        | TokenKind::Enum
-        | TokenKind::From
-        | TokenKind::Readonly)
+    matches!(kind, soft_reserved_keyword!())
 }
 
 pub fn is_reserved_identifier(kind: &TokenKind) -> bool {
@@ -0,0 +1,378 @@
+use ara_reporting::issue::Issue;
+use ara_source::source::Source;
+
+use crate::cache;
+use crate::lexer;
+use crate::lexer::iterator::TokenIterator;
+use crate::lexer::token::TokenKind;
+use crate::parser::edit::Edit;
+use crate::parser::internal::comment;
+use crate::parser::internal::definition;
+use crate::parser::options::ParserOptions;
+use crate::parser::state::State;
+use crate::tree;
+use crate::tree::comment::CommentAttachments;
+use crate::tree::comment::CommentGroup;
+use crate::tree::definition::Definition;
+use crate::tree::definition::DefinitionTree;
+use crate::tree::Node;
+use crate::tree::Tree;
+
+/// Parse the given source, the result of applying the given edit to the source of the given tree,
+/// reusing the definitions of the tree that the edit does not touch.
+///
+/// Each definition owns the text between the end of the preceding definition and its own end,
+/// the definitions owning text touched by the edit are parsed again, along with any new definitions
+/// in their place, while the remaining definitions are moved to their new positions.
+///
+/// The given warnings, reported while parsing the tree, are reused along with the definitions
+/// they were reported for, while the warnings of the edited region are reported again.
+///
+/// Returns `None` if the tree can not be reused, e.g. because it was not parsed from the original
+/// content, contains namespaces, or the edited region does not parse on its own.
+pub fn reparse(
+    tree: &Tree,
+    warnings: &[Issue],
+    original: &Source,
+    source: &Source,
+    edit: &Edit,
+) -> Option<(Tree, Vec<Issue>)> {
+    if tree.trivia.is_some() || tree.source_hash != cache::hash(&original.content) {
+        return None;
+    }
+
+    let definitions = &tree.definitions.definitions;
+    if definitions
+        .iter()
+        .any(|definition| matches!(definition, Definition::Namespace(_) | Definition::Pragma(_)))
+    {
+        return None;
+    }
+
+    let ends = definitions
+        .iter()
+        .map(|definition| end(&original.content, definition))
+        .collect::<Option<Vec<usize>>>()?;
+
+    let owner_start = |index: usize| match index {
+        0 => 0,
+        _ => ends[index - 1],
+    };
+
+    // the affected definitions, followed by the reused ones.
+    let first = (0..definitions.len())
+        .find(|index| edit.start <= ends[*index])
+        .unwrap_or(definitions.len());
+    let last = (first..definitions.len())
+        .find(|index| edit.end < owner_start(*index))
+        .unwrap_or(definitions.len());
+
+    // comments that a definition leaves unclaimed are claimed by the following ones, so neither
+    // the definitions preceding the edited region, nor those within it, may leave any.
+    let comments = tree
+        .comments
+        .comments
+        .iter()
+        .map(|comment| comment.position)
+        .collect::<Vec<usize>>();
+    let preceding = |position: usize| comments.partition_point(|comment| *comment < position);
+
+    let mut prefix = definitions[..first].to_vec();
+    let claimed_by_prefix = claimed(&mut prefix, &comments);
+    if preceding(owner_start(first)) != claimed_by_prefix {
+        return None;
+    }
+
+    if last < definitions.len() {
+        let claimed_by_region = claimed(&mut definitions[first..last].to_vec(), &comments);
+        if preceding(owner_start(last)) != claimed_by_prefix + claimed_by_region {
+            return None;
+        }
+    }
+
+    let offset = edit.offset();
+    let start = owner_start(first);
+    let end = match definitions.get(last) {
+        Some(definition) => definition.initial_position(),
+        None => original.content.len(),
+    };
+    let new_end = end.checked_add_signed(offset)?;
+
+    let region = Source::inline(source.kind, source.content.get(start..new_end)?);
+    let mut tokens = lexer::lex(&region).ok()?;
+    for token in &mut tokens {
+        if matches!(token.kind, TokenKind::Namespace | TokenKind::Pragma) {
+            return None;
+        }
+
+        token.position += start as u32;
+    }
+
+    let mut iterator = TokenIterator::new(&tokens);
+    let mut state = State::new(
+        source,
+        &mut iterator,
+        ParserOptions {
+            recovery: false,
+            ..ParserOptions::default()
+        },
+    );
+
+    let mut parsed = vec![];
+    while !state.iterator.is_eof() {
+        parsed.push(definition::definition(&mut state).ok()?);
+    }
+
+    if last < definitions.len() {
+        let gap = owner_start(last).checked_add_signed(offset)?;
+        let unclaimed = state.iterator.comments();
+        if !unclaimed.is_empty() && unclaimed.start < gap {
+            return None;
+        }
+    }
+
+    let ((), edited) = state.finish_with_warnings(()).ok()?;
+
+    let mut definitions_tree = DefinitionTree {
+        definitions: prefix,
+        eof: tree.definitions.eof.checked_add_signed(offset)?,
+    };
+
+    let reparsed = parsed.len();
+    definitions_tree.definitions.append(&mut parsed);
+    for definition in &definitions[last..] {
+        let mut definition = definition.clone();
+        tree::shift(&mut definition, offset);
+
+        definitions_tree.definitions.push(definition);
+    }
+
+    // the identifiers of the reused nodes are kept, rather than assigned by `Tree::new`.
+    let mut result = Tree {
+        source: tree.source.clone(),
+        ids: tree
+            .ids
+            .reused(&definitions_tree, first, last, reparsed, offset),
+        definitions: definitions_tree,
+        source_hash: 0,
+        comments: CommentAttachments::default(),
+        trivia: None,
+    };
+
+    let trailed = if first > 0 { Some(start) } else { None };
+    let attachments = comment::attachments_from(source, &tokens, start, trailed);
+    let reuse = |groups: &[(usize, CommentGroup)], edited: Vec<(usize, CommentGroup)>| {
+        let mut before = groups
+            .iter()
+            .filter(|(key, _)| *key < start)
+            .cloned()
+            .collect::<Vec<(usize, CommentGroup)>>();
+
+        let after = groups
+            .iter()
+            .filter(|(key, _)| *key > end)
+            .map(|(key, group)| {
+                let mut group = group.clone();
+                for position in group.positions_mut() {
+                    *position = position.saturating_add_signed(offset);
+                }
+
+                (key.saturating_add_signed(offset), group)
+            });
+
+        before.extend(edited);
+        before.extend(after);
+
+        before
+    };
+
+    result.comments.comments = tree
+        .comments
+        .comments
+        .iter()
+        .filter(|comment| comment.position < start)
+        .cloned()
+        .chain(attachments.comments)
+        .chain(
+            tree.comments
+                .comments
+                .iter()
+                .filter(|comment| comment.position > end)
+                .map(|comment| {
+                    let mut comment = comment.clone();
+                    comment.position = comment.position.saturating_add_signed(offset);
+
+                    comment
+                }),
+        )
+        .collect();
+    result.comments.leading = reuse(&tree.comments.leading, attachments.leading);
+    result.comments.trailing = reuse(&tree.comments.trailing, attachments.trailing);
+    result.source_hash = cache::hash(&source.content);
+
+    let mut warnings = warnings
+        .iter()
+        .filter_map(|issue| match &issue.source {
+            Some((_, _, to)) if *to <= start => Some(issue.clone()),
+            Some((_, from, _)) if *from >= end => Some(shift(issue, offset)),
+            Some(_) => None,
+            None => Some(issue.clone()),
+        })
+        .chain(edited)
+        .collect::<Vec<Issue>>();
+
+    // issues without a source are kept at the end, see `State::take_issues`.
+    warnings.sort_by_key(|issue| match &issue.source {
+        Some((_, from, to)) => (*from, *to),
+        None => (usize::MAX, usize::MAX),
+    });
+
+    Some((result, warnings))
+}
+
+/// The given issue, moved along with the content following an edit by the given offset.
+fn shift(issue: &Issue, offset: isize) -> Issue {
+    let mut issue = issue.clone();
+    if let Some((_, from, to)) = &mut issue.source {
+        *from = from.saturating_add_signed(offset);
+        *to = to.saturating_add_signed(offset);
+    }
+
+    for annotation in &mut issue.annotations {
+        annotation.from = annotation.from.saturating_add_signed(offset);
+        annotation.to = annotation.to.saturating_add_signed(offset);
+    }
+
+    issue
+}
+
+/// The number of the given comments claimed by the given definitions, or by any of their descendants.
+fn claimed(definitions: &mut [Definition], comments: &[usize]) -> usize {
+    let mut count = 0;
+    let mut stack = definitions
+        .iter_mut()
+        .map(|definition| definition as &mut dyn Node)
+        .collect::<Vec<&mut dyn Node>>();
+
+    while let Some(node) = stack.pop() {
+        // a comment can not share its position with a token, or with any other comment, so only
+        // the start of a comment group may share its position with a comment, and the end of the
+        // group immediately follows its start.
+        let mut positions = node.positions_mut().into_iter().map(|position| *position);
+        while let Some(position) = positions.next() {
+            if comments.binary_search(&position).is_ok() {
+                let end = positions.next().unwrap_or(position);
+
+                count += comments.partition_point(|comment| *comment < end)
+                    - comments.partition_point(|comment| *comment < position);
+            }
+        }
+
+        stack.extend(node.children_mut());
+    }
+
+    count
+}
+
+/// The end of the given definition, right after its closing brace or semicolon.
+///
+/// The final position of most definitions is already past their closing token, while that of
+/// constant definitions is the position of their semicolon.
+fn end(content: &str, definition: &Definition) -> Option<usize> {
+    let position = definition.final_position();
+    let bytes = content.as_bytes();
+
+    match (bytes.get(position.wrapping_sub(1)), bytes.get(position)) {
+        (Some(b'}' | b';'), _) => Some(position),
+        (_, Some(b';')) => Some(position + 1),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ara_source::source::SourceKind;
+
+    use crate::parser;
+
+    const CODE: &str = "// foo\nfunction foo(): void { // bar\n    foo();\n}\n\n/* baz */\nfinal class Baz {\n    # qux\n    public function qux(): int { return 1; }\n}\n\nconst int QUUX = 1; // quux\n// end\n";
+
+    fn edit(code: &str, edit: Edit) -> Option<Tree> {
+        let original = Source::new(SourceKind::Script, "code.ara", code);
+        let source = Source::new(SourceKind::Script, "code.ara", edit.apply(code).unwrap());
+
+        let options = ParserOptions::default();
+        let (tree, warnings) = parser::parse_with_warnings(&original, options.clone()).unwrap();
+        let result = reparse(&tree, &warnings, &original, &source, &edit);
+        if let Some((result, warnings)) = &result {
+            let (expected, expected_warnings) =
+                parser::parse_with_warnings(&source, options).unwrap();
+
+            assert_eq!(result.definitions, expected.definitions);
+            assert_eq!(result.comments, expected.comments);
+            assert_eq!(result.source_hash, expected.source_hash);
+            assert_eq!(warnings, &expected_warnings);
+        }
+
+        result.map(|(tree, _)| tree)
+    }
+
+    #[test]
+    fn test_reparse() {
+        let position = |needle: &str| CODE.find(needle).unwrap();
+
+        // edit within a definition.
+        let start = position("return 1");
+        assert!(edit(CODE, Edit::new(start + 7, start + 8, "100 + 2")).is_some());
+
+        // edit of a comment leading a definition.
+        let start = position("baz */");
+        assert!(edit(CODE, Edit::new(start, start + 3, "corge")).is_some());
+
+        // edit of a comment trailing a definition.
+        let start = position("quux\n");
+        assert!(edit(CODE, Edit::new(start, start + 4, "")).is_some());
+
+        // insertion of a new definition between two definitions.
+        let start = position("/* baz */");
+        assert!(edit(CODE, Edit::new(start, start, "type Foo = int;\n")).is_some());
+
+        // removal of a definition.
+        assert!(edit(
+            CODE,
+            Edit::new(position("/* baz */"), position("const"), "")
+        )
+        .is_some());
+
+        // edits at the start and the end of the source.
+        assert!(edit(CODE, Edit::new(0, 0, "\n\n")).is_some());
+        assert!(edit(CODE, Edit::new(CODE.len(), CODE.len(), "// more\n")).is_some());
+
+        // edits breaking the syntax, or introducing a namespace.
+        let start = position("return 1");
+        assert!(edit(CODE, Edit::new(start, start + 8, "return (1")).is_none());
+        assert!(edit(CODE, Edit::new(start, start + 6, "'")).is_none());
+        assert!(edit(CODE, Edit::new(0, 0, "namespace Foo;\n")).is_none());
+
+        // edits of definitions leaving comments for the following definitions to claim.
+        let start = position("return 1");
+        assert!(edit(CODE, Edit::new(start + 9, start + 9, " /* qux */")).is_none());
+        assert!(edit(CODE, Edit::new(start + 7, start + 8, "2")).is_some());
+    }
+
+    #[test]
+    fn test_reparse_warnings() {
+        let code = "function foo(): void {\n    if ($a) {} elseif ($b) {}\n}\n\nfunction bar(): void {}\n\nfunction baz(): void {\n    if ($a) {} elseif ($b) {}\n}\n";
+        let position = |needle: &str| code.find(needle).unwrap();
+
+        // the warnings of the definitions preceding, and following, the edit are reused.
+        let start = position("bar");
+        assert!(edit(code, Edit::new(start, start + 3, "corge")).is_some());
+
+        // the warnings of the edited definition are reported again, or no longer.
+        let start = position("elseif");
+        assert!(edit(code, Edit::new(start, start + 6, "else if")).is_some());
+        assert!(edit(code, Edit::new(start, start, "\n")).is_some());
+    }
+}
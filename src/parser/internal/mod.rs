@@ -1,6 +1,12 @@
+pub(crate) mod comment;
 pub(crate) mod definition;
+pub(crate) mod docblock;
 pub(crate) mod expression;
 pub(crate) mod identifier;
+pub(crate) mod incremental;
+pub(crate) mod recovery;
 pub(crate) mod statement;
+pub(crate) mod suggestion;
+pub(crate) mod trivia;
 pub(crate) mod utils;
 pub(crate) mod variable;
@@ -0,0 +1,184 @@
+use ara_reporting::Report;
+
+use crate::lexer::token::Token;
+use crate::lexer::token::TokenKind;
+use crate::parser::internal::suggestion;
+use crate::parser::result::ParseResult;
+use crate::parser::state::State;
+
+/// Record the issues of a report produced by an item that failed to parse, and discard the
+/// state gathered for it, so that parsing can continue after the item.
+///
+/// Fails with all issues recorded so far if parsing can not continue, see `State::can_recover`.
+pub fn recover(state: &mut State, report: Report) -> ParseResult<()> {
+    state.ignored_shift_at = None;
+    state.get_attributes();
+
+    for issue in report.issues {
+        state.record(issue);
+    }
+
+    if state.can_recover() {
+        Ok(())
+    } else {
+        if state.options.recovery {
+            state.stop();
+        }
+
+        state.finish(())
+    }
+}
+
+/// Skip to the start of the next definition, after the definition starting at the given
+/// position failed to parse.
+///
+/// A misspelled definition keyword at the beginning of a line, e.g. `clas`, is considered
+/// the start of a definition, so that it is reported as well.
+pub fn skip_to_definition(state: &mut State, start: usize) {
+    if state.iterator.current().start() <= start {
+        state.iterator.next();
+    }
+
+    while !state.iterator.is_eof() {
+        let current = state.iterator.current();
+        if is_definition_start(state, current)
+            || (is_at_line_start(state, current)
+                && suggestion::misspelled_keyword(current, suggestion::DEFINITION_KEYWORDS)
+                    .is_some())
+        {
+            break;
+        }
+
+        state.iterator.next();
+    }
+}
+
+/// Skip to the start of the next statement, after the statement starting at the given
+/// position failed to parse.
+///
+/// This stops after the next `;`, or before the `}` closing the enclosing block, skipping
+/// over any nested brackets, or before the start of the next definition, in case the
+/// enclosing block is missing its closing brace.
+pub fn skip_to_statement(state: &mut State, start: usize) {
+    let mut depth = 0usize;
+    let mut first = true;
+
+    while !state.iterator.is_eof() {
+        let current = state.iterator.current();
+        let progressed = !first || current.start() > start;
+        first = false;
+
+        match current.kind {
+            TokenKind::SemiColon if depth == 0 => {
+                state.iterator.next();
+
+                return;
+            }
+            TokenKind::RightBrace if depth == 0 && progressed => return,
+            TokenKind::LeftBrace | TokenKind::LeftParen | TokenKind::LeftBracket => depth += 1,
+            TokenKind::RightBrace | TokenKind::RightParen | TokenKind::RightBracket => {
+                depth = depth.saturating_sub(1)
+            }
+            _ if depth == 0 && progressed && is_definition_start(state, current) => return,
+            _ => {}
+        }
+
+        state.iterator.next();
+    }
+}
+
+/// Whether a `;` is missing before the current token.
+///
+/// This is the case if the current token closes the enclosing block, ends the source, or starts
+/// a new statement, member, or definition on a line following the previous token.
+pub fn is_missing_semicolon(state: &State) -> bool {
+    let current = state.iterator.current();
+    if matches!(current.kind, TokenKind::RightBrace | TokenKind::Eof) {
+        return true;
+    }
+
+    let previous = state.iterator.previous();
+    let on_following_line = state
+        .source
+        .content
+        .get(previous.end()..current.start())
+        .is_some_and(|gap| gap.contains('\n'));
+
+    on_following_line && (is_statement_start(current) || is_definition_start(state, current))
+}
+
+/// Whether the given token can start a statement, or a member of a class-like definition.
+fn is_statement_start(token: &Token) -> bool {
+    matches!(
+        token.kind,
+        TokenKind::Variable
+            | TokenKind::Identifier
+            | TokenKind::QualifiedIdentifier
+            | TokenKind::FullyQualifiedIdentifier
+            | TokenKind::Self_
+            | TokenKind::Static
+            | TokenKind::Parent
+            | TokenKind::If
+            | TokenKind::While
+            | TokenKind::Do
+            | TokenKind::For
+            | TokenKind::Foreach
+            | TokenKind::Return
+            | TokenKind::Try
+            | TokenKind::Throw
+            | TokenKind::Break
+            | TokenKind::Continue
+            | TokenKind::Using
+            | TokenKind::Yield
+            | TokenKind::Await
+            | TokenKind::Async
+            | TokenKind::Concurrently
+            | TokenKind::New
+            | TokenKind::Clone
+            | TokenKind::Print
+            | TokenKind::Exit
+            | TokenKind::Unset
+            | TokenKind::Increment
+            | TokenKind::Decrement
+            | TokenKind::Match
+            | TokenKind::Fn
+            | TokenKind::Function
+            | TokenKind::Public
+            | TokenKind::Protected
+            | TokenKind::Private
+            | TokenKind::Final
+            | TokenKind::Abstract
+            | TokenKind::Readonly
+            | TokenKind::Const
+            | TokenKind::Case
+            | TokenKind::Use
+    )
+}
+
+/// Whether the given token starts a definition.
+///
+/// Only tokens at the beginning of a line are considered, as most tokens starting a definition
+/// can also occur within one, e.g. `function` in a closure, or `use` in a trait use.
+pub fn is_definition_start(state: &State, token: &Token) -> bool {
+    is_at_line_start(state, token)
+        && matches!(
+            token.kind,
+            TokenKind::Namespace
+                | TokenKind::Use
+                | TokenKind::Const
+                | TokenKind::Type
+                | TokenKind::Attribute
+                | TokenKind::Enum
+                | TokenKind::Interface
+                | TokenKind::Async
+                | TokenKind::Function
+                | TokenKind::Readonly
+                | TokenKind::Final
+                | TokenKind::Abstract
+                | TokenKind::Class
+        )
+}
+
+fn is_at_line_start(state: &State, token: &Token) -> bool {
+    token.start() == 0 || state.source.content.as_bytes().get(token.start() - 1) == Some(&b'\n')
+}
@@ -1,5 +1,6 @@
 use crate::lexer::token::OpenTagKind;
 use crate::lexer::token::TokenKind;
+use crate::parser::internal::recovery;
 use crate::parser::internal::statement;
 use crate::parser::internal::utils;
 use crate::parser::result::ParseResult;
@@ -23,7 +24,7 @@ pub fn multiple_statements_until(
     let mut statements = Vec::new();
 
     let mut current = state.iterator.current();
-    while &current.kind != until {
+    while &current.kind != until && !state.iterator.is_eof() {
         if let TokenKind::OpenTag(OpenTagKind::Full) = current.kind {
             state.iterator.next();
 
@@ -31,7 +32,21 @@ pub fn multiple_statements_until(
             continue;
         }
 
-        statements.push(statement::statement(state)?);
+        // a definition at the beginning of a line ends the block, as its closing brace is
+        // most likely missing, the closing brace is then reported as missing by the caller.
+        if recovery::is_definition_start(state, current) {
+            break;
+        }
+
+        let start = current.start();
+        match statement::statement(state) {
+            Ok(statement) => statements.push(statement),
+            Err(report) => {
+                recovery::recover(state, *report)?;
+                recovery::skip_to_statement(state, start);
+            }
+        }
+
         current = state.iterator.current();
     }
 
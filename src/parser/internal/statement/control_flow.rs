@@ -1,3 +1,5 @@
+use smallvec::SmallVec;
+
 use crate::lexer::token::TokenKind;
 use crate::parser::internal::expression;
 use crate::parser::internal::statement::block;
@@ -24,9 +26,14 @@ pub fn if_statement(state: &mut State) -> ParseResult<IfStatement> {
     let mut elseifs: Vec<IfElseIfStatement> = vec![];
     let mut current = state.iterator.current();
     while current.kind == TokenKind::ElseIf {
+        let comments = state.iterator.comments();
+        let elseif = utils::skip_keyword(state, TokenKind::ElseIf)?;
+
+        crate::parser_report!(state, elseif_keyword_discouraged(&elseif));
+
         elseifs.push(IfElseIfStatement {
-            comments: state.iterator.comments(),
-            elseif: utils::skip_keyword(state, TokenKind::ElseIf)?,
+            comments,
+            elseif,
             condition: expression::create(state)?,
             block: block::block_statement(state)?,
         });
@@ -62,7 +69,7 @@ pub fn using_statement(state: &mut State) -> ParseResult<UsingStatement> {
     let comments = state.iterator.comments();
     let r#using = utils::skip_keyword(state, TokenKind::Using)?;
     let mut inner = vec![];
-    let mut commas = vec![];
+    let mut commas = SmallVec::new();
 
     let mut current = state.iterator.current();
     loop {
@@ -89,7 +96,7 @@ pub fn using_statement(state: &mut State) -> ParseResult<UsingStatement> {
             break;
         }
 
-        commas.push(current.position);
+        commas.push(current.start());
 
         state.iterator.next();
 
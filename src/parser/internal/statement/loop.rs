@@ -1,3 +1,5 @@
+use smallvec::SmallVec;
+
 use crate::lexer::token::Token;
 use crate::lexer::token::TokenKind;
 use crate::parser::internal::expression;
@@ -14,10 +16,54 @@ use crate::tree::statement::r#loop::ContinueStatement;
 use crate::tree::statement::r#loop::DoWhileStatement;
 use crate::tree::statement::r#loop::ForIteratorStatement;
 use crate::tree::statement::r#loop::ForStatement;
+use crate::tree::statement::r#loop::ForeachIteratorKeyStatement;
 use crate::tree::statement::r#loop::ForeachIteratorStatement;
 use crate::tree::statement::r#loop::ForeachStatement;
 use crate::tree::statement::r#loop::WhileStatement;
 use crate::tree::utils::CommaSeparated;
+use crate::tree::variable::Variable;
+
+/// Parse the key of a `foreach` iterator, which is either a variable, or a tuple pattern, e.g.
+/// `($a, $b)`, the key is the value of the iterator if it is not followed by `=>`.
+fn foreach_iterator_key(state: &mut State) -> ParseResult<ForeachIteratorKeyStatement> {
+    if state.iterator.current().kind != TokenKind::LeftParen {
+        return Ok(ForeachIteratorKeyStatement::Variable(variable::parse(
+            state,
+        )?));
+    }
+
+    let left_parenthesis = utils::skip_left_parenthesis(state)?;
+    let first = foreach_iterator_key(state)?;
+    let comma = utils::skip(state, TokenKind::Comma)?;
+
+    let mut elements =
+        utils::at_least_one_comma_separated(state, &foreach_iterator_key, TokenKind::RightParen)?;
+    elements.inner.insert(0, first);
+    elements.commas.insert(0, comma);
+
+    Ok(ForeachIteratorKeyStatement::Tuple {
+        left_parenthesis,
+        elements,
+        right_parenthesis: utils::skip_right_parenthesis(state)?,
+    })
+}
+
+/// The value of a `foreach` iterator without a key, which was parsed as a key, only keys can
+/// be tuple patterns.
+fn foreach_iterator_value(
+    state: &mut State,
+    key: ForeachIteratorKeyStatement,
+) -> ParseResult<Variable> {
+    match key {
+        ForeachIteratorKeyStatement::Variable(variable) => Ok(variable),
+        ForeachIteratorKeyStatement::Tuple { .. } => {
+            crate::parser_bail!(
+                state,
+                unexpected_token(vec!["=>"], state.iterator.current())
+            );
+        }
+    }
+}
 
 pub fn foreach_statement(state: &mut State) -> ParseResult<ForeachStatement> {
     let comments = state.iterator.comments();
@@ -35,14 +81,13 @@ pub fn foreach_statement(state: &mut State) -> ParseResult<ForeachStatement> {
             if state.iterator.current().kind == TokenKind::As {
                 // this is either 1 or 2
                 let r#as = utils::skip_keyword(state, TokenKind::As)?;
-                let mut value = variable::parse(state)?;
+                let key = foreach_iterator_key(state)?;
                 let current = state.iterator.current();
 
                 break 'iterator if current.kind == TokenKind::DoubleArrow {
                     state.iterator.next();
-                    let double_arrow = current.position;
-                    let mut key = variable::parse(state)?;
-                    std::mem::swap(&mut value, &mut key);
+                    let double_arrow = current.start();
+                    let value = variable::parse(state)?;
 
                     ForeachIteratorStatement::ParenthesizedKeyAndValue {
                         left_parenthesis,
@@ -58,7 +103,7 @@ pub fn foreach_statement(state: &mut State) -> ParseResult<ForeachStatement> {
                         left_parenthesis,
                         expression,
                         r#as,
-                        value,
+                        value: foreach_iterator_value(state, key)?,
                         right_parenthesis: utils::skip(state, TokenKind::RightParen)?,
                     }
                 };
@@ -80,14 +125,13 @@ pub fn foreach_statement(state: &mut State) -> ParseResult<ForeachStatement> {
         // 2. foreach $array as $key => $value { ... }
         let r#as = utils::skip_keyword(state, TokenKind::As)?;
 
-        let mut value = variable::parse(state)?;
+        let key = foreach_iterator_key(state)?;
 
         let current = state.iterator.current();
         if current.kind == TokenKind::DoubleArrow {
             state.iterator.next();
-            let double_arrow = current.position;
-            let mut key = variable::parse(state)?;
-            std::mem::swap(&mut value, &mut key);
+            let double_arrow = current.start();
+            let value = variable::parse(state)?;
 
             ForeachIteratorStatement::KeyAndValue {
                 expression,
@@ -100,7 +144,7 @@ pub fn foreach_statement(state: &mut State) -> ParseResult<ForeachStatement> {
             ForeachIteratorStatement::Value {
                 expression,
                 r#as,
-                value,
+                value: foreach_iterator_value(state, key)?,
             }
         }
     };
@@ -174,14 +218,13 @@ pub fn for_statement(state: &mut State) -> ParseResult<ForStatement> {
 
                     initializations.inner =
                         [expressions, initializations.inner.as_slice()].concat();
-                    initializations.commas =
-                        [commas.as_slice(), initializations.commas.as_slice()].concat();
+                    initializations.commas.insert_many(0, commas);
 
                     initializations
                 } else {
                     CommaSeparated {
                         inner: expressions.to_vec(),
-                        commas: vec![],
+                        commas: SmallVec::new(),
                     }
                 };
 
@@ -276,7 +319,7 @@ fn maybe_loop_level(state: &mut State) -> Option<LiteralInteger> {
         return Some(LiteralInteger {
             comments: state.iterator.comments(),
             value: value.clone(),
-            position: *position,
+            position: *position as usize,
         });
     }
 
@@ -1,6 +1,9 @@
 use crate::lexer::token::TokenKind;
 use crate::parser::internal::expression;
+use crate::parser::internal::recovery;
+use crate::parser::internal::suggestion;
 use crate::parser::internal::utils;
+use crate::parser::issue;
 use crate::parser::result::ParseResult;
 use crate::parser::state::State;
 use crate::tree::statement::expression::ExpressionStatement;
@@ -12,13 +15,19 @@ pub mod control_flow;
 pub mod r#loop;
 pub mod r#try;
 
-fn statement(state: &mut State) -> ParseResult<Statement> {
+pub fn statement(state: &mut State) -> ParseResult<Statement> {
+    utils::nested(state, statement_within_depth)
+}
+
+fn statement_within_depth(state: &mut State) -> ParseResult<Statement> {
     let current = state.iterator.current();
 
     if matches!(current.kind, TokenKind::OpenTag(_)) {
         state.iterator.next();
 
-        crate::parser_report!(state, php_opening_tag_not_supported(current));
+        if !state.options.allow_php_tags {
+            crate::parser_report!(state, php_opening_tag_not_supported(current));
+        }
 
         return statement(state);
     }
@@ -26,7 +35,9 @@ fn statement(state: &mut State) -> ParseResult<Statement> {
     if matches!(current.kind, TokenKind::CloseTag) {
         state.iterator.next();
 
-        crate::parser_report!(state, php_closing_tag_not_supported(current));
+        if !state.options.allow_php_tags {
+            crate::parser_report!(state, php_closing_tag_not_supported(current));
+        }
 
         return statement(state);
     }
@@ -62,9 +73,29 @@ fn statement(state: &mut State) -> ParseResult<Statement> {
         TokenKind::SemiColon => Statement::Empty(utils::skip_semicolon(state)?),
         _ => {
             let comments = state.iterator.comments();
-            let expression = expression::create(state)?;
+            let first = state.iterator.current();
+            let expression = expression::create(state).map_err(|mut report| {
+                // a statement starting with a misspelled keyword, e.g. `foreech`, fails to
+                // parse as an expression, often only after the misspelled keyword itself.
+                if let Some(issue) = report.issues.pop() {
+                    report.issues.push(issue::suggest_keyword(
+                        state,
+                        issue,
+                        first,
+                        suggestion::STATEMENT_KEYWORDS,
+                    ));
+                }
+
+                report
+            })?;
 
-            if state.iterator.current().kind == TokenKind::SemiColon {
+            let current = state.iterator.current();
+            // an expression at the end of a block is an implicit return, elsewhere the
+            // semicolon ending its statement is most likely missing.
+            if current.kind == TokenKind::SemiColon
+                || (!matches!(current.kind, TokenKind::RightBrace | TokenKind::Eof)
+                    && recovery::is_missing_semicolon(state))
+            {
                 Statement::Expression(Box::new(ExpressionStatement {
                     comments,
                     expression,
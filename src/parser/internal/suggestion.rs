@@ -0,0 +1,186 @@
+use crate::lexer::token::Token;
+use crate::lexer::token::TokenKind;
+
+/// The keywords that may be suggested in place of a misspelled identifier.
+pub const KEYWORDS: [&str; 61] = [
+    "abstract",
+    "as",
+    "async",
+    "await",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "clone",
+    "concurrently",
+    "const",
+    "continue",
+    "default",
+    "dict",
+    "do",
+    "else",
+    "enum",
+    "exit",
+    "extends",
+    "false",
+    "final",
+    "finally",
+    "fn",
+    "for",
+    "foreach",
+    "from",
+    "function",
+    "if",
+    "implements",
+    "in",
+    "instanceof",
+    "interface",
+    "into",
+    "is",
+    "isset",
+    "match",
+    "namespace",
+    "new",
+    "null",
+    "parent",
+    "permits",
+    "pragma",
+    "print",
+    "private",
+    "protected",
+    "public",
+    "readonly",
+    "return",
+    "self",
+    "static",
+    "throw",
+    "true",
+    "try",
+    "type",
+    "unset",
+    "use",
+    "using",
+    "vec",
+    "where",
+    "while",
+    "yield",
+];
+
+/// The keywords starting a definition.
+pub const DEFINITION_KEYWORDS: [&str; 12] = [
+    "namespace",
+    "use",
+    "const",
+    "type",
+    "enum",
+    "interface",
+    "async",
+    "function",
+    "readonly",
+    "final",
+    "abstract",
+    "class",
+];
+
+/// The keywords starting a statement.
+pub const STATEMENT_KEYWORDS: [&str; 14] = [
+    "if",
+    "while",
+    "do",
+    "for",
+    "foreach",
+    "return",
+    "try",
+    "throw",
+    "break",
+    "continue",
+    "using",
+    "yield",
+    "await",
+    "concurrently",
+];
+
+/// The keyword among the given keywords that the given token is most likely a misspelling of.
+///
+/// Only identifiers are considered, and only keywords at an edit distance of less than a third
+/// of the length of the identifier, counting a transposition of two adjacent characters as a
+/// single edit, e.g. `funtcion` for `function`.
+pub fn misspelled_keyword<'a, K: IntoIterator<Item = &'a str>>(
+    token: &Token,
+    keywords: K,
+) -> Option<&'a str> {
+    if token.kind != TokenKind::Identifier {
+        return None;
+    }
+
+    let identifier = token.value.to_string().to_ascii_lowercase();
+    keywords
+        .into_iter()
+        .map(|keyword| (distance(identifier.as_bytes(), keyword.as_bytes()), keyword))
+        .filter(|(distance, _)| *distance > 0 && *distance * 3 < identifier.len())
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, keyword)| keyword)
+}
+
+/// The optimal string alignment distance between the given strings.
+fn distance(a: &[u8], b: &[u8]) -> usize {
+    let mut distances = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[0] = i;
+    }
+
+    for (j, distance) in distances[0].iter_mut().enumerate() {
+        *distance = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+
+            distances[i][j] = (distances[i - 1][j] + 1)
+                .min(distances[i][j - 1] + 1)
+                .min(distances[i - 1][j - 1] + cost);
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distances[i][j] = distances[i][j].min(distances[i - 2][j - 2] + 1);
+            }
+        }
+    }
+
+    distances[a.len()][b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::lexer::byte_string::ByteString;
+
+    fn identifier(value: &str) -> Token {
+        Token {
+            kind: TokenKind::Identifier,
+            value: ByteString::from(value),
+            position: 0,
+        }
+    }
+
+    #[test]
+    fn test_misspelled_keyword() {
+        assert_eq!(
+            misspelled_keyword(&identifier("funtcion"), KEYWORDS),
+            Some("function")
+        );
+        assert_eq!(
+            misspelled_keyword(&identifier("foreech"), KEYWORDS),
+            Some("foreach")
+        );
+        assert_eq!(
+            misspelled_keyword(&identifier("Clas"), DEFINITION_KEYWORDS),
+            Some("class")
+        );
+        assert_eq!(misspelled_keyword(&identifier("foo"), ["for"]), None);
+        assert_eq!(misspelled_keyword(&identifier("fo"), KEYWORDS), None);
+        assert_eq!(misspelled_keyword(&identifier("bar"), KEYWORDS), None);
+        assert_eq!(misspelled_keyword(&identifier("fnction"), ["fn"]), None);
+    }
+}
@@ -0,0 +1,128 @@
+use ara_source::source::Source;
+
+use crate::lexer::byte_string::ByteString;
+use crate::lexer::token::Token;
+use crate::lexer::token::TokenKind;
+use crate::tree::trivia::Trivia;
+use crate::tree::trivia::TriviaKind;
+use crate::tree::trivia::TriviaTable;
+use crate::tree::trivia::TriviaToken;
+
+/// Build the trivia table of the given source from its tokens.
+///
+/// Comments are turned into trivia, and the gaps between tokens, which the lexer skips,
+/// are turned into whitespace trivia.
+pub fn table(source: &Source, tokens: &[Token]) -> TriviaTable {
+    let content = source.content.as_bytes();
+
+    let mut table = TriviaTable {
+        tokens: vec![],
+        trailing: vec![],
+    };
+
+    let mut leading = vec![];
+    let mut offset = 0;
+    for token in tokens {
+        if token.start() > offset {
+            leading.push(Trivia {
+                kind: TriviaKind::Whitespace,
+                position: offset,
+                value: ByteString::from(&content[offset..token.start()]),
+            });
+        }
+
+        offset = token.end();
+
+        let kind = match token.kind {
+            TokenKind::SingleLineComment => TriviaKind::SingleLineComment,
+            TokenKind::MultiLineComment => TriviaKind::MultiLineComment,
+            TokenKind::HashMarkComment => TriviaKind::HashMarkComment,
+            TokenKind::DocumentComment => TriviaKind::DocumentComment,
+            TokenKind::Eof => {
+                table.trailing = std::mem::take(&mut leading);
+
+                break;
+            }
+            _ => {
+                table.tokens.push(TriviaToken {
+                    position: token.start(),
+                    value: ByteString::from(token.text()),
+                    leading: std::mem::take(&mut leading),
+                });
+
+                continue;
+            }
+        };
+
+        leading.push(Trivia {
+            kind,
+            position: token.start(),
+            value: token.value.clone(),
+        });
+    }
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser;
+    use crate::tree::definition::Definition;
+    use ara_source::source::SourceKind;
+
+    #[test]
+    fn test_trivia_table() {
+        let code = "// foo\nfunction foo(): void {\n    /* bar */ bar();\n}\n";
+        let source = Source::inline(SourceKind::Script, code);
+
+        let tree = parser::parse_lossless(&source).unwrap();
+        let table = tree.trivia.as_ref().unwrap();
+
+        assert_eq!(table.source().as_bytes(), code.as_bytes());
+        assert_eq!(
+            table
+                .leading(&tree.definitions.definitions[0])
+                .iter()
+                .map(|trivia| trivia.kind.clone())
+                .collect::<Vec<TriviaKind>>(),
+            vec![TriviaKind::SingleLineComment, TriviaKind::Whitespace]
+        );
+        assert_eq!(table.trailing.len(), 1);
+    }
+
+    #[test]
+    fn test_trivia_between() {
+        let code = "function foo(): void {\n    bar() /* baz */ ;\n} // qux\n";
+        let source = Source::inline(SourceKind::Script, code);
+
+        let tree = parser::parse_lossless(&source).unwrap();
+        let table = tree.trivia.as_ref().unwrap();
+
+        let semicolon = code.find(';').unwrap();
+        assert_eq!(
+            table
+                .between(semicolon - 12, semicolon)
+                .iter()
+                .map(|trivia| trivia.value.to_string())
+                .collect::<Vec<String>>(),
+            vec![" ", "/* baz */", " "]
+        );
+
+        let end = code.rfind('}').unwrap() + 1;
+        assert_eq!(table.between(end, code.len()).len(), 3);
+        assert_eq!(table.between(semicolon, semicolon + 1).len(), 0);
+
+        let Definition::Function(function) = &tree.definitions.definitions[0] else {
+            unreachable!()
+        };
+        assert_eq!(
+            table.gap(&function.function, &function.name).as_bytes(),
+            b" "
+        );
+        assert_eq!(
+            table.gap(&function.name, &function.parameters).as_bytes(),
+            b""
+        );
+    }
+}
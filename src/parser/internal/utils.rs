@@ -1,17 +1,64 @@
+use smallvec::SmallVec;
+
 use crate::lexer::token::TokenKind;
+use crate::parser::internal::recovery;
 use crate::parser::result::ParseResult;
 use crate::parser::state::State;
 use crate::tree::token::Keyword;
 use crate::tree::utils::CommaSeparated;
 use crate::tree::Node;
 
+/// The amount of stack left below which parsing continues on a new stack segment, see `nested`,
+/// enough to parse a single level of nesting even in debug builds.
+const RED_ZONE: usize = 512 * 1024;
+
+/// The size of each new stack segment, see `nested`.
+const SEGMENT_SIZE: usize = 4 * 1024 * 1024;
+
+/// Parse a nested expression, statement, or type using the given parser, reporting an issue
+/// instead if the maximum nesting depth is reached.
+///
+/// When the stack is about to run out, parsing continues on a new, larger, stack segment, so
+/// that deeply nested code does not overflow the stack of the calling thread, regardless of its
+/// size, while ordinary code is parsed on the stack of the calling thread.
+///
+/// On platforms where the stack can not grow, such as WebAssembly, the maximum nesting depth
+/// alone prevents overflowing the stack, see `ParserOptions::max_depth`.
+pub fn nested<T, F: FnOnce(&mut State) -> ParseResult<T>>(
+    state: &mut State,
+    parse: F,
+) -> ParseResult<T> {
+    if state.depth >= state.options.max_depth {
+        crate::parser_bail!(
+            state,
+            maximum_nesting_depth_exceeded(state.iterator.current())
+        );
+    }
+
+    state.depth += 1;
+    let result = stacker::maybe_grow(RED_ZONE, SEGMENT_SIZE, || parse(state));
+    state.depth -= 1;
+
+    result
+}
+
 pub fn skip_semicolon(state: &mut State) -> ParseResult<usize> {
     let current = state.iterator.current();
 
     if current.kind == TokenKind::SemiColon {
         state.iterator.next();
 
-        Ok(current.position)
+        Ok(current.start())
+    } else if recovery::is_missing_semicolon(state) {
+        let previous = state.iterator.previous();
+
+        if !state.can_recover() {
+            crate::parser_bail!(state, missing_semicolon(previous));
+        }
+
+        crate::parser_report!(state, missing_semicolon(previous));
+
+        Ok(previous.end())
     } else {
         crate::parser_bail!(state, unexpected_token(vec![";"], current));
     }
@@ -45,11 +92,23 @@ pub fn skip_colon(state: &mut State) -> ParseResult<usize> {
     skip(state, TokenKind::Colon)
 }
 
+/// Whether the current token is of the given kind, if not, the kind is recorded as expected, so
+/// that it is included in the issue reported if the current token turns out to be unexpected.
+pub fn at(state: &mut State, kind: TokenKind) -> bool {
+    if state.iterator.current().kind == kind {
+        true
+    } else {
+        state.expect(kind);
+
+        false
+    }
+}
+
 pub fn skip_keyword(state: &mut State, kind: TokenKind) -> ParseResult<Keyword> {
     let current = state.iterator.current();
 
     if current.kind == kind {
-        let keyword = Keyword::new(current.value.clone(), current.position);
+        let keyword = Keyword::new(current.value.clone(), current.start());
 
         state.iterator.next();
 
@@ -63,7 +122,7 @@ pub fn skip(state: &mut State, kind: TokenKind) -> ParseResult<usize> {
     let current = state.iterator.current();
 
     if current.kind == kind {
-        let end = current.position;
+        let end = current.start();
 
         state.iterator.next();
 
@@ -76,22 +135,23 @@ pub fn skip(state: &mut State, kind: TokenKind) -> ParseResult<usize> {
 /// Parse a comma-separated list of items, allowing a trailing comma.
 pub fn comma_separated<T: Node>(
     state: &mut State,
-    func: &(dyn Fn(&mut State) -> ParseResult<T>),
+    func: &dyn Fn(&mut State) -> ParseResult<T>,
     until: TokenKind,
 ) -> ParseResult<CommaSeparated<T>> {
     let mut inner: Vec<T> = vec![];
-    let mut commas: Vec<usize> = vec![];
+    let mut commas = SmallVec::new();
     let mut current = state.iterator.current();
 
     while current.kind != until {
         inner.push(func(state)?);
 
-        current = state.iterator.current();
-        if current.kind != TokenKind::Comma {
+        if !at(state, TokenKind::Comma) {
             break;
         }
 
-        commas.push(current.position);
+        current = state.iterator.current();
+
+        commas.push(current.start());
 
         state.iterator.next();
 
@@ -104,21 +164,22 @@ pub fn comma_separated<T: Node>(
 /// Parse a comma-separated list of items, requiring at least one item, and not allowing trailing commas.
 pub fn at_least_one_comma_separated<T: Node>(
     state: &mut State,
-    func: &(dyn Fn(&mut State) -> ParseResult<T>),
+    func: &dyn Fn(&mut State) -> ParseResult<T>,
     until: TokenKind,
 ) -> ParseResult<CommaSeparated<T>> {
     let mut inner: Vec<T> = vec![];
-    let mut commas: Vec<usize> = vec![];
+    let mut commas = SmallVec::new();
 
     loop {
         inner.push(func(state)?);
 
-        let mut current = state.iterator.current();
-        if current.kind != TokenKind::Comma {
+        if !at(state, TokenKind::Comma) {
             break;
         }
 
-        commas.push(current.position);
+        let mut current = state.iterator.current();
+
+        commas.push(current.start());
 
         state.iterator.next();
 
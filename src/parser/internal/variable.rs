@@ -6,7 +6,7 @@ use crate::tree::variable::Variable;
 pub fn parse(state: &mut State) -> ParseResult<Variable> {
     let current = state.iterator.current();
     if let TokenKind::Variable = &current.kind {
-        let position = current.position;
+        let position = current.start();
         let name = current.value.clone();
         state.iterator.next();
 
@@ -2,14 +2,34 @@
 
 use ara_reporting::annotation::Annotation;
 use ara_reporting::issue::Issue;
+use ara_reporting::Report;
+use ara_source::SourceMap;
+use std::collections::HashMap;
 
 use crate::lexer::token::Token;
 use crate::lexer::token::TokenKind;
+use crate::parser::edit::Edit;
+use crate::parser::internal::suggestion;
 use crate::parser::state::State as ParserState;
+use crate::tree::definition::constant::ClassishConstantDefinition;
+use crate::tree::definition::function::FunctionDefinition;
+use crate::tree::definition::modifier::ModifierDefinition;
+use crate::tree::definition::pragma::PragmaDefinition;
+use crate::tree::definition::property::PropertyDefinition;
+use crate::tree::expression::argument::ArgumentExpression;
+use crate::tree::expression::Expression;
 use crate::tree::identifier::Identifier;
+use crate::tree::span::LineIndex;
+use crate::tree::span::Location;
+use crate::tree::span::Span;
+use crate::tree::token::Keyword;
+use crate::tree::variable::Variable;
 use crate::tree::Node;
 
-#[derive(Debug, Copy, Clone)]
+/// The code of an issue reported by the parser, displayed as `P` followed by its number, e.g. `P0001`.
+///
+/// Codes are stable, the number of a code never changes, and new codes are only ever appended.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 #[repr(u8)]
 pub enum ParserIssueCode {
     /// An unreachable code was encountered.
@@ -170,6 +190,286 @@ pub enum ParserIssueCode {
     /// }
     /// ```
     UnexpectedToken = 11,
+
+    /// Pragma must be declared at the top of the file ( code = 12 )
+    ///
+    /// Example:
+    ///
+    /// ```ara
+    /// namespace Foo;
+    ///
+    /// pragma strict_generics;
+    /// ```
+    ///
+    /// Possible solution(s):
+    ///
+    /// - Move the pragma before any other definition
+    PragmaMustBeDeclaredAtTheTopOfTheFile = 12,
+
+    /// Variadic template can only be used in a definition source ( code = 13 )
+    ///
+    /// Example:
+    ///
+    /// ```ara
+    /// function foo<...T>(T ...$args): void {}
+    /// ```
+    ///
+    /// Possible solution(s):
+    ///
+    /// - Move the declaration to a definition source ( `.d.ara` )
+    /// - Remove the `...` from the template
+    VariadicTemplateCanOnlyBeUsedInDefinitionSource = 13,
+
+    /// Interface cannot contain properties ( code = 14 )
+    ///
+    /// Example:
+    ///
+    /// ```ara
+    /// interface Foo {
+    ///     public int $bar;
+    /// }
+    /// ```
+    ///
+    /// Possible solution(s):
+    ///
+    /// - Replace the property with getter and setter methods
+    /// - Move the property to a class implementing the interface
+    InterfaceCannotContainProperties = 14,
+
+    /// Interface constant cannot be final ( code = 15 )
+    ///
+    /// Example:
+    ///
+    /// ```ara
+    /// interface Foo {
+    ///     final public const int BAR = 1;
+    /// }
+    /// ```
+    ///
+    /// Possible solution(s):
+    ///
+    /// - Remove the `final` modifier
+    InterfaceConstantCannotBeFinal = 15,
+
+    /// Parameter cannot be passed by reference ( code = 16 )
+    ///
+    /// Example:
+    ///
+    /// ```ara
+    /// function foo(vec<int> &$bar): void {}
+    /// ```
+    ///
+    /// Possible solution(s):
+    ///
+    /// - Remove the `&` and return the modified value instead
+    /// - Wrap the value in an object, which is always passed by handle
+    ParameterCannotBePassedByReference = 16,
+
+    /// Maximum nesting depth exceeded ( code = 17 )
+    ///
+    /// Example:
+    ///
+    /// ```ara
+    /// function foo(): int {
+    ///     return ((((((((((((((((((((1))))))))))))))))))));
+    /// }
+    /// ```
+    ///
+    /// Possible solution(s):
+    ///
+    /// - Split the nested expression, statement, or type into smaller parts
+    /// - Increase the maximum nesting depth using the parser options
+    MaximumNestingDepthExceeded = 17,
+
+    /// Missing semicolon ( code = 18 )
+    ///
+    /// Example:
+    ///
+    /// ```ara
+    /// function foo(): int {
+    ///     $a = 1
+    ///     return $a;
+    /// }
+    /// ```
+    ///
+    /// Possible solution(s):
+    ///
+    /// - Add the missing `;` at the end of the statement
+    MissingSemicolon = 18,
+
+    /// Function without a body can only be used in a definition source ( code = 19 )
+    ///
+    /// Example:
+    ///
+    /// ```ara
+    /// function foo(): void;
+    /// ```
+    ///
+    /// Possible solution(s):
+    ///
+    /// - Move the declaration to a definition source ( `.d.ara` )
+    /// - Add a body to the function
+    FunctionWithoutBodyCanOnlyBeUsedInDefinitionSource = 19,
+
+    /// Maximum number of issues reached ( code = 20 )
+    ///
+    /// Reported once parsing stops, after reaching the maximum number of errors, instead of
+    /// any further errors.
+    ///
+    /// Possible solution(s):
+    ///
+    /// - Fix the reported errors, and parse the source again
+    /// - Increase the maximum number of issues using the parser options
+    MaximumIssuesReached = 20,
+
+    /// PHP array literal is not supported ( code = 21 )
+    ///
+    /// Example:
+    ///
+    /// ```ara
+    /// function foo(): void {
+    ///     $a = array(1, 2, 3);
+    /// }
+    /// ```
+    ///
+    /// Possible solution(s):
+    ///
+    /// - Use a `vec` literal ( `vec[1, 2, 3]` ), or a `dict` literal ( `dict['a' => 1]` )
+    ArrayLiteralNotSupported = 21,
+
+    /// PHP cast is not supported ( code = 22 )
+    ///
+    /// Example:
+    ///
+    /// ```ara
+    /// function foo(string $a): int {
+    ///     return (int) $a;
+    /// }
+    /// ```
+    ///
+    /// Possible solution(s):
+    ///
+    /// - Use an `into` operation ( `$a into int` )
+    LegacyCastNotSupported = 22,
+
+    /// Unknown definition keyword ( code = 23 )
+    ///
+    /// Example:
+    ///
+    /// ```ara
+    /// clas Foo {
+    /// }
+    /// ```
+    ///
+    /// Possible solution(s):
+    ///
+    /// - Correct the spelling of the keyword, e.g. `class`
+    UnknownDefinitionKeyword = 23,
+
+    /// Dict key must be a constant expression ( code = 24 )
+    ///
+    /// Example:
+    ///
+    /// ```ara
+    /// const dict<string, int> FOO = dict[foo() => 1];
+    /// ```
+    ///
+    /// Possible solution(s):
+    ///
+    /// - Use a literal, or a constant, as the key
+    DictKeyMustBeConstant = 24,
+
+    /// `elseif` keyword is discouraged ( code = 25 )
+    ///
+    /// Example:
+    ///
+    /// ```ara
+    /// function foo(int $a): void {
+    ///     if $a > 1 {
+    ///     } elseif $a < 0 {
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Possible solution(s):
+    ///
+    /// - Use `else if` instead of `elseif`
+    ElseIfKeywordDiscouraged = 25,
+
+    /// Attribute argument must be a constant expression ( code = 26 )
+    ///
+    /// Example:
+    ///
+    /// ```ara
+    /// #[Foo(bar())]
+    /// function baz(): void {}
+    /// ```
+    ///
+    /// Possible solution(s):
+    ///
+    /// - Use a literal, a constant, or a class initialization with constant arguments
+    AttributeArgumentMustBeConstant = 26,
+
+    /// Parameter cannot be of type `never` ( code = 27 )
+    ///
+    /// Example:
+    ///
+    /// ```ara
+    /// function foo(never $bar): void {}
+    /// ```
+    ///
+    /// Possible solution(s):
+    ///
+    /// - Use a different type, e.g. `mixed`
+    /// - Remove the parameter
+    NeverTypeCannotBeUsedForParameter = 27,
+
+    /// Invalid type alias modifier ( code = 28 )
+    ///
+    /// Example:
+    ///
+    /// ```ara
+    /// final type Foo = int;
+    ///
+    /// internal public type Bar = int;
+    /// ```
+    ///
+    /// Possible solution(s):
+    ///
+    /// - Remove the modifier, only a single `public`, or `internal`, modifier can be used
+    InvalidTypeAliasModifier = 28,
+
+    /// Argument cannot be passed by reference ( code = 29 )
+    ///
+    /// Example:
+    ///
+    /// ```ara
+    /// function foo(vec<int> $bar): void {
+    ///     baz(&$bar);
+    /// }
+    /// ```
+    ///
+    /// Possible solution(s):
+    ///
+    /// - Remove the `&`, and use the value returned by the function instead
+    /// - Wrap the value in an object, which is always passed by handle
+    ArgumentCannotBePassedByReference = 29,
+
+    /// Function cannot return by reference ( code = 30 )
+    ///
+    /// Example:
+    ///
+    /// ```ara
+    /// function &foo(): vec<int> {
+    ///     return vec[];
+    /// }
+    /// ```
+    ///
+    /// Possible solution(s):
+    ///
+    /// - Remove the `&`, the function then returns a copy of the value
+    /// - Return an object, which is always returned by handle
+    FunctionCannotReturnByReference = 30,
 }
 
 pub(crate) fn unreachable_code<M: Into<String>>(state: &ParserState, message: M) -> Issue {
@@ -177,33 +477,29 @@ pub(crate) fn unreachable_code<M: Into<String>>(state: &ParserState, message: M)
 
     Issue::bug(ParserIssueCode::UnreachableCode, message).with_source(
         state.source.name(),
-        token.position,
-        token.position + token.value.len(),
+        token.start(),
+        token.end(),
     )
 }
 
 pub(crate) fn php_opening_tag_not_supported(state: &ParserState, token: &Token) -> Issue {
-    Issue::error(
+    let issue = Issue::error(
         ParserIssueCode::PHPOpeningTagNotSupported,
-        format!("PHP opening tag `{}` is not supported", token.value),
-    )
-    .with_source(
-        state.source.name(),
-        token.position,
-        token.position + token.value.len(),
+        format!("PHP opening tag `{token}` is not supported"),
     )
+    .with_source(state.source.name(), token.start(), token.end());
+
+    with_fix(state, issue, Edit::new(token.start(), token.end(), ""))
 }
 
 pub(crate) fn php_closing_tag_not_supported(state: &ParserState, token: &Token) -> Issue {
-    Issue::error(
+    let issue = Issue::error(
         ParserIssueCode::PHPClosingTagNotSupported,
-        format!("PHP closing tag `{}` is not supported", token.value),
-    )
-    .with_source(
-        state.source.name(),
-        token.position,
-        token.position + token.value.len(),
+        format!("PHP closing tag `{token}` is not supported"),
     )
+    .with_source(state.source.name(), token.start(), token.end());
+
+    with_fix(state, issue, Edit::new(token.start(), token.end(), ""))
 }
 
 pub(crate) fn unit_enum_case_cannot_have_value(
@@ -269,11 +565,7 @@ pub(crate) fn missing_item_definition_after_attributes(state: &ParserState) -> I
         ParserIssueCode::MissingItemDefinitionAfterAttributes,
         "missing item definition after attribute(s)",
     )
-    .with_source(
-        origin,
-        current.position,
-        current.position + current.value.len(),
-    )
+    .with_source(origin, current.start(), current.end())
     .with_note("an item definition can be a class, an interface, an enum, or a function.")
     .with_note("try adding an item definition after the attribute(s).");
 
@@ -341,11 +633,7 @@ pub(crate) fn missing_item_expression_after_attributes(state: &ParserState) -> I
         ParserIssueCode::MissingItemExpressionAfterAttributes,
         "missing item expression after attribute(s)",
     )
-    .with_source(
-        origin,
-        current.position,
-        current.position + current.value.len(),
-    )
+    .with_source(origin, current.start(), current.end())
     .with_note("an item expression can be an anonymous function, or an arrow function.")
     .with_note("try adding an item expression after the attribute(s)");
 
@@ -394,20 +682,34 @@ pub(crate) fn unexpected_token<T: ToString>(
             | TokenKind::Null
             | TokenKind::False
             | TokenKind::True => "literal".to_string(),
-            _ => format!("token `{}`", found.value),
+            _ => format!("token `{found}`"),
         },
     };
 
+    // include everything else that would have been accepted at the unexpected token.
+    let mut accepted = if state.expected_position == found.start() {
+        state.expected.clone()
+    } else {
+        vec![]
+    };
+
+    for expected in expected {
+        let expected = expected.to_string();
+        if !accepted.contains(&expected) {
+            accepted.push(expected);
+        }
+    }
+
+    let expected = accepted;
+
     let message = if expected.is_empty() {
         format!("unexpected {found_name}")
     } else {
         let expected: Vec<String> = expected
             .iter()
             .map(|s| {
-                let s: String = s.to_string();
-
                 if s.starts_with("a ") || s.starts_with("an ") {
-                    s
+                    s.clone()
                 } else {
                     format!("`{s}`")
                 }
@@ -426,13 +728,540 @@ pub(crate) fn unexpected_token<T: ToString>(
         format!("unexpected {found_name}, expected {expected}")
     };
 
-    Issue::error(ParserIssueCode::UnexpectedToken, message).with_source(
+    let issue = Issue::error(ParserIssueCode::UnexpectedToken, message).with_source(
+        state.source.name(),
+        found.start(),
+        found.end(),
+    );
+
+    let keywords = suggestion::KEYWORDS
+        .into_iter()
+        .filter(|keyword| expected.iter().any(|s| s == keyword));
+
+    if expected.iter().any(|s| s == "a definition") {
+        suggest_keyword(
+            state,
+            issue,
+            found,
+            keywords.chain(suggestion::DEFINITION_KEYWORDS),
+        )
+    } else {
+        suggest_keyword(state, issue, found, keywords)
+    }
+}
+
+/// Suggest the keyword the given token is most likely a misspelling of, among the given
+/// keywords, if any.
+pub(crate) fn suggest_keyword<'a, K: IntoIterator<Item = &'a str>>(
+    state: &ParserState,
+    issue: Issue,
+    token: &Token,
+    keywords: K,
+) -> Issue {
+    match suggestion::misspelled_keyword(token, keywords) {
+        Some(keyword) => issue.with_annotation(
+            Annotation::secondary(state.source.name(), token.start(), token.end())
+                .with_message(format!("did you mean `{keyword}`?")),
+        ),
+        None => issue,
+    }
+}
+
+pub(crate) fn pragma_must_be_declared_at_the_top_of_the_file(
+    state: &ParserState,
+    pragma: &PragmaDefinition,
+) -> Issue {
+    Issue::error(
+        ParserIssueCode::PragmaMustBeDeclaredAtTheTopOfTheFile,
+        format!(
+            "pragma `{}` must be declared at the top of the file",
+            pragma.name
+        ),
+    )
+    .with_source(
+        state.source.name(),
+        pragma.initial_position(),
+        pragma.final_position(),
+    )
+    .with_note("pragmas must be declared before any other definition.")
+}
+
+pub(crate) fn variadic_template_can_only_be_used_in_definition_source(
+    state: &ParserState,
+    from: usize,
+    to: usize,
+) -> Issue {
+    Issue::error(
+        ParserIssueCode::VariadicTemplateCanOnlyBeUsedInDefinitionSource,
+        "variadic template can only be used in a definition source",
+    )
+    .with_source(state.source.name(), from, to)
+    .with_note("definition sources are files with the `.d.ara` extension.")
+}
+
+pub(crate) fn function_without_body_can_only_be_used_in_definition_source(
+    state: &ParserState,
+    function: &FunctionDefinition,
+) -> Issue {
+    Issue::error(
+        ParserIssueCode::FunctionWithoutBodyCanOnlyBeUsedInDefinitionSource,
+        format!(
+            "function `{}` without a body can only be used in a definition source",
+            state.named(&function.name)
+        ),
+    )
+    .with_source(
+        state.source.name(),
+        function.initial_position(),
+        function.final_position(),
+    )
+    .with_note("definition sources are files with the `.d.ara` extension.")
+}
+
+pub(crate) fn maximum_issues_reached(state: &ParserState, max: usize) -> Issue {
+    Issue::note(
+        ParserIssueCode::MaximumIssuesReached,
+        format!(
+            "parsing \"{}\" stopped after reaching the maximum of {max} error(s)",
+            state.source.name()
+        ),
+    )
+    .with_note("the maximum number of issues can be increased using the parser options.")
+}
+
+pub(crate) fn interface_cannot_contain_properties(
+    state: &ParserState,
+    interface: &Identifier,
+    property: &PropertyDefinition,
+) -> Issue {
+    let origin = state.source.name();
+
+    Issue::error(
+        ParserIssueCode::InterfaceCannotContainProperties,
+        format!(
+            "interface `{}` cannot contain property `{}`",
+            state.named(&interface),
+            property.entry.variable().name,
+        ),
+    )
+    .with_source(
+        origin,
+        property.initial_position(),
+        property.final_position(),
+    )
+    .with_annotation(Annotation::secondary(
+        origin,
+        interface.initial_position(),
+        interface.final_position(),
+    ))
+}
+
+pub(crate) fn interface_constant_cannot_be_final(
+    state: &ParserState,
+    interface: &Identifier,
+    constant: &ClassishConstantDefinition,
+    modifier: &ModifierDefinition,
+) -> Issue {
+    let origin = state.source.name();
+
+    Issue::error(
+        ParserIssueCode::InterfaceConstantCannotBeFinal,
+        format!(
+            "constant `{}::{}` of interface `{}` cannot be final",
+            interface,
+            constant.name,
+            state.named(&interface),
+        ),
+    )
+    .with_source(
+        origin,
+        modifier.initial_position(),
+        modifier.final_position(),
+    )
+    .with_annotation(Annotation::secondary(
+        origin,
+        interface.initial_position(),
+        interface.final_position(),
+    ))
+    .with_annotation(Annotation::secondary(
+        origin,
+        constant.name.initial_position(),
+        constant.name.final_position(),
+    ))
+}
+
+pub(crate) fn parameter_cannot_be_passed_by_reference(
+    state: &ParserState,
+    ampersand: usize,
+    variable: &Variable,
+) -> Issue {
+    Issue::error(
+        ParserIssueCode::ParameterCannotBePassedByReference,
+        format!(
+            "parameter `{}` cannot be passed by reference",
+            variable.name
+        ),
+    )
+    .with_source(state.source.name(), ampersand, variable.final_position())
+    .with_note("by-reference parameters are not supported, parameters are always passed by value.")
+}
+
+pub(crate) fn maximum_nesting_depth_exceeded(state: &ParserState, token: &Token) -> Issue {
+    Issue::error(
+        ParserIssueCode::MaximumNestingDepthExceeded,
+        format!(
+            "maximum nesting depth of {} exceeded",
+            state.options.max_depth
+        ),
+    )
+    .with_source(state.source.name(), token.start(), token.end())
+    .with_note("the maximum nesting depth can be increased using the parser options.")
+}
+
+pub(crate) fn missing_semicolon(state: &ParserState, token: &Token) -> Issue {
+    let issue = Issue::error(
+        ParserIssueCode::MissingSemicolon,
+        format!("missing `;` after `{token}`"),
+    )
+    .with_source(state.source.name(), token.start(), token.end())
+    .with_note("parsing continued as if the `;` were present.");
+
+    with_fix(state, issue, Edit::new(token.end(), token.end(), ";"))
+}
+
+pub(crate) fn array_literal_not_supported(
+    state: &ParserState,
+    array: &Token,
+    left_parenthesis: usize,
+    right_parenthesis: usize,
+    keyed: bool,
+) -> Issue {
+    let literal = if keyed { "dict" } else { "vec" };
+
+    let issue = Issue::error(
+        ParserIssueCode::ArrayLiteralNotSupported,
+        "PHP array literal is not supported",
+    )
+    .with_source(state.source.name(), array.start(), right_parenthesis + 1)
+    .with_note("parsing continued as if the `vec`, or `dict`, literal were used.");
+
+    let issue = with_fix(
+        state,
+        issue,
+        Edit::new(array.start(), left_parenthesis + 1, format!("{literal}[")),
+    );
+
+    with_fix(
+        state,
+        issue,
+        Edit::new(right_parenthesis, right_parenthesis + 1, "]"),
+    )
+}
+
+pub(crate) fn legacy_cast_not_supported(
+    state: &ParserState,
+    left_parenthesis: usize,
+    r#type: &Token,
+    operand: &dyn Node,
+) -> Issue {
+    let name = match r#type.value.to_ascii_lowercase().as_slice() {
+        b"integer" => "int".to_string(),
+        b"double" => "float".to_string(),
+        b"boolean" => "bool".to_string(),
+        b"binary" => "string".to_string(),
+        name => String::from_utf8_lossy(name).to_string(),
+    };
+
+    let span = operand.span();
+    let issue = Issue::error(
+        ParserIssueCode::LegacyCastNotSupported,
+        format!("PHP cast `({})` is not supported", r#type.value),
+    )
+    .with_source(state.source.name(), left_parenthesis, span.end)
+    .with_note("parsing continued as if the cast were not present.");
+
+    let issue = with_fix(state, issue, Edit::new(left_parenthesis, span.start, "("));
+
+    with_fix(
+        state,
+        issue,
+        Edit::new(span.end, span.end, format!(" into {name})")),
+    )
+}
+
+pub(crate) fn unknown_definition_keyword(
+    state: &ParserState,
+    token: &Token,
+    keyword: &str,
+) -> Issue {
+    let issue = Issue::error(
+        ParserIssueCode::UnknownDefinitionKeyword,
+        format!("unknown definition keyword `{token}`, did you mean `{keyword}`?"),
+    )
+    .with_source(state.source.name(), token.start(), token.end());
+
+    with_fix(state, issue, Edit::new(token.start(), token.end(), keyword))
+}
+
+pub(crate) fn dict_key_must_be_constant(state: &ParserState, key: &Expression) -> Issue {
+    Issue::error(
+        ParserIssueCode::DictKeyMustBeConstant,
+        "dict key must be a constant expression",
+    )
+    .with_source(
+        state.source.name(),
+        key.initial_position(),
+        key.final_position(),
+    )
+    .with_note(
+        "dict keys of constant expressions, e.g. default values of parameters, must be constant.",
+    )
+}
+
+pub(crate) fn elseif_keyword_discouraged(state: &ParserState, keyword: &Keyword) -> Issue {
+    let issue = Issue::warning(
+        ParserIssueCode::ElseIfKeywordDiscouraged,
+        "`elseif` keyword is discouraged, use `else if` instead",
+    )
+    .with_source(
+        state.source.name(),
+        keyword.initial_position(),
+        keyword.final_position(),
+    );
+
+    with_fix(
+        state,
+        issue,
+        Edit::new(
+            keyword.initial_position(),
+            keyword.final_position(),
+            "else if",
+        ),
+    )
+}
+
+pub(crate) fn attribute_argument_must_be_constant(
+    state: &ParserState,
+    argument: &ArgumentExpression,
+) -> Issue {
+    Issue::error(
+        ParserIssueCode::AttributeArgumentMustBeConstant,
+        "attribute argument must be a constant expression",
+    )
+    .with_source(
+        state.source.name(),
+        argument.initial_position(),
+        argument.final_position(),
+    )
+    .with_note("attributes are evaluated without runtime context, e.g. variables, or functions.")
+}
+
+pub(crate) fn never_type_cannot_be_used_for_parameter(
+    state: &ParserState,
+    keyword: &Keyword,
+) -> Issue {
+    Issue::error(
+        ParserIssueCode::NeverTypeCannotBeUsedForParameter,
+        format!("parameter cannot be of type `{keyword}`"),
+    )
+    .with_source(
         state.source.name(),
-        found.position,
-        found.position + found.value.len(),
+        keyword.initial_position(),
+        keyword.final_position(),
+    )
+    .with_note(format!(
+        "`{keyword}` has no values, a function with a parameter of type `{keyword}` can never be called."
+    ))
+}
+
+pub(crate) fn invalid_type_alias_modifier(
+    state: &ParserState,
+    modifier: &ModifierDefinition,
+    visibility: Option<&ModifierDefinition>,
+) -> Issue {
+    let origin = state.source.name();
+
+    let issue = Issue::error(
+        ParserIssueCode::InvalidTypeAliasModifier,
+        format!("modifier `{modifier}` cannot be used on a type alias"),
+    )
+    .with_source(
+        origin,
+        modifier.initial_position(),
+        modifier.final_position(),
+    );
+
+    let issue = match visibility {
+        Some(visibility) => issue.with_annotation(
+            Annotation::secondary(
+                origin,
+                visibility.initial_position(),
+                visibility.final_position(),
+            )
+            .with_message("visibility of the type alias is already declared here"),
+        ),
+        None => issue.with_note("type aliases can only be marked `public`, or `internal`."),
+    };
+
+    with_fix(
+        state,
+        issue,
+        Edit::new(
+            modifier.initial_position(),
+            modifier.final_position() + 1,
+            "",
+        ),
+    )
+}
+
+pub(crate) fn argument_cannot_be_passed_by_reference(
+    state: &ParserState,
+    ampersand: usize,
+) -> Issue {
+    let issue = Issue::error(
+        ParserIssueCode::ArgumentCannotBePassedByReference,
+        "argument cannot be passed by reference",
+    )
+    .with_source(state.source.name(), ampersand, ampersand + 1)
+    .with_note("references are not supported, arguments are always passed by value.")
+    .with_note("parsing continued as if the `&` were not present.");
+
+    with_fix(state, issue, Edit::new(ampersand, ampersand + 1, ""))
+}
+
+pub(crate) fn function_cannot_return_by_reference(state: &ParserState, ampersand: usize) -> Issue {
+    let issue = Issue::error(
+        ParserIssueCode::FunctionCannotReturnByReference,
+        "function cannot return by reference",
+    )
+    .with_source(state.source.name(), ampersand, ampersand + 1)
+    .with_note("references are not supported, functions always return by value.")
+    .with_note("parsing continued as if the `&` were not present.");
+
+    with_fix(state, issue, Edit::new(ampersand, ampersand + 1, ""))
+}
+
+/// The prefix of the message of an annotation holding a fix, see `fixes`.
+const FIX: &str = "fix: ";
+
+/// Attach the given edit to the given issue, as part of the fix of the issue.
+///
+/// The edit is held by a secondary annotation, so it is kept along with the issue wherever
+/// the issue goes, e.g. through a report, while remaining readable when the issue is displayed.
+fn with_fix(state: &ParserState, issue: Issue, edit: Edit) -> Issue {
+    let message = if edit.replacement.is_empty() {
+        format!("{FIX}remove")
+    } else if edit.start == edit.end {
+        format!("{FIX}insert `{}`", edit.replacement)
+    } else {
+        format!("{FIX}replace with `{}`", edit.replacement)
+    };
+
+    issue.with_annotation(
+        Annotation::secondary(state.source.name(), edit.start, edit.end).with_message(message),
     )
 }
 
+/// The edits fixing the given issue, in the order of their positions, empty if the issue has no
+/// fix.
+///
+/// The edits of a fix do not overlap, and are meant to be applied together, from the last to the
+/// first, so that applying an edit does not move the positions of the edits before it.
+pub fn fixes(issue: &Issue) -> Vec<Edit> {
+    let mut edits = issue
+        .annotations
+        .iter()
+        .filter_map(|annotation| {
+            let fix = annotation.message.as_deref()?.strip_prefix(FIX)?;
+
+            let replacement = if fix == "remove" {
+                ""
+            } else {
+                fix.strip_prefix("insert ")
+                    .or_else(|| fix.strip_prefix("replace with "))?
+                    .strip_prefix('`')?
+                    .strip_suffix('`')?
+            };
+
+            Some(Edit::new(annotation.from, annotation.to, replacement))
+        })
+        .collect::<Vec<_>>();
+
+    edits.sort_by_key(|edit| edit.start);
+
+    edits
+}
+
+impl ParserIssueCode {
+    /// All codes, in order of their numbers.
+    pub const ALL: [Self; 31] = [
+        Self::UnreachableCode,
+        Self::PHPOpeningTagNotSupported,
+        Self::PHPClosingTagNotSupported,
+        Self::UnitEnumCaseCannotHaveValue,
+        Self::BackedEnumCaseMustHaveValue,
+        Self::MissingItemDefinitionAfterAttributes,
+        Self::ReservedKeywordCannotBeUsedForTypeName,
+        Self::ReservedKeywordCannotBeUsedForConstantName,
+        Self::TypeCannotBeUsedInCurrentContext,
+        Self::MissingItemExpressionAfterAttributes,
+        Self::InvalidEnumBackingType,
+        Self::UnexpectedToken,
+        Self::PragmaMustBeDeclaredAtTheTopOfTheFile,
+        Self::VariadicTemplateCanOnlyBeUsedInDefinitionSource,
+        Self::InterfaceCannotContainProperties,
+        Self::InterfaceConstantCannotBeFinal,
+        Self::ParameterCannotBePassedByReference,
+        Self::MaximumNestingDepthExceeded,
+        Self::MissingSemicolon,
+        Self::FunctionWithoutBodyCanOnlyBeUsedInDefinitionSource,
+        Self::MaximumIssuesReached,
+        Self::ArrayLiteralNotSupported,
+        Self::LegacyCastNotSupported,
+        Self::UnknownDefinitionKeyword,
+        Self::DictKeyMustBeConstant,
+        Self::ElseIfKeywordDiscouraged,
+        Self::AttributeArgumentMustBeConstant,
+        Self::NeverTypeCannotBeUsedForParameter,
+        Self::InvalidTypeAliasModifier,
+        Self::ArgumentCannotBePassedByReference,
+        Self::FunctionCannotReturnByReference,
+    ];
+
+    /// The code displayed as the given string, e.g. `P0001`.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|candidate| candidate.to_string() == code)
+    }
+
+    /// The code of the given issue, if it was reported by the parser.
+    pub fn of(issue: &Issue) -> Option<Self> {
+        issue.code.as_deref().and_then(Self::from_code)
+    }
+}
+
+/// The locations of the start and the end of each issue of the given report, in the order of
+/// the issues, or `None` for issues without a source, or with a source missing from the map.
+pub fn locations(report: &Report, map: &SourceMap) -> Vec<Option<(Location, Location)>> {
+    let mut indices: HashMap<&str, LineIndex> = HashMap::new();
+
+    report
+        .issues
+        .iter()
+        .map(|issue| {
+            let (name, from, to) = issue.source.as_ref()?;
+            if !indices.contains_key(name.as_str()) {
+                let source = map.sources.iter().find(|source| source.name() == name)?;
+
+                indices.insert(name, LineIndex::new(&source.content));
+            }
+
+            Some(indices[name.as_str()].span(&Span::new(*from, *to)))
+        })
+        .collect()
+}
+
 impl ::std::fmt::Display for ParserIssueCode {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         write!(f, "P{:04}", *self as u8)
@@ -444,3 +1273,170 @@ impl From<ParserIssueCode> for String {
         format!("{code}")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ara_source::source::Source;
+    use ara_source::source::SourceKind;
+
+    use crate::lexer::issue::LexerIssueCode;
+    use crate::parser;
+    use crate::parser::ParserOptions;
+
+    #[test]
+    fn test_issue_codes() {
+        for (number, code) in ParserIssueCode::ALL.into_iter().enumerate() {
+            assert_eq!(code as usize, number);
+            assert_eq!(ParserIssueCode::from_code(&code.to_string()), Some(code));
+        }
+
+        for (number, code) in LexerIssueCode::ALL.into_iter().enumerate() {
+            assert_eq!(code as usize, number);
+            assert_eq!(LexerIssueCode::from_code(&code.to_string()), Some(code));
+        }
+
+        assert_eq!(
+            ParserIssueCode::from_code("P0011"),
+            Some(ParserIssueCode::UnexpectedToken)
+        );
+        assert_eq!(ParserIssueCode::from_code("L0001"), None);
+
+        let issue = |code: &str| {
+            parser::parse(&Source::inline(SourceKind::Script, code))
+                .unwrap_err()
+                .issues
+                .remove(0)
+        };
+
+        let missing_semicolon = issue("function foo(): void {\n    $a = 1\n    $b = 2;\n}\n");
+        assert_eq!(
+            ParserIssueCode::of(&missing_semicolon),
+            Some(ParserIssueCode::MissingSemicolon)
+        );
+        assert_eq!(LexerIssueCode::of(&missing_semicolon), None);
+
+        let unclosed_string = issue("function foo(): void {\n    $a = 'foo;\n}\n");
+        assert_eq!(
+            LexerIssueCode::of(&unclosed_string),
+            Some(LexerIssueCode::UnclosedStringLiteral)
+        );
+        assert_eq!(ParserIssueCode::of(&unclosed_string), None);
+    }
+
+    #[test]
+    fn test_issue_locations() {
+        let source = Source::new(
+            SourceKind::Script,
+            "foo.ara",
+            "function foo(): void {\n    $a = 1\n    $b = 2;\n}\n",
+        );
+        let report = parser::parse(&source).unwrap_err();
+        let map = SourceMap::new(vec![source]);
+
+        assert_eq!(
+            locations(&report, &map),
+            vec![Some((
+                Location {
+                    line: 2,
+                    column: 10
+                },
+                Location {
+                    line: 2,
+                    column: 11
+                }
+            ))]
+        );
+
+        assert_eq!(locations(&report, &SourceMap::new(vec![])), vec![None]);
+    }
+
+    #[test]
+    fn test_fixes() {
+        let fix = |code: &str| {
+            let (_, issues) = parser::parse_lenient(&Source::inline(SourceKind::Script, code));
+
+            let mut edits = issues.iter().flat_map(fixes).collect::<Vec<_>>();
+            edits.sort_by_key(|edit| edit.start);
+
+            edits
+                .iter()
+                .rev()
+                .fold(code.to_string(), |fixed, edit| edit.apply(&fixed).unwrap())
+        };
+
+        assert_eq!(
+            fix("function foo(): void {\n    $a = 1\n    $b = 2;\n}\n"),
+            "function foo(): void {\n    $a = 1;\n    $b = 2;\n}\n"
+        );
+        assert_eq!(
+            fix("interfce Foo {}\n\nfinl class Bar {}\n"),
+            "interface Foo {}\n\nfinal class Bar {}\n"
+        );
+        assert_eq!(
+            fix("<?php\nfunction foo(): void {}\n"),
+            "\nfunction foo(): void {}\n"
+        );
+        assert_eq!(
+            fix("function foo(): void {\n    $a = array(1, array());\n}\n"),
+            "function foo(): void {\n    $a = vec[1, vec[]];\n}\n"
+        );
+        assert_eq!(
+            fix("function foo(): void {\n    $a = array('a' => 1, 'b' => 2,);\n}\n"),
+            "function foo(): void {\n    $a = dict['a' => 1, 'b' => 2,];\n}\n"
+        );
+        assert_eq!(
+            fix("function foo(string $a): int {\n    return (integer) $a + 1;\n}\n"),
+            "function foo(string $a): int {\n    return ($a into int) + 1;\n}\n"
+        );
+        assert_eq!(
+            fix("function foo(int $a): int {\n    return (foo)($a);\n}\n"),
+            "function foo(int $a): int {\n    return (foo)($a);\n}\n"
+        );
+        assert_eq!(
+            fix("function foo(int $a): void {\n    if $a > 1 {\n    } elseif $a < 0 {\n    } elseif $a == 0 {\n    } else {\n    }\n}\n"),
+            "function foo(int $a): void {\n    if $a > 1 {\n    } else if $a < 0 {\n    } else if $a == 0 {\n    } else {\n    }\n}\n"
+        );
+
+        assert_eq!(
+            fix("final internal public type Foo = int;\n"),
+            "internal type Foo = int;\n"
+        );
+        assert_eq!(
+            fix("function &foo(vec<int> $a): vec<int> {\n    bar(&$a);\n\n    return $a;\n}\n"),
+            "function foo(vec<int> $a): vec<int> {\n    bar($a);\n\n    return $a;\n}\n"
+        );
+
+        let (_, warnings) = parser::parse_with_warnings(
+            &Source::inline(
+                SourceKind::Script,
+                "function foo(int $a): void {\n    if $a {\n    } elseif $a {\n    }\n}\n",
+            ),
+            ParserOptions::default(),
+        )
+        .unwrap();
+
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            ParserIssueCode::of(&warnings[0]),
+            Some(ParserIssueCode::ElseIfKeywordDiscouraged)
+        );
+
+        let issue = parser::parse(&Source::inline(
+            SourceKind::Script,
+            "function foo(): void {\n    $a = array(1);\n}\n",
+        ))
+        .unwrap_err()
+        .issues
+        .remove(0);
+
+        assert_eq!(
+            ParserIssueCode::of(&issue),
+            Some(ParserIssueCode::ArrayLiteralNotSupported)
+        );
+        assert_eq!(
+            fixes(&issue),
+            vec![Edit::new(32, 38, "vec["), Edit::new(39, 40, "]")]
+        );
+    }
+}
@@ -1,17 +1,37 @@
+use ara_reporting::issue::Issue;
 use ara_reporting::Report;
 use ara_reporting::ReportFooter;
 use ara_source::source::Source;
+use ara_source::source::SourceKind;
 use ara_source::SourceMap;
 
+use crate::cache;
 use crate::lexer;
 use crate::lexer::iterator::TokenIterator;
 use crate::lexer::token::Token;
+use crate::lexer::token::TokenKind;
+use crate::parser::edit::Edit;
 use crate::parser::internal::definition;
+use crate::parser::internal::definition::r#type;
+use crate::parser::internal::expression;
+use crate::parser::internal::statement;
+use crate::parser::options::ParserOptions;
+use crate::parser::result::ParseResult;
+use crate::parser::session::ParseSession;
 use crate::parser::state::State;
+use crate::tree::definition::r#type::TypeDefinition;
+use crate::tree::definition::Definition;
+use crate::tree::definition::DefinitionTree;
+use crate::tree::expression::Expression;
+use crate::tree::statement::Statement;
+use crate::tree::Fragment;
 use crate::tree::Tree;
 use crate::tree::TreeMap;
 
+pub mod edit;
 pub mod issue;
+pub mod options;
+pub mod session;
 
 pub(in crate::parser) mod internal;
 pub(in crate::parser) mod macros;
@@ -19,55 +39,235 @@ pub(in crate::parser) mod result;
 pub(in crate::parser) mod state;
 
 pub fn parse_map(map: &SourceMap) -> Result<TreeMap, Box<Report>> {
-    let mut trees = vec![];
-    let mut reports = vec![];
+    ParseSession::default().parse_map(map)
+}
 
-    for source in &map.sources {
-        match parse(source) {
-            Ok(tree) => trees.push(tree),
-            Err(report) => reports.push(report),
-        }
-    }
+pub fn parse(source: &Source) -> Result<Tree, Box<Report>> {
+    parse_with_options(source, ParserOptions::default())
+}
 
-    if !reports.is_empty() {
-        let mut issues = vec![];
-        for mut report in reports {
-            issues.append(&mut report.issues);
-        }
+/// Parse the given content, as a source with the given name.
+///
+/// The source is parsed as a definition source if its name ends with `.d.ara`, and as a
+/// script otherwise.
+pub fn parse_str(name: &str, content: &str) -> Result<Tree, Box<Report>> {
+    parse(&source(name, content))
+}
 
-        Err(Box::new(Report {
-            issues,
-            footer: Some(ReportFooter::new(
-                "failed to parse source map due to the above issue(s)",
-            )),
-        }))
+/// The source with the given name and content, of the kind implied by its name, see `parse_str`.
+pub(crate) fn source(name: &str, content: &str) -> Source {
+    let kind = if name.ends_with(".d.ara") {
+        SourceKind::Definition
     } else {
-        Ok(TreeMap::new(trees))
-    }
+        SourceKind::Script
+    };
+
+    Source::new(kind, name, content)
 }
 
-pub fn parse(source: &Source) -> Result<Tree, Box<Report>> {
-    let tokens = match lexer::lex(source) {
+/// Parse the given source using the given options.
+pub fn parse_with_options(source: &Source, options: ParserOptions) -> Result<Tree, Box<Report>> {
+    parse_with_warnings(source, options).map(|(tree, _)| tree)
+}
+
+/// Parse the given source using the given options, returning the tree along with the warnings
+/// reported while parsing it.
+///
+/// Unlike errors, warnings, e.g. about deprecated syntax or discouraged constructs, do not fail
+/// parsing, they are included in the report only if parsing fails due to an error.
+pub fn parse_with_warnings(
+    source: &Source,
+    options: ParserOptions,
+) -> Result<(Tree, Vec<Issue>), Box<Report>> {
+    ParseSession::new(options).parse_with_warnings(source)
+}
+
+/// Parse the given source, recovering from as many issues as possible, and returning the tree
+/// along with all issues reported while parsing it, regardless of their severity.
+///
+/// Unlike `parse`, the tree is returned even if errors were reported, in which case it holds
+/// every definition that could be parsed, the tree is only empty if parsing could not recover
+/// at all, e.g. if the source failed to lex.
+pub fn parse_lenient(source: &Source) -> (Tree, Vec<Issue>) {
+    parse_lenient_with_options(source, ParserOptions::default())
+}
+
+/// Parse the given source leniently using the given options, see `parse_lenient`.
+///
+/// Parsing always recovers, regardless of `ParserOptions::recovery`, once `max_issues` errors
+/// are reported, parsing continues, but further errors are not reported, instead, a single
+/// issue notes that they were left out.
+pub fn parse_lenient_with_options(source: &Source, options: ParserOptions) -> (Tree, Vec<Issue>) {
+    let empty = || {
+        let mut tree = Tree::new(
+            source.name(),
+            DefinitionTree {
+                definitions: vec![],
+                eof: source.content.len(),
+            },
+        );
+        tree.source_hash = cache::hash(&source.content);
+
+        tree
+    };
+
+    let tokens = match lex(source) {
         Ok(tokens) => tokens,
-        Err(issue) => {
-            return Err(Box::new(Report {
-                issues: vec![*issue],
-                footer: Some(ReportFooter::new(format!(
-                    "failed to parse \"{}\" due to the above issue(s)",
-                    source.name(),
-                ))),
-            }))
+        Err(report) => return (empty(), report.issues),
+    };
+
+    let mut iterator = TokenIterator::new(&tokens);
+    let mut state = State::new(source, &mut iterator, options);
+    state.lenient = true;
+
+    match definition::tree(&mut state) {
+        Ok(definitions) => {
+            let tree = tree(source, &tokens, definitions);
+
+            (tree, state.take_issues())
         }
+        Err(report) => (empty(), report.issues),
+    }
+}
+
+/// Parse the given source, capturing all of its trivia ( whitespace, comments, and the original
+/// text of every token ) in the tree, so that the source can be reproduced byte-for-byte.
+pub fn parse_lossless(source: &Source) -> Result<Tree, Box<Report>> {
+    lossless(source).map(|(tree, _)| tree)
+}
+
+fn lossless(source: &Source) -> Result<(Tree, Vec<Issue>), Box<Report>> {
+    let tokens = lex(source)?;
+
+    let (mut tree, warnings) = build(source, &tokens, ParserOptions::default())?;
+    tree.trivia = Some(internal::trivia::table(source, &tokens));
+
+    Ok((tree, warnings))
+}
+
+/// Parse the source resulting from applying the given edit to the given source, from which the
+/// given tree was parsed along with the given warnings, see `parse_with_warnings`.
+///
+/// Only the definitions touched by the edit are parsed again, the remaining definitions of the
+/// tree are reused, along with their warnings, falling back to parsing the whole source if the
+/// tree can not be reused.
+///
+/// Returns the tree along with the warnings of the edited source, fails if the edit can not be
+/// applied to the source, see `Edit::apply`.
+pub fn reparse(
+    tree: &Tree,
+    warnings: &[Issue],
+    source: &Source,
+    edit: &Edit,
+) -> Result<(Tree, Vec<Issue>), Box<Report>> {
+    let edited = Source {
+        kind: source.kind,
+        origin: source.origin.clone(),
+        content: edit
+            .apply(&source.content)
+            .map_err(|error| report(source, error.into()))?,
     };
 
-    construct(source, &tokens)
+    match internal::incremental::reparse(tree, warnings, source, &edited, edit) {
+        Some(result) => Ok(result),
+        None if tree.trivia.is_some() => lossless(&edited),
+        None => parse_with_warnings(&edited, ParserOptions::default()),
+    }
+}
+
+/// Synthesize template definitions from `@template` docblock tags for definitions
+/// that do not declare any templates explicitly.
+pub fn synthesize_docblock_templates(source: &Source, tree: &mut Tree) {
+    internal::docblock::templates(source, tree);
 }
 
 pub fn construct(source: &Source, tokens: &[Token]) -> Result<Tree, Box<Report>> {
+    build(source, tokens, ParserOptions::default()).map(|(tree, _)| tree)
+}
+
+fn build(
+    source: &Source,
+    tokens: &[Token],
+    options: ParserOptions,
+) -> Result<(Tree, Vec<Issue>), Box<Report>> {
     let mut iterator = TokenIterator::new(tokens);
-    let mut state = State::new(source, &mut iterator);
+    let mut state = State::new(source, &mut iterator, options);
 
     let definitions = definition::tree(&mut state)?;
 
-    state.finish(Tree::new(source.name(), definitions))
+    state.finish_with_warnings(tree(source, tokens, definitions))
+}
+
+fn tree(source: &Source, tokens: &[Token], definitions: DefinitionTree) -> Tree {
+    let mut tree = Tree::new(source.name(), definitions);
+    tree.source_hash = cache::hash(&source.content);
+    tree.comments = internal::comment::attachments(source, tokens);
+
+    tree
+}
+
+/// Parse the given code as a single definition, along with its comments, and the warnings
+/// reported while parsing it.
+///
+/// Parsing fails if any errors were reported, see `parse_with_warnings`.
+pub fn parse_definition(code: &str) -> Result<Fragment<Definition>, Box<Report>> {
+    fragment(code, definition::definition)
+}
+
+/// Parse the given code as a single statement.
+pub fn parse_statement(code: &str) -> Result<Fragment<Statement>, Box<Report>> {
+    fragment(code, statement::statement)
+}
+
+/// Parse the given code as a single expression.
+pub fn parse_expression(code: &str) -> Result<Fragment<Expression>, Box<Report>> {
+    fragment(code, expression::create)
+}
+
+/// Parse the given code as a single type.
+pub fn parse_type(code: &str) -> Result<Fragment<TypeDefinition>, Box<Report>> {
+    fragment(code, r#type::type_definition)
+}
+
+/// Parse the whole code as a single item using the given parser.
+fn fragment<T>(
+    code: &str,
+    parse: fn(&mut State) -> ParseResult<T>,
+) -> Result<Fragment<T>, Box<Report>> {
+    let source = Source::inline(SourceKind::Script, code);
+    let tokens = lex(&source)?;
+    let mut iterator = TokenIterator::new(&tokens);
+    let mut state = State::new(&source, &mut iterator, ParserOptions::default());
+
+    let item = parse(&mut state)?;
+
+    let current = state.iterator.current();
+    if current.kind != TokenKind::Eof {
+        crate::parser_report!(
+            &mut state,
+            unexpected_token(vec!["an end of file"], current)
+        );
+    }
+
+    let (node, warnings) = state.finish_with_warnings(item)?;
+
+    Ok(Fragment {
+        node,
+        comments: internal::comment::attachments(&source, &tokens),
+        warnings,
+    })
+}
+
+fn lex(source: &Source) -> Result<Vec<Token>, Box<Report>> {
+    lexer::lex(source).map_err(|issue| report(source, *issue))
+}
+
+fn report(source: &Source, issue: Issue) -> Box<Report> {
+    Box::new(Report {
+        issues: vec![issue],
+        footer: Some(ReportFooter::new(format!(
+            "failed to parse \"{}\" due to the above issue(s)",
+            source.name(),
+        ))),
+    })
 }
@@ -0,0 +1,168 @@
+use ara_source::source::SourceKind;
+
+/// Options controlling how sources are parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParserOptions {
+    /// Whether to continue parsing after an error, synchronizing at the next statement or
+    /// definition, so that as many issues as possible are reported at once.
+    pub recovery: bool,
+    /// Whether PHP opening and closing tags are skipped, instead of being reported.
+    pub allow_php_tags: bool,
+    /// The kind of source to parse as, regardless of the kind of the given source.
+    ///
+    /// If `None`, the kind of the given source is used.
+    pub kind: Option<SourceKind>,
+    /// The number of errors after which parsing stops, instead of recovering, further errors
+    /// are not reported, instead, a single issue notes that parsing stopped.
+    ///
+    /// If `None`, parsing continues until the end of the source.
+    pub max_issues: Option<usize>,
+    /// The maximum nesting depth of expressions, statements, and types, beyond which an issue is
+    /// reported instead of parsing further.
+    ///
+    /// Where the stack can grow, parsing itself does not overflow the stack regardless of the
+    /// nesting depth, the limit protects code walking the tree recursively, such as dropping,
+    /// printing, or serializing it.
+    ///
+    /// Where it can not, such as on WebAssembly, the limit also protects parsing itself, each
+    /// level of nesting takes up to tens of kilobytes of stack, more so in debug builds, so
+    /// parsing on a small stack may require a lower limit than the default.
+    pub max_depth: usize,
+    /// Whether the values of identifiers, variables, literals, and comments in the tree share a
+    /// single copy of the source buffer, rather than each holding a copy of their bytes.
+    ///
+    /// Shared values are not copied when the tree is cloned, but keep the whole buffer alive for
+    /// as long as any of them is, which suits analysis passes that do not outlive the source.
+    pub shared_values: bool,
+}
+
+/// The default maximum nesting depth, see `ParserOptions::max_depth`.
+const DEFAULT_MAX_DEPTH: usize = if cfg!(target_family = "wasm") {
+    128
+} else {
+    1024
+};
+
+impl Default for ParserOptions {
+    fn default() -> Self {
+        Self {
+            recovery: true,
+            allow_php_tags: false,
+            kind: None,
+            max_issues: Some(100),
+            max_depth: DEFAULT_MAX_DEPTH,
+            shared_values: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ara_source::source::Source;
+
+    use crate::parser;
+    use crate::tree::definition::Definition;
+
+    #[test]
+    fn test_parser_options() {
+        let issues = |code: &str, options: ParserOptions| match parser::parse_with_options(
+            &Source::inline(SourceKind::Script, code),
+            options,
+        ) {
+            Ok(_) => 0,
+            Err(report) => report.issues.len(),
+        };
+
+        let code = "function foo(): void {\n    $a = [];\n    $a = [1];\n}\n";
+        assert_eq!(issues(code, ParserOptions::default()), 2);
+        assert_eq!(
+            issues(
+                code,
+                ParserOptions {
+                    recovery: false,
+                    ..ParserOptions::default()
+                }
+            ),
+            1
+        );
+        assert_eq!(
+            issues(
+                code,
+                ParserOptions {
+                    max_issues: Some(1),
+                    ..ParserOptions::default()
+                }
+            ),
+            2
+        );
+
+        let code = "<?php\nfunction foo(): void {}\n";
+        assert_eq!(issues(code, ParserOptions::default()), 1);
+        assert_eq!(
+            issues(
+                code,
+                ParserOptions {
+                    allow_php_tags: true,
+                    ..ParserOptions::default()
+                }
+            ),
+            0
+        );
+
+        let code = "function foo<...T>(): void {}\n";
+        assert_eq!(issues(code, ParserOptions::default()), 1);
+        assert_eq!(
+            issues(
+                code,
+                ParserOptions {
+                    kind: Some(SourceKind::Definition),
+                    ..ParserOptions::default()
+                }
+            ),
+            0
+        );
+
+        let code = "function foo(): int { return ((((((((((1)))))))))); }\n";
+        assert_eq!(issues(code, ParserOptions::default()), 0);
+        assert_eq!(
+            issues(
+                code,
+                ParserOptions {
+                    max_depth: 8,
+                    ..ParserOptions::default()
+                }
+            ),
+            1
+        );
+
+        let code = "function foo(): void {\n    $a = [];\n}\n".repeat(150);
+        let report = parser::parse(&Source::inline(SourceKind::Script, &code)).unwrap_err();
+        assert_eq!(report.issues.len(), 101);
+        assert_eq!(report.issues[100].code.as_deref(), Some("P0020"));
+    }
+
+    #[test]
+    fn test_shared_values() {
+        let source = Source::inline(SourceKind::Script, "function foo(): void { $a = 'b'; }\n");
+        let owned = parser::parse(&source).unwrap();
+        let shared = parser::parse_with_options(
+            &source,
+            ParserOptions {
+                shared_values: true,
+                ..ParserOptions::default()
+            },
+        )
+        .unwrap();
+
+        assert_eq!(owned.definitions, shared.definitions);
+
+        let name = match &shared.definitions.definitions[0] {
+            Definition::Function(function) => &function.name.value,
+            _ => unreachable!(),
+        };
+
+        assert!(name.is_shared());
+        assert_eq!(name, &b"foo");
+    }
+}
@@ -0,0 +1,111 @@
+use ara_reporting::issue::Issue;
+use ara_reporting::Report;
+use ara_reporting::ReportFooter;
+use ara_source::source::Source;
+use ara_source::SourceMap;
+
+use crate::lexer;
+use crate::lexer::token::Token;
+use crate::parser::options::ParserOptions;
+use crate::tree::Tree;
+use crate::tree::TreeMap;
+
+/// A session parsing any number of sources using the same options, e.g. every source of a
+/// project.
+///
+/// The buffers used while parsing a source, such as its tokens, are kept by the session once the
+/// source is parsed, and reused to parse the following sources, rather than allocated for each
+/// of them.
+#[derive(Debug, Default)]
+pub struct ParseSession {
+    options: ParserOptions,
+    tokens: Vec<Token>,
+}
+
+impl ParseSession {
+    pub fn new(options: ParserOptions) -> Self {
+        Self {
+            options,
+            tokens: Vec::new(),
+        }
+    }
+
+    pub fn options(&self) -> &ParserOptions {
+        &self.options
+    }
+
+    pub fn parse(&mut self, source: &Source) -> Result<Tree, Box<Report>> {
+        self.parse_with_warnings(source).map(|(tree, _)| tree)
+    }
+
+    /// Parse the given source, returning the tree along with the warnings reported while parsing
+    /// it, see `parser::parse_with_warnings`.
+    pub fn parse_with_warnings(
+        &mut self,
+        source: &Source,
+    ) -> Result<(Tree, Vec<Issue>), Box<Report>> {
+        lexer::lex_into(source, self.options.shared_values, &mut self.tokens)
+            .map_err(|issue| super::report(source, *issue))?;
+
+        super::build(source, &self.tokens, self.options.clone())
+    }
+
+    /// Parse every source of the given map, failing with the issues of all sources that failed
+    /// to parse, if any.
+    pub fn parse_map(&mut self, map: &SourceMap) -> Result<TreeMap, Box<Report>> {
+        let mut trees = vec![];
+        let mut issues = vec![];
+
+        for source in &map.sources {
+            match self.parse(source) {
+                Ok(tree) => trees.push(tree),
+                Err(mut report) => issues.append(&mut report.issues),
+            }
+        }
+
+        if !issues.is_empty() {
+            Err(Box::new(Report {
+                issues,
+                footer: Some(ReportFooter::new(
+                    "failed to parse source map due to the above issue(s)",
+                )),
+            }))
+        } else {
+            Ok(TreeMap::new(trees))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ara_source::source::SourceKind;
+
+    use crate::parser;
+
+    #[test]
+    fn test_parse_session() {
+        let large = Source::inline(
+            SourceKind::Script,
+            "function foo(): void {}\nfunction bar(): void {}\nfunction baz(): void {}",
+        );
+        let small = Source::inline(SourceKind::Script, "function qux(): void {}");
+        let invalid = Source::inline(SourceKind::Script, "function (): void {}");
+
+        let mut session = ParseSession::default();
+
+        let tree = session.parse(&large).unwrap();
+        assert_eq!(tree.definitions, parser::parse(&large).unwrap().definitions);
+
+        let capacity = session.tokens.capacity();
+        let tree = session.parse(&small).unwrap();
+        assert_eq!(tree.definitions, parser::parse(&small).unwrap().definitions);
+        assert_eq!(session.tokens.capacity(), capacity);
+
+        assert_eq!(
+            session.parse(&invalid).unwrap_err().issues,
+            parser::parse(&invalid).unwrap_err().issues
+        );
+        assert!(session.parse(&small).is_ok());
+    }
+}
@@ -1,11 +1,15 @@
+use std::collections::HashSet;
 use std::fmt::Display;
 
 use ara_reporting::issue::Issue;
+use ara_reporting::issue::IssueSeverity;
 use ara_reporting::{Report, ReportFooter};
 use ara_source::source::Source;
+use ara_source::source::SourceKind;
 
 use crate::lexer::iterator::TokenIterator;
 use crate::lexer::token::Token;
+use crate::parser::options::ParserOptions;
 use crate::parser::result::ParseResult;
 use crate::tree::definition::attribute::AttributeGroupDefinition;
 use crate::tree::identifier::Identifier;
@@ -13,22 +17,45 @@ use crate::tree::identifier::Identifier;
 #[derive(Debug)]
 pub struct State<'a> {
     pub source: &'a Source,
+    pub options: ParserOptions,
     pub iterator: &'a mut TokenIterator<'a>,
     pub namespace: Option<Identifier>,
     pub attributes: Vec<AttributeGroupDefinition>,
     pub issues: Vec<Issue>,
     pub ignored_shift_at: Option<&'a Token>,
+    /// The number of nested expressions, statements, and types currently being parsed.
+    pub depth: usize,
+    /// The position of the token at which `expected` was recorded.
+    pub expected_position: usize,
+    /// The descriptions of what was expected, but not found, at `expected_position`, included
+    /// in the issue reported if the token turns out to be unexpected.
+    pub expected: Vec<String>,
+    /// Whether parsing stopped due to reaching the maximum number of issues.
+    pub stopped: bool,
+    /// Whether parsing always continues after an error, regardless of the options, errors past
+    /// the maximum number of issues are not recorded, see `parser::parse_lenient`.
+    pub lenient: bool,
 }
 
 impl<'a> State<'a> {
-    pub fn new(source: &'a Source, iterator: &'a mut TokenIterator<'a>) -> Self {
+    pub fn new(
+        source: &'a Source,
+        iterator: &'a mut TokenIterator<'a>,
+        options: ParserOptions,
+    ) -> Self {
         Self {
             source,
+            options,
             iterator,
             namespace: None,
             attributes: vec![],
             issues: vec![],
             ignored_shift_at: None,
+            depth: 0,
+            expected_position: 0,
+            expected: vec![],
+            stopped: false,
+            lenient: false,
         }
     }
 
@@ -44,21 +71,55 @@ impl<'a> State<'a> {
         attributes
     }
 
+    /// Record that the given token, or item, would have been accepted at the current token.
+    pub fn expect<T: ToString>(&mut self, expected: T) {
+        let position = self.iterator.current().start();
+        if position != self.expected_position {
+            self.expected_position = position;
+            self.expected.clear();
+        }
+
+        let expected = expected.to_string();
+        if !self.expected.contains(&expected) {
+            self.expected.push(expected);
+        }
+    }
+
+    /// Record the given issue, parsing fails if it is an error, see `finish`.
+    ///
+    /// Once the maximum number of issues is reached, further errors are not recorded, instead,
+    /// a single issue is recorded summarizing that parsing stopped, see `stop`.
     pub fn record(&mut self, issue: Issue) {
+        if issue.severity >= IssueSeverity::Error && self.is_at_max_issues() {
+            self.stop();
+
+            return;
+        }
+
         self.issues.push(issue);
     }
 
+    /// Record that parsing stopped due to reaching the maximum number of issues, unless it was
+    /// already recorded.
+    pub fn stop(&mut self) {
+        if let Some(max) = self.options.max_issues {
+            if !self.stopped {
+                self.stopped = true;
+
+                crate::parser_report!(self, maximum_issues_reached(max));
+            }
+        }
+    }
+
     pub fn report(&mut self, issue: Issue) -> Report {
         if let Some(token) = self.ignored_shift_at {
             crate::parser_report!(self, unexpected_token(vec![">".to_string()], token));
         }
 
-        let mut issues = vec![];
-
-        std::mem::swap(&mut self.issues, &mut issues);
+        self.record(issue);
 
         Report {
-            issues: issues.into_iter().chain(std::iter::once(issue)).collect(),
+            issues: self.drain(),
             footer: Some(ReportFooter::new(format!(
                 "failed to parse \"{}\" due to the above issue(s)",
                 self.source.name(),
@@ -67,15 +128,24 @@ impl<'a> State<'a> {
     }
 
     pub fn finish<T>(&mut self, item: T) -> ParseResult<T> {
-        if let Some(token) = self.ignored_shift_at {
-            crate::parser_report!(self, unexpected_token(vec![">".to_string()], token));
-        }
+        self.finish_with_warnings(item).map(|(item, _)| item)
+    }
+
+    /// Finish parsing the given item, failing with all recorded issues if any of them is an error.
+    ///
+    /// Otherwise, the item is returned along with the recorded warnings, and any other issues
+    /// below the error severity, such as notes.
+    pub fn finish_with_warnings<T>(&mut self, item: T) -> ParseResult<(T, Vec<Issue>)> {
+        let issues = self.take_issues();
 
-        if self.issues.is_empty() {
-            Ok(item)
+        if issues
+            .iter()
+            .all(|issue| issue.severity < IssueSeverity::Error)
+        {
+            Ok((item, issues))
         } else {
             Err(Box::new(Report {
-                issues: self.issues.drain(..).collect(),
+                issues,
                 footer: Some(ReportFooter::new(format!(
                     "failed to parse \"{}\" due to the above issue(s)",
                     self.source.name(),
@@ -84,6 +154,63 @@ impl<'a> State<'a> {
         }
     }
 
+    /// Take all recorded issues, including any pending ones, regardless of their severity.
+    pub fn take_issues(&mut self) -> Vec<Issue> {
+        if let Some(token) = self.ignored_shift_at.take() {
+            crate::parser_report!(self, unexpected_token(vec![">".to_string()], token));
+        }
+
+        self.drain()
+    }
+
+    /// Take all recorded issues, ordered by their position in the source, keeping only the
+    /// first of any issues with the same code and source span, e.g. when the same mistake is reported
+    /// both before and after recovering from it.
+    fn drain(&mut self) -> Vec<Issue> {
+        let mut seen = HashSet::new();
+        let mut issues: Vec<Issue> = self
+            .issues
+            .drain(..)
+            .filter(|issue| {
+                issue.source.is_none() || seen.insert((issue.code.clone(), issue.source.clone()))
+            })
+            .collect();
+
+        // issues without a source are kept at the end, in the order they were recorded.
+        issues.sort_by_key(|issue| match &issue.source {
+            Some((_, from, to)) => (*from, *to),
+            None => (usize::MAX, usize::MAX),
+        });
+
+        issues
+    }
+
+    /// The number of errors recorded so far, excluding warnings and other issues below the
+    /// error severity.
+    pub fn errors(&self) -> usize {
+        self.issues
+            .iter()
+            .filter(|issue| issue.severity >= IssueSeverity::Error)
+            .count()
+    }
+
+    /// The kind of source being parsed, which can be overridden by the options.
+    pub fn kind(&self) -> SourceKind {
+        self.options.kind.unwrap_or(self.source.kind)
+    }
+
+    /// Whether parsing can continue after an error, given the options and the issues recorded so far.
+    pub fn can_recover(&self) -> bool {
+        self.lenient || (self.options.recovery && !self.is_at_max_issues())
+    }
+
+    /// Whether the maximum number of errors, given by the options, was reached.
+    pub fn is_at_max_issues(&self) -> bool {
+        self.options
+            .max_issues
+            .is_some_and(|max| self.errors() >= max)
+    }
+
     pub fn namespace(&mut self, namespace: Identifier) {
         self.namespace = Some(namespace);
     }
@@ -96,3 +223,64 @@ impl<'a> State<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::lexer;
+
+    #[test]
+    fn test_finish_with_warnings() {
+        let source = Source::inline(SourceKind::Script, "");
+        let tokens = lexer::lex(&source).unwrap();
+        let mut iterator = TokenIterator::new(&tokens);
+        let mut state = State::new(&source, &mut iterator, ParserOptions::default());
+
+        state.record(Issue::warning("P9999", "discouraged construct"));
+        state.record(Issue::note("P9999", "some note"));
+        assert_eq!(state.errors(), 0);
+
+        let ((), warnings) = state.finish_with_warnings(()).unwrap();
+        assert_eq!(warnings.len(), 2);
+        assert!(state.issues.is_empty());
+
+        state.record(Issue::warning("P9999", "discouraged construct"));
+        state.record(Issue::error("P9999", "invalid construct"));
+        assert_eq!(state.errors(), 1);
+
+        let report = state.finish(()).unwrap_err();
+        assert_eq!(report.issues.len(), 2);
+    }
+
+    #[test]
+    fn test_issue_ordering() {
+        let source = Source::inline(SourceKind::Script, "");
+        let tokens = lexer::lex(&source).unwrap();
+        let mut iterator = TokenIterator::new(&tokens);
+        let mut state = State::new(&source, &mut iterator, ParserOptions::default());
+
+        let issue =
+            |code: &str, from: usize| Issue::error(code, "").with_source("", from, from + 1);
+
+        state.record(issue("P0001", 10));
+        state.record(issue("P0002", 2));
+        state.record(issue("P0001", 10));
+        state.record(issue("P0003", 10));
+
+        let report = state.report(issue("P0002", 6));
+        assert_eq!(
+            report
+                .issues
+                .iter()
+                .map(|issue| (issue.code.clone().unwrap(), issue.source.clone().unwrap().1))
+                .collect::<Vec<(String, usize)>>(),
+            vec![
+                ("P0002".to_string(), 2),
+                ("P0002".to_string(), 6),
+                ("P0001".to_string(), 10),
+                ("P0003".to_string(), 10),
+            ]
+        );
+    }
+}
@@ -0,0 +1,1160 @@
+use crate::printer::Print;
+use crate::printer::Printer;
+use crate::transpiler;
+use crate::tree::definition::attribute::AttributeDefinition;
+use crate::tree::definition::attribute::AttributeGroupDefinition;
+use crate::tree::definition::class::ClassDefinition;
+use crate::tree::definition::class::ClassDefinitionBody;
+use crate::tree::definition::class::ClassDefinitionExtends;
+use crate::tree::definition::class::ClassDefinitionImplements;
+use crate::tree::definition::class::ClassDefinitionMember;
+use crate::tree::definition::class::ClassDefinitionPermits;
+use crate::tree::definition::constant::ClassishConstantDefinition;
+use crate::tree::definition::constant::ConstantDefinition;
+use crate::tree::definition::function::FunctionBodyDefinition;
+use crate::tree::definition::function::FunctionDefinition;
+use crate::tree::definition::function::FunctionLikeParameterDefaultValueDefinition;
+use crate::tree::definition::function::FunctionLikeParameterDefinition;
+use crate::tree::definition::function::FunctionLikeParameterListDefinition;
+use crate::tree::definition::function::FunctionLikeReturnTypeDefinition;
+use crate::tree::definition::function::MethodBodyDefinition;
+use crate::tree::definition::function::MethodDefinition;
+use crate::tree::definition::function::MethodParameterDefinition;
+use crate::tree::definition::function::MethodParameterListDefinition;
+use crate::tree::definition::function::MethodTypeConstraintDefinition;
+use crate::tree::definition::function::MethodTypeConstraintGroupDefinition;
+use crate::tree::definition::interface::InterfaceDefinition;
+use crate::tree::definition::interface::InterfaceDefinitionBody;
+use crate::tree::definition::interface::InterfaceDefinitionExtends;
+use crate::tree::definition::interface::InterfaceDefinitionMember;
+use crate::tree::definition::interface::InterfaceDefinitionPermits;
+use crate::tree::definition::modifier::ModifierDefinition;
+use crate::tree::definition::modifier::ModifierGroupDefinition;
+use crate::tree::definition::namespace::NamespaceDefinition;
+use crate::tree::definition::pragma::PragmaDefinition;
+use crate::tree::definition::property::PropertyDefinition;
+use crate::tree::definition::property::PropertyEntryDefinition;
+use crate::tree::definition::r#enum::BackedEnumBodyDefinition;
+use crate::tree::definition::r#enum::BackedEnumCaseDefinition;
+use crate::tree::definition::r#enum::BackedEnumDefinition;
+use crate::tree::definition::r#enum::BackedEnumMemberDefinition;
+use crate::tree::definition::r#enum::BackedEnumTypeDefinition;
+use crate::tree::definition::r#enum::EnumDefinition;
+use crate::tree::definition::r#enum::EnumImplementsDefinition;
+use crate::tree::definition::r#enum::UnitEnumBodyDefinition;
+use crate::tree::definition::r#enum::UnitEnumCaseDefinition;
+use crate::tree::definition::r#enum::UnitEnumDefinition;
+use crate::tree::definition::r#enum::UnitEnumMemberDefinition;
+use crate::tree::definition::r#type::FloatingPointTypeDefinition;
+use crate::tree::definition::r#type::IntegerRangeBoundTypeDefinition;
+use crate::tree::definition::r#type::ShapeFieldTypeDefinition;
+use crate::tree::definition::r#type::SignedIntegerTypeDefinition;
+use crate::tree::definition::r#type::TupleElementLabelDefinition;
+use crate::tree::definition::r#type::TupleElementTypeDefinition;
+use crate::tree::definition::r#type::TypeAliasDefinition;
+use crate::tree::definition::r#type::TypeDefinition;
+use crate::tree::definition::r#type::UnsignedIntegerTypeDefinition;
+use crate::tree::definition::r#use::UseDefinition;
+use crate::tree::definition::r#use::UseDefinitionGroupMember;
+use crate::tree::definition::r#use::UseDefinitionSymbolAlias;
+use crate::tree::definition::template::ConstTemplateDefinition;
+use crate::tree::definition::template::TemplateBoundsDefinition;
+use crate::tree::definition::template::TemplateDefinition;
+use crate::tree::definition::template::TemplateDefinitionTypeConstraint;
+use crate::tree::definition::template::TemplateDefinitionVariance;
+use crate::tree::definition::template::TemplateGroupDefinition;
+use crate::tree::definition::template::TemplateGroupDefinitionMember;
+use crate::tree::definition::template::TypeTemplateGroupDefinition;
+use crate::tree::definition::Definition;
+use crate::tree::definition::DefinitionTree;
+use crate::tree::identifier::Identifier;
+use crate::tree::identifier::TemplatedIdentifier;
+use crate::tree::token::Keyword;
+use crate::tree::variable::Variable;
+use crate::tree::Node;
+
+impl Print for DefinitionTree {
+    fn print(&self, printer: &mut Printer) {
+        definitions(printer, &self.definitions);
+    }
+}
+
+/// Print definitions separated by an empty line, keeping consecutive pragmas and uses together.
+///
+/// Pragmas and type aliases are omitted when transpiling.
+fn definitions(printer: &mut Printer, definitions: &[Definition]) {
+    let transpiling = printer.is_transpiling();
+
+    let mut previous: Option<&Definition> = None;
+    for definition in definitions {
+        if transpiling && matches!(definition, Definition::Pragma(_) | Definition::TypeAlias(_)) {
+            continue;
+        }
+
+        if let Some(previous) = previous {
+            let grouped = matches!(
+                (previous, definition),
+                (Definition::Pragma(_), Definition::Pragma(_))
+                    | (Definition::Use(_), Definition::Use(_))
+            );
+
+            if !grouped {
+                printer.new_line();
+            }
+        }
+
+        definition.print(printer);
+        printer.new_line();
+
+        previous = Some(definition);
+    }
+}
+
+impl Print for Definition {
+    fn print(&self, printer: &mut Printer) {
+        match &self {
+            Self::Pragma(definition) => definition.print(printer),
+            Self::Namespace(definition) => definition.print(printer),
+            Self::Use(definition) => definition.print(printer),
+            Self::TypeAlias(definition) => definition.print(printer),
+            Self::Constant(definition) => definition.print(printer),
+            Self::Function(definition) => definition.print(printer),
+            Self::Interface(definition) => definition.print(printer),
+            Self::Enum(definition) => definition.print(printer),
+            Self::Class(definition) => definition.print(printer),
+        }
+    }
+}
+
+impl Print for Keyword {
+    fn print(&self, printer: &mut Printer) {
+        printer.mapped_bytes(self.span(), &self.value);
+    }
+}
+
+impl Print for Identifier {
+    fn print(&self, printer: &mut Printer) {
+        printer.mapped_bytes(self.span(), &self.value);
+    }
+}
+
+impl Print for TemplatedIdentifier {
+    fn print(&self, printer: &mut Printer) {
+        self.name.print(printer);
+
+        if let Some(templates) = &self.templates {
+            templates.print(printer);
+        }
+    }
+}
+
+impl Print for Variable {
+    fn print(&self, printer: &mut Printer) {
+        printer.mapped_bytes(self.span(), &self.name);
+    }
+}
+
+impl Print for PragmaDefinition {
+    fn print(&self, printer: &mut Printer) {
+        printer.comments(&self.comments);
+        self.pragma.print(printer);
+        printer.space();
+        self.name.print(printer);
+        printer.token(";");
+    }
+}
+
+impl Print for NamespaceDefinition {
+    fn print(&self, printer: &mut Printer) {
+        self.namespace.print(printer);
+        printer.space();
+        self.name.print(printer);
+        printer.token(";");
+
+        if !self.definitions.is_empty() {
+            printer.new_line();
+            printer.new_line();
+
+            definitions(printer, &self.definitions);
+        }
+    }
+}
+
+impl Print for UseDefinition {
+    fn print(&self, printer: &mut Printer) {
+        match &self {
+            Self::Default {
+                r#use, name, alias, ..
+            } => {
+                r#use.print(printer);
+                printer.space();
+                name.print(printer);
+                symbol_alias(printer, alias);
+            }
+            Self::Function {
+                r#use,
+                function: r#type,
+                name,
+                alias,
+                ..
+            }
+            | Self::Constant {
+                r#use,
+                r#const: r#type,
+                name,
+                alias,
+                ..
+            } => {
+                r#use.print(printer);
+                printer.space();
+                r#type.print(printer);
+                printer.space();
+                name.print(printer);
+                symbol_alias(printer, alias);
+            }
+            Self::Group {
+                r#use,
+                prefix,
+                members,
+                ..
+            } => {
+                r#use.print(printer);
+                printer.space();
+                prefix.print(printer);
+                printer.token("{");
+                printer.comma_separated(members);
+                printer.token("}");
+            }
+        }
+
+        printer.token(";");
+    }
+}
+
+impl Print for UseDefinitionGroupMember {
+    fn print(&self, printer: &mut Printer) {
+        match &self {
+            Self::Default { name, alias } => {
+                name.print(printer);
+                symbol_alias(printer, alias);
+            }
+            Self::Function {
+                function: r#type,
+                name,
+                alias,
+            }
+            | Self::Constant {
+                r#const: r#type,
+                name,
+                alias,
+            } => {
+                r#type.print(printer);
+                printer.space();
+                name.print(printer);
+                symbol_alias(printer, alias);
+            }
+        }
+    }
+}
+
+fn symbol_alias(printer: &mut Printer, alias: &Option<UseDefinitionSymbolAlias>) {
+    if let Some(alias) = alias {
+        printer.space();
+        alias.r#as.print(printer);
+        printer.space();
+        alias.alias.print(printer);
+    }
+}
+
+/// Print the given type constraints preceded by a space, constraints are erased when transpiling.
+fn constraints(printer: &mut Printer, constraints: &Option<MethodTypeConstraintGroupDefinition>) {
+    if let Some(constraints) = constraints {
+        if !printer.is_transpiling() {
+            printer.space();
+            constraints.print(printer);
+        }
+    }
+}
+
+/// Bring the given templates into scope when transpiling, returning the scope to leave.
+fn enter(printer: &mut Printer, templates: &Option<TemplateGroupDefinition>) -> usize {
+    printer
+        .transpiler()
+        .map(|context| context.enter(templates.as_ref()))
+        .unwrap_or_default()
+}
+
+fn leave(printer: &mut Printer, scope: usize) {
+    if let Some(context) = printer.transpiler() {
+        context.leave(scope);
+    }
+}
+
+impl Print for TypeAliasDefinition {
+    fn print(&self, printer: &mut Printer) {
+        self.modifiers.print(printer);
+        self.r#type.print(printer);
+        printer.space();
+        self.name.print(printer);
+        printer.token(" = ");
+
+        if let Some(new) = &self.new {
+            new.print(printer);
+            printer.space();
+        }
+
+        self.type_definition.print(printer);
+
+        if let Some(constraints) = &self.constraints {
+            printer.space();
+            constraints.print(printer);
+        }
+
+        printer.token(";");
+    }
+}
+
+impl Print for ConstantDefinition {
+    fn print(&self, printer: &mut Printer) {
+        printer.comments(&self.comments);
+        self.r#const.print(printer);
+        printer.space();
+
+        // typed constants are not supported before PHP 8.3.
+        if !printer.is_transpiling() {
+            self.type_definition.print(printer);
+            printer.space();
+        }
+
+        self.name.print(printer);
+        printer.token(" = ");
+        self.value.print(printer);
+        printer.token(";");
+    }
+}
+
+impl Print for ClassishConstantDefinition {
+    fn print(&self, printer: &mut Printer) {
+        printer.comments(&self.comments);
+        attributes(printer, &self.attributes, true);
+        self.modifiers.print(printer);
+        self.r#const.print(printer);
+        printer.space();
+
+        // typed constants are not supported before PHP 8.3.
+        if !printer.is_transpiling() {
+            self.type_definition.print(printer);
+            printer.space();
+        }
+
+        self.name.print(printer);
+        printer.token(" = ");
+        self.value.print(printer);
+        printer.token(";");
+    }
+}
+
+/// Print attribute groups, each on its own line when `standalone` is set, or followed by a space otherwise.
+fn attributes(printer: &mut Printer, attributes: &[AttributeGroupDefinition], standalone: bool) {
+    for attribute in attributes {
+        attribute.print(printer);
+
+        if standalone {
+            printer.new_line();
+        } else {
+            printer.space();
+        }
+    }
+}
+
+impl Print for AttributeGroupDefinition {
+    fn print(&self, printer: &mut Printer) {
+        printer.token("#[");
+        printer.comma_separated(&self.members);
+        printer.token("]");
+    }
+}
+
+impl Print for AttributeDefinition {
+    fn print(&self, printer: &mut Printer) {
+        self.name.print(printer);
+
+        if let Some(arguments) = &self.arguments {
+            arguments.print(printer);
+        }
+    }
+}
+
+/// Modifiers are printed with a trailing space, so nothing is printed for an empty group.
+impl Print for ModifierGroupDefinition {
+    fn print(&self, printer: &mut Printer) {
+        for modifier in &self.modifiers {
+            if let ModifierDefinition::Async(keyword) = modifier {
+                if printer.is_transpiling() {
+                    transpiler::unsupported_async(printer, keyword);
+
+                    continue;
+                }
+            }
+
+            modifier.print(printer);
+            printer.space();
+        }
+    }
+}
+
+impl Print for ModifierDefinition {
+    fn print(&self, printer: &mut Printer) {
+        match &self {
+            Self::Public(keyword)
+            | Self::Protected(keyword)
+            | Self::Private(keyword)
+            | Self::Static(keyword)
+            | Self::Readonly(keyword)
+            | Self::Final(keyword)
+            | Self::Abstract(keyword)
+            | Self::Async(keyword)
+            | Self::Internal(keyword) => keyword.print(printer),
+        }
+    }
+}
+
+impl Print for FunctionDefinition {
+    fn print(&self, printer: &mut Printer) {
+        if let FunctionBodyDefinition::Declaration(_) = &self.body {
+            if printer.is_transpiling() {
+                transpiler::unsupported(printer, self);
+
+                return;
+            }
+        }
+
+        let scope = enter(printer, &self.templates);
+
+        printer.comments(&self.comments);
+        attributes(printer, &self.attributes, true);
+        self.modifiers.print(printer);
+        self.function.print(printer);
+        printer.space();
+        self.name.print(printer);
+
+        if let Some(templates) = &self.templates {
+            templates.print(printer);
+        }
+
+        self.parameters.print(printer);
+        self.return_type.print(printer);
+        constraints(printer, &self.constraints);
+
+        match &self.body {
+            FunctionBodyDefinition::Concrete(block) => {
+                printer.space();
+                block.print(printer);
+            }
+            FunctionBodyDefinition::Declaration(_) => printer.token(";"),
+        }
+
+        leave(printer, scope);
+    }
+}
+
+impl Print for FunctionLikeParameterListDefinition {
+    fn print(&self, printer: &mut Printer) {
+        printer.token("(");
+        printer.comma_separated(&self.parameters);
+        printer.token(")");
+    }
+}
+
+impl Print for FunctionLikeParameterDefinition {
+    fn print(&self, printer: &mut Printer) {
+        attributes(printer, &self.attributes, false);
+        self.modifiers.print(printer);
+        self.type_definition.print(printer);
+        printer.space();
+
+        if self.ellipsis.is_some() {
+            printer.token("...");
+        }
+
+        self.variable.print(printer);
+
+        if let Some(default) = &self.default {
+            default.print(printer);
+        }
+    }
+}
+
+impl Print for FunctionLikeParameterDefaultValueDefinition {
+    fn print(&self, printer: &mut Printer) {
+        printer.token(" = ");
+        self.value.print(printer);
+    }
+}
+
+/// Return types PHP can not declare are omitted when transpiling.
+impl Print for FunctionLikeReturnTypeDefinition {
+    fn print(&self, printer: &mut Printer) {
+        if let Some(context) = printer.transpiler() {
+            if let Some(declaration) = context.declaration(&self.type_definition) {
+                printer.token(": ");
+                printer.token(&declaration);
+            }
+
+            return;
+        }
+
+        printer.token(": ");
+        self.type_definition.print(printer);
+    }
+}
+
+impl Print for MethodTypeConstraintGroupDefinition {
+    fn print(&self, printer: &mut Printer) {
+        self.r#where.print(printer);
+        printer.space();
+        printer.comma_separated(&self.constraints);
+    }
+}
+
+impl Print for MethodTypeConstraintDefinition {
+    fn print(&self, printer: &mut Printer) {
+        self.identifier.print(printer);
+        printer.space();
+        self.r#is.print(printer);
+        printer.space();
+        self.bounds.print(printer);
+    }
+}
+
+impl Print for MethodDefinition {
+    fn print(&self, printer: &mut Printer) {
+        let scope = enter(printer, &self.templates);
+
+        printer.comments(&self.comments);
+        attributes(printer, &self.attributes, true);
+        self.modifiers.print(printer);
+        self.function.print(printer);
+        printer.space();
+        self.name.print(printer);
+
+        if let Some(templates) = &self.templates {
+            templates.print(printer);
+        }
+
+        self.parameters.print(printer);
+
+        if let Some(return_type) = &self.return_type {
+            // constructors, and destructors, can not declare a return type in PHP.
+            let structor = ["__construct", "__destruct"]
+                .iter()
+                .any(|name| self.name.value.eq_ignore_ascii_case(name.as_bytes()));
+
+            if !(printer.is_transpiling() && structor) {
+                return_type.print(printer);
+            }
+        }
+
+        constraints(printer, &self.constraints);
+
+        match &self.body {
+            MethodBodyDefinition::Concrete(block) => {
+                printer.space();
+                block.print(printer);
+            }
+            MethodBodyDefinition::Abstract(_) => printer.token(";"),
+        }
+
+        leave(printer, scope);
+    }
+}
+
+impl Print for MethodParameterListDefinition {
+    fn print(&self, printer: &mut Printer) {
+        printer.token("(");
+        printer.comma_separated(&self.parameters);
+        printer.token(")");
+    }
+}
+
+impl Print for MethodParameterDefinition {
+    fn print(&self, printer: &mut Printer) {
+        attributes(printer, &self.attributes, false);
+        self.modifiers.print(printer);
+        self.type_definition.print(printer);
+        printer.space();
+
+        if self.ellipsis.is_some() {
+            printer.token("...");
+        }
+
+        self.variable.print(printer);
+
+        if let Some(default) = &self.default {
+            default.print(printer);
+        }
+    }
+}
+
+impl Print for InterfaceDefinition {
+    fn print(&self, printer: &mut Printer) {
+        let scope = enter(printer, &self.templates);
+
+        printer.comments(&self.comments);
+        attributes(printer, &self.attributes, true);
+        self.interface.print(printer);
+        printer.space();
+        self.name.print(printer);
+
+        if let Some(templates) = &self.templates {
+            templates.print(printer);
+        }
+
+        if let Some(extends) = &self.extends {
+            printer.space();
+            extends.print(printer);
+        }
+
+        if let Some(permits) = &self.permits {
+            if !printer.is_transpiling() {
+                printer.space();
+                permits.print(printer);
+            }
+        }
+
+        printer.space();
+        self.body.print(printer);
+
+        leave(printer, scope);
+    }
+}
+
+impl Print for InterfaceDefinitionExtends {
+    fn print(&self, printer: &mut Printer) {
+        self.extends.print(printer);
+        printer.space();
+        printer.comma_separated(&self.parents);
+    }
+}
+
+impl Print for InterfaceDefinitionPermits {
+    fn print(&self, printer: &mut Printer) {
+        self.permits.print(printer);
+        printer.space();
+        printer.comma_separated(&self.permitted);
+    }
+}
+
+impl Print for InterfaceDefinitionBody {
+    fn print(&self, printer: &mut Printer) {
+        printer.braced(&self.members, true);
+    }
+}
+
+impl Print for InterfaceDefinitionMember {
+    fn print(&self, printer: &mut Printer) {
+        match &self {
+            Self::Constant(constant) => constant.print(printer),
+            Self::Method(method) => method.print(printer),
+        }
+    }
+}
+
+impl Print for EnumDefinition {
+    fn print(&self, printer: &mut Printer) {
+        match &self {
+            Self::Backed(definition) => definition.print(printer),
+            Self::Unit(definition) => definition.print(printer),
+        }
+    }
+}
+
+impl Print for UnitEnumDefinition {
+    fn print(&self, printer: &mut Printer) {
+        printer.comments(&self.comments);
+        attributes(printer, &self.attributes, true);
+        self.r#enum.print(printer);
+        printer.space();
+        self.name.print(printer);
+
+        if let Some(implements) = &self.implements {
+            printer.space();
+            implements.print(printer);
+        }
+
+        printer.space();
+        self.body.print(printer);
+    }
+}
+
+impl Print for UnitEnumBodyDefinition {
+    fn print(&self, printer: &mut Printer) {
+        printer.braced(&self.members, false);
+    }
+}
+
+impl Print for UnitEnumMemberDefinition {
+    fn print(&self, printer: &mut Printer) {
+        match &self {
+            Self::Case(case) => case.print(printer),
+            Self::Method(method) => method.print(printer),
+            Self::Constant(constant) => constant.print(printer),
+        }
+    }
+}
+
+impl Print for UnitEnumCaseDefinition {
+    fn print(&self, printer: &mut Printer) {
+        attributes(printer, &self.attributes, true);
+        self.case.print(printer);
+        printer.space();
+        self.name.print(printer);
+        printer.token(";");
+    }
+}
+
+impl Print for BackedEnumDefinition {
+    fn print(&self, printer: &mut Printer) {
+        printer.comments(&self.comments);
+        attributes(printer, &self.attributes, true);
+        self.r#enum.print(printer);
+        printer.space();
+        self.name.print(printer);
+        self.backed_type.print(printer);
+
+        if let Some(implements) = &self.implements {
+            printer.space();
+            implements.print(printer);
+        }
+
+        printer.space();
+        self.body.print(printer);
+    }
+}
+
+impl Print for BackedEnumTypeDefinition {
+    fn print(&self, printer: &mut Printer) {
+        match &self {
+            Self::String(_, identifier) | Self::Int(_, identifier) => {
+                printer.token(": ");
+                identifier.print(printer);
+            }
+        }
+    }
+}
+
+impl Print for BackedEnumBodyDefinition {
+    fn print(&self, printer: &mut Printer) {
+        printer.braced(&self.members, false);
+    }
+}
+
+impl Print for BackedEnumMemberDefinition {
+    fn print(&self, printer: &mut Printer) {
+        match &self {
+            Self::Case(case) => case.print(printer),
+            Self::Method(method) => method.print(printer),
+            Self::Constant(constant) => constant.print(printer),
+        }
+    }
+}
+
+impl Print for BackedEnumCaseDefinition {
+    fn print(&self, printer: &mut Printer) {
+        attributes(printer, &self.attributes, true);
+        self.case.print(printer);
+        printer.space();
+        self.name.print(printer);
+        printer.token(" = ");
+        self.value.print(printer);
+        printer.token(";");
+    }
+}
+
+impl Print for EnumImplementsDefinition {
+    fn print(&self, printer: &mut Printer) {
+        self.implements.print(printer);
+        printer.space();
+        printer.comma_separated(&self.interfaces);
+    }
+}
+
+impl Print for ClassDefinition {
+    fn print(&self, printer: &mut Printer) {
+        let scope = enter(printer, &self.templates);
+
+        printer.comments(&self.comments);
+        attributes(printer, &self.attributes, true);
+        self.modifiers.print(printer);
+        self.class.print(printer);
+        printer.space();
+        self.name.print(printer);
+
+        if let Some(templates) = &self.templates {
+            templates.print(printer);
+        }
+
+        if let Some(extends) = &self.extends {
+            printer.space();
+            extends.print(printer);
+        }
+
+        if let Some(implements) = &self.implements {
+            printer.space();
+            implements.print(printer);
+        }
+
+        if let Some(permits) = &self.permits {
+            if !printer.is_transpiling() {
+                printer.space();
+                permits.print(printer);
+            }
+        }
+
+        printer.space();
+        self.body.print(printer);
+
+        leave(printer, scope);
+    }
+}
+
+impl Print for ClassDefinitionExtends {
+    fn print(&self, printer: &mut Printer) {
+        self.extends.print(printer);
+        printer.space();
+        self.parent.print(printer);
+    }
+}
+
+impl Print for ClassDefinitionImplements {
+    fn print(&self, printer: &mut Printer) {
+        self.implements.print(printer);
+        printer.space();
+        printer.comma_separated(&self.interfaces);
+    }
+}
+
+impl Print for ClassDefinitionPermits {
+    fn print(&self, printer: &mut Printer) {
+        self.permits.print(printer);
+        printer.space();
+        printer.comma_separated(&self.permitted);
+    }
+}
+
+impl Print for ClassDefinitionBody {
+    fn print(&self, printer: &mut Printer) {
+        printer.braced(&self.members, true);
+    }
+}
+
+impl Print for ClassDefinitionMember {
+    fn print(&self, printer: &mut Printer) {
+        match &self {
+            Self::Constant(constant) => constant.print(printer),
+            Self::Property(property) => property.print(printer),
+            Self::Method(method) => method.print(printer),
+        }
+    }
+}
+
+impl Print for PropertyDefinition {
+    fn print(&self, printer: &mut Printer) {
+        attributes(printer, &self.attributes, true);
+        self.modifiers.print(printer);
+        self.type_definition.print(printer);
+        printer.space();
+
+        match &self.entry {
+            PropertyEntryDefinition::Uninitialized { variable } => variable.print(printer),
+            PropertyEntryDefinition::Initialized {
+                variable, value, ..
+            } => {
+                variable.print(printer);
+                printer.token(" = ");
+                value.print(printer);
+            }
+        }
+
+        printer.token(";");
+    }
+}
+
+/// Templates synthesized from docblocks are not printed, as they are still present in the docblock itself.
+///
+/// Templates are erased when transpiling.
+impl Print for TemplateGroupDefinition {
+    fn print(&self, printer: &mut Printer) {
+        if self.docblock.is_some() || printer.is_transpiling() {
+            return;
+        }
+
+        printer.token("<");
+        printer.comma_separated(&self.members);
+        printer.token(">");
+    }
+}
+
+impl Print for TemplateGroupDefinitionMember {
+    fn print(&self, printer: &mut Printer) {
+        match &self {
+            Self::Type(template) => template.print(printer),
+            Self::Const(template) => template.print(printer),
+        }
+    }
+}
+
+impl Print for TemplateDefinition {
+    fn print(&self, printer: &mut Printer) {
+        if let TemplateDefinitionVariance::Covariance(_) = self.variance {
+            printer.token("+");
+        }
+
+        if self.ellipsis.is_some() {
+            printer.token("...");
+        }
+
+        self.name.print(printer);
+
+        if let TemplateDefinitionTypeConstraint::SubType(keyword, bounds)
+        | TemplateDefinitionTypeConstraint::SuperType(keyword, bounds) = &self.constraint
+        {
+            printer.space();
+            keyword.print(printer);
+            printer.space();
+            bounds.print(printer);
+        }
+    }
+}
+
+impl Print for TemplateBoundsDefinition {
+    fn print(&self, printer: &mut Printer) {
+        for (index, bound) in self.inner.iter().enumerate() {
+            if index > 0 {
+                printer.token(" + ");
+            }
+
+            bound.print(printer);
+        }
+    }
+}
+
+impl Print for ConstTemplateDefinition {
+    fn print(&self, printer: &mut Printer) {
+        self.r#const.print(printer);
+        printer.space();
+        self.name.print(printer);
+        printer.token(": ");
+        self.type_definition.print(printer);
+    }
+}
+
+/// Generic arguments are erased when transpiling.
+impl Print for TypeTemplateGroupDefinition {
+    fn print(&self, printer: &mut Printer) {
+        if printer.is_transpiling() {
+            return;
+        }
+
+        printer.token("<");
+        printer.comma_separated(&self.members);
+        printer.token(">");
+    }
+}
+
+/// Types are printed as the closest type PHP can declare when transpiling, or `mixed` if there is
+/// none.
+impl Print for TypeDefinition {
+    fn print(&self, printer: &mut Printer) {
+        if let Some(context) = printer.transpiler() {
+            let declaration = context
+                .declaration(self)
+                .unwrap_or_else(|| "mixed".to_string());
+
+            printer.token(&declaration);
+
+            return;
+        }
+
+        match &self {
+            Self::Identifier(identifier) => identifier.print(printer),
+            Self::Nullable(_, inner) => {
+                printer.token("?");
+                inner.print(printer);
+            }
+            Self::Variadic(_, inner) => {
+                printer.token("...");
+                inner.print(printer);
+            }
+            Self::Union(inner) => printer.separated(inner, "|"),
+            Self::Intersection(inner) => printer.separated(inner, "&"),
+            Self::Void(keyword)
+            | Self::Never(keyword)
+            | Self::Boolean(keyword)
+            | Self::String(keyword)
+            | Self::Object(keyword)
+            | Self::Mixed(keyword)
+            | Self::NonNull(keyword)
+            | Self::Resource(keyword)
+            | Self::Self_(keyword)
+            | Self::Static(keyword)
+            | Self::Parent(keyword) => keyword.print(printer),
+            Self::SignedInteger(signed) => signed.print(printer),
+            Self::UnsignedInteger(unsigned) => unsigned.print(printer),
+            Self::FloatingPoint(floating) => floating.print(printer),
+            Self::Dict(keyword, templates)
+            | Self::Vec(keyword, templates)
+            | Self::Iterable(keyword, templates)
+            | Self::Class(keyword, templates)
+            | Self::Interface(keyword, templates) => {
+                keyword.print(printer);
+                templates.print(printer);
+            }
+            Self::Literal(literal) => literal.print(printer),
+            Self::Unit { .. } => printer.token("()"),
+            Self::Tuple { elements, .. } => {
+                printer.token("(");
+                printer.comma_separated(elements);
+                printer.token(")");
+            }
+            Self::Parenthesized {
+                type_definition, ..
+            } => {
+                printer.token("(");
+                type_definition.print(printer);
+                printer.token(")");
+            }
+            Self::KeyOf(keyword, type_definition) => {
+                keyword.print(printer);
+                printer.space();
+                type_definition.print(printer);
+            }
+            Self::IndexedAccess {
+                type_definition,
+                index,
+                ..
+            } => {
+                type_definition.print(printer);
+                printer.token("[");
+                index.print(printer);
+                printer.token("]");
+            }
+            Self::Shape {
+                shape,
+                fields,
+                ellipsis,
+                ..
+            } => {
+                shape.print(printer);
+                printer.token("(");
+                printer.comma_separated(fields);
+
+                if ellipsis.is_some() {
+                    if !fields.inner.is_empty() {
+                        printer.token(", ");
+                    }
+
+                    printer.token("...");
+                }
+
+                printer.token(")");
+            }
+            Self::IntegerRange { int, min, max, .. } => {
+                int.print(printer);
+                printer.token("<");
+                min.print(printer);
+                printer.token(", ");
+                max.print(printer);
+                printer.token(">");
+            }
+        }
+    }
+}
+
+impl Print for IntegerRangeBoundTypeDefinition {
+    fn print(&self, printer: &mut Printer) {
+        match &self {
+            Self::Integer { minus, value } => {
+                if minus.is_some() {
+                    printer.token("-");
+                }
+
+                value.print(printer);
+            }
+            Self::Unbounded(keyword) => keyword.print(printer),
+        }
+    }
+}
+
+impl Print for SignedIntegerTypeDefinition {
+    fn print(&self, printer: &mut Printer) {
+        match &self {
+            Self::Default(keyword)
+            | Self::I128(keyword)
+            | Self::I64(keyword)
+            | Self::I32(keyword)
+            | Self::I16(keyword)
+            | Self::I8(keyword) => keyword.print(printer),
+        }
+    }
+}
+
+impl Print for UnsignedIntegerTypeDefinition {
+    fn print(&self, printer: &mut Printer) {
+        match &self {
+            Self::Default(keyword)
+            | Self::U32(keyword)
+            | Self::U16(keyword)
+            | Self::U8(keyword) => keyword.print(printer),
+        }
+    }
+}
+
+impl Print for FloatingPointTypeDefinition {
+    fn print(&self, printer: &mut Printer) {
+        match &self {
+            Self::Default(keyword) | Self::F64(keyword) | Self::F32(keyword) => {
+                keyword.print(printer)
+            }
+        }
+    }
+}
+
+impl Print for TupleElementTypeDefinition {
+    fn print(&self, printer: &mut Printer) {
+        if let Some(label) = &self.label {
+            label.print(printer);
+        }
+
+        if let Some(optional) = &self.optional {
+            optional.print(printer);
+            printer.space();
+        }
+
+        self.type_definition.print(printer);
+
+        if let Some(variable) = &self.variable {
+            printer.space();
+            variable.print(printer);
+        }
+    }
+}
+
+impl Print for TupleElementLabelDefinition {
+    fn print(&self, printer: &mut Printer) {
+        self.name.print(printer);
+        printer.token(": ");
+    }
+}
+
+impl Print for ShapeFieldTypeDefinition {
+    fn print(&self, printer: &mut Printer) {
+        if self.question.is_some() {
+            printer.token("?");
+        }
+
+        self.key.print(printer);
+        printer.token(" => ");
+        self.type_definition.print(printer);
+    }
+}
@@ -0,0 +1,1019 @@
+use crate::printer::Print;
+use crate::printer::Printer;
+use crate::transpiler;
+use crate::tree::expression::argument::ArgumentExpression;
+use crate::tree::expression::argument::ArgumentListExpression;
+use crate::tree::expression::argument::ArgumentPlaceholderExpression;
+use crate::tree::expression::array::DictElementExpression;
+use crate::tree::expression::array::DictExpression;
+use crate::tree::expression::array::TupleExpression;
+use crate::tree::expression::array::VecElementExpression;
+use crate::tree::expression::array::VecExpression;
+use crate::tree::expression::class::AnonymousClassExpression;
+use crate::tree::expression::construct::ExitConstructExpression;
+use crate::tree::expression::control_flow::MatchArmConditionExpression;
+use crate::tree::expression::control_flow::MatchArmExpression;
+use crate::tree::expression::control_flow::MatchExpression;
+use crate::tree::expression::function::AnonymousFunctionExpression;
+use crate::tree::expression::function::AnonymousFunctionUseClauseExpression;
+use crate::tree::expression::function::AnonymousFunctionUseClauseVariableExpression;
+use crate::tree::expression::function::ArrowFunctionExpression;
+use crate::tree::expression::generic::GenericGroupExpression;
+use crate::tree::expression::literal::Literal;
+use crate::tree::expression::literal::LiteralFalse;
+use crate::tree::expression::literal::LiteralFloat;
+use crate::tree::expression::literal::LiteralInteger;
+use crate::tree::expression::literal::LiteralNull;
+use crate::tree::expression::literal::LiteralString;
+use crate::tree::expression::literal::LiteralTrue;
+use crate::tree::expression::magic_constant::MagicConstant;
+use crate::tree::expression::operator::ArithmeticOperationExpression;
+use crate::tree::expression::operator::ArrayOperationExpression;
+use crate::tree::expression::operator::AssignmentOperationExpression;
+use crate::tree::expression::operator::AsyncOperationExpression;
+use crate::tree::expression::operator::BitwiseOperationExpression;
+use crate::tree::expression::operator::ClassOperationExpression;
+use crate::tree::expression::operator::ClassOperationInitializationClassExpression;
+use crate::tree::expression::operator::CoalesceOperationExpression;
+use crate::tree::expression::operator::ComparisonOperationExpression;
+use crate::tree::expression::operator::ExceptionOperationExpression;
+use crate::tree::expression::operator::FunctionOperationExpression;
+use crate::tree::expression::operator::FunctionalOperationExpression;
+use crate::tree::expression::operator::GeneratorOperationExpression;
+use crate::tree::expression::operator::LogicalOperationExpression;
+use crate::tree::expression::operator::ObjectOperationExpression;
+use crate::tree::expression::operator::RangeOperationExpression;
+use crate::tree::expression::operator::StringOperationExpression;
+use crate::tree::expression::operator::TernaryOperationExpression;
+use crate::tree::expression::operator::TypeOperationExpression;
+use crate::tree::expression::Expression;
+use crate::tree::expression::ParenthesizedExpression;
+use crate::tree::Node;
+
+impl Print for Expression {
+    fn print(&self, printer: &mut Printer) {
+        match &self {
+            Self::Parenthesized(expression) => expression.print(printer),
+            Self::ExitConstruct(expression) => expression.print(printer),
+            Self::Literal(expression) => expression.print(printer),
+            Self::FunctionalOperation(expression) => expression.print(printer),
+            Self::ArithmeticOperation(expression) => expression.print(printer),
+            Self::AsyncOperation(expression) => expression.print(printer),
+            Self::ArrayOperation(expression) => expression.print(printer),
+            Self::AssignmentOperation(expression) => expression.print(printer),
+            Self::BitwiseOperation(expression) => expression.print(printer),
+            Self::ClassOperation(expression) => expression.print(printer),
+            Self::CoalesceOperation(expression) => expression.print(printer),
+            Self::ComparisonOperation(expression) => expression.print(printer),
+            Self::ExceptionOperation(expression) => expression.print(printer),
+            Self::FunctionOperation(expression) => expression.print(printer),
+            Self::GeneratorOperation(expression) => expression.print(printer),
+            Self::LogicalOperation(expression) => expression.print(printer),
+            Self::ObjectOperation(expression) => expression.print(printer),
+            Self::RangeOperation(expression) => expression.print(printer),
+            Self::StringOperation(expression) => expression.print(printer),
+            Self::TypeOperation(expression) => expression.print(printer),
+            Self::TernaryOperation(expression) => expression.print(printer),
+            Self::Identifier(expression) => expression.print(printer),
+            Self::Variable(expression) => expression.print(printer),
+            Self::Match(expression) => expression.print(printer),
+            Self::AnonymousFunction(expression) => expression.print(printer),
+            Self::ArrowFunction(expression) => expression.print(printer),
+            Self::Vec(expression) => expression.print(printer),
+            Self::Dict(expression) => expression.print(printer),
+            Self::Tuple(expression) => expression.print(printer),
+            Self::MagicConstant(expression) => expression.print(printer),
+        }
+    }
+}
+
+/// Print a binary operation, surrounding the operator with spaces.
+fn binary(printer: &mut Printer, left: &Expression, operator: &str, right: &Expression) {
+    left.print(printer);
+    printer.space();
+    printer.token(operator);
+    printer.space();
+    right.print(printer);
+}
+
+impl Print for ParenthesizedExpression {
+    fn print(&self, printer: &mut Printer) {
+        printer.token("(");
+        self.expression.print(printer);
+        printer.token(")");
+    }
+}
+
+impl Print for ExitConstructExpression {
+    fn print(&self, printer: &mut Printer) {
+        match &self {
+            Self::Exit { exit, .. } => exit.print(printer),
+            Self::ExitWith { exit, value, .. } => {
+                exit.print(printer);
+                printer.token("(");
+
+                if let Some(value) = value {
+                    value.print(printer);
+                }
+
+                printer.token(")");
+            }
+        }
+    }
+}
+
+impl Print for Literal {
+    fn print(&self, printer: &mut Printer) {
+        match &self {
+            Self::String(literal) => literal.print(printer),
+            Self::Integer(literal) => literal.print(printer),
+            Self::Float(literal) => literal.print(printer),
+            Self::Null(literal) => literal.print(printer),
+            Self::True(literal) => literal.print(printer),
+            Self::False(literal) => literal.print(printer),
+        }
+    }
+}
+
+impl Print for LiteralString {
+    fn print(&self, printer: &mut Printer) {
+        if printer.is_transpiling() {
+            printer.mapped(self.span(), &transpiler::string(&self.value));
+
+            return;
+        }
+
+        printer.mapped_bytes(self.span(), &self.value);
+    }
+}
+
+impl Print for LiteralInteger {
+    fn print(&self, printer: &mut Printer) {
+        printer.mapped_bytes(self.span(), &self.value);
+    }
+}
+
+impl Print for LiteralFloat {
+    fn print(&self, printer: &mut Printer) {
+        printer.mapped_bytes(self.span(), &self.value);
+    }
+}
+
+impl Print for LiteralNull {
+    fn print(&self, printer: &mut Printer) {
+        self.null.print(printer);
+    }
+}
+
+impl Print for LiteralTrue {
+    fn print(&self, printer: &mut Printer) {
+        self.r#true.print(printer);
+    }
+}
+
+impl Print for LiteralFalse {
+    fn print(&self, printer: &mut Printer) {
+        self.r#false.print(printer);
+    }
+}
+
+impl Print for MagicConstant {
+    fn print(&self, printer: &mut Printer) {
+        match &self {
+            Self::Directory { value, .. }
+            | Self::File { value, .. }
+            | Self::Line { value, .. }
+            | Self::Class { value, .. }
+            | Self::Function { value, .. }
+            | Self::Method { value, .. }
+            | Self::Namespace { value, .. } => printer.mapped_bytes(self.span(), value),
+        }
+    }
+}
+
+impl Print for ArgumentListExpression {
+    fn print(&self, printer: &mut Printer) {
+        printer.token("(");
+        printer.comma_separated(&self.arguments);
+        printer.token(")");
+    }
+}
+
+impl Print for ArgumentExpression {
+    fn print(&self, printer: &mut Printer) {
+        match &self {
+            Self::Value { value, .. } => value.print(printer),
+            Self::Spread { value, .. } => {
+                printer.token("...");
+                value.print(printer);
+            }
+            Self::ReverseSpread { value, .. } => {
+                if printer.is_transpiling() {
+                    transpiler::unsupported(printer, self);
+
+                    return;
+                }
+
+                value.print(printer);
+                printer.token("...");
+            }
+            Self::Named { name, value, .. } => {
+                name.print(printer);
+                printer.token(": ");
+                value.print(printer);
+            }
+        }
+    }
+}
+
+impl Print for ArgumentPlaceholderExpression {
+    fn print(&self, printer: &mut Printer) {
+        printer.token("(...)");
+    }
+}
+
+/// Generic arguments are erased when transpiling.
+impl Print for GenericGroupExpression {
+    fn print(&self, printer: &mut Printer) {
+        if printer.is_transpiling() {
+            return;
+        }
+
+        printer.token("::<");
+        printer.comma_separated(&self.types);
+        printer.token(">");
+    }
+}
+
+impl Print for VecExpression {
+    fn print(&self, printer: &mut Printer) {
+        if !printer.is_transpiling() {
+            self.vec.print(printer);
+
+            // unlike elsewhere, the generic arguments of a literal are not preceded by `::`.
+            if let Some(generics) = &self.generics {
+                printer.token("<");
+                printer.comma_separated(&generics.types);
+                printer.token(">");
+            }
+        }
+
+        printer.token("[");
+        printer.comma_separated(&self.elements);
+        printer.token("]");
+    }
+}
+
+impl Print for VecElementExpression {
+    fn print(&self, printer: &mut Printer) {
+        self.value.print(printer);
+    }
+}
+
+impl Print for DictExpression {
+    fn print(&self, printer: &mut Printer) {
+        if !printer.is_transpiling() {
+            self.dict.print(printer);
+
+            // unlike elsewhere, the generic arguments of a literal are not preceded by `::`.
+            if let Some(generics) = &self.generics {
+                printer.token("<");
+                printer.comma_separated(&generics.types);
+                printer.token(">");
+            }
+        }
+
+        printer.token("[");
+        printer.comma_separated(&self.elements);
+        printer.token("]");
+    }
+}
+
+impl Print for DictElementExpression {
+    fn print(&self, printer: &mut Printer) {
+        binary(printer, &self.key, "=>", &self.value);
+    }
+}
+
+/// Tuples are printed as arrays when transpiling.
+impl Print for TupleExpression {
+    fn print(&self, printer: &mut Printer) {
+        let (open, close) = if printer.is_transpiling() {
+            ("[", "]")
+        } else {
+            ("(", ")")
+        };
+
+        printer.token(open);
+        printer.comma_separated(&self.elements);
+        printer.token(close);
+    }
+}
+
+impl Print for MatchExpression {
+    fn print(&self, printer: &mut Printer) {
+        self.r#match.print(printer);
+        printer.space();
+
+        if printer.is_transpiling() {
+            match &self.expression {
+                Some(expression) => transpiler::parenthesized(printer, expression),
+                None => printer.token("(true)"),
+            }
+
+            printer.space();
+        } else if let Some(expression) = &self.expression {
+            expression.print(printer);
+            printer.space();
+        }
+
+        printer.token("{");
+        printer.new_line();
+        printer.indent();
+
+        for arm in &self.body.arms.inner {
+            arm.print(printer);
+            printer.token(",");
+            printer.new_line();
+        }
+
+        printer.dedent();
+        printer.token("}");
+    }
+}
+
+impl Print for MatchArmExpression {
+    fn print(&self, printer: &mut Printer) {
+        match &self.condition {
+            MatchArmConditionExpression::Expressions(expressions) => {
+                printer.comma_separated(expressions)
+            }
+            MatchArmConditionExpression::Default(default) => default.print(printer),
+        }
+
+        printer.token(" => ");
+        self.expression.print(printer);
+    }
+}
+
+impl Print for AnonymousFunctionExpression {
+    fn print(&self, printer: &mut Printer) {
+        for attribute in &self.attributes {
+            attribute.print(printer);
+            printer.space();
+        }
+
+        self.modifiers.print(printer);
+        self.function.print(printer);
+        self.parameters.print(printer);
+
+        if let Some(use_clause) = &self.use_clause {
+            printer.space();
+            use_clause.print(printer);
+        }
+
+        self.return_type.print(printer);
+        printer.space();
+        self.body.print(printer);
+    }
+}
+
+impl Print for AnonymousFunctionUseClauseExpression {
+    fn print(&self, printer: &mut Printer) {
+        self.r#use.print(printer);
+        printer.token("(");
+        printer.comma_separated(&self.variables);
+        printer.token(")");
+    }
+}
+
+impl Print for AnonymousFunctionUseClauseVariableExpression {
+    fn print(&self, printer: &mut Printer) {
+        self.variable.print(printer);
+    }
+}
+
+impl Print for ArrowFunctionExpression {
+    fn print(&self, printer: &mut Printer) {
+        for attribute in &self.attributes {
+            attribute.print(printer);
+            printer.space();
+        }
+
+        self.modifiers.print(printer);
+        self.r#fn.print(printer);
+        self.parameters.print(printer);
+        self.return_type.print(printer);
+        printer.token(" => ");
+        self.body.print(printer);
+    }
+}
+
+impl Print for AnonymousClassExpression {
+    fn print(&self, printer: &mut Printer) {
+        for attribute in &self.attributes {
+            attribute.print(printer);
+            printer.space();
+        }
+
+        self.class.print(printer);
+        self.arguments.print(printer);
+
+        if let Some(extends) = &self.extends {
+            printer.space();
+            extends.print(printer);
+        }
+
+        if let Some(implements) = &self.implements {
+            printer.space();
+            implements.print(printer);
+        }
+
+        printer.space();
+        self.body.print(printer);
+    }
+}
+
+impl Print for FunctionalOperationExpression {
+    fn print(&self, printer: &mut Printer) {
+        if printer.is_transpiling() {
+            transpiler::unsupported(printer, self);
+
+            return;
+        }
+
+        match &self {
+            Self::Pipe { left, right, .. } => binary(printer, left, "|>", right),
+            Self::Expression {
+                generics,
+                expression,
+                ..
+            } => {
+                printer.token("$");
+
+                if let Some(generics) = generics {
+                    generics.print(printer);
+                }
+
+                printer.token("(");
+                expression.print(printer);
+                printer.token(")");
+            }
+        }
+    }
+}
+
+impl Print for ArithmeticOperationExpression {
+    fn print(&self, printer: &mut Printer) {
+        match &self {
+            Self::Addition { left, right, .. } => binary(printer, left, "+", right),
+            Self::Subtraction { left, right, .. } => binary(printer, left, "-", right),
+            Self::Multiplication { left, right, .. } => binary(printer, left, "*", right),
+            Self::Division { left, right, .. } => binary(printer, left, "/", right),
+            Self::Modulo { left, right, .. } => binary(printer, left, "%", right),
+            Self::Exponentiation { left, right, .. } => binary(printer, left, "**", right),
+            Self::Negative { right, .. } => {
+                printer.token("-");
+                right.print(printer);
+            }
+            Self::Positive { right, .. } => {
+                printer.token("+");
+                right.print(printer);
+            }
+            Self::PreIncrement { right, .. } => {
+                printer.token("++");
+                right.print(printer);
+            }
+            Self::PostIncrement { left, .. } => {
+                left.print(printer);
+                printer.token("++");
+            }
+            Self::PreDecrement { right, .. } => {
+                printer.token("--");
+                right.print(printer);
+            }
+            Self::PostDecrement { left, .. } => {
+                left.print(printer);
+                printer.token("--");
+            }
+        }
+    }
+}
+
+impl Print for AssignmentOperationExpression {
+    fn print(&self, printer: &mut Printer) {
+        let (left, operator, right) = match &self {
+            Self::Assignment { left, right, .. } => (left, "=", right),
+            Self::Addition { left, right, .. } => (left, "+=", right),
+            Self::Subtraction { left, right, .. } => (left, "-=", right),
+            Self::Multiplication { left, right, .. } => (left, "*=", right),
+            Self::Division { left, right, .. } => (left, "/=", right),
+            Self::Modulo { left, right, .. } => (left, "%=", right),
+            Self::Exponentiation { left, right, .. } => (left, "**=", right),
+            Self::Concat { left, right, .. } => (left, ".=", right),
+            Self::BitwiseAnd { left, right, .. } => (left, "&=", right),
+            Self::BitwiseOr { left, right, .. } => (left, "|=", right),
+            Self::BitwiseXor { left, right, .. } => (left, "^=", right),
+            Self::LeftShift { left, right, .. } => (left, "<<=", right),
+            Self::RightShift { left, right, .. } => (left, ">>=", right),
+            Self::Coalesce { left, right, .. } => (left, "??=", right),
+        };
+
+        binary(printer, left, operator, right);
+    }
+}
+
+impl Print for BitwiseOperationExpression {
+    fn print(&self, printer: &mut Printer) {
+        match &self {
+            Self::And { left, right, .. } => binary(printer, left, "&", right),
+            Self::Or { left, right, .. } => binary(printer, left, "|", right),
+            Self::Xor { left, right, .. } => binary(printer, left, "^", right),
+            Self::LeftShift { left, right, .. } => binary(printer, left, "<<", right),
+            Self::RightShift { left, right, .. } => binary(printer, left, ">>", right),
+            Self::Not { right, .. } => {
+                printer.token("~");
+                right.print(printer);
+            }
+        }
+    }
+}
+
+impl Print for ComparisonOperationExpression {
+    fn print(&self, printer: &mut Printer) {
+        let (left, operator, right) = match &self {
+            Self::Equal { left, right, .. } => (left, "==", right),
+            Self::Identical { left, right, .. } => (left, "===", right),
+            Self::NotEqual { left, right, .. } => (left, "!=", right),
+            Self::NotIdentical { left, right, .. } => (left, "!==", right),
+            Self::LessThan { left, right, .. } => (left, "<", right),
+            Self::GreaterThan { left, right, .. } => (left, ">", right),
+            Self::LessThanOrEqual { left, right, .. } => (left, "<=", right),
+            Self::GreaterThanOrEqual { left, right, .. } => (left, ">=", right),
+            Self::Spaceship { left, right, .. } => (left, "<=>", right),
+        };
+
+        binary(printer, left, operator, right);
+    }
+}
+
+impl Print for LogicalOperationExpression {
+    fn print(&self, printer: &mut Printer) {
+        match &self {
+            Self::And { left, right, .. } => binary(printer, left, "&&", right),
+            Self::Or { left, right, .. } => binary(printer, left, "||", right),
+            Self::Not { right, .. } => {
+                printer.token("!");
+                right.print(printer);
+            }
+        }
+    }
+}
+
+impl Print for StringOperationExpression {
+    fn print(&self, printer: &mut Printer) {
+        match &self {
+            Self::Concat { left, right, .. } => binary(printer, left, ".", right),
+        }
+    }
+}
+
+impl Print for ArrayOperationExpression {
+    fn print(&self, printer: &mut Printer) {
+        match &self {
+            Self::Access { array, index, .. } => {
+                array.print(printer);
+                printer.token("[");
+                index.print(printer);
+                printer.token("]");
+            }
+            Self::Push { array, .. } => {
+                array.print(printer);
+                printer.token("[]");
+            }
+            Self::Unset {
+                unset: keyword,
+                item,
+                ..
+            }
+            | Self::Isset {
+                isset: keyword,
+                item,
+                ..
+            } => {
+                keyword.print(printer);
+
+                if printer.is_transpiling() {
+                    transpiler::parenthesized(printer, item);
+                } else {
+                    printer.space();
+                    item.print(printer);
+                }
+            }
+            Self::In { item, array, .. } if printer.is_transpiling() => {
+                printer.token("\\in_array(");
+                item.print(printer);
+                printer.token(", ");
+                array.print(printer);
+                printer.token(", true)");
+            }
+            Self::In {
+                item, r#in, array, ..
+            } => {
+                item.print(printer);
+                printer.space();
+                r#in.print(printer);
+                printer.space();
+                array.print(printer);
+            }
+        }
+    }
+}
+
+impl Print for CoalesceOperationExpression {
+    fn print(&self, printer: &mut Printer) {
+        match &self {
+            Self::Coalesce { left, right, .. } => binary(printer, left, "??", right),
+        }
+    }
+}
+
+impl Print for TernaryOperationExpression {
+    fn print(&self, printer: &mut Printer) {
+        match &self {
+            Self::Ternary {
+                condition,
+                if_true,
+                if_false,
+                ..
+            } => {
+                condition.print(printer);
+                printer.token(" ? ");
+                if_true.print(printer);
+                printer.token(" : ");
+                if_false.print(printer);
+            }
+            Self::ImplicitShortTernary {
+                condition,
+                if_false,
+                ..
+            } => binary(printer, condition, "? :", if_false),
+            Self::ShortTernary {
+                condition,
+                if_false,
+                ..
+            } => binary(printer, condition, "?:", if_false),
+        }
+    }
+}
+
+impl Print for TypeOperationExpression {
+    fn print(&self, printer: &mut Printer) {
+        match &self {
+            Self::Instanceof {
+                left,
+                instanceof,
+                right,
+                ..
+            } => {
+                left.print(printer);
+                printer.space();
+                instanceof.print(printer);
+                printer.space();
+                right.print(printer);
+            }
+            Self::Is { left, right, .. } if printer.is_transpiling() => {
+                transpiler::is(printer, left, right)
+            }
+            Self::Into { left, right, .. } if printer.is_transpiling() => {
+                transpiler::into(printer, left, right)
+            }
+            Self::As { left, right, .. } if printer.is_transpiling() => {
+                transpiler::r#as(printer, left, right)
+            }
+            Self::Is {
+                left,
+                is: keyword,
+                right,
+                ..
+            }
+            | Self::Into {
+                left,
+                into: keyword,
+                right,
+                ..
+            }
+            | Self::As {
+                left,
+                r#as: keyword,
+                right,
+                ..
+            } => {
+                left.print(printer);
+                printer.space();
+                keyword.print(printer);
+                printer.space();
+                right.print(printer);
+            }
+        }
+    }
+}
+
+impl Print for GeneratorOperationExpression {
+    fn print(&self, printer: &mut Printer) {
+        match &self {
+            Self::Yield { r#yield, .. } => r#yield.print(printer),
+            Self::YieldValue { r#yield, value, .. } => {
+                r#yield.print(printer);
+                printer.space();
+                value.print(printer);
+            }
+            Self::YieldKeyValue {
+                r#yield,
+                key,
+                value,
+                ..
+            } => {
+                r#yield.print(printer);
+                printer.space();
+                binary(printer, key, "=>", value);
+            }
+            Self::YieldFrom {
+                r#yield,
+                from,
+                value,
+                ..
+            } => {
+                r#yield.print(printer);
+                printer.space();
+                from.print(printer);
+                printer.space();
+                value.print(printer);
+            }
+        }
+    }
+}
+
+impl Print for ExceptionOperationExpression {
+    fn print(&self, printer: &mut Printer) {
+        match &self {
+            Self::Throw { r#throw, value, .. } => {
+                r#throw.print(printer);
+                printer.space();
+                value.print(printer);
+            }
+        }
+    }
+}
+
+impl Print for ObjectOperationExpression {
+    fn print(&self, printer: &mut Printer) {
+        match &self {
+            Self::Clone { clone, object, .. } => {
+                clone.print(printer);
+                printer.space();
+                object.print(printer);
+            }
+            Self::MethodCall {
+                object,
+                method,
+                generics,
+                arguments,
+                ..
+            } => {
+                object.print(printer);
+                printer.token("->");
+                method.print(printer);
+                generics.iter().for_each(|generics| generics.print(printer));
+                arguments.print(printer);
+            }
+            Self::NullsafeMethodCall {
+                object,
+                method,
+                generics,
+                arguments,
+                ..
+            } => {
+                object.print(printer);
+                printer.token("?->");
+                method.print(printer);
+                generics.iter().for_each(|generics| generics.print(printer));
+                arguments.print(printer);
+            }
+            Self::MethodClosureCreation {
+                object,
+                method,
+                generics,
+                placeholder,
+                ..
+            } => {
+                object.print(printer);
+                printer.token("->");
+                method.print(printer);
+                generics.iter().for_each(|generics| generics.print(printer));
+                placeholder.print(printer);
+            }
+            Self::PropertyFetch {
+                object, property, ..
+            } => {
+                object.print(printer);
+                printer.token("->");
+                property.print(printer);
+            }
+            Self::NullsafePropertyFetch {
+                object, property, ..
+            } => {
+                object.print(printer);
+                printer.token("?->");
+                property.print(printer);
+            }
+        }
+    }
+}
+
+impl Print for ClassOperationExpression {
+    fn print(&self, printer: &mut Printer) {
+        match &self {
+            Self::Initialization {
+                new,
+                class,
+                generics,
+                arguments,
+                ..
+            } => {
+                new.print(printer);
+                printer.space();
+
+                match class {
+                    ClassOperationInitializationClassExpression::Identifier(identifier) => {
+                        identifier.print(printer)
+                    }
+                    ClassOperationInitializationClassExpression::Variable(variable) => {
+                        variable.print(printer)
+                    }
+                }
+
+                generics.iter().for_each(|generics| generics.print(printer));
+                arguments.print(printer);
+            }
+            Self::AnonymousInitialization { new, class, .. } => {
+                new.print(printer);
+                printer.space();
+                class.print(printer);
+            }
+            Self::StaticMethodCall {
+                class,
+                method,
+                generics,
+                arguments,
+                ..
+            } => {
+                class.print(printer);
+                printer.token("::");
+                method.print(printer);
+                generics.iter().for_each(|generics| generics.print(printer));
+                arguments.print(printer);
+            }
+            Self::StaticMethodClosureCreation {
+                class,
+                method,
+                generics,
+                placeholder,
+                ..
+            } => {
+                class.print(printer);
+                printer.token("::");
+                method.print(printer);
+                generics.iter().for_each(|generics| generics.print(printer));
+                placeholder.print(printer);
+            }
+            Self::StaticPropertyFetch {
+                class, property, ..
+            } => {
+                class.print(printer);
+                printer.token("::");
+                property.print(printer);
+            }
+            Self::ConstantFetch {
+                class, constant, ..
+            } => {
+                class.print(printer);
+                printer.token("::");
+                constant.print(printer);
+            }
+        }
+    }
+}
+
+impl Print for FunctionOperationExpression {
+    fn print(&self, printer: &mut Printer) {
+        match &self {
+            Self::Call {
+                function,
+                generics,
+                arguments,
+                ..
+            } => {
+                function.print(printer);
+                generics.iter().for_each(|generics| generics.print(printer));
+                arguments.print(printer);
+            }
+            Self::ClosureCreation {
+                function,
+                generics,
+                placeholder,
+                ..
+            } => {
+                function.print(printer);
+                generics.iter().for_each(|generics| generics.print(printer));
+                placeholder.print(printer);
+            }
+        }
+    }
+}
+
+impl Print for AsyncOperationExpression {
+    fn print(&self, printer: &mut Printer) {
+        if printer.is_transpiling() {
+            transpiler::unsupported_async(printer, self);
+
+            return;
+        }
+
+        match &self {
+            Self::Async {
+                r#async,
+                expression,
+                ..
+            } => {
+                r#async.print(printer);
+                printer.space();
+                expression.print(printer);
+            }
+            Self::Await {
+                r#await,
+                expression,
+                ..
+            } => {
+                r#await.print(printer);
+                printer.space();
+                expression.print(printer);
+            }
+            Self::Concurrently {
+                concurrently,
+                expressions,
+                ..
+            } => {
+                concurrently.print(printer);
+                printer.token(" {");
+                printer.new_line();
+                printer.indent();
+
+                for expression in &expressions.inner {
+                    expression.print(printer);
+                    printer.token(",");
+                    printer.new_line();
+                }
+
+                printer.dedent();
+                printer.token("}");
+            }
+        }
+    }
+}
+
+impl Print for RangeOperationExpression {
+    fn print(&self, printer: &mut Printer) {
+        if printer.is_transpiling() {
+            transpiler::unsupported(printer, self);
+
+            return;
+        }
+
+        match &self {
+            Self::Between { from, to, .. } => {
+                from.print(printer);
+                printer.token("..");
+                to.print(printer);
+            }
+            Self::BetweenInclusive { from, to, .. } => {
+                from.print(printer);
+                printer.token("..=");
+                to.print(printer);
+            }
+            Self::To { to, .. } => {
+                printer.token("..");
+                to.print(printer);
+            }
+            Self::ToInclusive { to, .. } => {
+                printer.token("..=");
+                to.print(printer);
+            }
+            Self::From { from, .. } => {
+                from.print(printer);
+                printer.token("..");
+            }
+            Self::Full { .. } => printer.token(".."),
+        }
+    }
+}
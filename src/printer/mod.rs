@@ -0,0 +1,368 @@
+use crate::lexer::byte_string::ByteString;
+use crate::printer::source_map::Mapping;
+use crate::printer::source_map::SourceMap;
+use crate::transpiler::Context;
+use crate::tree::comment::CommentAttachments;
+use crate::tree::comment::CommentGroup;
+use crate::tree::span::Span;
+use crate::tree::utils::CommaSeparated;
+use crate::tree::Node;
+use crate::tree::Tree;
+
+pub(in crate::printer) mod definition;
+pub(in crate::printer) mod expression;
+pub mod source_map;
+pub(in crate::printer) mod statement;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrinterOptions {
+    /// The string used for a single level of indentation.
+    pub indentation: String,
+}
+
+impl Default for PrinterOptions {
+    fn default() -> Self {
+        Self {
+            indentation: "    ".to_string(),
+        }
+    }
+}
+
+/// A node that can be printed back to Ara source code.
+pub trait Print {
+    fn print(&self, printer: &mut Printer);
+}
+
+/// Prints nodes as Ara source code.
+///
+/// Unlike the `Display` implementations of the nodes, the printer emits complete source code,
+/// including bodies, which parses back into an equivalent tree.
+///
+/// Comments are only printed if the printer was given the comments of the tree being printed.
+#[derive(Debug)]
+pub struct Printer<'a> {
+    options: PrinterOptions,
+    comments: Option<&'a CommentAttachments>,
+    output: String,
+    level: usize,
+    line_start: bool,
+    transpiler: Option<Context<'a>>,
+    source_map: Option<SourceMap>,
+}
+
+impl<'a> Printer<'a> {
+    pub fn new(options: PrinterOptions) -> Self {
+        Self {
+            options,
+            comments: None,
+            output: String::new(),
+            level: 0,
+            line_start: true,
+            transpiler: None,
+            source_map: None,
+        }
+    }
+
+    /// A printer printing the comments of nodes from the given comments of their tree.
+    pub fn with_comments(options: PrinterOptions, comments: &'a CommentAttachments) -> Self {
+        Self {
+            comments: Some(comments),
+            ..Self::new(options)
+        }
+    }
+
+    /// A printer printing nodes as PHP source code, see `transpiler::transpile`.
+    pub(crate) fn transpiling(
+        options: PrinterOptions,
+        comments: &'a CommentAttachments,
+        context: Context<'a>,
+    ) -> Self {
+        Self {
+            transpiler: Some(context),
+            ..Self::with_comments(options, comments)
+        }
+    }
+
+    /// Record the mappings of the printed nodes to their output, see `finish_with_source_map`.
+    pub fn with_source_map(mut self) -> Self {
+        self.source_map = Some(SourceMap::default());
+
+        self
+    }
+
+    pub fn finish(self) -> String {
+        self.output
+    }
+
+    /// The output, along with the mappings of the printed nodes to it, which are empty unless the
+    /// printer was created `with_source_map`.
+    pub fn finish_with_source_map(self) -> (String, SourceMap) {
+        (self.output, self.source_map.unwrap_or_default())
+    }
+
+    pub(crate) fn finish_transpiling(self) -> (String, Option<SourceMap>, Option<Context<'a>>) {
+        (self.output, self.source_map, self.transpiler)
+    }
+
+    /// The state of transpiling, `None` unless the printer prints PHP source code.
+    pub(crate) fn transpiler(&mut self) -> Option<&mut Context<'a>> {
+        self.transpiler.as_mut()
+    }
+
+    pub(crate) fn is_transpiling(&self) -> bool {
+        self.transpiler.is_some()
+    }
+
+    /// Print the given node on its own, returning its output instead of appending it.
+    ///
+    /// The node is not mapped, as the position of its output is not known.
+    pub(crate) fn capture<T: Print + ?Sized>(&mut self, node: &T) -> String {
+        let output = std::mem::take(&mut self.output);
+        let line_start = std::mem::replace(&mut self.line_start, false);
+        let mappings = self
+            .source_map
+            .as_ref()
+            .map(|source_map| source_map.mappings.len());
+
+        node.print(self);
+
+        if let (Some(source_map), Some(mappings)) = (&mut self.source_map, mappings) {
+            source_map.mappings.truncate(mappings);
+        }
+
+        self.line_start = line_start;
+        std::mem::replace(&mut self.output, output)
+    }
+
+    pub fn token(&mut self, value: &str) {
+        self.indentation();
+        self.output.push_str(value);
+    }
+
+    /// Print the indentation of the current line, if nothing was printed on it yet.
+    fn indentation(&mut self) {
+        if self.line_start {
+            for _ in 0..self.level {
+                self.output.push_str(&self.options.indentation);
+            }
+
+            self.line_start = false;
+        }
+    }
+
+    pub fn bytes(&mut self, value: &ByteString) {
+        self.token(&String::from_utf8_lossy(value));
+    }
+
+    /// Print the given value as the output of the given span of the source, mapping the span to
+    /// the output if a source map is recorded.
+    pub fn mapped(&mut self, span: Span, value: &str) {
+        self.indentation();
+
+        let start = self.output.len();
+        self.output.push_str(value);
+
+        if let Some(source_map) = &mut self.source_map {
+            source_map.mappings.push(Mapping {
+                source: span,
+                output: Span::new(start, self.output.len()),
+            });
+        }
+    }
+
+    pub fn mapped_bytes(&mut self, span: Span, value: &ByteString) {
+        self.mapped(span, &String::from_utf8_lossy(value));
+    }
+
+    pub fn space(&mut self) {
+        self.token(" ");
+    }
+
+    pub fn new_line(&mut self) {
+        self.output.push('\n');
+        self.line_start = true;
+    }
+
+    pub fn indent(&mut self) {
+        self.level += 1;
+    }
+
+    pub fn dedent(&mut self) {
+        self.level = self.level.saturating_sub(1);
+    }
+
+    pub fn node<T: Print + ?Sized>(&mut self, node: &T) {
+        node.print(self);
+    }
+
+    /// Print each comment of the group on its own line.
+    pub fn comments(&mut self, group: &CommentGroup) {
+        let comments = match self.comments {
+            Some(comments) => comments.group(group),
+            None => return,
+        };
+
+        for comment in comments {
+            self.bytes(&comment.content);
+            self.new_line();
+        }
+    }
+
+    pub fn comma_separated<T: Node + Print>(&mut self, items: &CommaSeparated<T>) {
+        self.separated(&items.inner, ", ");
+    }
+
+    pub fn separated<T: Print>(&mut self, items: &[T], separator: &str) {
+        for (index, item) in items.iter().enumerate() {
+            if index > 0 {
+                self.token(separator);
+            }
+
+            item.print(self);
+        }
+    }
+
+    /// Print `{`, the given members each on their own line, and `}`.
+    ///
+    /// Members are separated by an empty line when `spaced` is set, and an empty body is printed as `{}`.
+    pub fn braced<T: Print>(&mut self, members: &[T], spaced: bool) {
+        if members.is_empty() {
+            self.token("{}");
+
+            return;
+        }
+
+        self.token("{");
+        self.new_line();
+        self.indent();
+
+        for (index, member) in members.iter().enumerate() {
+            if spaced && index > 0 {
+                self.new_line();
+            }
+
+            member.print(self);
+            self.new_line();
+        }
+
+        self.dedent();
+        self.token("}");
+    }
+}
+
+pub fn print(tree: &Tree) -> String {
+    print_with_options(tree, PrinterOptions::default())
+}
+
+pub fn print_with_options(tree: &Tree, options: PrinterOptions) -> String {
+    let mut printer = Printer::with_comments(options, &tree.comments);
+
+    tree.definitions.print(&mut printer);
+
+    printer.finish()
+}
+
+/// Print the tree, along with the mappings of its nodes to the output.
+pub fn print_with_source_map(tree: &Tree, options: PrinterOptions) -> (String, SourceMap) {
+    let mut printer = Printer::with_comments(options, &tree.comments).with_source_map();
+
+    tree.definitions.print(&mut printer);
+
+    printer.finish_with_source_map()
+}
+
+/// Print the given node, along with its comments from the given comments of its tree, or of the
+/// fragment it was parsed as, see `parser::parse_definition`.
+pub fn print_node<T: Print + ?Sized>(
+    node: &T,
+    comments: &CommentAttachments,
+    options: PrinterOptions,
+) -> String {
+    let mut printer = Printer::with_comments(options, comments);
+
+    node.print(&mut printer);
+
+    printer.finish()
+}
+
+/// Print the tree exactly as it was parsed, including all of its trivia.
+///
+/// Returns `None` if the tree was not produced by a lossless parse.
+pub fn print_lossless(tree: &Tree) -> Option<String> {
+    tree.trivia
+        .as_ref()
+        .map(|trivia| String::from_utf8_lossy(&trivia.source()).into_owned())
+}
+
+/// Print the given node of the tree exactly as it was parsed, excluding the trivia preceding it.
+///
+/// Returns `None` if the tree was not produced by a lossless parse.
+pub fn print_lossless_node(tree: &Tree, node: &dyn Node) -> Option<String> {
+    tree.trivia
+        .as_ref()
+        .map(|trivia| String::from_utf8_lossy(&trivia.text(node.span())).into_owned())
+}
+
+impl<T: Print + ?Sized> Print for Box<T> {
+    fn print(&self, printer: &mut Printer) {
+        self.as_ref().print(printer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser;
+    use ara_source::source::Source;
+    use ara_source::source::SourceKind;
+
+    #[test]
+    fn test_print_with_indentation() {
+        let source = Source::inline(
+            SourceKind::Script,
+            "function foo(): void { if $a { bar(); } }",
+        );
+
+        let tree = parser::parse(&source).unwrap();
+        let options = PrinterOptions {
+            indentation: "\t".to_string(),
+        };
+
+        assert_eq!(
+            print_with_options(&tree, options),
+            "function foo(): void {\n\tif $a {\n\t\tbar();\n\t}\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_print_with_source_map() {
+        let code = "function foo(): void {\n  $a = 'b';\n\n    if $a {   bar(1.5); }\n}\n";
+        let source = Source::inline(SourceKind::Script, code);
+
+        let tree = parser::parse(&source).unwrap();
+        let (output, source_map) = print_with_source_map(&tree, PrinterOptions::default());
+
+        assert_eq!(output, print(&tree));
+        assert_eq!(source_map.mappings.len(), 9);
+        for mapping in &source_map.mappings {
+            assert_eq!(
+                &code[mapping.source.start..mapping.source.end],
+                &output[mapping.output.start..mapping.output.end]
+            );
+        }
+
+        let bar = output.find("bar").unwrap();
+        assert_eq!(
+            source_map.source(bar + 1),
+            Some(Span::new(
+                code.find("bar").unwrap(),
+                code.find("bar").unwrap() + 3
+            ))
+        );
+        assert_eq!(source_map.source(output.find('{').unwrap()), None);
+        assert_eq!(
+            source_map.lines(code, &output),
+            vec![Some(1), Some(2), Some(4), Some(4), None, None, None]
+        );
+    }
+}
@@ -0,0 +1,70 @@
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::tree::span::LineIndex;
+use crate::tree::span::Span;
+
+/// The span of a printed node in its source, paired with the span of its output.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct Mapping {
+    pub source: Span,
+    pub output: Span,
+}
+
+/// The mappings of the nodes of a printed tree to their output, in the order they were printed.
+///
+/// Only leaves, such as keywords, identifiers, variables, and literals, are mapped, along with
+/// the constructs lowered as a whole when transpiling, such as `is` operations, whose own leaves
+/// are not mapped.
+#[derive(Debug, PartialEq, Eq, Clone, Default, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct SourceMap {
+    pub mappings: Vec<Mapping>,
+}
+
+impl SourceMap {
+    /// The span of the source printed at the given output offset, `None` if the output at the
+    /// offset is not mapped, e.g. punctuation.
+    pub fn source(&self, offset: usize) -> Option<Span> {
+        // mappings never overlap, and are ordered by their output.
+        let index = self
+            .mappings
+            .partition_point(|mapping| mapping.output.end <= offset);
+
+        self.mappings
+            .get(index)
+            .filter(|mapping| mapping.output.contains(offset))
+            .map(|mapping| mapping.source)
+    }
+
+    /// The span of the output of the source at the given offset, `None` if the source at the
+    /// offset is not mapped.
+    pub fn output(&self, offset: usize) -> Option<Span> {
+        self.mappings
+            .iter()
+            .find(|mapping| mapping.source.contains(offset))
+            .map(|mapping| mapping.output)
+    }
+
+    /// The line of the source first mapped on each line of the given output, e.g. to map the
+    /// lines of a stack trace of the output back to the source.
+    ///
+    /// Lines start at 1, the first element is the source line of the first output line, lines
+    /// without any mapping are `None`.
+    pub fn lines(&self, source: &str, output: &str) -> Vec<Option<usize>> {
+        let source = LineIndex::new(source);
+        let output = LineIndex::new(output);
+
+        let mut lines = vec![None; output.lines()];
+        for mapping in &self.mappings {
+            let line = output.location(mapping.output.start).line - 1;
+            if lines[line].is_none() {
+                lines[line] = Some(source.location(mapping.source.start).line);
+            }
+        }
+
+        lines
+    }
+}
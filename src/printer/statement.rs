@@ -0,0 +1,451 @@
+use crate::printer::Print;
+use crate::printer::Printer;
+use crate::transpiler;
+use crate::tree::expression::Expression;
+use crate::tree::statement::block::BlockStatement;
+use crate::tree::statement::control_flow::IfElseBlockStatement;
+use crate::tree::statement::control_flow::IfElseIfStatement;
+use crate::tree::statement::control_flow::IfElseStatement;
+use crate::tree::statement::control_flow::IfStatement;
+use crate::tree::statement::control_flow::UsingAssignmentStatement;
+use crate::tree::statement::control_flow::UsingIfClauseStatement;
+use crate::tree::statement::control_flow::UsingStatement;
+use crate::tree::statement::expression::ExpressionStatement;
+use crate::tree::statement::r#loop::BreakStatement;
+use crate::tree::statement::r#loop::ContinueStatement;
+use crate::tree::statement::r#loop::DoWhileStatement;
+use crate::tree::statement::r#loop::ForIteratorStatement;
+use crate::tree::statement::r#loop::ForStatement;
+use crate::tree::statement::r#loop::ForeachIteratorKeyStatement;
+use crate::tree::statement::r#loop::ForeachIteratorStatement;
+use crate::tree::statement::r#loop::ForeachStatement;
+use crate::tree::statement::r#loop::WhileStatement;
+use crate::tree::statement::r#return::ReturnStatement;
+use crate::tree::statement::r#try::TryCatchBlockStatement;
+use crate::tree::statement::r#try::TryCatchTypeStatement;
+use crate::tree::statement::r#try::TryFinallyBlockStatement;
+use crate::tree::statement::r#try::TryStatement;
+use crate::tree::statement::Statement;
+use crate::tree::utils::CommaSeparated;
+
+impl Print for Statement {
+    fn print(&self, printer: &mut Printer) {
+        match &self {
+            Self::DoWhile(statement) => statement.print(printer),
+            Self::While(statement) => statement.print(printer),
+            Self::For(statement) => statement.print(printer),
+            Self::Foreach(statement) => statement.print(printer),
+            Self::Break(statement) => statement.print(printer),
+            Self::Continue(statement) => statement.print(printer),
+            Self::If(statement) => statement.print(printer),
+            Self::Using(statement) => statement.print(printer),
+            Self::Try(statement) => statement.print(printer),
+            Self::Expression(statement) => statement.print(printer),
+            Self::Return(statement) => statement.print(printer),
+            Self::Block(statement) => statement.print(printer),
+            Self::Empty(_) => printer.token(";"),
+        }
+    }
+}
+
+impl Print for BlockStatement {
+    fn print(&self, printer: &mut Printer) {
+        printer.braced(&self.statements, false);
+    }
+}
+
+impl Print for ExpressionStatement {
+    fn print(&self, printer: &mut Printer) {
+        printer.comments(&self.comments);
+        self.expression.print(printer);
+        printer.token(";");
+    }
+}
+
+impl Print for ReturnStatement {
+    fn print(&self, printer: &mut Printer) {
+        match &self {
+            Self::Explicit {
+                comments,
+                r#return,
+                expression,
+                ..
+            } => {
+                printer.comments(comments);
+                r#return.print(printer);
+
+                if let Some(expression) = expression {
+                    printer.space();
+                    expression.print(printer);
+                }
+
+                printer.token(";");
+            }
+            Self::Implicit {
+                comments,
+                expression,
+            } => {
+                printer.comments(comments);
+
+                if printer.is_transpiling() {
+                    printer.token("return ");
+                    expression.print(printer);
+                    printer.token(";");
+                } else {
+                    expression.print(printer);
+                }
+            }
+        }
+    }
+}
+
+impl Print for IfStatement {
+    fn print(&self, printer: &mut Printer) {
+        printer.comments(&self.comments);
+        self.r#if.print(printer);
+        printer.space();
+        conditions(printer, &self.conditions);
+        printer.space();
+        self.block.print(printer);
+
+        for elseif in &self.elseifs {
+            printer.space();
+            elseif.print(printer);
+        }
+
+        if let Some(r#else) = &self.r#else {
+            printer.space();
+            r#else.print(printer);
+        }
+    }
+}
+
+impl Print for IfElseIfStatement {
+    fn print(&self, printer: &mut Printer) {
+        self.elseif.print(printer);
+        printer.space();
+
+        if printer.is_transpiling() {
+            transpiler::parenthesized(printer, &self.condition);
+        } else {
+            self.condition.print(printer);
+        }
+        printer.space();
+        self.block.print(printer);
+    }
+}
+
+impl Print for IfElseStatement {
+    fn print(&self, printer: &mut Printer) {
+        self.r#else.print(printer);
+        printer.space();
+
+        match &self.block {
+            IfElseBlockStatement::If(r#if) => r#if.print(printer),
+            IfElseBlockStatement::Block(block) => block.print(printer),
+        }
+    }
+}
+
+impl Print for UsingStatement {
+    fn print(&self, printer: &mut Printer) {
+        if printer.is_transpiling() {
+            transpiler::unsupported(printer, self);
+
+            return;
+        }
+
+        printer.comments(&self.comments);
+        self.r#using.print(printer);
+        printer.space();
+        printer.comma_separated(&self.assignments);
+
+        if let Some(if_clause) = &self.if_clause {
+            printer.space();
+            if_clause.print(printer);
+        }
+
+        printer.space();
+        self.block.print(printer);
+    }
+}
+
+impl Print for UsingAssignmentStatement {
+    fn print(&self, printer: &mut Printer) {
+        self.variable.print(printer);
+        printer.token(" = ");
+        self.expression.print(printer);
+    }
+}
+
+impl Print for UsingIfClauseStatement {
+    fn print(&self, printer: &mut Printer) {
+        self.r#if.print(printer);
+        printer.space();
+        self.condition.print(printer);
+    }
+}
+
+impl Print for ForeachStatement {
+    fn print(&self, printer: &mut Printer) {
+        printer.comments(&self.comments);
+        self.foreach.print(printer);
+        printer.space();
+        self.iterator.print(printer);
+        printer.space();
+        self.block.print(printer);
+
+        if let (Some(r#else), Some(else_block)) = (&self.r#else, &self.else_block) {
+            if printer.is_transpiling() {
+                transpiler::unsupported(printer, self);
+
+                return;
+            }
+
+            printer.space();
+            r#else.print(printer);
+            printer.space();
+            else_block.print(printer);
+        }
+    }
+}
+
+impl Print for ForeachIteratorStatement {
+    fn print(&self, printer: &mut Printer) {
+        let parenthesized = printer.is_transpiling()
+            || matches!(
+                self,
+                Self::ParenthesizedValue { .. } | Self::ParenthesizedKeyAndValue { .. }
+            );
+
+        let r#as = match &self {
+            Self::Value { r#as, .. }
+            | Self::ParenthesizedValue { r#as, .. }
+            | Self::KeyAndValue { r#as, .. }
+            | Self::ParenthesizedKeyAndValue { r#as, .. } => r#as,
+        };
+
+        if parenthesized {
+            printer.token("(");
+        }
+
+        self.expression().print(printer);
+        printer.space();
+        r#as.print(printer);
+        printer.space();
+
+        if let Some(key) = self.key() {
+            key.print(printer);
+            printer.token(" => ");
+        }
+
+        self.value().print(printer);
+
+        if parenthesized {
+            printer.token(")");
+        }
+    }
+}
+
+/// Tuple keys are not supported by PHP, they are reported when transpiling.
+impl Print for ForeachIteratorKeyStatement {
+    fn print(&self, printer: &mut Printer) {
+        match self {
+            Self::Variable(variable) => variable.print(printer),
+            Self::Tuple { elements, .. } => {
+                if printer.is_transpiling() {
+                    transpiler::unsupported(printer, self);
+                }
+
+                printer.token("(");
+                printer.comma_separated(elements);
+                printer.token(")");
+            }
+        }
+    }
+}
+
+impl Print for ForStatement {
+    fn print(&self, printer: &mut Printer) {
+        printer.comments(&self.comments);
+        self.r#for.print(printer);
+        printer.space();
+        self.iterator.print(printer);
+        printer.space();
+        self.block.print(printer);
+    }
+}
+
+impl Print for ForIteratorStatement {
+    fn print(&self, printer: &mut Printer) {
+        let (parenthesized, initializations, conditions, r#loop) = match &self {
+            Self::Standalone {
+                initializations,
+                conditions,
+                r#loop,
+                ..
+            } => (
+                printer.is_transpiling(),
+                initializations,
+                conditions,
+                r#loop,
+            ),
+            Self::Parenthesized {
+                initializations,
+                conditions,
+                r#loop,
+                ..
+            } => (true, initializations, conditions, r#loop),
+        };
+
+        if parenthesized {
+            printer.token("(");
+        }
+
+        printer.comma_separated(initializations);
+        printer.token(";");
+
+        if !conditions.inner.is_empty() {
+            printer.space();
+            printer.comma_separated(conditions);
+        }
+
+        printer.token(";");
+
+        if !r#loop.inner.is_empty() {
+            printer.space();
+            printer.comma_separated(r#loop);
+        }
+
+        if parenthesized {
+            printer.token(")");
+        }
+    }
+}
+
+impl Print for DoWhileStatement {
+    fn print(&self, printer: &mut Printer) {
+        printer.comments(&self.comments);
+        self.r#do.print(printer);
+        printer.space();
+        self.block.print(printer);
+        printer.space();
+        self.r#while.print(printer);
+        printer.space();
+        conditions(printer, &self.conditions);
+        printer.token(";");
+    }
+}
+
+impl Print for WhileStatement {
+    fn print(&self, printer: &mut Printer) {
+        printer.comments(&self.comments);
+        self.r#while.print(printer);
+        printer.space();
+        conditions(printer, &self.conditions);
+        printer.space();
+        self.block.print(printer);
+    }
+}
+
+/// Print the conditions of a statement, which are parenthesized, and joined by `&&` when
+/// transpiling.
+fn conditions(printer: &mut Printer, conditions: &CommaSeparated<Expression>) {
+    if !printer.is_transpiling() {
+        printer.comma_separated(conditions);
+
+        return;
+    }
+
+    match conditions.inner.as_slice() {
+        [condition] => transpiler::parenthesized(printer, condition),
+        conditions => {
+            printer.token("(");
+
+            for (index, condition) in conditions.iter().enumerate() {
+                if index > 0 {
+                    printer.token(" && ");
+                }
+
+                transpiler::parenthesized(printer, condition);
+            }
+
+            printer.token(")");
+        }
+    }
+}
+
+impl Print for BreakStatement {
+    fn print(&self, printer: &mut Printer) {
+        printer.comments(&self.comments);
+        self.r#break.print(printer);
+
+        if let Some(level) = &self.level {
+            printer.space();
+            level.print(printer);
+        }
+
+        printer.token(";");
+    }
+}
+
+impl Print for ContinueStatement {
+    fn print(&self, printer: &mut Printer) {
+        printer.comments(&self.comments);
+        self.r#continue.print(printer);
+
+        if let Some(level) = &self.level {
+            printer.space();
+            level.print(printer);
+        }
+
+        printer.token(";");
+    }
+}
+
+impl Print for TryStatement {
+    fn print(&self, printer: &mut Printer) {
+        printer.comments(&self.comments);
+        self.r#try.print(printer);
+        printer.space();
+        self.block.print(printer);
+
+        for catch in &self.catches {
+            printer.space();
+            catch.print(printer);
+        }
+
+        if let Some(finally) = &self.finally {
+            printer.space();
+            finally.print(printer);
+        }
+    }
+}
+
+impl Print for TryCatchBlockStatement {
+    fn print(&self, printer: &mut Printer) {
+        self.catch.print(printer);
+        printer.token(" (");
+
+        match &self.types {
+            TryCatchTypeStatement::Identifier(identifier) => identifier.print(printer),
+            TryCatchTypeStatement::Union(identifiers) => printer.separated(identifiers, " | "),
+        }
+
+        if let Some(variable) = &self.variable {
+            printer.space();
+            variable.print(printer);
+        }
+
+        printer.token(") ");
+        self.block.print(printer);
+    }
+}
+
+impl Print for TryFinallyBlockStatement {
+    fn print(&self, printer: &mut Printer) {
+        self.finally.print(printer);
+        printer.space();
+        self.block.print(printer);
+    }
+}
@@ -0,0 +1,217 @@
+use ara_reporting::Report;
+
+use crate::parser;
+use crate::printer;
+use crate::printer::Print;
+use crate::printer::PrinterOptions;
+use crate::tree::comment::CommentAttachments;
+use crate::tree::definition::Definition;
+use crate::tree::expression::operator::ArrayOperationExpression;
+use crate::tree::expression::operator::ClassOperationExpression;
+use crate::tree::expression::operator::ObjectOperationExpression;
+use crate::tree::expression::Expression;
+use crate::tree::statement::Statement;
+use crate::tree::Fragment;
+
+/// A snippet of Ara source code with `{placeholder}` holes, used to build nodes without
+/// constructing them manually.
+///
+/// A hole is an identifier wrapped in braces, with no whitespace in between, e.g. `{name}`,
+/// any other brace is left untouched, as are holes that have not been bound.
+#[derive(Debug, Clone)]
+pub struct Quote {
+    snippet: String,
+    bindings: Vec<(String, String)>,
+}
+
+impl Quote {
+    pub fn new<S: Into<String>>(snippet: S) -> Self {
+        Self {
+            snippet: snippet.into(),
+            bindings: vec![],
+        }
+    }
+
+    /// Fill the given hole with the source code of the given node, including its comments from the
+    /// given comments of its tree, or fragment, see `printer::print_node`.
+    pub fn bind<T: Print + ?Sized>(
+        self,
+        name: &str,
+        node: &T,
+        comments: &CommentAttachments,
+    ) -> Self {
+        let code = printer::print_node(node, comments, PrinterOptions::default());
+
+        self.bind_code(name, &code)
+    }
+
+    /// Fill the given hole with the given expression, parenthesized unless it is a primary
+    /// expression, so it keeps its meaning regardless of the surrounding operators, see `bind`.
+    pub fn bind_expression(
+        self,
+        name: &str,
+        expression: &Expression,
+        comments: &CommentAttachments,
+    ) -> Self {
+        let code = printer::print_node(expression, comments, PrinterOptions::default());
+
+        if is_primary(expression) {
+            self.bind_code(name, &code)
+        } else {
+            self.bind_code(name, &format!("({code})"))
+        }
+    }
+
+    /// Fill the given hole with the given source code, as is.
+    pub fn bind_code(mut self, name: &str, code: &str) -> Self {
+        self.bindings.push((name.to_string(), code.to_string()));
+
+        self
+    }
+
+    /// The snippet, with all bound holes filled.
+    pub fn render(&self) -> String {
+        let snippet = self.snippet.as_bytes();
+
+        let mut output = String::new();
+        let mut offset = 0;
+        let mut copied = 0;
+        while offset < snippet.len() {
+            if snippet[offset] == b'{' {
+                let end = offset
+                    + 1
+                    + snippet[offset + 1..]
+                        .iter()
+                        .take_while(|byte| matches!(byte, crate::ident!()))
+                        .count();
+
+                if end > offset + 1 && end < snippet.len() && snippet[end] == b'}' {
+                    let name = &self.snippet[offset + 1..end];
+
+                    if let Some((_, code)) = self.bindings.iter().rev().find(|(n, _)| n == name) {
+                        output.push_str(&self.snippet[copied..offset]);
+                        output.push_str(code);
+
+                        copied = end + 1;
+                    }
+
+                    offset = end + 1;
+
+                    continue;
+                }
+            }
+
+            offset += 1;
+        }
+
+        output.push_str(&self.snippet[copied..]);
+
+        output
+    }
+
+    pub fn definition(&self) -> Result<Fragment<Definition>, Box<Report>> {
+        parser::parse_definition(&self.render())
+    }
+
+    pub fn statement(&self) -> Result<Fragment<Statement>, Box<Report>> {
+        parser::parse_statement(&self.render())
+    }
+
+    pub fn expression(&self) -> Result<Fragment<Expression>, Box<Report>> {
+        parser::parse_expression(&self.render())
+    }
+}
+
+/// Whether the given expression binds tighter than any operator it could be placed next to.
+fn is_primary(expression: &Expression) -> bool {
+    match expression {
+        Expression::Parenthesized(_)
+        | Expression::Literal(_)
+        | Expression::Identifier(_)
+        | Expression::Variable(_)
+        | Expression::Vec(_)
+        | Expression::Dict(_)
+        | Expression::Tuple(_)
+        | Expression::MagicConstant(_)
+        | Expression::FunctionOperation(_) => true,
+        Expression::ArrayOperation(operation) => matches!(
+            operation,
+            ArrayOperationExpression::Access { .. } | ArrayOperationExpression::Push { .. }
+        ),
+        Expression::ObjectOperation(operation) => {
+            !matches!(operation.as_ref(), ObjectOperationExpression::Clone { .. })
+        }
+        Expression::ClassOperation(operation) => !matches!(
+            operation.as_ref(),
+            ClassOperationExpression::Initialization { .. }
+                | ClassOperationExpression::AnonymousInitialization { .. }
+        ),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quote() {
+        let sum = Quote::new("$a + $b").expression().unwrap();
+
+        let quote = Quote::new("function {name}(): int { return {sum} * 2; }")
+            .bind_code("name", "double_sum")
+            .bind_expression("sum", &sum.node, &sum.comments);
+
+        assert_eq!(
+            quote.render(),
+            "function double_sum(): int { return ($a + $b) * 2; }"
+        );
+
+        let definition = quote.definition().unwrap();
+        assert!(matches!(definition.node, Definition::Function(_)));
+
+        let target = Quote::new("$x").expression().unwrap();
+        let statement = Quote::new("{target} = {value};")
+            .bind_expression("target", &target.node, &target.comments)
+            .bind("value", &sum.node, &sum.comments)
+            .statement()
+            .unwrap();
+
+        assert_eq!(
+            printer::print_node(
+                &statement.node,
+                &statement.comments,
+                PrinterOptions::default()
+            ),
+            "$x = $a + $b;"
+        );
+
+        assert_eq!(Quote::new("{ {unbound} }").render(), "{ {unbound} }");
+        assert!(Quote::new("$a $b").expression().is_err());
+    }
+
+    #[test]
+    fn test_quote_comments() {
+        let statement = Quote::new("// the answer\nreturn 42;").statement().unwrap();
+
+        let quote = Quote::new("function answer(): int { {body} }").bind(
+            "body",
+            &statement.node,
+            &statement.comments,
+        );
+
+        let definition = quote.definition().unwrap();
+        let code = printer::print_node(
+            &definition.node,
+            &definition.comments,
+            PrinterOptions::default(),
+        );
+
+        assert!(
+            quote.render().contains("// the answer"),
+            "{}",
+            quote.render()
+        );
+        assert!(code.contains("// the answer"), "{code}");
+    }
+}
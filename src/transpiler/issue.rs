@@ -0,0 +1,111 @@
+use ara_reporting::issue::Issue;
+
+use crate::transpiler::Context;
+use crate::tree::definition::r#type::TypeDefinition;
+use crate::tree::Node;
+
+/// The code of an issue reported by the transpiler, displayed as `T` followed by its number, e.g. `T0001`.
+///
+/// Codes are stable, the number of a code never changes, and new codes are only ever appended.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum TranspilerIssueCode {
+    /// A construct that has no equivalent in PHP was encountered.
+    ///
+    /// Example:
+    ///
+    /// ```ara
+    /// function foo(): void {
+    ///     $range = 1..10;
+    /// }
+    /// ```
+    UnsupportedConstruct = 0,
+
+    /// An async construct was encountered, PHP has no native support for asynchronous code.
+    ///
+    /// Example:
+    ///
+    /// ```ara
+    /// async function foo(): void {
+    ///     await bar();
+    /// }
+    /// ```
+    AsyncNotSupported = 1,
+
+    /// A type that can not be checked at runtime was used in a type operation, such as a
+    /// template, whose type is erased.
+    ///
+    /// Example:
+    ///
+    /// ```ara
+    /// function foo<T>(mixed $value): bool {
+    ///     return $value is T;
+    /// }
+    /// ```
+    UncheckableType = 2,
+}
+
+pub(crate) fn unsupported_construct(context: &Context, node: &dyn Node) -> Issue {
+    let span = node.span();
+
+    Issue::error(
+        TranspilerIssueCode::UnsupportedConstruct,
+        format!("{} can not be transpiled to PHP", node.get_description()),
+    )
+    .with_source(context.source(), span.start, span.end)
+}
+
+pub(crate) fn async_not_supported(context: &Context, node: &dyn Node) -> Issue {
+    let span = node.span();
+
+    Issue::error(
+        TranspilerIssueCode::AsyncNotSupported,
+        format!("{} can not be transpiled to PHP", node.get_description()),
+    )
+    .with_source(context.source(), span.start, span.end)
+    .with_note("PHP has no native support for asynchronous code.")
+}
+
+pub(crate) fn uncheckable_type(context: &Context, type_definition: &TypeDefinition) -> Issue {
+    let span = type_definition.span();
+
+    Issue::error(
+        TranspilerIssueCode::UncheckableType,
+        format!("type `{type_definition}` can not be checked at runtime"),
+    )
+    .with_source(context.source(), span.start, span.end)
+    .with_note("templates, type aliases, and some other types are erased when transpiling to PHP.")
+}
+
+impl TranspilerIssueCode {
+    /// All codes, in order of their numbers.
+    pub const ALL: [Self; 3] = [
+        Self::UnsupportedConstruct,
+        Self::AsyncNotSupported,
+        Self::UncheckableType,
+    ];
+
+    /// The code displayed as the given string, e.g. `T0001`.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|candidate| candidate.to_string() == code)
+    }
+
+    /// The code of the given issue, if it was reported by the transpiler.
+    pub fn of(issue: &Issue) -> Option<Self> {
+        issue.code.as_deref().and_then(Self::from_code)
+    }
+}
+
+impl ::std::fmt::Display for TranspilerIssueCode {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        write!(f, "T{:04}", *self as u8)
+    }
+}
+
+impl From<TranspilerIssueCode> for String {
+    fn from(code: TranspilerIssueCode) -> String {
+        format!("{code}")
+    }
+}
@@ -0,0 +1,507 @@
+use ara_reporting::issue::Issue;
+use ara_reporting::Report;
+use ara_reporting::ReportFooter;
+
+use crate::printer::source_map::SourceMap;
+use crate::printer::Print;
+use crate::printer::Printer;
+use crate::printer::PrinterOptions;
+use crate::tree::definition::r#type::TypeDefinition;
+use crate::tree::definition::template::TemplateGroupDefinition;
+use crate::tree::definition::template::TemplateGroupDefinitionMember;
+use crate::tree::expression::Expression;
+use crate::tree::identifier::Identifier;
+use crate::tree::index::SymbolIndex;
+use crate::tree::index::SymbolKind;
+use crate::tree::resolver::NameKind;
+use crate::tree::resolver::NameResolver;
+use crate::tree::Node;
+use crate::tree::Tree;
+use crate::tree::TreeMap;
+
+pub mod issue;
+
+pub(crate) mod types;
+
+/// Transpile the given tree to PHP source code.
+///
+/// The tree is lowered to code targeting PHP 8.1, as follows:
+///
+/// - templates, generic arguments, type constraints, `permits` clauses, and type aliases are erased.
+/// - types are declared as the closest PHP type, e.g. `vec<int>` as `array`, or omitted if there
+///   is none, e.g. for templates, in which case parameters, and properties, are declared `mixed`.
+/// - `vec`, `dict`, and tuple literals are converted to arrays.
+/// - `is`, `as`, and `into` operations are converted to runtime checks, and casts.
+///
+/// Constructs that have no equivalent in PHP, such as async operations, or ranges, fail the
+/// transpilation with an issue for each of them.
+///
+/// Only the type aliases defined by the tree itself are known, see `transpile_map`.
+pub fn transpile(tree: &Tree) -> Result<String, Box<Report>> {
+    transpile_tree(tree, false).map(|(output, _)| output)
+}
+
+/// Transpile the given tree to PHP source code, along with the mappings of its nodes to the
+/// output, see `transpile`.
+pub fn transpile_with_source_map(tree: &Tree) -> Result<(String, SourceMap), Box<Report>> {
+    transpile_tree(tree, true)
+}
+
+fn transpile_tree(tree: &Tree, source_map: bool) -> Result<(String, SourceMap), Box<Report>> {
+    let mut index = SymbolIndex::default();
+    index.add(tree);
+
+    transpile_with_index(tree, &index, PrinterOptions::default(), source_map).map_err(|issues| {
+        Box::new(Report {
+            issues,
+            footer: Some(ReportFooter::new(format!(
+                "failed to transpile \"{}\" due to the above issue(s)",
+                tree.source
+            ))),
+        })
+    })
+}
+
+/// Transpile each tree of the given map to PHP source code, in the order of the trees.
+///
+/// Unlike `transpile`, type aliases defined by any tree of the map are known to every tree.
+pub fn transpile_map(map: &TreeMap) -> Result<Vec<String>, Box<Report>> {
+    let index = SymbolIndex::new(map);
+
+    let mut outputs = vec![];
+    let mut issues = vec![];
+    for tree in &map.trees {
+        match transpile_with_index(tree, &index, PrinterOptions::default(), false) {
+            Ok((output, _)) => outputs.push(output),
+            Err(mut tree_issues) => issues.append(&mut tree_issues),
+        }
+    }
+
+    if !issues.is_empty() {
+        Err(Box::new(Report {
+            issues,
+            footer: Some(ReportFooter::new(
+                "failed to transpile source map due to the above issue(s)",
+            )),
+        }))
+    } else {
+        Ok(outputs)
+    }
+}
+
+fn transpile_with_index(
+    tree: &Tree,
+    index: &SymbolIndex,
+    options: PrinterOptions,
+    source_map: bool,
+) -> Result<(String, SourceMap), Vec<Issue>> {
+    let context = Context {
+        source: tree.source.clone(),
+        index,
+        resolver: NameResolver::new(tree),
+        templates: vec![],
+        issues: vec![],
+    };
+
+    let mut printer = Printer::transpiling(options, &tree.comments, context);
+    if source_map {
+        printer = printer.with_source_map();
+    }
+
+    printer.token("<?php");
+    printer.new_line();
+    printer.new_line();
+    printer.token("declare(strict_types=1);");
+    printer.new_line();
+    printer.new_line();
+
+    tree.definitions.print(&mut printer);
+
+    let (output, source_map, context) = printer.finish_transpiling();
+    match context {
+        Some(context) if !context.issues.is_empty() => Err(context.issues),
+        _ => Ok((output, source_map.unwrap_or_default())),
+    }
+}
+
+/// The state of transpiling a tree, held by the printer printing it.
+#[derive(Debug)]
+pub(crate) struct Context<'a> {
+    source: String,
+    index: &'a SymbolIndex,
+    resolver: NameResolver,
+    /// The names of the templates in scope, from the outermost definition to the innermost.
+    templates: Vec<String>,
+    issues: Vec<Issue>,
+}
+
+impl Context<'_> {
+    pub(crate) fn source(&self) -> &str {
+        &self.source
+    }
+
+    pub(crate) fn report(&mut self, issue: Issue) {
+        self.issues.push(issue);
+    }
+
+    /// Bring the given templates into scope, returning the scope to restore once they are out of
+    /// scope, see `leave`.
+    pub(crate) fn enter(&mut self, templates: Option<&TemplateGroupDefinition>) -> usize {
+        let scope = self.templates.len();
+
+        for member in templates
+            .iter()
+            .flat_map(|templates| &templates.members.inner)
+        {
+            let name = match member {
+                TemplateGroupDefinitionMember::Type(template) => &template.name,
+                TemplateGroupDefinitionMember::Const(template) => &template.name,
+            };
+
+            self.templates.push(name.value.to_string());
+        }
+
+        scope
+    }
+
+    pub(crate) fn leave(&mut self, scope: usize) {
+        self.templates.truncate(scope);
+    }
+
+    /// Whether the type named by the given identifier is erased, i.e. is a template in scope, or
+    /// a type alias.
+    pub(crate) fn is_erased(&self, identifier: &Identifier) -> bool {
+        let name = identifier.value.to_string();
+        if self.templates.contains(&name) {
+            return true;
+        }
+
+        let name = self.resolver.resolve(identifier, NameKind::Class);
+
+        let is_alias = self
+            .index
+            .find(&name)
+            .any(|symbol| symbol.kind == SymbolKind::TypeAlias);
+
+        is_alias
+    }
+
+    /// The PHP type declaring the given type, see `types::declaration`.
+    pub(crate) fn declaration(&self, type_definition: &TypeDefinition) -> Option<String> {
+        types::declaration(self, type_definition)
+    }
+}
+
+/// Report the given node as a construct that can not be transpiled to PHP.
+pub(crate) fn unsupported(printer: &mut Printer, node: &dyn Node) {
+    if let Some(context) = printer.transpiler() {
+        let issue = issue::unsupported_construct(context, node);
+
+        context.report(issue);
+    }
+}
+
+/// Report the given node as an asynchronous construct, which can not be transpiled to PHP.
+pub(crate) fn unsupported_async(printer: &mut Printer, node: &dyn Node) {
+    if let Some(context) = printer.transpiler() {
+        let issue = issue::async_not_supported(context, node);
+
+        context.report(issue);
+    }
+}
+
+/// Print the given expression within parentheses, unless it is already parenthesized.
+pub(crate) fn parenthesized(printer: &mut Printer, expression: &Expression) {
+    if let Expression::Parenthesized(_) = expression {
+        expression.print(printer);
+    } else {
+        printer.token("(");
+        expression.print(printer);
+        printer.token(")");
+    }
+}
+
+/// Print `left is right` as a PHP expression checking the type of `left`.
+pub(crate) fn is(printer: &mut Printer, left: &Expression, right: &TypeDefinition) {
+    let subject = printer.capture(left);
+    let Some(context) = printer.transpiler() else {
+        return;
+    };
+
+    let lowered = if let Expression::Variable(_) = left {
+        types::check(context, right, &subject).map(|check| format!("({check})"))
+    } else {
+        types::check(context, right, "$value")
+            .map(|check| format!("(static fn ($value) => {check})({subject})"))
+    };
+
+    print(printer, lowered, left, right);
+}
+
+/// Print `left as right` as a PHP expression evaluating to `left` if it is of the given type,
+/// and throwing a `TypeError` otherwise.
+pub(crate) fn r#as(printer: &mut Printer, left: &Expression, right: &TypeDefinition) {
+    let subject = printer.capture(left);
+    let Some(context) = printer.transpiler() else {
+        return;
+    };
+
+    let expected = right.to_string().replace('\\', "\\\\").replace('\'', "\\'");
+    let assert = |value: &str| {
+        types::check(context, right, value).map(|check| {
+            format!(
+                "{check} ? {value} : throw new \\TypeError(\\sprintf('Expected a value of type %s, got %s.', '{expected}', \\get_debug_type({value})))"
+            )
+        })
+    };
+
+    let lowered = if let Expression::Variable(_) = left {
+        assert(&subject).map(|assert| format!("({assert})"))
+    } else {
+        assert("$value").map(|assert| format!("(static fn ($value) => {assert})({subject})"))
+    };
+
+    print(printer, lowered, left, right);
+}
+
+/// Print `left into right` as a PHP cast of `left` to the given type.
+pub(crate) fn into(printer: &mut Printer, left: &Expression, right: &TypeDefinition) {
+    let subject = printer.capture(left);
+
+    let lowered = match right {
+        TypeDefinition::Vec(..) => Some(format!("\\array_values((array) ({subject}))")),
+        _ => types::cast(right).map(|cast| format!("({cast} ({subject}))")),
+    };
+
+    print(printer, lowered, left, right);
+}
+
+/// Print the given lowered operation, reporting the given type as uncheckable if the operation
+/// could not be lowered.
+fn print(
+    printer: &mut Printer,
+    lowered: Option<String>,
+    left: &Expression,
+    right: &TypeDefinition,
+) {
+    match lowered {
+        Some(lowered) => printer.mapped(left.span().union(&right.span()), &lowered),
+        None => {
+            if let Some(context) = printer.transpiler() {
+                let issue = issue::uncheckable_type(context, right);
+
+                context.report(issue);
+            }
+        }
+    }
+}
+
+/// The given string literal, as lexed, as a PHP string literal.
+///
+/// The value of a double-quoted literal is held unescaped, it is escaped again so that PHP does
+/// not interpolate the variables within it.
+pub(crate) fn string(value: &[u8]) -> String {
+    let value = match value {
+        [b'b' | b'B', rest @ ..] => rest,
+        _ => value,
+    };
+
+    let inner = match value {
+        [b'"', inner @ .., b'"'] => inner,
+        _ => return String::from_utf8_lossy(value).into_owned(),
+    };
+
+    let mut string = String::from("\"");
+    for chunk in inner.utf8_chunks() {
+        for character in chunk.valid().chars() {
+            match character {
+                '\\' => string.push_str("\\\\"),
+                '"' => string.push_str("\\\""),
+                '$' => string.push_str("\\$"),
+                '\n' => string.push_str("\\n"),
+                '\r' => string.push_str("\\r"),
+                '\t' => string.push_str("\\t"),
+                '\x0b' => string.push_str("\\v"),
+                '\x1b' => string.push_str("\\e"),
+                '\x0c' => string.push_str("\\f"),
+                character if character.is_ascii_control() => {
+                    string.push_str(&format!("\\x{:02x}", character as u8))
+                }
+                character => string.push(character),
+            }
+        }
+
+        for byte in chunk.invalid() {
+            string.push_str(&format!("\\x{byte:02x}"));
+        }
+    }
+
+    string.push('"');
+
+    string
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ara_source::source::Source;
+    use ara_source::source::SourceKind;
+
+    use crate::parser;
+    use crate::transpiler::issue::TranspilerIssueCode;
+    use crate::tree::span::Span;
+
+    fn parse(code: &str) -> Tree {
+        parser::parse(&Source::inline(SourceKind::Script, code)).unwrap()
+    }
+
+    #[test]
+    fn test_transpile() {
+        let tree = parse(
+            r#"
+type Number = int|float;
+
+final class Box<T> {
+    public function __construct(
+        private readonly T $value,
+    ) {}
+
+    public function get(): T {
+        return $this->value;
+    }
+}
+
+function sum(vec<int> $values, ?Number $initial = null): int {
+    $total = $initial as int;
+    foreach $values as $value {
+        $total += $value;
+    }
+
+    $percentage = $total as int<0, 100>;
+    $pairs = dict["total" => (1, "$total")];
+    if $total > 10, $values is vec<int> {
+        return $total into u8;
+    }
+
+    $total
+}
+"#,
+        );
+
+        assert_eq!(
+            transpile(&tree).unwrap(),
+            r#"<?php
+
+declare(strict_types=1);
+
+final class Box {
+    public function __construct(private readonly mixed $value) {}
+
+    public function get() {
+        return $this->value;
+    }
+}
+
+function sum(array $values, mixed $initial = null): int {
+    $total = (\is_int($initial) ? $initial : throw new \TypeError(\sprintf('Expected a value of type %s, got %s.', 'int', \get_debug_type($initial))));
+    foreach ($values as $value) {
+        $total += $value;
+    }
+    $percentage = ((\is_int($total) && $total >= 0 && $total <= 100) ? $total : throw new \TypeError(\sprintf('Expected a value of type %s, got %s.', 'int<0, 100>', \get_debug_type($total))));
+    $pairs = ["total" => [1, "\$total"]];
+    if (($total > 10) && (((\is_array($values) && \array_is_list($values))))) {
+        return ((int) ($total));
+    }
+    return $total;
+}
+"#
+        );
+    }
+
+    #[test]
+    fn test_transpile_unsupported() {
+        let tree = parse(
+            r#"
+async function foo<T>(T $value): vec<int> {
+    $bar = await bar();
+    $check = $value is T;
+
+    foreach $bar as ($a, $b) => $c {}
+
+    return vec[1..3];
+}
+"#,
+        );
+
+        let report = transpile(&tree).unwrap_err();
+        let codes = report
+            .issues
+            .iter()
+            .map(|issue| TranspilerIssueCode::of(issue).unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            codes,
+            vec![
+                TranspilerIssueCode::AsyncNotSupported,
+                TranspilerIssueCode::AsyncNotSupported,
+                TranspilerIssueCode::UncheckableType,
+                TranspilerIssueCode::UnsupportedConstruct,
+                TranspilerIssueCode::UnsupportedConstruct,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_transpile_with_source_map() {
+        let code = "function foo(mixed $value): bool {\n    return $value is int;\n}\n";
+        let tree = parse(code);
+
+        let (output, source_map) = transpile_with_source_map(&tree).unwrap();
+
+        assert_eq!(output, transpile(&tree).unwrap());
+        assert_eq!(
+            source_map.lines(code, &output),
+            vec![None, None, None, None, Some(1), Some(2), None, None]
+        );
+
+        let check = output.find("(\\is_int").unwrap();
+        assert_eq!(
+            source_map.source(check),
+            Some(Span::new(
+                code.find("$value is").unwrap(),
+                code.find(";").unwrap()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_transpile_map() {
+        let map = parser::parse_map(&ara_source::SourceMap::new(vec![
+            Source::inline(SourceKind::Definition, "type Id = int;"),
+            Source::inline(
+                SourceKind::Script,
+                "function id(Id $id): Id { return $id; }",
+            ),
+        ]))
+        .unwrap();
+
+        let outputs = transpile_map(&map).unwrap();
+
+        assert_eq!(
+            outputs[1],
+            "<?php\n\ndeclare(strict_types=1);\n\nfunction id(mixed $id) {\n    return $id;\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_issue_codes() {
+        for (number, code) in TranspilerIssueCode::ALL.into_iter().enumerate() {
+            assert_eq!(code as usize, number);
+            assert_eq!(
+                TranspilerIssueCode::from_code(&code.to_string()),
+                Some(code)
+            );
+        }
+    }
+}
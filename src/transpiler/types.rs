@@ -0,0 +1,291 @@
+use crate::transpiler::Context;
+use crate::tree::definition::r#type::IntegerRangeBoundTypeDefinition;
+use crate::tree::definition::r#type::SignedIntegerTypeDefinition;
+use crate::tree::definition::r#type::TypeDefinition;
+use crate::tree::definition::r#type::UnsignedIntegerTypeDefinition;
+use crate::tree::expression::literal::Literal;
+
+/// The PHP type declaring the given type, `None` if PHP can not declare it, e.g. a template.
+///
+/// The declared type may be wider than the given type, e.g. `vec<int>` is declared as `array`.
+pub(crate) fn declaration(context: &Context, type_definition: &TypeDefinition) -> Option<String> {
+    let members = members(context, type_definition)?;
+    if members.iter().any(|member| member == "mixed") {
+        return Some("mixed".to_string());
+    }
+
+    match members.as_slice() {
+        // a standalone `null` type is not supported before PHP 8.2.
+        [] => None,
+        [member] if member == "null" => None,
+        [member] => Some(member.clone()),
+        [first, second] if first == "null" && !second.contains('&') => Some(format!("?{second}")),
+        [first, second] if second == "null" && !first.contains('&') => Some(format!("?{first}")),
+        _ => Some(
+            members
+                .iter()
+                .map(|member| {
+                    if member.contains('&') {
+                        format!("({member})")
+                    } else {
+                        member.clone()
+                    }
+                })
+                .collect::<Vec<String>>()
+                .join("|"),
+        ),
+    }
+}
+
+/// The members of the union declaring the given type, without duplicates.
+fn members(context: &Context, type_definition: &TypeDefinition) -> Option<Vec<String>> {
+    let member = match type_definition {
+        TypeDefinition::Identifier(identifier) => {
+            if context.is_erased(&identifier.name) {
+                return None;
+            }
+
+            identifier.name.value.to_string()
+        }
+        TypeDefinition::Nullable(_, inner) => {
+            let mut union = vec!["null".to_string()];
+            extend(&mut union, members(context, inner)?);
+
+            return Some(union);
+        }
+        TypeDefinition::Union(inner) => {
+            let mut union = vec![];
+            for type_definition in inner {
+                extend(&mut union, members(context, type_definition)?);
+            }
+
+            return Some(union);
+        }
+        TypeDefinition::Intersection(inner) => {
+            let mut intersection = vec![];
+            for type_definition in inner {
+                match members(context, type_definition)?.as_slice() {
+                    [member] if is_class(member) => intersection.push(member.clone()),
+                    _ => return None,
+                }
+            }
+
+            intersection.join("&")
+        }
+        TypeDefinition::Parenthesized {
+            type_definition, ..
+        } => return members(context, type_definition),
+        TypeDefinition::Void(_) => "void".to_string(),
+        TypeDefinition::Never(_) => "never".to_string(),
+        TypeDefinition::Boolean(_) => "bool".to_string(),
+        TypeDefinition::String(_) => "string".to_string(),
+        TypeDefinition::SignedInteger(_)
+        | TypeDefinition::UnsignedInteger(_)
+        | TypeDefinition::IntegerRange { .. } => "int".to_string(),
+        TypeDefinition::FloatingPoint(_) => "float".to_string(),
+        TypeDefinition::Dict(..)
+        | TypeDefinition::Vec(..)
+        | TypeDefinition::Unit { .. }
+        | TypeDefinition::Tuple { .. }
+        | TypeDefinition::Shape { .. } => "array".to_string(),
+        TypeDefinition::Object(_) => "object".to_string(),
+        TypeDefinition::Self_(keyword)
+        | TypeDefinition::Static(keyword)
+        | TypeDefinition::Parent(keyword) => keyword.value.to_string(),
+        TypeDefinition::Mixed(_) | TypeDefinition::NonNull(_) => "mixed".to_string(),
+        TypeDefinition::Iterable(..) => "iterable".to_string(),
+        TypeDefinition::Class(..) | TypeDefinition::Interface(..) => "string".to_string(),
+        TypeDefinition::Literal(literal) => match literal {
+            Literal::String(_) => "string".to_string(),
+            Literal::Integer(_) => "int".to_string(),
+            Literal::Float(_) => "float".to_string(),
+            Literal::Null(_) => "null".to_string(),
+            Literal::True(_) | Literal::False(_) => "bool".to_string(),
+        },
+        TypeDefinition::Resource(_)
+        | TypeDefinition::Variadic(..)
+        | TypeDefinition::KeyOf(..)
+        | TypeDefinition::IndexedAccess { .. } => return None,
+    };
+
+    Some(vec![member])
+}
+
+fn extend(members: &mut Vec<String>, other: Vec<String>) {
+    for member in other {
+        if !members.contains(&member) {
+            members.push(member);
+        }
+    }
+}
+
+/// Whether the given declared type is a class name, which may be part of an intersection.
+fn is_class(member: &str) -> bool {
+    !member.contains('&')
+        && !matches!(
+            member.to_ascii_lowercase().as_str(),
+            "void"
+                | "never"
+                | "bool"
+                | "string"
+                | "int"
+                | "float"
+                | "array"
+                | "object"
+                | "mixed"
+                | "iterable"
+                | "null"
+        )
+}
+
+/// A PHP expression checking whether the given subject, a PHP expression evaluated once for
+/// each of its occurrences, is of the given type, `None` if the type can not be checked.
+pub(crate) fn check(
+    context: &Context,
+    type_definition: &TypeDefinition,
+    subject: &str,
+) -> Option<String> {
+    let check = match type_definition {
+        TypeDefinition::Identifier(identifier) => {
+            if context.is_erased(&identifier.name) {
+                return None;
+            }
+
+            format!("{subject} instanceof {}", identifier.name.value)
+        }
+        TypeDefinition::Nullable(_, inner) => {
+            format!(
+                "({subject} === null || {})",
+                check(context, inner, subject)?
+            )
+        }
+        TypeDefinition::Union(inner) => {
+            let checks = inner
+                .iter()
+                .map(|type_definition| check(context, type_definition, subject))
+                .collect::<Option<Vec<String>>>()?;
+
+            format!("({})", checks.join(" || "))
+        }
+        TypeDefinition::Intersection(inner) => {
+            let checks = inner
+                .iter()
+                .map(|type_definition| check(context, type_definition, subject))
+                .collect::<Option<Vec<String>>>()?;
+
+            format!("({})", checks.join(" && "))
+        }
+        TypeDefinition::Parenthesized {
+            type_definition, ..
+        } => return check(context, type_definition, subject),
+        TypeDefinition::Never(_) => "false".to_string(),
+        TypeDefinition::Boolean(_) => format!("\\is_bool({subject})"),
+        TypeDefinition::String(_) => format!("\\is_string({subject})"),
+        TypeDefinition::SignedInteger(signed) => {
+            let bits = match signed {
+                SignedIntegerTypeDefinition::Default(_)
+                | SignedIntegerTypeDefinition::I128(_)
+                | SignedIntegerTypeDefinition::I64(_) => None,
+                SignedIntegerTypeDefinition::I32(_) => Some(31),
+                SignedIntegerTypeDefinition::I16(_) => Some(15),
+                SignedIntegerTypeDefinition::I8(_) => Some(7),
+            };
+
+            match bits {
+                Some(bits) => format!(
+                    "(\\is_int({subject}) && {subject} >= -{} && {subject} <= {})",
+                    1_i64 << bits,
+                    (1_i64 << bits) - 1
+                ),
+                None => format!("\\is_int({subject})"),
+            }
+        }
+        TypeDefinition::UnsignedInteger(unsigned) => {
+            let bits = match unsigned {
+                UnsignedIntegerTypeDefinition::Default(_) => None,
+                UnsignedIntegerTypeDefinition::U32(_) => Some(32),
+                UnsignedIntegerTypeDefinition::U16(_) => Some(16),
+                UnsignedIntegerTypeDefinition::U8(_) => Some(8),
+            };
+
+            match bits {
+                Some(bits) => format!(
+                    "(\\is_int({subject}) && {subject} >= 0 && {subject} <= {})",
+                    (1_i64 << bits) - 1
+                ),
+                None => format!("(\\is_int({subject}) && {subject} >= 0)"),
+            }
+        }
+        TypeDefinition::IntegerRange { min, max, .. } => {
+            let mut checks = vec![format!("\\is_int({subject})")];
+            if let IntegerRangeBoundTypeDefinition::Integer { .. } = min {
+                checks.push(format!("{subject} >= {min}"));
+            }
+            if let IntegerRangeBoundTypeDefinition::Integer { .. } = max {
+                checks.push(format!("{subject} <= {max}"));
+            }
+
+            match checks.as_slice() {
+                [check] => check.clone(),
+                _ => format!("({})", checks.join(" && ")),
+            }
+        }
+        TypeDefinition::FloatingPoint(_) => format!("\\is_float({subject})"),
+        TypeDefinition::Dict(..) | TypeDefinition::Shape { .. } => {
+            format!("\\is_array({subject})")
+        }
+        TypeDefinition::Unit { .. } => format!("{subject} === []"),
+        TypeDefinition::Vec(..) | TypeDefinition::Tuple { .. } => {
+            format!("(\\is_array({subject}) && \\array_is_list({subject}))")
+        }
+        TypeDefinition::Object(_) => format!("\\is_object({subject})"),
+        TypeDefinition::Self_(keyword)
+        | TypeDefinition::Static(keyword)
+        | TypeDefinition::Parent(keyword) => format!("{subject} instanceof {keyword}"),
+        TypeDefinition::Mixed(_) => "true".to_string(),
+        TypeDefinition::NonNull(_) => format!("{subject} !== null"),
+        TypeDefinition::Resource(_) => format!("\\is_resource({subject})"),
+        TypeDefinition::Iterable(..) => format!("\\is_iterable({subject})"),
+        TypeDefinition::Class(..) => {
+            format!("(\\is_string({subject}) && \\class_exists({subject}))")
+        }
+        TypeDefinition::Interface(..) => {
+            format!("(\\is_string({subject}) && \\interface_exists({subject}))")
+        }
+        TypeDefinition::Literal(literal) => {
+            let value = match literal {
+                Literal::String(literal) => super::string(&literal.value),
+                Literal::Integer(literal) => literal.value.to_string(),
+                Literal::Float(literal) => literal.value.to_string(),
+                Literal::Null(_) => "null".to_string(),
+                Literal::True(_) => "true".to_string(),
+                Literal::False(_) => "false".to_string(),
+            };
+
+            format!("{subject} === {value}")
+        }
+        TypeDefinition::Void(_)
+        | TypeDefinition::Variadic(..)
+        | TypeDefinition::KeyOf(..)
+        | TypeDefinition::IndexedAccess { .. } => return None,
+    };
+
+    Some(check)
+}
+
+/// The PHP cast converting a value into the given type, `None` if there is no such cast.
+pub(crate) fn cast(type_definition: &TypeDefinition) -> Option<&'static str> {
+    match type_definition {
+        TypeDefinition::Parenthesized {
+            type_definition, ..
+        } => cast(type_definition),
+        TypeDefinition::Boolean(_) => Some("(bool)"),
+        TypeDefinition::String(_) => Some("(string)"),
+        TypeDefinition::SignedInteger(_)
+        | TypeDefinition::UnsignedInteger(_)
+        | TypeDefinition::IntegerRange { .. } => Some("(int)"),
+        TypeDefinition::FloatingPoint(_) => Some("(float)"),
+        TypeDefinition::Dict(..) => Some("(array)"),
+        _ => None,
+    }
+}
@@ -1,6 +1,11 @@
 use std::fmt::Debug;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
 
+use crate::traverser::visitor::Context;
+use crate::traverser::visitor::ContextVisitor;
 use crate::traverser::visitor::NodeVisitor;
+use crate::tree::Node;
 use crate::tree::Tree;
 use crate::tree::TreeMap;
 
@@ -8,11 +13,25 @@ pub mod visitor;
 
 pub struct TreeTraverser<E: Debug> {
     visitors: Vec<Box<dyn NodeVisitor<E>>>,
+    context_visitors: Vec<Box<dyn ContextVisitor<E>>>,
 }
 
 impl<E: Debug> TreeTraverser<E> {
     pub fn new(visitors: Vec<Box<dyn NodeVisitor<E>>>) -> Self {
-        Self { visitors }
+        Self {
+            visitors,
+            context_visitors: vec![],
+        }
+    }
+
+    /// Add visitors that are handed the context of every node.
+    ///
+    /// Unlike node visitors, which walk the tree themselves, context visitors share a single walk
+    /// of the tree, a visitor that fails is not visited with any further node of the same tree.
+    pub fn with_context_visitors(mut self, visitors: Vec<Box<dyn ContextVisitor<E>>>) -> Self {
+        self.context_visitors.extend(visitors);
+
+        self
     }
 
     pub fn traverse(&mut self, map: &TreeMap) -> Result<(), Vec<E>> {
@@ -31,6 +50,75 @@ impl<E: Debug> TreeTraverser<E> {
         }
     }
 
+    /// Traverse the trees of the given map in parallel.
+    ///
+    /// Trees are taken in turn by a pool of threads, one per available core, each thread
+    /// traverses the trees it takes with its own traverser, created by the given function,
+    /// visitors that collect results across trees must therefore share them, e.g. through a
+    /// mutex.
+    ///
+    /// Errors are returned in the same order as `traverse` would return them, regardless of
+    /// the order in which the trees are traversed.
+    pub fn traverse_parallel<F>(map: &TreeMap, traverser: F) -> Result<(), Vec<E>>
+    where
+        E: Send,
+        F: Fn() -> TreeTraverser<E> + Sync,
+    {
+        let next = AtomicUsize::new(0);
+        let work = || {
+            let mut traverser = traverser();
+            let mut errors = vec![];
+
+            loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(tree) = map.trees.get(index) else {
+                    break;
+                };
+
+                if let Err(error) = traverser.traverse_tree(tree) {
+                    errors.push((index, error));
+                }
+            }
+
+            errors
+        };
+
+        let threads = std::thread::available_parallelism()
+            .map_or(1, usize::from)
+            .min(map.trees.len());
+
+        // the current thread takes part in the traversal, so that the trees are traversed even
+        // if no thread could be spawned, e.g. on targets without threads.
+        let mut errors = std::thread::scope(|scope| {
+            let handles = (1..threads)
+                .filter_map(|_| std::thread::Builder::new().spawn_scoped(scope, work).ok())
+                .collect::<Vec<_>>();
+
+            let mut errors = work();
+            for handle in handles {
+                match handle.join() {
+                    Ok(mut error) => errors.append(&mut error),
+                    Err(panic) => std::panic::resume_unwind(panic),
+                }
+            }
+
+            errors
+        });
+
+        errors.sort_by_key(|(index, _)| *index);
+
+        let errors = errors
+            .into_iter()
+            .flat_map(|(_, error)| error)
+            .collect::<Vec<E>>();
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
     pub fn traverse_tree(&mut self, tree: &Tree) -> Result<(), Vec<E>> {
         let mut errors = Vec::new();
 
@@ -40,10 +128,136 @@ impl<E: Debug> TreeTraverser<E> {
             }
         }
 
+        if !self.context_visitors.is_empty() {
+            self.walk(tree, &mut errors);
+        }
+
         if errors.is_empty() {
             Ok(())
         } else {
             Err(errors)
         }
     }
+
+    fn walk(&mut self, tree: &Tree, errors: &mut Vec<E>) {
+        let mut failed = vec![false; self.context_visitors.len()];
+        let mut ancestors: Vec<&dyn Node> = vec![];
+        let mut stack: Vec<(&dyn Node, usize)> = vec![(&tree.definitions, 0)];
+
+        while let Some((node, depth)) = stack.pop() {
+            ancestors.truncate(depth);
+
+            let context = Context {
+                source: &tree.source,
+                ancestors: &ancestors,
+            };
+
+            for (visitor, failed) in self.context_visitors.iter_mut().zip(failed.iter_mut()) {
+                if *failed {
+                    continue;
+                }
+
+                if let Err(error) = visitor.visit(&context, node) {
+                    errors.push(error);
+                    *failed = true;
+                }
+            }
+
+            ancestors.push(node);
+
+            stack.extend(
+                node.children()
+                    .into_iter()
+                    .rev()
+                    .map(|child| (child, depth + 1)),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ara_source::source::Source;
+    use ara_source::source::SourceKind;
+
+    use crate::parser;
+    use crate::tree::definition::class::ClassDefinition;
+    use crate::tree::definition::function::FunctionDefinition;
+    use crate::tree::definition::function::MethodDefinition;
+    use crate::tree::downcast;
+
+    struct MethodInFinalClass;
+
+    impl ContextVisitor<String> for MethodInFinalClass {
+        fn visit(&mut self, context: &Context<'_>, node: &dyn Node) -> Result<(), String> {
+            if let Some(method) = downcast::<MethodDefinition>(node) {
+                let class = context.closest::<ClassDefinition>().unwrap();
+
+                if class.modifiers.to_string().contains("final") {
+                    return Err(format!(
+                        "{}: {}\\{}::{}",
+                        context.source,
+                        context.namespace().unwrap().value,
+                        class.name.value,
+                        method.name.value
+                    ));
+                }
+            }
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_context_visitor() {
+        let code = "namespace App;\n\nfinal class Foo { public function bar(): void {} }\nclass Baz { public function qux(): void {} }";
+        let source = Source::new(SourceKind::Script, "foo.ara", code);
+        let tree = parser::parse(&source).unwrap();
+
+        let mut traverser =
+            TreeTraverser::new(vec![]).with_context_visitors(vec![Box::new(MethodInFinalClass)]);
+
+        assert_eq!(
+            traverser.traverse_tree(&tree),
+            Err(vec!["foo.ara: App\\Foo::bar".to_string()])
+        );
+    }
+
+    struct FirstFunction;
+
+    impl NodeVisitor<String> for FirstFunction {
+        fn visit(
+            &mut self,
+            source: &str,
+            node: &dyn Node,
+            _parent: Option<&dyn Node>,
+        ) -> Result<(), String> {
+            match downcast::<FunctionDefinition>(node) {
+                Some(function) => Err(format!("{}: {}", source, function.name.value)),
+                None => Ok(()),
+            }
+        }
+    }
+
+    #[test]
+    fn test_traverse_parallel() {
+        let trees = (0..32)
+            .map(|i| {
+                let code = format!("function foo{i}(): void {{}}\nfunction bar{i}(): void {{}}");
+                let source = Source::new(SourceKind::Script, format!("{i}.ara"), code);
+
+                parser::parse(&source).unwrap()
+            })
+            .collect();
+        let map = TreeMap::new(trees);
+
+        let expected = TreeTraverser::new(vec![Box::new(FirstFunction)]).traverse(&map);
+        let errors = TreeTraverser::traverse_parallel(&map, || {
+            TreeTraverser::new(vec![Box::new(FirstFunction)])
+        });
+
+        assert_eq!(errors, expected);
+        assert_eq!(expected.unwrap_err().len(), 32);
+    }
 }
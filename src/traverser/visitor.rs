@@ -1,5 +1,146 @@
 use std::fmt::Debug;
 
+use crate::tree::definition::attribute::AttributeDefinition;
+use crate::tree::definition::attribute::AttributeGroupDefinition;
+use crate::tree::definition::class::ClassDefinition;
+use crate::tree::definition::class::ClassDefinitionBody;
+use crate::tree::definition::class::ClassDefinitionExtends;
+use crate::tree::definition::class::ClassDefinitionImplements;
+use crate::tree::definition::class::ClassDefinitionMember;
+use crate::tree::definition::class::ClassDefinitionPermits;
+use crate::tree::definition::constant::ClassishConstantDefinition;
+use crate::tree::definition::constant::ConstantDefinition;
+use crate::tree::definition::function::FunctionBodyDefinition;
+use crate::tree::definition::function::FunctionDefinition;
+use crate::tree::definition::function::FunctionLikeParameterDefaultValueDefinition;
+use crate::tree::definition::function::FunctionLikeParameterDefinition;
+use crate::tree::definition::function::FunctionLikeParameterListDefinition;
+use crate::tree::definition::function::FunctionLikeReturnTypeDefinition;
+use crate::tree::definition::function::MethodBodyDefinition;
+use crate::tree::definition::function::MethodDefinition;
+use crate::tree::definition::function::MethodParameterDefinition;
+use crate::tree::definition::function::MethodParameterListDefinition;
+use crate::tree::definition::function::MethodTypeConstraintDefinition;
+use crate::tree::definition::function::MethodTypeConstraintGroupDefinition;
+use crate::tree::definition::interface::InterfaceDefinition;
+use crate::tree::definition::interface::InterfaceDefinitionBody;
+use crate::tree::definition::interface::InterfaceDefinitionExtends;
+use crate::tree::definition::interface::InterfaceDefinitionMember;
+use crate::tree::definition::interface::InterfaceDefinitionPermits;
+use crate::tree::definition::modifier::ModifierDefinition;
+use crate::tree::definition::modifier::ModifierGroupDefinition;
+use crate::tree::definition::namespace::NamespaceDefinition;
+use crate::tree::definition::pragma::PragmaDefinition;
+use crate::tree::definition::property::PropertyDefinition;
+use crate::tree::definition::property::PropertyEntryDefinition;
+use crate::tree::definition::r#enum::BackedEnumBodyDefinition;
+use crate::tree::definition::r#enum::BackedEnumCaseDefinition;
+use crate::tree::definition::r#enum::BackedEnumDefinition;
+use crate::tree::definition::r#enum::BackedEnumMemberDefinition;
+use crate::tree::definition::r#enum::BackedEnumTypeDefinition;
+use crate::tree::definition::r#enum::EnumDefinition;
+use crate::tree::definition::r#enum::EnumImplementsDefinition;
+use crate::tree::definition::r#enum::UnitEnumBodyDefinition;
+use crate::tree::definition::r#enum::UnitEnumCaseDefinition;
+use crate::tree::definition::r#enum::UnitEnumDefinition;
+use crate::tree::definition::r#enum::UnitEnumMemberDefinition;
+use crate::tree::definition::r#type::FloatingPointTypeDefinition;
+use crate::tree::definition::r#type::IntegerRangeBoundTypeDefinition;
+use crate::tree::definition::r#type::ShapeFieldTypeDefinition;
+use crate::tree::definition::r#type::SignedIntegerTypeDefinition;
+use crate::tree::definition::r#type::TupleElementLabelDefinition;
+use crate::tree::definition::r#type::TupleElementTypeDefinition;
+use crate::tree::definition::r#type::TypeAliasDefinition;
+use crate::tree::definition::r#type::TypeDefinition;
+use crate::tree::definition::r#type::UnsignedIntegerTypeDefinition;
+use crate::tree::definition::r#use::UseDefinition;
+use crate::tree::definition::r#use::UseDefinitionGroupMember;
+use crate::tree::definition::r#use::UseDefinitionSymbolAlias;
+use crate::tree::definition::template::ConstTemplateDefinition;
+use crate::tree::definition::template::TemplateBoundsDefinition;
+use crate::tree::definition::template::TemplateDefinition;
+use crate::tree::definition::template::TemplateGroupDefinition;
+use crate::tree::definition::template::TemplateGroupDefinitionMember;
+use crate::tree::definition::template::TypeTemplateGroupDefinition;
+use crate::tree::definition::Definition;
+use crate::tree::definition::DefinitionTree;
+use crate::tree::downcast;
+use crate::tree::expression::argument::ArgumentExpression;
+use crate::tree::expression::argument::ArgumentListExpression;
+use crate::tree::expression::argument::ArgumentPlaceholderExpression;
+use crate::tree::expression::array::DictElementExpression;
+use crate::tree::expression::array::DictExpression;
+use crate::tree::expression::array::TupleExpression;
+use crate::tree::expression::array::VecElementExpression;
+use crate::tree::expression::array::VecExpression;
+use crate::tree::expression::class::AnonymousClassExpression;
+use crate::tree::expression::construct::ExitConstructExpression;
+use crate::tree::expression::control_flow::MatchArmConditionExpression;
+use crate::tree::expression::control_flow::MatchArmExpression;
+use crate::tree::expression::control_flow::MatchBodyExpression;
+use crate::tree::expression::control_flow::MatchExpression;
+use crate::tree::expression::function::AnonymousFunctionExpression;
+use crate::tree::expression::function::AnonymousFunctionUseClauseExpression;
+use crate::tree::expression::function::AnonymousFunctionUseClauseVariableExpression;
+use crate::tree::expression::function::ArrowFunctionExpression;
+use crate::tree::expression::generic::GenericGroupExpression;
+use crate::tree::expression::literal::Literal;
+use crate::tree::expression::literal::LiteralFalse;
+use crate::tree::expression::literal::LiteralFloat;
+use crate::tree::expression::literal::LiteralInteger;
+use crate::tree::expression::literal::LiteralNull;
+use crate::tree::expression::literal::LiteralString;
+use crate::tree::expression::literal::LiteralTrue;
+use crate::tree::expression::magic_constant::MagicConstant;
+use crate::tree::expression::operator::ArithmeticOperationExpression;
+use crate::tree::expression::operator::ArrayOperationExpression;
+use crate::tree::expression::operator::AssignmentOperationExpression;
+use crate::tree::expression::operator::AsyncOperationExpression;
+use crate::tree::expression::operator::BitwiseOperationExpression;
+use crate::tree::expression::operator::ClassOperationExpression;
+use crate::tree::expression::operator::ClassOperationInitializationClassExpression;
+use crate::tree::expression::operator::CoalesceOperationExpression;
+use crate::tree::expression::operator::ComparisonOperationExpression;
+use crate::tree::expression::operator::ExceptionOperationExpression;
+use crate::tree::expression::operator::FunctionOperationExpression;
+use crate::tree::expression::operator::FunctionalOperationExpression;
+use crate::tree::expression::operator::GeneratorOperationExpression;
+use crate::tree::expression::operator::LogicalOperationExpression;
+use crate::tree::expression::operator::ObjectOperationExpression;
+use crate::tree::expression::operator::RangeOperationExpression;
+use crate::tree::expression::operator::StringOperationExpression;
+use crate::tree::expression::operator::TernaryOperationExpression;
+use crate::tree::expression::operator::TypeOperationExpression;
+use crate::tree::expression::Expression;
+use crate::tree::expression::ParenthesizedExpression;
+use crate::tree::identifier::Identifier;
+use crate::tree::identifier::TemplatedIdentifier;
+use crate::tree::kind::NodeKind;
+use crate::tree::statement::block::BlockStatement;
+use crate::tree::statement::control_flow::IfElseIfStatement;
+use crate::tree::statement::control_flow::IfElseStatement;
+use crate::tree::statement::control_flow::IfStatement;
+use crate::tree::statement::control_flow::UsingAssignmentStatement;
+use crate::tree::statement::control_flow::UsingIfClauseStatement;
+use crate::tree::statement::control_flow::UsingStatement;
+use crate::tree::statement::expression::ExpressionStatement;
+use crate::tree::statement::r#loop::BreakStatement;
+use crate::tree::statement::r#loop::ContinueStatement;
+use crate::tree::statement::r#loop::DoWhileStatement;
+use crate::tree::statement::r#loop::ForIteratorStatement;
+use crate::tree::statement::r#loop::ForStatement;
+use crate::tree::statement::r#loop::ForeachIteratorKeyStatement;
+use crate::tree::statement::r#loop::ForeachIteratorStatement;
+use crate::tree::statement::r#loop::ForeachStatement;
+use crate::tree::statement::r#loop::WhileStatement;
+use crate::tree::statement::r#return::ReturnStatement;
+use crate::tree::statement::r#try::TryCatchBlockStatement;
+use crate::tree::statement::r#try::TryCatchTypeStatement;
+use crate::tree::statement::r#try::TryFinallyBlockStatement;
+use crate::tree::statement::r#try::TryStatement;
+use crate::tree::statement::Statement;
+use crate::tree::token::Keyword;
+use crate::tree::variable::Variable;
 use crate::tree::Node;
 
 pub trait NodeVisitor<E: Debug> {
@@ -20,3 +161,314 @@ pub trait NodeVisitor<E: Debug> {
 
     fn visit(&mut self, source: &str, node: &dyn Node, parent: Option<&dyn Node>) -> Result<(), E>;
 }
+
+pub trait NodeVisitorMut<E: Debug> {
+    fn visit_node_mut(&mut self, source: &str, node: &mut dyn Node) -> Result<(), E> {
+        self.visit_mut(source, node)?;
+
+        for child in node.children_mut() {
+            self.visit_node_mut(source, child)?;
+        }
+
+        Ok(())
+    }
+
+    /// Visit the given node, before any of its children.
+    ///
+    /// The children are collected after the visit, so replacing them is allowed.
+    fn visit_mut(&mut self, source: &str, node: &mut dyn Node) -> Result<(), E>;
+}
+
+/// The context in which a node is visited, as tracked by the traverser.
+#[derive(Clone, Copy)]
+pub struct Context<'a> {
+    /// The name of the source the node belongs to.
+    pub source: &'a str,
+    /// The ancestors of the node, from the root down to its parent.
+    pub ancestors: &'a [&'a dyn Node],
+}
+
+impl<'a> Context<'a> {
+    /// The parent of the node, if it is not the root.
+    pub fn parent(&self) -> Option<&'a dyn Node> {
+        self.ancestors.last().copied()
+    }
+
+    /// The closest ancestor of the given type.
+    pub fn closest<T: Node>(&self) -> Option<&'a T> {
+        self.ancestors
+            .iter()
+            .rev()
+            .find_map(|ancestor| downcast::<T>(*ancestor))
+    }
+
+    /// The name of the namespace the node belongs to, if any.
+    pub fn namespace(&self) -> Option<&'a Identifier> {
+        self.closest::<NamespaceDefinition>()
+            .map(|namespace| &namespace.name)
+    }
+}
+
+/// A visitor that is handed the context of every node it visits, rather than walking the tree itself.
+pub trait ContextVisitor<E: Debug> {
+    fn visit(&mut self, context: &Context<'_>, node: &dyn Node) -> Result<(), E>;
+}
+
+macro_rules! typed_visitor {
+    ($($node:ident => $method:ident),+ $(,)?) => {
+        /// A visitor with a method for each kind of node.
+        ///
+        /// Every method visits the children of the given node by default, so implementations only
+        /// need to override the methods of the nodes they are interested in, calling `visit_children`
+        /// to keep descending into them.
+        pub trait Visitor<E: Debug> {
+            /// Visit the given node, dispatching to the method of its kind.
+            fn visit(&mut self, node: &dyn Node) -> Result<(), E> {
+                match node.kind() {
+                    $(
+                        NodeKind::$node => self.$method(
+                            downcast::<$node>(node).expect("the kind of a node matches its type"),
+                        ),
+                    )+
+                }
+            }
+
+            /// Visit the children of the given node.
+            fn visit_children(&mut self, node: &dyn Node) -> Result<(), E> {
+                for child in node.children() {
+                    self.visit(child)?;
+                }
+
+                Ok(())
+            }
+
+            $(
+                fn $method(&mut self, node: &$node) -> Result<(), E> {
+                    self.visit_children(node)
+                }
+            )+
+        }
+    };
+}
+
+typed_visitor!(
+    Definition => visit_definition,
+    DefinitionTree => visit_definition_tree,
+    AttributeDefinition => visit_attribute_definition,
+    AttributeGroupDefinition => visit_attribute_group_definition,
+    ClassDefinition => visit_class_definition,
+    ClassDefinitionBody => visit_class_definition_body,
+    ClassDefinitionExtends => visit_class_definition_extends,
+    ClassDefinitionImplements => visit_class_definition_implements,
+    ClassDefinitionMember => visit_class_definition_member,
+    ClassDefinitionPermits => visit_class_definition_permits,
+    ClassishConstantDefinition => visit_classish_constant_definition,
+    ConstantDefinition => visit_constant_definition,
+    BackedEnumBodyDefinition => visit_backed_enum_body_definition,
+    BackedEnumCaseDefinition => visit_backed_enum_case_definition,
+    BackedEnumDefinition => visit_backed_enum_definition,
+    BackedEnumMemberDefinition => visit_backed_enum_member_definition,
+    BackedEnumTypeDefinition => visit_backed_enum_type_definition,
+    EnumDefinition => visit_enum_definition,
+    EnumImplementsDefinition => visit_enum_implements_definition,
+    UnitEnumBodyDefinition => visit_unit_enum_body_definition,
+    UnitEnumCaseDefinition => visit_unit_enum_case_definition,
+    UnitEnumDefinition => visit_unit_enum_definition,
+    UnitEnumMemberDefinition => visit_unit_enum_member_definition,
+    FunctionBodyDefinition => visit_function_body_definition,
+    FunctionDefinition => visit_function_definition,
+    FunctionLikeParameterDefaultValueDefinition => visit_function_like_parameter_default_value_definition,
+    FunctionLikeParameterDefinition => visit_function_like_parameter_definition,
+    FunctionLikeParameterListDefinition => visit_function_like_parameter_list_definition,
+    FunctionLikeReturnTypeDefinition => visit_function_like_return_type_definition,
+    MethodBodyDefinition => visit_method_body_definition,
+    MethodDefinition => visit_method_definition,
+    MethodParameterDefinition => visit_method_parameter_definition,
+    MethodParameterListDefinition => visit_method_parameter_list_definition,
+    MethodTypeConstraintDefinition => visit_method_type_constraint_definition,
+    MethodTypeConstraintGroupDefinition => visit_method_type_constraint_group_definition,
+    InterfaceDefinition => visit_interface_definition,
+    InterfaceDefinitionBody => visit_interface_definition_body,
+    InterfaceDefinitionExtends => visit_interface_definition_extends,
+    InterfaceDefinitionMember => visit_interface_definition_member,
+    InterfaceDefinitionPermits => visit_interface_definition_permits,
+    ModifierDefinition => visit_modifier_definition,
+    ModifierGroupDefinition => visit_modifier_group_definition,
+    NamespaceDefinition => visit_namespace_definition,
+    PragmaDefinition => visit_pragma_definition,
+    PropertyDefinition => visit_property_definition,
+    PropertyEntryDefinition => visit_property_entry_definition,
+    ConstTemplateDefinition => visit_const_template_definition,
+    TemplateDefinition => visit_template_definition,
+    TemplateBoundsDefinition => visit_template_bounds_definition,
+    TemplateGroupDefinition => visit_template_group_definition,
+    TemplateGroupDefinitionMember => visit_template_group_definition_member,
+    TypeTemplateGroupDefinition => visit_type_template_group_definition,
+    FloatingPointTypeDefinition => visit_floating_point_type_definition,
+    IntegerRangeBoundTypeDefinition => visit_integer_range_bound_type_definition,
+    ShapeFieldTypeDefinition => visit_shape_field_type_definition,
+    SignedIntegerTypeDefinition => visit_signed_integer_type_definition,
+    TupleElementLabelDefinition => visit_tuple_element_label_definition,
+    TupleElementTypeDefinition => visit_tuple_element_type_definition,
+    TypeAliasDefinition => visit_type_alias_definition,
+    TypeDefinition => visit_type_definition,
+    UnsignedIntegerTypeDefinition => visit_unsigned_integer_type_definition,
+    UseDefinition => visit_use_definition,
+    UseDefinitionGroupMember => visit_use_definition_group_member,
+    UseDefinitionSymbolAlias => visit_use_definition_symbol_alias,
+    Expression => visit_expression,
+    ParenthesizedExpression => visit_parenthesized_expression,
+    ArgumentExpression => visit_argument_expression,
+    ArgumentListExpression => visit_argument_list_expression,
+    ArgumentPlaceholderExpression => visit_argument_placeholder_expression,
+    DictElementExpression => visit_dict_element_expression,
+    DictExpression => visit_dict_expression,
+    TupleExpression => visit_tuple_expression,
+    VecElementExpression => visit_vec_element_expression,
+    VecExpression => visit_vec_expression,
+    AnonymousClassExpression => visit_anonymous_class_expression,
+    ExitConstructExpression => visit_exit_construct_expression,
+    MatchArmConditionExpression => visit_match_arm_condition_expression,
+    MatchArmExpression => visit_match_arm_expression,
+    MatchBodyExpression => visit_match_body_expression,
+    MatchExpression => visit_match_expression,
+    AnonymousFunctionExpression => visit_anonymous_function_expression,
+    AnonymousFunctionUseClauseExpression => visit_anonymous_function_use_clause_expression,
+    AnonymousFunctionUseClauseVariableExpression => visit_anonymous_function_use_clause_variable_expression,
+    ArrowFunctionExpression => visit_arrow_function_expression,
+    GenericGroupExpression => visit_generic_group_expression,
+    Literal => visit_literal,
+    LiteralFalse => visit_literal_false,
+    LiteralFloat => visit_literal_float,
+    LiteralInteger => visit_literal_integer,
+    LiteralNull => visit_literal_null,
+    LiteralString => visit_literal_string,
+    LiteralTrue => visit_literal_true,
+    MagicConstant => visit_magic_constant,
+    ArithmeticOperationExpression => visit_arithmetic_operation_expression,
+    ArrayOperationExpression => visit_array_operation_expression,
+    AssignmentOperationExpression => visit_assignment_operation_expression,
+    AsyncOperationExpression => visit_async_operation_expression,
+    BitwiseOperationExpression => visit_bitwise_operation_expression,
+    ClassOperationExpression => visit_class_operation_expression,
+    ClassOperationInitializationClassExpression => visit_class_operation_initialization_class_expression,
+    CoalesceOperationExpression => visit_coalesce_operation_expression,
+    ComparisonOperationExpression => visit_comparison_operation_expression,
+    ExceptionOperationExpression => visit_exception_operation_expression,
+    FunctionOperationExpression => visit_function_operation_expression,
+    FunctionalOperationExpression => visit_functional_operation_expression,
+    GeneratorOperationExpression => visit_generator_operation_expression,
+    LogicalOperationExpression => visit_logical_operation_expression,
+    ObjectOperationExpression => visit_object_operation_expression,
+    RangeOperationExpression => visit_range_operation_expression,
+    StringOperationExpression => visit_string_operation_expression,
+    TernaryOperationExpression => visit_ternary_operation_expression,
+    TypeOperationExpression => visit_type_operation_expression,
+    Identifier => visit_identifier,
+    TemplatedIdentifier => visit_templated_identifier,
+    Statement => visit_statement,
+    BlockStatement => visit_block_statement,
+    IfElseIfStatement => visit_if_else_if_statement,
+    IfElseStatement => visit_if_else_statement,
+    IfStatement => visit_if_statement,
+    UsingAssignmentStatement => visit_using_assignment_statement,
+    UsingIfClauseStatement => visit_using_if_clause_statement,
+    UsingStatement => visit_using_statement,
+    ExpressionStatement => visit_expression_statement,
+    BreakStatement => visit_break_statement,
+    ContinueStatement => visit_continue_statement,
+    DoWhileStatement => visit_do_while_statement,
+    ForIteratorStatement => visit_for_iterator_statement,
+    ForStatement => visit_for_statement,
+    ForeachIteratorKeyStatement => visit_foreach_iterator_key_statement,
+    ForeachIteratorStatement => visit_foreach_iterator_statement,
+    ForeachStatement => visit_foreach_statement,
+    WhileStatement => visit_while_statement,
+    ReturnStatement => visit_return_statement,
+    TryCatchBlockStatement => visit_try_catch_block_statement,
+    TryCatchTypeStatement => visit_try_catch_type_statement,
+    TryFinallyBlockStatement => visit_try_finally_block_statement,
+    TryStatement => visit_try_statement,
+    Keyword => visit_keyword,
+    Variable => visit_variable
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ara_source::source::Source;
+    use ara_source::source::SourceKind;
+
+    use crate::lexer::byte_string::ByteString;
+    use crate::parser;
+    use crate::printer;
+    use crate::tree::downcast_mut;
+    use crate::tree::variable::Variable;
+
+    struct RenameVariable;
+
+    impl NodeVisitorMut<()> for RenameVariable {
+        fn visit_mut(&mut self, _source: &str, node: &mut dyn Node) -> Result<(), ()> {
+            if let Some(variable) = downcast_mut::<Variable>(node) {
+                if variable.name == ByteString::from("$foo") {
+                    variable.name = ByteString::from("$bar");
+                }
+            }
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_node_visitor_mut() {
+        let source = Source::inline(
+            SourceKind::Script,
+            "function foo(int $foo): int { return $foo + 1; }",
+        );
+
+        let mut tree = parser::parse(&source).unwrap();
+        RenameVariable
+            .visit_node_mut(&tree.source, &mut tree.definitions)
+            .unwrap();
+
+        assert_eq!(
+            printer::print(&tree),
+            "function foo(int $bar): int {\n    return $bar + 1;\n}\n"
+        );
+    }
+
+    #[derive(Default)]
+    struct FunctionNames {
+        names: Vec<String>,
+    }
+
+    impl Visitor<()> for FunctionNames {
+        fn visit_function_definition(&mut self, node: &FunctionDefinition) -> Result<(), ()> {
+            self.names.push(node.name.to_string());
+
+            self.visit_children(node)
+        }
+
+        fn visit_method_definition(&mut self, node: &MethodDefinition) -> Result<(), ()> {
+            self.names.push(node.name.to_string());
+
+            // skip the method body.
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_visitor() {
+        let source = Source::inline(
+            SourceKind::Script,
+            "function foo(): void {} final class Bar { public function baz(): void {} }",
+        );
+
+        let tree = parser::parse(&source).unwrap();
+
+        let mut visitor = FunctionNames::default();
+        visitor.visit(&tree.definitions).unwrap();
+
+        assert_eq!(visitor.names, vec!["foo", "baz"]);
+    }
+}
@@ -23,8 +23,89 @@ pub struct Comment {
     pub content: ByteString,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
+/// The comments preceding a node, as the range of the source they span.
+///
+/// A group does not hold its comments, the comments of a source are held once, by the
+/// `CommentAttachments` of its tree, see `CommentAttachments::group`, an empty group spans
+/// an empty range.
+#[derive(
+    Debug, PartialEq, Eq, Clone, Hash, Default, Deserialize, Serialize, Encode, Decode, JsonSchema,
+)]
 #[serde(rename_all = "snake_case")]
 pub struct CommentGroup {
+    /// The position of the first comment of the group.
+    pub start: usize,
+    /// The position right after the last comment of the group.
+    pub end: usize,
+}
+
+impl CommentGroup {
+    /// Whether the group holds no comments.
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// The positions of the group, mutable, an empty group has no positions.
+    pub fn positions_mut(&mut self) -> impl Iterator<Item = &mut usize> {
+        let empty = self.is_empty();
+
+        [&mut self.start, &mut self.end]
+            .into_iter()
+            .filter(move |_| !empty)
+    }
+}
+
+/// The comments of a source, in order of their positions, attached to the tokens surrounding them.
+///
+/// A comment following a token on the same line trails that token, any other comment leads
+/// the token following it, including comments at the end of the source, which lead the end of file.
+///
+/// Every node starting with a token shares the comments leading it, and every node ending with a
+/// token shares the comments trailing it.
+#[derive(
+    Debug, PartialEq, Eq, Clone, Hash, Default, Deserialize, Serialize, Encode, Decode, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub struct CommentAttachments {
+    /// All comments of the source, in order of their positions.
     pub comments: Vec<Comment>,
+    /// The comments leading a token, keyed by the position of the token.
+    pub leading: Vec<(usize, CommentGroup)>,
+    /// The comments trailing a token, keyed by the position right after the token.
+    pub trailing: Vec<(usize, CommentGroup)>,
+}
+
+impl CommentAttachments {
+    /// The comments of the given group.
+    pub fn group(&self, group: &CommentGroup) -> &[Comment] {
+        if group.is_empty() {
+            return &[];
+        }
+
+        let start = self
+            .comments
+            .partition_point(|comment| comment.position < group.start);
+        let end = self
+            .comments
+            .partition_point(|comment| comment.position < group.end);
+
+        &self.comments[start..end]
+    }
+
+    /// The comments leading the token at the given position.
+    pub fn leading_at(&self, position: usize) -> &[Comment] {
+        self.find(&self.leading, position)
+    }
+
+    /// The comments trailing the token ending at the given position.
+    pub fn trailing_at(&self, position: usize) -> &[Comment] {
+        self.find(&self.trailing, position)
+    }
+
+    fn find(&self, groups: &[(usize, CommentGroup)], position: usize) -> &[Comment] {
+        groups
+            .binary_search_by_key(&position, |(key, _)| *key)
+            .map(|index| self.group(&groups[index].1))
+            .unwrap_or_default()
+    }
 }
@@ -6,7 +6,10 @@ use serde::Serialize;
 
 use crate::tree::expression::argument::ArgumentListExpression;
 use crate::tree::identifier::Identifier;
+use crate::tree::kind::NodeKind;
+use crate::tree::span::Span;
 use crate::tree::utils::CommaSeparated;
+use crate::tree::utils::ListView;
 use crate::tree::Node;
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
@@ -25,48 +28,81 @@ pub struct AttributeDefinition {
 }
 
 impl Node for AttributeGroupDefinition {
-    fn initial_position(&self) -> usize {
-        self.hash_left_bracket
+    fn span(&self) -> Span {
+        Span::new(self.hash_left_bracket, self.right_bracket + 1)
     }
 
-    fn final_position(&self) -> usize {
-        self.right_bracket + 1
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        self.members
+            .inner
+            .iter()
+            .map(|member| ("members", member as &dyn Node))
+            .collect()
+    }
+
+    fn lists(&self) -> Vec<ListView<'_>> {
+        vec![self.members.view()]
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         self.members
             .inner
-            .iter()
-            .map(|member| member as &dyn Node)
+            .iter_mut()
+            .map(|member| member as &mut dyn Node)
             .collect()
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        let mut positions: Vec<&mut usize> =
+            vec![&mut self.hash_left_bracket, &mut self.right_bracket];
+        positions.extend(self.members.commas.iter_mut());
+        positions
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::AttributeGroupDefinition
+    }
+
     fn get_description(&self) -> String {
         "attribute group definition".to_string()
     }
 }
 
 impl Node for AttributeDefinition {
-    fn initial_position(&self) -> usize {
-        self.name.initial_position()
+    fn span(&self) -> Span {
+        let final_position = if let Some(arguments) = &self.arguments {
+            arguments.final_position()
+        } else {
+            self.name.final_position()
+        };
+
+        Span::new(self.name.initial_position(), final_position)
     }
 
-    fn final_position(&self) -> usize {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
         if let Some(arguments) = &self.arguments {
-            arguments.final_position()
+            vec![("name", &self.name), ("arguments", arguments)]
         } else {
-            self.name.final_position()
+            vec![("name", &self.name)]
         }
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        if let Some(arguments) = &self.arguments {
-            vec![&self.name, arguments]
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        if let Some(arguments) = &mut self.arguments {
+            vec![&mut self.name, arguments]
         } else {
-            vec![&self.name]
+            vec![&mut self.name]
         }
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        vec![]
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::AttributeDefinition
+    }
+
     fn get_description(&self) -> String {
         "attribute definition".to_string()
     }
@@ -97,6 +133,7 @@ mod tests {
     use crate::tree::expression::literal::Literal::Integer;
     use crate::tree::expression::literal::LiteralInteger;
     use crate::tree::expression::Expression;
+    use smallvec::smallvec;
 
     #[test]
     fn test_attribute_group_definition_display() {
@@ -117,24 +154,24 @@ mod tests {
                             value: ByteString::from("Bar"),
                         },
                         arguments: Some(ArgumentListExpression {
-                            comments: CommentGroup { comments: vec![] },
+                            comments: CommentGroup::default(),
                             left_parenthesis: 24,
                             arguments: CommaSeparated {
                                 inner: vec![ArgumentExpression::Value {
-                                    comments: CommentGroup { comments: vec![] },
+                                    comments: CommentGroup::default(),
                                     value: Expression::Literal(Integer(LiteralInteger {
-                                        comments: CommentGroup { comments: vec![] },
+                                        comments: CommentGroup::default(),
                                         position: 0,
                                         value: ByteString::from("2"),
                                     })),
                                 }],
-                                commas: vec![],
+                                commas: smallvec![],
                             },
                             right_parenthesis: 30,
                         }),
                     },
                 ],
-                commas: vec![],
+                commas: smallvec![],
             },
             right_bracket: 32,
         };
@@ -13,8 +13,11 @@ use crate::tree::definition::property::PropertyDefinition;
 use crate::tree::definition::template::TemplateGroupDefinition;
 use crate::tree::identifier::Identifier;
 use crate::tree::identifier::TemplatedIdentifier;
+use crate::tree::kind::NodeKind;
+use crate::tree::span::Span;
 use crate::tree::token::Keyword;
 use crate::tree::utils::CommaSeparated;
+use crate::tree::utils::ListView;
 use crate::tree::Node;
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
@@ -28,6 +31,7 @@ pub struct ClassDefinition {
     pub templates: Option<TemplateGroupDefinition>,
     pub extends: Option<ClassDefinitionExtends>,
     pub implements: Option<ClassDefinitionImplements>,
+    pub permits: Option<ClassDefinitionPermits>,
     pub body: ClassDefinitionBody,
 }
 
@@ -45,6 +49,13 @@ pub struct ClassDefinitionImplements {
     pub interfaces: CommaSeparated<TemplatedIdentifier>,
 }
 
+#[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ClassDefinitionPermits {
+    pub permits: Keyword,
+    pub permitted: CommaSeparated<Identifier>,
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub struct ClassDefinitionBody {
@@ -55,6 +66,7 @@ pub struct ClassDefinitionBody {
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
 #[serde(rename_all = "snake_case", tag = "type", content = "value")]
+#[allow(clippy::large_enum_variant)]
 pub enum ClassDefinitionMember {
     Constant(ClassishConstantDefinition),
     Property(PropertyDefinition),
@@ -66,61 +78,114 @@ impl Node for ClassDefinition {
         Some(&self.comments)
     }
 
-    fn initial_position(&self) -> usize {
-        if let Some(attributes) = self.attributes.first() {
-            return attributes.initial_position();
-        }
+    fn span(&self) -> Span {
+        let initial_position = match self.attributes.first() {
+            Some(attributes) => attributes.initial_position(),
+            None => self.modifiers.initial_position(),
+        };
 
-        self.modifiers.initial_position()
+        Span::new(initial_position, self.body.final_position())
     }
 
-    fn final_position(&self) -> usize {
-        self.body.final_position()
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut children: Vec<(&'static str, &dyn Node)> = vec![];
+
+        for attribute in &self.attributes {
+            children.push(("attributes", attribute));
+        }
+
+        children.push(("modifiers", &self.modifiers));
+        children.push(("class", &self.class));
+        children.push(("name", &self.name));
+
+        if let Some(templates) = &self.templates {
+            children.push(("templates", templates));
+        }
+
+        if let Some(extends) = &self.extends {
+            children.push(("extends", extends));
+        }
+
+        if let Some(implements) = &self.implements {
+            children.push(("implements", implements));
+        }
+
+        if let Some(permits) = &self.permits {
+            children.push(("permits", permits));
+        }
+
+        children.push(("body", &self.body));
+
+        children
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        let mut children: Vec<&dyn Node> = vec![&self.class];
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = vec![];
 
-        for attribute in &self.attributes {
+        for attribute in &mut self.attributes {
             children.push(attribute);
         }
 
-        children.push(&self.modifiers);
-        children.push(&self.name);
+        children.push(&mut self.modifiers);
+        children.push(&mut self.class);
+        children.push(&mut self.name);
 
-        if let Some(templates) = &self.templates {
+        if let Some(templates) = &mut self.templates {
             children.push(templates);
         }
 
-        if let Some(extends) = &self.extends {
+        if let Some(extends) = &mut self.extends {
             children.push(extends);
         }
 
-        if let Some(implements) = &self.implements {
+        if let Some(implements) = &mut self.implements {
             children.push(implements);
         }
 
-        children.push(&self.body);
+        if let Some(permits) = &mut self.permits {
+            children.push(permits);
+        }
+
+        children.push(&mut self.body);
 
         children
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        self.comments.positions_mut().collect()
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::ClassDefinition
+    }
+
     fn get_description(&self) -> String {
         "class definition".to_string()
     }
 }
 
 impl Node for ClassDefinitionExtends {
-    fn initial_position(&self) -> usize {
-        self.extends.initial_position()
+    fn span(&self) -> Span {
+        Span::new(
+            self.extends.initial_position(),
+            self.parent.final_position(),
+        )
+    }
+
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("extends", &self.extends), ("parent", &self.parent)]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        vec![&mut self.extends, &mut self.parent]
     }
 
-    fn final_position(&self) -> usize {
-        self.parent.final_position()
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        vec![]
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        vec![&self.extends, &self.parent]
+    fn kind(&self) -> NodeKind {
+        NodeKind::ClassDefinitionExtends
     }
 
     fn get_description(&self) -> String {
@@ -129,57 +194,134 @@ impl Node for ClassDefinitionExtends {
 }
 
 impl Node for ClassDefinitionImplements {
-    fn initial_position(&self) -> usize {
-        self.implements.initial_position()
-    }
-
-    fn final_position(&self) -> usize {
-        if let Some(last_interface) = self.interfaces.inner.last() {
-            let last_interface_position = last_interface.final_position();
-            if let Some(last_comma) = self.interfaces.commas.last() {
-                let last_comma_position = last_comma + 1;
-                if last_comma_position > last_interface_position {
-                    return last_comma_position;
-                }
+    fn span(&self) -> Span {
+        // a trailing comma is part of the node.
+        let final_position = match (self.interfaces.inner.last(), self.interfaces.commas.last()) {
+            (Some(last_interface), Some(last_comma)) => {
+                last_interface.final_position().max(last_comma + 1)
             }
+            (Some(last_interface), None) => last_interface.final_position(),
+            (None, _) => self.implements.final_position(),
+        };
+
+        Span::new(self.implements.initial_position(), final_position)
+    }
+
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut children: Vec<(&'static str, &dyn Node)> = vec![("implements", &self.implements)];
 
-            return last_interface_position;
+        for interface in &self.interfaces.inner {
+            children.push(("interfaces", interface));
         }
 
-        self.implements.final_position()
+        children
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        let mut children: Vec<&dyn Node> = vec![&self.implements];
+    fn lists(&self) -> Vec<ListView<'_>> {
+        vec![self.interfaces.view()]
+    }
 
-        for interface in &self.interfaces.inner {
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = vec![&mut self.implements];
+
+        for interface in &mut self.interfaces.inner {
             children.push(interface);
         }
 
         children
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        self.interfaces.commas.iter_mut().collect()
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::ClassDefinitionImplements
+    }
+
     fn get_description(&self) -> String {
         "class implements definition".to_string()
     }
 }
 
-impl Node for ClassDefinitionBody {
-    fn initial_position(&self) -> usize {
-        self.left_brace
+impl Node for ClassDefinitionPermits {
+    fn span(&self) -> Span {
+        // a trailing comma is part of the node.
+        let final_position = match (self.permitted.inner.last(), self.permitted.commas.last()) {
+            (Some(last_permitted), Some(last_comma)) => {
+                last_permitted.final_position().max(last_comma + 1)
+            }
+            (Some(last_permitted), None) => last_permitted.final_position(),
+            (None, _) => self.permits.final_position(),
+        };
+
+        Span::new(self.permits.initial_position(), final_position)
+    }
+
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut children: Vec<(&'static str, &dyn Node)> = vec![("permits", &self.permits)];
+
+        for permitted in &self.permitted.inner {
+            children.push(("permitted", permitted));
+        }
+
+        children
+    }
+
+    fn lists(&self) -> Vec<ListView<'_>> {
+        vec![self.permitted.view()]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = vec![&mut self.permits];
+
+        for permitted in &mut self.permitted.inner {
+            children.push(permitted);
+        }
+
+        children
+    }
+
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        self.permitted.commas.iter_mut().collect()
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::ClassDefinitionPermits
     }
 
-    fn final_position(&self) -> usize {
-        self.right_brace + 1
+    fn get_description(&self) -> String {
+        "class permits definition".to_string()
+    }
+}
+
+impl Node for ClassDefinitionBody {
+    fn span(&self) -> Span {
+        Span::new(self.left_brace, self.right_brace + 1)
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
         self.members
             .iter()
-            .map(|member| member as &dyn Node)
+            .map(|member| ("members", member as &dyn Node))
+            .collect()
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        self.members
+            .iter_mut()
+            .map(|member| member as &mut dyn Node)
             .collect()
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        vec![&mut self.left_brace, &mut self.right_brace]
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::ClassDefinitionBody
+    }
+
     fn get_description(&self) -> String {
         "class body definition".to_string()
     }
@@ -194,30 +336,38 @@ impl Node for ClassDefinitionMember {
         }
     }
 
-    fn initial_position(&self) -> usize {
+    fn span(&self) -> Span {
         match &self {
-            Self::Constant(constant) => constant.initial_position(),
-            Self::Property(property) => property.initial_position(),
-            Self::Method(method) => method.initial_position(),
+            Self::Constant(constant) => constant.span(),
+            Self::Property(property) => property.span(),
+            Self::Method(method) => method.span(),
         }
     }
 
-    fn final_position(&self) -> usize {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
         match &self {
-            Self::Constant(constant) => constant.final_position(),
-            Self::Property(property) => property.final_position(),
-            Self::Method(method) => method.final_position(),
+            Self::Constant(constant) => vec![("constant", constant)],
+            Self::Property(property) => vec![("property", property)],
+            Self::Method(method) => vec![("method", method)],
         }
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        match &self {
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
             Self::Constant(constant) => vec![constant],
             Self::Property(property) => vec![property],
             Self::Method(method) => vec![method],
         }
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        vec![]
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::ClassDefinitionMember
+    }
+
     fn get_description(&self) -> String {
         match &self {
             Self::Constant(constant) => constant.get_description(),
@@ -239,6 +389,12 @@ impl std::fmt::Display for ClassDefinitionImplements {
     }
 }
 
+impl std::fmt::Display for ClassDefinitionPermits {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.permits, self.permitted)
+    }
+}
+
 impl std::fmt::Display for ClassDefinitionMember {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match &self {
@@ -271,6 +427,10 @@ impl std::fmt::Display for ClassDefinition {
             write!(f, " {}", implements)?;
         }
 
+        if let Some(permits) = &self.permits {
+            write!(f, " {}", permits)?;
+        }
+
         write!(f, " {}", self.body)
     }
 }
@@ -284,7 +444,7 @@ mod tests {
     #[test]
     fn test_class_definition_display() {
         let class_definition = ClassDefinition {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             class: Keyword::new(ByteString::from("class"), 0),
             attributes: vec![],
             modifiers: ModifierGroupDefinition {
@@ -310,6 +470,7 @@ mod tests {
                 },
             }),
             implements: None,
+            permits: None,
             body: ClassDefinitionBody {
                 left_brace: 19,
                 members: vec![],
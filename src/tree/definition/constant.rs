@@ -10,6 +10,8 @@ use crate::tree::definition::modifier::ModifierGroupDefinition;
 use crate::tree::definition::r#type::TypeDefinition;
 use crate::tree::expression::Expression;
 use crate::tree::identifier::Identifier;
+use crate::tree::kind::NodeKind;
+use crate::tree::span::Span;
 use crate::tree::token::Keyword;
 use crate::tree::Node;
 
@@ -44,23 +46,38 @@ impl Node for ConstantDefinition {
         Some(&self.comments)
     }
 
-    fn initial_position(&self) -> usize {
-        self.r#const.initial_position()
+    fn span(&self) -> Span {
+        Span::new(self.r#const.initial_position(), self.semicolon)
     }
 
-    fn final_position(&self) -> usize {
-        self.semicolon
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![
+            ("const", &self.r#const),
+            ("type_definition", &self.type_definition),
+            ("name", &self.name),
+            ("value", &self.value),
+        ]
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![
-            &self.r#const,
-            &self.type_definition,
-            &self.name,
-            &self.value,
+            &mut self.r#const,
+            &mut self.type_definition,
+            &mut self.name,
+            &mut self.value,
         ]
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        let mut positions: Vec<&mut usize> = vec![&mut self.equals, &mut self.semicolon];
+        positions.extend(self.comments.positions_mut());
+        positions
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::ConstantDefinition
+    }
+
     fn get_description(&self) -> String {
         "constant definition".to_string()
     }
@@ -71,33 +88,55 @@ impl Node for ClassishConstantDefinition {
         Some(&self.comments)
     }
 
-    fn initial_position(&self) -> usize {
-        if let Some(attribute) = self.attributes.first() {
-            return attribute.initial_position();
-        }
+    fn span(&self) -> Span {
+        let initial_position = match self.attributes.first() {
+            Some(attribute) => attribute.initial_position(),
+            None => self.modifiers.initial_position(),
+        };
 
-        self.modifiers.initial_position()
+        Span::new(initial_position, self.semicolon)
     }
 
-    fn final_position(&self) -> usize {
-        self.semicolon
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut children: Vec<(&'static str, &dyn Node)> = vec![];
+        for attribute in &self.attributes {
+            children.push(("attributes", attribute));
+        }
+
+        children.push(("modifiers", &self.modifiers));
+        children.push(("const", &self.r#const));
+        children.push(("type_definition", &self.type_definition));
+        children.push(("name", &self.name));
+        children.push(("value", &self.value));
+
+        children
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        let mut children: Vec<&dyn Node> = vec![];
-        for attribute in &self.attributes {
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = vec![];
+        for attribute in &mut self.attributes {
             children.push(attribute);
         }
 
-        children.push(&self.modifiers);
-        children.push(&self.r#const);
-        children.push(&self.type_definition);
-        children.push(&self.name);
-        children.push(&self.value);
+        children.push(&mut self.modifiers);
+        children.push(&mut self.r#const);
+        children.push(&mut self.type_definition);
+        children.push(&mut self.name);
+        children.push(&mut self.value);
 
         children
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        let mut positions: Vec<&mut usize> = vec![&mut self.equals, &mut self.semicolon];
+        positions.extend(self.comments.positions_mut());
+        positions
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::ClassishConstantDefinition
+    }
+
     fn get_description(&self) -> String {
         "classish constant definition".to_string()
     }
@@ -135,7 +174,7 @@ mod tests {
     #[test]
     fn test_constant_definition_display() {
         let constant_definition = ConstantDefinition {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             r#const: Keyword::new(ByteString::from("const"), 0),
             type_definition: TypeDefinition::SignedInteger(SignedIntegerTypeDefinition::I64(
                 Keyword::new(ByteString::from("i64"), 15),
@@ -146,7 +185,7 @@ mod tests {
             },
             equals: 0,
             value: Expression::Literal(Integer(LiteralInteger {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 position: 0,
                 value: ByteString::from("1"),
             })),
@@ -162,7 +201,7 @@ mod tests {
     #[test]
     fn test_classish_constant_definition_display() {
         let classish_constant_definition = ClassishConstantDefinition {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             attributes: vec![],
             modifiers: ModifierGroupDefinition {
                 position: 0,
@@ -181,7 +220,7 @@ mod tests {
             },
             equals: 0,
             value: Expression::Literal(Integer(LiteralInteger {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 position: 0,
                 value: ByteString::from("1"),
             })),
@@ -11,8 +11,11 @@ use crate::tree::definition::function::MethodDefinition;
 use crate::tree::expression::Expression;
 use crate::tree::identifier::Identifier;
 use crate::tree::identifier::TemplatedIdentifier;
+use crate::tree::kind::NodeKind;
+use crate::tree::span::Span;
 use crate::tree::token::Keyword;
 use crate::tree::utils::CommaSeparated;
+use crate::tree::utils::ListView;
 use crate::tree::Node;
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
@@ -50,6 +53,7 @@ pub struct UnitEnumBodyDefinition {
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
 #[serde(rename_all = "snake_case", tag = "type", content = "value")]
+#[allow(clippy::large_enum_variant)]
 pub enum UnitEnumMemberDefinition {
     Case(UnitEnumCaseDefinition),
     Method(MethodDefinition),
@@ -94,6 +98,7 @@ pub struct BackedEnumBodyDefinition {
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
 #[serde(rename_all = "snake_case", tag = "type", content = "value")]
+#[allow(clippy::large_enum_variant)]
 pub enum BackedEnumMemberDefinition {
     Case(BackedEnumCaseDefinition),
     Method(MethodDefinition),
@@ -111,32 +116,100 @@ pub struct BackedEnumCaseDefinition {
     pub semicolon: usize,
 }
 
+impl EnumDefinition {
+    pub fn name(&self) -> &Identifier {
+        match &self {
+            Self::Backed(definition) => &definition.name,
+            Self::Unit(definition) => &definition.name,
+        }
+    }
+
+    pub fn is_unit_enum(&self) -> bool {
+        matches!(self, Self::Unit(_))
+    }
+
+    pub fn is_backed_enum(&self) -> bool {
+        matches!(self, Self::Backed(_))
+    }
+
+    pub fn backed_type(&self) -> Option<&BackedEnumTypeDefinition> {
+        match &self {
+            Self::Backed(definition) => Some(&definition.backed_type),
+            Self::Unit(_) => None,
+        }
+    }
+
+    /// The names of all cases, in declaration order.
+    pub fn cases(&self) -> Vec<&Identifier> {
+        match &self {
+            Self::Backed(definition) => definition.cases().map(|case| &case.name).collect(),
+            Self::Unit(definition) => definition.cases().map(|case| &case.name).collect(),
+        }
+    }
+}
+
+impl UnitEnumDefinition {
+    pub fn cases(&self) -> impl Iterator<Item = &UnitEnumCaseDefinition> {
+        self.body.members.iter().filter_map(|member| match member {
+            UnitEnumMemberDefinition::Case(case) => Some(case),
+            _ => None,
+        })
+    }
+}
+
+impl BackedEnumDefinition {
+    pub fn cases(&self) -> impl Iterator<Item = &BackedEnumCaseDefinition> {
+        self.body.members.iter().filter_map(|member| match member {
+            BackedEnumMemberDefinition::Case(case) => Some(case),
+            _ => None,
+        })
+    }
+}
+
+impl BackedEnumTypeDefinition {
+    pub fn is_string(&self) -> bool {
+        matches!(self, Self::String(_, _))
+    }
+
+    pub fn is_int(&self) -> bool {
+        matches!(self, Self::Int(_, _))
+    }
+}
+
 impl Node for EnumDefinition {
     fn comments(&self) -> Option<&CommentGroup> {
         None
     }
 
-    fn initial_position(&self) -> usize {
+    fn span(&self) -> Span {
         match &self {
-            Self::Backed(definition) => definition.initial_position(),
-            Self::Unit(definition) => definition.initial_position(),
+            Self::Backed(definition) => definition.span(),
+            Self::Unit(definition) => definition.span(),
         }
     }
 
-    fn final_position(&self) -> usize {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
         match &self {
-            Self::Backed(definition) => definition.final_position(),
-            Self::Unit(definition) => definition.final_position(),
+            Self::Backed(definition) => vec![("definition", definition)],
+            Self::Unit(definition) => vec![("definition", definition)],
         }
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        match &self {
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
             Self::Backed(definition) => vec![definition],
             Self::Unit(definition) => vec![definition],
         }
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        vec![]
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::EnumDefinition
+    }
+
     fn get_description(&self) -> String {
         match &self {
             Self::Backed(definition) => definition.get_description(),
@@ -150,121 +223,181 @@ impl Node for UnitEnumDefinition {
         Some(&self.comments)
     }
 
-    fn initial_position(&self) -> usize {
-        if let Some(attributes) = self.attributes.first() {
+    fn span(&self) -> Span {
+        let initial_position = if let Some(attributes) = self.attributes.first() {
             attributes.initial_position()
         } else {
             self.r#enum.initial_position()
-        }
-    }
+        };
 
-    fn final_position(&self) -> usize {
-        self.body.right_brace + 1
+        Span::new(initial_position, self.body.right_brace + 1)
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        let mut children: Vec<&dyn Node> = vec![&self.r#enum, &self.name];
-
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut children: Vec<(&'static str, &dyn Node)> = vec![];
         for attribute in &self.attributes {
-            children.push(attribute);
+            children.push(("attributes", attribute));
         }
 
+        children.push(("enum", &self.r#enum));
+        children.push(("name", &self.name));
+
         if let Some(implements) = &self.implements {
+            children.push(("implements", implements));
+        }
+
+        children.push(("body", &self.body));
+
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = vec![];
+        for attribute in &mut self.attributes {
+            children.push(attribute);
+        }
+
+        children.push(&mut self.r#enum);
+        children.push(&mut self.name);
+
+        if let Some(implements) = &mut self.implements {
             children.push(implements);
         }
 
-        children.push(&self.body);
+        children.push(&mut self.body);
 
         children
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        self.comments.positions_mut().collect()
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::UnitEnumDefinition
+    }
+
     fn get_description(&self) -> String {
         "unit enum definition".to_string()
     }
 }
 
 impl Node for EnumImplementsDefinition {
-    fn initial_position(&self) -> usize {
-        self.implements.initial_position()
-    }
-
-    fn final_position(&self) -> usize {
-        if let Some(last_interface) = self.interfaces.inner.last() {
-            let last_interface_position = last_interface.final_position();
-            if let Some(last_comma) = self.interfaces.commas.last() {
-                let last_comma_position = last_comma + 1;
-                if last_comma_position > last_interface_position {
-                    return last_comma_position;
-                }
+    fn span(&self) -> Span {
+        // a trailing comma is part of the node.
+        let final_position = match (self.interfaces.inner.last(), self.interfaces.commas.last()) {
+            (Some(last_interface), Some(last_comma)) => {
+                last_interface.final_position().max(last_comma + 1)
             }
+            (Some(last_interface), None) => last_interface.final_position(),
+            (None, _) => self.implements.final_position(),
+        };
+
+        Span::new(self.implements.initial_position(), final_position)
+    }
 
-            return last_interface_position;
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut children: Vec<(&'static str, &dyn Node)> = vec![("implements", &self.implements)];
+
+        for interface in &self.interfaces.inner {
+            children.push(("interfaces", interface));
         }
 
-        self.implements.final_position()
+        children
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        let mut children: Vec<&dyn Node> = vec![&self.implements];
+    fn lists(&self) -> Vec<ListView<'_>> {
+        vec![self.interfaces.view()]
+    }
 
-        for interface in &self.interfaces.inner {
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = vec![&mut self.implements];
+
+        for interface in &mut self.interfaces.inner {
             children.push(interface);
         }
 
         children
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        self.interfaces.commas.iter_mut().collect()
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::EnumImplementsDefinition
+    }
+
     fn get_description(&self) -> String {
         "enum implements definition".to_string()
     }
 }
 
 impl Node for UnitEnumBodyDefinition {
-    fn initial_position(&self) -> usize {
-        self.left_brace
+    fn span(&self) -> Span {
+        Span::new(self.left_brace, self.right_brace + 1)
     }
 
-    fn final_position(&self) -> usize {
-        self.right_brace + 1
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        self.members
+            .iter()
+            .map(|member| ("members", member as &dyn Node))
+            .collect()
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         self.members
-            .iter()
-            .map(|member| member as &dyn Node)
+            .iter_mut()
+            .map(|member| member as &mut dyn Node)
             .collect()
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        vec![&mut self.left_brace, &mut self.right_brace]
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::UnitEnumBodyDefinition
+    }
+
     fn get_description(&self) -> String {
         "unit enum body definition".to_string()
     }
 }
 
 impl Node for UnitEnumMemberDefinition {
-    fn initial_position(&self) -> usize {
+    fn span(&self) -> Span {
         match &self {
-            Self::Case(case) => case.initial_position(),
-            Self::Method(method) => method.initial_position(),
-            Self::Constant(constant) => constant.initial_position(),
+            Self::Case(case) => case.span(),
+            Self::Method(method) => method.span(),
+            Self::Constant(constant) => constant.span(),
         }
     }
 
-    fn final_position(&self) -> usize {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
         match &self {
-            Self::Case(case) => case.final_position(),
-            Self::Method(method) => method.final_position(),
-            Self::Constant(constant) => constant.final_position(),
+            Self::Case(case) => vec![("case", case)],
+            Self::Method(method) => vec![("method", method)],
+            Self::Constant(constant) => vec![("constant", constant)],
         }
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        match &self {
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
             Self::Case(case) => vec![case],
             Self::Method(method) => vec![method],
             Self::Constant(constant) => vec![constant],
         }
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        vec![]
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::UnitEnumMemberDefinition
+    }
+
     fn get_description(&self) -> String {
         match &self {
             Self::Case(case) => case.get_description(),
@@ -275,27 +408,48 @@ impl Node for UnitEnumMemberDefinition {
 }
 
 impl Node for UnitEnumCaseDefinition {
-    fn initial_position(&self) -> usize {
-        if let Some(attributes) = self.attributes.first() {
+    fn span(&self) -> Span {
+        let initial_position = if let Some(attributes) = self.attributes.first() {
             attributes.initial_position()
         } else {
             self.case.initial_position()
-        }
-    }
+        };
 
-    fn final_position(&self) -> usize {
-        self.semicolon + 1
+        Span::new(initial_position, self.semicolon + 1)
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        let mut children: Vec<&dyn Node> = vec![&self.case];
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut children: Vec<(&'static str, &dyn Node)> = vec![];
         for attribute in &self.attributes {
+            children.push(("attributes", attribute));
+        }
+
+        children.push(("case", &self.case));
+        children.push(("name", &self.name));
+
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = vec![];
+        for attribute in &mut self.attributes {
             children.push(attribute);
         }
 
+        children.push(&mut self.case);
+        children.push(&mut self.name);
+
         children
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        vec![&mut self.semicolon]
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::UnitEnumCaseDefinition
+    }
+
     fn get_description(&self) -> String {
         "unit enum case definition".to_string()
     }
@@ -306,108 +460,176 @@ impl Node for BackedEnumDefinition {
         Some(&self.comments)
     }
 
-    fn initial_position(&self) -> usize {
-        if let Some(attributes) = self.attributes.first() {
+    fn span(&self) -> Span {
+        let initial_position = if let Some(attributes) = self.attributes.first() {
             attributes.initial_position()
         } else {
             self.r#enum.initial_position()
-        }
-    }
+        };
 
-    fn final_position(&self) -> usize {
-        self.body.right_brace + 1
+        Span::new(initial_position, self.body.right_brace + 1)
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        let mut children: Vec<&dyn Node> = vec![&self.r#enum, &self.name, &self.backed_type];
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut children: Vec<(&'static str, &dyn Node)> = vec![];
         for attribute in &self.attributes {
-            children.push(attribute);
+            children.push(("attributes", attribute));
         }
 
+        children.push(("enum", &self.r#enum));
+        children.push(("name", &self.name));
+        children.push(("backed_type", &self.backed_type));
+
         if let Some(implements) = &self.implements {
+            children.push(("implements", implements));
+        }
+
+        children.push(("body", &self.body));
+
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = vec![];
+        for attribute in &mut self.attributes {
+            children.push(attribute);
+        }
+
+        children.push(&mut self.r#enum);
+        children.push(&mut self.name);
+        children.push(&mut self.backed_type);
+
+        if let Some(implements) = &mut self.implements {
             children.push(implements);
         }
 
-        children.push(&self.body);
+        children.push(&mut self.body);
 
         children
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        self.comments.positions_mut().collect()
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::BackedEnumDefinition
+    }
+
     fn get_description(&self) -> String {
         "backed enum definition".to_string()
     }
 }
 
 impl Node for BackedEnumTypeDefinition {
-    fn initial_position(&self) -> usize {
-        match &self {
+    fn span(&self) -> Span {
+        let initial_position = match &self {
             Self::String(colon, _) | Self::Int(colon, _) => *colon,
-        }
+        };
+        let final_position = match &self {
+            Self::String(_, identifier) | Self::Int(_, identifier) => identifier.final_position(),
+        };
+
+        Span::new(initial_position, final_position)
     }
 
-    fn final_position(&self) -> usize {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
         match &self {
-            Self::String(_, identifier) | Self::Int(_, identifier) => identifier.final_position(),
+            Self::String(_, identifier) | Self::Int(_, identifier) => {
+                vec![("identifier", identifier)]
+            }
         }
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        match &self {
-            Self::String(_, identifier) | Self::Int(_, identifier) => vec![identifier],
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
+            Self::String(_, identifier) | Self::Int(_, identifier) => {
+                vec![identifier]
+            }
+        }
+    }
+
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        match self {
+            Self::String(position, _) => vec![position],
+            Self::Int(position, _) => vec![position],
         }
     }
 
+    fn kind(&self) -> NodeKind {
+        NodeKind::BackedEnumTypeDefinition
+    }
+
     fn get_description(&self) -> String {
         "backed enum type definition".to_string()
     }
 }
 
 impl Node for BackedEnumBodyDefinition {
-    fn initial_position(&self) -> usize {
-        self.left_brace
+    fn span(&self) -> Span {
+        Span::new(self.left_brace, self.right_brace + 1)
     }
 
-    fn final_position(&self) -> usize {
-        self.right_brace + 1
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        self.members
+            .iter()
+            .map(|member| ("members", member as &dyn Node))
+            .collect()
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         self.members
-            .iter()
-            .map(|member| member as &dyn Node)
+            .iter_mut()
+            .map(|member| member as &mut dyn Node)
             .collect()
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        vec![&mut self.left_brace, &mut self.right_brace]
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::BackedEnumBodyDefinition
+    }
+
     fn get_description(&self) -> String {
         "backed enum body definition".to_string()
     }
 }
 
 impl Node for BackedEnumMemberDefinition {
-    fn initial_position(&self) -> usize {
+    fn span(&self) -> Span {
         match &self {
-            Self::Case(case) => case.initial_position(),
-            Self::Method(method) => method.initial_position(),
-            Self::Constant(constant) => constant.initial_position(),
+            Self::Case(case) => case.span(),
+            Self::Method(method) => method.span(),
+            Self::Constant(constant) => constant.span(),
         }
     }
 
-    fn final_position(&self) -> usize {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
         match &self {
-            Self::Case(case) => case.final_position(),
-            Self::Method(method) => method.final_position(),
-            Self::Constant(constant) => constant.final_position(),
+            Self::Case(case) => vec![("case", case)],
+            Self::Method(method) => vec![("method", method)],
+            Self::Constant(constant) => vec![("constant", constant)],
         }
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        match &self {
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
             Self::Case(case) => vec![case],
             Self::Method(method) => vec![method],
             Self::Constant(constant) => vec![constant],
         }
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        vec![]
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::BackedEnumMemberDefinition
+    }
+
     fn get_description(&self) -> String {
         match &self {
             Self::Case(case) => case.get_description(),
@@ -418,20 +640,48 @@ impl Node for BackedEnumMemberDefinition {
 }
 
 impl Node for BackedEnumCaseDefinition {
-    fn initial_position(&self) -> usize {
-        if let Some(attributes) = self.attributes.first() {
+    fn span(&self) -> Span {
+        let initial_position = if let Some(attributes) = self.attributes.first() {
             attributes.initial_position()
         } else {
             self.case.initial_position()
+        };
+
+        Span::new(initial_position, self.semicolon + 1)
+    }
+
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut children: Vec<(&'static str, &dyn Node)> = vec![];
+        for attribute in &self.attributes {
+            children.push(("attributes", attribute));
         }
+
+        children.push(("case", &self.case));
+        children.push(("name", &self.name));
+        children.push(("value", &self.value));
+
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = vec![];
+        for attribute in &mut self.attributes {
+            children.push(attribute);
+        }
+
+        children.push(&mut self.case);
+        children.push(&mut self.name);
+        children.push(&mut self.value);
+
+        children
     }
 
-    fn final_position(&self) -> usize {
-        self.semicolon + 1
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        vec![&mut self.equals, &mut self.semicolon]
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        vec![&self.case, &self.name, &self.value]
+    fn kind(&self) -> NodeKind {
+        NodeKind::BackedEnumCaseDefinition
     }
 
     fn get_description(&self) -> String {
@@ -531,7 +781,7 @@ mod tests {
     #[test]
     fn test_enum_definition_display() {
         let backed = BackedEnumDefinition {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             attributes: vec![],
             r#enum: Keyword::new(ByteString::from("enum"), 0),
             name: Identifier {
@@ -556,7 +806,7 @@ mod tests {
         assert_eq!(backed.to_string(), "enum Foo: string { /* ... */ }");
 
         let unit = UnitEnumDefinition {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             attributes: vec![],
             r#enum: Keyword::new(ByteString::from("enum"), 0),
             name: Identifier {
@@ -573,4 +823,55 @@ mod tests {
 
         assert_eq!(unit.to_string(), "enum Foo { /* ... */ }");
     }
+
+    #[test]
+    fn test_enum_definition_cases() {
+        let definition = EnumDefinition::Unit(UnitEnumDefinition {
+            comments: CommentGroup::default(),
+            attributes: vec![],
+            r#enum: Keyword::new(ByteString::from("enum"), 0),
+            name: Identifier {
+                position: 5,
+                value: ByteString::from("Suit"),
+            },
+            implements: None,
+            body: UnitEnumBodyDefinition {
+                left_brace: 10,
+                members: vec![
+                    UnitEnumMemberDefinition::Case(UnitEnumCaseDefinition {
+                        attributes: vec![],
+                        case: Keyword::new(ByteString::from("case"), 12),
+                        name: Identifier {
+                            position: 17,
+                            value: ByteString::from("Hearts"),
+                        },
+                        semicolon: 23,
+                    }),
+                    UnitEnumMemberDefinition::Case(UnitEnumCaseDefinition {
+                        attributes: vec![],
+                        case: Keyword::new(ByteString::from("case"), 25),
+                        name: Identifier {
+                            position: 30,
+                            value: ByteString::from("Spades"),
+                        },
+                        semicolon: 36,
+                    }),
+                ],
+                right_brace: 38,
+            },
+        });
+
+        assert!(definition.is_unit_enum());
+        assert!(!definition.is_backed_enum());
+        assert!(definition.backed_type().is_none());
+        assert_eq!(definition.name().value, ByteString::from("Suit"));
+        assert_eq!(
+            definition
+                .cases()
+                .iter()
+                .map(|case| case.to_string())
+                .collect::<Vec<String>>(),
+            vec!["Hearts", "Spades"]
+        );
+    }
 }
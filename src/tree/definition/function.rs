@@ -8,12 +8,16 @@ use crate::tree::comment::CommentGroup;
 use crate::tree::definition::attribute::AttributeGroupDefinition;
 use crate::tree::definition::modifier::ModifierGroupDefinition;
 use crate::tree::definition::r#type::TypeDefinition;
+use crate::tree::definition::template::TemplateBoundsDefinition;
 use crate::tree::definition::template::TemplateGroupDefinition;
 use crate::tree::expression::Expression;
 use crate::tree::identifier::Identifier;
+use crate::tree::kind::NodeKind;
+use crate::tree::span::Span;
 use crate::tree::statement::block::BlockStatement;
 use crate::tree::token::Keyword;
 use crate::tree::utils::CommaSeparated;
+use crate::tree::utils::ListView;
 use crate::tree::variable::Variable;
 use crate::tree::Node;
 
@@ -29,6 +33,7 @@ pub struct FunctionLikeReturnTypeDefinition {
 pub struct FunctionLikeParameterDefinition {
     pub comments: CommentGroup,
     pub attributes: Vec<AttributeGroupDefinition>,
+    pub modifiers: ModifierGroupDefinition,
     pub type_definition: TypeDefinition,
     pub ellipsis: Option<usize>,
     pub variable: Variable,
@@ -62,7 +67,16 @@ pub struct FunctionDefinition {
     pub templates: Option<TemplateGroupDefinition>,
     pub parameters: FunctionLikeParameterListDefinition,
     pub return_type: FunctionLikeReturnTypeDefinition,
-    pub body: BlockStatement,
+    pub constraints: Option<MethodTypeConstraintGroupDefinition>,
+    pub body: FunctionBodyDefinition,
+}
+
+/// The body of a function, which can only be omitted in definition sources.
+#[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum FunctionBodyDefinition {
+    Concrete(BlockStatement),
+    Declaration(usize),
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
@@ -92,7 +106,7 @@ pub struct MethodTypeConstraintDefinition {
     pub comments: CommentGroup,
     pub identifier: Identifier,
     pub r#is: Keyword,
-    pub type_definition: TypeDefinition,
+    pub bounds: TemplateBoundsDefinition,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
@@ -126,16 +140,24 @@ pub struct MethodDefinition {
 }
 
 impl Node for FunctionLikeReturnTypeDefinition {
-    fn initial_position(&self) -> usize {
-        self.colon
+    fn span(&self) -> Span {
+        Span::new(self.colon, self.type_definition.final_position())
+    }
+
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("type_definition", &self.type_definition)]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        vec![&mut self.type_definition]
     }
 
-    fn final_position(&self) -> usize {
-        self.type_definition.final_position()
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        vec![&mut self.colon]
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        vec![&self.type_definition]
+    fn kind(&self) -> NodeKind {
+        NodeKind::FunctionLikeReturnTypeDefinition
     }
 
     fn get_description(&self) -> String {
@@ -148,48 +170,89 @@ impl Node for FunctionLikeParameterDefinition {
         Some(&self.comments)
     }
 
-    fn initial_position(&self) -> usize {
-        if let Some(attributes) = self.attributes.first() {
-            return attributes.initial_position();
-        }
+    fn span(&self) -> Span {
+        let initial_position = match self.attributes.first() {
+            Some(attributes) => attributes.initial_position(),
+            None => self.modifiers.initial_position(),
+        };
+        let final_position = match &self.default {
+            Some(default) => default.final_position(),
+            None => self.variable.final_position(),
+        };
 
-        self.type_definition.initial_position()
+        Span::new(initial_position, final_position)
     }
 
-    fn final_position(&self) -> usize {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut children: Vec<(&'static str, &dyn Node)> = vec![
+            ("modifiers", &self.modifiers),
+            ("type_definition", &self.type_definition),
+            ("variable", &self.variable),
+        ];
+
         if let Some(default) = &self.default {
-            return default.final_position();
+            children.push(("default", default));
         }
 
-        self.variable.final_position()
+        children
+    }
+
+    fn tokens(&self) -> Vec<&[u8]> {
+        match self.ellipsis {
+            Some(_) => vec![b"..."],
+            None => vec![],
+        }
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        let mut children: Vec<&dyn Node> = vec![&self.type_definition, &self.variable];
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = vec![
+            &mut self.modifiers,
+            &mut self.type_definition,
+            &mut self.variable,
+        ];
 
-        if let Some(default) = &self.default {
+        if let Some(default) = &mut self.default {
             children.push(default);
         }
 
         children
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        let mut positions: Vec<&mut usize> = vec![];
+        positions.extend(self.comments.positions_mut());
+        positions.extend(self.ellipsis.iter_mut());
+        positions
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::FunctionLikeParameterDefinition
+    }
+
     fn get_description(&self) -> String {
         "function like parameter definition".to_string()
     }
 }
 
 impl Node for FunctionLikeParameterDefaultValueDefinition {
-    fn initial_position(&self) -> usize {
-        self.equals
+    fn span(&self) -> Span {
+        Span::new(self.equals, self.value.final_position())
+    }
+
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("value", &self.value)]
     }
 
-    fn final_position(&self) -> usize {
-        self.value.final_position()
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        vec![&mut self.value]
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        vec![&self.value]
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        vec![&mut self.equals]
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::FunctionLikeParameterDefaultValueDefinition
     }
 
     fn get_description(&self) -> String {
@@ -202,24 +265,46 @@ impl Node for FunctionLikeParameterListDefinition {
         Some(&self.comments)
     }
 
-    fn initial_position(&self) -> usize {
-        self.left_parenthesis
+    fn span(&self) -> Span {
+        Span::new(self.left_parenthesis, self.right_parenthesis + 1)
     }
 
-    fn final_position(&self) -> usize {
-        self.right_parenthesis + 1
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut children: Vec<(&'static str, &dyn Node)> = vec![];
+
+        for parameter in &self.parameters.inner {
+            children.push(("parameters", parameter));
+        }
+
+        children
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        let mut children: Vec<&dyn Node> = vec![];
+    fn lists(&self) -> Vec<ListView<'_>> {
+        vec![self.parameters.view()]
+    }
 
-        for parameter in &self.parameters.inner {
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = vec![];
+
+        for parameter in &mut self.parameters.inner {
             children.push(parameter);
         }
 
         children
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        let mut positions: Vec<&mut usize> =
+            vec![&mut self.left_parenthesis, &mut self.right_parenthesis];
+        positions.extend(self.comments.positions_mut());
+        positions.extend(self.parameters.commas.iter_mut());
+        positions
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::FunctionLikeParameterListDefinition
+    }
+
     fn get_description(&self) -> String {
         "function like parameter list definition".to_string()
     }
@@ -230,40 +315,78 @@ impl Node for FunctionDefinition {
         Some(&self.comments)
     }
 
-    fn initial_position(&self) -> usize {
-        if let Some(attributes) = self.attributes.first() {
-            return attributes.initial_position();
-        }
+    fn span(&self) -> Span {
+        let initial_position = match self.attributes.first() {
+            Some(attributes) => attributes.initial_position(),
+            None if !self.modifiers.modifiers.is_empty() => self.modifiers.initial_position(),
+            None => self.function.initial_position(),
+        };
 
-        self.function.initial_position()
+        Span::new(initial_position, self.body.final_position())
     }
 
-    fn final_position(&self) -> usize {
-        self.body.final_position()
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut children: Vec<(&'static str, &dyn Node)> = vec![];
+
+        for attribute in &self.attributes {
+            children.push(("attributes", attribute));
+        }
+
+        children.push(("modifiers", &self.modifiers));
+        children.push(("function", &self.function));
+        children.push(("name", &self.name));
+
+        if let Some(templates) = &self.templates {
+            children.push(("templates", templates));
+        }
+
+        children.push(("parameters", &self.parameters));
+        children.push(("return_type", &self.return_type));
+
+        if let Some(constraints) = &self.constraints {
+            children.push(("constraints", constraints));
+        }
+
+        children.push(("body", &self.body));
+
+        children
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        let mut children: Vec<&dyn Node> = vec![];
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = vec![];
 
-        for attribute in &self.attributes {
+        for attribute in &mut self.attributes {
             children.push(attribute);
         }
 
-        children.push(&self.function);
-        children.push(&self.name);
+        children.push(&mut self.modifiers);
+        children.push(&mut self.function);
+        children.push(&mut self.name);
 
-        if let Some(templates) = &self.templates {
+        if let Some(templates) = &mut self.templates {
             children.push(templates);
         }
 
-        children.push(&self.modifiers);
-        children.push(&self.parameters);
-        children.push(&self.return_type);
-        children.push(&self.body);
+        children.push(&mut self.parameters);
+        children.push(&mut self.return_type);
+
+        if let Some(constraints) = &mut self.constraints {
+            children.push(constraints);
+        }
+
+        children.push(&mut self.body);
 
         children
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        self.comments.positions_mut().collect()
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::FunctionDefinition
+    }
+
     fn get_description(&self) -> String {
         "function definition".to_string()
     }
@@ -274,32 +397,65 @@ impl Node for MethodParameterDefinition {
         Some(&self.comments)
     }
 
-    fn initial_position(&self) -> usize {
-        if let Some(attributes) = self.attributes.first() {
-            return attributes.initial_position();
-        }
+    fn span(&self) -> Span {
+        let initial_position = match self.attributes.first() {
+            Some(attributes) => attributes.initial_position(),
+            None => self.modifiers.initial_position(),
+        };
+        let final_position = match &self.default {
+            Some(default) => default.final_position(),
+            None => self.variable.final_position(),
+        };
 
-        self.modifiers.initial_position()
+        Span::new(initial_position, final_position)
     }
 
-    fn final_position(&self) -> usize {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut children: Vec<(&'static str, &dyn Node)> = vec![
+            ("modifiers", &self.modifiers),
+            ("type_definition", &self.type_definition),
+            ("variable", &self.variable),
+        ];
+
         if let Some(default) = &self.default {
-            return default.final_position();
+            children.push(("default", default));
         }
 
-        self.variable.final_position()
+        children
+    }
+
+    fn tokens(&self) -> Vec<&[u8]> {
+        match self.ellipsis {
+            Some(_) => vec![b"..."],
+            None => vec![],
+        }
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        let mut children: Vec<&dyn Node> = vec![&self.type_definition, &self.variable];
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = vec![
+            &mut self.modifiers,
+            &mut self.type_definition,
+            &mut self.variable,
+        ];
 
-        if let Some(default) = &self.default {
+        if let Some(default) = &mut self.default {
             children.push(default);
         }
 
         children
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        let mut positions: Vec<&mut usize> = vec![];
+        positions.extend(self.comments.positions_mut());
+        positions.extend(self.ellipsis.iter_mut());
+        positions
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::MethodParameterDefinition
+    }
+
     fn get_description(&self) -> String {
         "method parameter definition".to_string()
     }
@@ -310,40 +466,77 @@ impl Node for MethodParameterListDefinition {
         Some(&self.comments)
     }
 
-    fn initial_position(&self) -> usize {
-        self.left_parenthesis
+    fn span(&self) -> Span {
+        Span::new(self.left_parenthesis, self.right_parenthesis + 1)
+    }
+
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut children: Vec<(&'static str, &dyn Node)> = vec![];
+
+        for parameter in &self.parameters.inner {
+            children.push(("parameters", parameter));
+        }
+
+        children
     }
 
-    fn final_position(&self) -> usize {
-        self.right_parenthesis + 1
+    fn lists(&self) -> Vec<ListView<'_>> {
+        vec![self.parameters.view()]
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        let mut children: Vec<&dyn Node> = vec![];
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = vec![];
 
-        for parameter in &self.parameters.inner {
+        for parameter in &mut self.parameters.inner {
             children.push(parameter);
         }
 
         children
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        let mut positions: Vec<&mut usize> =
+            vec![&mut self.left_parenthesis, &mut self.right_parenthesis];
+        positions.extend(self.comments.positions_mut());
+        positions.extend(self.parameters.commas.iter_mut());
+        positions
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::MethodParameterListDefinition
+    }
+
     fn get_description(&self) -> String {
         "method parameter list definition".to_string()
     }
 }
 
 impl Node for MethodTypeConstraintDefinition {
-    fn initial_position(&self) -> usize {
-        self.identifier.initial_position()
+    fn span(&self) -> Span {
+        Span::new(
+            self.identifier.initial_position(),
+            self.bounds.final_position(),
+        )
     }
 
-    fn final_position(&self) -> usize {
-        self.type_definition.final_position()
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![
+            ("identifier", &self.identifier),
+            ("is", &self.r#is),
+            ("bounds", &self.bounds),
+        ]
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        vec![&self.identifier, &self.r#is, &self.type_definition]
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        vec![&mut self.identifier, &mut self.r#is, &mut self.bounds]
+    }
+
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        self.comments.positions_mut().collect()
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::MethodTypeConstraintDefinition
     }
 
     fn get_description(&self) -> String {
@@ -356,63 +549,133 @@ impl Node for MethodTypeConstraintGroupDefinition {
         Some(&self.comments)
     }
 
-    fn initial_position(&self) -> usize {
-        self.r#where.initial_position()
+    fn span(&self) -> Span {
+        // a trailing comma is part of the node.
+        let final_position = match (
+            self.constraints.inner.last(),
+            self.constraints.commas.last(),
+        ) {
+            (Some(last_constraint), Some(last_comma)) => {
+                last_constraint.final_position().max(last_comma + 1)
+            }
+            (Some(last_constraint), None) => last_constraint.final_position(),
+            (None, _) => self.r#where.final_position(),
+        };
+
+        Span::new(self.r#where.initial_position(), final_position)
     }
 
-    fn final_position(&self) -> usize {
-        if let Some(last_constraint) = self.constraints.inner.last() {
-            let last_constraint_position = last_constraint.final_position();
-            if let Some(last_comma) = self.constraints.commas.last() {
-                let last_comma_position = last_comma + 1;
-                if last_comma_position >= last_constraint_position {
-                    return last_comma_position;
-                }
-            }
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut children: Vec<(&'static str, &dyn Node)> = vec![("where", &self.r#where)];
 
-            last_constraint_position
-        } else {
-            self.r#where.final_position()
+        for constraint in &self.constraints.inner {
+            children.push(("constraints", constraint));
         }
+
+        children
+    }
+
+    fn lists(&self) -> Vec<ListView<'_>> {
+        vec![self.constraints.view()]
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        let mut children: Vec<&dyn Node> = vec![&self.r#where];
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = vec![&mut self.r#where];
 
-        for constraint in &self.constraints.inner {
+        for constraint in &mut self.constraints.inner {
             children.push(constraint);
         }
 
         children
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        let mut positions: Vec<&mut usize> = vec![];
+        positions.extend(self.comments.positions_mut());
+        positions.extend(self.constraints.commas.iter_mut());
+        positions
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::MethodTypeConstraintGroupDefinition
+    }
+
     fn get_description(&self) -> String {
         "method type constraint group definition".to_string()
     }
 }
 
-impl Node for MethodBodyDefinition {
-    fn initial_position(&self) -> usize {
+impl Node for FunctionBodyDefinition {
+    fn span(&self) -> Span {
+        match &self {
+            FunctionBodyDefinition::Concrete(block) => block.span(),
+            FunctionBodyDefinition::Declaration(semicolon) => Span::new(*semicolon, semicolon + 1),
+        }
+    }
+
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
         match &self {
-            MethodBodyDefinition::Concrete(block) => block.initial_position(),
-            MethodBodyDefinition::Abstract(semicolon) => *semicolon,
+            FunctionBodyDefinition::Concrete(block) => vec![("block", block)],
+            FunctionBodyDefinition::Declaration(..) => vec![],
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
+            FunctionBodyDefinition::Concrete(block) => vec![block],
+            FunctionBodyDefinition::Declaration(..) => vec![],
+        }
+    }
+
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        match self {
+            Self::Declaration(position) => vec![position],
+            _ => vec![],
         }
     }
 
-    fn final_position(&self) -> usize {
+    fn kind(&self) -> NodeKind {
+        NodeKind::FunctionBodyDefinition
+    }
+
+    fn get_description(&self) -> String {
+        "function body definition".to_string()
+    }
+}
+
+impl Node for MethodBodyDefinition {
+    fn span(&self) -> Span {
         match &self {
-            MethodBodyDefinition::Concrete(block) => block.final_position(),
-            MethodBodyDefinition::Abstract(semicolon) => semicolon + 1,
+            MethodBodyDefinition::Concrete(block) => block.span(),
+            MethodBodyDefinition::Abstract(semicolon) => Span::new(*semicolon, semicolon + 1),
         }
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
         match &self {
+            MethodBodyDefinition::Concrete(block) => vec![("block", block)],
+            MethodBodyDefinition::Abstract(..) => vec![],
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
             MethodBodyDefinition::Concrete(block) => vec![block],
             MethodBodyDefinition::Abstract(..) => vec![],
         }
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        match self {
+            Self::Abstract(position) => vec![position],
+            _ => vec![],
+        }
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::MethodBodyDefinition
+    }
+
     fn get_description(&self) -> String {
         "method body definition".to_string()
     }
@@ -423,47 +686,81 @@ impl Node for MethodDefinition {
         Some(&self.comments)
     }
 
-    fn initial_position(&self) -> usize {
-        if let Some(attributes) = self.attributes.first() {
-            return attributes.initial_position();
-        }
+    fn span(&self) -> Span {
+        let initial_position = match self.attributes.first() {
+            Some(attributes) => attributes.initial_position(),
+            None => self.modifiers.initial_position(),
+        };
 
-        self.modifiers.initial_position()
+        Span::new(initial_position, self.body.final_position())
     }
 
-    fn final_position(&self) -> usize {
-        self.body.final_position()
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut children: Vec<(&'static str, &dyn Node)> = vec![];
+
+        for attribute in &self.attributes {
+            children.push(("attributes", attribute));
+        }
+
+        children.push(("modifiers", &self.modifiers));
+        children.push(("function", &self.function));
+        children.push(("name", &self.name));
+
+        if let Some(templates) = &self.templates {
+            children.push(("templates", templates));
+        }
+
+        children.push(("parameters", &self.parameters));
+        if let Some(return_type) = &self.return_type {
+            children.push(("return_type", return_type));
+        }
+
+        if let Some(constraints) = &self.constraints {
+            children.push(("constraints", constraints));
+        }
+
+        children.push(("body", &self.body));
+
+        children
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        let mut children: Vec<&dyn Node> = vec![];
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = vec![];
 
-        for attribute in &self.attributes {
+        for attribute in &mut self.attributes {
             children.push(attribute);
         }
 
-        children.push(&self.modifiers);
-        children.push(&self.function);
-        children.push(&self.name);
+        children.push(&mut self.modifiers);
+        children.push(&mut self.function);
+        children.push(&mut self.name);
 
-        if let Some(templates) = &self.templates {
+        if let Some(templates) = &mut self.templates {
             children.push(templates);
         }
 
-        children.push(&self.parameters);
-        if let Some(return_type) = &self.return_type {
+        children.push(&mut self.parameters);
+        if let Some(return_type) = &mut self.return_type {
             children.push(return_type);
         }
 
-        if let Some(constraints) = &self.constraints {
+        if let Some(constraints) = &mut self.constraints {
             children.push(constraints);
         }
 
-        children.push(&self.body);
+        children.push(&mut self.body);
 
         children
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        self.comments.positions_mut().collect()
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::MethodDefinition
+    }
+
     fn get_description(&self) -> String {
         "concrete method definition".to_string()
     }
@@ -477,6 +774,10 @@ impl std::fmt::Display for FunctionLikeReturnTypeDefinition {
 
 impl std::fmt::Display for FunctionLikeParameterDefinition {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if !self.modifiers.modifiers.is_empty() {
+            write!(f, "{} ", self.modifiers)?;
+        }
+
         write!(f, "{}", self.type_definition)?;
 
         if self.ellipsis.is_some() {
@@ -517,13 +818,21 @@ impl std::fmt::Display for FunctionDefinition {
             write!(f, "{}", templates)?;
         }
 
-        write!(f, "{}{} {}", self.parameters, self.return_type, self.body)
+        write!(f, "{}{}", self.parameters, self.return_type)?;
+
+        if let Some(constraints) = &self.constraints {
+            write!(f, " {}", constraints)?;
+        }
+
+        write!(f, "{}", self.body)
     }
 }
 
 impl std::fmt::Display for MethodParameterDefinition {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.modifiers)?;
+        if !self.modifiers.modifiers.is_empty() {
+            write!(f, "{} ", self.modifiers)?;
+        }
 
         write!(f, "{} {}", self.type_definition, self.variable,)?;
 
@@ -543,11 +852,7 @@ impl std::fmt::Display for MethodParameterListDefinition {
 
 impl std::fmt::Display for MethodTypeConstraintDefinition {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{} {} {}",
-            self.type_definition, self.r#is, self.type_definition
-        )
+        write!(f, "{} {} {}", self.identifier, self.r#is, self.bounds)
     }
 }
 
@@ -557,6 +862,15 @@ impl std::fmt::Display for MethodTypeConstraintGroupDefinition {
     }
 }
 
+impl std::fmt::Display for FunctionBodyDefinition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self {
+            FunctionBodyDefinition::Concrete(block) => write!(f, " {}", block),
+            FunctionBodyDefinition::Declaration(..) => write!(f, ";"),
+        }
+    }
+}
+
 impl std::fmt::Display for MethodBodyDefinition {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match &self {
@@ -581,7 +895,7 @@ impl std::fmt::Display for MethodDefinition {
         }
 
         if let Some(constraints) = &self.constraints {
-            write!(f, "{}", constraints)?;
+            write!(f, " {}", constraints)?;
         }
 
         write!(f, " {}", self.body)
@@ -597,7 +911,9 @@ mod tests {
     use crate::tree::definition::template::TemplateDefinition;
     use crate::tree::definition::template::TemplateDefinitionTypeConstraint;
     use crate::tree::definition::template::TemplateDefinitionVariance;
+    use crate::tree::definition::template::TemplateGroupDefinitionMember;
     use crate::tree::identifier::TemplatedIdentifier;
+    use smallvec::smallvec;
 
     #[test]
     fn test_function_definition_display() {
@@ -616,12 +932,12 @@ mod tests {
             },
             templates: None,
             parameters: FunctionLikeParameterListDefinition {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 left_parenthesis: 0,
                 parameters: CommaSeparated {
                     inner: vec![FunctionLikeParameterDefinition {
                         attributes: vec![],
-                        comments: CommentGroup { comments: vec![] },
+                        comments: CommentGroup::default(),
                         type_definition: TypeDefinition::SignedInteger(
                             SignedIntegerTypeDefinition::I32(Keyword::new(
                                 ByteString::from("i32"),
@@ -634,8 +950,12 @@ mod tests {
                             name: ByteString::from("foo"),
                         },
                         default: None,
+                        modifiers: ModifierGroupDefinition {
+                            position: 0,
+                            modifiers: vec![],
+                        },
                     }],
-                    commas: vec![],
+                    commas: smallvec![],
                 },
                 right_parenthesis: 0,
             },
@@ -645,14 +965,15 @@ mod tests {
                     Keyword::new(ByteString::from("i64"), 15),
                 )),
             },
-            body: BlockStatement {
-                comments: CommentGroup { comments: vec![] },
+            body: FunctionBodyDefinition::Concrete(BlockStatement {
+                comments: CommentGroup::default(),
                 left_brace: 0,
                 statements: vec![],
                 right_brace: 0,
-            },
-            comments: CommentGroup { comments: vec![] },
+            }),
+            comments: CommentGroup::default(),
             attributes: vec![],
+            constraints: None,
         };
 
         assert_eq!(
@@ -677,39 +998,42 @@ mod tests {
                 value: ByteString::from("Foo"),
             },
             templates: Some(TemplateGroupDefinition {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 less_than: 0,
                 members: CommaSeparated {
                     inner: vec![
-                        TemplateDefinition {
+                        TemplateGroupDefinitionMember::Type(TemplateDefinition {
                             variance: TemplateDefinitionVariance::Invaraint,
+                            ellipsis: None,
                             name: Identifier {
                                 position: 1,
                                 value: ByteString::from("T"),
                             },
                             constraint: TemplateDefinitionTypeConstraint::None,
-                        },
-                        TemplateDefinition {
+                        }),
+                        TemplateGroupDefinitionMember::Type(TemplateDefinition {
                             variance: TemplateDefinitionVariance::Invaraint,
+                            ellipsis: None,
                             name: Identifier {
                                 position: 1,
                                 value: ByteString::from("U"),
                             },
                             constraint: TemplateDefinitionTypeConstraint::None,
-                        },
+                        }),
                     ],
-                    commas: vec![],
+                    commas: smallvec![],
                 },
                 greater_than: 4,
+                docblock: None,
             }),
             parameters: MethodParameterListDefinition {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 left_parenthesis: 0,
                 parameters: CommaSeparated {
                     inner: vec![
                         MethodParameterDefinition {
                             attributes: vec![],
-                            comments: CommentGroup { comments: vec![] },
+                            comments: CommentGroup::default(),
                             modifiers: ModifierGroupDefinition {
                                 position: 0,
                                 modifiers: vec![],
@@ -730,7 +1054,7 @@ mod tests {
                         },
                         MethodParameterDefinition {
                             attributes: vec![],
-                            comments: CommentGroup { comments: vec![] },
+                            comments: CommentGroup::default(),
                             modifiers: ModifierGroupDefinition {
                                 position: 0,
                                 modifiers: vec![],
@@ -750,19 +1074,19 @@ mod tests {
                             default: None,
                         },
                     ],
-                    commas: vec![],
+                    commas: smallvec![],
                 },
                 right_parenthesis: 0,
             },
             return_type: None,
             constraints: None,
             body: MethodBodyDefinition::Concrete(BlockStatement {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 left_brace: 0,
                 statements: vec![],
                 right_brace: 0,
             }),
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             attributes: vec![],
         };
 
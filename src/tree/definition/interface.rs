@@ -11,8 +11,11 @@ use crate::tree::definition::function::MethodDefinition;
 use crate::tree::definition::template::TemplateGroupDefinition;
 use crate::tree::identifier::Identifier;
 use crate::tree::identifier::TemplatedIdentifier;
+use crate::tree::kind::NodeKind;
+use crate::tree::span::Span;
 use crate::tree::token::Keyword;
 use crate::tree::utils::CommaSeparated;
+use crate::tree::utils::ListView;
 use crate::tree::Node;
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
@@ -24,6 +27,7 @@ pub struct InterfaceDefinition {
     pub name: Identifier,
     pub templates: Option<TemplateGroupDefinition>,
     pub extends: Option<InterfaceDefinitionExtends>,
+    pub permits: Option<InterfaceDefinitionPermits>,
     pub body: InterfaceDefinitionBody,
 }
 
@@ -34,6 +38,13 @@ pub struct InterfaceDefinitionExtends {
     pub parents: CommaSeparated<TemplatedIdentifier>,
 }
 
+#[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct InterfaceDefinitionPermits {
+    pub permits: Keyword,
+    pub permitted: CommaSeparated<Identifier>,
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub struct InterfaceDefinitionBody {
@@ -44,6 +55,7 @@ pub struct InterfaceDefinitionBody {
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
 #[serde(rename_all = "snake_case", tag = "type", content = "value")]
+#[allow(clippy::large_enum_variant)]
 pub enum InterfaceDefinitionMember {
     Constant(ClassishConstantDefinition),
     Method(MethodDefinition),
@@ -54,92 +66,206 @@ impl Node for InterfaceDefinition {
         Some(&self.comments)
     }
 
-    fn initial_position(&self) -> usize {
-        if let Some(attributes) = self.attributes.first() {
+    fn span(&self) -> Span {
+        let initial_position = if let Some(attributes) = self.attributes.first() {
             attributes.initial_position()
         } else {
             self.interface.initial_position()
-        }
-    }
+        };
 
-    fn final_position(&self) -> usize {
-        self.body.right_brace + 1
+        Span::new(initial_position, self.body.right_brace + 1)
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        let mut children: Vec<&dyn Node> = vec![];
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut children: Vec<(&'static str, &dyn Node)> = vec![];
 
         for attribute in &self.attributes {
-            children.push(attribute);
+            children.push(("attributes", attribute));
         }
 
-        children.push(&self.interface);
-        children.push(&self.name);
+        children.push(("interface", &self.interface));
+        children.push(("name", &self.name));
 
         if let Some(templates) = &self.templates {
-            children.push(templates);
+            children.push(("templates", templates));
         }
 
         if let Some(extends) = &self.extends {
+            children.push(("extends", extends));
+        }
+
+        if let Some(permits) = &self.permits {
+            children.push(("permits", permits));
+        }
+
+        children.push(("body", &self.body));
+
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = vec![];
+
+        for attribute in &mut self.attributes {
+            children.push(attribute);
+        }
+
+        children.push(&mut self.interface);
+        children.push(&mut self.name);
+
+        if let Some(templates) = &mut self.templates {
+            children.push(templates);
+        }
+
+        if let Some(extends) = &mut self.extends {
             children.push(extends);
         }
 
-        children.push(&self.body);
+        if let Some(permits) = &mut self.permits {
+            children.push(permits);
+        }
+
+        children.push(&mut self.body);
 
         children
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        self.comments.positions_mut().collect()
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::InterfaceDefinition
+    }
+
     fn get_description(&self) -> String {
         "interface definition".to_string()
     }
 }
 
 impl Node for InterfaceDefinitionExtends {
-    fn initial_position(&self) -> usize {
-        self.extends.initial_position()
-    }
-
-    fn final_position(&self) -> usize {
-        if let Some(last_interface) = self.parents.inner.last() {
-            let last_interface_position = last_interface.final_position();
-            if let Some(last_comma) = self.parents.commas.last() {
-                let last_comma_position = last_comma + 1;
-                if last_comma_position > last_interface_position {
-                    return last_comma_position;
-                }
+    fn span(&self) -> Span {
+        // a trailing comma is part of the node.
+        let final_position = match (self.parents.inner.last(), self.parents.commas.last()) {
+            (Some(last_interface), Some(last_comma)) => {
+                last_interface.final_position().max(last_comma + 1)
             }
+            (Some(last_interface), None) => last_interface.final_position(),
+            (None, _) => self.extends.final_position(),
+        };
 
-            return last_interface_position;
+        Span::new(self.extends.initial_position(), final_position)
+    }
+
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut children: Vec<(&'static str, &dyn Node)> = vec![("extends", &self.extends)];
+        for parent in &self.parents.inner {
+            children.push(("parents", parent));
         }
 
-        self.extends.final_position()
+        children
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        let mut children: Vec<&dyn Node> = vec![&self.extends];
-        for parent in &self.parents.inner {
+    fn lists(&self) -> Vec<ListView<'_>> {
+        vec![self.parents.view()]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = vec![&mut self.extends];
+        for parent in &mut self.parents.inner {
             children.push(parent);
         }
 
         children
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        self.parents.commas.iter_mut().collect()
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::InterfaceDefinitionExtends
+    }
+
     fn get_description(&self) -> String {
         "interface extends definition".to_string()
     }
 }
 
+impl Node for InterfaceDefinitionPermits {
+    fn span(&self) -> Span {
+        // a trailing comma is part of the node.
+        let final_position = match (self.permitted.inner.last(), self.permitted.commas.last()) {
+            (Some(last_permitted), Some(last_comma)) => {
+                last_permitted.final_position().max(last_comma + 1)
+            }
+            (Some(last_permitted), None) => last_permitted.final_position(),
+            (None, _) => self.permits.final_position(),
+        };
+
+        Span::new(self.permits.initial_position(), final_position)
+    }
+
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut children: Vec<(&'static str, &dyn Node)> = vec![("permits", &self.permits)];
+        for permitted in &self.permitted.inner {
+            children.push(("permitted", permitted));
+        }
+
+        children
+    }
+
+    fn lists(&self) -> Vec<ListView<'_>> {
+        vec![self.permitted.view()]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = vec![&mut self.permits];
+        for permitted in &mut self.permitted.inner {
+            children.push(permitted);
+        }
+
+        children
+    }
+
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        self.permitted.commas.iter_mut().collect()
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::InterfaceDefinitionPermits
+    }
+
+    fn get_description(&self) -> String {
+        "interface permits definition".to_string()
+    }
+}
+
 impl Node for InterfaceDefinitionBody {
-    fn initial_position(&self) -> usize {
-        self.left_brace
+    fn span(&self) -> Span {
+        Span::new(self.left_brace, self.right_brace + 1)
+    }
+
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        self.members
+            .iter()
+            .map(|item| ("members", item as &dyn Node))
+            .collect()
     }
 
-    fn final_position(&self) -> usize {
-        self.right_brace + 1
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        self.members
+            .iter_mut()
+            .map(|item| item as &mut dyn Node)
+            .collect()
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        self.members.iter().map(|item| item as &dyn Node).collect()
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        vec![&mut self.left_brace, &mut self.right_brace]
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::InterfaceDefinitionBody
     }
 
     fn get_description(&self) -> String {
@@ -148,27 +274,35 @@ impl Node for InterfaceDefinitionBody {
 }
 
 impl Node for InterfaceDefinitionMember {
-    fn initial_position(&self) -> usize {
+    fn span(&self) -> Span {
         match &self {
-            Self::Constant(constant) => constant.initial_position(),
-            Self::Method(method) => method.initial_position(),
+            Self::Constant(constant) => constant.span(),
+            Self::Method(method) => method.span(),
         }
     }
 
-    fn final_position(&self) -> usize {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
         match &self {
-            Self::Constant(constant) => constant.final_position(),
-            Self::Method(method) => method.final_position(),
+            Self::Constant(constant) => vec![("constant", constant)],
+            Self::Method(method) => vec![("method", method)],
         }
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        match &self {
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
             Self::Constant(constant) => vec![constant],
             Self::Method(method) => vec![method],
         }
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        vec![]
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::InterfaceDefinitionMember
+    }
+
     fn get_description(&self) -> String {
         match &self {
             Self::Constant(constant) => constant.get_description(),
@@ -189,6 +323,10 @@ impl std::fmt::Display for InterfaceDefinition {
             write!(f, " {}", extends)?;
         }
 
+        if let Some(permits) = &self.permits {
+            write!(f, " {}", permits)?;
+        }
+
         write!(f, " {}", self.body)
     }
 }
@@ -199,6 +337,12 @@ impl std::fmt::Display for InterfaceDefinitionExtends {
     }
 }
 
+impl std::fmt::Display for InterfaceDefinitionPermits {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.permits, self.permitted)
+    }
+}
+
 impl std::fmt::Display for InterfaceDefinitionBody {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{{ /* ... */ }}")
@@ -219,14 +363,17 @@ mod tests {
     use super::*;
     use crate::lexer::byte_string::ByteString;
     use crate::tree::definition::r#type::TypeDefinition;
+    use crate::tree::definition::template::TemplateBoundsDefinition;
     use crate::tree::definition::template::TemplateDefinition;
     use crate::tree::definition::template::TemplateDefinitionTypeConstraint;
     use crate::tree::definition::template::TemplateDefinitionVariance;
+    use crate::tree::definition::template::TemplateGroupDefinitionMember;
+    use smallvec::smallvec;
 
     #[test]
     pub fn test_interface_definition_display() {
         let interface_definition = InterfaceDefinition {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             attributes: vec![],
             interface: Keyword::new(ByteString::from("interface"), 0),
             name: Identifier {
@@ -234,11 +381,12 @@ mod tests {
                 value: ByteString::from("Foo"),
             },
             templates: Some(TemplateGroupDefinition {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 less_than: 0,
                 members: CommaSeparated {
-                    inner: vec![TemplateDefinition {
+                    inner: vec![TemplateGroupDefinitionMember::Type(TemplateDefinition {
                         variance: TemplateDefinitionVariance::Invaraint,
+                        ellipsis: None,
                         name: Identifier {
                             position: 1,
                             value: ByteString::from("U"),
@@ -248,18 +396,22 @@ mod tests {
                                 value: ByteString::from("as"),
                                 position: 2,
                             },
-                            TypeDefinition::Identifier(TemplatedIdentifier {
-                                name: Identifier {
-                                    position: 3,
-                                    value: ByteString::from("IFoo"),
-                                },
-                                templates: None,
-                            }),
+                            TemplateBoundsDefinition {
+                                inner: vec![TypeDefinition::Identifier(TemplatedIdentifier {
+                                    name: Identifier {
+                                        position: 3,
+                                        value: ByteString::from("IFoo"),
+                                    },
+                                    templates: None,
+                                })],
+                                pluses: vec![],
+                            },
                         ),
-                    }],
-                    commas: vec![],
+                    })],
+                    commas: smallvec![],
                 },
                 greater_than: 0,
+                docblock: None,
             }),
             extends: Some(InterfaceDefinitionExtends {
                 extends: Keyword::new(ByteString::from("extends"), 0),
@@ -271,9 +423,10 @@ mod tests {
                         },
                         templates: None,
                     }],
-                    commas: vec![],
+                    commas: smallvec![],
                 },
             }),
+            permits: None,
             body: InterfaceDefinitionBody {
                 left_brace: 0,
                 members: vec![],
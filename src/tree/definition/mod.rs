@@ -9,9 +9,12 @@ use crate::tree::definition::constant::ConstantDefinition;
 use crate::tree::definition::function::FunctionDefinition;
 use crate::tree::definition::interface::InterfaceDefinition;
 use crate::tree::definition::namespace::NamespaceDefinition;
+use crate::tree::definition::pragma::PragmaDefinition;
 use crate::tree::definition::r#enum::EnumDefinition;
 use crate::tree::definition::r#type::TypeAliasDefinition;
 use crate::tree::definition::r#use::UseDefinition;
+use crate::tree::kind::NodeKind;
+use crate::tree::span::Span;
 use crate::tree::Node;
 
 pub mod attribute;
@@ -22,6 +25,7 @@ pub mod function;
 pub mod interface;
 pub mod modifier;
 pub mod namespace;
+pub mod pragma;
 pub mod property;
 pub mod template;
 pub mod r#type;
@@ -37,6 +41,7 @@ pub struct DefinitionTree {
 #[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
 #[serde(rename_all = "snake_case", tag = "type", content = "value")]
 pub enum Definition {
+    Pragma(Box<PragmaDefinition>),
     Namespace(Box<NamespaceDefinition>),
     Use(Box<UseDefinition>),
     TypeAlias(Box<TypeAliasDefinition>),
@@ -48,16 +53,30 @@ pub enum Definition {
 }
 
 impl Node for DefinitionTree {
-    fn initial_position(&self) -> usize {
-        0
+    fn span(&self) -> Span {
+        Span::new(0, self.eof)
     }
 
-    fn final_position(&self) -> usize {
-        self.eof
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        self.definitions
+            .iter()
+            .map(|d| ("definitions", d as &dyn Node))
+            .collect()
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        self.definitions.iter().map(|d| d as &dyn Node).collect()
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        self.definitions
+            .iter_mut()
+            .map(|d| d as &mut dyn Node)
+            .collect()
+    }
+
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        vec![&mut self.eof]
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::DefinitionTree
     }
 
     fn get_description(&self) -> String {
@@ -66,47 +85,59 @@ impl Node for DefinitionTree {
 }
 
 impl Node for Definition {
-    fn initial_position(&self) -> usize {
+    fn span(&self) -> Span {
         match &self {
-            Self::Namespace(definition) => definition.initial_position(),
-            Self::Use(definition) => definition.initial_position(),
-            Self::TypeAlias(definition) => definition.initial_position(),
-            Self::Constant(definition) => definition.initial_position(),
-            Self::Function(definition) => definition.initial_position(),
-            Self::Interface(definition) => definition.initial_position(),
-            Self::Enum(definition) => definition.initial_position(),
-            Self::Class(definition) => definition.initial_position(),
+            Self::Pragma(definition) => definition.span(),
+            Self::Namespace(definition) => definition.span(),
+            Self::Use(definition) => definition.span(),
+            Self::TypeAlias(definition) => definition.span(),
+            Self::Constant(definition) => definition.span(),
+            Self::Function(definition) => definition.span(),
+            Self::Interface(definition) => definition.span(),
+            Self::Enum(definition) => definition.span(),
+            Self::Class(definition) => definition.span(),
         }
     }
 
-    fn final_position(&self) -> usize {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
         match &self {
-            Self::Namespace(definition) => definition.final_position(),
-            Self::Use(definition) => definition.final_position(),
-            Self::TypeAlias(definition) => definition.final_position(),
-            Self::Constant(definition) => definition.final_position(),
-            Self::Function(definition) => definition.final_position(),
-            Self::Interface(definition) => definition.final_position(),
-            Self::Enum(definition) => definition.final_position(),
-            Self::Class(definition) => definition.final_position(),
+            Self::Pragma(definition) => vec![("definition", definition.as_ref())],
+            Self::Namespace(definition) => vec![("definition", definition.as_ref())],
+            Self::Use(definition) => vec![("definition", definition.as_ref())],
+            Self::TypeAlias(definition) => vec![("definition", definition.as_ref())],
+            Self::Constant(definition) => vec![("definition", definition.as_ref())],
+            Self::Function(definition) => vec![("definition", definition.as_ref())],
+            Self::Interface(definition) => vec![("definition", definition.as_ref())],
+            Self::Enum(definition) => vec![("definition", definition.as_ref())],
+            Self::Class(definition) => vec![("definition", definition.as_ref())],
         }
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        match &self {
-            Self::Namespace(definition) => vec![definition.as_ref()],
-            Self::Use(definition) => vec![definition.as_ref()],
-            Self::TypeAlias(definition) => vec![definition.as_ref()],
-            Self::Constant(definition) => vec![definition.as_ref()],
-            Self::Function(definition) => vec![definition.as_ref()],
-            Self::Interface(definition) => vec![definition.as_ref()],
-            Self::Enum(definition) => vec![definition.as_ref()],
-            Self::Class(definition) => vec![definition.as_ref()],
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
+            Self::Pragma(definition) => vec![definition.as_mut()],
+            Self::Namespace(definition) => vec![definition.as_mut()],
+            Self::Use(definition) => vec![definition.as_mut()],
+            Self::TypeAlias(definition) => vec![definition.as_mut()],
+            Self::Constant(definition) => vec![definition.as_mut()],
+            Self::Function(definition) => vec![definition.as_mut()],
+            Self::Interface(definition) => vec![definition.as_mut()],
+            Self::Enum(definition) => vec![definition.as_mut()],
+            Self::Class(definition) => vec![definition.as_mut()],
         }
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        vec![]
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::Definition
+    }
+
     fn get_description(&self) -> String {
         match &self {
+            Self::Pragma(definition) => definition.get_description(),
             Self::Namespace(definition) => definition.get_description(),
             Self::Use(definition) => definition.get_description(),
             Self::TypeAlias(definition) => definition.get_description(),
@@ -122,6 +153,7 @@ impl Node for Definition {
 impl std::fmt::Display for Definition {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match &self {
+            Self::Pragma(definition) => write!(f, "{}", definition),
             Self::Namespace(definition) => write!(f, "{}", definition),
             Self::Use(definition) => write!(f, "{}", definition),
             Self::TypeAlias(definition) => write!(f, "{}", definition),
@@ -4,6 +4,8 @@ use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::tree::kind::NodeKind;
+use crate::tree::span::Span;
 use crate::tree::token::Keyword;
 use crate::tree::Node;
 
@@ -18,6 +20,7 @@ pub enum ModifierDefinition {
     Final(Keyword),
     Abstract(Keyword),
     Async(Keyword),
+    Internal(Keyword),
 }
 
 #[derive(Debug, Clone, Hash, Eq, PartialEq, Deserialize, Serialize, Encode, Decode, JsonSchema)]
@@ -28,34 +31,50 @@ pub struct ModifierGroupDefinition {
 }
 
 impl Node for ModifierGroupDefinition {
-    fn initial_position(&self) -> usize {
-        self.modifiers
+    fn span(&self) -> Span {
+        let initial_position = self
+            .modifiers
             .first()
             .map(|modifier| modifier.initial_position())
-            .unwrap_or(self.position)
-    }
-
-    fn final_position(&self) -> usize {
-        self.modifiers
+            .unwrap_or(self.position);
+        let final_position = self
+            .modifiers
             .last()
             .map(|modifier| modifier.final_position())
-            .unwrap_or(self.position)
+            .unwrap_or(self.position);
+
+        Span::new(initial_position, final_position)
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
         self.modifiers
             .iter()
-            .map(|modifier| modifier as &dyn Node)
+            .map(|modifier| ("modifiers", modifier as &dyn Node))
+            .collect()
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        self.modifiers
+            .iter_mut()
+            .map(|modifier| modifier as &mut dyn Node)
             .collect()
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        vec![&mut self.position]
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::ModifierGroupDefinition
+    }
+
     fn get_description(&self) -> String {
         "modifier group definition".to_string()
     }
 }
 
 impl Node for ModifierDefinition {
-    fn initial_position(&self) -> usize {
+    fn span(&self) -> Span {
         match &self {
             Self::Public(keyword)
             | Self::Protected(keyword)
@@ -64,11 +83,12 @@ impl Node for ModifierDefinition {
             | Self::Static(keyword)
             | Self::Abstract(keyword)
             | Self::Async(keyword)
-            | Self::Final(keyword) => keyword.initial_position(),
+            | Self::Internal(keyword)
+            | Self::Final(keyword) => keyword.span(),
         }
     }
 
-    fn final_position(&self) -> usize {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
         match &self {
             Self::Public(keyword)
             | Self::Protected(keyword)
@@ -77,12 +97,13 @@ impl Node for ModifierDefinition {
             | Self::Static(keyword)
             | Self::Abstract(keyword)
             | Self::Async(keyword)
-            | Self::Final(keyword) => keyword.final_position(),
+            | Self::Internal(keyword)
+            | Self::Final(keyword) => vec![("keyword", keyword as &dyn Node)],
         }
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        match &self {
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
             Self::Public(keyword)
             | Self::Protected(keyword)
             | Self::Private(keyword)
@@ -90,10 +111,19 @@ impl Node for ModifierDefinition {
             | Self::Static(keyword)
             | Self::Abstract(keyword)
             | Self::Async(keyword)
-            | Self::Final(keyword) => vec![keyword as &dyn Node],
+            | Self::Internal(keyword)
+            | Self::Final(keyword) => vec![keyword as &mut dyn Node],
         }
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        vec![]
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::ModifierDefinition
+    }
+
     fn get_description(&self) -> String {
         match &self {
             Self::Public(_keyword) => "public modifier definition".to_string(),
@@ -103,6 +133,7 @@ impl Node for ModifierDefinition {
             Self::Static(_keyword) => "static modifier definition".to_string(),
             Self::Abstract(_keyword) => "abstract modifier definition".to_string(),
             Self::Async(_keyword) => "async modifier definition".to_string(),
+            Self::Internal(_keyword) => "internal modifier definition".to_string(),
             Self::Final(_keyword) => "final modifier definition".to_string(),
         }
     }
@@ -118,6 +149,7 @@ impl std::fmt::Display for ModifierDefinition {
             | Self::Static(keyword)
             | Self::Abstract(keyword)
             | Self::Async(keyword)
+            | Self::Internal(keyword)
             | Self::Final(keyword) => write!(f, "{}", keyword.value),
         }
     }
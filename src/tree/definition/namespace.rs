@@ -6,6 +6,8 @@ use serde::Serialize;
 
 use crate::tree::definition::Definition;
 use crate::tree::identifier::Identifier;
+use crate::tree::kind::NodeKind;
+use crate::tree::span::Span;
 use crate::tree::token::Keyword;
 use crate::tree::Node;
 
@@ -19,24 +21,44 @@ pub struct NamespaceDefinition {
 }
 
 impl Node for NamespaceDefinition {
-    fn initial_position(&self) -> usize {
-        self.namespace.initial_position()
-    }
+    fn span(&self) -> Span {
+        let final_position = match self.definitions.last() {
+            Some(definition) => definition.final_position(),
+            None => self.semicolon + 1,
+        };
 
-    fn final_position(&self) -> usize {
-        self.semicolon + 1
+        Span::new(self.namespace.initial_position(), final_position)
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        let mut children: Vec<&dyn Node> = vec![&self.namespace, &self.name];
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut children: Vec<(&'static str, &dyn Node)> =
+            vec![("namespace", &self.namespace), ("name", &self.name)];
 
         for definition in &self.definitions {
+            children.push(("definitions", definition));
+        }
+
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = vec![&mut self.namespace, &mut self.name];
+
+        for definition in &mut self.definitions {
             children.push(definition);
         }
 
         children
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        vec![&mut self.semicolon]
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::NamespaceDefinition
+    }
+
     fn get_description(&self) -> String {
         "namespace definition".to_string()
     }
@@ -0,0 +1,80 @@
+use bincode::Decode;
+use bincode::Encode;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::tree::comment::CommentGroup;
+use crate::tree::identifier::Identifier;
+use crate::tree::kind::NodeKind;
+use crate::tree::span::Span;
+use crate::tree::token::Keyword;
+use crate::tree::Node;
+
+#[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct PragmaDefinition {
+    pub comments: CommentGroup,
+    pub pragma: Keyword,
+    pub name: Identifier,
+    pub semicolon: usize,
+}
+
+impl Node for PragmaDefinition {
+    fn comments(&self) -> Option<&CommentGroup> {
+        Some(&self.comments)
+    }
+
+    fn span(&self) -> Span {
+        Span::new(self.pragma.initial_position(), self.semicolon + 1)
+    }
+
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("pragma", &self.pragma), ("name", &self.name)]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        vec![&mut self.pragma, &mut self.name]
+    }
+
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        let mut positions: Vec<&mut usize> = vec![&mut self.semicolon];
+        positions.extend(self.comments.positions_mut());
+        positions
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::PragmaDefinition
+    }
+
+    fn get_description(&self) -> String {
+        "pragma definition".to_string()
+    }
+}
+
+impl std::fmt::Display for PragmaDefinition {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{} {};", self.pragma, self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexer::byte_string::ByteString;
+
+    #[test]
+    fn test_pragma_definition_display() {
+        let pragma_definition = PragmaDefinition {
+            comments: CommentGroup::default(),
+            pragma: Keyword::new(ByteString::from("pragma"), 0),
+            name: Identifier {
+                position: 7,
+                value: ByteString::from("strict_generics"),
+            },
+            semicolon: 22,
+        };
+
+        assert_eq!(pragma_definition.to_string(), "pragma strict_generics;");
+    }
+}
@@ -8,6 +8,8 @@ use crate::tree::definition::attribute::AttributeGroupDefinition;
 use crate::tree::definition::modifier::ModifierGroupDefinition;
 use crate::tree::definition::r#type::TypeDefinition;
 use crate::tree::expression::Expression;
+use crate::tree::kind::NodeKind;
+use crate::tree::span::Span;
 use crate::tree::variable::Variable;
 use crate::tree::Node;
 
@@ -23,6 +25,7 @@ pub struct PropertyDefinition {
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
 #[serde(rename_all = "snake_case", tag = "type", content = "value")]
+#[allow(clippy::large_enum_variant)]
 pub enum PropertyEntryDefinition {
     Uninitialized {
         variable: Variable,
@@ -44,58 +47,84 @@ impl PropertyEntryDefinition {
 }
 
 impl Node for PropertyDefinition {
-    fn initial_position(&self) -> usize {
-        if let Some(attribute) = self.attributes.first() {
-            return attribute.initial_position();
-        }
+    fn span(&self) -> Span {
+        let initial_position = match self.attributes.first() {
+            Some(attribute) => attribute.initial_position(),
+            None => match self.modifiers.modifiers.first() {
+                Some(modifier) => modifier.initial_position(),
+                None => self.type_definition.initial_position(),
+            },
+        };
+
+        Span::new(initial_position, self.semicolon + 1)
+    }
+
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut children: Vec<(&'static str, &dyn Node)> = vec![];
 
-        if let Some(modifier) = self.modifiers.modifiers.first() {
-            return modifier.initial_position();
+        for attribute in &self.attributes {
+            children.push(("attributes", attribute));
         }
 
-        self.type_definition.initial_position()
-    }
+        children.push(("modifiers", &self.modifiers));
+        children.push(("type_definition", &self.type_definition));
+        children.push(("entry", &self.entry));
 
-    fn final_position(&self) -> usize {
-        self.semicolon + 1
+        children
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        let mut children: Vec<&dyn Node> = vec![];
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = vec![];
 
-        for attribute in &self.attributes {
+        for attribute in &mut self.attributes {
             children.push(attribute);
         }
 
-        children.push(&self.modifiers);
-        children.push(&self.type_definition);
-        children.push(&self.entry);
+        children.push(&mut self.modifiers);
+        children.push(&mut self.type_definition);
+        children.push(&mut self.entry);
 
         children
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        vec![&mut self.semicolon]
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::PropertyDefinition
+    }
+
     fn get_description(&self) -> String {
         "property definition".to_string()
     }
 }
 
 impl Node for PropertyEntryDefinition {
-    fn initial_position(&self) -> usize {
-        match &self {
+    fn span(&self) -> Span {
+        let initial_position = match &self {
             Self::Uninitialized { variable } => variable.initial_position(),
             Self::Initialized { variable, .. } => variable.initial_position(),
-        }
+        };
+        let final_position = match &self {
+            Self::Uninitialized { variable } => variable.final_position(),
+            Self::Initialized { value, .. } => value.final_position(),
+        };
+
+        Span::new(initial_position, final_position)
     }
 
-    fn final_position(&self) -> usize {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
         match &self {
-            Self::Uninitialized { variable } => variable.final_position(),
-            Self::Initialized { value, .. } => value.final_position(),
+            Self::Uninitialized { variable } => vec![("variable", variable)],
+            Self::Initialized {
+                variable, value, ..
+            } => vec![("variable", variable), ("value", value)],
         }
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        match &self {
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
             Self::Uninitialized { variable } => vec![variable],
             Self::Initialized {
                 variable, value, ..
@@ -103,6 +132,17 @@ impl Node for PropertyEntryDefinition {
         }
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        match self {
+            Self::Initialized { equals, .. } => vec![equals],
+            _ => vec![],
+        }
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::PropertyEntryDefinition
+    }
+
     fn get_description(&self) -> String {
         match &self {
             Self::Uninitialized { .. } => "uninitialized property entry".to_string(),
@@ -190,7 +230,7 @@ mod tests {
                 },
                 equals: 0,
                 value: Expression::Literal(Integer(LiteralInteger {
-                    comments: CommentGroup { comments: vec![] },
+                    comments: CommentGroup::default(),
                     position: 0,
                     value: ByteString::from("123"),
                 })),
@@ -7,8 +7,11 @@ use serde::Serialize;
 use crate::tree::comment::CommentGroup;
 use crate::tree::definition::r#type::TypeDefinition;
 use crate::tree::identifier::Identifier;
+use crate::tree::kind::NodeKind;
+use crate::tree::span::Span;
 use crate::tree::token::Keyword;
 use crate::tree::utils::CommaSeparated;
+use crate::tree::utils::ListView;
 use crate::tree::Node;
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
@@ -21,25 +24,59 @@ pub enum TemplateDefinitionVariance {
 #[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum TemplateDefinitionTypeConstraint {
-    SubType(Keyword, TypeDefinition),
+    /// An upper bound, e.g. `T as Foo`, the template must be a subtype of its bounds.
+    SubType(Keyword, TemplateBoundsDefinition),
+    /// A lower bound, e.g. `T super Foo`, the template must be a supertype of its bounds.
+    SuperType(Keyword, TemplateBoundsDefinition),
     None,
 }
 
+/// The bounds of a template constraint, separated by `+`, e.g. `Countable + Stringable` in
+/// `T as Countable + Stringable`, all of which must be satisfied.
+#[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct TemplateBoundsDefinition {
+    pub inner: Vec<TypeDefinition>,
+    pub pluses: Vec<usize>,
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub struct TemplateDefinition {
     pub variance: TemplateDefinitionVariance,
+    pub ellipsis: Option<usize>,
     pub name: Identifier,
     pub constraint: TemplateDefinitionTypeConstraint,
 }
 
+#[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ConstTemplateDefinition {
+    pub r#const: Keyword,
+    pub name: Identifier,
+    pub colon: usize,
+    pub type_definition: TypeDefinition,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
+#[serde(rename_all = "snake_case", tag = "type", content = "value")]
+pub enum TemplateGroupDefinitionMember {
+    Type(TemplateDefinition),
+    Const(ConstTemplateDefinition),
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub struct TemplateGroupDefinition {
     pub comments: CommentGroup,
     pub less_than: usize,
-    pub members: CommaSeparated<TemplateDefinition>,
+    pub members: CommaSeparated<TemplateGroupDefinitionMember>,
     pub greater_than: usize,
+    /// The docblock the group was synthesized from, if it was not declared in the source.
+    ///
+    /// A synthesized group has an empty span right after the name of its definition, and its
+    /// members are not exposed as children, as they are located within the docblock.
+    pub docblock: Option<Span>,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
@@ -52,27 +89,66 @@ pub struct TypeTemplateGroupDefinition {
 }
 
 impl Node for TemplateDefinition {
-    fn initial_position(&self) -> usize {
-        match &self.variance {
+    fn span(&self) -> Span {
+        let initial_position = match &self.variance {
             TemplateDefinitionVariance::Covariance(position) => *position,
-            TemplateDefinitionVariance::Invaraint => self.name.initial_position(),
-        }
+            TemplateDefinitionVariance::Invaraint => match self.ellipsis {
+                Some(ellipsis) => ellipsis,
+                None => self.name.initial_position(),
+            },
+        };
+        let final_position = match &self.constraint {
+            TemplateDefinitionTypeConstraint::SubType(_, b)
+            | TemplateDefinitionTypeConstraint::SuperType(_, b) => b.final_position(),
+            TemplateDefinitionTypeConstraint::None => self.name.final_position(),
+        };
+
+        Span::new(initial_position, final_position)
     }
 
-    fn final_position(&self) -> usize {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
         match &self.constraint {
-            TemplateDefinitionTypeConstraint::SubType(_, t) => t.final_position(),
-            TemplateDefinitionTypeConstraint::None => self.name.final_position(),
+            TemplateDefinitionTypeConstraint::SubType(k, b)
+            | TemplateDefinitionTypeConstraint::SuperType(k, b) => {
+                vec![("name", &self.name), ("keyword", k), ("bounds", b)]
+            }
+            TemplateDefinitionTypeConstraint::None => vec![("name", &self.name)],
         }
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        match &self.constraint {
-            TemplateDefinitionTypeConstraint::SubType(k, t) => {
-                vec![&self.name, k, t]
+    fn tokens(&self) -> Vec<&[u8]> {
+        let mut tokens: Vec<&[u8]> = vec![];
+        if let TemplateDefinitionVariance::Covariance(_) = &self.variance {
+            tokens.push(b"+");
+        }
+        if self.ellipsis.is_some() {
+            tokens.push(b"...");
+        }
+
+        tokens
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match &mut self.constraint {
+            TemplateDefinitionTypeConstraint::SubType(k, b)
+            | TemplateDefinitionTypeConstraint::SuperType(k, b) => {
+                vec![&mut self.name, k, b]
             }
-            TemplateDefinitionTypeConstraint::None => vec![&self.name],
+            TemplateDefinitionTypeConstraint::None => vec![&mut self.name],
+        }
+    }
+
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        let mut positions: Vec<&mut usize> = self.ellipsis.iter_mut().collect();
+        if let TemplateDefinitionVariance::Covariance(position) = &mut self.variance {
+            positions.push(position);
         }
+
+        positions
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::TemplateDefinition
     }
 
     fn get_description(&self) -> String {
@@ -80,21 +156,161 @@ impl Node for TemplateDefinition {
     }
 }
 
+impl Node for TemplateBoundsDefinition {
+    fn span(&self) -> Span {
+        let final_position = self.inner[self.inner.len() - 1].final_position();
+
+        Span::new(self.inner[0].initial_position(), final_position)
+    }
+
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        self.inner
+            .iter()
+            .map(|s| ("bounds", s as &dyn Node))
+            .collect()
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        self.inner.iter_mut().map(|s| s as &mut dyn Node).collect()
+    }
+
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        self.pluses.iter_mut().collect()
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::TemplateBoundsDefinition
+    }
+
+    fn get_description(&self) -> String {
+        "template bounds definition".to_string()
+    }
+}
+
+impl Node for ConstTemplateDefinition {
+    fn span(&self) -> Span {
+        Span::new(
+            self.r#const.initial_position(),
+            self.type_definition.final_position(),
+        )
+    }
+
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![
+            ("const", &self.r#const),
+            ("name", &self.name),
+            ("type_definition", &self.type_definition),
+        ]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        vec![&mut self.r#const, &mut self.name, &mut self.type_definition]
+    }
+
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        vec![&mut self.colon]
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::ConstTemplateDefinition
+    }
+
+    fn get_description(&self) -> String {
+        "const template definition".to_string()
+    }
+}
+
+impl Node for TemplateGroupDefinitionMember {
+    fn span(&self) -> Span {
+        match &self {
+            Self::Type(template) => template.span(),
+            Self::Const(template) => template.span(),
+        }
+    }
+
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        match &self {
+            Self::Type(template) => vec![("template", template)],
+            Self::Const(template) => vec![("template", template)],
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
+            Self::Type(template) => vec![template],
+            Self::Const(template) => vec![template],
+        }
+    }
+
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        vec![]
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::TemplateGroupDefinitionMember
+    }
+
+    fn get_description(&self) -> String {
+        match &self {
+            Self::Type(template) => template.get_description(),
+            Self::Const(template) => template.get_description(),
+        }
+    }
+}
+
 impl Node for TemplateGroupDefinition {
     fn comments(&self) -> Option<&CommentGroup> {
         Some(&self.comments)
     }
 
-    fn initial_position(&self) -> usize {
-        self.less_than
+    fn span(&self) -> Span {
+        if self.docblock.is_some() {
+            return Span::new(self.less_than, self.less_than);
+        }
+
+        Span::new(self.less_than, self.greater_than + 1)
+    }
+
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        if self.docblock.is_some() {
+            return vec![];
+        }
+
+        self.members
+            .inner
+            .iter()
+            .map(|s| ("members", s as &dyn Node))
+            .collect()
+    }
+
+    fn lists(&self) -> Vec<ListView<'_>> {
+        if self.docblock.is_some() {
+            return vec![];
+        }
+
+        vec![self.members.view()]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        self.members
+            .inner
+            .iter_mut()
+            .map(|s| s as &mut dyn Node)
+            .collect()
     }
 
-    fn final_position(&self) -> usize {
-        self.greater_than + 1
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        let mut positions: Vec<&mut usize> = vec![&mut self.less_than, &mut self.greater_than];
+        positions.extend(self.comments.positions_mut());
+        positions.extend(self.members.commas.iter_mut());
+        if let Some(docblock) = &mut self.docblock {
+            positions.extend([&mut docblock.start, &mut docblock.end]);
+        }
+        positions
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        self.members.inner.iter().map(|s| s as &dyn Node).collect()
+    fn kind(&self) -> NodeKind {
+        NodeKind::TemplateGroupDefinition
     }
 
     fn get_description(&self) -> String {
@@ -107,16 +323,39 @@ impl Node for TypeTemplateGroupDefinition {
         Some(&self.comments)
     }
 
-    fn initial_position(&self) -> usize {
-        self.less_than
+    fn span(&self) -> Span {
+        Span::new(self.less_than, self.greater_than + 1)
+    }
+
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        self.members
+            .inner
+            .iter()
+            .map(|s| ("members", s as &dyn Node))
+            .collect()
+    }
+
+    fn lists(&self) -> Vec<ListView<'_>> {
+        vec![self.members.view()]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        self.members
+            .inner
+            .iter_mut()
+            .map(|s| s as &mut dyn Node)
+            .collect()
     }
 
-    fn final_position(&self) -> usize {
-        self.greater_than + 1
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        let mut positions: Vec<&mut usize> = vec![&mut self.less_than, &mut self.greater_than];
+        positions.extend(self.comments.positions_mut());
+        positions.extend(self.members.commas.iter_mut());
+        positions
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        self.members.inner.iter().map(|s| s as &dyn Node).collect()
+    fn kind(&self) -> NodeKind {
+        NodeKind::TypeTemplateGroupDefinition
     }
 
     fn get_description(&self) -> String {
@@ -154,6 +393,25 @@ impl std::fmt::Display for TypeTemplateGroupDefinition {
     }
 }
 
+impl std::fmt::Display for ConstTemplateDefinition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {}: {}",
+            self.r#const, self.name, self.type_definition
+        )
+    }
+}
+
+impl std::fmt::Display for TemplateGroupDefinitionMember {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self {
+            Self::Type(template) => write!(f, "{template}"),
+            Self::Const(template) => write!(f, "{template}"),
+        }
+    }
+}
+
 impl std::fmt::Display for TemplateDefinitionVariance {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -163,10 +421,24 @@ impl std::fmt::Display for TemplateDefinitionVariance {
     }
 }
 
+impl std::fmt::Display for TemplateBoundsDefinition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.inner
+                .iter()
+                .map(|type_definition| type_definition.to_string())
+                .collect::<Vec<String>>()
+                .join(" + ")
+        )
+    }
+}
+
 impl std::fmt::Display for TemplateDefinitionTypeConstraint {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::SubType(k, t) => write!(f, " {k} {t}"),
+            Self::SubType(k, t) | Self::SuperType(k, t) => write!(f, " {k} {t}"),
             Self::None => write!(f, ""),
         }
     }
@@ -174,11 +446,13 @@ impl std::fmt::Display for TemplateDefinitionTypeConstraint {
 
 impl std::fmt::Display for TemplateDefinition {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        Ok(write!(
-            f,
-            "{}{}{}",
-            &self.variance, self.name, &self.constraint
-        )?)
+        write!(f, "{}", &self.variance)?;
+
+        if self.ellipsis.is_some() {
+            write!(f, "...")?;
+        }
+
+        write!(f, "{}{}", self.name, &self.constraint)
     }
 }
 
@@ -187,11 +461,13 @@ mod tests {
     use super::*;
     use crate::lexer::byte_string::ByteString;
     use crate::tree::identifier::TemplatedIdentifier;
+    use smallvec::smallvec;
 
     #[test]
     fn test_template_definition_display() {
         let template_definition = TemplateDefinition {
             variance: TemplateDefinitionVariance::Covariance(0),
+            ellipsis: None,
             name: Identifier {
                 position: 1,
                 value: ByteString::from("T"),
@@ -201,13 +477,16 @@ mod tests {
                     value: ByteString::from("as"),
                     position: 2,
                 },
-                TypeDefinition::Identifier(TemplatedIdentifier {
-                    name: Identifier {
-                        position: 3,
-                        value: ByteString::from("object"),
-                    },
-                    templates: None,
-                }),
+                TemplateBoundsDefinition {
+                    inner: vec![TypeDefinition::Identifier(TemplatedIdentifier {
+                        name: Identifier {
+                            position: 3,
+                            value: ByteString::from("object"),
+                        },
+                        templates: None,
+                    })],
+                    pluses: vec![],
+                },
             ),
         };
 
@@ -215,6 +494,7 @@ mod tests {
 
         let template_definition = TemplateDefinition {
             variance: TemplateDefinitionVariance::Invaraint,
+            ellipsis: None,
             name: Identifier {
                 position: 1,
                 value: ByteString::from("U"),
@@ -224,28 +504,134 @@ mod tests {
                     value: ByteString::from("as"),
                     position: 2,
                 },
-                TypeDefinition::Identifier(TemplatedIdentifier {
-                    name: Identifier {
-                        position: 3,
-                        value: ByteString::from("IFoo"),
-                    },
-                    templates: None,
-                }),
+                TemplateBoundsDefinition {
+                    inner: vec![TypeDefinition::Identifier(TemplatedIdentifier {
+                        name: Identifier {
+                            position: 3,
+                            value: ByteString::from("IFoo"),
+                        },
+                        templates: None,
+                    })],
+                    pluses: vec![],
+                },
             ),
         };
 
         assert_eq!(template_definition.to_string(), "U as IFoo");
+
+        let template_definition = TemplateDefinition {
+            variance: TemplateDefinitionVariance::Invaraint,
+            ellipsis: None,
+            name: Identifier {
+                position: 1,
+                value: ByteString::from("T"),
+            },
+            constraint: TemplateDefinitionTypeConstraint::SubType(
+                Keyword {
+                    value: ByteString::from("as"),
+                    position: 2,
+                },
+                TemplateBoundsDefinition {
+                    inner: vec![
+                        TypeDefinition::Identifier(TemplatedIdentifier {
+                            name: Identifier {
+                                position: 3,
+                                value: ByteString::from("Countable"),
+                            },
+                            templates: None,
+                        }),
+                        TypeDefinition::Identifier(TemplatedIdentifier {
+                            name: Identifier {
+                                position: 5,
+                                value: ByteString::from("Stringable"),
+                            },
+                            templates: None,
+                        }),
+                    ],
+                    pluses: vec![4],
+                },
+            ),
+        };
+
+        assert_eq!(
+            template_definition.to_string(),
+            "T as Countable + Stringable"
+        );
+
+        let template_definition = TemplateDefinition {
+            variance: TemplateDefinitionVariance::Invaraint,
+            ellipsis: None,
+            name: Identifier {
+                position: 1,
+                value: ByteString::from("T"),
+            },
+            constraint: TemplateDefinitionTypeConstraint::SuperType(
+                Keyword {
+                    value: ByteString::from("super"),
+                    position: 2,
+                },
+                TemplateBoundsDefinition {
+                    inner: vec![TypeDefinition::Identifier(TemplatedIdentifier {
+                        name: Identifier {
+                            position: 3,
+                            value: ByteString::from("Foo"),
+                        },
+                        templates: None,
+                    })],
+                    pluses: vec![],
+                },
+            ),
+        };
+
+        assert_eq!(template_definition.to_string(), "T super Foo");
+
+        let template_definition = TemplateDefinition {
+            variance: TemplateDefinitionVariance::Covariance(0),
+            ellipsis: Some(1),
+            name: Identifier {
+                position: 4,
+                value: ByteString::from("T"),
+            },
+            constraint: TemplateDefinitionTypeConstraint::None,
+        };
+
+        assert_eq!(template_definition.to_string(), "+...T");
+    }
+
+    #[test]
+    fn test_const_template_definition_display() {
+        let const_template_definition = ConstTemplateDefinition {
+            r#const: Keyword {
+                value: ByteString::from("const"),
+                position: 0,
+            },
+            name: Identifier {
+                position: 6,
+                value: ByteString::from("N"),
+            },
+            colon: 7,
+            type_definition: TypeDefinition::Identifier(TemplatedIdentifier {
+                name: Identifier {
+                    position: 9,
+                    value: ByteString::from("int"),
+                },
+                templates: None,
+            }),
+        };
+
+        assert_eq!(const_template_definition.to_string(), "const N: int");
     }
 
     #[test]
     fn test_template_group_definition_display() {
         let template_group_definition = TemplateGroupDefinition {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             less_than: 0,
             members: CommaSeparated {
                 inner: vec![
-                    TemplateDefinition {
+                    TemplateGroupDefinitionMember::Type(TemplateDefinition {
                         variance: TemplateDefinitionVariance::Covariance(0),
+                        ellipsis: None,
                         name: Identifier {
                             position: 1,
                             value: ByteString::from("T"),
@@ -255,17 +641,21 @@ mod tests {
                                 value: ByteString::from("as"),
                                 position: 2,
                             },
-                            TypeDefinition::Identifier(TemplatedIdentifier {
-                                name: Identifier {
-                                    position: 3,
-                                    value: ByteString::from("object"),
-                                },
-                                templates: None,
-                            }),
+                            TemplateBoundsDefinition {
+                                inner: vec![TypeDefinition::Identifier(TemplatedIdentifier {
+                                    name: Identifier {
+                                        position: 3,
+                                        value: ByteString::from("object"),
+                                    },
+                                    templates: None,
+                                })],
+                                pluses: vec![],
+                            },
                         ),
-                    },
-                    TemplateDefinition {
+                    }),
+                    TemplateGroupDefinitionMember::Type(TemplateDefinition {
                         variance: TemplateDefinitionVariance::Invaraint,
+                        ellipsis: None,
                         name: Identifier {
                             position: 1,
                             value: ByteString::from("U"),
@@ -275,19 +665,23 @@ mod tests {
                                 value: ByteString::from("as"),
                                 position: 2,
                             },
-                            TypeDefinition::Identifier(TemplatedIdentifier {
-                                name: Identifier {
-                                    position: 3,
-                                    value: ByteString::from("IFoo"),
-                                },
-                                templates: None,
-                            }),
+                            TemplateBoundsDefinition {
+                                inner: vec![TypeDefinition::Identifier(TemplatedIdentifier {
+                                    name: Identifier {
+                                        position: 3,
+                                        value: ByteString::from("IFoo"),
+                                    },
+                                    templates: None,
+                                })],
+                                pluses: vec![],
+                            },
                         ),
-                    },
+                    }),
                 ],
-                commas: vec![],
+                commas: smallvec![],
             },
             greater_than: 4,
+            docblock: None,
         };
 
         assert_eq!(
@@ -296,30 +690,33 @@ mod tests {
         );
 
         let template_group_definition = TemplateGroupDefinition {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             less_than: 0,
             members: CommaSeparated {
                 inner: vec![
-                    TemplateDefinition {
+                    TemplateGroupDefinitionMember::Type(TemplateDefinition {
                         variance: TemplateDefinitionVariance::Invaraint,
+                        ellipsis: None,
                         name: Identifier {
                             position: 1,
                             value: ByteString::from("T"),
                         },
                         constraint: TemplateDefinitionTypeConstraint::None,
-                    },
-                    TemplateDefinition {
+                    }),
+                    TemplateGroupDefinitionMember::Type(TemplateDefinition {
                         variance: TemplateDefinitionVariance::Invaraint,
+                        ellipsis: None,
                         name: Identifier {
                             position: 1,
                             value: ByteString::from("U"),
                         },
                         constraint: TemplateDefinitionTypeConstraint::None,
-                    },
+                    }),
                 ],
-                commas: vec![],
+                commas: smallvec![],
             },
             greater_than: 4,
+            docblock: None,
         };
 
         assert_eq!(template_group_definition.to_string(), "<T, U>");
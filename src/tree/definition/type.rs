@@ -4,20 +4,31 @@ use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::tree::definition::function::MethodTypeConstraintGroupDefinition;
+use crate::tree::definition::modifier::ModifierGroupDefinition;
 use crate::tree::definition::template::TypeTemplateGroupDefinition;
 use crate::tree::expression::literal::Literal;
+use crate::tree::expression::literal::LiteralInteger;
+use crate::tree::identifier::Identifier;
 use crate::tree::identifier::TemplatedIdentifier;
+use crate::tree::kind::NodeKind;
+use crate::tree::span::Span;
 use crate::tree::token::Keyword;
 use crate::tree::utils::CommaSeparated;
+use crate::tree::utils::ListView;
+use crate::tree::variable::Variable;
 use crate::tree::Node;
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub struct TypeAliasDefinition {
+    pub modifiers: ModifierGroupDefinition,
     pub r#type: Keyword,
     pub name: TemplatedIdentifier,
     pub equals: usize,
+    pub new: Option<Keyword>,
     pub type_definition: TypeDefinition,
+    pub constraints: Option<MethodTypeConstraintGroupDefinition>,
     pub semicolon: usize,
 }
 
@@ -54,9 +65,11 @@ pub enum FloatingPointTypeDefinition {
 pub enum TypeDefinition {
     Identifier(TemplatedIdentifier),
     Nullable(usize, Box<TypeDefinition>),
+    Variadic(usize, Box<TypeDefinition>),
     Union(Vec<TypeDefinition>),
     Intersection(Vec<TypeDefinition>),
     Void(Keyword),
+    /// `never`, or `noreturn`.
     Never(Keyword),
     Boolean(Keyword),
     String(Keyword),
@@ -69,13 +82,26 @@ pub enum TypeDefinition {
     Mixed(Keyword),
     NonNull(Keyword),
     Resource(Keyword),
+    /// `self`, the class in which the type is used.
+    Self_(Keyword),
+    /// `static`, the class on which the method is called, i.e. late static binding.
+    Static(Keyword),
+    /// `parent`, the parent class of the class in which the type is used.
+    Parent(Keyword),
     Iterable(Keyword, TypeTemplateGroupDefinition),
+    /// `class<T>`, or `class-string<T>`, the name of a class.
     Class(Keyword, TypeTemplateGroupDefinition),
+    /// `interface<T>`, or `interface-string<T>`, the name of an interface.
     Interface(Keyword, TypeTemplateGroupDefinition),
     Literal(Literal),
+    /// `()`, the unit type, a tuple with no elements.
+    Unit {
+        left_parenthesis: usize,
+        right_parenthesis: usize,
+    },
     Tuple {
         left_parenthesis: usize,
-        type_definitions: CommaSeparated<TypeDefinition>,
+        elements: CommaSeparated<TupleElementTypeDefinition>,
         right_parenthesis: usize,
     },
     Parenthesized {
@@ -83,6 +109,83 @@ pub enum TypeDefinition {
         type_definition: Box<TypeDefinition>,
         right_parenthesis: usize,
     },
+    KeyOf(Keyword, Box<TypeDefinition>),
+    IndexedAccess {
+        type_definition: Box<TypeDefinition>,
+        left_bracket: usize,
+        index: Box<TypeDefinition>,
+        right_bracket: usize,
+    },
+    Shape {
+        shape: Keyword,
+        left_parenthesis: usize,
+        fields: CommaSeparated<ShapeFieldTypeDefinition>,
+        ellipsis: Option<usize>,
+        right_parenthesis: usize,
+    },
+    IntegerRange {
+        int: Keyword,
+        less_than: usize,
+        min: IntegerRangeBoundTypeDefinition,
+        comma: usize,
+        max: IntegerRangeBoundTypeDefinition,
+        greater_than: usize,
+    },
+}
+
+/// A bound of an integer range type, e.g. `-1`, or `max` in `int<-1, max>`.
+#[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
+#[serde(rename_all = "snake_case", tag = "type", content = "value")]
+pub enum IntegerRangeBoundTypeDefinition {
+    Integer {
+        minus: Option<usize>,
+        value: LiteralInteger,
+    },
+    /// `min` as the lower bound, or `max` as the upper bound.
+    Unbounded(Keyword),
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct TupleElementLabelDefinition {
+    pub name: Identifier,
+    pub colon: usize,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct TupleElementTypeDefinition {
+    pub label: Option<TupleElementLabelDefinition>,
+    pub optional: Option<Keyword>,
+    pub type_definition: TypeDefinition,
+    pub variable: Option<Variable>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ShapeFieldTypeDefinition {
+    pub question: Option<usize>,
+    pub key: Literal,
+    pub double_arrow: usize,
+    pub type_definition: TypeDefinition,
+}
+
+impl TupleElementTypeDefinition {
+    pub fn is_optional(&self) -> bool {
+        self.optional.is_some()
+    }
+}
+
+impl ShapeFieldTypeDefinition {
+    pub fn is_optional(&self) -> bool {
+        self.question.is_some()
+    }
+}
+
+impl TypeAliasDefinition {
+    pub fn is_opaque(&self) -> bool {
+        self.new.is_some()
+    }
 }
 
 impl TypeDefinition {
@@ -130,16 +233,59 @@ impl TypeDefinition {
 }
 
 impl Node for TypeAliasDefinition {
-    fn initial_position(&self) -> usize {
-        self.r#type.initial_position()
+    fn span(&self) -> Span {
+        let initial_position = if self.modifiers.modifiers.is_empty() {
+            self.r#type.initial_position()
+        } else {
+            self.modifiers.initial_position()
+        };
+
+        Span::new(initial_position, self.semicolon + 1)
+    }
+
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut children: Vec<(&'static str, &dyn Node)> = vec![
+            ("modifiers", &self.modifiers),
+            ("type", &self.r#type),
+            ("name", &self.name),
+        ];
+
+        if let Some(new) = &self.new {
+            children.push(("new", new));
+        }
+
+        children.push(("type_definition", &self.type_definition));
+
+        if let Some(constraints) = &self.constraints {
+            children.push(("constraints", constraints));
+        }
+
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> =
+            vec![&mut self.modifiers, &mut self.r#type, &mut self.name];
+
+        if let Some(new) = &mut self.new {
+            children.push(new);
+        }
+
+        children.push(&mut self.type_definition);
+
+        if let Some(constraints) = &mut self.constraints {
+            children.push(constraints);
+        }
+
+        children
     }
 
-    fn final_position(&self) -> usize {
-        self.semicolon + 1
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        vec![&mut self.equals, &mut self.semicolon]
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        vec![&self.r#type, &self.name, &self.type_definition]
+    fn kind(&self) -> NodeKind {
+        NodeKind::TypeAliasDefinition
     }
 
     fn get_description(&self) -> String {
@@ -148,30 +294,30 @@ impl Node for TypeAliasDefinition {
 }
 
 impl Node for SignedIntegerTypeDefinition {
-    fn initial_position(&self) -> usize {
+    fn span(&self) -> Span {
         match &self {
             Self::Default(keyword)
             | Self::I128(keyword)
             | Self::I64(keyword)
             | Self::I32(keyword)
             | Self::I16(keyword)
-            | Self::I8(keyword) => keyword.initial_position(),
+            | Self::I8(keyword) => keyword.span(),
         }
     }
 
-    fn final_position(&self) -> usize {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
         match &self {
             Self::Default(keyword)
             | Self::I128(keyword)
             | Self::I64(keyword)
             | Self::I32(keyword)
             | Self::I16(keyword)
-            | Self::I8(keyword) => keyword.final_position(),
+            | Self::I8(keyword) => vec![("keyword", keyword)],
         }
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        match &self {
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
             Self::Default(keyword)
             | Self::I128(keyword)
             | Self::I64(keyword)
@@ -181,32 +327,40 @@ impl Node for SignedIntegerTypeDefinition {
         }
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        vec![]
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::SignedIntegerTypeDefinition
+    }
+
     fn get_description(&self) -> String {
         "signed integer type definition".to_string()
     }
 }
 
 impl Node for UnsignedIntegerTypeDefinition {
-    fn initial_position(&self) -> usize {
+    fn span(&self) -> Span {
         match &self {
             Self::Default(keyword)
             | Self::U32(keyword)
             | Self::U16(keyword)
-            | Self::U8(keyword) => keyword.initial_position(),
+            | Self::U8(keyword) => keyword.span(),
         }
     }
 
-    fn final_position(&self) -> usize {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
         match &self {
             Self::Default(keyword)
             | Self::U32(keyword)
             | Self::U16(keyword)
-            | Self::U8(keyword) => keyword.final_position(),
+            | Self::U8(keyword) => vec![("keyword", keyword)],
         }
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        match &self {
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
             Self::Default(keyword)
             | Self::U32(keyword)
             | Self::U16(keyword)
@@ -214,47 +368,64 @@ impl Node for UnsignedIntegerTypeDefinition {
         }
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        vec![]
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::UnsignedIntegerTypeDefinition
+    }
+
     fn get_description(&self) -> String {
         "unsigned integer type definition".to_string()
     }
 }
 
 impl Node for FloatingPointTypeDefinition {
-    fn initial_position(&self) -> usize {
+    fn span(&self) -> Span {
         match &self {
-            Self::Default(keyword) | Self::F64(keyword) | Self::F32(keyword) => {
-                keyword.initial_position()
-            }
+            Self::Default(keyword) | Self::F64(keyword) | Self::F32(keyword) => keyword.span(),
         }
     }
 
-    fn final_position(&self) -> usize {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
         match &self {
             Self::Default(keyword) | Self::F64(keyword) | Self::F32(keyword) => {
-                keyword.final_position()
+                vec![("keyword", keyword)]
             }
         }
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        match &self {
-            Self::Default(keyword) | Self::F64(keyword) | Self::F32(keyword) => vec![keyword],
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
+            Self::Default(keyword) | Self::F64(keyword) | Self::F32(keyword) => {
+                vec![keyword]
+            }
         }
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        vec![]
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::FloatingPointTypeDefinition
+    }
+
     fn get_description(&self) -> String {
         "floating point type definition".to_string()
     }
 }
 
 impl Node for TypeDefinition {
-    fn initial_position(&self) -> usize {
-        match &self {
+    fn span(&self) -> Span {
+        let initial_position = match &self {
             Self::Identifier(inner) => inner.initial_position(),
             Self::Union(inner) => inner[0].initial_position(),
             Self::Intersection(inner) => inner[0].initial_position(),
             Self::Literal(literal) => literal.initial_position(),
             Self::Nullable(position, _) => *position,
+            Self::Variadic(position, _) => *position,
             Self::Void(keyword)
             | Self::Never(keyword)
             | Self::Boolean(keyword)
@@ -265,13 +436,20 @@ impl Node for TypeDefinition {
             | Self::Mixed(keyword)
             | Self::NonNull(keyword)
             | Self::Resource(keyword)
+            | Self::Self_(keyword)
+            | Self::Static(keyword)
+            | Self::Parent(keyword)
             | Self::Class(keyword, _)
             | Self::Interface(keyword, _)
             | Self::Iterable(keyword, _) => keyword.initial_position(),
             Self::SignedInteger(signed) => signed.initial_position(),
             Self::UnsignedInteger(unsigned) => unsigned.initial_position(),
             Self::FloatingPoint(floating) => floating.initial_position(),
-            Self::Tuple {
+            Self::Unit {
+                left_parenthesis: position,
+                ..
+            }
+            | Self::Tuple {
                 left_parenthesis: position,
                 ..
             }
@@ -279,13 +457,17 @@ impl Node for TypeDefinition {
                 left_parenthesis: position,
                 ..
             } => *position,
-        }
-    }
-
-    fn final_position(&self) -> usize {
-        match &self {
+            Self::KeyOf(keyword, _) => keyword.initial_position(),
+            Self::IndexedAccess {
+                type_definition, ..
+            } => type_definition.initial_position(),
+            Self::Shape { shape, .. } => shape.initial_position(),
+            Self::IntegerRange { int, .. } => int.initial_position(),
+        };
+        let final_position = match &self {
             Self::Identifier(inner) => inner.final_position(),
             Self::Nullable(_, inner) => inner.final_position(),
+            Self::Variadic(_, inner) => inner.final_position(),
             Self::Dict(_, template)
             | Self::Vec(_, template)
             | Self::Class(_, template)
@@ -301,25 +483,121 @@ impl Node for TypeDefinition {
             | Self::Object(keyword)
             | Self::Mixed(keyword)
             | Self::NonNull(keyword)
-            | Self::Resource(keyword) => keyword.final_position(),
+            | Self::Resource(keyword)
+            | Self::Self_(keyword)
+            | Self::Static(keyword)
+            | Self::Parent(keyword) => keyword.final_position(),
             Self::SignedInteger(signed) => signed.final_position(),
             Self::UnsignedInteger(unsigned) => unsigned.final_position(),
             Self::FloatingPoint(floating) => floating.final_position(),
             Self::Parenthesized {
                 right_parenthesis, ..
             }
+            | Self::Unit {
+                right_parenthesis, ..
+            }
             | Self::Tuple {
                 right_parenthesis, ..
+            }
+            | Self::Shape {
+                right_parenthesis, ..
             } => right_parenthesis + 1,
+            Self::KeyOf(_, type_definition) => type_definition.final_position(),
+            Self::IndexedAccess { right_bracket, .. } => right_bracket + 1,
+            Self::IntegerRange { greater_than, .. } => greater_than + 1,
+        };
+
+        Span::new(initial_position, final_position)
+    }
+
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        match &self {
+            Self::Identifier(inner) => vec![("identifier", inner)],
+            Self::Nullable(_, inner) => vec![("type_definition", inner.as_ref())],
+            Self::Variadic(_, inner) => vec![("type_definition", inner.as_ref())],
+            Self::Union(inner) | Self::Intersection(inner) => {
+                inner.iter().map(|t| ("types", t as &dyn Node)).collect()
+            }
+            Self::Void(keyword)
+            | Self::Object(keyword)
+            | Self::NonNull(keyword)
+            | Self::Mixed(keyword)
+            | Self::Resource(keyword)
+            | Self::Never(keyword)
+            | Self::Boolean(keyword)
+            | Self::String(keyword)
+            | Self::Self_(keyword)
+            | Self::Static(keyword)
+            | Self::Parent(keyword) => vec![("keyword", keyword)],
+            Self::Literal(literal) => vec![("literal", literal)],
+            Self::SignedInteger(signed) => vec![("signed", signed)],
+            Self::UnsignedInteger(unsigned) => vec![("unsigned", unsigned)],
+            Self::FloatingPoint(floating) => vec![("floating", floating)],
+            Self::Class(keyword, template)
+            | Self::Interface(keyword, template)
+            | Self::Iterable(keyword, template)
+            | Self::Dict(keyword, template)
+            | Self::Vec(keyword, template) => vec![("keyword", keyword), ("template", template)],
+            Self::Unit { .. } => vec![],
+            Self::Tuple { elements, .. } => elements
+                .inner
+                .iter()
+                .map(|t| ("elements", t as &dyn Node))
+                .collect::<Vec<(&'static str, &dyn Node)>>(),
+            Self::Parenthesized {
+                type_definition, ..
+            } => vec![("type_definition", type_definition.as_ref())],
+            Self::KeyOf(keyword, type_definition) => vec![
+                ("keyword", keyword),
+                ("type_definition", type_definition.as_ref()),
+            ],
+            Self::IndexedAccess {
+                type_definition,
+                index,
+                ..
+            } => vec![
+                ("type_definition", type_definition.as_ref()),
+                ("index", index.as_ref()),
+            ],
+            Self::Shape { shape, fields, .. } => {
+                let mut children: Vec<(&'static str, &dyn Node)> = vec![("shape", shape)];
+
+                for field in &fields.inner {
+                    children.push(("fields", field));
+                }
+
+                children
+            }
+            Self::IntegerRange { int, min, max, .. } => {
+                vec![("int", int), ("min", min), ("max", max)]
+            }
         }
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
+    fn lists(&self) -> Vec<ListView<'_>> {
         match &self {
+            Self::Tuple { elements, .. } => vec![elements.view()],
+            Self::Shape { fields, .. } => vec![fields.view()],
+            _ => vec![],
+        }
+    }
+
+    fn tokens(&self) -> Vec<&[u8]> {
+        match &self {
+            Self::Shape {
+                ellipsis: Some(_), ..
+            } => vec![b"..."],
+            _ => vec![],
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
             Self::Identifier(inner) => vec![inner],
-            Self::Nullable(_, inner) => vec![inner.as_ref()],
+            Self::Nullable(_, inner) => vec![inner.as_mut()],
+            Self::Variadic(_, inner) => vec![inner.as_mut()],
             Self::Union(inner) | Self::Intersection(inner) => {
-                inner.iter().map(|t| t as &dyn Node).collect()
+                inner.iter_mut().map(|t| t as &mut dyn Node).collect()
             }
             Self::Void(keyword)
             | Self::Object(keyword)
@@ -328,7 +606,10 @@ impl Node for TypeDefinition {
             | Self::Resource(keyword)
             | Self::Never(keyword)
             | Self::Boolean(keyword)
-            | Self::String(keyword) => vec![keyword],
+            | Self::String(keyword)
+            | Self::Self_(keyword)
+            | Self::Static(keyword)
+            | Self::Parent(keyword) => vec![keyword],
             Self::Literal(literal) => vec![literal],
             Self::SignedInteger(signed) => vec![signed],
             Self::UnsignedInteger(unsigned) => vec![unsigned],
@@ -338,19 +619,88 @@ impl Node for TypeDefinition {
             | Self::Iterable(keyword, template)
             | Self::Dict(keyword, template)
             | Self::Vec(keyword, template) => vec![keyword, template],
-            Self::Tuple {
-                type_definitions, ..
-            } => type_definitions
+            Self::Unit { .. } => vec![],
+            Self::Tuple { elements, .. } => elements
                 .inner
-                .iter()
-                .map(|t| t as &dyn Node)
-                .collect::<Vec<&dyn Node>>(),
+                .iter_mut()
+                .map(|t| t as &mut dyn Node)
+                .collect::<Vec<&mut dyn Node>>(),
             Self::Parenthesized {
                 type_definition, ..
-            } => vec![type_definition.as_ref()],
+            } => vec![type_definition.as_mut()],
+            Self::KeyOf(keyword, type_definition) => vec![keyword, type_definition.as_mut()],
+            Self::IndexedAccess {
+                type_definition,
+                index,
+                ..
+            } => vec![type_definition.as_mut(), index.as_mut()],
+            Self::Shape { shape, fields, .. } => {
+                let mut children: Vec<&mut dyn Node> = vec![shape];
+
+                for field in &mut fields.inner {
+                    children.push(field);
+                }
+
+                children
+            }
+            Self::IntegerRange { int, min, max, .. } => vec![int, min, max],
         }
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        match self {
+            Self::Nullable(position, _) => vec![position],
+            Self::Variadic(position, _) => vec![position],
+            Self::Unit {
+                left_parenthesis,
+                right_parenthesis,
+            } => vec![left_parenthesis, right_parenthesis],
+            Self::Tuple {
+                left_parenthesis,
+                elements,
+                right_parenthesis,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![left_parenthesis, right_parenthesis];
+                positions.extend(elements.commas.iter_mut());
+                positions
+            }
+            Self::Parenthesized {
+                left_parenthesis,
+                right_parenthesis,
+                ..
+            } => vec![left_parenthesis, right_parenthesis],
+            Self::IndexedAccess {
+                left_bracket,
+                right_bracket,
+                ..
+            } => vec![left_bracket, right_bracket],
+            Self::Shape {
+                left_parenthesis,
+                fields,
+                ellipsis,
+                right_parenthesis,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![left_parenthesis, right_parenthesis];
+                positions.extend(fields.commas.iter_mut());
+                positions.extend(ellipsis.iter_mut());
+                positions
+            }
+            Self::IntegerRange {
+                less_than,
+                comma,
+                greater_than,
+                ..
+            } => vec![less_than, comma, greater_than],
+            _ => vec![],
+        }
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::TypeDefinition
+    }
+
     fn get_description(&self) -> String {
         match &self {
             Self::Identifier(_) => "identifier type definition".to_string(),
@@ -358,6 +708,7 @@ impl Node for TypeDefinition {
             Self::Intersection(_) => "intersection type definition".to_string(),
             Self::Literal(literal) => literal.get_description(),
             Self::Nullable(_, _) => "nullable type definition".to_string(),
+            Self::Variadic(_, _) => "variadic type definition".to_string(),
             Self::Void(_) => "void type definition".to_string(),
             Self::Never(_) => "never type definition".to_string(),
             Self::Boolean(_) => "boolean type definition".to_string(),
@@ -368,25 +719,235 @@ impl Node for TypeDefinition {
             Self::Mixed(_) => "mixed type definition".to_string(),
             Self::NonNull(_) => "non-null type definition".to_string(),
             Self::Resource(_) => "resource type definition".to_string(),
+            Self::Self_(_) => "self type definition".to_string(),
+            Self::Static(_) => "static type definition".to_string(),
+            Self::Parent(_) => "parent type definition".to_string(),
             Self::Class(_, _) => "class type definition".to_string(),
             Self::Interface(_, _) => "interface type definition".to_string(),
             Self::Iterable(_, _) => "iterable type definition".to_string(),
             Self::SignedInteger(signed) => signed.get_description(),
             Self::UnsignedInteger(unsigned) => unsigned.get_description(),
             Self::FloatingPoint(floating) => floating.get_description(),
+            Self::Unit { .. } => "unit type definition".to_string(),
             Self::Tuple { .. } => "tuple type definition".to_string(),
             Self::Parenthesized { .. } => "parenthesized type definition".to_string(),
+            Self::KeyOf(_, _) => "keyof type definition".to_string(),
+            Self::IndexedAccess { .. } => "indexed access type definition".to_string(),
+            Self::Shape { .. } => "shape type definition".to_string(),
+            Self::IntegerRange { .. } => "integer range type definition".to_string(),
+        }
+    }
+}
+
+impl Node for TupleElementLabelDefinition {
+    fn span(&self) -> Span {
+        Span::new(self.name.initial_position(), self.colon + 1)
+    }
+
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("name", &self.name)]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        vec![&mut self.name]
+    }
+
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        vec![&mut self.colon]
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::TupleElementLabelDefinition
+    }
+
+    fn get_description(&self) -> String {
+        "tuple element label definition".to_string()
+    }
+}
+
+impl Node for TupleElementTypeDefinition {
+    fn span(&self) -> Span {
+        let initial_position = match &self.label {
+            Some(label) => label.initial_position(),
+            None => match &self.optional {
+                Some(optional) => optional.initial_position(),
+                None => self.type_definition.initial_position(),
+            },
+        };
+        let final_position = match &self.variable {
+            Some(variable) => variable.final_position(),
+            None => self.type_definition.final_position(),
+        };
+
+        Span::new(initial_position, final_position)
+    }
+
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut children: Vec<(&'static str, &dyn Node)> = vec![];
+
+        if let Some(label) = &self.label {
+            children.push(("label", label));
+        }
+
+        if let Some(optional) = &self.optional {
+            children.push(("optional", optional));
+        }
+
+        children.push(("type_definition", &self.type_definition));
+
+        if let Some(variable) = &self.variable {
+            children.push(("variable", variable));
+        }
+
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = vec![];
+
+        if let Some(label) = &mut self.label {
+            children.push(label);
+        }
+
+        if let Some(optional) = &mut self.optional {
+            children.push(optional);
+        }
+
+        children.push(&mut self.type_definition);
+
+        if let Some(variable) = &mut self.variable {
+            children.push(variable);
+        }
+
+        children
+    }
+
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        vec![]
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::TupleElementTypeDefinition
+    }
+
+    fn get_description(&self) -> String {
+        "tuple element type definition".to_string()
+    }
+}
+
+impl Node for ShapeFieldTypeDefinition {
+    fn span(&self) -> Span {
+        let initial_position = match self.question {
+            Some(question) => question,
+            None => self.key.initial_position(),
+        };
+
+        Span::new(initial_position, self.type_definition.final_position())
+    }
+
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![
+            ("key", &self.key),
+            ("type_definition", &self.type_definition),
+        ]
+    }
+
+    fn tokens(&self) -> Vec<&[u8]> {
+        match self.question {
+            Some(_) => vec![b"?"],
+            None => vec![],
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        vec![&mut self.key, &mut self.type_definition]
+    }
+
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        let mut positions: Vec<&mut usize> = vec![&mut self.double_arrow];
+        positions.extend(self.question.iter_mut());
+        positions
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::ShapeFieldTypeDefinition
+    }
+
+    fn get_description(&self) -> String {
+        "shape field type definition".to_string()
+    }
+}
+
+impl Node for IntegerRangeBoundTypeDefinition {
+    fn span(&self) -> Span {
+        let initial_position = match &self {
+            Self::Integer { minus, value } => minus.unwrap_or(value.position),
+            Self::Unbounded(keyword) => keyword.initial_position(),
+        };
+        let final_position = match &self {
+            Self::Integer { value, .. } => value.final_position(),
+            Self::Unbounded(keyword) => keyword.final_position(),
+        };
+
+        Span::new(initial_position, final_position)
+    }
+
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        match &self {
+            Self::Integer { value, .. } => vec![("value", value)],
+            Self::Unbounded(keyword) => vec![("keyword", keyword)],
+        }
+    }
+
+    fn tokens(&self) -> Vec<&[u8]> {
+        match &self {
+            Self::Integer { minus: Some(_), .. } => vec![b"-"],
+            _ => vec![],
         }
     }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
+            Self::Integer { value, .. } => vec![value],
+            Self::Unbounded(keyword) => vec![keyword],
+        }
+    }
+
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        match self {
+            Self::Integer { minus, .. } => minus.iter_mut().collect(),
+            Self::Unbounded(_) => vec![],
+        }
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::IntegerRangeBoundTypeDefinition
+    }
+
+    fn get_description(&self) -> String {
+        "integer range bound type definition".to_string()
+    }
 }
 
 impl std::fmt::Display for TypeAliasDefinition {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{} {} = {};",
-            self.r#type, self.name, self.type_definition,
-        )
+        if !self.modifiers.modifiers.is_empty() {
+            write!(f, "{} ", self.modifiers)?;
+        }
+
+        write!(f, "{} {} = ", self.r#type, self.name)?;
+
+        if let Some(new) = &self.new {
+            write!(f, "{} ", new)?;
+        }
+
+        write!(f, "{}", self.type_definition)?;
+
+        if let Some(constraints) = &self.constraints {
+            write!(f, " {}", constraints)?;
+        }
+
+        write!(f, ";")
     }
 }
 
@@ -395,6 +956,7 @@ impl std::fmt::Display for TypeDefinition {
         match &self {
             Self::Identifier(inner) => write!(f, "{inner}"),
             Self::Nullable(_, inner) => write!(f, "?{inner}"),
+            Self::Variadic(_, inner) => write!(f, "...{inner}"),
             Self::Union(inner) => write!(
                 f,
                 "{}",
@@ -414,7 +976,7 @@ impl std::fmt::Display for TypeDefinition {
                     .join("&")
             ),
             Self::Void(_) => write!(f, "void"),
-            Self::Never(_) => write!(f, "never"),
+            Self::Never(keyword) => write!(f, "{keyword}"),
             Self::Boolean(_) => write!(f, "bool"),
             Self::Literal(literal) => write!(f, "{literal}"),
             Self::SignedInteger(signed) => write!(f, "{signed}"),
@@ -424,18 +986,21 @@ impl std::fmt::Display for TypeDefinition {
             Self::Dict(_, template) => write!(f, "dict{template}"),
             Self::Vec(_, template) => write!(f, "vec{template}"),
             Self::Iterable(_, template) => write!(f, "iterable{template}"),
-            Self::Class(_, template) => write!(f, "class{template}"),
-            Self::Interface(_, template) => write!(f, "interface{template}"),
+            Self::Class(keyword, template) | Self::Interface(keyword, template) => {
+                write!(f, "{keyword}{template}")
+            }
             Self::Object(_) => write!(f, "object"),
             Self::Mixed(_) => write!(f, "mixed"),
             Self::NonNull(_) => write!(f, "nonnull"),
             Self::Resource(_) => write!(f, "resource"),
-            Self::Tuple {
-                type_definitions, ..
-            } => write!(
+            Self::Self_(keyword) | Self::Static(keyword) | Self::Parent(keyword) => {
+                write!(f, "{keyword}")
+            }
+            Self::Unit { .. } => write!(f, "()"),
+            Self::Tuple { elements, .. } => write!(
                 f,
                 "({})",
-                type_definitions
+                elements
                     .inner
                     .iter()
                     .map(|t| t.to_string())
@@ -447,6 +1012,79 @@ impl std::fmt::Display for TypeDefinition {
             } => {
                 write!(f, "({type_definition})")
             }
+            Self::KeyOf(_, type_definition) => write!(f, "keyof {type_definition}"),
+            Self::IndexedAccess {
+                type_definition,
+                index,
+                ..
+            } => write!(f, "{type_definition}[{index}]"),
+            Self::Shape {
+                fields, ellipsis, ..
+            } => {
+                let mut members = fields
+                    .inner
+                    .iter()
+                    .map(|field| field.to_string())
+                    .collect::<Vec<String>>();
+
+                if ellipsis.is_some() {
+                    members.push("...".to_string());
+                }
+
+                write!(f, "shape({})", members.join(", "))
+            }
+            Self::IntegerRange { min, max, .. } => write!(f, "int<{min}, {max}>"),
+        }
+    }
+}
+
+impl std::fmt::Display for TupleElementLabelDefinition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:", self.name)
+    }
+}
+
+impl std::fmt::Display for TupleElementTypeDefinition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(label) = &self.label {
+            write!(f, "{} ", label)?;
+        }
+
+        if let Some(optional) = &self.optional {
+            write!(f, "{} ", optional)?;
+        }
+
+        write!(f, "{}", self.type_definition)?;
+
+        if let Some(variable) = &self.variable {
+            write!(f, " {}", variable)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for ShapeFieldTypeDefinition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.question.is_some() {
+            write!(f, "?")?;
+        }
+
+        write!(f, "{} => {}", self.key, self.type_definition)
+    }
+}
+
+impl std::fmt::Display for IntegerRangeBoundTypeDefinition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Integer { minus, value } => {
+                if minus.is_some() {
+                    write!(f, "-")?;
+                }
+
+                write!(f, "{value}")
+            }
+            Self::Unbounded(keyword) => write!(f, "{keyword}"),
         }
     }
 }
@@ -490,11 +1128,16 @@ mod tests {
     use super::*;
     use crate::lexer::byte_string::ByteString;
     use crate::tree::comment::CommentGroup;
-    use crate::tree::identifier::Identifier;
+    use crate::tree::expression::literal::LiteralString;
+    use smallvec::smallvec;
 
     #[test]
     fn test_type_alias_definition_display() {
         let type_alias_definition = TypeAliasDefinition {
+            modifiers: ModifierGroupDefinition {
+                position: 0,
+                modifiers: vec![],
+            },
             r#type: Keyword {
                 value: ByteString::from("type"),
                 position: 0,
@@ -507,6 +1150,7 @@ mod tests {
                 templates: None,
             },
             equals: 0,
+            new: None,
             type_definition: TypeDefinition::UnsignedInteger(UnsignedIntegerTypeDefinition::U32(
                 Keyword {
                     value: ByteString::from("u32"),
@@ -514,14 +1158,56 @@ mod tests {
                 },
             )),
             semicolon: 0,
+            constraints: None,
         };
 
         assert_eq!(type_alias_definition.to_string(), "type Foo = u32;");
     }
 
+    #[test]
+    fn test_opaque_type_alias_definition_display() {
+        let type_alias_definition = TypeAliasDefinition {
+            modifiers: ModifierGroupDefinition {
+                position: 0,
+                modifiers: vec![],
+            },
+            r#type: Keyword {
+                value: ByteString::from("type"),
+                position: 0,
+            },
+            name: TemplatedIdentifier {
+                name: Identifier {
+                    position: 0,
+                    value: ByteString::from("UserId"),
+                },
+                templates: None,
+            },
+            equals: 0,
+            new: Some(Keyword {
+                value: ByteString::from("new"),
+                position: 0,
+            }),
+            type_definition: TypeDefinition::SignedInteger(SignedIntegerTypeDefinition::Default(
+                Keyword {
+                    value: ByteString::from("int"),
+                    position: 0,
+                },
+            )),
+            semicolon: 0,
+            constraints: None,
+        };
+
+        assert!(type_alias_definition.is_opaque());
+        assert_eq!(type_alias_definition.to_string(), "type UserId = new int;");
+    }
+
     #[test]
     fn test_type_alias_definition_with_templates_display() {
         let type_alias_definition = TypeAliasDefinition {
+            modifiers: ModifierGroupDefinition {
+                position: 0,
+                modifiers: vec![],
+            },
             r#type: Keyword {
                 value: ByteString::from("type"),
                 position: 0,
@@ -532,7 +1218,7 @@ mod tests {
                     value: ByteString::from("filter"),
                 },
                 templates: Some(TypeTemplateGroupDefinition {
-                    comments: CommentGroup { comments: vec![] },
+                    comments: CommentGroup::default(),
                     less_than: 0,
                     members: CommaSeparated {
                         inner: vec![TypeDefinition::Identifier(TemplatedIdentifier {
@@ -542,33 +1228,41 @@ mod tests {
                             },
                             templates: None,
                         })],
-                        commas: vec![],
+                        commas: smallvec![],
                     },
                     greater_than: 0,
                 }),
             },
             equals: 0,
+            new: None,
             type_definition: TypeDefinition::Identifier(TemplatedIdentifier {
                 name: Identifier {
                     position: 3,
                     value: ByteString::from("Closure"),
                 },
                 templates: Some(TypeTemplateGroupDefinition {
-                    comments: CommentGroup { comments: vec![] },
+                    comments: CommentGroup::default(),
                     less_than: 0,
                     members: CommaSeparated {
                         inner: vec![
                             TypeDefinition::Tuple {
                                 left_parenthesis: 0,
-                                type_definitions: CommaSeparated {
-                                    inner: vec![TypeDefinition::Identifier(TemplatedIdentifier {
-                                        name: Identifier {
-                                            position: 3,
-                                            value: ByteString::from("T"),
-                                        },
-                                        templates: None,
-                                    })],
-                                    commas: vec![],
+                                elements: CommaSeparated {
+                                    inner: vec![TupleElementTypeDefinition {
+                                        label: None,
+                                        optional: None,
+                                        type_definition: TypeDefinition::Identifier(
+                                            TemplatedIdentifier {
+                                                name: Identifier {
+                                                    position: 3,
+                                                    value: ByteString::from("T"),
+                                                },
+                                                templates: None,
+                                            },
+                                        ),
+                                        variable: None,
+                                    }],
+                                    commas: smallvec![],
                                 },
                                 right_parenthesis: 0,
                             },
@@ -577,12 +1271,13 @@ mod tests {
                                 position: 0,
                             }),
                         ],
-                        commas: vec![0],
+                        commas: smallvec![0],
                     },
                     greater_than: 0,
                 }),
             }),
             semicolon: 0,
+            constraints: None,
         };
 
         assert_eq!(
@@ -668,6 +1363,13 @@ mod tests {
 
         assert_eq!(never.to_string(), "never");
 
+        let noreturn = TypeDefinition::Never(Keyword {
+            value: ByteString::from("noreturn"),
+            position: 0,
+        });
+
+        assert_eq!(noreturn.to_string(), "noreturn");
+
         let boolean = TypeDefinition::Boolean(Keyword {
             value: ByteString::from("bool"),
             position: 0,
@@ -688,7 +1390,7 @@ mod tests {
                 position: 0,
             },
             TypeTemplateGroupDefinition {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 less_than: 0,
                 members: CommaSeparated {
                     inner: vec![
@@ -707,7 +1409,7 @@ mod tests {
                             templates: None,
                         }),
                     ],
-                    commas: vec![0],
+                    commas: smallvec![0],
                 },
                 greater_than: 0,
             },
@@ -721,7 +1423,7 @@ mod tests {
                 position: 0,
             },
             TypeTemplateGroupDefinition {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 less_than: 0,
                 members: CommaSeparated {
                     inner: vec![TypeDefinition::Identifier(TemplatedIdentifier {
@@ -731,7 +1433,7 @@ mod tests {
                         },
                         templates: None,
                     })],
-                    commas: vec![],
+                    commas: smallvec![],
                 },
                 greater_than: 0,
             },
@@ -773,7 +1475,7 @@ mod tests {
                 position: 0,
             },
             TypeTemplateGroupDefinition {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 less_than: 0,
                 members: CommaSeparated {
                     inner: vec![TypeDefinition::Identifier(TemplatedIdentifier {
@@ -783,7 +1485,7 @@ mod tests {
                         },
                         templates: None,
                     })],
-                    commas: vec![],
+                    commas: smallvec![],
                 },
                 greater_than: 0,
             },
@@ -797,7 +1499,7 @@ mod tests {
                 position: 0,
             },
             TypeTemplateGroupDefinition {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 less_than: 0,
                 members: CommaSeparated {
                     inner: vec![TypeDefinition::Identifier(TemplatedIdentifier {
@@ -807,7 +1509,7 @@ mod tests {
                         },
                         templates: None,
                     })],
-                    commas: vec![],
+                    commas: smallvec![],
                 },
                 greater_than: 0,
             },
@@ -821,7 +1523,7 @@ mod tests {
                 position: 0,
             },
             TypeTemplateGroupDefinition {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 less_than: 0,
                 members: CommaSeparated {
                     inner: vec![TypeDefinition::Identifier(TemplatedIdentifier {
@@ -831,7 +1533,7 @@ mod tests {
                         },
                         templates: None,
                     })],
-                    commas: vec![],
+                    commas: smallvec![],
                 },
                 greater_than: 0,
             },
@@ -839,32 +1541,170 @@ mod tests {
 
         assert_eq!(interface.to_string(), "interface<Foo>");
 
-        let tuple = TypeDefinition::Tuple {
-            left_parenthesis: 0,
-            type_definitions: CommaSeparated {
-                inner: vec![
-                    TypeDefinition::Identifier(TemplatedIdentifier {
+        let class_string = TypeDefinition::Class(
+            Keyword {
+                value: ByteString::from("class-string"),
+                position: 0,
+            },
+            TypeTemplateGroupDefinition {
+                comments: CommentGroup::default(),
+                less_than: 0,
+                members: CommaSeparated {
+                    inner: vec![TypeDefinition::Identifier(TemplatedIdentifier {
                         name: Identifier {
                             position: 0,
                             value: ByteString::from("Foo"),
                         },
                         templates: None,
-                    }),
-                    TypeDefinition::Identifier(TemplatedIdentifier {
-                        name: Identifier {
-                            position: 0,
-                            value: ByteString::from("Bar"),
-                        },
-                        templates: None,
-                    }),
+                    })],
+                    commas: smallvec![],
+                },
+                greater_than: 0,
+            },
+        );
+
+        assert_eq!(class_string.to_string(), "class-string<Foo>");
+
+        let r#static = TypeDefinition::Static(Keyword {
+            value: ByteString::from("static"),
+            position: 0,
+        });
+
+        assert_eq!(r#static.to_string(), "static");
+
+        let tuple = TypeDefinition::Tuple {
+            left_parenthesis: 0,
+            elements: CommaSeparated {
+                inner: vec![
+                    TupleElementTypeDefinition {
+                        label: None,
+                        optional: None,
+                        type_definition: TypeDefinition::Identifier(TemplatedIdentifier {
+                            name: Identifier {
+                                position: 0,
+                                value: ByteString::from("Foo"),
+                            },
+                            templates: None,
+                        }),
+                        variable: None,
+                    },
+                    TupleElementTypeDefinition {
+                        label: None,
+                        optional: None,
+                        type_definition: TypeDefinition::Identifier(TemplatedIdentifier {
+                            name: Identifier {
+                                position: 0,
+                                value: ByteString::from("Bar"),
+                            },
+                            templates: None,
+                        }),
+                        variable: None,
+                    },
                 ],
-                commas: vec![0],
+                commas: smallvec![0],
             },
             right_parenthesis: 0,
         };
 
         assert_eq!(tuple.to_string(), "(Foo, Bar)");
 
+        let unit = TypeDefinition::Unit {
+            left_parenthesis: 0,
+            right_parenthesis: 1,
+        };
+
+        assert_eq!(unit.to_string(), "()");
+
+        let named_tuple = TypeDefinition::Tuple {
+            left_parenthesis: 0,
+            elements: CommaSeparated {
+                inner: vec![
+                    TupleElementTypeDefinition {
+                        label: Some(TupleElementLabelDefinition {
+                            name: Identifier {
+                                position: 0,
+                                value: ByteString::from("x"),
+                            },
+                            colon: 0,
+                        }),
+                        optional: None,
+                        type_definition: TypeDefinition::SignedInteger(
+                            SignedIntegerTypeDefinition::Default(Keyword {
+                                value: ByteString::from("int"),
+                                position: 0,
+                            }),
+                        ),
+                        variable: None,
+                    },
+                    TupleElementTypeDefinition {
+                        label: Some(TupleElementLabelDefinition {
+                            name: Identifier {
+                                position: 0,
+                                value: ByteString::from("y"),
+                            },
+                            colon: 0,
+                        }),
+                        optional: None,
+                        type_definition: TypeDefinition::SignedInteger(
+                            SignedIntegerTypeDefinition::Default(Keyword {
+                                value: ByteString::from("int"),
+                                position: 0,
+                            }),
+                        ),
+                        variable: None,
+                    },
+                ],
+                commas: smallvec![0],
+            },
+            right_parenthesis: 0,
+        };
+
+        assert_eq!(named_tuple.to_string(), "(x: int, y: int)");
+
+        let parameters = TypeDefinition::Tuple {
+            left_parenthesis: 0,
+            elements: CommaSeparated {
+                inner: vec![
+                    TupleElementTypeDefinition {
+                        label: None,
+                        optional: None,
+                        type_definition: TypeDefinition::SignedInteger(
+                            SignedIntegerTypeDefinition::Default(Keyword {
+                                value: ByteString::from("int"),
+                                position: 0,
+                            }),
+                        ),
+                        variable: Some(Variable {
+                            position: 0,
+                            name: ByteString::from("limit"),
+                        }),
+                    },
+                    TupleElementTypeDefinition {
+                        label: None,
+                        optional: Some(Keyword {
+                            value: ByteString::from("optional"),
+                            position: 0,
+                        }),
+                        type_definition: TypeDefinition::String(Keyword {
+                            value: ByteString::from("string"),
+                            position: 0,
+                        }),
+                        variable: Some(Variable {
+                            position: 0,
+                            name: ByteString::from("cursor"),
+                        }),
+                    },
+                ],
+                commas: smallvec![0],
+            },
+            right_parenthesis: 0,
+        };
+
+        assert_eq!(
+            parameters.to_string(),
+            "(int $limit, optional string $cursor)"
+        );
+
         let parenthesized = TypeDefinition::Parenthesized {
             left_parenthesis: 0,
             type_definition: Box::new(TypeDefinition::Identifier(TemplatedIdentifier {
@@ -878,5 +1718,84 @@ mod tests {
         };
 
         assert_eq!(parenthesized.to_string(), "(Foo)");
+
+        let shape = TypeDefinition::Shape {
+            shape: Keyword {
+                value: ByteString::from("shape"),
+                position: 0,
+            },
+            left_parenthesis: 0,
+            fields: CommaSeparated {
+                inner: vec![
+                    ShapeFieldTypeDefinition {
+                        question: None,
+                        key: Literal::String(LiteralString {
+                            comments: CommentGroup::default(),
+                            value: ByteString::from("'id'"),
+                            position: 0,
+                        }),
+                        double_arrow: 0,
+                        type_definition: TypeDefinition::SignedInteger(
+                            SignedIntegerTypeDefinition::Default(Keyword {
+                                value: ByteString::from("int"),
+                                position: 0,
+                            }),
+                        ),
+                    },
+                    ShapeFieldTypeDefinition {
+                        question: Some(0),
+                        key: Literal::String(LiteralString {
+                            comments: CommentGroup::default(),
+                            value: ByteString::from("'name'"),
+                            position: 0,
+                        }),
+                        double_arrow: 0,
+                        type_definition: TypeDefinition::String(Keyword {
+                            value: ByteString::from("string"),
+                            position: 0,
+                        }),
+                    },
+                ],
+                commas: smallvec![0],
+            },
+            ellipsis: Some(0),
+            right_parenthesis: 0,
+        };
+
+        assert_eq!(
+            shape.to_string(),
+            "shape('id' => int, ?'name' => string, ...)"
+        );
+
+        let user = TypeDefinition::Identifier(TemplatedIdentifier {
+            name: Identifier {
+                position: 0,
+                value: ByteString::from("User"),
+            },
+            templates: None,
+        });
+
+        let keyof = TypeDefinition::KeyOf(
+            Keyword {
+                value: ByteString::from("keyof"),
+                position: 0,
+            },
+            Box::new(user.clone()),
+        );
+
+        assert_eq!(keyof.to_string(), "keyof User");
+
+        let indexed_access = TypeDefinition::IndexedAccess {
+            type_definition: Box::new(user),
+            left_bracket: 0,
+            index: Box::new(TypeDefinition::Literal(Literal::String(LiteralString {
+                comments: CommentGroup::default(),
+                value: ByteString::from("'id'"),
+                position: 0,
+            }))),
+            right_bracket: 0,
+        };
+
+        assert_eq!(indexed_access.to_string(), "User['id']");
     }
 }
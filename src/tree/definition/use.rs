@@ -5,7 +5,11 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use crate::tree::identifier::Identifier;
+use crate::tree::kind::NodeKind;
+use crate::tree::span::Span;
 use crate::tree::token::Keyword;
+use crate::tree::utils::CommaSeparated;
+use crate::tree::utils::ListView;
 use crate::tree::Node;
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
@@ -32,6 +36,34 @@ pub enum UseDefinition {
         alias: Option<UseDefinitionSymbolAlias>,
         semicolon: usize,
     },
+    // use Foo\{Bar, Baz as Qux, function helper, const MAX};
+    Group {
+        r#use: Keyword,
+        prefix: Identifier,
+        left_brace: usize,
+        members: CommaSeparated<UseDefinitionGroupMember>,
+        right_brace: usize,
+        semicolon: usize,
+    },
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
+#[serde(rename_all = "snake_case", tag = "type", content = "value")]
+pub enum UseDefinitionGroupMember {
+    Default {
+        name: Identifier,
+        alias: Option<UseDefinitionSymbolAlias>,
+    },
+    Function {
+        function: Keyword,
+        name: Identifier,
+        alias: Option<UseDefinitionSymbolAlias>,
+    },
+    Constant {
+        r#const: Keyword,
+        name: Identifier,
+        alias: Option<UseDefinitionSymbolAlias>,
+    },
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
@@ -42,28 +74,91 @@ pub struct UseDefinitionSymbolAlias {
 }
 
 impl Node for UseDefinition {
-    fn initial_position(&self) -> usize {
-        match &self {
+    fn span(&self) -> Span {
+        let initial_position = match &self {
             Self::Default { r#use, .. }
             | Self::Function { r#use, .. }
-            | Self::Constant { r#use, .. } => r#use.initial_position(),
-        }
+            | Self::Constant { r#use, .. }
+            | Self::Group { r#use, .. } => r#use.initial_position(),
+        };
+        let final_position = match &self {
+            Self::Default { semicolon, .. }
+            | Self::Function { semicolon, .. }
+            | Self::Constant { semicolon, .. }
+            | Self::Group { semicolon, .. } => semicolon + 1,
+        };
+
+        Span::new(initial_position, final_position)
     }
 
-    fn final_position(&self) -> usize {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
         match &self {
-            Self::Default { semicolon, .. }
-            | Self::Function { semicolon, .. }
-            | Self::Constant { semicolon, .. } => semicolon + 1,
+            Self::Default {
+                r#use, name, alias, ..
+            } => {
+                let mut children: Vec<(&'static str, &dyn Node)> =
+                    vec![("use", r#use), ("name", name)];
+
+                if let Some(alias) = alias {
+                    children.push(("alias", alias));
+                }
+
+                children
+            }
+            Self::Function {
+                r#use,
+                function: r#type,
+                name,
+                alias,
+                ..
+            }
+            | Self::Constant {
+                r#use,
+                r#const: r#type,
+                name,
+                alias,
+                ..
+            } => {
+                let mut children: Vec<(&'static str, &dyn Node)> =
+                    vec![("use", r#use), ("type", r#type), ("name", name)];
+
+                if let Some(alias) = alias {
+                    children.push(("alias", alias));
+                }
+
+                children
+            }
+            Self::Group {
+                r#use,
+                prefix,
+                members,
+                ..
+            } => {
+                let mut children: Vec<(&'static str, &dyn Node)> =
+                    vec![("use", r#use), ("prefix", prefix)];
+
+                for member in &members.inner {
+                    children.push(("members", member));
+                }
+
+                children
+            }
         }
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
+    fn lists(&self) -> Vec<ListView<'_>> {
         match &self {
+            Self::Group { members, .. } => vec![members.view()],
+            _ => vec![],
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
             Self::Default {
                 r#use, name, alias, ..
             } => {
-                let mut children: Vec<&dyn Node> = vec![r#use, name];
+                let mut children: Vec<&mut dyn Node> = vec![r#use, name];
 
                 if let Some(alias) = alias {
                     children.push(alias);
@@ -85,7 +180,7 @@ impl Node for UseDefinition {
                 alias,
                 ..
             } => {
-                let mut children: Vec<&dyn Node> = vec![r#use, r#type, name];
+                let mut children: Vec<&mut dyn Node> = vec![r#use, r#type, name];
 
                 if let Some(alias) = alias {
                     children.push(alias);
@@ -93,29 +188,176 @@ impl Node for UseDefinition {
 
                 children
             }
+            Self::Group {
+                r#use,
+                prefix,
+                members,
+                ..
+            } => {
+                let mut children: Vec<&mut dyn Node> = vec![r#use, prefix];
+
+                for member in &mut members.inner {
+                    children.push(member);
+                }
+
+                children
+            }
+        }
+    }
+
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        match self {
+            Self::Default { semicolon, .. } => vec![semicolon],
+            Self::Function { semicolon, .. } => vec![semicolon],
+            Self::Constant { semicolon, .. } => vec![semicolon],
+            Self::Group {
+                left_brace,
+                members,
+                right_brace,
+                semicolon,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![left_brace, right_brace, semicolon];
+                positions.extend(members.commas.iter_mut());
+                positions
+            }
         }
     }
 
+    fn kind(&self) -> NodeKind {
+        NodeKind::UseDefinition
+    }
+
     fn get_description(&self) -> String {
         match &self {
             Self::Default { .. } => "use definition".to_string(),
             Self::Function { .. } => "use function definition".to_string(),
             Self::Constant { .. } => "use constant definition".to_string(),
+            Self::Group { .. } => "use group definition".to_string(),
+        }
+    }
+}
+
+impl Node for UseDefinitionGroupMember {
+    fn span(&self) -> Span {
+        let initial_position = match &self {
+            Self::Default { name, .. } => name.initial_position(),
+            Self::Function { function, .. } => function.initial_position(),
+            Self::Constant { r#const, .. } => r#const.initial_position(),
+        };
+        let final_position = match &self {
+            Self::Default { name, alias }
+            | Self::Function { name, alias, .. }
+            | Self::Constant { name, alias, .. } => match alias {
+                Some(alias) => alias.final_position(),
+                None => name.final_position(),
+            },
+        };
+
+        Span::new(initial_position, final_position)
+    }
+
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        match &self {
+            Self::Default { name, alias } => {
+                let mut children: Vec<(&'static str, &dyn Node)> = vec![("name", name)];
+
+                if let Some(alias) = alias {
+                    children.push(("alias", alias));
+                }
+
+                children
+            }
+            Self::Function {
+                function: r#type,
+                name,
+                alias,
+            }
+            | Self::Constant {
+                r#const: r#type,
+                name,
+                alias,
+            } => {
+                let mut children: Vec<(&'static str, &dyn Node)> =
+                    vec![("type", r#type), ("name", name)];
+
+                if let Some(alias) = alias {
+                    children.push(("alias", alias));
+                }
+
+                children
+            }
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
+            Self::Default { name, alias } => {
+                let mut children: Vec<&mut dyn Node> = vec![name];
+
+                if let Some(alias) = alias {
+                    children.push(alias);
+                }
+
+                children
+            }
+            Self::Function {
+                function: r#type,
+                name,
+                alias,
+            }
+            | Self::Constant {
+                r#const: r#type,
+                name,
+                alias,
+            } => {
+                let mut children: Vec<&mut dyn Node> = vec![r#type, name];
+
+                if let Some(alias) = alias {
+                    children.push(alias);
+                }
+
+                children
+            }
+        }
+    }
+
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        vec![]
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::UseDefinitionGroupMember
+    }
+
+    fn get_description(&self) -> String {
+        match &self {
+            Self::Default { .. } => "use group member definition".to_string(),
+            Self::Function { .. } => "use group function member definition".to_string(),
+            Self::Constant { .. } => "use group constant member definition".to_string(),
         }
     }
 }
 
 impl Node for UseDefinitionSymbolAlias {
-    fn initial_position(&self) -> usize {
-        self.r#as.initial_position()
+    fn span(&self) -> Span {
+        Span::new(self.r#as.initial_position(), self.alias.final_position())
     }
 
-    fn final_position(&self) -> usize {
-        self.alias.final_position()
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("as", &self.r#as), ("alias", &self.alias)]
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        vec![&self.r#as, &self.alias]
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        vec![&mut self.r#as, &mut self.alias]
+    }
+
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        vec![]
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::UseDefinitionSymbolAlias
     }
 
     fn get_description(&self) -> String {
@@ -139,14 +381,59 @@ impl std::fmt::Display for UseDefinition {
                 name,
                 ..
             } => write!(f, "{} {} {}", r#use, r#type, name),
+            Self::Group {
+                r#use,
+                prefix,
+                members,
+                ..
+            } => write!(f, "{} {}{{{}}}", r#use, prefix, members),
         }
     }
 }
 
+impl std::fmt::Display for UseDefinitionSymbolAlias {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.r#as, self.alias)
+    }
+}
+
+impl std::fmt::Display for UseDefinitionGroupMember {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let alias = match &self {
+            Self::Default { name, alias } => {
+                write!(f, "{}", name)?;
+
+                alias
+            }
+            Self::Function {
+                function: r#type,
+                name,
+                alias,
+            }
+            | Self::Constant {
+                r#const: r#type,
+                name,
+                alias,
+            } => {
+                write!(f, "{} {}", r#type, name)?;
+
+                alias
+            }
+        };
+
+        if let Some(alias) = alias {
+            write!(f, " {}", alias)?;
+        }
+
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::lexer::byte_string::ByteString;
+    use smallvec::smallvec;
 
     #[test]
     fn test_use_definition_display() {
@@ -194,4 +481,49 @@ mod tests {
 
         assert_eq!(use_definition.to_string(), "use const FOO");
     }
+
+    #[test]
+    fn test_use_group_definition_display() {
+        let use_definition = UseDefinition::Group {
+            r#use: Keyword::new(ByteString::from("use"), 0),
+            prefix: Identifier {
+                position: 0,
+                value: ByteString::from("Foo\\"),
+            },
+            left_brace: 0,
+            members: CommaSeparated {
+                inner: vec![
+                    UseDefinitionGroupMember::Default {
+                        name: Identifier {
+                            position: 0,
+                            value: ByteString::from("Bar"),
+                        },
+                        alias: Some(UseDefinitionSymbolAlias {
+                            r#as: Keyword::new(ByteString::from("as"), 0),
+                            alias: Identifier {
+                                position: 0,
+                                value: ByteString::from("Baz"),
+                            },
+                        }),
+                    },
+                    UseDefinitionGroupMember::Function {
+                        function: Keyword::new(ByteString::from("function"), 0),
+                        name: Identifier {
+                            position: 0,
+                            value: ByteString::from("helper"),
+                        },
+                        alias: None,
+                    },
+                ],
+                commas: smallvec![0],
+            },
+            right_brace: 0,
+            semicolon: 0,
+        };
+
+        assert_eq!(
+            use_definition.to_string(),
+            "use Foo\\{Bar as Baz, function helper}"
+        );
+    }
 }
@@ -7,7 +7,10 @@ use serde::Serialize;
 use crate::tree::comment::CommentGroup;
 use crate::tree::expression::Expression;
 use crate::tree::identifier::Identifier;
+use crate::tree::kind::NodeKind;
+use crate::tree::span::Span;
 use crate::tree::utils::CommaSeparated;
+use crate::tree::utils::ListView;
 use crate::tree::Node;
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
@@ -63,26 +66,34 @@ impl Node for ArgumentExpression {
         }
     }
 
-    fn initial_position(&self) -> usize {
-        match &self {
+    fn span(&self) -> Span {
+        let initial_position = match &self {
             Self::Value { value, .. } | Self::ReverseSpread { value, .. } => {
                 value.initial_position()
             }
             Self::Spread { ellipsis, .. } => *ellipsis,
             Self::Named { name, .. } => name.initial_position(),
-        }
-    }
-
-    fn final_position(&self) -> usize {
-        match &self {
+        };
+        let final_position = match &self {
             Self::Value { value, .. } | Self::Spread { value, .. } => value.final_position(),
             Self::ReverseSpread { ellipsis, .. } => *ellipsis,
             Self::Named { value, .. } => value.final_position(),
-        }
+        };
+
+        Span::new(initial_position, final_position)
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
         match &self {
+            Self::Value { value, .. }
+            | Self::Spread { value, .. }
+            | Self::ReverseSpread { value, .. } => vec![("value", value)],
+            Self::Named { name, value, .. } => vec![("name", name), ("value", value)],
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
             Self::Value { value, .. }
             | Self::Spread { value, .. }
             | Self::ReverseSpread { value, .. } => vec![value],
@@ -90,6 +101,37 @@ impl Node for ArgumentExpression {
         }
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        match self {
+            Self::Value { comments, .. } => comments.positions_mut().collect(),
+            Self::Spread {
+                comments, ellipsis, ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![ellipsis];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::ReverseSpread {
+                comments, ellipsis, ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![ellipsis];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::Named {
+                comments, colon, ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![colon];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+        }
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::ArgumentExpression
+    }
+
     fn get_description(&self) -> String {
         match &self {
             Self::Value { .. } => "value argument expression".to_string(),
@@ -105,22 +147,42 @@ impl Node for ArgumentListExpression {
         Some(&self.comments)
     }
 
-    fn initial_position(&self) -> usize {
-        self.left_parenthesis
+    fn span(&self) -> Span {
+        Span::new(self.left_parenthesis, self.right_parenthesis + 1)
+    }
+
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        self.arguments
+            .inner
+            .iter()
+            .map(|a| ("arguments", a as &dyn Node))
+            .collect()
     }
 
-    fn final_position(&self) -> usize {
-        self.right_parenthesis + 1
+    fn lists(&self) -> Vec<ListView<'_>> {
+        vec![self.arguments.view()]
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         self.arguments
             .inner
-            .iter()
-            .map(|a| a as &dyn Node)
+            .iter_mut()
+            .map(|a| a as &mut dyn Node)
             .collect()
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        let mut positions: Vec<&mut usize> =
+            vec![&mut self.left_parenthesis, &mut self.right_parenthesis];
+        positions.extend(self.comments.positions_mut());
+        positions.extend(self.arguments.commas.iter_mut());
+        positions
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::ArgumentListExpression
+    }
+
     fn get_description(&self) -> String {
         "argument list expression".to_string()
     }
@@ -131,18 +193,32 @@ impl Node for ArgumentPlaceholderExpression {
         Some(&self.comments)
     }
 
-    fn initial_position(&self) -> usize {
-        self.left_parenthesis
+    fn span(&self) -> Span {
+        Span::new(self.left_parenthesis, self.right_parenthesis + 1)
     }
 
-    fn final_position(&self) -> usize {
-        self.right_parenthesis + 1
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![]
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![]
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        let mut positions: Vec<&mut usize> = vec![
+            &mut self.left_parenthesis,
+            &mut self.ellipsis,
+            &mut self.right_parenthesis,
+        ];
+        positions.extend(self.comments.positions_mut());
+        positions
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::ArgumentPlaceholderExpression
+    }
+
     fn get_description(&self) -> String {
         "argument placeholder expression".to_string()
     }
@@ -184,7 +260,7 @@ mod tests {
     #[test]
     fn test_argument_expression_display() {
         let argument = ArgumentExpression::Value {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             value: Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("a"),
@@ -193,7 +269,7 @@ mod tests {
         assert_eq!(argument.to_string(), "$a");
 
         let argument = ArgumentExpression::Spread {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             ellipsis: 0,
             value: Expression::Variable(Variable {
                 position: 0,
@@ -203,7 +279,7 @@ mod tests {
         assert_eq!(argument.to_string(), "...$a");
 
         let argument = ArgumentExpression::ReverseSpread {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             value: Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("a"),
@@ -213,14 +289,14 @@ mod tests {
         assert_eq!(argument.to_string(), "$a...");
 
         let argument = ArgumentExpression::Named {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             name: Identifier {
                 position: 0,
                 value: ByteString::from("a"),
             },
             colon: 1,
             value: Expression::Literal(Integer(LiteralInteger {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 position: 0,
                 value: ByteString::from("1"),
             })),
@@ -5,9 +5,13 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use crate::tree::comment::CommentGroup;
+use crate::tree::expression::generic::GenericGroupExpression;
 use crate::tree::expression::Expression;
+use crate::tree::kind::NodeKind;
+use crate::tree::span::Span;
 use crate::tree::token::Keyword;
 use crate::tree::utils::CommaSeparated;
+use crate::tree::utils::ListView;
 use crate::tree::Node;
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
@@ -15,6 +19,9 @@ use crate::tree::Node;
 pub struct VecExpression {
     pub comments: CommentGroup,
     pub vec: Keyword,
+    /// The generic arguments of the literal, e.g. `<int>` in `vec<int>[...]`, where
+    /// `double_colon_less_than` is the position of the `<`.
+    pub generics: Option<GenericGroupExpression>,
     pub left_bracket: usize,
     pub elements: CommaSeparated<VecElementExpression>,
     pub right_bracket: usize,
@@ -31,6 +38,9 @@ pub struct VecElementExpression {
 pub struct DictExpression {
     pub comments: CommentGroup,
     pub dict: Keyword,
+    /// The generic arguments of the literal, e.g. `<int>` in `dict<int>[...]`, where
+    /// `double_colon_less_than` is the position of the `<`.
+    pub generics: Option<GenericGroupExpression>,
     pub left_bracket: usize,
     pub elements: CommaSeparated<DictElementExpression>,
     pub right_bracket: usize,
@@ -54,16 +64,24 @@ pub struct TupleExpression {
 }
 
 impl Node for VecElementExpression {
-    fn initial_position(&self) -> usize {
-        self.value.initial_position()
+    fn span(&self) -> Span {
+        self.value.span()
     }
 
-    fn final_position(&self) -> usize {
-        self.value.final_position()
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("value", &self.value)]
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        vec![&self.value]
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        vec![&mut self.value]
+    }
+
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        vec![]
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::VecElementExpression
     }
 
     fn get_description(&self) -> String {
@@ -76,39 +94,75 @@ impl Node for VecExpression {
         Some(&self.comments)
     }
 
-    fn initial_position(&self) -> usize {
-        self.vec.initial_position()
+    fn span(&self) -> Span {
+        Span::new(self.vec.initial_position(), self.right_bracket + 1)
     }
 
-    fn final_position(&self) -> usize {
-        self.right_bracket + 1
-    }
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut children: Vec<(&'static str, &dyn Node)> = vec![("vec", &self.vec)];
+        if let Some(generics) = &self.generics {
+            children.push(("generics", generics));
+        }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        let mut children: Vec<&dyn Node> = vec![&self.vec];
         for element in &self.elements.inner {
+            children.push(("elements", element));
+        }
+
+        children
+    }
+
+    fn lists(&self) -> Vec<ListView<'_>> {
+        vec![self.elements.view()]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = vec![&mut self.vec];
+        if let Some(generics) = &mut self.generics {
+            children.push(generics);
+        }
+
+        for element in &mut self.elements.inner {
             children.push(element);
         }
 
         children
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        let mut positions: Vec<&mut usize> = vec![&mut self.left_bracket, &mut self.right_bracket];
+        positions.extend(self.comments.positions_mut());
+        positions.extend(self.elements.commas.iter_mut());
+        positions
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::VecExpression
+    }
+
     fn get_description(&self) -> String {
         "vec expression".to_string()
     }
 }
 
 impl Node for DictElementExpression {
-    fn initial_position(&self) -> usize {
-        self.key.initial_position()
+    fn span(&self) -> Span {
+        Span::new(self.key.initial_position(), self.value.final_position())
     }
 
-    fn final_position(&self) -> usize {
-        self.value.final_position()
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("key", &self.key), ("value", &self.value)]
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        vec![&self.key, &self.value]
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        vec![&mut self.key, &mut self.value]
+    }
+
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        vec![&mut self.double_arrow]
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::DictElementExpression
     }
 
     fn get_description(&self) -> String {
@@ -121,23 +175,51 @@ impl Node for DictExpression {
         Some(&self.comments)
     }
 
-    fn initial_position(&self) -> usize {
-        self.dict.initial_position()
+    fn span(&self) -> Span {
+        Span::new(self.dict.initial_position(), self.right_bracket + 1)
     }
 
-    fn final_position(&self) -> usize {
-        self.right_bracket + 1
-    }
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut children: Vec<(&'static str, &dyn Node)> = vec![("dict", &self.dict)];
+        if let Some(generics) = &self.generics {
+            children.push(("generics", generics));
+        }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        let mut children: Vec<&dyn Node> = vec![&self.dict];
         for element in &self.elements.inner {
+            children.push(("elements", element));
+        }
+
+        children
+    }
+
+    fn lists(&self) -> Vec<ListView<'_>> {
+        vec![self.elements.view()]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = vec![&mut self.dict];
+        if let Some(generics) = &mut self.generics {
+            children.push(generics);
+        }
+
+        for element in &mut self.elements.inner {
             children.push(element);
         }
 
         children
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        let mut positions: Vec<&mut usize> = vec![&mut self.left_bracket, &mut self.right_bracket];
+        positions.extend(self.comments.positions_mut());
+        positions.extend(self.elements.commas.iter_mut());
+        positions
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::DictExpression
+    }
+
     fn get_description(&self) -> String {
         "dict expression".to_string()
     }
@@ -148,22 +230,42 @@ impl Node for TupleExpression {
         Some(&self.comments)
     }
 
-    fn initial_position(&self) -> usize {
-        self.left_parenthesis
+    fn span(&self) -> Span {
+        Span::new(self.left_parenthesis, self.right_parenthesis + 1)
     }
 
-    fn final_position(&self) -> usize {
-        self.right_parenthesis + 1
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        self.elements
+            .inner
+            .iter()
+            .map(|element| ("elements", element as &dyn Node))
+            .collect()
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
+    fn lists(&self) -> Vec<ListView<'_>> {
+        vec![self.elements.view()]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         self.elements
             .inner
-            .iter()
-            .map(|element| element as &dyn Node)
+            .iter_mut()
+            .map(|element| element as &mut dyn Node)
             .collect()
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        let mut positions: Vec<&mut usize> =
+            vec![&mut self.left_parenthesis, &mut self.right_parenthesis];
+        positions.extend(self.comments.positions_mut());
+        positions.extend(self.elements.commas.iter_mut());
+        positions
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::TupleExpression
+    }
+
     fn get_description(&self) -> String {
         "tuple expression".to_string()
     }
@@ -171,7 +273,10 @@ impl Node for TupleExpression {
 
 impl std::fmt::Display for VecExpression {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}[{}]", self.vec, self.elements)
+        match &self.generics {
+            Some(generics) => write!(f, "{}<{}>[{}]", self.vec, generics.types, self.elements),
+            None => write!(f, "{}[{}]", self.vec, self.elements),
+        }
     }
 }
 
@@ -183,7 +288,10 @@ impl std::fmt::Display for VecElementExpression {
 
 impl std::fmt::Display for DictExpression {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}[{}]", self.dict, self.elements)
+        match &self.generics {
+            Some(generics) => write!(f, "{}<{}>[{}]", self.dict, generics.types, self.elements),
+            None => write!(f, "{}[{}]", self.dict, self.elements),
+        }
     }
 }
 
@@ -208,31 +316,33 @@ mod tests {
     use crate::tree::expression::literal::LiteralInteger;
     use crate::tree::expression::literal::LiteralString;
     use crate::tree::expression::Expression;
+    use smallvec::smallvec;
 
     #[test]
     fn test_vec_expression_display() {
         let vec_expression = VecExpression {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             vec: Keyword::new(ByteString::from("vec"), 0),
+            generics: None,
             left_bracket: 0,
             elements: CommaSeparated {
                 inner: vec![
                     VecElementExpression {
                         value: Expression::Literal(Integer(LiteralInteger {
-                            comments: CommentGroup { comments: vec![] },
+                            comments: CommentGroup::default(),
                             position: 0,
                             value: ByteString::from("1"),
                         })),
                     },
                     VecElementExpression {
                         value: Expression::Literal(Integer(LiteralInteger {
-                            comments: CommentGroup { comments: vec![] },
+                            comments: CommentGroup::default(),
                             position: 0,
                             value: ByteString::from("2"),
                         })),
                     },
                 ],
-                commas: vec![],
+                commas: smallvec![],
             },
             right_bracket: 0,
         };
@@ -243,39 +353,40 @@ mod tests {
     #[test]
     fn test_dict_expression_display() {
         let dict_expression = DictExpression {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             dict: Keyword::new(ByteString::from("dict"), 0),
+            generics: None,
             left_bracket: 0,
             elements: CommaSeparated {
                 inner: vec![
                     DictElementExpression {
                         key: Expression::Literal(String(LiteralString {
-                            comments: CommentGroup { comments: vec![] },
+                            comments: CommentGroup::default(),
                             position: 0,
                             value: ByteString::from("\"a\""),
                         })),
                         double_arrow: 0,
                         value: Expression::Literal(Integer(LiteralInteger {
-                            comments: CommentGroup { comments: vec![] },
+                            comments: CommentGroup::default(),
                             position: 0,
                             value: ByteString::from("1"),
                         })),
                     },
                     DictElementExpression {
                         key: Expression::Literal(String(LiteralString {
-                            comments: CommentGroup { comments: vec![] },
+                            comments: CommentGroup::default(),
                             position: 0,
                             value: ByteString::from("\"b\""),
                         })),
                         double_arrow: 0,
                         value: Expression::Literal(Integer(LiteralInteger {
-                            comments: CommentGroup { comments: vec![] },
+                            comments: CommentGroup::default(),
                             position: 0,
                             value: ByteString::from("2"),
                         })),
                     },
                 ],
-                commas: vec![],
+                commas: smallvec![],
             },
             right_bracket: 0,
         };
@@ -286,27 +397,27 @@ mod tests {
     #[test]
     fn test_tuple_expression_display() {
         let tuple_expression = TupleExpression {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             left_parenthesis: 0,
             elements: CommaSeparated {
                 inner: vec![
                     Expression::Literal(Integer(LiteralInteger {
-                        comments: CommentGroup { comments: vec![] },
+                        comments: CommentGroup::default(),
                         position: 0,
                         value: ByteString::from("1"),
                     })),
                     Expression::Literal(Integer(LiteralInteger {
-                        comments: CommentGroup { comments: vec![] },
+                        comments: CommentGroup::default(),
                         position: 0,
                         value: ByteString::from("2"),
                     })),
                     Expression::Literal(Integer(LiteralInteger {
-                        comments: CommentGroup { comments: vec![] },
+                        comments: CommentGroup::default(),
                         position: 0,
                         value: ByteString::from("3"),
                     })),
                 ],
-                commas: vec![],
+                commas: smallvec![],
             },
             right_parenthesis: 0,
         };
@@ -10,6 +10,8 @@ use crate::tree::definition::class::ClassDefinitionBody;
 use crate::tree::definition::class::ClassDefinitionExtends;
 use crate::tree::definition::class::ClassDefinitionImplements;
 use crate::tree::expression::argument::ArgumentListExpression;
+use crate::tree::kind::NodeKind;
+use crate::tree::span::Span;
 use crate::tree::token::Keyword;
 use crate::tree::Node;
 
@@ -30,36 +32,62 @@ impl Node for AnonymousClassExpression {
         Some(&self.comments)
     }
 
-    fn initial_position(&self) -> usize {
-        self.class.initial_position()
+    fn span(&self) -> Span {
+        Span::new(self.class.initial_position(), self.body.final_position())
     }
 
-    fn final_position(&self) -> usize {
-        self.body.final_position()
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut children: Vec<(&'static str, &dyn Node)> = vec![("class", &self.class)];
+
+        for attribute in &self.attributes {
+            children.push(("attributes", attribute));
+        }
+
+        children.push(("arguments", &self.arguments));
+
+        if let Some(extends) = &self.extends {
+            children.push(("extends", extends));
+        }
+
+        if let Some(implements) = &self.implements {
+            children.push(("implements", implements));
+        }
+
+        children.push(("body", &self.body));
+
+        children
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        let mut children: Vec<&dyn Node> = vec![&self.class];
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = vec![&mut self.class];
 
-        for attribute in &self.attributes {
+        for attribute in &mut self.attributes {
             children.push(attribute);
         }
 
-        children.push(&self.arguments);
+        children.push(&mut self.arguments);
 
-        if let Some(extends) = &self.extends {
+        if let Some(extends) = &mut self.extends {
             children.push(extends);
         }
 
-        if let Some(implements) = &self.implements {
+        if let Some(implements) = &mut self.implements {
             children.push(implements);
         }
 
-        children.push(&self.body);
+        children.push(&mut self.body);
 
         children
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        self.comments.positions_mut().collect()
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::AnonymousClassExpression
+    }
+
     fn get_description(&self) -> String {
         "anonymous class expression".to_string()
     }
@@ -96,26 +124,27 @@ mod tests {
     use crate::tree::identifier::Identifier;
     use crate::tree::identifier::TemplatedIdentifier;
     use crate::tree::utils::CommaSeparated;
+    use smallvec::smallvec;
 
     #[test]
     fn anonymous_class_expression_display() {
         let anonymous_class = AnonymousClassExpression {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             attributes: vec![],
             class: Keyword::new(ByteString::from("class"), 0),
             arguments: ArgumentListExpression {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 left_parenthesis: 0,
                 arguments: CommaSeparated {
                     inner: vec![ArgumentExpression::Value {
-                        comments: CommentGroup { comments: vec![] },
+                        comments: CommentGroup::default(),
                         value: Expression::Literal(Integer(LiteralInteger {
-                            comments: CommentGroup { comments: vec![] },
+                            comments: CommentGroup::default(),
                             position: 0,
                             value: ByteString::from("1"),
                         })),
                     }],
-                    commas: vec![],
+                    commas: smallvec![],
                 },
                 right_parenthesis: 0,
             },
@@ -148,7 +177,7 @@ mod tests {
                             templates: None,
                         },
                     ],
-                    commas: vec![],
+                    commas: smallvec![],
                 },
             }),
             body: ClassDefinitionBody {
@@ -6,6 +6,8 @@ use serde::Serialize;
 
 use crate::tree::comment::CommentGroup;
 use crate::tree::expression::Expression;
+use crate::tree::kind::NodeKind;
+use crate::tree::span::Span;
 use crate::tree::token::Keyword;
 use crate::tree::Node;
 
@@ -34,27 +36,39 @@ impl Node for ExitConstructExpression {
         }
     }
 
-    fn initial_position(&self) -> usize {
-        match &self {
+    fn span(&self) -> Span {
+        let initial_position = match &self {
             Self::Exit { exit, .. } | Self::ExitWith { exit, .. } => exit.initial_position(),
-        }
-    }
-
-    fn final_position(&self) -> usize {
-        match &self {
+        };
+        let final_position = match &self {
             Self::Exit { exit, .. } => exit.final_position(),
             Self::ExitWith {
                 right_parenthesis, ..
             } => right_parenthesis + 1,
-        }
+        };
+
+        Span::new(initial_position, final_position)
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
         match &self {
+            Self::Exit { exit, .. } => vec![("exit", exit)],
+            Self::ExitWith { exit, value, .. } => {
+                if let Some(value) = value {
+                    vec![("exit", exit), ("value", value.as_ref())]
+                } else {
+                    vec![("exit", exit)]
+                }
+            }
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
             Self::Exit { exit, .. } => vec![exit],
             Self::ExitWith { exit, value, .. } => {
                 if let Some(value) = value {
-                    vec![exit, value.as_ref()]
+                    vec![exit, value.as_mut()]
                 } else {
                     vec![exit]
                 }
@@ -62,6 +76,26 @@ impl Node for ExitConstructExpression {
         }
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        match self {
+            Self::Exit { comments, .. } => comments.positions_mut().collect(),
+            Self::ExitWith {
+                comments,
+                left_parenthesis,
+                right_parenthesis,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![left_parenthesis, right_parenthesis];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+        }
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::ExitConstructExpression
+    }
+
     fn get_description(&self) -> String {
         "exit construct expression".to_string()
     }
@@ -92,20 +126,20 @@ mod tests {
     #[test]
     fn test_exit_display() {
         let exit = ExitConstructExpression::Exit {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             exit: Keyword::new(ByteString::from("exit"), 0),
         };
 
         assert_eq!(exit.to_string(), "exit;");
 
         let value = Expression::Literal(Integer(LiteralInteger {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             position: 0,
             value: ByteString::from("1"),
         }));
 
         let exit_with = ExitConstructExpression::ExitWith {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             exit: Keyword::new(ByteString::from("exit"), 0),
             left_parenthesis: 0,
             value: Some(Box::new(value)),
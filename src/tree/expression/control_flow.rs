@@ -6,8 +6,11 @@ use serde::Serialize;
 
 use crate::tree::comment::CommentGroup;
 use crate::tree::expression::Expression;
+use crate::tree::kind::NodeKind;
+use crate::tree::span::Span;
 use crate::tree::token::Keyword;
 use crate::tree::utils::CommaSeparated;
+use crate::tree::utils::ListView;
 use crate::tree::Node;
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
@@ -47,38 +50,74 @@ impl Node for MatchExpression {
         Some(&self.comments)
     }
 
-    fn initial_position(&self) -> usize {
-        self.r#match.initial_position()
+    fn span(&self) -> Span {
+        Span::new(self.r#match.initial_position(), self.body.final_position())
     }
 
-    fn final_position(&self) -> usize {
-        self.body.final_position()
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut children: Vec<(&'static str, &dyn Node)> = vec![("match", &self.r#match)];
+        if let Some(expression) = &self.expression {
+            children.push(("expression", expression.as_ref()));
+        }
+        children.push(("body", &self.body));
+        children
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        let mut children: Vec<&dyn Node> = vec![&self.r#match, &self.body];
-        if let Some(expression) = &self.expression {
-            children.push(expression.as_ref());
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = vec![&mut self.r#match];
+        if let Some(expression) = &mut self.expression {
+            children.push(expression.as_mut());
         }
+        children.push(&mut self.body);
         children
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        self.comments.positions_mut().collect()
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::MatchExpression
+    }
+
     fn get_description(&self) -> String {
         "match expression".to_string()
     }
 }
 
 impl Node for MatchBodyExpression {
-    fn initial_position(&self) -> usize {
-        self.left_brace
+    fn span(&self) -> Span {
+        Span::new(self.left_brace, self.right_brace + 1)
     }
 
-    fn final_position(&self) -> usize {
-        self.right_brace + 1
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        self.arms
+            .inner
+            .iter()
+            .map(|arm| ("arms", arm as &dyn Node))
+            .collect()
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        self.arms.inner.iter().map(|arm| arm as &dyn Node).collect()
+    fn lists(&self) -> Vec<ListView<'_>> {
+        vec![self.arms.view()]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        self.arms
+            .inner
+            .iter_mut()
+            .map(|arm| arm as &mut dyn Node)
+            .collect()
+    }
+
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        let mut positions: Vec<&mut usize> = vec![&mut self.left_brace, &mut self.right_brace];
+        positions.extend(self.arms.commas.iter_mut());
+        positions
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::MatchBodyExpression
     }
 
     fn get_description(&self) -> String {
@@ -87,16 +126,30 @@ impl Node for MatchBodyExpression {
 }
 
 impl Node for MatchArmExpression {
-    fn initial_position(&self) -> usize {
-        self.condition.initial_position()
+    fn span(&self) -> Span {
+        Span::new(
+            self.condition.initial_position(),
+            self.expression.final_position(),
+        )
+    }
+
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![
+            ("condition", &self.condition),
+            ("expression", &self.expression),
+        ]
     }
 
-    fn final_position(&self) -> usize {
-        self.expression.final_position()
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        vec![&mut self.condition, &mut self.expression]
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        vec![&self.condition, &self.expression]
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        vec![&mut self.arrow]
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::MatchArmExpression
     }
 
     fn get_description(&self) -> String {
@@ -105,31 +158,56 @@ impl Node for MatchArmExpression {
 }
 
 impl Node for MatchArmConditionExpression {
-    fn initial_position(&self) -> usize {
+    fn span(&self) -> Span {
         match &self {
-            Self::Expressions(expressions) => expressions.inner.first().unwrap().initial_position(),
-            Self::Default(default) => default.initial_position(),
+            Self::Expressions(expressions) => Span::new(
+                expressions.inner.first().unwrap().initial_position(),
+                expressions.inner.last().unwrap().final_position(),
+            ),
+            Self::Default(default) => default.span(),
         }
     }
 
-    fn final_position(&self) -> usize {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
         match &self {
-            Self::Expressions(expressions) => expressions.inner.last().unwrap().final_position(),
-            Self::Default(default) => default.final_position(),
+            Self::Expressions(expressions) => expressions
+                .inner
+                .iter()
+                .map(|expression| ("expressions", expression as &dyn Node))
+                .collect(),
+            Self::Default(default) => vec![("default", default)],
         }
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
+    fn lists(&self) -> Vec<ListView<'_>> {
         match &self {
+            Self::Expressions(expressions) => vec![expressions.view()],
+            _ => vec![],
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
             Self::Expressions(expressions) => expressions
                 .inner
-                .iter()
-                .map(|expression| expression as &dyn Node)
+                .iter_mut()
+                .map(|expression| expression as &mut dyn Node)
                 .collect(),
             Self::Default(default) => vec![default],
         }
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        match self {
+            Self::Expressions(position) => position.commas.iter_mut().collect(),
+            _ => vec![],
+        }
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::MatchArmConditionExpression
+    }
+
     fn get_description(&self) -> String {
         "match arm condition expression".to_string()
     }
@@ -171,11 +249,12 @@ mod tests {
     use super::*;
     use crate::lexer::byte_string::ByteString;
     use crate::tree::variable::Variable;
+    use smallvec::smallvec;
 
     #[test]
     fn test_match_expression_display() {
-        let expression = Expression::Match(MatchExpression {
-            comments: CommentGroup { comments: vec![] },
+        let expression = Expression::Match(Box::new(MatchExpression {
+            comments: CommentGroup::default(),
             r#match: Keyword::new(ByteString::from("match"), 0),
             expression: Some(Box::new(Expression::Variable(Variable {
                 position: 0,
@@ -185,11 +264,11 @@ mod tests {
                 left_brace: 0,
                 arms: CommaSeparated {
                     inner: vec![],
-                    commas: vec![],
+                    commas: smallvec![],
                 },
                 right_brace: 0,
             },
-        });
+        }));
         assert_eq!(expression.to_string(), "match $a { /* ... */ }");
     }
 }
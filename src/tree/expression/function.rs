@@ -10,9 +10,12 @@ use crate::tree::definition::function::FunctionLikeParameterListDefinition;
 use crate::tree::definition::function::FunctionLikeReturnTypeDefinition;
 use crate::tree::definition::modifier::ModifierGroupDefinition;
 use crate::tree::expression::Expression;
+use crate::tree::kind::NodeKind;
+use crate::tree::span::Span;
 use crate::tree::statement::block::BlockStatement;
 use crate::tree::token::Keyword;
 use crate::tree::utils::CommaSeparated;
+use crate::tree::utils::ListView;
 use crate::tree::variable::Variable;
 use crate::tree::Node;
 
@@ -64,38 +67,58 @@ impl Node for ArrowFunctionExpression {
         Some(&self.comments)
     }
 
-    fn initial_position(&self) -> usize {
-        if let Some(attribute) = self.attributes.first() {
-            return attribute.initial_position();
-        }
+    fn span(&self) -> Span {
+        let initial_position = match self.attributes.first() {
+            Some(attribute) => attribute.initial_position(),
+            None if !self.modifiers.modifiers.is_empty() => self.modifiers.initial_position(),
+            None => self.r#fn.initial_position(),
+        };
+
+        Span::new(initial_position, self.body.final_position())
+    }
+
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut children: Vec<(&'static str, &dyn Node)> = vec![];
 
-        if !&self.modifiers.modifiers.is_empty() {
-            return self.modifiers.initial_position();
+        for attribute in &self.attributes {
+            children.push(("attributes", attribute));
         }
 
-        self.r#fn.initial_position()
-    }
+        children.push(("modifiers", &self.modifiers));
+        children.push(("fn", &self.r#fn));
+        children.push(("parameters", &self.parameters));
+        children.push(("return_type", &self.return_type));
+        children.push(("body", self.body.as_ref()));
 
-    fn final_position(&self) -> usize {
-        self.body.final_position()
+        children
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        let mut children: Vec<&dyn Node> = vec![];
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = vec![];
 
-        for attribute in &self.attributes {
+        for attribute in &mut self.attributes {
             children.push(attribute);
         }
 
-        children.push(&self.r#fn);
-        children.push(&self.modifiers);
-        children.push(&self.parameters);
-        children.push(&self.return_type);
-        children.push(self.body.as_ref());
+        children.push(&mut self.modifiers);
+        children.push(&mut self.r#fn);
+        children.push(&mut self.parameters);
+        children.push(&mut self.return_type);
+        children.push(self.body.as_mut());
 
         children
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        let mut positions: Vec<&mut usize> = vec![&mut self.double_arrow];
+        positions.extend(self.comments.positions_mut());
+        positions
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::ArrowFunctionExpression
+    }
+
     fn get_description(&self) -> String {
         "arrow function expression".to_string()
     }
@@ -106,38 +129,56 @@ impl Node for AnonymousFunctionExpression {
         Some(&self.comments)
     }
 
-    fn initial_position(&self) -> usize {
-        if let Some(attribute) = self.attributes.first() {
-            return attribute.initial_position();
-        }
+    fn span(&self) -> Span {
+        let initial_position = match self.attributes.first() {
+            Some(attribute) => attribute.initial_position(),
+            None if !self.modifiers.modifiers.is_empty() => self.modifiers.initial_position(),
+            None => self.function.initial_position(),
+        };
+
+        Span::new(initial_position, self.body.final_position())
+    }
+
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut children: Vec<(&'static str, &dyn Node)> = vec![];
 
-        if !&self.modifiers.modifiers.is_empty() {
-            return self.modifiers.initial_position();
+        for attribute in &self.attributes {
+            children.push(("attributes", attribute));
         }
 
-        self.function.initial_position()
-    }
+        children.push(("modifiers", &self.modifiers));
+        children.push(("function", &self.function));
+        children.push(("parameters", &self.parameters));
+        children.push(("return_type", &self.return_type));
+        children.push(("body", &self.body));
 
-    fn final_position(&self) -> usize {
-        self.body.final_position()
+        children
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        let mut children: Vec<&dyn Node> = vec![];
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = vec![];
 
-        for attribute in &self.attributes {
+        for attribute in &mut self.attributes {
             children.push(attribute);
         }
 
-        children.push(&self.function);
-        children.push(&self.modifiers);
-        children.push(&self.parameters);
-        children.push(&self.return_type);
-        children.push(&self.body);
+        children.push(&mut self.modifiers);
+        children.push(&mut self.function);
+        children.push(&mut self.parameters);
+        children.push(&mut self.return_type);
+        children.push(&mut self.body);
 
         children
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        self.comments.positions_mut().collect()
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::AnonymousFunctionExpression
+    }
+
     fn get_description(&self) -> String {
         "anonymous function expression".to_string()
     }
@@ -148,24 +189,46 @@ impl Node for AnonymousFunctionUseClauseExpression {
         Some(&self.comments)
     }
 
-    fn initial_position(&self) -> usize {
-        self.r#use.initial_position()
+    fn span(&self) -> Span {
+        Span::new(self.r#use.initial_position(), self.right_parenthesis)
+    }
+
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut children: Vec<(&'static str, &dyn Node)> = vec![("use", &self.r#use)];
+
+        for variable in &self.variables.inner {
+            children.push(("variables", variable));
+        }
+
+        children
     }
 
-    fn final_position(&self) -> usize {
-        self.right_parenthesis
+    fn lists(&self) -> Vec<ListView<'_>> {
+        vec![self.variables.view()]
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        let mut children: Vec<&dyn Node> = vec![&self.r#use];
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = vec![&mut self.r#use];
 
-        for variable in &self.variables.inner {
+        for variable in &mut self.variables.inner {
             children.push(variable);
         }
 
         children
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        let mut positions: Vec<&mut usize> =
+            vec![&mut self.left_parenthesis, &mut self.right_parenthesis];
+        positions.extend(self.comments.positions_mut());
+        positions.extend(self.variables.commas.iter_mut());
+        positions
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::AnonymousFunctionUseClauseExpression
+    }
+
     fn get_description(&self) -> String {
         "anonymous function use clause expression".to_string()
     }
@@ -176,16 +239,24 @@ impl Node for AnonymousFunctionUseClauseVariableExpression {
         Some(&self.comments)
     }
 
-    fn initial_position(&self) -> usize {
-        self.variable.initial_position()
+    fn span(&self) -> Span {
+        self.variable.span()
     }
 
-    fn final_position(&self) -> usize {
-        self.variable.final_position()
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("variable", &self.variable)]
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        vec![&self.variable]
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        vec![&mut self.variable]
+    }
+
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        self.comments.positions_mut().collect()
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::AnonymousFunctionUseClauseVariableExpression
     }
 
     fn get_description(&self) -> String {
@@ -243,12 +314,13 @@ mod tests {
     use crate::tree::definition::modifier::ModifierDefinition;
     use crate::tree::definition::r#type::SignedIntegerTypeDefinition;
     use crate::tree::definition::r#type::TypeDefinition;
+    use smallvec::smallvec;
 
     #[test]
     fn arrow_function_expression_display() {
         let arrow_function_expression = ArrowFunctionExpression {
             attributes: vec![],
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             modifiers: ModifierGroupDefinition {
                 position: 0,
                 modifiers: vec![ModifierDefinition::Static(Keyword::new(
@@ -258,12 +330,12 @@ mod tests {
             },
             r#fn: Keyword::new(ByteString::from("fn"), 0),
             parameters: FunctionLikeParameterListDefinition {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 left_parenthesis: 0,
                 parameters: CommaSeparated {
                     inner: vec![FunctionLikeParameterDefinition {
                         attributes: vec![],
-                        comments: CommentGroup { comments: vec![] },
+                        comments: CommentGroup::default(),
                         type_definition: TypeDefinition::SignedInteger(
                             SignedIntegerTypeDefinition::I64(Keyword::new(
                                 ByteString::from("i64"),
@@ -276,8 +348,12 @@ mod tests {
                             name: ByteString::from("foo"),
                         },
                         default: None,
+                        modifiers: ModifierGroupDefinition {
+                            position: 0,
+                            modifiers: vec![],
+                        },
                     }],
-                    commas: vec![],
+                    commas: smallvec![],
                 },
                 right_parenthesis: 0,
             },
@@ -304,7 +380,7 @@ mod tests {
     fn anonymous_function_expression_display() {
         let anonymous_function_expression = AnonymousFunctionExpression {
             attributes: vec![],
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             modifiers: ModifierGroupDefinition {
                 position: 0,
                 modifiers: vec![ModifierDefinition::Static(Keyword::new(
@@ -314,12 +390,12 @@ mod tests {
             },
             function: Keyword::new(ByteString::from("function"), 0),
             parameters: FunctionLikeParameterListDefinition {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 left_parenthesis: 0,
                 parameters: CommaSeparated {
                     inner: vec![FunctionLikeParameterDefinition {
                         attributes: vec![],
-                        comments: CommentGroup { comments: vec![] },
+                        comments: CommentGroup::default(),
                         type_definition: TypeDefinition::SignedInteger(
                             SignedIntegerTypeDefinition::I32(Keyword::new(
                                 ByteString::from("i32"),
@@ -332,8 +408,12 @@ mod tests {
                             name: ByteString::from("foo"),
                         },
                         default: None,
+                        modifiers: ModifierGroupDefinition {
+                            position: 0,
+                            modifiers: vec![],
+                        },
                     }],
-                    commas: vec![],
+                    commas: smallvec![],
                 },
                 right_parenthesis: 0,
             },
@@ -344,23 +424,23 @@ mod tests {
                 )),
             },
             use_clause: Some(AnonymousFunctionUseClauseExpression {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 r#use: Keyword::new(ByteString::from("use"), 0),
                 variables: CommaSeparated {
                     inner: vec![AnonymousFunctionUseClauseVariableExpression {
-                        comments: CommentGroup { comments: vec![] },
+                        comments: CommentGroup::default(),
                         variable: Variable {
                             position: 0,
                             name: ByteString::from("bar"),
                         },
                     }],
-                    commas: vec![],
+                    commas: smallvec![],
                 },
                 left_parenthesis: 0,
                 right_parenthesis: 0,
             }),
             body: BlockStatement {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 left_brace: 0,
                 statements: vec![],
                 right_brace: 0,
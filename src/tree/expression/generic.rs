@@ -5,7 +5,10 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use crate::tree::definition::r#type::TypeDefinition;
+use crate::tree::kind::NodeKind;
+use crate::tree::span::Span;
 use crate::tree::utils::CommaSeparated;
+use crate::tree::utils::ListView;
 use crate::tree::Node;
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
@@ -17,16 +20,39 @@ pub struct GenericGroupExpression {
 }
 
 impl Node for GenericGroupExpression {
-    fn initial_position(&self) -> usize {
-        self.double_colon_less_than
+    fn span(&self) -> Span {
+        Span::new(self.double_colon_less_than, self.greater_than + 1)
     }
 
-    fn final_position(&self) -> usize {
-        self.greater_than + 1
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        self.types
+            .inner
+            .iter()
+            .map(|t| ("types", t as &dyn Node))
+            .collect()
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        self.types.inner.iter().map(|t| t as &dyn Node).collect()
+    fn lists(&self) -> Vec<ListView<'_>> {
+        vec![self.types.view()]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        self.types
+            .inner
+            .iter_mut()
+            .map(|t| t as &mut dyn Node)
+            .collect()
+    }
+
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        let mut positions: Vec<&mut usize> =
+            vec![&mut self.double_colon_less_than, &mut self.greater_than];
+        positions.extend(self.types.commas.iter_mut());
+        positions
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::GenericGroupExpression
     }
 
     fn get_description(&self) -> String {
@@ -46,6 +72,7 @@ mod tests {
     use crate::lexer::byte_string::ByteString;
     use crate::tree::identifier::Identifier;
     use crate::tree::identifier::TemplatedIdentifier;
+    use smallvec::smallvec;
 
     #[test]
     fn test_generic_group_expression_display() {
@@ -68,7 +95,7 @@ mod tests {
                         templates: None,
                     }),
                 ],
-                commas: vec![],
+                commas: smallvec![],
             },
             greater_than: 0,
         };
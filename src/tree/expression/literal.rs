@@ -6,6 +6,8 @@ use serde::Serialize;
 
 use crate::lexer::byte_string::ByteString;
 use crate::tree::comment::CommentGroup;
+use crate::tree::kind::NodeKind;
+use crate::tree::span::Span;
 use crate::tree::token::Keyword;
 use crate::tree::Node;
 
@@ -77,30 +79,30 @@ impl Node for Literal {
         }
     }
 
-    fn initial_position(&self) -> usize {
+    fn span(&self) -> Span {
         match &self {
-            Literal::String(literal) => literal.initial_position(),
-            Literal::Integer(literal) => literal.initial_position(),
-            Literal::Float(literal) => literal.initial_position(),
-            Literal::Null(literal) => literal.initial_position(),
-            Literal::True(literal) => literal.initial_position(),
-            Literal::False(literal) => literal.initial_position(),
+            Literal::String(literal) => literal.span(),
+            Literal::Integer(literal) => literal.span(),
+            Literal::Float(literal) => literal.span(),
+            Literal::Null(literal) => literal.span(),
+            Literal::True(literal) => literal.span(),
+            Literal::False(literal) => literal.span(),
         }
     }
 
-    fn final_position(&self) -> usize {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
         match &self {
-            Literal::String(literal) => literal.final_position(),
-            Literal::Integer(literal) => literal.final_position(),
-            Literal::Float(literal) => literal.final_position(),
-            Literal::Null(literal) => literal.final_position(),
-            Literal::True(literal) => literal.final_position(),
-            Literal::False(literal) => literal.final_position(),
+            Literal::String(literal) => vec![("literal", literal)],
+            Literal::Integer(literal) => vec![("literal", literal)],
+            Literal::Float(literal) => vec![("literal", literal)],
+            Literal::Null(literal) => vec![("literal", literal)],
+            Literal::True(literal) => vec![("literal", literal)],
+            Literal::False(literal) => vec![("literal", literal)],
         }
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        match &self {
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
             Literal::String(literal) => vec![literal],
             Literal::Integer(literal) => vec![literal],
             Literal::Float(literal) => vec![literal],
@@ -110,6 +112,14 @@ impl Node for Literal {
         }
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        vec![]
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::Literal
+    }
+
     fn get_description(&self) -> String {
         match &self {
             Literal::String(literal) => literal.get_description(),
@@ -127,18 +137,32 @@ impl Node for LiteralString {
         Some(&self.comments)
     }
 
-    fn initial_position(&self) -> usize {
-        self.position
+    fn span(&self) -> Span {
+        Span::new(self.position, self.position + self.value.len())
+    }
+
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![]
     }
 
-    fn final_position(&self) -> usize {
-        self.position + self.value.len()
+    fn tokens(&self) -> Vec<&[u8]> {
+        vec![self.value.as_bytes()]
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![]
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        let mut positions: Vec<&mut usize> = vec![&mut self.position];
+        positions.extend(self.comments.positions_mut());
+        positions
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::LiteralString
+    }
+
     fn get_description(&self) -> String {
         "literal string expression".to_string()
     }
@@ -149,18 +173,32 @@ impl Node for LiteralInteger {
         Some(&self.comments)
     }
 
-    fn initial_position(&self) -> usize {
-        self.position
+    fn span(&self) -> Span {
+        Span::new(self.position, self.position + self.value.len())
+    }
+
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![]
     }
 
-    fn final_position(&self) -> usize {
-        self.position + self.value.len()
+    fn tokens(&self) -> Vec<&[u8]> {
+        vec![self.value.as_bytes()]
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![]
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        let mut positions: Vec<&mut usize> = vec![&mut self.position];
+        positions.extend(self.comments.positions_mut());
+        positions
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::LiteralInteger
+    }
+
     fn get_description(&self) -> String {
         "literal integer expression".to_string()
     }
@@ -171,18 +209,32 @@ impl Node for LiteralFloat {
         Some(&self.comments)
     }
 
-    fn initial_position(&self) -> usize {
-        self.position
+    fn span(&self) -> Span {
+        Span::new(self.position, self.position + self.value.len())
     }
 
-    fn final_position(&self) -> usize {
-        self.position + self.value.len()
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![]
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
+    fn tokens(&self) -> Vec<&[u8]> {
+        vec![self.value.as_bytes()]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![]
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        let mut positions: Vec<&mut usize> = vec![&mut self.position];
+        positions.extend(self.comments.positions_mut());
+        positions
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::LiteralFloat
+    }
+
     fn get_description(&self) -> String {
         "literal float expression".to_string()
     }
@@ -193,16 +245,24 @@ impl Node for LiteralNull {
         Some(&self.comments)
     }
 
-    fn initial_position(&self) -> usize {
-        self.null.initial_position()
+    fn span(&self) -> Span {
+        self.null.span()
+    }
+
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("null", &self.null)]
     }
 
-    fn final_position(&self) -> usize {
-        self.null.final_position()
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        vec![&mut self.null]
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        vec![&self.null]
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        self.comments.positions_mut().collect()
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::LiteralNull
     }
 
     fn get_description(&self) -> String {
@@ -215,16 +275,24 @@ impl Node for LiteralTrue {
         Some(&self.comments)
     }
 
-    fn initial_position(&self) -> usize {
-        self.r#true.initial_position()
+    fn span(&self) -> Span {
+        self.r#true.span()
+    }
+
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("true", &self.r#true)]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        vec![&mut self.r#true]
     }
 
-    fn final_position(&self) -> usize {
-        self.r#true.final_position()
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        self.comments.positions_mut().collect()
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        vec![&self.r#true]
+    fn kind(&self) -> NodeKind {
+        NodeKind::LiteralTrue
     }
 
     fn get_description(&self) -> String {
@@ -237,16 +305,24 @@ impl Node for LiteralFalse {
         Some(&self.comments)
     }
 
-    fn initial_position(&self) -> usize {
-        self.r#false.initial_position()
+    fn span(&self) -> Span {
+        self.r#false.span()
+    }
+
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("false", &self.r#false)]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        vec![&mut self.r#false]
     }
 
-    fn final_position(&self) -> usize {
-        self.r#false.final_position()
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        self.comments.positions_mut().collect()
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        vec![&self.r#false]
+    fn kind(&self) -> NodeKind {
+        NodeKind::LiteralFalse
     }
 
     fn get_description(&self) -> String {
@@ -310,7 +386,7 @@ mod tests {
     #[test]
     fn test_literal_display() {
         let string = Literal::String(LiteralString {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             value: ByteString::from("\"foo\""),
             position: 0,
         });
@@ -318,7 +394,7 @@ mod tests {
         assert_eq!(string.to_string(), "\"foo\"");
 
         let integer = LiteralInteger {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             position: 0,
             value: ByteString::from("123"),
         };
@@ -326,7 +402,7 @@ mod tests {
         assert_eq!(integer.to_string(), "123");
 
         let float = LiteralFloat {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             position: 0,
             value: ByteString::from("3.14"),
         };
@@ -334,21 +410,21 @@ mod tests {
         assert_eq!(float.to_string(), "3.14");
 
         let null = LiteralNull {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             null: Keyword::new(ByteString::from("null"), 0),
         };
 
         assert_eq!(null.to_string(), "null");
 
         let r#true = LiteralTrue {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             r#true: Keyword::new(ByteString::from("true"), 0),
         };
 
         assert_eq!(r#true.to_string(), "true");
 
         let r#false = LiteralFalse {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             r#false: Keyword::new(ByteString::from("false"), 0),
         };
 
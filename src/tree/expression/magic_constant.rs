@@ -5,6 +5,8 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use crate::lexer::byte_string::ByteString;
+use crate::tree::kind::NodeKind;
+use crate::tree::span::Span;
 use crate::tree::Node;
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
@@ -20,8 +22,8 @@ pub enum MagicConstant {
 }
 
 impl Node for MagicConstant {
-    fn initial_position(&self) -> usize {
-        match &self {
+    fn span(&self) -> Span {
+        let initial_position = match &self {
             Self::Directory { position, .. } => *position,
             Self::File { position, .. } => *position,
             Self::Line { position, .. } => *position,
@@ -29,11 +31,8 @@ impl Node for MagicConstant {
             Self::Function { position, .. } => *position,
             Self::Method { position, .. } => *position,
             Self::Namespace { position, .. } => *position,
-        }
-    }
-
-    fn final_position(&self) -> usize {
-        match &self {
+        };
+        let final_position = match &self {
             Self::Directory { position, value } => position + value.len(),
             Self::File { position, value } => position + value.len(),
             Self::Line { position, value } => position + value.len(),
@@ -41,13 +40,35 @@ impl Node for MagicConstant {
             Self::Function { position, value } => position + value.len(),
             Self::Method { position, value } => position + value.len(),
             Self::Namespace { position, value } => position + value.len(),
-        }
+        };
+
+        Span::new(initial_position, final_position)
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
         vec![]
     }
 
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        vec![]
+    }
+
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        match self {
+            Self::Directory { position, .. } => vec![position],
+            Self::File { position, .. } => vec![position],
+            Self::Line { position, .. } => vec![position],
+            Self::Class { position, .. } => vec![position],
+            Self::Function { position, .. } => vec![position],
+            Self::Method { position, .. } => vec![position],
+            Self::Namespace { position, .. } => vec![position],
+        }
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::MagicConstant
+    }
+
     fn get_description(&self) -> String {
         match &self {
             Self::Directory { .. } => "directory magic constant expression".to_string(),
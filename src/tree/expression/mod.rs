@@ -34,6 +34,8 @@ use crate::tree::expression::operator::StringOperationExpression;
 use crate::tree::expression::operator::TernaryOperationExpression;
 use crate::tree::expression::operator::TypeOperationExpression;
 use crate::tree::identifier::Identifier;
+use crate::tree::kind::NodeKind;
+use crate::tree::span::Span;
 use crate::tree::variable::Variable;
 use crate::tree::Node;
 
@@ -48,6 +50,11 @@ pub mod literal;
 pub mod magic_constant;
 pub mod operator;
 
+// The larger kinds of expressions are boxed, so that they do not inflate the size of every
+// expression, including the small and common ones, such as variables and literals.
+#[cfg(target_pointer_width = "64")]
+const _: () = assert!(std::mem::size_of::<Expression>() <= 112);
+
 #[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
 #[serde(rename_all = "snake_case", tag = "type", content = "value")]
 pub enum Expression {
@@ -56,29 +63,29 @@ pub enum Expression {
     Literal(Literal),
     FunctionalOperation(FunctionalOperationExpression),
     ArithmeticOperation(ArithmeticOperationExpression),
-    AsyncOperation(AsyncOperationExpression),
+    AsyncOperation(Box<AsyncOperationExpression>),
     ArrayOperation(ArrayOperationExpression),
     AssignmentOperation(AssignmentOperationExpression),
     BitwiseOperation(BitwiseOperationExpression),
-    ClassOperation(ClassOperationExpression),
+    ClassOperation(Box<ClassOperationExpression>),
     CoalesceOperation(CoalesceOperationExpression),
     ComparisonOperation(ComparisonOperationExpression),
     ExceptionOperation(ExceptionOperationExpression),
-    FunctionOperation(FunctionOperationExpression),
+    FunctionOperation(Box<FunctionOperationExpression>),
     GeneratorOperation(GeneratorOperationExpression),
     LogicalOperation(LogicalOperationExpression),
-    ObjectOperation(ObjectOperationExpression),
+    ObjectOperation(Box<ObjectOperationExpression>),
     RangeOperation(RangeOperationExpression),
     StringOperation(StringOperationExpression),
-    TypeOperation(TypeOperationExpression),
+    TypeOperation(Box<TypeOperationExpression>),
     TernaryOperation(TernaryOperationExpression),
     Identifier(Identifier),
     Variable(Variable),
-    Match(MatchExpression),
-    AnonymousFunction(AnonymousFunctionExpression),
-    ArrowFunction(ArrowFunctionExpression),
-    Vec(VecExpression),
-    Dict(DictExpression),
+    Match(Box<MatchExpression>),
+    AnonymousFunction(Box<AnonymousFunctionExpression>),
+    ArrowFunction(Box<ArrowFunctionExpression>),
+    Vec(Box<VecExpression>),
+    Dict(Box<DictExpression>),
     Tuple(TupleExpression),
     MagicConstant(MagicConstant),
 }
@@ -97,16 +104,27 @@ impl Node for ParenthesizedExpression {
         Some(&self.comments)
     }
 
-    fn initial_position(&self) -> usize {
-        self.left_parenthesis
+    fn span(&self) -> Span {
+        Span::new(self.left_parenthesis, self.right_parenthesis + 1)
+    }
+
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("expression", self.expression.as_ref())]
     }
 
-    fn final_position(&self) -> usize {
-        self.right_parenthesis + 1
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        vec![self.expression.as_mut()]
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        vec![self.expression.as_ref()]
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        let mut positions: Vec<&mut usize> =
+            vec![&mut self.left_parenthesis, &mut self.right_parenthesis];
+        positions.extend(self.comments.positions_mut());
+        positions
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::ParenthesizedExpression
     }
 
     fn get_description(&self) -> String {
@@ -155,7 +173,7 @@ impl Expression {
                 }
                 BitwiseOperationExpression::Not { right, .. } => right.is_constant(initilization),
             },
-            Self::ClassOperation(expression) => match &expression {
+            Self::ClassOperation(expression) => match expression.as_ref() {
                 ClassOperationExpression::Initialization {
                     class, arguments, ..
                 } if initilization => match class {
@@ -254,9 +272,15 @@ impl Expression {
         match &self {
             Self::Variable(_)
             | Self::ArrayOperation(ArrayOperationExpression::Push { .. })
-            | Self::ArrayOperation(ArrayOperationExpression::Access { .. })
-            | Self::ObjectOperation(ObjectOperationExpression::PropertyFetch { .. })
-            | Self::ClassOperation(ClassOperationExpression::StaticPropertyFetch { .. }) => true,
+            | Self::ArrayOperation(ArrayOperationExpression::Access { .. }) => true,
+            Self::ObjectOperation(expression) => matches!(
+                expression.as_ref(),
+                ObjectOperationExpression::PropertyFetch { .. }
+            ),
+            Self::ClassOperation(expression) => matches!(
+                expression.as_ref(),
+                ClassOperationExpression::StaticPropertyFetch { .. }
+            ),
             Self::Tuple(TupleExpression { elements, .. }) => {
                 elements.inner.iter().all(|element| element.is_writable())
             }
@@ -271,12 +295,13 @@ impl Expression {
             | Self::ExitConstruct(..)
             | Self::ExceptionOperation(ExceptionOperationExpression::Throw { .. })
             | Self::ArrayOperation(ArrayOperationExpression::Push { .. }) => false,
-            Self::AsyncOperation(AsyncOperationExpression::Concurrently {
-                expressions, ..
-            }) => expressions
-                .inner
-                .iter()
-                .all(|expression| expression.is_readable()),
+            Self::AsyncOperation(expression) => match expression.as_ref() {
+                AsyncOperationExpression::Concurrently { expressions, .. } => expressions
+                    .inner
+                    .iter()
+                    .all(|expression| expression.is_readable()),
+                _ => true,
+            },
             _ => true,
         }
     }
@@ -287,111 +312,119 @@ impl Node for Expression {
         None
     }
 
-    fn initial_position(&self) -> usize {
+    fn span(&self) -> Span {
         match &self {
-            Self::Parenthesized(expression) => expression.initial_position(),
-            Self::ExitConstruct(expression) => expression.initial_position(),
-            Self::Literal(expression) => expression.initial_position(),
-            Self::ArithmeticOperation(expression) => expression.initial_position(),
-            Self::AsyncOperation(expression) => expression.initial_position(),
-            Self::ArrayOperation(expression) => expression.initial_position(),
-            Self::AssignmentOperation(expression) => expression.initial_position(),
-            Self::BitwiseOperation(expression) => expression.initial_position(),
-            Self::ClassOperation(expression) => expression.initial_position(),
-            Self::CoalesceOperation(expression) => expression.initial_position(),
-            Self::ComparisonOperation(expression) => expression.initial_position(),
-            Self::ExceptionOperation(expression) => expression.initial_position(),
-            Self::FunctionOperation(expression) => expression.initial_position(),
-            Self::GeneratorOperation(expression) => expression.initial_position(),
-            Self::LogicalOperation(expression) => expression.initial_position(),
-            Self::ObjectOperation(expression) => expression.initial_position(),
-            Self::RangeOperation(expression) => expression.initial_position(),
-            Self::StringOperation(expression) => expression.initial_position(),
-            Self::TypeOperation(expression) => expression.initial_position(),
-            Self::TernaryOperation(expression) => expression.initial_position(),
-            Self::Identifier(expression) => expression.initial_position(),
-            Self::Variable(expression) => expression.initial_position(),
-            Self::Match(expression) => expression.initial_position(),
-            Self::AnonymousFunction(expression) => expression.initial_position(),
-            Self::ArrowFunction(expression) => expression.initial_position(),
-            Self::Vec(expression) => expression.initial_position(),
-            Self::Dict(expression) => expression.initial_position(),
-            Self::Tuple(expression) => expression.initial_position(),
-            Self::MagicConstant(expression) => expression.initial_position(),
-            Self::FunctionalOperation(expression) => expression.initial_position(),
+            Self::Parenthesized(expression) => expression.span(),
+            Self::ExitConstruct(expression) => expression.span(),
+            Self::Literal(expression) => expression.span(),
+            Self::ArithmeticOperation(expression) => expression.span(),
+            Self::AsyncOperation(expression) => expression.span(),
+            Self::ArrayOperation(expression) => expression.span(),
+            Self::AssignmentOperation(expression) => expression.span(),
+            Self::BitwiseOperation(expression) => expression.span(),
+            Self::ClassOperation(expression) => expression.span(),
+            Self::CoalesceOperation(expression) => expression.span(),
+            Self::ComparisonOperation(expression) => expression.span(),
+            Self::ExceptionOperation(expression) => expression.span(),
+            Self::FunctionOperation(expression) => expression.span(),
+            Self::GeneratorOperation(expression) => expression.span(),
+            Self::LogicalOperation(expression) => expression.span(),
+            Self::ObjectOperation(expression) => expression.span(),
+            Self::RangeOperation(expression) => expression.span(),
+            Self::StringOperation(expression) => expression.span(),
+            Self::TypeOperation(expression) => expression.span(),
+            Self::TernaryOperation(expression) => expression.span(),
+            Self::Identifier(expression) => expression.span(),
+            Self::Variable(expression) => expression.span(),
+            Self::Match(expression) => expression.span(),
+            Self::AnonymousFunction(expression) => expression.span(),
+            Self::ArrowFunction(expression) => expression.span(),
+            Self::Vec(expression) => expression.span(),
+            Self::Dict(expression) => expression.span(),
+            Self::Tuple(expression) => expression.span(),
+            Self::MagicConstant(expression) => expression.span(),
+            Self::FunctionalOperation(expression) => expression.span(),
         }
     }
 
-    fn final_position(&self) -> usize {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
         match &self {
-            Self::Parenthesized(expression) => expression.final_position(),
-            Self::ExitConstruct(expression) => expression.final_position(),
-            Self::Literal(expression) => expression.final_position(),
-            Self::ArithmeticOperation(expression) => expression.final_position(),
-            Self::AsyncOperation(expression) => expression.final_position(),
-            Self::ArrayOperation(expression) => expression.final_position(),
-            Self::AssignmentOperation(expression) => expression.final_position(),
-            Self::BitwiseOperation(expression) => expression.final_position(),
-            Self::ClassOperation(expression) => expression.final_position(),
-            Self::CoalesceOperation(expression) => expression.final_position(),
-            Self::ComparisonOperation(expression) => expression.final_position(),
-            Self::ExceptionOperation(expression) => expression.final_position(),
-            Self::FunctionOperation(expression) => expression.final_position(),
-            Self::GeneratorOperation(expression) => expression.final_position(),
-            Self::LogicalOperation(expression) => expression.final_position(),
-            Self::ObjectOperation(expression) => expression.final_position(),
-            Self::RangeOperation(expression) => expression.final_position(),
-            Self::StringOperation(expression) => expression.final_position(),
-            Self::TypeOperation(expression) => expression.final_position(),
-            Self::TernaryOperation(expression) => expression.final_position(),
-            Self::Identifier(expression) => expression.final_position(),
-            Self::Variable(expression) => expression.final_position(),
-            Self::Match(expression) => expression.final_position(),
-            Self::AnonymousFunction(expression) => expression.final_position(),
-            Self::ArrowFunction(expression) => expression.final_position(),
-            Self::Vec(expression) => expression.final_position(),
-            Self::Dict(expression) => expression.final_position(),
-            Self::Tuple(expression) => expression.final_position(),
-            Self::MagicConstant(expression) => expression.final_position(),
-            Self::FunctionalOperation(expression) => expression.final_position(),
+            Self::Parenthesized(expression) => vec![("expression", expression)],
+            Self::ExitConstruct(expression) => vec![("expression", expression)],
+            Self::Literal(expression) => vec![("expression", expression)],
+            Self::ArithmeticOperation(expression) => vec![("expression", expression)],
+            Self::AsyncOperation(expression) => vec![("expression", expression.as_ref())],
+            Self::ArrayOperation(expression) => vec![("expression", expression)],
+            Self::AssignmentOperation(expression) => vec![("expression", expression)],
+            Self::BitwiseOperation(expression) => vec![("expression", expression)],
+            Self::ClassOperation(expression) => vec![("expression", expression.as_ref())],
+            Self::CoalesceOperation(expression) => vec![("expression", expression)],
+            Self::ComparisonOperation(expression) => vec![("expression", expression)],
+            Self::ExceptionOperation(expression) => vec![("expression", expression)],
+            Self::FunctionOperation(expression) => vec![("expression", expression.as_ref())],
+            Self::GeneratorOperation(expression) => vec![("expression", expression)],
+            Self::LogicalOperation(expression) => vec![("expression", expression)],
+            Self::ObjectOperation(expression) => vec![("expression", expression.as_ref())],
+            Self::RangeOperation(expression) => vec![("expression", expression)],
+            Self::StringOperation(expression) => vec![("expression", expression)],
+            Self::TypeOperation(expression) => vec![("expression", expression.as_ref())],
+            Self::TernaryOperation(expression) => vec![("expression", expression)],
+            Self::Identifier(expression) => vec![("expression", expression)],
+            Self::Variable(expression) => vec![("expression", expression)],
+            Self::Match(expression) => vec![("expression", expression.as_ref())],
+            Self::AnonymousFunction(expression) => vec![("expression", expression.as_ref())],
+            Self::ArrowFunction(expression) => vec![("expression", expression.as_ref())],
+            Self::Vec(expression) => vec![("expression", expression.as_ref())],
+            Self::Dict(expression) => vec![("expression", expression.as_ref())],
+            Self::Tuple(expression) => vec![("expression", expression)],
+            Self::MagicConstant(expression) => vec![("expression", expression)],
+            Self::FunctionalOperation(expression) => vec![("expression", expression)],
         }
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        match &self {
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
             Self::Parenthesized(expression) => vec![expression],
             Self::ExitConstruct(expression) => vec![expression],
             Self::Literal(expression) => vec![expression],
             Self::ArithmeticOperation(expression) => vec![expression],
-            Self::AsyncOperation(expression) => vec![expression],
+            Self::AsyncOperation(expression) => vec![expression.as_mut()],
             Self::ArrayOperation(expression) => vec![expression],
             Self::AssignmentOperation(expression) => vec![expression],
             Self::BitwiseOperation(expression) => vec![expression],
-            Self::ClassOperation(expression) => vec![expression],
+            Self::ClassOperation(expression) => vec![expression.as_mut()],
             Self::CoalesceOperation(expression) => vec![expression],
             Self::ComparisonOperation(expression) => vec![expression],
             Self::ExceptionOperation(expression) => vec![expression],
-            Self::FunctionOperation(expression) => vec![expression],
+            Self::FunctionOperation(expression) => vec![expression.as_mut()],
             Self::GeneratorOperation(expression) => vec![expression],
             Self::LogicalOperation(expression) => vec![expression],
-            Self::ObjectOperation(expression) => vec![expression],
+            Self::ObjectOperation(expression) => vec![expression.as_mut()],
             Self::RangeOperation(expression) => vec![expression],
             Self::StringOperation(expression) => vec![expression],
-            Self::TypeOperation(expression) => vec![expression],
+            Self::TypeOperation(expression) => vec![expression.as_mut()],
             Self::TernaryOperation(expression) => vec![expression],
             Self::Identifier(expression) => vec![expression],
             Self::Variable(expression) => vec![expression],
-            Self::Match(expression) => vec![expression],
-            Self::AnonymousFunction(expression) => vec![expression],
-            Self::ArrowFunction(expression) => vec![expression],
-            Self::Vec(expression) => vec![expression],
-            Self::Dict(expression) => vec![expression],
+            Self::Match(expression) => vec![expression.as_mut()],
+            Self::AnonymousFunction(expression) => vec![expression.as_mut()],
+            Self::ArrowFunction(expression) => vec![expression.as_mut()],
+            Self::Vec(expression) => vec![expression.as_mut()],
+            Self::Dict(expression) => vec![expression.as_mut()],
             Self::Tuple(expression) => vec![expression],
             Self::MagicConstant(expression) => vec![expression],
             Self::FunctionalOperation(expression) => vec![expression],
         }
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        vec![]
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::Expression
+    }
+
     fn get_description(&self) -> String {
         match &self {
             Self::Parenthesized(expression) => expression.get_description(),
@@ -12,8 +12,11 @@ use crate::tree::expression::class::AnonymousClassExpression;
 use crate::tree::expression::generic::GenericGroupExpression;
 use crate::tree::expression::Expression;
 use crate::tree::identifier::Identifier;
+use crate::tree::kind::NodeKind;
+use crate::tree::span::Span;
 use crate::tree::token::Keyword;
 use crate::tree::utils::CommaSeparated;
+use crate::tree::utils::ListView;
 use crate::tree::variable::Variable;
 use crate::tree::Node;
 
@@ -519,6 +522,7 @@ pub enum ClassOperationInitializationClassExpression {
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
 #[serde(rename_all = "snake_case", tag = "type", content = "value")]
+#[allow(clippy::large_enum_variant)]
 pub enum ClassOperationExpression {
     Initialization {
         comments: CommentGroup,
@@ -659,39 +663,92 @@ impl Node for FunctionalOperationExpression {
         }
     }
 
-    fn initial_position(&self) -> usize {
-        match &self {
+    fn span(&self) -> Span {
+        let initial_position = match &self {
             Self::Pipe { left, .. } => left.initial_position(),
             Self::Expression { dollar, .. } => *dollar,
-        }
-    }
-
-    fn final_position(&self) -> usize {
-        match &self {
+        };
+        let final_position = match &self {
             Self::Pipe { right, .. } => right.final_position(),
             Self::Expression {
                 right_parenthesis, ..
             } => *right_parenthesis,
-        }
+        };
+
+        Span::new(initial_position, final_position)
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
         match &self {
-            Self::Pipe { left, right, .. } => vec![left.as_ref(), right.as_ref()],
+            Self::Pipe { left, right, .. } => {
+                vec![("left", left.as_ref()), ("right", right.as_ref())]
+            }
+            Self::Expression {
+                generics,
+                expression,
+                ..
+            } => {
+                let mut children: Vec<(&'static str, &dyn Node)> = vec![];
+                if let Some(generics) = generics {
+                    children.push(("generics", generics));
+                }
+                children.push(("expression", expression.as_ref()));
+                children
+            }
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
+            Self::Pipe { left, right, .. } => {
+                vec![left.as_mut(), right.as_mut()]
+            }
             Self::Expression {
                 generics,
                 expression,
                 ..
             } => {
-                let mut children: Vec<&dyn Node> = vec![expression.as_ref()];
+                let mut children: Vec<&mut dyn Node> = vec![];
                 if let Some(generics) = generics {
                     children.push(generics);
                 }
+                children.push(expression.as_mut());
                 children
             }
         }
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        match self {
+            Self::Pipe {
+                comments,
+                pipe,
+                greater_than,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![pipe, greater_than];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::Expression {
+                comments,
+                dollar,
+                left_parenthesis,
+                right_parenthesis,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> =
+                    vec![dollar, left_parenthesis, right_parenthesis];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+        }
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::FunctionalOperationExpression
+    }
+
     fn get_description(&self) -> String {
         match &self {
             Self::Pipe { .. } => "pipe functional operation expression".to_string(),
@@ -718,8 +775,8 @@ impl Node for ArithmeticOperationExpression {
         }
     }
 
-    fn initial_position(&self) -> usize {
-        match &self {
+    fn span(&self) -> Span {
+        let initial_position = match &self {
             Self::Addition { left, .. } => left.initial_position(),
             Self::Subtraction { left, .. } => left.initial_position(),
             Self::Multiplication { left, .. } => left.initial_position(),
@@ -732,11 +789,8 @@ impl Node for ArithmeticOperationExpression {
             Self::PreDecrement { decrement, .. } => *decrement,
             Self::PostIncrement { left, .. } => left.initial_position(),
             Self::PostDecrement { left, .. } => left.initial_position(),
-        }
-    }
-
-    fn final_position(&self) -> usize {
-        match &self {
+        };
+        let final_position = match &self {
             Self::Addition { right, .. } => right.final_position(),
             Self::Subtraction { right, .. } => right.final_position(),
             Self::Multiplication { right, .. } => right.final_position(),
@@ -749,10 +803,12 @@ impl Node for ArithmeticOperationExpression {
             Self::PreDecrement { right, .. } => right.final_position(),
             Self::PostIncrement { increment, .. } => *increment,
             Self::PostDecrement { decrement, .. } => *decrement,
-        }
+        };
+
+        Span::new(initial_position, final_position)
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
         match &self {
             Self::Addition { left, right, .. }
             | Self::Subtraction { left, right, .. }
@@ -760,18 +816,117 @@ impl Node for ArithmeticOperationExpression {
             | Self::Division { left, right, .. }
             | Self::Modulo { left, right, .. }
             | Self::Exponentiation { left, right, .. } => {
-                vec![left.as_ref(), right.as_ref()]
+                vec![("left", left.as_ref()), ("right", right.as_ref())]
+            }
+            Self::Negative { right, .. }
+            | Self::Positive { right, .. }
+            | Self::PreIncrement { right, .. }
+            | Self::PreDecrement { right, .. } => vec![("right", right.as_ref())],
+            Self::PostIncrement { left, .. } | Self::PostDecrement { left, .. } => {
+                vec![("left", left.as_ref())]
+            }
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
+            Self::Addition { left, right, .. }
+            | Self::Subtraction { left, right, .. }
+            | Self::Multiplication { left, right, .. }
+            | Self::Division { left, right, .. }
+            | Self::Modulo { left, right, .. }
+            | Self::Exponentiation { left, right, .. } => {
+                vec![left.as_mut(), right.as_mut()]
             }
             Self::Negative { right, .. }
             | Self::Positive { right, .. }
             | Self::PreIncrement { right, .. }
-            | Self::PreDecrement { right, .. } => vec![right.as_ref()],
+            | Self::PreDecrement { right, .. } => vec![right.as_mut()],
             Self::PostIncrement { left, .. } | Self::PostDecrement { left, .. } => {
-                vec![left.as_ref()]
+                vec![left.as_mut()]
+            }
+        }
+    }
+
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        match self {
+            Self::Addition { comments, plus, .. } => {
+                let mut positions: Vec<&mut usize> = vec![plus];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::Subtraction {
+                comments, minus, ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![minus];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::Multiplication {
+                comments, asterisk, ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![asterisk];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::Division {
+                comments, slash, ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![slash];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::Modulo {
+                comments, percent, ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![percent];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::Exponentiation { comments, pow, .. } => {
+                let mut positions: Vec<&mut usize> = vec![pow];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::Negative {
+                comments, minus, ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![minus];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::Positive { comments, plus, .. } => {
+                let mut positions: Vec<&mut usize> = vec![plus];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::PreIncrement {
+                comments,
+                increment,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![increment];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::PostIncrement { increment, .. } => vec![increment],
+            Self::PreDecrement {
+                comments,
+                decrement,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![decrement];
+                positions.extend(comments.positions_mut());
+                positions
             }
+            Self::PostDecrement { decrement, .. } => vec![decrement],
         }
     }
 
+    fn kind(&self) -> NodeKind {
+        NodeKind::ArithmeticOperationExpression
+    }
+
     fn get_description(&self) -> String {
         match &self {
             Self::Addition { .. } => "addition arithmetic operation expression".to_string(),
@@ -822,8 +977,8 @@ impl Node for AssignmentOperationExpression {
         }
     }
 
-    fn initial_position(&self) -> usize {
-        match &self {
+    fn span(&self) -> Span {
+        let initial_position = match &self {
             Self::Assignment { left, .. } => left.initial_position(),
             Self::Addition { left, .. } => left.initial_position(),
             Self::Subtraction { left, .. } => left.initial_position(),
@@ -838,11 +993,8 @@ impl Node for AssignmentOperationExpression {
             Self::RightShift { left, .. } => left.initial_position(),
             Self::Coalesce { left, .. } => left.initial_position(),
             Self::Concat { left, .. } => left.initial_position(),
-        }
-    }
-
-    fn final_position(&self) -> usize {
-        match &self {
+        };
+        let final_position = match &self {
             Self::Assignment { right, .. } => right.final_position(),
             Self::Addition { right, .. } => right.final_position(),
             Self::Subtraction { right, .. } => right.final_position(),
@@ -857,10 +1009,12 @@ impl Node for AssignmentOperationExpression {
             Self::RightShift { right, .. } => right.final_position(),
             Self::Coalesce { right, .. } => right.final_position(),
             Self::Concat { right, .. } => right.final_position(),
-        }
+        };
+
+        Span::new(initial_position, final_position)
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
         match &self {
             Self::Assignment { left, right, .. }
             | Self::Addition { left, right, .. }
@@ -876,11 +1030,165 @@ impl Node for AssignmentOperationExpression {
             | Self::RightShift { left, right, .. }
             | Self::Coalesce { left, right, .. }
             | Self::Concat { left, right, .. } => {
-                vec![left.as_ref(), right.as_ref()]
+                vec![("left", left.as_ref()), ("right", right.as_ref())]
+            }
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
+            Self::Assignment { left, right, .. }
+            | Self::Addition { left, right, .. }
+            | Self::Subtraction { left, right, .. }
+            | Self::Multiplication { left, right, .. }
+            | Self::Division { left, right, .. }
+            | Self::Modulo { left, right, .. }
+            | Self::Exponentiation { left, right, .. }
+            | Self::BitwiseAnd { left, right, .. }
+            | Self::BitwiseOr { left, right, .. }
+            | Self::BitwiseXor { left, right, .. }
+            | Self::LeftShift { left, right, .. }
+            | Self::RightShift { left, right, .. }
+            | Self::Coalesce { left, right, .. }
+            | Self::Concat { left, right, .. } => {
+                vec![left.as_mut(), right.as_mut()]
+            }
+        }
+    }
+
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        match self {
+            Self::Assignment {
+                comments, equals, ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![equals];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::Addition {
+                comments,
+                plus_equals,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![plus_equals];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::Subtraction {
+                comments,
+                minus_equals,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![minus_equals];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::Multiplication {
+                comments,
+                asterisk_equals,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![asterisk_equals];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::Division {
+                comments,
+                slash_equals,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![slash_equals];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::Modulo {
+                comments,
+                percent_equals,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![percent_equals];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::Exponentiation {
+                comments,
+                pow_equals,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![pow_equals];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::Concat {
+                comments,
+                dot_equals,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![dot_equals];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::BitwiseAnd {
+                comments,
+                ampersand_equals,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![ampersand_equals];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::BitwiseOr {
+                comments,
+                pipe_equals,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![pipe_equals];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::BitwiseXor {
+                comments,
+                caret_equals,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![caret_equals];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::LeftShift {
+                comments,
+                left_shift_equals,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![left_shift_equals];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::RightShift {
+                comments,
+                right_shift_equals,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![right_shift_equals];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::Coalesce {
+                comments,
+                coalesce_equals,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![coalesce_equals];
+                positions.extend(comments.positions_mut());
+                positions
             }
         }
     }
 
+    fn kind(&self) -> NodeKind {
+        NodeKind::AssignmentOperationExpression
+    }
+
     fn get_description(&self) -> String {
         match &self {
             Self::Assignment { .. } => "assignment operation expression".to_string(),
@@ -917,41 +1225,100 @@ impl Node for BitwiseOperationExpression {
         }
     }
 
-    fn initial_position(&self) -> usize {
-        match &self {
+    fn span(&self) -> Span {
+        let initial_position = match &self {
             Self::And { left, .. } => left.initial_position(),
             Self::Or { left, .. } => left.initial_position(),
             Self::Xor { left, .. } => left.initial_position(),
             Self::LeftShift { left, .. } => left.initial_position(),
             Self::RightShift { left, .. } => left.initial_position(),
             Self::Not { not, .. } => *not,
-        }
-    }
-
-    fn final_position(&self) -> usize {
-        match &self {
+        };
+        let final_position = match &self {
             Self::And { right, .. } => right.final_position(),
             Self::Or { right, .. } => right.final_position(),
             Self::Xor { right, .. } => right.final_position(),
             Self::LeftShift { right, .. } => right.final_position(),
             Self::RightShift { right, .. } => right.final_position(),
             Self::Not { right, .. } => right.final_position(),
-        }
+        };
+
+        Span::new(initial_position, final_position)
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
         match &self {
             Self::And { left, right, .. }
             | Self::Or { left, right, .. }
             | Self::Xor { left, right, .. }
             | Self::LeftShift { left, right, .. }
             | Self::RightShift { left, right, .. } => {
-                vec![left.as_ref(), right.as_ref()]
+                vec![("left", left.as_ref()), ("right", right.as_ref())]
+            }
+            Self::Not { right, .. } => vec![("right", right.as_ref())],
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
+            Self::And { left, right, .. }
+            | Self::Or { left, right, .. }
+            | Self::Xor { left, right, .. }
+            | Self::LeftShift { left, right, .. }
+            | Self::RightShift { left, right, .. } => {
+                vec![left.as_mut(), right.as_mut()]
+            }
+            Self::Not { right, .. } => vec![right.as_mut()],
+        }
+    }
+
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        match self {
+            Self::And { comments, and, .. } => {
+                let mut positions: Vec<&mut usize> = vec![and];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::Or { comments, or, .. } => {
+                let mut positions: Vec<&mut usize> = vec![or];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::Xor { comments, xor, .. } => {
+                let mut positions: Vec<&mut usize> = vec![xor];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::LeftShift {
+                comments,
+                left_shift,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![left_shift];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::RightShift {
+                comments,
+                right_shift,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![right_shift];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::Not { comments, not, .. } => {
+                let mut positions: Vec<&mut usize> = vec![not];
+                positions.extend(comments.positions_mut());
+                positions
             }
-            Self::Not { right, .. } => vec![right.as_ref()],
         }
     }
 
+    fn kind(&self) -> NodeKind {
+        NodeKind::BitwiseOperationExpression
+    }
+
     fn get_description(&self) -> String {
         match &self {
             Self::And { .. } => "bitwise AND operation expression".to_string(),
@@ -979,8 +1346,8 @@ impl Node for ComparisonOperationExpression {
         }
     }
 
-    fn initial_position(&self) -> usize {
-        match &self {
+    fn span(&self) -> Span {
+        let initial_position = match &self {
             Self::Equal { left, .. }
             | Self::NotEqual { left, .. }
             | Self::Identical { left, .. }
@@ -990,11 +1357,8 @@ impl Node for ComparisonOperationExpression {
             | Self::GreaterThan { left, .. }
             | Self::GreaterThanOrEqual { left, .. }
             | Self::Spaceship { left, .. } => left.initial_position(),
-        }
-    }
-
-    fn final_position(&self) -> usize {
-        match &self {
+        };
+        let final_position = match &self {
             Self::Equal { right, .. }
             | Self::NotEqual { right, .. }
             | Self::Identical { right, .. }
@@ -1004,10 +1368,12 @@ impl Node for ComparisonOperationExpression {
             | Self::GreaterThan { right, .. }
             | Self::GreaterThanOrEqual { right, .. }
             | Self::Spaceship { right, .. } => right.final_position(),
-        }
+        };
+
+        Span::new(initial_position, final_position)
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
         match &self {
             Self::Equal { left, right, .. }
             | Self::NotEqual { left, right, .. }
@@ -1018,11 +1384,117 @@ impl Node for ComparisonOperationExpression {
             | Self::GreaterThan { left, right, .. }
             | Self::GreaterThanOrEqual { left, right, .. }
             | Self::Spaceship { left, right, .. } => {
-                vec![left.as_ref(), right.as_ref()]
+                vec![("left", left.as_ref()), ("right", right.as_ref())]
+            }
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
+            Self::Equal { left, right, .. }
+            | Self::NotEqual { left, right, .. }
+            | Self::Identical { left, right, .. }
+            | Self::NotIdentical { left, right, .. }
+            | Self::LessThan { left, right, .. }
+            | Self::LessThanOrEqual { left, right, .. }
+            | Self::GreaterThan { left, right, .. }
+            | Self::GreaterThanOrEqual { left, right, .. }
+            | Self::Spaceship { left, right, .. } => {
+                vec![left.as_mut(), right.as_mut()]
+            }
+        }
+    }
+
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        match self {
+            Self::Equal {
+                comments,
+                double_equals,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![double_equals];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::Identical {
+                comments,
+                triple_equals,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![triple_equals];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::NotEqual {
+                comments,
+                bang_equals,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![bang_equals];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::NotIdentical {
+                comments,
+                bang_double_equals,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![bang_double_equals];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::LessThan {
+                comments,
+                less_than,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![less_than];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::GreaterThan {
+                comments,
+                greater_than,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![greater_than];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::LessThanOrEqual {
+                comments,
+                less_than_equals,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![less_than_equals];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::GreaterThanOrEqual {
+                comments,
+                greater_than_equals,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![greater_than_equals];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::Spaceship {
+                comments,
+                spaceship,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![spaceship];
+                positions.extend(comments.positions_mut());
+                positions
             }
         }
     }
 
+    fn kind(&self) -> NodeKind {
+        NodeKind::ComparisonOperationExpression
+    }
+
     fn get_description(&self) -> String {
         match &self {
             Self::Equal { .. } => "equal comparison operation expression".to_string(),
@@ -1053,31 +1525,71 @@ impl Node for LogicalOperationExpression {
         }
     }
 
-    fn initial_position(&self) -> usize {
-        match &self {
+    fn span(&self) -> Span {
+        let initial_position = match &self {
             Self::And { left, .. } => left.initial_position(),
             Self::Or { left, .. } => left.initial_position(),
             Self::Not { bang, .. } => *bang,
-        }
-    }
-
-    fn final_position(&self) -> usize {
-        match &self {
+        };
+        let final_position = match &self {
             Self::And { right, .. } => right.final_position(),
             Self::Or { right, .. } => right.final_position(),
             Self::Not { right, .. } => right.final_position(),
-        }
+        };
+
+        Span::new(initial_position, final_position)
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
         match &self {
             Self::And { left, right, .. } | Self::Or { left, right, .. } => {
-                vec![left.as_ref(), right.as_ref()]
+                vec![("left", left.as_ref()), ("right", right.as_ref())]
             }
-            Self::Not { right, .. } => vec![right.as_ref()],
+            Self::Not { right, .. } => vec![("right", right.as_ref())],
         }
     }
 
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
+            Self::And { left, right, .. } | Self::Or { left, right, .. } => {
+                vec![left.as_mut(), right.as_mut()]
+            }
+            Self::Not { right, .. } => vec![right.as_mut()],
+        }
+    }
+
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        match self {
+            Self::And {
+                comments,
+                double_ampersand,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![double_ampersand];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::Or {
+                comments,
+                double_pipe,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![double_pipe];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::Not { comments, bang, .. } => {
+                let mut positions: Vec<&mut usize> = vec![bang];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+        }
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::LogicalOperationExpression
+    }
+
     fn get_description(&self) -> String {
         match &self {
             Self::And { .. } => "logical AND operation expression".to_string(),
@@ -1094,26 +1606,47 @@ impl Node for StringOperationExpression {
         }
     }
 
-    fn initial_position(&self) -> usize {
-        match &self {
+    fn span(&self) -> Span {
+        let initial_position = match &self {
             Self::Concat { left, .. } => left.initial_position(),
-        }
+        };
+        let final_position = match &self {
+            Self::Concat { right, .. } => right.final_position(),
+        };
+
+        Span::new(initial_position, final_position)
     }
 
-    fn final_position(&self) -> usize {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
         match &self {
-            Self::Concat { right, .. } => right.final_position(),
+            Self::Concat { left, right, .. } => {
+                vec![("left", left.as_ref()), ("right", right.as_ref())]
+            }
         }
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        match &self {
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
             Self::Concat { left, right, .. } => {
-                vec![left.as_ref(), right.as_ref()]
+                vec![left.as_mut(), right.as_mut()]
+            }
+        }
+    }
+
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        match self {
+            Self::Concat { comments, dot, .. } => {
+                let mut positions: Vec<&mut usize> = vec![dot];
+                positions.extend(comments.positions_mut());
+                positions
             }
         }
     }
 
+    fn kind(&self) -> NodeKind {
+        NodeKind::StringOperationExpression
+    }
+
     fn get_description(&self) -> String {
         match &self {
             Self::Concat { .. } => "string concatenation operation expression".to_string(),
@@ -1132,32 +1665,63 @@ impl Node for ArrayOperationExpression {
         }
     }
 
-    fn initial_position(&self) -> usize {
-        match &self {
+    fn span(&self) -> Span {
+        let initial_position = match &self {
             Self::Access { array, .. } | Self::Push { array, .. } => array.initial_position(),
             Self::Isset { isset, .. } => isset.initial_position(),
             Self::Unset { unset, .. } => unset.initial_position(),
             Self::In { item, .. } => item.initial_position(),
-        }
-    }
-
-    fn final_position(&self) -> usize {
-        match &self {
+        };
+        let final_position = match &self {
             Self::Access { right_bracket, .. } | Self::Push { right_bracket, .. } => {
                 right_bracket + 1
             }
             Self::Isset { item, .. } | Self::Unset { item, .. } => item.final_position(),
             Self::In { array, .. } => array.final_position(),
-        }
+        };
+
+        Span::new(initial_position, final_position)
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
         match &self {
             Self::Access { array, index, .. } => {
-                vec![array.as_ref(), index.as_ref()]
+                vec![("array", array.as_ref()), ("index", index.as_ref())]
+            }
+            Self::Push { array, .. } => {
+                vec![("array", array.as_ref())]
+            }
+            Self::Isset {
+                isset: keyword,
+                item,
+                ..
+            }
+            | Self::Unset {
+                unset: keyword,
+                item,
+                ..
+            } => {
+                vec![("keyword", keyword), ("item", item.as_ref())]
+            }
+            Self::In {
+                item, r#in, array, ..
+            } => {
+                vec![
+                    ("item", item.as_ref()),
+                    ("in", r#in),
+                    ("array", array.as_ref()),
+                ]
+            }
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
+            Self::Access { array, index, .. } => {
+                vec![array.as_mut(), index.as_mut()]
             }
             Self::Push { array, .. } => {
-                vec![array.as_ref()]
+                vec![array.as_mut()]
             }
             Self::Isset {
                 isset: keyword,
@@ -1169,16 +1733,48 @@ impl Node for ArrayOperationExpression {
                 item,
                 ..
             } => {
-                vec![keyword, item.as_ref()]
+                vec![keyword, item.as_mut()]
             }
             Self::In {
                 item, r#in, array, ..
             } => {
-                vec![item.as_ref(), r#in, array.as_ref()]
+                vec![item.as_mut(), r#in, array.as_mut()]
             }
         }
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        match self {
+            Self::Access {
+                comments,
+                left_bracket,
+                right_bracket,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![left_bracket, right_bracket];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::Push {
+                comments,
+                left_bracket,
+                right_bracket,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![left_bracket, right_bracket];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::Unset { comments, .. } => comments.positions_mut().collect(),
+            Self::Isset { comments, .. } => comments.positions_mut().collect(),
+            Self::In { comments, .. } => comments.positions_mut().collect(),
+        }
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::ArrayOperationExpression
+    }
+
     fn get_description(&self) -> String {
         match &self {
             Self::Access { .. } => "array access operation expression".to_string(),
@@ -1197,26 +1793,51 @@ impl Node for CoalesceOperationExpression {
         }
     }
 
-    fn initial_position(&self) -> usize {
-        match &self {
+    fn span(&self) -> Span {
+        let initial_position = match &self {
             Self::Coalesce { left, .. } => left.initial_position(),
-        }
+        };
+        let final_position = match &self {
+            Self::Coalesce { right, .. } => right.final_position(),
+        };
+
+        Span::new(initial_position, final_position)
     }
 
-    fn final_position(&self) -> usize {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
         match &self {
-            Self::Coalesce { right, .. } => right.final_position(),
+            Self::Coalesce { left, right, .. } => {
+                vec![("left", left.as_ref()), ("right", right.as_ref())]
+            }
         }
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        match &self {
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
             Self::Coalesce { left, right, .. } => {
-                vec![left.as_ref(), right.as_ref()]
+                vec![left.as_mut(), right.as_mut()]
             }
         }
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        match self {
+            Self::Coalesce {
+                comments,
+                double_question,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![double_question];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+        }
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::CoalesceOperationExpression
+    }
+
     fn get_description(&self) -> String {
         match &self {
             Self::Coalesce { .. } => "coalesce operation expression".to_string(),
@@ -1233,43 +1854,111 @@ impl Node for TernaryOperationExpression {
         }
     }
 
-    fn initial_position(&self) -> usize {
-        match &self {
+    fn span(&self) -> Span {
+        let initial_position = match &self {
             Self::Ternary { condition, .. } => condition.initial_position(),
             Self::ShortTernary { condition, .. } => condition.initial_position(),
             Self::ImplicitShortTernary { condition, .. } => condition.initial_position(),
-        }
-    }
-
-    fn final_position(&self) -> usize {
-        match &self {
+        };
+        let final_position = match &self {
             Self::Ternary { if_false, .. } => if_false.final_position(),
             Self::ShortTernary { if_false, .. } => if_false.final_position(),
             Self::ImplicitShortTernary { if_false, .. } => if_false.final_position(),
-        }
+        };
+
+        Span::new(initial_position, final_position)
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
         match &self {
             Self::Ternary {
                 condition,
                 if_true,
                 if_false,
                 ..
-            } => vec![condition.as_ref(), if_true.as_ref(), if_false.as_ref()],
+            } => vec![
+                ("condition", condition.as_ref()),
+                ("if_true", if_true.as_ref()),
+                ("if_false", if_false.as_ref()),
+            ],
             Self::ShortTernary {
                 condition,
                 if_false,
                 ..
-            } => vec![condition.as_ref(), if_false.as_ref()],
+            } => vec![
+                ("condition", condition.as_ref()),
+                ("if_false", if_false.as_ref()),
+            ],
             Self::ImplicitShortTernary {
                 condition,
                 if_false,
                 ..
-            } => vec![condition.as_ref(), if_false.as_ref()],
+            } => vec![
+                ("condition", condition.as_ref()),
+                ("if_false", if_false.as_ref()),
+            ],
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
+            Self::Ternary {
+                condition,
+                if_true,
+                if_false,
+                ..
+            } => vec![condition.as_mut(), if_true.as_mut(), if_false.as_mut()],
+            Self::ShortTernary {
+                condition,
+                if_false,
+                ..
+            } => vec![condition.as_mut(), if_false.as_mut()],
+            Self::ImplicitShortTernary {
+                condition,
+                if_false,
+                ..
+            } => vec![condition.as_mut(), if_false.as_mut()],
+        }
+    }
+
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        match self {
+            Self::Ternary {
+                comments,
+                question,
+                colon,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![question, colon];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::ImplicitShortTernary {
+                comments,
+                question,
+                colon,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![question, colon];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::ShortTernary {
+                comments,
+                question_colon,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![question_colon];
+                positions.extend(comments.positions_mut());
+                positions
+            }
         }
     }
 
+    fn kind(&self) -> NodeKind {
+        NodeKind::TernaryOperationExpression
+    }
+
     fn get_description(&self) -> String {
         match &self {
             Self::Ternary { .. } => "ternary operation expression".to_string(),
@@ -1291,44 +1980,80 @@ impl Node for TypeOperationExpression {
         }
     }
 
-    fn initial_position(&self) -> usize {
-        match &self {
+    fn span(&self) -> Span {
+        let initial_position = match &self {
             Self::Instanceof { left, .. } => left.initial_position(),
             Self::Is { left, .. } => left.initial_position(),
             Self::Into { left, .. } => left.initial_position(),
             Self::As { left, .. } => left.initial_position(),
-        }
-    }
-
-    fn final_position(&self) -> usize {
-        match &self {
+        };
+        let final_position = match &self {
             Self::Instanceof { right, .. } => right.final_position(),
             Self::Is { right, .. } => right.final_position(),
             Self::Into { right, .. } => right.final_position(),
             Self::As { right, .. } => right.final_position(),
-        }
+        };
+
+        Span::new(initial_position, final_position)
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
         match &self {
             Self::Instanceof {
                 left,
                 instanceof,
                 right,
                 ..
-            } => vec![left.as_ref(), instanceof, right],
+            } => vec![
+                ("left", left.as_ref()),
+                ("instanceof", instanceof),
+                ("right", right),
+            ],
+            Self::Is {
+                left, is, right, ..
+            } => vec![("left", left.as_ref()), ("is", is), ("right", right)],
+            Self::Into {
+                left, into, right, ..
+            } => vec![("left", left.as_ref()), ("into", into), ("right", right)],
+            Self::As {
+                left, r#as, right, ..
+            } => vec![("left", left.as_ref()), ("as", r#as), ("right", right)],
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
+            Self::Instanceof {
+                left,
+                instanceof,
+                right,
+                ..
+            } => vec![left.as_mut(), instanceof, right],
             Self::Is {
                 left, is, right, ..
-            } => vec![left.as_ref(), is, right],
+            } => vec![left.as_mut(), is, right],
             Self::Into {
                 left, into, right, ..
-            } => vec![left.as_ref(), into, right],
+            } => vec![left.as_mut(), into, right],
             Self::As {
                 left, r#as, right, ..
-            } => vec![left.as_ref(), r#as, right],
+            } => vec![left.as_mut(), r#as, right],
         }
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        match self {
+            Self::Instanceof { comments, .. } => comments.positions_mut().collect(),
+            Self::Is { comments, .. } => comments.positions_mut().collect(),
+            Self::Into { comments, .. } => comments.positions_mut().collect(),
+            Self::As { comments, .. } => comments.positions_mut().collect(),
+        }
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::TypeOperationExpression
+    }
+
     fn get_description(&self) -> String {
         match &self {
             Self::Instanceof { .. } => "instanceof type operation expression".to_string(),
@@ -1349,29 +2074,59 @@ impl Node for GeneratorOperationExpression {
         }
     }
 
-    fn initial_position(&self) -> usize {
-        match &self {
+    fn span(&self) -> Span {
+        let initial_position = match &self {
             Self::Yield { r#yield, .. }
             | Self::YieldValue { r#yield, .. }
             | Self::YieldKeyValue { r#yield, .. }
             | Self::YieldFrom { r#yield, .. } => r#yield.initial_position(),
-        }
-    }
-
-    fn final_position(&self) -> usize {
-        match &self {
+        };
+        let final_position = match &self {
             Self::Yield { r#yield, .. } => r#yield.final_position(),
             Self::YieldValue { value, .. } => value.final_position(),
             Self::YieldKeyValue { value, .. } => value.final_position(),
             Self::YieldFrom { value, .. } => value.final_position(),
-        }
+        };
+
+        Span::new(initial_position, final_position)
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
         match &self {
+            Self::Yield { r#yield, .. } => vec![("yield", r#yield)],
+            Self::YieldValue { r#yield, value, .. } => {
+                vec![("yield", r#yield), ("value", value.as_ref())]
+            }
+            Self::YieldKeyValue {
+                r#yield,
+                key,
+                value,
+                ..
+            } => {
+                vec![
+                    ("yield", r#yield),
+                    ("key", key.as_ref()),
+                    ("value", value.as_ref()),
+                ]
+            }
+            Self::YieldFrom {
+                r#yield,
+                from,
+                value,
+                ..
+            } => vec![
+                ("yield", r#yield),
+                ("from", from),
+                ("value", value.as_ref()),
+            ],
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
             Self::Yield { r#yield, .. } => vec![r#yield],
             Self::YieldValue { r#yield, value, .. } => {
-                vec![r#yield, value.as_ref()]
+                vec![r#yield, value.as_mut()]
             }
             Self::YieldKeyValue {
                 r#yield,
@@ -1379,17 +2134,38 @@ impl Node for GeneratorOperationExpression {
                 value,
                 ..
             } => {
-                vec![r#yield, key.as_ref(), value.as_ref()]
+                vec![r#yield, key.as_mut(), value.as_mut()]
             }
             Self::YieldFrom {
                 r#yield,
                 from,
                 value,
                 ..
-            } => vec![r#yield, from, value.as_ref()],
+            } => vec![r#yield, from, value.as_mut()],
         }
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        match self {
+            Self::Yield { comments, .. } => comments.positions_mut().collect(),
+            Self::YieldValue { comments, .. } => comments.positions_mut().collect(),
+            Self::YieldKeyValue {
+                comments,
+                double_arrow,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![double_arrow];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::YieldFrom { comments, .. } => comments.positions_mut().collect(),
+        }
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::GeneratorOperationExpression
+    }
+
     fn get_description(&self) -> String {
         match &self {
             Self::Yield { .. } => "yield generator operation expression".to_string(),
@@ -1409,24 +2185,39 @@ impl Node for ExceptionOperationExpression {
         }
     }
 
-    fn initial_position(&self) -> usize {
-        match &self {
+    fn span(&self) -> Span {
+        let initial_position = match &self {
             Self::Throw { throw, .. } => throw.initial_position(),
-        }
+        };
+        let final_position = match &self {
+            Self::Throw { value, .. } => value.final_position(),
+        };
+
+        Span::new(initial_position, final_position)
     }
 
-    fn final_position(&self) -> usize {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
         match &self {
-            Self::Throw { value, .. } => value.final_position(),
+            Self::Throw { throw, value, .. } => vec![("throw", throw), ("value", value.as_ref())],
         }
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        match &self {
-            Self::Throw { throw, value, .. } => vec![throw, value.as_ref()],
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
+            Self::Throw { throw, value, .. } => vec![throw, value.as_mut()],
+        }
+    }
+
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        match self {
+            Self::Throw { comments, .. } => comments.positions_mut().collect(),
         }
     }
 
+    fn kind(&self) -> NodeKind {
+        NodeKind::ExceptionOperationExpression
+    }
+
     fn get_description(&self) -> String {
         match &self {
             Self::Throw { .. } => "throw exception operation expression".to_string(),
@@ -1446,31 +2237,89 @@ impl Node for ObjectOperationExpression {
         }
     }
 
-    fn initial_position(&self) -> usize {
-        match &self {
+    fn span(&self) -> Span {
+        let initial_position = match &self {
             Self::Clone { clone, .. } => clone.initial_position(),
             Self::MethodCall { object, .. } => object.initial_position(),
             Self::NullsafeMethodCall { object, .. } => object.initial_position(),
             Self::MethodClosureCreation { object, .. } => object.initial_position(),
             Self::PropertyFetch { object, .. } => object.initial_position(),
             Self::NullsafePropertyFetch { object, .. } => object.initial_position(),
-        }
-    }
-
-    fn final_position(&self) -> usize {
-        match &self {
+        };
+        let final_position = match &self {
             Self::Clone { object, .. } => object.final_position(),
             Self::MethodCall { arguments, .. } => arguments.final_position(),
             Self::NullsafeMethodCall { arguments, .. } => arguments.final_position(),
             Self::MethodClosureCreation { placeholder, .. } => placeholder.final_position(),
             Self::PropertyFetch { property, .. } => property.final_position(),
             Self::NullsafePropertyFetch { property, .. } => property.final_position(),
+        };
+
+        Span::new(initial_position, final_position)
+    }
+
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        match &self {
+            Self::Clone { clone, object, .. } => {
+                vec![("clone", clone), ("object", object.as_ref())]
+            }
+            Self::MethodCall {
+                object,
+                method,
+                generics,
+                arguments,
+                ..
+            }
+            | Self::NullsafeMethodCall {
+                object,
+                method,
+                generics,
+                arguments,
+                ..
+            } => {
+                let mut children: Vec<(&'static str, &dyn Node)> =
+                    vec![("object", object.as_ref()), ("method", method)];
+                if let Some(generics) = generics {
+                    children.push(("generics", generics));
+                }
+
+                children.push(("arguments", arguments));
+
+                children
+            }
+            Self::MethodClosureCreation {
+                object,
+                method,
+                generics,
+                placeholder,
+                ..
+            } => {
+                let mut children: Vec<(&'static str, &dyn Node)> =
+                    vec![("object", object.as_ref()), ("method", method)];
+                if let Some(generics) = generics {
+                    children.push(("generics", generics));
+                }
+
+                children.push(("placeholder", placeholder));
+
+                children
+            }
+            Self::PropertyFetch {
+                object, property, ..
+            }
+            | Self::NullsafePropertyFetch {
+                object, property, ..
+            } => {
+                vec![("object", object.as_ref()), ("property", property)]
+            }
         }
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        match &self {
-            Self::Clone { clone, object, .. } => vec![clone, object.as_ref()],
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
+            Self::Clone { clone, object, .. } => {
+                vec![clone, object.as_mut()]
+            }
             Self::MethodCall {
                 object,
                 method,
@@ -1485,7 +2334,7 @@ impl Node for ObjectOperationExpression {
                 arguments,
                 ..
             } => {
-                let mut children: Vec<&dyn Node> = vec![object.as_ref(), method];
+                let mut children: Vec<&mut dyn Node> = vec![object.as_mut(), method];
                 if let Some(generics) = generics {
                     children.push(generics);
                 }
@@ -1501,7 +2350,7 @@ impl Node for ObjectOperationExpression {
                 placeholder,
                 ..
             } => {
-                let mut children: Vec<&dyn Node> = vec![object.as_ref(), method];
+                let mut children: Vec<&mut dyn Node> = vec![object.as_mut(), method];
                 if let Some(generics) = generics {
                     children.push(generics);
                 }
@@ -1516,11 +2365,60 @@ impl Node for ObjectOperationExpression {
             | Self::NullsafePropertyFetch {
                 object, property, ..
             } => {
-                vec![object.as_ref(), property]
+                vec![object.as_mut(), property]
+            }
+        }
+    }
+
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        match self {
+            Self::Clone { comments, .. } => comments.positions_mut().collect(),
+            Self::MethodCall {
+                comments, arrow, ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![arrow];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::NullsafeMethodCall {
+                comments,
+                question_arrow,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![question_arrow];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::MethodClosureCreation {
+                comments, arrow, ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![arrow];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::PropertyFetch {
+                comments, arrow, ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![arrow];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::NullsafePropertyFetch {
+                comments,
+                question_arrow,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![question_arrow];
+                positions.extend(comments.positions_mut());
+                positions
             }
         }
     }
 
+    fn kind(&self) -> NodeKind {
+        NodeKind::ObjectOperationExpression
+    }
+
     fn get_description(&self) -> String {
         match &self {
             Self::Clone { .. } => "object clone operation expression".to_string(),
@@ -1547,27 +2445,35 @@ impl Node for ClassOperationInitializationClassExpression {
         }
     }
 
-    fn initial_position(&self) -> usize {
+    fn span(&self) -> Span {
         match &self {
-            Self::Identifier(identifier) => identifier.initial_position(),
-            Self::Variable(variable) => variable.initial_position(),
+            Self::Identifier(identifier) => identifier.span(),
+            Self::Variable(variable) => variable.span(),
         }
     }
 
-    fn final_position(&self) -> usize {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
         match &self {
-            Self::Identifier(identifier) => identifier.final_position(),
-            Self::Variable(variable) => variable.final_position(),
+            Self::Identifier(identifier) => vec![("identifier", identifier)],
+            Self::Variable(variable) => vec![("variable", variable)],
         }
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        match &self {
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
             Self::Identifier(identifier) => vec![identifier],
             Self::Variable(variable) => vec![variable],
         }
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        vec![]
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::ClassOperationInitializationClassExpression
+    }
+
     fn get_description(&self) -> String {
         match &self {
             Self::Identifier(identifier) => identifier.get_description(),
@@ -1588,8 +2494,8 @@ impl Node for ClassOperationExpression {
         }
     }
 
-    fn initial_position(&self) -> usize {
-        match &self {
+    fn span(&self) -> Span {
+        let initial_position = match &self {
             Self::Initialization { new, .. }
             | ClassOperationExpression::AnonymousInitialization { new, .. } => {
                 new.initial_position()
@@ -1598,21 +2504,20 @@ impl Node for ClassOperationExpression {
             Self::StaticMethodClosureCreation { class, .. } => class.initial_position(),
             Self::StaticPropertyFetch { class, .. } => class.initial_position(),
             Self::ConstantFetch { class, .. } => class.initial_position(),
-        }
-    }
-
-    fn final_position(&self) -> usize {
-        match &self {
+        };
+        let final_position = match &self {
             Self::Initialization { arguments, .. } => arguments.final_position(),
             Self::AnonymousInitialization { class, .. } => class.final_position(),
             Self::StaticMethodCall { arguments, .. } => arguments.final_position(),
             Self::StaticMethodClosureCreation { placeholder, .. } => placeholder.final_position(),
             Self::StaticPropertyFetch { property, .. } => property.final_position(),
             Self::ConstantFetch { constant, .. } => constant.final_position(),
-        }
+        };
+
+        Span::new(initial_position, final_position)
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
         match &self {
             Self::Initialization {
                 new,
@@ -1621,7 +2526,76 @@ impl Node for ClassOperationExpression {
                 arguments,
                 ..
             } => {
-                let mut children: Vec<&dyn Node> = vec![new, class];
+                let mut children: Vec<(&'static str, &dyn Node)> =
+                    vec![("new", new), ("class", class)];
+                if let Some(generics) = generics {
+                    children.push(("generics", generics));
+                }
+
+                children.push(("arguments", arguments));
+
+                children
+            }
+            Self::AnonymousInitialization { new, class, .. } => {
+                vec![("new", new), ("class", class)]
+            }
+            Self::StaticMethodCall {
+                class,
+                method,
+                generics,
+                arguments,
+                ..
+            } => {
+                let mut children: Vec<(&'static str, &dyn Node)> =
+                    vec![("class", class.as_ref()), ("method", method)];
+                if let Some(generics) = generics {
+                    children.push(("generics", generics));
+                }
+
+                children.push(("arguments", arguments));
+
+                children
+            }
+            Self::StaticMethodClosureCreation {
+                class,
+                generics,
+                method,
+                placeholder,
+                ..
+            } => {
+                let mut children: Vec<(&'static str, &dyn Node)> =
+                    vec![("class", class.as_ref()), ("method", method)];
+                if let Some(generics) = generics {
+                    children.push(("generics", generics));
+                }
+
+                children.push(("placeholder", placeholder));
+
+                children
+            }
+            Self::StaticPropertyFetch {
+                class, property, ..
+            } => {
+                vec![("class", class.as_ref()), ("property", property)]
+            }
+            Self::ConstantFetch {
+                class, constant, ..
+            } => {
+                vec![("class", class.as_ref()), ("constant", constant)]
+            }
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
+            Self::Initialization {
+                new,
+                class,
+                generics,
+                arguments,
+                ..
+            } => {
+                let mut children: Vec<&mut dyn Node> = vec![new, class];
                 if let Some(generics) = generics {
                     children.push(generics);
                 }
@@ -1640,7 +2614,7 @@ impl Node for ClassOperationExpression {
                 arguments,
                 ..
             } => {
-                let mut children: Vec<&dyn Node> = vec![class.as_ref(), method];
+                let mut children: Vec<&mut dyn Node> = vec![class.as_mut(), method];
                 if let Some(generics) = generics {
                     children.push(generics);
                 }
@@ -1656,7 +2630,7 @@ impl Node for ClassOperationExpression {
                 placeholder,
                 ..
             } => {
-                let mut children: Vec<&dyn Node> = vec![class.as_ref(), method];
+                let mut children: Vec<&mut dyn Node> = vec![class.as_mut(), method];
                 if let Some(generics) = generics {
                     children.push(generics);
                 }
@@ -1668,16 +2642,63 @@ impl Node for ClassOperationExpression {
             Self::StaticPropertyFetch {
                 class, property, ..
             } => {
-                vec![class.as_ref(), property]
+                vec![class.as_mut(), property]
             }
             Self::ConstantFetch {
                 class, constant, ..
             } => {
-                vec![class.as_ref(), constant]
+                vec![class.as_mut(), constant]
+            }
+        }
+    }
+
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        match self {
+            Self::Initialization { comments, .. } => comments.positions_mut().collect(),
+            Self::AnonymousInitialization { comments, .. } => comments.positions_mut().collect(),
+            Self::StaticMethodCall {
+                comments,
+                double_colon,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![double_colon];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::StaticMethodClosureCreation {
+                comments,
+                double_colon,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![double_colon];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::StaticPropertyFetch {
+                comments,
+                double_colon,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![double_colon];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::ConstantFetch {
+                comments,
+                double_colon,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![double_colon];
+                positions.extend(comments.positions_mut());
+                positions
             }
         }
     }
 
+    fn kind(&self) -> NodeKind {
+        NodeKind::ClassOperationExpression
+    }
+
     fn get_description(&self) -> String {
         match &self {
             Self::Initialization { .. } => "class initialization operation expression".to_string(),
@@ -1706,30 +2727,64 @@ impl Node for FunctionOperationExpression {
         }
     }
 
-    fn initial_position(&self) -> usize {
-        match &self {
+    fn span(&self) -> Span {
+        let initial_position = match &self {
             Self::Call { function, .. } => function.initial_position(),
             Self::ClosureCreation { function, .. } => function.initial_position(),
-        }
+        };
+        let final_position = match &self {
+            Self::Call { arguments, .. } => arguments.final_position(),
+            Self::ClosureCreation { placeholder, .. } => placeholder.final_position(),
+        };
+
+        Span::new(initial_position, final_position)
     }
 
-    fn final_position(&self) -> usize {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
         match &self {
-            Self::Call { arguments, .. } => arguments.final_position(),
-            Self::ClosureCreation { placeholder, .. } => placeholder.final_position(),
+            Self::Call {
+                function,
+                generics,
+                arguments,
+                ..
+            } => {
+                let mut children: Vec<(&'static str, &dyn Node)> = vec![];
+                children.push(("function", function.as_ref()));
+                if let Some(generics) = generics {
+                    children.push(("generics", generics));
+                }
+                children.push(("arguments", arguments));
+
+                children
+            }
+            Self::ClosureCreation {
+                function,
+                generics,
+                placeholder,
+                ..
+            } => {
+                let mut children: Vec<(&'static str, &dyn Node)> = vec![];
+                children.push(("function", function.as_ref()));
+                if let Some(generics) = generics {
+                    children.push(("generics", generics));
+                }
+                children.push(("placeholder", placeholder));
+
+                children
+            }
         }
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        match &self {
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
             Self::Call {
                 function,
                 generics,
                 arguments,
                 ..
             } => {
-                let mut children: Vec<&dyn Node> = vec![];
-                children.push(function.as_ref());
+                let mut children: Vec<&mut dyn Node> = vec![];
+                children.push(function.as_mut());
                 if let Some(generics) = generics {
                     children.push(generics);
                 }
@@ -1743,8 +2798,8 @@ impl Node for FunctionOperationExpression {
                 placeholder,
                 ..
             } => {
-                let mut children: Vec<&dyn Node> = vec![];
-                children.push(function.as_ref());
+                let mut children: Vec<&mut dyn Node> = vec![];
+                children.push(function.as_mut());
                 if let Some(generics) = generics {
                     children.push(generics);
                 }
@@ -1755,6 +2810,17 @@ impl Node for FunctionOperationExpression {
         }
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        match self {
+            Self::Call { comments, .. } => comments.positions_mut().collect(),
+            Self::ClosureCreation { comments, .. } => comments.positions_mut().collect(),
+        }
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::FunctionOperationExpression
+    }
+
     fn get_description(&self) -> String {
         match &self {
             Self::Call { .. } => "function call operation expression".to_string(),
@@ -1774,41 +2840,75 @@ impl Node for AsyncOperationExpression {
         }
     }
 
-    fn initial_position(&self) -> usize {
-        match &self {
+    fn span(&self) -> Span {
+        let initial_position = match &self {
             Self::Await { r#await, .. } => r#await.initial_position(),
             Self::Async { r#async, .. } => r#async.initial_position(),
             Self::Concurrently { concurrently, .. } => concurrently.initial_position(),
-        }
-    }
-
-    fn final_position(&self) -> usize {
-        match &self {
+        };
+        let final_position = match &self {
             Self::Await { expression, .. } => expression.final_position(),
             Self::Async { expression, .. } => expression.final_position(),
             Self::Concurrently { right_brace, .. } => right_brace + 1,
-        }
+        };
+
+        Span::new(initial_position, final_position)
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
         match &self {
             Self::Await {
                 r#await,
                 expression,
                 ..
-            } => vec![r#await, expression.as_ref()],
+            } => vec![("await", r#await), ("expressions", expression.as_ref())],
             Self::Async {
                 r#async,
                 expression,
                 ..
-            } => vec![r#async, expression.as_ref()],
+            } => vec![("async", r#async), ("expressions", expression.as_ref())],
             Self::Concurrently {
                 concurrently,
                 expressions,
                 ..
             } => {
-                let mut children: Vec<&dyn Node> = vec![concurrently];
+                let mut children: Vec<(&'static str, &dyn Node)> =
+                    vec![("concurrently", concurrently)];
                 for expression in &expressions.inner {
+                    children.push(("expressions", expression));
+                }
+
+                children
+            }
+        }
+    }
+
+    fn lists(&self) -> Vec<ListView<'_>> {
+        match &self {
+            Self::Concurrently { expressions, .. } => vec![expressions.view()],
+            _ => vec![],
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
+            Self::Await {
+                r#await,
+                expression,
+                ..
+            } => vec![r#await, expression.as_mut()],
+            Self::Async {
+                r#async,
+                expression,
+                ..
+            } => vec![r#async, expression.as_mut()],
+            Self::Concurrently {
+                concurrently,
+                expressions,
+                ..
+            } => {
+                let mut children: Vec<&mut dyn Node> = vec![concurrently];
+                for expression in &mut expressions.inner {
                     children.push(expression);
                 }
 
@@ -1817,6 +2917,29 @@ impl Node for AsyncOperationExpression {
         }
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        match self {
+            Self::Async { comments, .. } => comments.positions_mut().collect(),
+            Self::Await { comments, .. } => comments.positions_mut().collect(),
+            Self::Concurrently {
+                comments,
+                left_brace,
+                expressions,
+                right_brace,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![left_brace, right_brace];
+                positions.extend(comments.positions_mut());
+                positions.extend(expressions.commas.iter_mut());
+                positions
+            }
+        }
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::AsyncOperationExpression
+    }
+
     fn get_description(&self) -> String {
         match &self {
             Self::Await { .. } => "async await operation expression".to_string(),
@@ -1838,43 +2961,122 @@ impl Node for RangeOperationExpression {
         }
     }
 
-    fn initial_position(&self) -> usize {
-        match &self {
+    fn span(&self) -> Span {
+        let initial_position = match &self {
             Self::Between { from, .. } => from.initial_position(),
             Self::BetweenInclusive { from, .. } => from.initial_position(),
             Self::To { double_dot, .. } => *double_dot,
             Self::ToInclusive { double_dot, .. } => *double_dot,
             Self::From { from, .. } => from.initial_position(),
             Self::Full { double_dot, .. } => *double_dot,
-        }
-    }
-
-    fn final_position(&self) -> usize {
-        match &self {
+        };
+        let final_position = match &self {
             Self::Between { to, .. } => to.final_position(),
             Self::BetweenInclusive { to, .. } => to.final_position(),
             Self::To { to, .. } => to.final_position(),
             Self::ToInclusive { to, .. } => to.final_position(),
             Self::From { double_dot, .. } => *double_dot,
             Self::Full { double_dot, .. } => *double_dot,
-        }
+        };
+
+        Span::new(initial_position, final_position)
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
         match &self {
             Self::Between { from, to, .. } => {
-                vec![from.as_ref(), to.as_ref()]
+                vec![("from", from.as_ref()), ("to", to.as_ref())]
+            }
+            Self::BetweenInclusive { from, to, .. } => {
+                vec![("from", from.as_ref()), ("to", to.as_ref())]
+            }
+            Self::To { to, .. } => vec![("to", to.as_ref())],
+            Self::ToInclusive { to, .. } => vec![("to", to.as_ref())],
+            Self::From { from, .. } => vec![("from", from.as_ref())],
+            Self::Full { .. } => vec![],
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
+            Self::Between { from, to, .. } => {
+                vec![from.as_mut(), to.as_mut()]
             }
             Self::BetweenInclusive { from, to, .. } => {
-                vec![from.as_ref(), to.as_ref()]
+                vec![from.as_mut(), to.as_mut()]
             }
-            Self::To { to, .. } => vec![to.as_ref()],
-            Self::ToInclusive { to, .. } => vec![to.as_ref()],
-            Self::From { from, .. } => vec![from.as_ref()],
+            Self::To { to, .. } => vec![to.as_mut()],
+            Self::ToInclusive { to, .. } => vec![to.as_mut()],
+            Self::From { from, .. } => vec![from.as_mut()],
             Self::Full { .. } => vec![],
         }
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        match self {
+            Self::Between {
+                comments,
+                double_dot,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![double_dot];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::BetweenInclusive {
+                comments,
+                double_dot,
+                equals,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![double_dot, equals];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::To {
+                comments,
+                double_dot,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![double_dot];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::ToInclusive {
+                comments,
+                double_dot,
+                equals,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![double_dot, equals];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::From {
+                comments,
+                double_dot,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![double_dot];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::Full {
+                comments,
+                double_dot,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![double_dot];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+        }
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::RangeOperationExpression
+    }
+
     fn get_description(&self) -> String {
         match &self {
             Self::Between { .. } => "range between operation expression".to_string(),
@@ -2412,11 +3614,12 @@ mod tests {
     use crate::tree::expression::argument::ArgumentExpression;
     use crate::tree::expression::literal::Literal::Integer;
     use crate::tree::expression::literal::LiteralInteger;
+    use smallvec::smallvec;
 
     #[test]
     fn test_functional_operation_expression_display() {
         let pipe = FunctionalOperationExpression::Pipe {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             left: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -2432,7 +3635,7 @@ mod tests {
         assert_eq!(pipe.to_string(), "$foo |> $bar");
 
         let expression = FunctionalOperationExpression::Expression {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             dollar: 0,
             generics: None,
             left_parenthesis: 0,
@@ -2449,7 +3652,7 @@ mod tests {
     #[test]
     fn test_assignment_operation_expression_display() {
         let assignment = AssignmentOperationExpression::Assignment {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             left: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -2464,7 +3667,7 @@ mod tests {
         assert_eq!(assignment.to_string(), "$foo = $bar");
 
         let addition = AssignmentOperationExpression::Addition {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             left: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -2479,7 +3682,7 @@ mod tests {
         assert_eq!(addition.to_string(), "$foo += $bar");
 
         let subtraction = AssignmentOperationExpression::Subtraction {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             left: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -2494,7 +3697,7 @@ mod tests {
         assert_eq!(subtraction.to_string(), "$foo -= $bar");
 
         let multiplication = AssignmentOperationExpression::Multiplication {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             left: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -2509,7 +3712,7 @@ mod tests {
         assert_eq!(multiplication.to_string(), "$foo *= $bar");
 
         let division = AssignmentOperationExpression::Division {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             left: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -2524,7 +3727,7 @@ mod tests {
         assert_eq!(division.to_string(), "$foo /= $bar");
 
         let modulo = AssignmentOperationExpression::Modulo {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             left: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -2539,7 +3742,7 @@ mod tests {
         assert_eq!(modulo.to_string(), "$foo %= $bar");
 
         let bitwise_and = AssignmentOperationExpression::BitwiseAnd {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             left: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -2554,7 +3757,7 @@ mod tests {
         assert_eq!(bitwise_and.to_string(), "$foo &= $bar");
 
         let bitwise_or = AssignmentOperationExpression::BitwiseOr {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             left: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -2569,7 +3772,7 @@ mod tests {
         assert_eq!(bitwise_or.to_string(), "$foo |= $bar");
 
         let bitwise_xor = AssignmentOperationExpression::BitwiseXor {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             left: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -2584,7 +3787,7 @@ mod tests {
         assert_eq!(bitwise_xor.to_string(), "$foo ^= $bar");
 
         let left_shift = AssignmentOperationExpression::LeftShift {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             left: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -2599,7 +3802,7 @@ mod tests {
         assert_eq!(left_shift.to_string(), "$foo <<= $bar");
 
         let right_shift = AssignmentOperationExpression::RightShift {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             left: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -2614,7 +3817,7 @@ mod tests {
         assert_eq!(right_shift.to_string(), "$foo >>= $bar");
 
         let exponentiation = AssignmentOperationExpression::Exponentiation {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             left: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -2629,7 +3832,7 @@ mod tests {
         assert_eq!(exponentiation.to_string(), "$foo **= $bar");
 
         let concat = AssignmentOperationExpression::Concat {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             left: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -2644,7 +3847,7 @@ mod tests {
         assert_eq!(concat.to_string(), "$foo .= $bar");
 
         let coalesce = AssignmentOperationExpression::Coalesce {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             left: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -2662,7 +3865,7 @@ mod tests {
     #[test]
     fn test_arithmetic_operation_expression_display() {
         let addition = ArithmeticOperationExpression::Addition {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             left: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -2677,7 +3880,7 @@ mod tests {
         assert_eq!(addition.to_string(), "$foo + $bar");
 
         let subtraction = ArithmeticOperationExpression::Subtraction {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             left: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -2692,7 +3895,7 @@ mod tests {
         assert_eq!(subtraction.to_string(), "$foo - $bar");
 
         let multiplication = ArithmeticOperationExpression::Multiplication {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             left: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -2707,7 +3910,7 @@ mod tests {
         assert_eq!(multiplication.to_string(), "$foo * $bar");
 
         let division = ArithmeticOperationExpression::Division {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             left: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -2722,7 +3925,7 @@ mod tests {
         assert_eq!(division.to_string(), "$foo / $bar");
 
         let modulo = ArithmeticOperationExpression::Modulo {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             left: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -2737,7 +3940,7 @@ mod tests {
         assert_eq!(modulo.to_string(), "$foo % $bar");
 
         let exponentiation = ArithmeticOperationExpression::Exponentiation {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             left: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -2752,7 +3955,7 @@ mod tests {
         assert_eq!(exponentiation.to_string(), "$foo ** $bar");
 
         let negative = ArithmeticOperationExpression::Negative {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             minus: 0,
             right: Box::new(Expression::Variable(Variable {
                 position: 0,
@@ -2763,7 +3966,7 @@ mod tests {
         assert_eq!(negative.to_string(), "-$bar");
 
         let positive = ArithmeticOperationExpression::Positive {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             plus: 0,
             right: Box::new(Expression::Variable(Variable {
                 position: 0,
@@ -2774,7 +3977,7 @@ mod tests {
         assert_eq!(positive.to_string(), "+$bar");
 
         let pre_increment = ArithmeticOperationExpression::PreIncrement {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             increment: 0,
             right: Box::new(Expression::Variable(Variable {
                 position: 0,
@@ -2785,7 +3988,7 @@ mod tests {
         assert_eq!(pre_increment.to_string(), "++$bar");
 
         let pre_decrement = ArithmeticOperationExpression::PreDecrement {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             decrement: 0,
             right: Box::new(Expression::Variable(Variable {
                 position: 0,
@@ -2819,7 +4022,7 @@ mod tests {
     #[test]
     fn test_bitwise_operation_expression_display() {
         let bitwise_and = BitwiseOperationExpression::And {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             left: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -2834,7 +4037,7 @@ mod tests {
         assert_eq!(bitwise_and.to_string(), "$foo & $bar");
 
         let bitwise_or = BitwiseOperationExpression::Or {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             left: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -2849,7 +4052,7 @@ mod tests {
         assert_eq!(bitwise_or.to_string(), "$foo | $bar");
 
         let bitwise_xor = BitwiseOperationExpression::Xor {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             left: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -2864,7 +4067,7 @@ mod tests {
         assert_eq!(bitwise_xor.to_string(), "$foo ^ $bar");
 
         let bitwise_not = BitwiseOperationExpression::Not {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             not: 0,
             right: Box::new(Expression::Variable(Variable {
                 position: 0,
@@ -2875,7 +4078,7 @@ mod tests {
         assert_eq!(bitwise_not.to_string(), "~$bar");
 
         let bitwise_left_shift = BitwiseOperationExpression::LeftShift {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             left: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -2890,7 +4093,7 @@ mod tests {
         assert_eq!(bitwise_left_shift.to_string(), "$foo << $bar");
 
         let bitwise_right_shift = BitwiseOperationExpression::RightShift {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             left: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -2908,7 +4111,7 @@ mod tests {
     #[test]
     fn test_comparison_operation_expression_display() {
         let equal = ComparisonOperationExpression::Equal {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             left: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -2923,7 +4126,7 @@ mod tests {
         assert_eq!(equal.to_string(), "$foo == $bar");
 
         let identical = ComparisonOperationExpression::Identical {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             left: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -2938,7 +4141,7 @@ mod tests {
         assert_eq!(identical.to_string(), "$foo === $bar");
 
         let not_equal = ComparisonOperationExpression::NotEqual {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             left: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -2953,7 +4156,7 @@ mod tests {
         assert_eq!(not_equal.to_string(), "$foo != $bar");
 
         let not_identical = ComparisonOperationExpression::NotIdentical {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             left: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -2968,7 +4171,7 @@ mod tests {
         assert_eq!(not_identical.to_string(), "$foo !== $bar");
 
         let less_than = ComparisonOperationExpression::LessThan {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             left: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -2983,7 +4186,7 @@ mod tests {
         assert_eq!(less_than.to_string(), "$foo < $bar");
 
         let greater_than = ComparisonOperationExpression::GreaterThan {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             left: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -2998,7 +4201,7 @@ mod tests {
         assert_eq!(greater_than.to_string(), "$foo > $bar");
 
         let less_than_or_equal = ComparisonOperationExpression::LessThanOrEqual {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             left: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -3013,7 +4216,7 @@ mod tests {
         assert_eq!(less_than_or_equal.to_string(), "$foo <= $bar");
 
         let greater_than_or_equal = ComparisonOperationExpression::GreaterThanOrEqual {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             left: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -3028,7 +4231,7 @@ mod tests {
         assert_eq!(greater_than_or_equal.to_string(), "$foo >= $bar");
 
         let spaceship = ComparisonOperationExpression::Spaceship {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             left: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -3046,7 +4249,7 @@ mod tests {
     #[test]
     fn test_logical_operation_expression_display() {
         let logical_and = LogicalOperationExpression::And {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             left: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -3061,7 +4264,7 @@ mod tests {
         assert_eq!(logical_and.to_string(), "$foo && $bar");
 
         let logical_or = LogicalOperationExpression::Or {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             left: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -3076,7 +4279,7 @@ mod tests {
         assert_eq!(logical_or.to_string(), "$foo || $bar");
 
         let logical_not = LogicalOperationExpression::Not {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             bang: 0,
             right: Box::new(Expression::Variable(Variable {
                 position: 0,
@@ -3090,7 +4293,7 @@ mod tests {
     #[test]
     fn test_string_operation_expression_display() {
         let concat = StringOperationExpression::Concat {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             left: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -3108,7 +4311,7 @@ mod tests {
     #[test]
     fn test_array_operation_expression_display() {
         let access = ArrayOperationExpression::Access {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             array: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -3124,7 +4327,7 @@ mod tests {
         assert_eq!(access.to_string(), "$foo[$bar]");
 
         let push = ArrayOperationExpression::Push {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             array: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -3136,11 +4339,11 @@ mod tests {
         assert_eq!(push.to_string(), "$foo[]");
 
         let unset = ArrayOperationExpression::Unset {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             unset: Keyword::new(ByteString::from("unset"), 0),
             item: Box::new(Expression::ArrayOperation(
                 ArrayOperationExpression::Access {
-                    comments: CommentGroup { comments: vec![] },
+                    comments: CommentGroup::default(),
                     array: Box::new(Expression::Variable(Variable {
                         position: 0,
                         name: ByteString::from("foo"),
@@ -3158,11 +4361,11 @@ mod tests {
         assert_eq!(unset.to_string(), "unset $foo[$bar]");
 
         let isset = ArrayOperationExpression::Isset {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             isset: Keyword::new(ByteString::from("isset"), 0),
             item: Box::new(Expression::ArrayOperation(
                 ArrayOperationExpression::Access {
-                    comments: CommentGroup { comments: vec![] },
+                    comments: CommentGroup::default(),
                     array: Box::new(Expression::Variable(Variable {
                         position: 0,
                         name: ByteString::from("foo"),
@@ -3180,9 +4383,9 @@ mod tests {
         assert_eq!(isset.to_string(), "isset $foo[$bar]");
 
         let r#in = ArrayOperationExpression::In {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             item: Box::new(Expression::Literal(Integer(LiteralInteger {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 position: 0,
                 value: ByteString::from("1"),
             }))),
@@ -3199,7 +4402,7 @@ mod tests {
     #[test]
     fn test_coalesce_operation_expression_display() {
         let coalesce = CoalesceOperationExpression::Coalesce {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             left: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -3217,7 +4420,7 @@ mod tests {
     #[test]
     fn test_ternary_operation_expression_display() {
         let ternary = TernaryOperationExpression::Ternary {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             condition: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -3237,7 +4440,7 @@ mod tests {
         assert_eq!(ternary.to_string(), "$foo ? $bar : $baz");
 
         let short_ternary = TernaryOperationExpression::ShortTernary {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             condition: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -3252,7 +4455,7 @@ mod tests {
         assert_eq!(short_ternary.to_string(), "$foo ?: $bar");
 
         let implicit_short_ternary = TernaryOperationExpression::ImplicitShortTernary {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             condition: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -3271,7 +4474,7 @@ mod tests {
     #[test]
     fn test_type_operation_expression_display() {
         let instance_of = TypeOperationExpression::Instanceof {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             left: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -3286,7 +4489,7 @@ mod tests {
         assert_eq!(instance_of.to_string(), "$foo instanceof Foo");
 
         let is = TypeOperationExpression::Is {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             left: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -3301,7 +4504,7 @@ mod tests {
         assert_eq!(is.to_string(), "$foo is i64");
 
         let into = TypeOperationExpression::Into {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             left: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -3316,7 +4519,7 @@ mod tests {
         assert_eq!(into.to_string(), "$foo into i64");
 
         let r#as = TypeOperationExpression::As {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             left: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -3334,14 +4537,14 @@ mod tests {
     #[test]
     fn test_generator_operation_expression_display() {
         let r#yield = GeneratorOperationExpression::Yield {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             r#yield: Keyword::new(ByteString::from("yield"), 0),
         };
 
         assert_eq!(r#yield.to_string(), "yield");
 
         let yield_value = GeneratorOperationExpression::YieldValue {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             r#yield: Keyword::new(ByteString::from("yield"), 0),
             value: Box::new(Expression::Variable(Variable {
                 position: 0,
@@ -3352,7 +4555,7 @@ mod tests {
         assert_eq!(yield_value.to_string(), "yield $foo");
 
         let yield_key_value = GeneratorOperationExpression::YieldKeyValue {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             r#yield: Keyword::new(ByteString::from("yield"), 0),
             key: Box::new(Expression::Variable(Variable {
                 position: 0,
@@ -3368,7 +4571,7 @@ mod tests {
         assert_eq!(yield_key_value.to_string(), "yield $foo => $bar");
 
         let yield_from = GeneratorOperationExpression::YieldFrom {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             r#yield: Keyword::new(ByteString::from("yield"), 0),
             from: Keyword::new(ByteString::from("from"), 0),
             value: Box::new(Expression::Variable(Variable {
@@ -3383,7 +4586,7 @@ mod tests {
     #[test]
     fn test_exception_operation_expression_display() {
         let throw = ExceptionOperationExpression::Throw {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             r#throw: Keyword::new(ByteString::from("throw"), 0),
             value: Box::new(Expression::Variable(Variable {
                 position: 0,
@@ -3397,7 +4600,7 @@ mod tests {
     #[test]
     fn test_object_operation_expression_display() {
         let clone = ObjectOperationExpression::Clone {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             clone: Keyword::new(ByteString::from("clone"), 0),
             object: Box::new(Expression::Variable(Variable {
                 position: 0,
@@ -3408,7 +4611,7 @@ mod tests {
         assert_eq!(clone.to_string(), "clone $foo");
 
         let method_call = ObjectOperationExpression::MethodCall {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             object: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -3420,18 +4623,18 @@ mod tests {
             },
             generics: None,
             arguments: ArgumentListExpression {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 left_parenthesis: 0,
                 arguments: CommaSeparated {
                     inner: vec![ArgumentExpression::Value {
-                        comments: CommentGroup { comments: vec![] },
+                        comments: CommentGroup::default(),
                         value: Expression::Literal(Integer(LiteralInteger {
-                            comments: CommentGroup { comments: vec![] },
+                            comments: CommentGroup::default(),
                             position: 0,
                             value: ByteString::from("1"),
                         })),
                     }],
-                    commas: vec![],
+                    commas: smallvec![],
                 },
                 right_parenthesis: 0,
             },
@@ -3440,7 +4643,7 @@ mod tests {
         assert_eq!(method_call.to_string(), "$foo->bar(1)");
 
         let nullsafe_method_call = ObjectOperationExpression::NullsafeMethodCall {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             object: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -3452,18 +4655,18 @@ mod tests {
             },
             generics: None,
             arguments: ArgumentListExpression {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 left_parenthesis: 0,
                 arguments: CommaSeparated {
                     inner: vec![ArgumentExpression::Value {
-                        comments: CommentGroup { comments: vec![] },
+                        comments: CommentGroup::default(),
                         value: Expression::Literal(Integer(LiteralInteger {
-                            comments: CommentGroup { comments: vec![] },
+                            comments: CommentGroup::default(),
                             position: 0,
                             value: ByteString::from("1"),
                         })),
                     }],
-                    commas: vec![],
+                    commas: smallvec![],
                 },
                 right_parenthesis: 0,
             },
@@ -3472,7 +4675,7 @@ mod tests {
         assert_eq!(nullsafe_method_call.to_string(), "$foo?->bar(1)");
 
         let method_closure_creation = ObjectOperationExpression::MethodClosureCreation {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             object: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -3484,7 +4687,7 @@ mod tests {
             },
             generics: None,
             placeholder: ArgumentPlaceholderExpression {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 left_parenthesis: 0,
                 ellipsis: 0,
                 right_parenthesis: 0,
@@ -3494,7 +4697,7 @@ mod tests {
         assert_eq!(method_closure_creation.to_string(), "$foo->bar(...)");
 
         let property_fetch = ObjectOperationExpression::PropertyFetch {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             object: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -3509,7 +4712,7 @@ mod tests {
         assert_eq!(property_fetch.to_string(), "$foo->bar");
 
         let nullsafe_property_fetch = ObjectOperationExpression::NullsafePropertyFetch {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             object: Box::new(Expression::Variable(Variable {
                 position: 0,
                 name: ByteString::from("foo"),
@@ -3544,7 +4747,7 @@ mod tests {
     #[test]
     fn test_class_operation_expression() {
         let initialization = ClassOperationExpression::Initialization {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             new: Keyword::new(ByteString::from("new"), 0),
             class: ClassOperationInitializationClassExpression::Identifier(Identifier {
                 position: 0,
@@ -3552,18 +4755,18 @@ mod tests {
             }),
             generics: None,
             arguments: ArgumentListExpression {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 left_parenthesis: 0,
                 arguments: CommaSeparated {
                     inner: vec![ArgumentExpression::Value {
-                        comments: CommentGroup { comments: vec![] },
+                        comments: CommentGroup::default(),
                         value: Expression::Literal(Integer(LiteralInteger {
-                            comments: CommentGroup { comments: vec![] },
+                            comments: CommentGroup::default(),
                             position: 0,
                             value: ByteString::from("1"),
                         })),
                     }],
-                    commas: vec![],
+                    commas: smallvec![],
                 },
                 right_parenthesis: 0,
             },
@@ -3572,18 +4775,18 @@ mod tests {
         assert_eq!(initialization.to_string(), "new Foo(1)");
 
         let anonymous_initialization = ClassOperationExpression::AnonymousInitialization {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             new: Keyword::new(ByteString::from("new"), 0),
             class: AnonymousClassExpression {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 attributes: vec![],
                 class: Keyword::new(ByteString::from("class"), 0),
                 arguments: ArgumentListExpression {
-                    comments: CommentGroup { comments: vec![] },
+                    comments: CommentGroup::default(),
                     left_parenthesis: 0,
                     arguments: CommaSeparated {
                         inner: vec![],
-                        commas: vec![],
+                        commas: smallvec![],
                     },
                     right_parenthesis: 0,
                 },
@@ -3604,25 +4807,25 @@ mod tests {
 
         let anonymous_initialization_with_argument =
             ClassOperationExpression::AnonymousInitialization {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 new: Keyword::new(ByteString::from("new"), 0),
                 class: AnonymousClassExpression {
-                    comments: CommentGroup { comments: vec![] },
+                    comments: CommentGroup::default(),
                     attributes: vec![],
                     class: Keyword::new(ByteString::from("class"), 0),
                     arguments: ArgumentListExpression {
-                        comments: CommentGroup { comments: vec![] },
+                        comments: CommentGroup::default(),
                         left_parenthesis: 0,
                         arguments: CommaSeparated {
                             inner: vec![ArgumentExpression::Value {
-                                comments: CommentGroup { comments: vec![] },
+                                comments: CommentGroup::default(),
                                 value: Expression::Literal(Integer(LiteralInteger {
-                                    comments: CommentGroup { comments: vec![] },
+                                    comments: CommentGroup::default(),
                                     position: 0,
                                     value: ByteString::from("1"),
                                 })),
                             }],
-                            commas: vec![],
+                            commas: smallvec![],
                         },
                         right_parenthesis: 0,
                     },
@@ -3642,7 +4845,7 @@ mod tests {
         );
 
         let static_method_call = ClassOperationExpression::StaticMethodCall {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             class: Box::new(Expression::Identifier(Identifier {
                 position: 0,
                 value: ByteString::from("Foo"),
@@ -3654,18 +4857,18 @@ mod tests {
             },
             generics: None,
             arguments: ArgumentListExpression {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 left_parenthesis: 0,
                 arguments: CommaSeparated {
                     inner: vec![ArgumentExpression::Value {
-                        comments: CommentGroup { comments: vec![] },
+                        comments: CommentGroup::default(),
                         value: Expression::Literal(Integer(LiteralInteger {
-                            comments: CommentGroup { comments: vec![] },
+                            comments: CommentGroup::default(),
                             position: 0,
                             value: ByteString::from("1"),
                         })),
                     }],
-                    commas: vec![],
+                    commas: smallvec![],
                 },
                 right_parenthesis: 0,
             },
@@ -3674,7 +4877,7 @@ mod tests {
         assert_eq!(static_method_call.to_string(), "Foo::bar(1)");
 
         let static_method_closure = ClassOperationExpression::StaticMethodClosureCreation {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             class: Box::new(Expression::Identifier(Identifier {
                 position: 0,
                 value: ByteString::from("Foo"),
@@ -3686,7 +4889,7 @@ mod tests {
             },
             generics: None,
             placeholder: ArgumentPlaceholderExpression {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 left_parenthesis: 0,
                 ellipsis: 0,
                 right_parenthesis: 0,
@@ -3696,7 +4899,7 @@ mod tests {
         assert_eq!(static_method_closure.to_string(), "Foo::bar(...)");
 
         let static_property_fetch = ClassOperationExpression::StaticPropertyFetch {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             class: Box::new(Expression::Identifier(Identifier {
                 position: 0,
                 value: ByteString::from("Foo"),
@@ -3711,7 +4914,7 @@ mod tests {
         assert_eq!(static_property_fetch.to_string(), "Foo::$bar");
 
         let constant_fetch = ClassOperationExpression::ConstantFetch {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             class: Box::new(Expression::Identifier(Identifier {
                 position: 0,
                 value: ByteString::from("Foo"),
@@ -3729,25 +4932,25 @@ mod tests {
     #[test]
     fn test_function_operation_expression() {
         let function_call = FunctionOperationExpression::Call {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             function: Box::new(Expression::Identifier(Identifier {
                 position: 0,
                 value: ByteString::from("foo"),
             })),
             generics: None,
             arguments: ArgumentListExpression {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 left_parenthesis: 0,
                 arguments: CommaSeparated {
                     inner: vec![ArgumentExpression::Value {
-                        comments: CommentGroup { comments: vec![] },
+                        comments: CommentGroup::default(),
                         value: Expression::Literal(Integer(LiteralInteger {
-                            comments: CommentGroup { comments: vec![] },
+                            comments: CommentGroup::default(),
                             position: 0,
                             value: ByteString::from("1"),
                         })),
                     }],
-                    commas: vec![],
+                    commas: smallvec![],
                 },
                 right_parenthesis: 0,
             },
@@ -3756,14 +4959,14 @@ mod tests {
         assert_eq!(function_call.to_string(), "foo(1)");
 
         let function_closure = FunctionOperationExpression::ClosureCreation {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             function: Box::new(Expression::Identifier(Identifier {
                 position: 0,
                 value: ByteString::from("foo"),
             })),
             generics: None,
             placeholder: ArgumentPlaceholderExpression {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 left_parenthesis: 0,
                 ellipsis: 0,
                 right_parenthesis: 0,
@@ -3776,40 +4979,40 @@ mod tests {
     #[test]
     fn test_async_operation_expression() {
         let r#async = AsyncOperationExpression::Async {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             r#async: Keyword::new(ByteString::from("async"), 0),
-            expression: Box::new(Expression::FunctionOperation(
+            expression: Box::new(Expression::FunctionOperation(Box::new(
                 FunctionOperationExpression::Call {
-                    comments: CommentGroup { comments: vec![] },
+                    comments: CommentGroup::default(),
                     function: Box::new(Expression::Identifier(Identifier {
                         position: 0,
                         value: ByteString::from("foo"),
                     })),
                     generics: None,
                     arguments: ArgumentListExpression {
-                        comments: CommentGroup { comments: vec![] },
+                        comments: CommentGroup::default(),
                         left_parenthesis: 0,
                         arguments: CommaSeparated {
                             inner: vec![ArgumentExpression::Value {
-                                comments: CommentGroup { comments: vec![] },
+                                comments: CommentGroup::default(),
                                 value: Expression::Literal(Integer(LiteralInteger {
-                                    comments: CommentGroup { comments: vec![] },
+                                    comments: CommentGroup::default(),
                                     position: 0,
                                     value: ByteString::from("1"),
                                 })),
                             }],
-                            commas: vec![],
+                            commas: smallvec![],
                         },
                         right_parenthesis: 0,
                     },
                 },
-            )),
+            ))),
         };
 
         assert_eq!(r#async.to_string(), "async foo(1)");
 
         let r#await = AsyncOperationExpression::Await {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             r#await: Keyword::new(ByteString::from("await"), 0),
             expression: Box::new(Expression::Variable(Variable {
                 position: 0,
@@ -3820,12 +5023,12 @@ mod tests {
         assert_eq!(r#await.to_string(), "await $foo");
 
         let concurrently = AsyncOperationExpression::Concurrently {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             concurrently: Keyword::new(ByteString::from("concurrently"), 0),
             left_brace: 0,
             expressions: CommaSeparated {
                 inner: vec![],
-                commas: vec![],
+                commas: smallvec![],
             },
             right_brace: 0,
         };
@@ -3836,15 +5039,15 @@ mod tests {
     #[test]
     fn test_range_operation_expression() {
         let between = RangeOperationExpression::Between {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             from: Box::new(Expression::Literal(Integer(LiteralInteger {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 position: 0,
                 value: ByteString::from("1"),
             }))),
             double_dot: 0,
             to: Box::new(Expression::Literal(Integer(LiteralInteger {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 position: 0,
                 value: ByteString::from("10"),
             }))),
@@ -3853,15 +5056,15 @@ mod tests {
         assert_eq!(between.to_string(), "1..10");
 
         let between_inclusive = RangeOperationExpression::BetweenInclusive {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             from: Box::new(Expression::Literal(Integer(LiteralInteger {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 position: 0,
                 value: ByteString::from("1"),
             }))),
             double_dot: 0,
             to: Box::new(Expression::Literal(Integer(LiteralInteger {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 position: 0,
                 value: ByteString::from("10"),
             }))),
@@ -3871,10 +5074,10 @@ mod tests {
         assert_eq!(between_inclusive.to_string(), "1..=10");
 
         let to = RangeOperationExpression::To {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             double_dot: 0,
             to: Box::new(Expression::Literal(Integer(LiteralInteger {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 position: 0,
                 value: ByteString::from("10"),
             }))),
@@ -3883,10 +5086,10 @@ mod tests {
         assert_eq!(to.to_string(), "..10");
 
         let to_inclusive = RangeOperationExpression::ToInclusive {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             double_dot: 0,
             to: Box::new(Expression::Literal(Integer(LiteralInteger {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 position: 0,
                 value: ByteString::from("10"),
             }))),
@@ -3896,9 +5099,9 @@ mod tests {
         assert_eq!(to_inclusive.to_string(), "..=10");
 
         let from = RangeOperationExpression::From {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             from: Box::new(Expression::Literal(Integer(LiteralInteger {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 position: 0,
                 value: ByteString::from("10"),
             }))),
@@ -3908,7 +5111,7 @@ mod tests {
         assert_eq!(from.to_string(), "10..");
 
         let full = RangeOperationExpression::Full {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             double_dot: 0,
         };
 
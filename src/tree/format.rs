@@ -0,0 +1,146 @@
+use std::fmt::Display;
+
+use bincode::de::Decoder;
+use bincode::enc::Encoder;
+use bincode::error::DecodeError;
+use bincode::error::EncodeError;
+use bincode::Decode;
+use bincode::Encode;
+
+use crate::tree::Tree;
+
+/// The bytes every encoded tree starts with.
+pub const MAGIC: [u8; 4] = *b"ARAT";
+
+/// The version of the binary format of trees, written after the magic bytes.
+///
+/// This must be incremented whenever the layout of the tree, or of any node, changes.
+pub const VERSION: u32 = 10;
+
+#[derive(Debug)]
+pub enum FormatError {
+    /// The bytes do not start with the magic bytes, so they do not hold a tree.
+    InvalidMagic,
+    /// The tree was encoded using a different version of the format.
+    UnsupportedVersion {
+        found: u32,
+    },
+    Encode(EncodeError),
+    Decode(DecodeError),
+}
+
+impl Display for FormatError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidMagic => write!(f, "the given bytes do not hold an encoded tree"),
+            Self::UnsupportedVersion { found } => write!(
+                f,
+                "the tree was encoded using version {found} of the format, expected version {VERSION}"
+            ),
+            Self::Encode(error) => write!(f, "failed to encode the tree: {error}"),
+            Self::Decode(error) => write!(f, "failed to decode the tree: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for FormatError {}
+
+/// Encode the given tree, using the standard bincode configuration.
+pub fn encode(tree: &Tree) -> Result<Vec<u8>, FormatError> {
+    bincode::encode_to_vec(tree, bincode::config::standard()).map_err(FormatError::Encode)
+}
+
+/// Decode a tree encoded by `encode`.
+pub fn decode(bytes: &[u8]) -> Result<Tree, FormatError> {
+    let config = bincode::config::standard();
+
+    let ((magic, version), _) = bincode::decode_from_slice::<([u8; 4], u32), _>(bytes, config)
+        .map_err(|_| FormatError::InvalidMagic)?;
+
+    if magic != MAGIC {
+        return Err(FormatError::InvalidMagic);
+    }
+
+    if version != VERSION {
+        return Err(FormatError::UnsupportedVersion { found: version });
+    }
+
+    bincode::decode_from_slice(bytes, config)
+        .map(|(tree, _)| tree)
+        .map_err(FormatError::Decode)
+}
+
+impl Encode for Tree {
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        MAGIC.encode(encoder)?;
+        VERSION.encode(encoder)?;
+
+        self.source.encode(encoder)?;
+        self.definitions.encode(encoder)?;
+        self.source_hash.encode(encoder)?;
+        self.comments.encode(encoder)?;
+        self.trivia.encode(encoder)?;
+        self.ids.encode(encoder)
+    }
+}
+
+impl<Context> Decode<Context> for Tree {
+    fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        if <[u8; 4]>::decode(decoder)? != MAGIC {
+            return Err(DecodeError::Other(
+                "the given bytes do not hold an encoded tree",
+            ));
+        }
+
+        let version = u32::decode(decoder)?;
+        if version != VERSION {
+            return Err(DecodeError::OtherString(format!(
+                "the tree was encoded using version {version} of the format, expected version {VERSION}"
+            )));
+        }
+
+        Ok(Tree {
+            source: Decode::decode(decoder)?,
+            definitions: Decode::decode(decoder)?,
+            source_hash: Decode::decode(decoder)?,
+            comments: Decode::decode(decoder)?,
+            trivia: Decode::decode(decoder)?,
+            ids: Decode::decode(decoder)?,
+        })
+    }
+}
+
+bincode::impl_borrow_decode!(Tree);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ara_source::source::Source;
+    use ara_source::source::SourceKind;
+
+    use crate::parser;
+
+    #[test]
+    fn test_format() {
+        let source = Source::inline(SourceKind::Script, "function foo(): void {}");
+        let tree = parser::parse(&source).unwrap();
+
+        let mut bytes = encode(&tree).unwrap();
+        assert_eq!(&bytes[..4], &MAGIC);
+
+        let decoded = decode(&bytes).unwrap();
+        assert_eq!(decoded.definitions, tree.definitions);
+
+        bytes[4] = VERSION as u8 + 1;
+        assert!(matches!(
+            decode(&bytes),
+            Err(FormatError::UnsupportedVersion { found }) if found == VERSION + 1
+        ));
+        assert!(
+            bincode::decode_from_slice::<Tree, _>(&bytes, bincode::config::standard()).is_err()
+        );
+
+        assert!(matches!(decode(b"tree"), Err(FormatError::InvalidMagic)));
+        assert!(matches!(decode(b""), Err(FormatError::InvalidMagic)));
+    }
+}
@@ -0,0 +1,395 @@
+use bincode::de::Decoder;
+use bincode::enc::Encoder;
+use bincode::error::DecodeError;
+use bincode::error::EncodeError;
+use bincode::Decode;
+use bincode::Encode;
+use schemars::gen::SchemaGenerator;
+use schemars::schema::Schema;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use smallvec::SmallVec;
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::hash::BuildHasherDefault;
+use std::hash::Hash;
+use std::hash::Hasher;
+
+use crate::tree::definition::DefinitionTree;
+use crate::tree::kind::NodeKind;
+use crate::tree::span::Span;
+use crate::tree::Node;
+use crate::tree::NodeId;
+
+/// A node of a tree, as recorded by its `NodeIdTable`.
+#[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct NodeIdEntry {
+    pub id: NodeId,
+    /// The index of the entry of the parent of the node, `None` for the definition tree.
+    pub parent: Option<usize>,
+    /// The index of the node among the children of its parent.
+    pub child: usize,
+    pub kind: NodeKind,
+    pub span: Span,
+}
+
+/// The entries of a `NodeIdTable`, as serialized.
+#[derive(
+    Debug, PartialEq, Eq, Clone, Hash, Default, Deserialize, Serialize, Encode, Decode, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub struct NodeIdEntries {
+    /// The entry of every node of the tree, in pre-order.
+    pub entries: Vec<NodeIdEntry>,
+    /// The identifier of the next node added to the tree.
+    pub next: usize,
+}
+
+/// The identifiers of the nodes of a tree, built once the source is parsed, see `Tree::new`.
+///
+/// Identifiers are assigned in pre-order by walking the parsed definition tree, starting with the
+/// definition tree itself at `0`, so two nodes sharing the same position ( e.g. zero-width nodes )
+/// still get distinct identifiers.
+///
+/// A node keeps its identifier as long as its kind, and its span, are unchanged, including when
+/// the definition holding it is reused by `reparse`, or moved along with it, nodes added to the
+/// tree are assigned new identifiers, so an identifier is never reused within a tree.
+///
+/// Both the node of an identifier, and the identifier of a node, are looked up by following the
+/// path from the definition tree to the node, without walking the whole tree.
+#[derive(Debug, Clone, Default)]
+pub struct NodeIdTable {
+    entries: NodeIdEntries,
+    /// The index of the entry of each identifier assigned so far, `None` once its node is removed.
+    ids: Vec<Option<usize>>,
+    /// The indices of the entries of the nodes starting at each position.
+    starts: HashMap<usize, SmallVec<[usize; 4]>, BuildHasherDefault<PositionHasher>>,
+}
+
+/// A hasher for positions, which are hashed once per node of a tree, and don't need to be
+/// resistant to collisions.
+#[derive(Debug, Clone, Default)]
+struct PositionHasher(u64);
+
+impl Hasher for PositionHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.write_u64(*byte as u64);
+        }
+    }
+
+    fn write_u64(&mut self, value: u64) {
+        self.0 = (self.0.rotate_left(5) ^ value).wrapping_mul(0x51_7c_c1_b7_27_22_0a_95);
+    }
+
+    fn write_usize(&mut self, value: usize) {
+        self.write_u64(value as u64);
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+impl NodeIdTable {
+    /// Assign identifiers to the given tree, and all of its descendants.
+    pub fn new(tree: &DefinitionTree) -> Self {
+        let mut table = Self::default();
+        table.refresh(tree);
+
+        table
+    }
+
+    /// Assign identifiers to the nodes of the given tree, after it was modified in place.
+    ///
+    /// Nodes of the same kind, and span, as a node of the previous tree keep its identifier,
+    /// the remaining nodes are assigned new identifiers.
+    pub fn refresh(&mut self, tree: &DefinitionTree) {
+        let previous = std::mem::take(self);
+        let mut claimed = vec![false; previous.entries.entries.len()];
+
+        self.entries.next = previous.entries.next;
+        self.assign(tree, None, 0, &previous, &mut claimed);
+        self.index();
+    }
+
+    /// The table of the given tree, parsed from the source of the tree of this table after an
+    /// edit, where the given number of parsed definitions replaced the definitions from `first`
+    /// up to `last`, and the definitions following them were moved by the given offset.
+    ///
+    /// The reused definitions, and all of their descendants, keep their identifiers, while the
+    /// parsed definitions are assigned new identifiers, without walking the reused definitions.
+    pub(crate) fn reused(
+        &self,
+        tree: &DefinitionTree,
+        first: usize,
+        last: usize,
+        parsed: usize,
+        offset: isize,
+    ) -> Self {
+        let previous = &self.entries.entries;
+        // the index of the entry of each definition, followed by the number of entries.
+        let mut blocks = previous
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| entry.parent == Some(0))
+            .map(|(index, _)| index)
+            .collect::<Vec<usize>>();
+        blocks.push(previous.len());
+
+        let mut table = Self::default();
+        table.entries.next = self.entries.next;
+        table.entries.entries.push(NodeIdEntry {
+            id: previous[0].id,
+            parent: None,
+            child: 0,
+            kind: tree.kind(),
+            span: tree.span(),
+        });
+
+        let reuse = |table: &mut Self, definition: usize, child: usize, offset: isize| {
+            let (start, end) = (blocks[definition], blocks[definition + 1]);
+            let moved = table.entries.entries.len();
+            for entry in &previous[start..end] {
+                table.entries.entries.push(NodeIdEntry {
+                    parent: entry.parent.map(|parent| {
+                        if parent == 0 {
+                            0
+                        } else {
+                            parent - start + moved
+                        }
+                    }),
+                    child: if entry.parent == Some(0) {
+                        child
+                    } else {
+                        entry.child
+                    },
+                    span: Span::new(
+                        entry.span.start.saturating_add_signed(offset),
+                        entry.span.end.saturating_add_signed(offset),
+                    ),
+                    ..entry.clone()
+                });
+            }
+        };
+
+        for definition in 0..first {
+            reuse(&mut table, definition, definition, 0);
+        }
+
+        for child in first..first + parsed {
+            table.assign(
+                &tree.definitions[child],
+                Some(0),
+                child,
+                &Self::default(),
+                &mut [],
+            );
+        }
+
+        for definition in last..blocks.len() - 1 {
+            reuse(
+                &mut table,
+                definition,
+                definition - last + first + parsed,
+                offset,
+            );
+        }
+
+        table.index();
+
+        table
+    }
+
+    /// The identifiers of all nodes, in pre-order.
+    pub fn ids(&self) -> impl Iterator<Item = NodeId> + '_ {
+        self.entries.entries.iter().map(|entry| entry.id)
+    }
+
+    /// The node with the given identifier, within the given tree.
+    pub fn node<'a>(&self, tree: &'a DefinitionTree, id: NodeId) -> Option<&'a dyn Node> {
+        let mut index = (*self.ids.get(id.0)?)?;
+        let mut path = vec![];
+        while let Some(parent) = self.entries.entries[index].parent {
+            path.push(self.entries.entries[index].child);
+            index = parent;
+        }
+
+        let mut node: &'a dyn Node = tree;
+        for child in path.into_iter().rev() {
+            node = node.children().get(child).copied()?;
+        }
+
+        Some(node)
+    }
+
+    /// The identifier of the node with the given kind, span, address, and concrete type, within
+    /// the given tree, see `Node::id`.
+    pub(crate) fn find(
+        &self,
+        tree: &DefinitionTree,
+        kind: NodeKind,
+        span: Span,
+        address: *const u8,
+        r#type: TypeId,
+    ) -> Option<NodeId> {
+        self.candidates(kind, span)
+            .map(|index| self.entries.entries[index].id)
+            .find(|id| {
+                self.node(tree, *id).is_some_and(|node| {
+                    std::ptr::eq(node as *const dyn Node as *const u8, address)
+                        && node.as_any().type_id() == r#type
+                })
+            })
+    }
+
+    /// Add the entries of the given node, and all of its descendants, reusing the identifiers of
+    /// the unclaimed nodes of the previous table with the same kind, and span.
+    fn assign(
+        &mut self,
+        node: &dyn Node,
+        parent: Option<usize>,
+        child: usize,
+        previous: &Self,
+        claimed: &mut [bool],
+    ) {
+        let mut stack: Vec<(&dyn Node, Option<usize>, usize)> = vec![(node, parent, child)];
+        while let Some((node, parent, child)) = stack.pop() {
+            let (kind, span) = (node.kind(), node.span());
+            // the definition tree keeps its identifier, regardless of its span.
+            let previous_root = match parent {
+                None if !claimed.is_empty() => Some(0),
+                _ => None,
+            };
+
+            let id = previous_root
+                .or_else(|| {
+                    previous
+                        .candidates(kind, span)
+                        .find(|candidate| !claimed[*candidate])
+                })
+                .map(|index| {
+                    claimed[index] = true;
+
+                    previous.entries.entries[index].id
+                })
+                .unwrap_or_else(|| {
+                    self.entries.next += 1;
+
+                    NodeId(self.entries.next - 1)
+                });
+
+            let index = self.entries.entries.len();
+            self.entries.entries.push(NodeIdEntry {
+                id,
+                parent,
+                child,
+                kind,
+                span,
+            });
+
+            stack.extend(
+                node.children()
+                    .into_iter()
+                    .enumerate()
+                    .rev()
+                    .map(|(child, node)| (node, Some(index), child)),
+            );
+        }
+    }
+
+    /// The indices of the entries of the nodes with the given kind, and span.
+    fn candidates(&self, kind: NodeKind, span: Span) -> impl Iterator<Item = usize> + '_ {
+        self.starts
+            .get(&span.start)
+            .into_iter()
+            .flatten()
+            .copied()
+            .filter(move |index| {
+                let entry = &self.entries.entries[*index];
+
+                entry.kind == kind && entry.span == span
+            })
+    }
+
+    fn index(&mut self) {
+        self.ids.clear();
+        self.starts.clear();
+
+        self.ids.resize(self.entries.next, None);
+        for (index, entry) in self.entries.entries.iter().enumerate() {
+            self.ids[entry.id.0] = Some(index);
+            self.starts.entry(entry.span.start).or_default().push(index);
+        }
+    }
+}
+
+impl From<NodeIdEntries> for NodeIdTable {
+    fn from(entries: NodeIdEntries) -> Self {
+        let mut table = Self {
+            entries,
+            ..Self::default()
+        };
+        table.index();
+
+        table
+    }
+}
+
+impl From<NodeIdTable> for NodeIdEntries {
+    fn from(table: NodeIdTable) -> Self {
+        table.entries
+    }
+}
+
+impl PartialEq for NodeIdTable {
+    fn eq(&self, other: &Self) -> bool {
+        self.entries == other.entries
+    }
+}
+
+impl Eq for NodeIdTable {}
+
+impl Hash for NodeIdTable {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.entries.hash(state);
+    }
+}
+
+impl Serialize for NodeIdTable {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.entries.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for NodeIdTable {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        NodeIdEntries::deserialize(deserializer).map(Self::from)
+    }
+}
+
+impl JsonSchema for NodeIdTable {
+    fn schema_name() -> String {
+        NodeIdEntries::schema_name()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        NodeIdEntries::json_schema(gen)
+    }
+}
+
+impl Encode for NodeIdTable {
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        self.entries.encode(encoder)
+    }
+}
+
+impl<Context> Decode<Context> for NodeIdTable {
+    fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        NodeIdEntries::decode(decoder).map(Self::from)
+    }
+}
+
+bincode::impl_borrow_decode!(NodeIdTable);
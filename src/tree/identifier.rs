@@ -7,6 +7,8 @@ use serde::Serialize;
 use crate::lexer::byte_string::ByteString;
 
 use crate::tree::definition::template::TypeTemplateGroupDefinition;
+use crate::tree::kind::NodeKind;
+use crate::tree::span::Span;
 use crate::tree::Node;
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
@@ -24,39 +26,62 @@ pub struct TemplatedIdentifier {
 }
 
 impl Node for Identifier {
-    fn initial_position(&self) -> usize {
-        self.position
+    fn span(&self) -> Span {
+        Span::new(self.position, self.position + self.value.len())
     }
 
-    fn final_position(&self) -> usize {
-        self.position + self.value.len()
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![]
+    }
+
+    fn tokens(&self) -> Vec<&[u8]> {
+        vec![self.value.as_bytes()]
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![]
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        vec![&mut self.position]
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::Identifier
+    }
+
     fn get_description(&self) -> String {
         "identifier".to_string()
     }
 }
 
 impl Node for TemplatedIdentifier {
-    fn initial_position(&self) -> usize {
-        self.name.initial_position()
+    fn span(&self) -> Span {
+        let final_position = match &self.templates {
+            Some(templates) => templates.final_position(),
+            None => self.name.final_position(),
+        };
+
+        Span::new(self.name.initial_position(), final_position)
     }
 
-    fn final_position(&self) -> usize {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut children: Vec<(&'static str, &dyn Node)> = vec![("name", &self.name)];
+
         match &self.templates {
-            Some(templates) => templates.final_position(),
-            None => self.name.final_position(),
+            Some(templates) => {
+                children.push(("templates", templates));
+
+                children
+            }
+            None => children,
         }
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        let mut children: Vec<&dyn Node> = vec![&self.name];
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = vec![&mut self.name];
 
-        match &self.templates {
+        match &mut self.templates {
             Some(templates) => {
                 children.push(templates);
 
@@ -66,6 +91,14 @@ impl Node for TemplatedIdentifier {
         }
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        vec![]
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::TemplatedIdentifier
+    }
+
     fn get_description(&self) -> String {
         "templated identifier".to_string()
     }
@@ -96,6 +129,7 @@ mod tests {
     use crate::tree::definition::r#type::TypeDefinition;
     use crate::tree::token::Keyword;
     use crate::tree::utils::CommaSeparated;
+    use smallvec::smallvec;
 
     #[test]
     fn test_identifier_display() {
@@ -125,13 +159,13 @@ mod tests {
                 value: ByteString::from("Foo"),
             },
             templates: Some(TypeTemplateGroupDefinition {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 less_than: 10,
                 members: CommaSeparated {
                     inner: vec![TypeDefinition::SignedInteger(
                         SignedIntegerTypeDefinition::I64(Keyword::new(ByteString::from("i64"), 15)),
                     )],
-                    commas: vec![1],
+                    commas: smallvec![1],
                 },
                 greater_than: 20,
             }),
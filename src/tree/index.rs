@@ -0,0 +1,223 @@
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use std::collections::HashMap;
+
+use crate::tree::definition::r#enum::EnumDefinition;
+use crate::tree::definition::Definition;
+use crate::tree::identifier::Identifier;
+use crate::tree::span::Span;
+use crate::tree::Node;
+use crate::tree::Tree;
+use crate::tree::TreeMap;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SymbolKind {
+    Class,
+    Interface,
+    Enum,
+    Function,
+    Constant,
+    TypeAlias,
+}
+
+impl SymbolKind {
+    /// Whether symbols of this kind share their names with classes.
+    ///
+    /// Classes, interfaces, enums and type aliases can not share the same name, while functions
+    /// and constants each have their own set of names.
+    pub fn is_classlike(&self) -> bool {
+        matches!(
+            self,
+            Self::Class | Self::Interface | Self::Enum | Self::TypeAlias
+        )
+    }
+
+    /// Whether names of symbols of this kind are case sensitive, only constants are.
+    pub fn is_case_sensitive(&self) -> bool {
+        matches!(self, Self::Constant)
+    }
+}
+
+/// A symbol defined at the top level of a source.
+#[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct Symbol {
+    pub kind: SymbolKind,
+    /// The fully-qualified name of the symbol, without a leading backslash, e.g. `Foo\Bar`.
+    pub name: String,
+    /// The name of the source defining the symbol.
+    pub source: String,
+    /// The span of the definition of the symbol.
+    pub span: Span,
+}
+
+/// The symbols defined by a set of trees.
+#[derive(Debug, PartialEq, Eq, Clone, Default, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct SymbolIndex {
+    pub symbols: Vec<Symbol>,
+}
+
+impl SymbolIndex {
+    pub fn new(map: &TreeMap) -> Self {
+        let mut index = Self::default();
+        for tree in &map.trees {
+            index.add(tree);
+        }
+
+        index
+    }
+
+    /// Add the symbols defined by the given tree to the index.
+    pub fn add(&mut self, tree: &Tree) {
+        self.definitions(tree, "", &tree.definitions.definitions);
+    }
+
+    /// The symbols with the given fully-qualified name, a leading backslash is ignored.
+    pub fn find<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Symbol> + 'a {
+        let name = name.strip_prefix('\\').unwrap_or(name);
+
+        self.symbols.iter().filter(move |symbol| {
+            if symbol.kind.is_case_sensitive() {
+                symbol.name == name
+            } else {
+                symbol.name.eq_ignore_ascii_case(name)
+            }
+        })
+    }
+
+    /// Groups of symbols that are defined more than once, in order of definition.
+    pub fn duplicates(&self) -> Vec<Vec<&Symbol>> {
+        let mut groups: Vec<Vec<&Symbol>> = vec![];
+        let mut indices: HashMap<(u8, String), usize> = HashMap::new();
+
+        for symbol in &self.symbols {
+            let table = match symbol.kind {
+                kind if kind.is_classlike() => 0,
+                SymbolKind::Function => 1,
+                _ => 2,
+            };
+
+            let name = if symbol.kind.is_case_sensitive() {
+                symbol.name.clone()
+            } else {
+                symbol.name.to_ascii_lowercase()
+            };
+
+            match indices.get(&(table, name.clone())) {
+                Some(index) => groups[*index].push(symbol),
+                None => {
+                    indices.insert((table, name), groups.len());
+                    groups.push(vec![symbol]);
+                }
+            }
+        }
+
+        groups.retain(|group| group.len() > 1);
+
+        groups
+    }
+
+    fn definitions(&mut self, tree: &Tree, namespace: &str, definitions: &[Definition]) {
+        for definition in definitions {
+            let (kind, name) = match definition {
+                Definition::Namespace(definition) => {
+                    let namespace = definition.name.value.to_string();
+                    let namespace = namespace.trim_start_matches('\\');
+
+                    self.definitions(tree, namespace, &definition.definitions);
+
+                    continue;
+                }
+                Definition::Class(definition) => (SymbolKind::Class, &definition.name),
+                Definition::Interface(definition) => (SymbolKind::Interface, &definition.name),
+                Definition::Enum(definition) => match definition.as_ref() {
+                    EnumDefinition::Unit(definition) => (SymbolKind::Enum, &definition.name),
+                    EnumDefinition::Backed(definition) => (SymbolKind::Enum, &definition.name),
+                },
+                Definition::Function(definition) => (SymbolKind::Function, &definition.name),
+                Definition::Constant(definition) => (SymbolKind::Constant, &definition.name),
+                Definition::TypeAlias(definition) => (SymbolKind::TypeAlias, &definition.name.name),
+                Definition::Pragma(_) | Definition::Use(_) => continue,
+            };
+
+            self.symbols.push(Symbol {
+                kind,
+                name: qualify(namespace, name),
+                source: tree.source.clone(),
+                span: definition.span(),
+            });
+        }
+    }
+}
+
+fn qualify(namespace: &str, name: &Identifier) -> String {
+    if namespace.is_empty() {
+        name.value.to_string()
+    } else {
+        format!("{}\\{}", namespace, name.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ara_source::source::Source;
+    use ara_source::source::SourceKind;
+    use ara_source::SourceMap;
+
+    use crate::parser;
+
+    #[test]
+    fn test_symbol_index() {
+        let map = SourceMap::new(vec![
+            Source::new(
+                SourceKind::Script,
+                "foo.ara",
+                "namespace Foo;\n\nfinal class Bar {}\nfunction baz(): void {}\nconst int QUX = 1;\ntype Quux = int;\n",
+            ),
+            Source::new(
+                SourceKind::Definition,
+                "bar.d.ara",
+                "namespace Foo;\n\ninterface bar {}\nenum Corge { case A; }\nconst int qux = 1;\n",
+            ),
+        ]);
+
+        let index = SymbolIndex::new(&parser::parse_map(&map).unwrap());
+
+        assert_eq!(
+            index
+                .symbols
+                .iter()
+                .map(|symbol| (symbol.kind, symbol.name.as_str()))
+                .collect::<Vec<(SymbolKind, &str)>>(),
+            vec![
+                (SymbolKind::Class, "Foo\\Bar"),
+                (SymbolKind::Function, "Foo\\baz"),
+                (SymbolKind::Constant, "Foo\\QUX"),
+                (SymbolKind::TypeAlias, "Foo\\Quux"),
+                (SymbolKind::Interface, "Foo\\bar"),
+                (SymbolKind::Enum, "Foo\\Corge"),
+                (SymbolKind::Constant, "Foo\\qux"),
+            ]
+        );
+
+        let function = index.find("\\Foo\\Baz").next().unwrap();
+        assert_eq!(function.source, "foo.ara");
+        assert_eq!(function.span.start, 35);
+
+        assert!(index.find("Foo\\Qux").next().is_none());
+
+        let duplicates = index.duplicates();
+        assert_eq!(duplicates.len(), 1);
+        assert_eq!(
+            duplicates[0]
+                .iter()
+                .map(|symbol| symbol.kind)
+                .collect::<Vec<SymbolKind>>(),
+            vec![SymbolKind::Class, SymbolKind::Interface]
+        );
+    }
+}
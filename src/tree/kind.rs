@@ -0,0 +1,166 @@
+use bincode::Decode;
+use bincode::Encode;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The kind of a node, with a variant for each node type.
+///
+/// Matching on the kind of a node is cheaper than downcasting it, or comparing its description.
+#[derive(
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Clone,
+    Copy,
+    Hash,
+    Deserialize,
+    Serialize,
+    Encode,
+    Decode,
+    JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum NodeKind {
+    Definition,
+    DefinitionTree,
+    AttributeDefinition,
+    AttributeGroupDefinition,
+    ClassDefinition,
+    ClassDefinitionBody,
+    ClassDefinitionExtends,
+    ClassDefinitionImplements,
+    ClassDefinitionMember,
+    ClassDefinitionPermits,
+    ClassishConstantDefinition,
+    ConstantDefinition,
+    BackedEnumBodyDefinition,
+    BackedEnumCaseDefinition,
+    BackedEnumDefinition,
+    BackedEnumMemberDefinition,
+    BackedEnumTypeDefinition,
+    EnumDefinition,
+    EnumImplementsDefinition,
+    UnitEnumBodyDefinition,
+    UnitEnumCaseDefinition,
+    UnitEnumDefinition,
+    UnitEnumMemberDefinition,
+    FunctionBodyDefinition,
+    FunctionDefinition,
+    FunctionLikeParameterDefaultValueDefinition,
+    FunctionLikeParameterDefinition,
+    FunctionLikeParameterListDefinition,
+    FunctionLikeReturnTypeDefinition,
+    MethodBodyDefinition,
+    MethodDefinition,
+    MethodParameterDefinition,
+    MethodParameterListDefinition,
+    MethodTypeConstraintDefinition,
+    MethodTypeConstraintGroupDefinition,
+    InterfaceDefinition,
+    InterfaceDefinitionBody,
+    InterfaceDefinitionExtends,
+    InterfaceDefinitionMember,
+    InterfaceDefinitionPermits,
+    ModifierDefinition,
+    ModifierGroupDefinition,
+    NamespaceDefinition,
+    PragmaDefinition,
+    PropertyDefinition,
+    PropertyEntryDefinition,
+    ConstTemplateDefinition,
+    TemplateDefinition,
+    TemplateBoundsDefinition,
+    TemplateGroupDefinition,
+    TemplateGroupDefinitionMember,
+    TypeTemplateGroupDefinition,
+    FloatingPointTypeDefinition,
+    IntegerRangeBoundTypeDefinition,
+    ShapeFieldTypeDefinition,
+    SignedIntegerTypeDefinition,
+    TupleElementLabelDefinition,
+    TupleElementTypeDefinition,
+    TypeAliasDefinition,
+    TypeDefinition,
+    UnsignedIntegerTypeDefinition,
+    UseDefinition,
+    UseDefinitionGroupMember,
+    UseDefinitionSymbolAlias,
+    Expression,
+    ParenthesizedExpression,
+    ArgumentExpression,
+    ArgumentListExpression,
+    ArgumentPlaceholderExpression,
+    DictElementExpression,
+    DictExpression,
+    TupleExpression,
+    VecElementExpression,
+    VecExpression,
+    AnonymousClassExpression,
+    ExitConstructExpression,
+    MatchArmConditionExpression,
+    MatchArmExpression,
+    MatchBodyExpression,
+    MatchExpression,
+    AnonymousFunctionExpression,
+    AnonymousFunctionUseClauseExpression,
+    AnonymousFunctionUseClauseVariableExpression,
+    ArrowFunctionExpression,
+    GenericGroupExpression,
+    Literal,
+    LiteralFalse,
+    LiteralFloat,
+    LiteralInteger,
+    LiteralNull,
+    LiteralString,
+    LiteralTrue,
+    MagicConstant,
+    ArithmeticOperationExpression,
+    ArrayOperationExpression,
+    AssignmentOperationExpression,
+    AsyncOperationExpression,
+    BitwiseOperationExpression,
+    ClassOperationExpression,
+    ClassOperationInitializationClassExpression,
+    CoalesceOperationExpression,
+    ComparisonOperationExpression,
+    ExceptionOperationExpression,
+    FunctionOperationExpression,
+    FunctionalOperationExpression,
+    GeneratorOperationExpression,
+    LogicalOperationExpression,
+    ObjectOperationExpression,
+    RangeOperationExpression,
+    StringOperationExpression,
+    TernaryOperationExpression,
+    TypeOperationExpression,
+    Identifier,
+    TemplatedIdentifier,
+    Statement,
+    BlockStatement,
+    IfElseIfStatement,
+    IfElseStatement,
+    IfStatement,
+    UsingAssignmentStatement,
+    UsingIfClauseStatement,
+    UsingStatement,
+    ExpressionStatement,
+    BreakStatement,
+    ContinueStatement,
+    DoWhileStatement,
+    ForIteratorStatement,
+    ForStatement,
+    ForeachIteratorKeyStatement,
+    ForeachIteratorStatement,
+    ForeachStatement,
+    WhileStatement,
+    ReturnStatement,
+    TryCatchBlockStatement,
+    TryCatchTypeStatement,
+    TryFinallyBlockStatement,
+    TryStatement,
+    Keyword,
+    Variable,
+}
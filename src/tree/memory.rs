@@ -0,0 +1,151 @@
+use std::collections::BTreeMap;
+use std::mem::size_of;
+use std::mem::size_of_val;
+
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::lexer::byte_string::ByteString;
+use crate::tree::comment::Comment;
+use crate::tree::comment::CommentAttachments;
+use crate::tree::comment::CommentGroup;
+use crate::tree::definition::DefinitionTree;
+use crate::tree::downcast;
+use crate::tree::expression::literal::LiteralFloat;
+use crate::tree::expression::literal::LiteralInteger;
+use crate::tree::expression::literal::LiteralString;
+use crate::tree::identifier::Identifier;
+use crate::tree::kind::NodeKind;
+use crate::tree::token::Keyword;
+use crate::tree::trivia::Trivia;
+use crate::tree::trivia::TriviaTable;
+use crate::tree::trivia::TriviaToken;
+use crate::tree::variable::Variable;
+use crate::tree::Node;
+use crate::tree::Tree;
+
+/// The approximate memory used by one or more trees, in bytes.
+///
+/// The memory used by a node is its own size, excluding the nodes it holds inline, along with
+/// the bytes of the values it owns, such as the name of an identifier.
+///
+/// The spare capacity of vectors is not accounted for, nor are the bytes of values sharing the
+/// buffer of their source, see `ParserOptions::shared_values`.
+#[derive(Debug, PartialEq, Eq, Clone, Default, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct MemoryUsage {
+    /// The memory used by the nodes of each kind, kinds missing from the trees are omitted.
+    pub nodes: BTreeMap<NodeKind, usize>,
+    /// The memory used by the comments of the trees.
+    pub comments: usize,
+    /// The memory used by the trivia of the trees, only captured by a lossless parse.
+    pub trivia: usize,
+    /// The memory used by the trees themselves, e.g. the names of their sources.
+    pub trees: usize,
+}
+
+impl MemoryUsage {
+    /// The total memory used.
+    pub fn total(&self) -> usize {
+        self.nodes.values().sum::<usize>() + self.comments + self.trivia + self.trees
+    }
+
+    /// Add the given memory usage to this one.
+    pub fn add(&mut self, other: &MemoryUsage) {
+        for (kind, size) in &other.nodes {
+            *self.nodes.entry(*kind).or_default() += size;
+        }
+
+        self.comments += other.comments;
+        self.trivia += other.trivia;
+        self.trees += other.trees;
+    }
+}
+
+/// Measure the memory used by the given tree.
+pub(crate) fn tree(tree: &Tree) -> MemoryUsage {
+    let mut usage = MemoryUsage {
+        comments: comments(&tree.comments),
+        trivia: tree.trivia.as_ref().map(trivia).unwrap_or_default(),
+        trees: size_of::<Tree>() - size_of::<DefinitionTree>() + tree.source.len(),
+        ..MemoryUsage::default()
+    };
+
+    let mut stack: Vec<&dyn Node> = vec![&tree.definitions];
+    while let Some(node) = stack.pop() {
+        // children held inline are part of the size of their parent, they are only accounted
+        // for once, as children.
+        let start = address(node);
+        let inline = start..start + size_of_val(node);
+
+        let mut size = size_of_val(node) + values(node);
+        for child in node.children() {
+            if inline.contains(&address(child)) {
+                size = size.saturating_sub(size_of_val(child));
+            }
+
+            stack.push(child);
+        }
+
+        *usage.nodes.entry(node.kind()).or_default() += size;
+    }
+
+    usage
+}
+
+fn address(node: &dyn Node) -> usize {
+    node as *const dyn Node as *const u8 as usize
+}
+
+/// The bytes of the values owned by the given node.
+fn values(node: &dyn Node) -> usize {
+    let value = match node.kind() {
+        NodeKind::Identifier => downcast::<Identifier>(node).map(|n| &n.value),
+        NodeKind::Keyword => downcast::<Keyword>(node).map(|n| &n.value),
+        NodeKind::Variable => downcast::<Variable>(node).map(|n| &n.name),
+        NodeKind::LiteralString => downcast::<LiteralString>(node).map(|n| &n.value),
+        NodeKind::LiteralInteger => downcast::<LiteralInteger>(node).map(|n| &n.value),
+        NodeKind::LiteralFloat => downcast::<LiteralFloat>(node).map(|n| &n.value),
+        _ => None,
+    };
+
+    value.map(bytes).unwrap_or_default()
+}
+
+fn bytes(value: &ByteString) -> usize {
+    if value.is_shared() {
+        0
+    } else {
+        value.len()
+    }
+}
+
+fn comments(attachments: &CommentAttachments) -> usize {
+    let comments = attachments
+        .comments
+        .iter()
+        .map(|comment| size_of::<Comment>() + bytes(&comment.content))
+        .sum::<usize>();
+
+    let groups = attachments.leading.len() + attachments.trailing.len();
+
+    comments + groups * size_of::<(usize, CommentGroup)>()
+}
+
+fn trivia(table: &TriviaTable) -> usize {
+    let trivia = |trivia: &[Trivia]| {
+        trivia
+            .iter()
+            .map(|trivia| size_of::<Trivia>() + bytes(&trivia.value))
+            .sum::<usize>()
+    };
+
+    let tokens = table
+        .tokens
+        .iter()
+        .map(|token| size_of::<TriviaToken>() + bytes(&token.value) + trivia(&token.leading))
+        .sum::<usize>();
+
+    tokens + trivia(&table.trailing)
+}
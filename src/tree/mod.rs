@@ -1,21 +1,58 @@
+use ara_reporting::issue::Issue;
 use bincode::Decode;
 use bincode::Encode;
+use schemars::schema::RootSchema;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
 use std::any::Any;
 use std::any::TypeId;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::hash::Hash as _;
+use std::hash::Hasher;
 
+use crate::tree::comment::Comment;
+use crate::tree::comment::CommentAttachments;
+use crate::tree::comment::CommentFormat;
 use crate::tree::comment::CommentGroup;
 use crate::tree::definition::DefinitionTree;
+use crate::tree::id::NodeIdTable;
+use crate::tree::kind::NodeKind;
+use crate::tree::memory::MemoryUsage;
+use crate::tree::selector::Selector;
+use crate::tree::selector::SelectorError;
+use crate::tree::serialization::SerializationOptions;
+use crate::tree::span::Span;
+use crate::tree::trivia::TriviaTable;
+use crate::tree::utils::ListView;
+use crate::tree::validation::ValidationError;
 
 pub mod comment;
 pub mod definition;
 pub mod expression;
+pub mod format;
+pub mod id;
 pub mod identifier;
+pub mod index;
+pub mod kind;
+pub mod memory;
+pub mod node_type;
+pub mod outline;
+pub mod resolver;
+pub mod selector;
+pub mod serialization;
+pub mod span;
 pub mod statement;
 pub mod token;
+pub mod trivia;
 pub mod utils;
+pub mod validation;
 pub mod variable;
 
-#[derive(Debug)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
 pub struct TreeMap {
     pub trees: Vec<Tree>,
 }
@@ -24,67 +61,1096 @@ impl TreeMap {
     pub fn new(trees: Vec<Tree>) -> Self {
         Self { trees }
     }
+
+    /// The tree of the given source.
+    pub fn get(&self, source: &str) -> Option<&Tree> {
+        self.trees.iter().find(|tree| tree.source == source)
+    }
+
+    pub fn get_mut(&mut self, source: &str) -> Option<&mut Tree> {
+        self.trees.iter_mut().find(|tree| tree.source == source)
+    }
+
+    /// Insert the given tree, replacing the tree of the same source, if any, which is returned.
+    ///
+    /// This is used for re-parsed sources as well, the position of a replaced tree in the map is kept.
+    pub fn insert(&mut self, tree: Tree) -> Option<Tree> {
+        match self.get_mut(&tree.source) {
+            Some(existing) => Some(std::mem::replace(existing, tree)),
+            None => {
+                self.trees.push(tree);
+
+                None
+            }
+        }
+    }
+
+    /// Remove the tree of the given source.
+    pub fn remove(&mut self, source: &str) -> Option<Tree> {
+        self.trees
+            .iter()
+            .position(|tree| tree.source == source)
+            .map(|index| self.trees.remove(index))
+    }
+
+    /// Insert all trees of the given map, replacing the trees of the same sources.
+    pub fn merge(&mut self, other: TreeMap) {
+        for tree in other.trees {
+            self.insert(tree);
+        }
+    }
+
+    /// The trees, ordered by the name of their source, regardless of the order they were inserted in.
+    pub fn iter(&self) -> impl Iterator<Item = &Tree> {
+        let mut trees = self.trees.iter().collect::<Vec<&Tree>>();
+        trees.sort_by(|a, b| a.source.cmp(&b.source));
+
+        trees.into_iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.trees.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.trees.is_empty()
+    }
+
+    /// The JSON schema of a serialized tree map.
+    pub fn schema() -> RootSchema {
+        schema_of::<TreeMap>()
+    }
+
+    /// The approximate memory used by the trees of the map, see `MemoryUsage`.
+    pub fn approximate_size(&self) -> MemoryUsage {
+        let mut usage = MemoryUsage::default();
+        for tree in &self.trees {
+            usage.add(&tree.approximate_size());
+        }
+
+        usage
+    }
+}
+
+/// An identifier of a node, unique within a single tree, see `NodeIdTable`.
+#[derive(
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Clone,
+    Copy,
+    Hash,
+    Deserialize,
+    Serialize,
+    Encode,
+    Decode,
+    JsonSchema,
+)]
+pub struct NodeId(pub usize);
+
+/// A node parsed on its own, along with the comments of the code it was parsed from, which are
+/// attached to its tokens, see `parser::parse_definition`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Fragment<T> {
+    pub node: T,
+    /// The comments of the code, attached to the tokens surrounding them.
+    pub comments: CommentAttachments,
+    /// The warnings, and any other issues below the error severity, reported while parsing the
+    /// node, see `parser::parse_with_warnings`.
+    pub warnings: Vec<Issue>,
 }
 
-#[derive(Debug, Hash, Encode, Decode)]
+/// A parsed source.
+///
+/// The binary encoding of a tree is versioned, see the `format` module.
+#[derive(Debug, Hash, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
 pub struct Tree {
+    /// The name of the source.
     pub source: String,
     pub definitions: DefinitionTree,
+    /// The hash of the source content, see `cache::hash`.
+    pub source_hash: u64,
+    /// The comments of the source, attached to the tokens surrounding them.
+    pub comments: CommentAttachments,
+    /// The tokens and trivia of the source, only captured by a lossless parse.
+    pub trivia: Option<TriviaTable>,
+    /// The identifiers of the nodes of the tree.
+    pub ids: NodeIdTable,
 }
 
 impl Tree {
     pub fn new<S: Into<String>>(source: S, definitions: DefinitionTree) -> Self {
         Self {
             source: source.into(),
+            ids: NodeIdTable::new(&definitions),
             definitions,
+            source_hash: 0,
+            comments: CommentAttachments::default(),
+            trivia: None,
+        }
+    }
+
+    /// All nodes of the tree paired with their identifiers, in pre-order.
+    pub fn nodes(&self) -> Vec<(NodeId, &dyn Node)> {
+        self.ids
+            .ids()
+            .zip(
+                std::iter::once(&self.definitions as &dyn Node)
+                    .chain(self.definitions.descendants()),
+            )
+            .collect()
+    }
+
+    /// Assign identifiers to the nodes of the tree after modifying it in place, see
+    /// `NodeIdTable::refresh`.
+    pub fn refresh_ids(&mut self) {
+        self.ids.refresh(&self.definitions);
+    }
+
+    /// The parent of every node in the tree.
+    pub fn parents(&self) -> ParentMap<'_> {
+        ParentMap::new(&self.definitions)
+    }
+
+    /// The node with the given identifier.
+    pub fn node(&self, id: NodeId) -> Option<&dyn Node> {
+        self.ids.node(&self.definitions, id)
+    }
+
+    /// The approximate memory used by the tree, per kind of node, see `MemoryUsage`.
+    pub fn approximate_size(&self) -> MemoryUsage {
+        memory::tree(self)
+    }
+
+    /// The identifier of the given node, if it belongs to this tree.
+    pub fn id(&self, node: &dyn Node) -> Option<NodeId> {
+        node.id(self)
+    }
+}
+
+/// Access to a node as `Any`, implemented for every node.
+pub trait AsAny: Any {
+    fn as_any(&self) -> &dyn Any;
+
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<T: Node> AsAny for T {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// Hashing of the structure of a node, implemented for every node.
+pub trait StructuralHash {
+    /// A hash of the node that ignores positions and comments, so that the same code hashes
+    /// the same regardless of its formatting.
+    ///
+    /// The hash is only stable within the same build of the parser.
+    fn structural_hash(&self) -> u64;
+
+    /// Feeds the structure of the node, and of its descendants, to the given hasher.
+    fn hash_structure(&self, hasher: &mut dyn Hasher);
+}
+
+impl<T: Node> StructuralHash for T {
+    fn structural_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.hash_structure(&mut hasher);
+
+        hasher.finish()
+    }
+
+    fn hash_structure(&self, mut hasher: &mut dyn Hasher) {
+        // The structure of a node is made of its kind, its variant, the text of its own tokens,
+        // and the structure of its children; positions and comments are never looked at.
+        self.kind().hash(&mut hasher);
+        std::mem::discriminant(self).hash(&mut hasher);
+        self.tokens().hash(&mut hasher);
+
+        let children = self.children();
+        children.len().hash(&mut hasher);
+        for child in children {
+            child.hash_structure(hasher);
         }
     }
 }
 
-pub trait Node: Any {
-    /// The comments associated with the node.
+/// Serialization of a node to a JSON value, implemented for every node.
+pub trait ToValue {
+    fn to_value(&self, options: SerializationOptions) -> serde_json::Result<serde_json::Value>;
+}
+
+impl<T: Node + Serialize> ToValue for T {
+    fn to_value(&self, options: SerializationOptions) -> serde_json::Result<serde_json::Value> {
+        serialization::to_value(self, options)
+    }
+}
+
+pub trait Node: AsAny + StructuralHash + ToValue {
+    /// The comments associated with the node, held by the tree, see `CommentAttachments::group`.
     fn comments(&self) -> Option<&CommentGroup> {
         None
     }
 
-    /// The position of the first token in the node.
-    fn initial_position(&self) -> usize;
+    /// The comments preceding the first token of the node, see `CommentAttachments`.
+    fn leading_comments<'a>(&self, tree: &'a Tree) -> &'a [Comment] {
+        tree.comments.leading_at(self.initial_position())
+    }
 
-    /// The position of the last token in the node, including the last token itself.
+    /// The comments following the last token of the node on the same line, see `CommentAttachments`.
+    fn trailing_comments<'a>(&self, tree: &'a Tree) -> &'a [Comment] {
+        tree.comments.trailing_at(self.final_position())
+    }
+
+    /// The document comment immediately preceding the node, if any.
     ///
-    /// This is not necessarily the same as the last token in the node's children.
-    fn final_position(&self) -> usize;
+    /// Unlike `comments`, this takes the attributes of the node into account, as the document
+    /// comment of a node precedes its attributes.
+    fn doc_comment<'a>(&self, tree: &'a Tree) -> Option<&'a Comment> {
+        self.leading_comments(tree)
+            .last()
+            .filter(|comment| comment.format == CommentFormat::Document)
+    }
+
+    /// The identifier of the node, if it belongs to the given tree, see `NodeIdTable`.
+    fn id(&self, tree: &Tree) -> Option<NodeId> {
+        tree.ids.find(
+            &tree.definitions,
+            self.kind(),
+            self.span(),
+            self as *const Self as *const u8,
+            TypeId::of::<Self>(),
+        )
+    }
+
+    /// The span of the node, from the position of its first token, to the position right after
+    /// its last token.
+    ///
+    /// The span of a node covers the spans of its children, see `validate`.
+    fn span(&self) -> Span;
+
+    /// The position of the first token in the node, see `span`.
+    fn initial_position(&self) -> usize {
+        self.span().start
+    }
+
+    /// The position right after the last token in the node, see `span`.
+    fn final_position(&self) -> usize {
+        self.span().end
+    }
+
+    /// The children of the node, each paired with the name of the field holding it.
+    ///
+    /// Children held by the same field, such as the items of a list, share the same name.
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)>;
 
     /// The children of the node.
     ///
     /// This is used for traversing the tree.
-    fn children(&self) -> Vec<&dyn Node>;
+    fn children(&self) -> Vec<&dyn Node> {
+        self.named_children()
+            .into_iter()
+            .map(|(_, child)| child)
+            .collect()
+    }
+
+    /// The comma separated lists held by the node itself, excluding those of its children.
+    ///
+    /// This is used for validating the tree, see `validate`.
+    fn lists(&self) -> Vec<ListView<'_>> {
+        vec![]
+    }
+
+    /// The text of the tokens held by the node itself rather than by its children, e.g. the
+    /// value of an identifier, or the `...` of a variadic parameter.
+    ///
+    /// Delimiters and separators are left out, as they follow from the kind of the node. This is
+    /// used for hashing the structure of the tree, see `StructuralHash`.
+    fn tokens(&self) -> Vec<&[u8]> {
+        vec![]
+    }
+
+    /// The children of the node, in the same order as `children`, but mutable.
+    ///
+    /// The members of a template group synthesized from a docblock are the only exception, they
+    /// are not children of the group, but are still returned here, so they move along with it.
+    ///
+    /// This is used for transforming the tree in place.
+    fn children_mut(&mut self) -> Vec<&mut dyn Node>;
+
+    /// The positions held by the node itself, including those of its comments, but excluding
+    /// those of its children.
+    ///
+    /// This is used for moving nodes within a source, see `shift`.
+    fn positions_mut(&mut self) -> Vec<&mut usize>;
+
+    /// The descendants of the node, excluding the node itself, in pre-order.
+    fn descendants(&self) -> Descendants<'_> {
+        Descendants {
+            stack: self.children().into_iter().rev().collect(),
+        }
+    }
+
+    /// The kind of the node.
+    fn kind(&self) -> NodeKind;
 
     /// The description of the node.
     fn get_description(&self) -> String;
 }
 
-pub fn downcast<T: Node + 'static>(node: &dyn Node) -> Option<&T> {
-    // Get `TypeId` of the type this function is instantiated with.
-    let t = TypeId::of::<T>();
+/// A pre-order iterator over the descendants of a node.
+pub struct Descendants<'a> {
+    stack: Vec<&'a dyn Node>,
+}
+
+impl<'a> Iterator for Descendants<'a> {
+    type Item = &'a dyn Node;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+
+        self.stack.extend(node.children().into_iter().rev());
+
+        Some(node)
+    }
+}
+
+/// The parent of every node below a root node.
+///
+/// Nodes are looked up by address and concrete type, see `is_same_node`.
+pub struct ParentMap<'a> {
+    parents: HashMap<(*const u8, TypeId), &'a dyn Node>,
+}
+
+impl<'a> ParentMap<'a> {
+    pub fn new(root: &'a dyn Node) -> Self {
+        let mut parents = HashMap::new();
+        let mut stack = vec![root];
+
+        while let Some(node) = stack.pop() {
+            for child in node.children() {
+                parents.insert(key(child), node);
+
+                stack.push(child);
+            }
+        }
+
+        Self { parents }
+    }
+
+    /// The parent of the given node, if it is below the root.
+    pub fn parent(&self, node: &dyn Node) -> Option<&'a dyn Node> {
+        self.parents.get(&key(node)).copied()
+    }
+
+    /// The ancestors of the given node, excluding the node itself, from its parent up to the root.
+    pub fn ancestors<'m>(&'m self, node: &dyn Node) -> Ancestors<'a, 'm> {
+        Ancestors {
+            map: self,
+            current: self.parent(node),
+        }
+    }
+}
+
+/// An iterator over the ancestors of a node, from its parent up to the root.
+pub struct Ancestors<'a, 'm> {
+    map: &'m ParentMap<'a>,
+    current: Option<&'a dyn Node>,
+}
+
+impl<'a, 'm> Iterator for Ancestors<'a, 'm> {
+    type Item = &'a dyn Node;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.current?;
+
+        self.current = self.map.parent(node);
+
+        Some(node)
+    }
+}
+
+fn key(node: &dyn Node) -> (*const u8, TypeId) {
+    (node as *const dyn Node as *const u8, node.type_id())
+}
+
+/// Size and shape measurements of a tree.
+#[derive(Debug, PartialEq, Eq, Clone, Default, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct Metrics {
+    /// The number of nodes of each kind, kinds missing from the tree are omitted.
+    pub nodes: BTreeMap<NodeKind, usize>,
+    /// The depth of the deepest node, the definition tree itself having a depth of `1`.
+    pub depth: usize,
+    /// The number of expressions nested in one another along the deepest chain of expressions.
+    pub expression_depth: usize,
+    /// The size of the source, in bytes.
+    pub source_size: usize,
+}
+
+impl Metrics {
+    /// The total number of nodes.
+    pub fn total(&self) -> usize {
+        self.nodes.values().sum()
+    }
+}
+
+/// Measure the size and shape of the given tree.
+pub fn metrics(tree: &Tree) -> Metrics {
+    let mut metrics = Metrics {
+        source_size: tree.definitions.eof,
+        ..Metrics::default()
+    };
 
-    // Get `TypeId` of the node we want to downcast.
-    let concrete = node.type_id();
+    let mut stack: Vec<(&dyn Node, usize, usize)> = vec![(&tree.definitions, 1, 0)];
+    while let Some((node, depth, mut expression_depth)) = stack.pop() {
+        let kind = node.kind();
+        if kind == NodeKind::Expression {
+            expression_depth += 1;
+        }
 
-    // Compare both `TypeId`s on equality.
-    if t == concrete {
-        // Get the concrete type pointer from the trait object.
-        let concrete = node as *const dyn Node as *const T;
+        *metrics.nodes.entry(kind).or_default() += 1;
+        metrics.depth = metrics.depth.max(depth);
+        metrics.expression_depth = metrics.expression_depth.max(expression_depth);
 
-        // Convert it to a reference and return it.
-        //
-        // SAFETY: This is safe because we know for sure that the pointer
-        // is valid and references are only handed out for the lifetime
-        // of the function.
-        let concrete = unsafe { &*concrete };
+        for child in node.children() {
+            stack.push((child, depth + 1, expression_depth));
+        }
+    }
+
+    metrics
+}
+
+/// Check the structural invariants the parser guarantees for the trees it produces, for use
+/// after constructing or transforming a tree programmatically:
+///
+/// - the final position of each node is not before its initial position.
+/// - the children of each node lie within it, in source order, without overlapping.
+/// - identifiers and variables are not empty.
+/// - each comma separated list has a comma between each pair of items, and at most one trailing
+///   comma, in order.
+pub fn validate(tree: &Tree) -> Result<(), Vec<ValidationError>> {
+    let mut errors = vec![];
+
+    let mut stack: Vec<&dyn Node> = vec![&tree.definitions];
+    while let Some(node) = stack.pop() {
+        validation::node(node, &mut errors);
+
+        stack.extend(node.children().into_iter().rev());
+    }
 
-        Some(concrete)
+    if errors.is_empty() {
+        Ok(())
     } else {
-        None
+        Err(errors)
+    }
+}
+
+/// All nodes of the tree matching the given selector, in pre-order, see `Selector` for the syntax.
+pub fn query<'a>(tree: &'a Tree, selector: &str) -> Result<Vec<&'a dyn Node>, SelectorError> {
+    Selector::parse(selector).map(|selector| selector.select(&tree.definitions))
+}
+
+/// The chain of nodes covering the given byte offset, from the outermost to the innermost.
+///
+/// An offset right after a token, such as a cursor at the end of an identifier, covers that
+/// token, unless another token starts at the same offset.
+///
+/// The chain is empty if the offset falls outside the tree.
+pub fn locate(tree: &Tree, offset: usize) -> Vec<&dyn Node> {
+    let ends_at = |span: Span| span.start < offset && span.end == offset;
+
+    let mut chain: Vec<&dyn Node> = vec![];
+
+    let root: &dyn Node = &tree.definitions;
+    let mut current =
+        Some(root).filter(|node| node.span().contains(offset) || ends_at(node.span()));
+    while let Some(node) = current {
+        chain.push(node);
+
+        let children = node.children();
+        current = children
+            .iter()
+            .find(|child| child.span().contains(offset))
+            .or_else(|| children.iter().find(|child| ends_at(child.span())))
+            .copied();
+    }
+
+    chain
+}
+
+/// The JSON schema of a serialized definition tree, see `schema_of`.
+pub fn schema() -> RootSchema {
+    schema_of::<DefinitionTree>()
+}
+
+/// The JSON schema of the given serialized type, such as a fragment of a tree, e.g. `Expression`.
+///
+/// The schema is identified by the name of the type, and the version of the parser, e.g.
+/// `https://ara-lang.io/schema/parser/0.6.6/DefinitionTree.json`, as the schema of a type may
+/// change between versions.
+pub fn schema_of<T: JsonSchema>() -> RootSchema {
+    let mut schema = schemars::schema_for!(T);
+    schema.schema.metadata().id = Some(format!(
+        "https://ara-lang.io/schema/parser/{}/{}.json",
+        env!("CARGO_PKG_VERSION"),
+        T::schema_name()
+    ));
+
+    schema
+}
+
+/// Move the given node, and all of its descendants, by the given number of bytes within the source.
+pub fn shift(node: &mut dyn Node, offset: isize) {
+    let mut stack: Vec<&mut dyn Node> = vec![node];
+    while let Some(node) = stack.pop() {
+        for position in node.positions_mut() {
+            *position = position.saturating_add_signed(offset);
+        }
+
+        stack.extend(node.children_mut());
+    }
+}
+
+/// Whether both references point to the same node, rather than to equal nodes.
+///
+/// A node and its first field may share an address, so the concrete types are compared as well.
+pub fn is_same_node(a: &dyn Node, b: &dyn Node) -> bool {
+    std::ptr::eq(
+        a as *const dyn Node as *const u8,
+        b as *const dyn Node as *const u8,
+    ) && a.type_id() == b.type_id()
+}
+
+pub fn downcast<T: Node + 'static>(node: &dyn Node) -> Option<&T> {
+    node.as_any().downcast_ref::<T>()
+}
+
+pub fn downcast_mut<T: Node + 'static>(node: &mut dyn Node) -> Option<&mut T> {
+    node.as_any_mut().downcast_mut::<T>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ara_source::source::Source;
+    use ara_source::source::SourceKind;
+
+    use crate::parser;
+    use crate::parser::edit::Edit;
+    use crate::tree::definition::Definition;
+    use crate::tree::expression::Expression;
+    use crate::tree::identifier::Identifier;
+    use crate::tree::token::Keyword;
+
+    #[test]
+    fn test_node_ids() {
+        let source = Source::inline(SourceKind::Script, "function foo(): void {}");
+        let tree = parser::parse(&source).unwrap();
+
+        let nodes = tree.nodes();
+        assert_eq!(nodes[0].0, NodeId(0));
+        assert!(is_same_node(nodes[0].1, &tree.definitions));
+
+        let function = match &tree.definitions.definitions[0] {
+            Definition::Function(function) => function.as_ref(),
+            _ => unreachable!(),
+        };
+
+        let id = tree.id(function).unwrap();
+        assert!(is_same_node(tree.node(id).unwrap(), function));
+        assert!(tree.id(&function.name).unwrap() > id);
+
+        // the modifier group and the `function` keyword share a position, but not an identifier.
+        assert_eq!(
+            function.modifiers.initial_position(),
+            function.function.initial_position()
+        );
+        assert_ne!(
+            tree.id(&function.modifiers).unwrap(),
+            tree.id(&function.function).unwrap()
+        );
+
+        // the node is equal to, but not the same as, the node of the tree.
+        assert_eq!(tree.id(&function.clone()), None);
+        assert_eq!(function.id(&tree), Some(id));
+    }
+
+    #[test]
+    fn test_node_ids_after_reparse() {
+        let code = "function foo(): void {}
+
+function bar(): int { return 1; }
+
+function baz(): void {}
+";
+        let source = Source::inline(SourceKind::Script, code);
+        let tree = parser::parse(&source).unwrap();
+
+        let ids = |tree: &Tree| {
+            tree.definitions
+                .definitions
+                .iter()
+                .map(|definition| tree.id(definition).unwrap())
+                .collect::<Vec<NodeId>>()
+        };
+
+        let start = code.find("1;").unwrap();
+        let reparsed = parser::reparse(&tree, &[], &source, &Edit::new(start, start + 1, "100"))
+            .unwrap()
+            .0;
+
+        let (before, after) = (ids(&tree), ids(&reparsed));
+        assert_eq!(after[0], before[0]);
+        assert_ne!(after[1], before[1]);
+        assert_eq!(after[2], before[2]);
+        assert_eq!(reparsed.id(&reparsed.definitions), Some(NodeId(0)));
+
+        // new nodes are never assigned the identifier of a node of the previous tree.
+        let previous = tree.nodes().into_iter().map(|(id, _)| id).max().unwrap();
+        assert!(after[1] > previous);
+        assert!(is_same_node(
+            reparsed.node(after[2]).unwrap(),
+            &reparsed.definitions.definitions[2]
+        ));
+
+        for (id, node) in reparsed.nodes() {
+            assert!(is_same_node(reparsed.node(id).unwrap(), node));
+            assert_eq!(reparsed.id(node), Some(id));
+        }
+    }
+
+    #[test]
+    fn test_locate() {
+        let source = Source::inline(SourceKind::Script, "function foo(): void {}");
+        let tree = parser::parse(&source).unwrap();
+
+        let chain = locate(&tree, 10);
+        assert_eq!(
+            chain
+                .iter()
+                .map(|node| node.get_description())
+                .collect::<Vec<String>>(),
+            vec![
+                "tree definition",
+                "function definition",
+                "function definition",
+                "identifier",
+            ]
+        );
+
+        assert!(locate(&tree, 100).is_empty());
+    }
+
+    #[test]
+    fn test_locate_end_of_token() {
+        let source = Source::inline(SourceKind::Script, "function foo (): void {}");
+        let tree = parser::parse(&source).unwrap();
+
+        // right after `foo`.
+        let chain = locate(&tree, 12);
+        assert_eq!(
+            chain
+                .iter()
+                .map(|node| node.get_description())
+                .collect::<Vec<String>>(),
+            vec![
+                "tree definition",
+                "function definition",
+                "function definition",
+                "identifier",
+            ]
+        );
+
+        let source = Source::inline(SourceKind::Script, "function foo(): void { $a = $b; }");
+        let tree = parser::parse(&source).unwrap();
+
+        // right after `$b`, before `;`.
+        let chain = locate(&tree, 30);
+        let innermost = chain.last().unwrap();
+        assert_eq!(innermost.span(), Span::new(28, 30));
+        assert_eq!(innermost.get_description(), "variable");
+
+        // right after the closing brace of the body.
+        let chain = locate(&tree, 33);
+        assert_eq!(chain.first().unwrap().get_description(), "tree definition");
+        assert_eq!(chain.last().unwrap().span(), Span::new(21, 33));
+
+        assert!(locate(&tree, 34).is_empty());
+    }
+
+    #[test]
+    fn test_named_children() {
+        let source = Source::inline(SourceKind::Script, "function foo(int $a, int $b): void {}");
+        let tree = parser::parse(&source).unwrap();
+
+        let function = match &tree.definitions.definitions[0] {
+            Definition::Function(function) => function.as_ref(),
+            _ => unreachable!(),
+        };
+
+        assert_eq!(
+            function
+                .named_children()
+                .iter()
+                .map(|(name, _)| *name)
+                .collect::<Vec<&str>>(),
+            vec![
+                "modifiers",
+                "function",
+                "name",
+                "parameters",
+                "return_type",
+                "body"
+            ]
+        );
+
+        assert_eq!(
+            function
+                .parameters
+                .named_children()
+                .iter()
+                .map(|(name, _)| *name)
+                .collect::<Vec<&str>>(),
+            vec!["parameters", "parameters"]
+        );
+    }
+
+    #[test]
+    fn test_descendants_and_ancestors() {
+        let source = Source::inline(SourceKind::Script, "function foo(int $a): void {}");
+        let tree = parser::parse(&source).unwrap();
+
+        let function = &tree.definitions.definitions[0];
+        let descendants = function.descendants().collect::<Vec<&dyn Node>>();
+        assert_eq!(descendants.len(), tree.nodes().len() - 2);
+        assert!(descendants
+            .iter()
+            .all(|node| !is_same_node(*node, function)));
+
+        let variable = descendants
+            .iter()
+            .find(|node| node.get_description() == "variable")
+            .unwrap();
+
+        let parents = tree.parents();
+        assert!(parents.parent(&tree.definitions).is_none());
+        assert_eq!(
+            parents
+                .ancestors(*variable)
+                .map(|node| node.get_description())
+                .collect::<Vec<String>>(),
+            vec![
+                "function like parameter definition",
+                "function like parameter list definition",
+                "function definition",
+                "function definition",
+                "tree definition",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_shift() {
+        let code = "// foo\nfunction foo<+T>(vec<T> $a, int ...$b): void { $c = vec[$a, 1,]; }\n";
+        let parse = |code: &str| parser::parse(&Source::inline(SourceKind::Script, code)).unwrap();
+
+        let mut tree = parse(code);
+        shift(&mut tree.definitions, 4);
+        assert_eq!(tree.definitions, parse(&format!("    {code}")).definitions);
+
+        shift(&mut tree.definitions, -4);
+        assert_eq!(tree.definitions, parse(code).definitions);
+    }
+
+    #[test]
+    fn test_downcast() {
+        let source = Source::inline(SourceKind::Script, "function foo(): void {}");
+        let mut tree = parser::parse(&source).unwrap();
+
+        let definition: &mut dyn Node = &mut tree.definitions.definitions[0];
+        assert!(downcast::<Definition>(definition).is_some());
+        assert!(downcast::<DefinitionTree>(definition).is_none());
+        assert!(definition.as_any().is::<Definition>());
+
+        if let Some(Definition::Function(function)) = downcast_mut::<Definition>(definition) {
+            function.name.value = "bar".into();
+        }
+
+        let function = match &tree.definitions.definitions[0] {
+            Definition::Function(function) => function.as_ref(),
+            _ => unreachable!(),
+        };
+
+        assert_eq!(function.name.value, b"bar");
+    }
+
+    #[test]
+    fn test_structural_hash() {
+        let parse = |code: &str| parser::parse(&Source::inline(SourceKind::Script, code)).unwrap();
+
+        let a = parse("function foo(int $a, int $b,): int { return $a + $b; }");
+        let b = parse(
+            "// sum\nfunction foo(\n    int $a,\n    int $b\n): int {\n    return $a + $b;\n}\n",
+        );
+        let c = parse("function foo(int $a, int $b): int { return $a - $b; }");
+        let d = parse("function foo(int ...$a): int { return 0; }");
+        let e = parse("function foo(int $a): int { return 0; }");
+
+        assert_eq!(
+            a.definitions.structural_hash(),
+            b.definitions.structural_hash()
+        );
+        assert_ne!(
+            a.definitions.structural_hash(),
+            c.definitions.structural_hash()
+        );
+        assert_ne!(
+            d.definitions.structural_hash(),
+            e.definitions.structural_hash()
+        );
+
+        let f = parse("type Payload = shape(?'type' => string, ...);");
+        let g = parse("type Payload = shape('type' => string);");
+        assert_ne!(
+            f.definitions.structural_hash(),
+            g.definitions.structural_hash()
+        );
+
+        let node: &dyn Node = &a.definitions.definitions[0];
+        assert_eq!(
+            node.structural_hash(),
+            b.definitions.definitions[0].structural_hash()
+        );
+    }
+
+    #[test]
+    fn test_node_kind() {
+        let source = Source::inline(SourceKind::Script, "function foo(int $a): void {}");
+        let tree = parser::parse(&source).unwrap();
+
+        assert_eq!(tree.definitions.kind(), NodeKind::DefinitionTree);
+        assert_eq!(
+            tree.definitions
+                .descendants()
+                .take(5)
+                .map(|node| node.kind())
+                .collect::<Vec<NodeKind>>(),
+            vec![
+                NodeKind::Definition,
+                NodeKind::FunctionDefinition,
+                NodeKind::ModifierGroupDefinition,
+                NodeKind::Keyword,
+                NodeKind::Identifier,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_doc_comment() {
+        let code = "/** foo */\n#[Bar]\nfunction foo(): void {}\n\n/** bar */\n// baz\nfunction bar(): void {}\n";
+        let source = Source::inline(SourceKind::Script, code);
+        let tree = parser::parse(&source).unwrap();
+
+        let foo = &tree.definitions.definitions[0];
+        assert_eq!(
+            foo.doc_comment(&tree)
+                .map(|comment| comment.content.to_string()),
+            Some("/** foo */".to_string())
+        );
+
+        let bar = &tree.definitions.definitions[1];
+        assert!(bar.doc_comment(&tree).is_none());
+    }
+
+    #[test]
+    fn test_metrics() {
+        let code = "function foo(): int { return 1 + (2 * 3); }";
+        let source = Source::inline(SourceKind::Script, code);
+        let tree = parser::parse(&source).unwrap();
+
+        let metrics = metrics(&tree);
+        assert_eq!(metrics.source_size, code.len());
+        assert_eq!(metrics.total(), tree.nodes().len());
+        assert_eq!(metrics.nodes[&NodeKind::FunctionDefinition], 1);
+        assert_eq!(metrics.nodes[&NodeKind::LiteralInteger], 3);
+        assert!(!metrics.nodes.contains_key(&NodeKind::ClassDefinition));
+        // 1 + (..) -> (..) -> 2 * 3 -> 2
+        assert_eq!(metrics.expression_depth, 4);
+        assert!(metrics.depth > metrics.expression_depth);
+
+        let json = serde_json::to_value(&metrics).unwrap();
+        assert_eq!(json["nodes"]["function_definition"], 1);
+    }
+
+    #[test]
+    fn test_approximate_size() {
+        let code = "// foo\nfunction foo(): int { return 1 + (2 * 3); }";
+        let tree = parser::parse(&Source::inline(SourceKind::Script, code)).unwrap();
+
+        let usage = tree.approximate_size();
+        assert!(usage.nodes[&NodeKind::FunctionDefinition] > 0);
+        // `foo`.
+        assert_eq!(
+            usage.nodes[&NodeKind::Identifier],
+            std::mem::size_of::<Identifier>() + 3
+        );
+        // `function`, `int`, and `return`.
+        assert_eq!(
+            usage.nodes[&NodeKind::Keyword],
+            3 * std::mem::size_of::<Keyword>() + 17
+        );
+        assert_eq!(
+            usage.comments,
+            std::mem::size_of::<Comment>() + 6 + std::mem::size_of::<(usize, CommentGroup)>()
+        );
+        assert_eq!(usage.trivia, 0);
+
+        let longer = code.replace("foo", "foo_bar_baz");
+        let longer = parser::parse(&Source::inline(SourceKind::Script, &longer)).unwrap();
+        assert_eq!(longer.approximate_size().total(), usage.total() + 16);
+
+        let map = TreeMap::new(vec![tree, longer]);
+        assert_eq!(map.approximate_size().total(), 2 * usage.total() + 16);
+    }
+
+    #[test]
+    fn test_validate() {
+        let code = "function foo(int $a, int $b): void { bar($a, $b); }";
+        let source = Source::inline(SourceKind::Script, code);
+        let mut tree = parser::parse(&source).unwrap();
+
+        assert_eq!(validate(&tree), Ok(()));
+
+        let function = match &mut tree.definitions.definitions[0] {
+            Definition::Function(function) => function,
+            _ => unreachable!(),
+        };
+
+        function.name.position = 5;
+        function.parameters.parameters.inner[1]
+            .variable
+            .name
+            .to_mut()
+            .truncate(1);
+        function.parameters.parameters.commas.extend([10, 11]);
+
+        let messages = |tree: &Tree| {
+            validate(tree)
+                .unwrap_err()
+                .into_iter()
+                .map(|error| error.message)
+                .collect::<Vec<String>>()
+        };
+
+        assert_eq!(
+            messages(&tree),
+            vec![
+                "identifier does not follow the preceding keyword".to_string(),
+                "comma separated list of 2 items has 3 commas".to_string(),
+                "variable is empty".to_string(),
+            ]
+        );
+
+        let mut tree = parser::parse(&source).unwrap();
+        let function = match &mut tree.definitions.definitions[0] {
+            Definition::Function(function) => function,
+            _ => unreachable!(),
+        };
+
+        function.parameters.parameters.commas[0] = 14;
+        function.parameters.parameters.inner[0].variable.position = 100;
+
+        assert_eq!(
+            messages(&tree),
+            vec![
+                "function like parameter definition lies outside of its parent function like parameter list definition".to_string(),
+                "function like parameter definition does not follow the preceding function like parameter definition".to_string(),
+                "commas of comma separated list are out of order".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tree_map() {
+        let parse = |name: &str, code: &str| {
+            parser::parse(&Source::new(SourceKind::Script, name, code)).unwrap()
+        };
+
+        let mut map = TreeMap::new(vec![
+            parse("b.ara", "function b(): void {}"),
+            parse("a.ara", "function a(): void {}"),
+        ]);
+
+        assert_eq!(
+            map.iter()
+                .map(|tree| tree.source.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["a.ara", "b.ara"]
+        );
+
+        let previous = map.insert(parse("a.ara", "function c(): void {}"));
+        assert!(previous.is_some());
+        assert_eq!(map.len(), 2);
+
+        map.merge(TreeMap::new(vec![
+            parse("b.ara", ""),
+            parse("c.ara", "function c(): void {}"),
+        ]));
+
+        assert_eq!(map.len(), 3);
+        assert!(map.get("b.ara").unwrap().definitions.definitions.is_empty());
+        assert!(map.remove("a.ara").is_some());
+        assert!(map.get("a.ara").is_none());
+    }
+
+    #[test]
+    fn test_tree_map_serialization() {
+        let source = Source::new(SourceKind::Script, "foo.ara", "function foo(): void {}");
+        let map = TreeMap::new(vec![parser::parse(&source).unwrap()]);
+
+        let json = serde_json::to_value(&map).unwrap();
+        assert_eq!(json["trees"][0]["source"], "foo.ara");
+        assert_eq!(
+            json["trees"][0]["definitions"]["definitions"][0]["type"],
+            "function"
+        );
+
+        let decoded: TreeMap = serde_json::from_value(json).unwrap();
+        assert_eq!(decoded.trees[0].definitions, map.trees[0].definitions);
+
+        let schema = serde_json::to_value(TreeMap::schema()).unwrap();
+        assert_eq!(schema["title"], "TreeMap");
+        assert!(schema["definitions"]["Tree"].is_object());
+        assert!(schema["definitions"]["DefinitionTree"].is_object());
+    }
+
+    #[test]
+    fn test_schema() {
+        let schema = serde_json::to_value(schema()).unwrap();
+        assert_eq!(schema["title"], "DefinitionTree");
+        assert_eq!(
+            schema["$id"],
+            format!(
+                "https://ara-lang.io/schema/parser/{}/DefinitionTree.json",
+                env!("CARGO_PKG_VERSION")
+            )
+        );
+        assert!(schema["definitions"]["Expression"].is_object());
+
+        let fragment = serde_json::to_value(schema_of::<Expression>()).unwrap();
+        assert_eq!(fragment["title"], "Expression");
+        assert!(fragment["$id"]
+            .as_str()
+            .unwrap()
+            .ends_with("/Expression.json"));
     }
 }
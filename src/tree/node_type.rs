@@ -0,0 +1,309 @@
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Map;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use crate::tree::definition::DefinitionTree;
+use crate::tree::kind::NodeKind;
+
+/// The description of a node type, akin to an entry of the `node-types.json` file of tree-sitter.
+///
+/// Struct nodes are described by their fields, enum nodes by their variants, leaves, such as
+/// keywords and literals, have neither.
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct NodeType {
+    #[serde(rename = "type")]
+    pub kind: NodeKind,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub fields: BTreeMap<String, FieldType>,
+    /// The fields of each variant, tuple variants name their fields by their position.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub variants: BTreeMap<String, BTreeMap<String, FieldType>>,
+}
+
+/// The description of a field holding nodes, fields holding only positions, or values, are
+/// omitted.
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct FieldType {
+    /// Whether the field holds a list of nodes, such as comma separated arguments.
+    pub multiple: bool,
+    /// Whether the field always holds a node, lists are never required, as they may be empty.
+    pub required: bool,
+    pub types: BTreeSet<NodeKind>,
+}
+
+/// The description of every node type, ordered by kind.
+///
+/// The description is generated from the JSON schema of the tree, so it can not get out of sync
+/// with the tree definitions.
+pub fn node_types() -> Vec<NodeType> {
+    let mut schema = match serde_json::to_value(schemars::schema_for!(DefinitionTree)) {
+        Ok(Value::Object(schema)) => schema,
+        _ => unreachable!("the schema of a tree is always an object"),
+    };
+
+    let mut definitions = match schema.remove("definitions") {
+        Some(Value::Object(definitions)) => definitions,
+        _ => Map::new(),
+    };
+    definitions.insert("DefinitionTree".to_string(), Value::Object(schema));
+
+    let mut node_types = definitions
+        .iter()
+        .filter_map(|(name, definition)| {
+            let kind = kind(name)?;
+            let mut node_type = NodeType {
+                kind,
+                fields: BTreeMap::new(),
+                variants: BTreeMap::new(),
+            };
+
+            if let Some(variants) = definition.get("oneOf").and_then(Value::as_array) {
+                for variant in variants {
+                    // unit variants are serialized as strings.
+                    if let Some(names) = variant.get("enum").and_then(Value::as_array) {
+                        for name in names.iter().filter_map(Value::as_str) {
+                            node_type.variants.insert(name.to_string(), BTreeMap::new());
+                        }
+
+                        continue;
+                    }
+
+                    let properties = &variant["properties"];
+                    let (name, value) = match properties["type"]["enum"][0].as_str() {
+                        // adjacently tagged variants.
+                        Some(name) => (name.to_string(), &properties["value"]),
+                        // externally tagged variants.
+                        None => match properties.as_object().and_then(|p| p.iter().next()) {
+                            Some((name, value)) => (name.clone(), value),
+                            None => continue,
+                        },
+                    };
+
+                    let fields = if value.get("properties").is_some() {
+                        fields(&definitions, value)
+                    } else if let Some(items) = value.get("items").and_then(Value::as_array) {
+                        positional(&definitions, items)
+                    } else {
+                        positional(&definitions, std::slice::from_ref(value))
+                    };
+
+                    node_type.variants.insert(name, fields);
+                }
+            } else {
+                node_type.fields = fields(&definitions, definition);
+            }
+
+            Some(node_type)
+        })
+        .collect::<Vec<_>>();
+
+    node_types.sort_by_key(|node_type| node_type.kind);
+
+    node_types
+}
+
+/// The kind of the node type with the given name, `None` if the type is not a node, e.g. a
+/// comma separated list.
+fn kind(name: &str) -> Option<NodeKind> {
+    let mut snake = String::with_capacity(name.len() + 8);
+    for (index, character) in name.chars().enumerate() {
+        if character.is_ascii_uppercase() {
+            if index > 0 {
+                snake.push('_');
+            }
+            snake.push(character.to_ascii_lowercase());
+        } else {
+            snake.push(character);
+        }
+    }
+
+    serde_json::from_value(Value::String(snake)).ok()
+}
+
+/// The fields holding nodes of the given struct schema.
+fn fields(definitions: &Map<String, Value>, schema: &Value) -> BTreeMap<String, FieldType> {
+    let required = schema
+        .get("required")
+        .and_then(Value::as_array)
+        .map(|required| {
+            required
+                .iter()
+                .filter_map(Value::as_str)
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    let properties = match schema.get("properties").and_then(Value::as_object) {
+        Some(properties) => properties,
+        None => return BTreeMap::new(),
+    };
+
+    properties
+        .iter()
+        .filter_map(|(name, property)| {
+            field(definitions, property, required.contains(&name.as_str()))
+                .map(|field| (name.clone(), field))
+        })
+        .collect()
+}
+
+/// The fields holding nodes of the given tuple, named by their position.
+fn positional(definitions: &Map<String, Value>, items: &[Value]) -> BTreeMap<String, FieldType> {
+    items
+        .iter()
+        .enumerate()
+        .filter_map(|(index, item)| field(definitions, item, true).map(|f| (index.to_string(), f)))
+        .collect()
+}
+
+/// The description of a field with the given schema, `None` if the field holds no nodes.
+fn field(definitions: &Map<String, Value>, schema: &Value, required: bool) -> Option<FieldType> {
+    let mut types = BTreeSet::new();
+    let mut multiple = false;
+    collect(definitions, schema, &mut types, &mut multiple, &mut vec![]);
+
+    if types.is_empty() {
+        return None;
+    }
+
+    let optional = schema
+        .get("anyOf")
+        .and_then(Value::as_array)
+        .map(|schemas| schemas.iter().any(|schema| schema["type"] == "null"))
+        .unwrap_or(false);
+
+    Some(FieldType {
+        multiple,
+        required: required && !optional && !multiple,
+        types,
+    })
+}
+
+/// Collect the kinds of the nodes held by a value of the given schema, looking through types
+/// that are not nodes, such as comma separated lists.
+fn collect<'a>(
+    definitions: &'a Map<String, Value>,
+    schema: &'a Value,
+    types: &mut BTreeSet<NodeKind>,
+    multiple: &mut bool,
+    visited: &mut Vec<&'a str>,
+) {
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        let name = reference.trim_start_matches("#/definitions/");
+        if let Some(kind) = kind(name) {
+            types.insert(kind);
+        } else if !visited.contains(&name) {
+            visited.push(name);
+            if let Some(definition) = definitions.get(name) {
+                collect(definitions, definition, types, multiple, visited);
+            }
+        }
+
+        return;
+    }
+
+    match schema.get("items") {
+        Some(Value::Array(items)) => {
+            for item in items {
+                collect(definitions, item, types, multiple, visited);
+            }
+        }
+        Some(item) => {
+            let mut items = BTreeSet::new();
+            collect(definitions, item, &mut items, multiple, visited);
+            if !items.is_empty() {
+                *multiple = true;
+                types.extend(items);
+            }
+        }
+        None => {}
+    }
+
+    for key in ["anyOf", "oneOf", "allOf"] {
+        if let Some(schemas) = schema.get(key).and_then(Value::as_array) {
+            for schema in schemas {
+                collect(definitions, schema, types, multiple, visited);
+            }
+        }
+    }
+
+    if let Some(properties) = schema.get("properties").and_then(Value::as_object) {
+        for property in properties.values() {
+            collect(definitions, property, types, multiple, visited);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_node_types() {
+        let node_types = node_types();
+
+        let kinds = serde_json::to_value(schemars::schema_for!(NodeKind)).unwrap();
+        let kinds = kinds["enum"].as_array().unwrap();
+        assert_eq!(node_types.len(), kinds.len());
+
+        let function = node_types
+            .iter()
+            .find(|node_type| node_type.kind == NodeKind::FunctionDefinition)
+            .unwrap();
+
+        assert_eq!(
+            function.fields["name"],
+            FieldType {
+                multiple: false,
+                required: true,
+                types: BTreeSet::from([NodeKind::Identifier]),
+            }
+        );
+        assert!(!function.fields["templates"].required);
+        assert!(function.fields["attributes"].multiple);
+        assert!(!function.fields.contains_key("comments"));
+
+        let arguments = node_types
+            .iter()
+            .find(|node_type| node_type.kind == NodeKind::ArgumentListExpression)
+            .unwrap();
+
+        assert_eq!(
+            arguments.fields["arguments"].types,
+            BTreeSet::from([NodeKind::ArgumentExpression])
+        );
+        assert!(arguments.fields["arguments"].multiple);
+
+        let type_definition = node_types
+            .iter()
+            .find(|node_type| node_type.kind == NodeKind::TypeDefinition)
+            .unwrap();
+
+        assert_eq!(
+            type_definition.variants["nullable"]["1"].types,
+            BTreeSet::from([NodeKind::TypeDefinition])
+        );
+        assert!(!type_definition.variants["nullable"].contains_key("0"));
+
+        let body = node_types
+            .iter()
+            .find(|node_type| node_type.kind == NodeKind::FunctionBodyDefinition)
+            .unwrap();
+
+        assert_eq!(
+            body.variants["concrete"]["0"].types,
+            BTreeSet::from([NodeKind::BlockStatement])
+        );
+        assert!(body.variants["declaration"].is_empty());
+
+        let json = serde_json::to_value(function).unwrap();
+        assert_eq!(json["type"], "function_definition");
+        assert_eq!(json["fields"]["name"]["types"][0], "identifier");
+    }
+}
@@ -0,0 +1,227 @@
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use std::fmt::Display;
+
+use crate::tree::definition::class::ClassDefinitionMember;
+use crate::tree::definition::constant::ClassishConstantDefinition;
+use crate::tree::definition::function::MethodDefinition;
+use crate::tree::definition::interface::InterfaceDefinitionMember;
+use crate::tree::definition::property::PropertyDefinition;
+use crate::tree::definition::r#enum::BackedEnumMemberDefinition;
+use crate::tree::definition::r#enum::EnumDefinition;
+use crate::tree::definition::r#enum::UnitEnumMemberDefinition;
+use crate::tree::definition::Definition;
+use crate::tree::span::Span;
+use crate::tree::Node;
+use crate::tree::Tree;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum OutlineKind {
+    Namespace,
+    Class,
+    Interface,
+    Enum,
+    Function,
+    Constant,
+    TypeAlias,
+    Method,
+    Property,
+    ClassConstant,
+    Case,
+}
+
+/// An item of the outline of a source, e.g. a class, along with the items it contains, e.g. its
+/// methods.
+#[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct OutlineItem {
+    pub kind: OutlineKind,
+    /// The name of the item as written, e.g. `Bar` for `Foo\Bar`, or `$bar` for a property.
+    pub name: String,
+    /// The span of the name of the item.
+    pub name_span: Span,
+    /// The span of the whole definition of the item.
+    pub span: Span,
+    pub children: Vec<OutlineItem>,
+}
+
+/// The outline of the given tree, in order of definition.
+///
+/// Namespaces contain the definitions following them, classes, interfaces, and enums contain
+/// their members, while uses and pragmas are omitted.
+pub fn outline(tree: &Tree) -> Vec<OutlineItem> {
+    definitions(&tree.definitions.definitions)
+}
+
+fn definitions(definitions: &[Definition]) -> Vec<OutlineItem> {
+    definitions.iter().filter_map(definition).collect()
+}
+
+fn definition(definition: &Definition) -> Option<OutlineItem> {
+    let (kind, name, children) = match definition {
+        Definition::Namespace(namespace) => (
+            OutlineKind::Namespace,
+            &namespace.name,
+            self::definitions(&namespace.definitions),
+        ),
+        Definition::Class(class) => (
+            OutlineKind::Class,
+            &class.name,
+            class
+                .body
+                .members
+                .iter()
+                .map(|member| match member {
+                    ClassDefinitionMember::Constant(constant) => self::constant(constant),
+                    ClassDefinitionMember::Property(property) => self::property(property),
+                    ClassDefinitionMember::Method(method) => self::method(method),
+                })
+                .collect(),
+        ),
+        Definition::Interface(interface) => (
+            OutlineKind::Interface,
+            &interface.name,
+            interface
+                .body
+                .members
+                .iter()
+                .map(|member| match member {
+                    InterfaceDefinitionMember::Constant(constant) => self::constant(constant),
+                    InterfaceDefinitionMember::Method(method) => self::method(method),
+                })
+                .collect(),
+        ),
+        Definition::Enum(r#enum) => match r#enum.as_ref() {
+            EnumDefinition::Unit(r#enum) => (
+                OutlineKind::Enum,
+                &r#enum.name,
+                r#enum
+                    .body
+                    .members
+                    .iter()
+                    .map(|member| match member {
+                        UnitEnumMemberDefinition::Case(case) => {
+                            item(OutlineKind::Case, &case.name, case, vec![])
+                        }
+                        UnitEnumMemberDefinition::Method(method) => self::method(method),
+                        UnitEnumMemberDefinition::Constant(constant) => self::constant(constant),
+                    })
+                    .collect(),
+            ),
+            EnumDefinition::Backed(r#enum) => (
+                OutlineKind::Enum,
+                &r#enum.name,
+                r#enum
+                    .body
+                    .members
+                    .iter()
+                    .map(|member| match member {
+                        BackedEnumMemberDefinition::Case(case) => {
+                            item(OutlineKind::Case, &case.name, case, vec![])
+                        }
+                        BackedEnumMemberDefinition::Method(method) => self::method(method),
+                        BackedEnumMemberDefinition::Constant(constant) => self::constant(constant),
+                    })
+                    .collect(),
+            ),
+        },
+        Definition::Function(function) => (OutlineKind::Function, &function.name, vec![]),
+        Definition::Constant(constant) => (OutlineKind::Constant, &constant.name, vec![]),
+        Definition::TypeAlias(alias) => (OutlineKind::TypeAlias, &alias.name.name, vec![]),
+        Definition::Pragma(_) | Definition::Use(_) => return None,
+    };
+
+    Some(item(kind, name, definition, children))
+}
+
+fn method(method: &MethodDefinition) -> OutlineItem {
+    item(OutlineKind::Method, &method.name, method, vec![])
+}
+
+fn property(property: &PropertyDefinition) -> OutlineItem {
+    let variable = property.entry.variable();
+
+    // the name of a variable includes its `$`.
+    OutlineItem {
+        kind: OutlineKind::Property,
+        name: variable.name.to_string(),
+        name_span: variable.span(),
+        span: property.span(),
+        children: vec![],
+    }
+}
+
+fn constant(constant: &ClassishConstantDefinition) -> OutlineItem {
+    item(OutlineKind::ClassConstant, &constant.name, constant, vec![])
+}
+
+fn item<N: Node + Display>(
+    kind: OutlineKind,
+    name: &N,
+    definition: &dyn Node,
+    children: Vec<OutlineItem>,
+) -> OutlineItem {
+    OutlineItem {
+        kind,
+        name: name.to_string(),
+        name_span: name.span(),
+        span: definition.span(),
+        children,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ara_source::source::Source;
+    use ara_source::source::SourceKind;
+
+    use crate::parser;
+
+    #[test]
+    fn test_outline() {
+        let code = "namespace Foo;\n\nuse Bar\\Baz;\n\nfinal class Qux {\n    const int A = 1;\n    private int $b = 2;\n    public function c(): void {}\n}\n\nenum Quux: int {\n    case D = 1;\n}\n\nfunction e(): void {}\n";
+        let tree = parser::parse(&Source::inline(SourceKind::Script, code)).unwrap();
+
+        let outline = outline(&tree);
+        let shape = |items: &[OutlineItem]| {
+            items
+                .iter()
+                .map(|item| (item.kind, item.name.clone(), item.children.len()))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            shape(&outline),
+            vec![(OutlineKind::Namespace, "Foo".to_string(), 3)]
+        );
+
+        let namespace = &outline[0];
+        assert_eq!(
+            shape(&namespace.children),
+            vec![
+                (OutlineKind::Class, "Qux".to_string(), 3),
+                (OutlineKind::Enum, "Quux".to_string(), 1),
+                (OutlineKind::Function, "e".to_string(), 0),
+            ]
+        );
+        assert_eq!(
+            shape(&namespace.children[0].children),
+            vec![
+                (OutlineKind::ClassConstant, "A".to_string(), 0),
+                (OutlineKind::Property, "$b".to_string(), 0),
+                (OutlineKind::Method, "c".to_string(), 0),
+            ]
+        );
+
+        let class = &namespace.children[0];
+        assert_eq!(&code[class.name_span.start..class.name_span.end], "Qux");
+        assert!(code[class.span.start..class.span.end].starts_with("final class Qux {"));
+        assert_eq!(
+            &code[class.children[1].span.start..class.children[1].span.end],
+            "private int $b = 2;"
+        );
+    }
+}
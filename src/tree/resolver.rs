@@ -0,0 +1,241 @@
+use std::collections::HashMap;
+
+use crate::tree::definition::r#use::UseDefinition;
+use crate::tree::definition::r#use::UseDefinitionGroupMember;
+use crate::tree::definition::r#use::UseDefinitionSymbolAlias;
+use crate::tree::definition::Definition;
+use crate::tree::identifier::Identifier;
+use crate::tree::Node;
+use crate::tree::Tree;
+
+/// The kind of symbol a name refers to, each kind of symbol is imported separately.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum NameKind {
+    Class,
+    Function,
+    Constant,
+}
+
+/// Resolves names occurring in a tree to fully-qualified names, using the namespace and the
+/// `use` definitions in effect where the name occurs.
+///
+/// Names are resolved as follows:
+///
+/// - fully-qualified names, e.g. `\Foo\Bar`, are kept as is.
+/// - names relative to the current namespace, e.g. `namespace\Bar`, are prefixed with it.
+/// - qualified names, e.g. `Foo\Bar`, have their first segment replaced if it matches a class
+///   import, and are prefixed with the current namespace otherwise.
+/// - unqualified names, e.g. `Bar`, are replaced if they match an import of the same kind, and
+///   are prefixed with the current namespace otherwise.
+///
+/// Resolved names never have a leading backslash. Note that unqualified function and constant
+/// names that are not imported fall back to the global namespace at runtime if they are not
+/// defined in the current namespace, which can not be determined from a single tree.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct NameResolver {
+    scopes: Vec<Scope>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+struct Scope {
+    /// The position at which the scope starts, it ends where the next scope starts.
+    start: usize,
+    namespace: String,
+    imports: HashMap<(NameKind, String), String>,
+}
+
+impl NameResolver {
+    pub fn new(tree: &Tree) -> Self {
+        let mut global = Scope::default();
+        let mut scopes = vec![];
+
+        for definition in &tree.definitions.definitions {
+            match definition {
+                Definition::Namespace(namespace) => {
+                    let mut scope = Scope {
+                        start: namespace.initial_position(),
+                        namespace: trim(&namespace.name.value.to_string()).to_string(),
+                        imports: HashMap::new(),
+                    };
+
+                    for definition in &namespace.definitions {
+                        if let Definition::Use(r#use) = definition {
+                            scope.import(r#use);
+                        }
+                    }
+
+                    scopes.push(scope);
+                }
+                Definition::Use(r#use) => global.import(r#use),
+                _ => {}
+            }
+        }
+
+        scopes.insert(0, global);
+
+        Self { scopes }
+    }
+
+    /// Resolve the given identifier, occurring in the tree, to a fully-qualified name.
+    pub fn resolve(&self, identifier: &Identifier, kind: NameKind) -> String {
+        self.resolve_name(identifier.position, &identifier.value.to_string(), kind)
+    }
+
+    /// Resolve the given name, occurring at the given position in the tree, to a fully-qualified name.
+    pub fn resolve_name(&self, position: usize, name: &str, kind: NameKind) -> String {
+        let scope = self
+            .scopes
+            .iter()
+            .rev()
+            .find(|scope| scope.start <= position)
+            .unwrap_or(&self.scopes[0]);
+
+        if let Some(name) = name.strip_prefix('\\') {
+            return name.to_string();
+        }
+
+        if let Some((first, rest)) = name.split_once('\\') {
+            if first.eq_ignore_ascii_case("namespace") {
+                return scope.qualify(rest);
+            }
+
+            return match scope
+                .imports
+                .get(&(NameKind::Class, first.to_ascii_lowercase()))
+            {
+                Some(import) => format!("{import}\\{rest}"),
+                None => scope.qualify(name),
+            };
+        }
+
+        let key = match kind {
+            NameKind::Constant => name.to_string(),
+            _ => name.to_ascii_lowercase(),
+        };
+
+        match scope.imports.get(&(kind, key)) {
+            Some(import) => import.clone(),
+            None => scope.qualify(name),
+        }
+    }
+}
+
+impl Scope {
+    fn import(&mut self, definition: &UseDefinition) {
+        match definition {
+            UseDefinition::Default { name, alias, .. } => {
+                self.add(NameKind::Class, name.value.to_string(), alias);
+            }
+            UseDefinition::Function { name, alias, .. } => {
+                self.add(NameKind::Function, name.value.to_string(), alias);
+            }
+            UseDefinition::Constant { name, alias, .. } => {
+                self.add(NameKind::Constant, name.value.to_string(), alias);
+            }
+            UseDefinition::Group {
+                prefix, members, ..
+            } => {
+                let prefix = trim(&prefix.value.to_string()).to_string();
+                for member in &members.inner {
+                    let (kind, name, alias) = match member {
+                        UseDefinitionGroupMember::Default { name, alias } => {
+                            (NameKind::Class, name, alias)
+                        }
+                        UseDefinitionGroupMember::Function { name, alias, .. } => {
+                            (NameKind::Function, name, alias)
+                        }
+                        UseDefinitionGroupMember::Constant { name, alias, .. } => {
+                            (NameKind::Constant, name, alias)
+                        }
+                    };
+
+                    self.add(kind, format!("{}\\{}", prefix, name.value), alias);
+                }
+            }
+        }
+    }
+
+    fn add(&mut self, kind: NameKind, name: String, alias: &Option<UseDefinitionSymbolAlias>) {
+        let name = trim(&name).to_string();
+        let alias = match alias {
+            Some(alias) => alias.alias.value.to_string(),
+            None => name.rsplit('\\').next().unwrap_or(&name).to_string(),
+        };
+
+        let alias = match kind {
+            NameKind::Constant => alias,
+            _ => alias.to_ascii_lowercase(),
+        };
+
+        self.imports.insert((kind, alias), name);
+    }
+
+    fn qualify(&self, name: &str) -> String {
+        if self.namespace.is_empty() {
+            name.to_string()
+        } else {
+            format!("{}\\{}", self.namespace, name)
+        }
+    }
+}
+
+fn trim(name: &str) -> &str {
+    name.trim_matches('\\')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ara_source::source::Source;
+    use ara_source::source::SourceKind;
+
+    use crate::parser;
+
+    #[test]
+    fn test_name_resolver() {
+        let code = r#"
+namespace Foo;
+
+use Bar\Baz;
+use Bar\Qux as Quux;
+use function Bar\helper;
+use const Bar\MAX;
+use Corge\{Grault, Garply\Waldo as Fred, function other, const MIN};
+
+function foo(): void {}
+
+namespace Plugh;
+
+function bar(): void {}
+"#;
+
+        let source = Source::inline(SourceKind::Script, code);
+        let tree = parser::parse(&source).unwrap();
+        let resolver = NameResolver::new(&tree);
+
+        let foo = code.find("function foo").unwrap();
+        let resolve = |name: &str, kind: NameKind| resolver.resolve_name(foo, name, kind);
+
+        assert_eq!(resolve("Baz", NameKind::Class), "Bar\\Baz");
+        assert_eq!(resolve("baz", NameKind::Class), "Bar\\Baz");
+        assert_eq!(resolve("Quux", NameKind::Class), "Bar\\Qux");
+        assert_eq!(resolve("Quux\\Inner", NameKind::Class), "Bar\\Qux\\Inner");
+        assert_eq!(resolve("Grault", NameKind::Class), "Corge\\Grault");
+        assert_eq!(resolve("Fred", NameKind::Class), "Corge\\Garply\\Waldo");
+        assert_eq!(resolve("helper", NameKind::Function), "Bar\\helper");
+        assert_eq!(resolve("other", NameKind::Function), "Corge\\other");
+        assert_eq!(resolve("MAX", NameKind::Constant), "Bar\\MAX");
+        assert_eq!(resolve("max", NameKind::Constant), "Foo\\max");
+        assert_eq!(resolve("MIN", NameKind::Constant), "Corge\\MIN");
+        assert_eq!(resolve("helper", NameKind::Class), "Foo\\helper");
+        assert_eq!(resolve("\\Baz", NameKind::Class), "Baz");
+        assert_eq!(resolve("namespace\\Baz", NameKind::Class), "Foo\\Baz");
+        assert_eq!(resolve("Other\\Baz", NameKind::Class), "Foo\\Other\\Baz");
+
+        let bar = code.find("function bar").unwrap();
+        assert_eq!(
+            resolver.resolve_name(bar, "Baz", NameKind::Class),
+            "Plugh\\Baz"
+        );
+    }
+}
@@ -0,0 +1,354 @@
+use std::fmt::Display;
+
+use crate::lexer::byte_string::ByteString;
+use crate::tree::downcast;
+use crate::tree::expression::literal::LiteralFloat;
+use crate::tree::expression::literal::LiteralInteger;
+use crate::tree::expression::literal::LiteralString;
+use crate::tree::identifier::Identifier;
+use crate::tree::kind::NodeKind;
+use crate::tree::token::Keyword;
+use crate::tree::variable::Variable;
+use crate::tree::Node;
+
+/// A selector matching nodes by kind, fields, and position relative to other nodes.
+///
+/// A selector is a sequence of compound selectors, separated by combinators:
+///
+/// - `kind` matches nodes of the given kind, using the snake case name of the `NodeKind`,
+///   or any node when `*` is used.
+/// - `[field]` matches nodes with a child held by the given field.
+/// - `[field="value"]` matches nodes with a child held by the given field, whose text is the
+///   given value, only identifiers, variables, keywords and literals have a text.
+/// - `a b` matches nodes matching `b` that are descendants of a node matching `a`.
+/// - `a > b` matches nodes matching `b` that are children of a node matching `a`.
+///
+/// The tree contains wrapper nodes, such as `definition` or `class_definition_member`, which
+/// have to be accounted for when using the child combinator.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Selector {
+    compounds: Vec<Compound>,
+    combinators: Vec<Combinator>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct Compound {
+    kind: Option<NodeKind>,
+    fields: Vec<(String, Option<String>)>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Combinator {
+    Descendant,
+    Child,
+}
+
+/// An error in the syntax of a selector.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SelectorError {
+    /// The byte offset within the selector at which the error occurred.
+    pub position: usize,
+    pub message: String,
+}
+
+impl Display for SelectorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} at position {}", self.message, self.position)
+    }
+}
+
+impl std::error::Error for SelectorError {}
+
+impl Selector {
+    pub fn parse(selector: &str) -> Result<Self, SelectorError> {
+        let mut parser = SelectorParser {
+            bytes: selector.as_bytes(),
+            position: 0,
+        };
+
+        let mut compounds = vec![parser.compound()?];
+        let mut combinators = vec![];
+        loop {
+            let skipped = parser.whitespace();
+            if parser.is_eof() {
+                break;
+            }
+
+            if parser.eat(b'>') {
+                parser.whitespace();
+                combinators.push(Combinator::Child);
+            } else if skipped {
+                combinators.push(Combinator::Descendant);
+            } else {
+                return Err(parser.error("expected a combinator"));
+            }
+
+            compounds.push(parser.compound()?);
+        }
+
+        Ok(Self {
+            compounds,
+            combinators,
+        })
+    }
+
+    /// All nodes below the given root, including the root itself, matching the selector,
+    /// in pre-order.
+    pub fn select<'a>(&self, root: &'a dyn Node) -> Vec<&'a dyn Node> {
+        let mut selected = vec![];
+        let mut ancestors: Vec<&'a dyn Node> = vec![];
+        let mut stack = vec![(root, 0)];
+
+        while let Some((node, depth)) = stack.pop() {
+            ancestors.truncate(depth);
+
+            if self.matches(self.compounds.len() - 1, node, &ancestors) {
+                selected.push(node);
+            }
+
+            ancestors.push(node);
+
+            stack.extend(
+                node.children()
+                    .into_iter()
+                    .rev()
+                    .map(|child| (child, depth + 1)),
+            );
+        }
+
+        selected
+    }
+
+    fn matches(&self, index: usize, node: &dyn Node, ancestors: &[&dyn Node]) -> bool {
+        if !self.compounds[index].matches(node) {
+            return false;
+        }
+
+        if index == 0 {
+            return true;
+        }
+
+        match self.combinators[index - 1] {
+            Combinator::Child => match ancestors.split_last() {
+                Some((parent, rest)) => self.matches(index - 1, *parent, rest),
+                None => false,
+            },
+            Combinator::Descendant => (0..ancestors.len())
+                .rev()
+                .any(|i| self.matches(index - 1, ancestors[i], &ancestors[..i])),
+        }
+    }
+}
+
+impl Compound {
+    fn matches(&self, node: &dyn Node) -> bool {
+        if let Some(kind) = self.kind {
+            if node.kind() != kind {
+                return false;
+            }
+        }
+
+        self.fields.iter().all(|(field, value)| {
+            node.named_children()
+                .into_iter()
+                .filter(|(name, _)| name == field)
+                .any(|(_, child)| match value {
+                    Some(value) => {
+                        text(child).is_some_and(|text| text.as_bytes() == value.as_bytes())
+                    }
+                    None => true,
+                })
+        })
+    }
+}
+
+/// The text of the given node, if it is an identifier, a variable, a keyword, or a literal.
+fn text(node: &dyn Node) -> Option<&ByteString> {
+    match node.kind() {
+        NodeKind::Identifier => downcast::<Identifier>(node).map(|node| &node.value),
+        NodeKind::Variable => downcast::<Variable>(node).map(|node| &node.name),
+        NodeKind::Keyword => downcast::<Keyword>(node).map(|node| &node.value),
+        NodeKind::LiteralString => downcast::<LiteralString>(node).map(|node| &node.value),
+        NodeKind::LiteralInteger => downcast::<LiteralInteger>(node).map(|node| &node.value),
+        NodeKind::LiteralFloat => downcast::<LiteralFloat>(node).map(|node| &node.value),
+        _ => None,
+    }
+}
+
+struct SelectorParser<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> SelectorParser<'a> {
+    fn compound(&mut self) -> Result<Compound, SelectorError> {
+        let kind = if self.eat(b'*') {
+            None
+        } else {
+            let start = self.position;
+            let name = self.name();
+            if name.is_empty() {
+                return Err(self.error("expected a node kind or `*`"));
+            }
+
+            let kind = serde_json::from_value(serde_json::Value::String(name.to_string()))
+                .map_err(|_| SelectorError {
+                    position: start,
+                    message: format!("unknown node kind `{name}`"),
+                })?;
+
+            Some(kind)
+        };
+
+        let mut fields = vec![];
+        while self.eat(b'[') {
+            let field = self.name();
+            if field.is_empty() {
+                return Err(self.error("expected a field name"));
+            }
+
+            let value = if self.eat(b'=') {
+                Some(self.string()?)
+            } else {
+                None
+            };
+
+            if !self.eat(b']') {
+                return Err(self.error("expected `]`"));
+            }
+
+            fields.push((field.to_string(), value));
+        }
+
+        Ok(Compound { kind, fields })
+    }
+
+    fn name(&mut self) -> &'a str {
+        let start = self.position;
+        while matches!(self.peek(), Some(b'a'..=b'z' | b'0'..=b'9' | b'_')) {
+            self.position += 1;
+        }
+
+        // the name only contains ASCII bytes, so it is always valid UTF-8.
+        std::str::from_utf8(&self.bytes[start..self.position]).unwrap()
+    }
+
+    fn string(&mut self) -> Result<String, SelectorError> {
+        if !self.eat(b'"') {
+            return Err(self.error("expected `\"`"));
+        }
+
+        let mut value = vec![];
+        loop {
+            match self.peek() {
+                Some(b'"') => {
+                    self.position += 1;
+
+                    break;
+                }
+                Some(b'\\') if matches!(self.bytes.get(self.position + 1), Some(b'"' | b'\\')) => {
+                    value.push(self.bytes[self.position + 1]);
+                    self.position += 2;
+                }
+                Some(byte) => {
+                    value.push(byte);
+                    self.position += 1;
+                }
+                None => return Err(self.error("unterminated string")),
+            }
+        }
+
+        Ok(String::from_utf8_lossy(&value).into_owned())
+    }
+
+    fn whitespace(&mut self) -> bool {
+        let start = self.position;
+        while matches!(self.peek(), Some(byte) if byte.is_ascii_whitespace()) {
+            self.position += 1;
+        }
+
+        self.position > start
+    }
+
+    fn eat(&mut self, byte: u8) -> bool {
+        if self.peek() == Some(byte) {
+            self.position += 1;
+
+            true
+        } else {
+            false
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.position).copied()
+    }
+
+    fn is_eof(&self) -> bool {
+        self.position >= self.bytes.len()
+    }
+
+    fn error(&self, message: &str) -> SelectorError {
+        SelectorError {
+            position: self.position,
+            message: message.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ara_source::source::Source;
+    use ara_source::source::SourceKind;
+
+    use crate::parser;
+    use crate::tree;
+
+    #[test]
+    fn test_selector() {
+        let code = "final class Foo { public function __construct() {} public function bar(): void {} }\nfunction __construct(): void {}";
+        let source = Source::inline(SourceKind::Script, code);
+        let tree = parser::parse(&source).unwrap();
+
+        let names = |selector: &str| {
+            tree::query(&tree, selector)
+                .unwrap()
+                .iter()
+                .map(|node| {
+                    node.named_children()
+                        .into_iter()
+                        .find(|(name, _)| *name == "name")
+                        .and_then(|(_, name)| text(name))
+                        .map(|name| name.to_string())
+                        .unwrap_or_default()
+                })
+                .collect::<Vec<String>>()
+        };
+
+        assert_eq!(
+            names(r#"class_definition method_definition[name="__construct"]"#),
+            vec!["__construct"]
+        );
+        assert_eq!(
+            names("class_definition_body > class_definition_member > method_definition"),
+            vec!["__construct", "bar"]
+        );
+        assert_eq!(names("function_definition[name]"), vec!["__construct"]);
+        assert_eq!(
+            names("definition > *[modifiers]"),
+            vec!["Foo", "__construct"]
+        );
+        assert!(names("interface_definition").is_empty());
+
+        assert_eq!(
+            Selector::parse("class_definition >").unwrap_err().position,
+            18
+        );
+        assert_eq!(
+            Selector::parse("klass").unwrap_err().message,
+            "unknown node kind `klass`"
+        );
+        assert!(Selector::parse(r#"*[name="foo]"#).is_err());
+    }
+}
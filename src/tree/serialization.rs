@@ -0,0 +1,203 @@
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::tree::Node;
+
+/// Options controlling what the serialization of a node includes.
+///
+/// The full serialization includes every position and comment, while the compact one only
+/// keeps the structure of the tree, for consumers that do not need to map nodes back to the
+/// source, and care about the size of the output.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct SerializationOptions {
+    /// Whether to include comments.
+    pub comments: bool,
+    /// Whether to include positions.
+    ///
+    /// Without positions, positions held in a list, such as the positions of commas, are removed,
+    /// while others are collapsed to `0`, so that optional tokens can still be told apart.
+    pub positions: bool,
+}
+
+impl SerializationOptions {
+    pub fn full() -> Self {
+        Self {
+            comments: true,
+            positions: true,
+        }
+    }
+
+    pub fn compact() -> Self {
+        Self {
+            comments: false,
+            positions: false,
+        }
+    }
+}
+
+impl Default for SerializationOptions {
+    fn default() -> Self {
+        Self::full()
+    }
+}
+
+/// Serialize the given node, or tree, to a JSON value using the given options.
+///
+/// The compact output is not meant to be deserialized back into a tree.
+pub fn to_value<T: Serialize + ?Sized>(
+    node: &T,
+    options: SerializationOptions,
+) -> serde_json::Result<Value> {
+    let mut value = serde_json::to_value(node)?;
+    strip(&mut value, options);
+
+    Ok(value)
+}
+
+/// Serialize the given node to a JSON value using the given options, where the object of
+/// each node additionally carries the source text of its span under the `source` key.
+///
+/// The given source must be the content the node was parsed from, so that debugging tools
+/// and error reporters can show the code of a node without keeping the original file around.
+///
+/// The annotated output is not meant to be deserialized back into a tree.
+pub fn to_annotated_value(
+    node: &dyn Node,
+    source: &str,
+    options: SerializationOptions,
+) -> serde_json::Result<Value> {
+    let mut value = node.to_value(SerializationOptions::full())?;
+    annotate(node, &mut value, source.as_bytes())?;
+    strip(&mut value, options);
+
+    Ok(value)
+}
+
+/// Add the source text of the given node, and of each of its descendants, to its serialized value.
+///
+/// Children are matched to the part of the value they were serialized to by equality, a child
+/// that was already annotated no longer matches, so children that serialize the same are
+/// matched in order.
+fn annotate(node: &dyn Node, value: &mut Value, source: &[u8]) -> serde_json::Result<()> {
+    for child in node.children() {
+        let expected = child.to_value(SerializationOptions::full())?;
+        if let Some(child_value) = find(value, &expected) {
+            annotate(child, child_value, source)?;
+        }
+    }
+
+    if let Value::Object(fields) = value {
+        let span = node.span();
+        let text = source.get(span.start..span.end).unwrap_or_default();
+
+        fields.insert(
+            "source".to_string(),
+            Value::String(String::from_utf8_lossy(text).into_owned()),
+        );
+    }
+
+    Ok(())
+}
+
+/// The first value within the given value, in pre-order, equal to the expected value.
+fn find<'a>(value: &'a mut Value, expected: &Value) -> Option<&'a mut Value> {
+    if value == expected {
+        return Some(value);
+    }
+
+    match value {
+        Value::Array(items) => items.iter_mut().find_map(|item| find(item, expected)),
+        Value::Object(fields) => fields.values_mut().find_map(|field| find(field, expected)),
+        _ => None,
+    }
+}
+
+/// Strip the comments and positions from a serialized node, as configured by the given options.
+///
+/// Every number within a serialized node is a position.
+fn strip(value: &mut Value, options: SerializationOptions) {
+    match value {
+        Value::Number(_) if !options.positions => *value = Value::from(0),
+        Value::Array(items) => {
+            if !options.positions {
+                items.retain(|item| !item.is_number());
+            }
+
+            items.iter_mut().for_each(|item| strip(item, options));
+        }
+        Value::Object(fields) => {
+            if !options.comments {
+                fields.remove("comments");
+            }
+
+            fields.values_mut().for_each(|field| strip(field, options));
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ara_source::source::Source;
+    use ara_source::source::SourceKind;
+
+    use crate::parser;
+
+    #[test]
+    fn test_serialization_options() {
+        let code = "// foo\nfunction foo(int $a, int ...$b): void {}";
+        let source = Source::inline(SourceKind::Script, code);
+        let tree = parser::parse(&source).unwrap();
+
+        let full = to_value(&tree.definitions, SerializationOptions::full()).unwrap();
+        assert_eq!(full, serde_json::to_value(&tree.definitions).unwrap());
+
+        let compact = to_value(&tree.definitions, SerializationOptions::compact()).unwrap();
+        assert!(compact.to_string().len() < full.to_string().len());
+        assert!(!compact.to_string().contains("// foo"));
+
+        let function = &compact["definitions"][0]["value"];
+        assert_eq!(
+            function["name"],
+            serde_json::json!({"position": 0, "value": "foo"})
+        );
+
+        let parameters = &function["parameters"]["parameters"];
+        assert_eq!(parameters["commas"], serde_json::json!([]));
+        assert_eq!(parameters["inner"][0]["ellipsis"], Value::Null);
+        assert_eq!(parameters["inner"][1]["ellipsis"], 0);
+    }
+
+    #[test]
+    fn test_annotated_serialization() {
+        let code = "function foo(): int { return $a + $a; }";
+        let source = Source::inline(SourceKind::Script, code);
+        let tree = parser::parse(&source).unwrap();
+
+        let annotated =
+            to_annotated_value(&tree.definitions, code, SerializationOptions::full()).unwrap();
+
+        let definition = &annotated["definitions"][0];
+        assert_eq!(definition["source"], code);
+
+        let function = &definition["value"];
+        assert_eq!(function["source"], code);
+        assert_eq!(function["name"]["source"], "foo");
+        assert_eq!(function["return_type"]["source"], ": int");
+
+        let r#return = &function["body"]["concrete"]["statements"][0];
+        assert_eq!(r#return["source"], "return $a + $a;");
+
+        let addition = &r#return["value"]["value"]["expression"];
+        assert_eq!(addition["source"], "$a + $a");
+        assert_eq!(addition["value"]["value"]["left"]["source"], "$a");
+        assert_eq!(addition["value"]["value"]["right"]["source"], "$a");
+        assert_eq!(addition["value"]["value"]["right"]["value"]["position"], 34);
+
+        let compact =
+            to_annotated_value(&tree.definitions, code, SerializationOptions::compact()).unwrap();
+        assert_eq!(compact["definitions"][0]["value"]["name"]["source"], "foo");
+        assert_eq!(compact["definitions"][0]["value"]["name"]["position"], 0);
+    }
+}
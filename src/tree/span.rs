@@ -0,0 +1,183 @@
+use bincode::Decode;
+use bincode::Encode;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A range of bytes in the source, from `start` ( inclusive ) to `end` ( exclusive ).
+#[derive(
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Clone,
+    Copy,
+    Hash,
+    Default,
+    Deserialize,
+    Serialize,
+    Encode,
+    Decode,
+    JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    pub fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+
+    pub fn len(&self) -> usize {
+        self.end.saturating_sub(self.start)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Whether the given byte offset falls within the span.
+    pub fn contains(&self, offset: usize) -> bool {
+        self.start <= offset && offset < self.end
+    }
+
+    /// Whether the given span lies entirely within this span.
+    pub fn encloses(&self, other: &Span) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    /// Whether both spans share at least one byte.
+    pub fn intersects(&self, other: &Span) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+
+    /// The smallest span covering both spans.
+    pub fn union(&self, other: &Span) -> Span {
+        Span::new(self.start.min(other.start), self.end.max(other.end))
+    }
+}
+
+impl std::fmt::Display for Span {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}..{}", self.start, self.end)
+    }
+}
+
+/// A location in the source, as a line and a column, both starting at 1.
+///
+/// The column counts characters, not bytes, from the start of the line.
+#[derive(
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Clone,
+    Copy,
+    Hash,
+    Default,
+    Deserialize,
+    Serialize,
+    JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl std::fmt::Display for Location {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+/// The start positions of the lines of a source, converting byte offsets into locations.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct LineIndex<'a> {
+    content: &'a str,
+    starts: Vec<usize>,
+}
+
+impl<'a> LineIndex<'a> {
+    pub fn new(content: &'a str) -> Self {
+        let starts = std::iter::once(0)
+            .chain(
+                content
+                    .bytes()
+                    .enumerate()
+                    .filter(|(_, byte)| *byte == b'\n')
+                    .map(|(position, _)| position + 1),
+            )
+            .collect();
+
+        Self { content, starts }
+    }
+
+    /// The number of lines in the source.
+    pub fn lines(&self) -> usize {
+        self.starts.len()
+    }
+
+    /// The location of the given byte offset, offsets past the end of the source are located
+    /// at its end.
+    pub fn location(&self, offset: usize) -> Location {
+        let offset = offset.min(self.content.len());
+        let line = self.starts.partition_point(|start| *start <= offset) - 1;
+        let prefix = &self.content.as_bytes()[self.starts[line]..offset];
+
+        Location {
+            line: line + 1,
+            column: String::from_utf8_lossy(prefix).chars().count() + 1,
+        }
+    }
+
+    /// The locations of the start and the end of the given span.
+    pub fn span(&self, span: &Span) -> (Location, Location) {
+        (self.location(span.start), self.location(span.end))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_span() {
+        let span = Span::new(4, 10);
+
+        assert_eq!(span.len(), 6);
+        assert!(span.contains(4));
+        assert!(!span.contains(10));
+        assert!(span.encloses(&Span::new(5, 10)));
+        assert!(span.intersects(&Span::new(9, 12)));
+        assert!(!span.intersects(&Span::new(10, 12)));
+        assert_eq!(span.union(&Span::new(12, 14)), Span::new(4, 14));
+        assert!(Span::new(3, 3).is_empty());
+        assert_eq!(span.to_string(), "4..10");
+    }
+    #[test]
+    fn test_line_index() {
+        let index = LineIndex::new("foo\nbär baz\n\nqux");
+
+        assert_eq!(index.lines(), 4);
+        assert_eq!(index.location(0), Location { line: 1, column: 1 });
+        assert_eq!(index.location(3), Location { line: 1, column: 4 });
+        assert_eq!(index.location(4), Location { line: 2, column: 1 });
+        assert_eq!(index.location(9), Location { line: 2, column: 5 });
+        assert_eq!(index.location(14), Location { line: 4, column: 1 });
+        assert_eq!(index.location(100), Location { line: 4, column: 4 });
+        assert_eq!(
+            index.span(&Span::new(9, 12)),
+            (
+                Location { line: 2, column: 5 },
+                Location { line: 2, column: 8 }
+            )
+        );
+        assert_eq!(index.location(9).to_string(), "2:5");
+    }
+}
@@ -5,6 +5,8 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use crate::tree::comment::CommentGroup;
+use crate::tree::kind::NodeKind;
+use crate::tree::span::Span;
 use crate::tree::statement::Statement;
 use crate::tree::Node;
 
@@ -22,21 +24,34 @@ impl Node for BlockStatement {
         Some(&self.comments)
     }
 
-    fn initial_position(&self) -> usize {
-        self.left_brace
+    fn span(&self) -> Span {
+        Span::new(self.left_brace, self.right_brace + 1)
     }
 
-    fn final_position(&self) -> usize {
-        self.right_brace + 1
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        self.statements
+            .iter()
+            .map(|statement| ("statements", statement as &dyn Node))
+            .collect()
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         self.statements
-            .iter()
-            .map(|statement| statement as &dyn Node)
+            .iter_mut()
+            .map(|statement| statement as &mut dyn Node)
             .collect()
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        let mut positions: Vec<&mut usize> = vec![&mut self.left_brace, &mut self.right_brace];
+        positions.extend(self.comments.positions_mut());
+        positions
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::BlockStatement
+    }
+
     fn get_description(&self) -> String {
         "block statement".to_string()
     }
@@ -6,9 +6,12 @@ use serde::Serialize;
 
 use crate::tree::comment::CommentGroup;
 use crate::tree::expression::Expression;
+use crate::tree::kind::NodeKind;
+use crate::tree::span::Span;
 use crate::tree::statement::block::BlockStatement;
 use crate::tree::token::Keyword;
 use crate::tree::utils::CommaSeparated;
+use crate::tree::utils::ListView;
 use crate::tree::variable::Variable;
 use crate::tree::Node;
 
@@ -79,32 +82,71 @@ impl Node for IfStatement {
         Some(&self.comments)
     }
 
-    fn initial_position(&self) -> usize {
-        self.r#if.initial_position()
-    }
+    fn span(&self) -> Span {
+        let final_position = match (&self.r#else, self.elseifs.last()) {
+            (Some(r#else), _) => r#else.final_position(),
+            (None, Some(elseif)) => elseif.final_position(),
+            (None, None) => self.block.final_position(),
+        };
 
-    fn final_position(&self) -> usize {
-        self.block.final_position()
+        Span::new(self.r#if.initial_position(), final_position)
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        let mut children: Vec<&dyn Node> = vec![&self.r#if, &self.block];
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut children: Vec<(&'static str, &dyn Node)> = vec![("if", &self.r#if)];
 
         for condition in &self.conditions.inner {
-            children.push(condition);
+            children.push(("conditions", condition));
         }
 
+        children.push(("block", &self.block));
+
         for elseif in &self.elseifs {
-            children.push(elseif);
+            children.push(("elseifs", elseif));
         }
 
         if let Some(r#else) = &self.r#else {
+            children.push(("else", r#else));
+        }
+
+        children
+    }
+
+    fn lists(&self) -> Vec<ListView<'_>> {
+        vec![self.conditions.view()]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = vec![&mut self.r#if];
+
+        for condition in &mut self.conditions.inner {
+            children.push(condition);
+        }
+
+        children.push(&mut self.block);
+
+        for elseif in &mut self.elseifs {
+            children.push(elseif);
+        }
+
+        if let Some(r#else) = &mut self.r#else {
             children.push(r#else);
         }
 
         children
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        let mut positions: Vec<&mut usize> = vec![];
+        positions.extend(self.comments.positions_mut());
+        positions.extend(self.conditions.commas.iter_mut());
+        positions
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::IfStatement
+    }
+
     fn get_description(&self) -> String {
         "if statement".to_string()
     }
@@ -115,16 +157,28 @@ impl Node for IfElseIfStatement {
         Some(&self.comments)
     }
 
-    fn initial_position(&self) -> usize {
-        self.elseif.initial_position()
+    fn span(&self) -> Span {
+        Span::new(self.elseif.initial_position(), self.block.final_position())
+    }
+
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![
+            ("elseif", &self.elseif),
+            ("condition", &self.condition),
+            ("block", &self.block),
+        ]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        vec![&mut self.elseif, &mut self.condition, &mut self.block]
     }
 
-    fn final_position(&self) -> usize {
-        self.block.final_position()
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        self.comments.positions_mut().collect()
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        vec![&self.elseif, &self.condition, &self.block]
+    fn kind(&self) -> NodeKind {
+        NodeKind::IfElseIfStatement
     }
 
     fn get_description(&self) -> String {
@@ -137,24 +191,37 @@ impl Node for IfElseStatement {
         Some(&self.comments)
     }
 
-    fn initial_position(&self) -> usize {
-        self.r#else.initial_position()
+    fn span(&self) -> Span {
+        let final_position = match &self.block {
+            IfElseBlockStatement::If(r#if) => r#if.final_position(),
+            IfElseBlockStatement::Block(block) => block.final_position(),
+        };
+
+        Span::new(self.r#else.initial_position(), final_position)
     }
 
-    fn final_position(&self) -> usize {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
         match &self.block {
-            IfElseBlockStatement::If(r#if) => r#if.final_position(),
-            IfElseBlockStatement::Block(block) => block.final_position(),
+            IfElseBlockStatement::If(r#if) => vec![("else", &self.r#else), ("if", r#if.as_ref())],
+            IfElseBlockStatement::Block(block) => vec![("else", &self.r#else), ("block", block)],
         }
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        match &self.block {
-            IfElseBlockStatement::If(r#if) => vec![&self.r#else, r#if.as_ref()],
-            IfElseBlockStatement::Block(block) => vec![&self.r#else, block],
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match &mut self.block {
+            IfElseBlockStatement::If(r#if) => vec![&mut self.r#else, r#if.as_mut()],
+            IfElseBlockStatement::Block(block) => vec![&mut self.r#else, block],
         }
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        self.comments.positions_mut().collect()
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::IfElseStatement
+    }
+
     fn get_description(&self) -> String {
         "else statement".to_string()
     }
@@ -165,29 +232,55 @@ impl Node for UsingStatement {
         Some(&self.comments)
     }
 
-    fn initial_position(&self) -> usize {
-        self.r#using.initial_position()
+    fn span(&self) -> Span {
+        Span::new(self.r#using.initial_position(), self.block.final_position())
     }
 
-    fn final_position(&self) -> usize {
-        self.block.final_position()
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut children: Vec<(&'static str, &dyn Node)> = vec![("using", &self.r#using)];
+        for assignment in &self.assignments.inner {
+            children.push(("assignments", assignment));
+        }
+
+        if let Some(if_clause) = &self.if_clause {
+            children.push(("if_clause", if_clause));
+        }
+
+        children.push(("block", &self.block));
+
+        children
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        let mut children: Vec<&dyn Node> = vec![&self.r#using];
-        for assignment in &self.assignments.inner {
+    fn lists(&self) -> Vec<ListView<'_>> {
+        vec![self.assignments.view()]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = vec![&mut self.r#using];
+        for assignment in &mut self.assignments.inner {
             children.push(assignment);
         }
 
-        if let Some(if_clause) = &self.if_clause {
+        if let Some(if_clause) = &mut self.if_clause {
             children.push(if_clause);
         }
 
-        children.push(&self.block);
+        children.push(&mut self.block);
 
         children
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        let mut positions: Vec<&mut usize> = vec![];
+        positions.extend(self.comments.positions_mut());
+        positions.extend(self.assignments.commas.iter_mut());
+        positions
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::UsingStatement
+    }
+
     fn get_description(&self) -> String {
         "using statement".to_string()
     }
@@ -198,16 +291,32 @@ impl Node for UsingAssignmentStatement {
         Some(&self.comments)
     }
 
-    fn initial_position(&self) -> usize {
-        self.variable.initial_position()
+    fn span(&self) -> Span {
+        Span::new(
+            self.variable.initial_position(),
+            self.expression.final_position(),
+        )
+    }
+
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![
+            ("variable", &self.variable),
+            ("expression", &self.expression),
+        ]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        vec![&mut self.variable, &mut self.expression]
     }
 
-    fn final_position(&self) -> usize {
-        self.expression.final_position()
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        let mut positions: Vec<&mut usize> = vec![&mut self.equals];
+        positions.extend(self.comments.positions_mut());
+        positions
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        vec![&self.variable, &self.expression]
+    fn kind(&self) -> NodeKind {
+        NodeKind::UsingAssignmentStatement
     }
 
     fn get_description(&self) -> String {
@@ -220,16 +329,27 @@ impl Node for UsingIfClauseStatement {
         Some(&self.comments)
     }
 
-    fn initial_position(&self) -> usize {
-        self.r#if.initial_position()
+    fn span(&self) -> Span {
+        Span::new(
+            self.r#if.initial_position(),
+            self.condition.final_position(),
+        )
+    }
+
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("if", &self.r#if), ("condition", &self.condition)]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        vec![&mut self.r#if, &mut self.condition]
     }
 
-    fn final_position(&self) -> usize {
-        self.condition.final_position()
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        self.comments.positions_mut().collect()
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        vec![&self.r#if, &self.condition]
+    fn kind(&self) -> NodeKind {
+        NodeKind::UsingIfClauseStatement
     }
 
     fn get_description(&self) -> String {
@@ -313,32 +433,33 @@ mod tests {
     use crate::tree::expression::operator::ComparisonOperationExpression;
     use crate::tree::expression::operator::FunctionOperationExpression;
     use crate::tree::identifier::Identifier;
+    use smallvec::smallvec;
 
     #[test]
     pub fn test_if_statement_display() {
         let r#if = IfStatement {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             r#if: Keyword::new(ByteString::from("if"), 0),
             conditions: CommaSeparated {
                 inner: vec![Expression::ComparisonOperation(
                     ComparisonOperationExpression::LessThan {
-                        comments: CommentGroup { comments: vec![] },
+                        comments: CommentGroup::default(),
                         left: Box::from(Expression::Variable(Variable {
                             position: 0,
                             name: ByteString::from("foo"),
                         })),
                         right: Box::from(Expression::Literal(Integer(LiteralInteger {
-                            comments: CommentGroup { comments: vec![] },
+                            comments: CommentGroup::default(),
                             position: 0,
                             value: ByteString::from("10"),
                         }))),
                         less_than: 0,
                     },
                 )],
-                commas: vec![],
+                commas: smallvec![],
             },
             block: BlockStatement {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 left_brace: 0,
                 statements: vec![],
                 right_brace: 0,
@@ -350,44 +471,44 @@ mod tests {
         assert_eq!(r#if.to_string(), "if $foo < 10 { /* ... */ }");
 
         let if_elseif = IfStatement {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             r#if: Keyword::new(ByteString::from("if"), 0),
             conditions: CommaSeparated {
                 inner: vec![Expression::ComparisonOperation(
                     ComparisonOperationExpression::LessThan {
-                        comments: CommentGroup { comments: vec![] },
+                        comments: CommentGroup::default(),
                         left: Box::from(Expression::Variable(Variable {
                             position: 0,
                             name: ByteString::from("foo"),
                         })),
                         right: Box::from(Expression::Literal(Integer(LiteralInteger {
-                            comments: CommentGroup { comments: vec![] },
+                            comments: CommentGroup::default(),
                             position: 0,
                             value: ByteString::from("10"),
                         }))),
                         less_than: 0,
                     },
                 )],
-                commas: vec![],
+                commas: smallvec![],
             },
             block: BlockStatement {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 left_brace: 0,
                 statements: vec![],
                 right_brace: 0,
             },
             elseifs: vec![IfElseIfStatement {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 elseif: Keyword::new(ByteString::from("elseif"), 0),
                 condition: Expression::ComparisonOperation(
                     ComparisonOperationExpression::LessThan {
-                        comments: CommentGroup { comments: vec![] },
+                        comments: CommentGroup::default(),
                         left: Box::from(Expression::Variable(Variable {
                             position: 0,
                             name: ByteString::from("foo"),
                         })),
                         right: Box::from(Expression::Literal(Integer(LiteralInteger {
-                            comments: CommentGroup { comments: vec![] },
+                            comments: CommentGroup::default(),
                             position: 0,
                             value: ByteString::from("10"),
                         }))),
@@ -395,7 +516,7 @@ mod tests {
                     },
                 ),
                 block: BlockStatement {
-                    comments: CommentGroup { comments: vec![] },
+                    comments: CommentGroup::default(),
                     left_brace: 0,
                     statements: vec![],
                     right_brace: 0,
@@ -410,69 +531,69 @@ mod tests {
         );
 
         let if_else_if = IfStatement {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             r#if: Keyword::new(ByteString::from("if"), 0),
             conditions: CommaSeparated {
                 inner: vec![Expression::ComparisonOperation(
                     ComparisonOperationExpression::LessThan {
-                        comments: CommentGroup { comments: vec![] },
+                        comments: CommentGroup::default(),
                         left: Box::from(Expression::Variable(Variable {
                             position: 0,
                             name: ByteString::from("foo"),
                         })),
                         right: Box::from(Expression::Literal(Integer(LiteralInteger {
-                            comments: CommentGroup { comments: vec![] },
+                            comments: CommentGroup::default(),
                             position: 0,
                             value: ByteString::from("10"),
                         }))),
                         less_than: 0,
                     },
                 )],
-                commas: vec![],
+                commas: smallvec![],
             },
             block: BlockStatement {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 left_brace: 0,
                 statements: vec![],
                 right_brace: 0,
             },
             elseifs: vec![],
             r#else: Some(IfElseStatement {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 r#else: Keyword::new(ByteString::from("else"), 0),
                 block: IfElseBlockStatement::If(Box::from(IfStatement {
-                    comments: CommentGroup { comments: vec![] },
+                    comments: CommentGroup::default(),
                     r#if: Keyword::new(ByteString::from("if"), 0),
                     conditions: CommaSeparated {
                         inner: vec![Expression::ComparisonOperation(
                             ComparisonOperationExpression::LessThan {
-                                comments: CommentGroup { comments: vec![] },
+                                comments: CommentGroup::default(),
                                 left: Box::from(Expression::Variable(Variable {
                                     position: 0,
                                     name: ByteString::from("foo"),
                                 })),
                                 right: Box::from(Expression::Literal(Integer(LiteralInteger {
-                                    comments: CommentGroup { comments: vec![] },
+                                    comments: CommentGroup::default(),
                                     position: 0,
                                     value: ByteString::from("10"),
                                 }))),
                                 less_than: 0,
                             },
                         )],
-                        commas: vec![],
+                        commas: smallvec![],
                     },
                     block: BlockStatement {
-                        comments: CommentGroup { comments: vec![] },
+                        comments: CommentGroup::default(),
                         left_brace: 0,
                         statements: vec![],
                         right_brace: 0,
                     },
                     elseifs: vec![],
                     r#else: Some(IfElseStatement {
-                        comments: CommentGroup { comments: vec![] },
+                        comments: CommentGroup::default(),
                         r#else: Keyword::new(ByteString::from("else"), 0),
                         block: IfElseBlockStatement::Block(BlockStatement {
-                            comments: CommentGroup { comments: vec![] },
+                            comments: CommentGroup::default(),
                             left_brace: 0,
                             statements: vec![],
                             right_brace: 0,
@@ -491,61 +612,63 @@ mod tests {
     #[test]
     pub fn test_using_statement_display() {
         let using = UsingStatement {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             r#using: Keyword::new(ByteString::from("using"), 0),
             assignments: CommaSeparated {
                 inner: vec![UsingAssignmentStatement {
-                    comments: CommentGroup { comments: vec![] },
+                    comments: CommentGroup::default(),
                     variable: Variable {
                         position: 0,
                         name: ByteString::from("foo"),
                     },
                     equals: 0,
-                    expression: Expression::FunctionOperation(FunctionOperationExpression::Call {
-                        comments: CommentGroup { comments: vec![] },
-                        function: Box::new(Expression::Identifier(Identifier {
-                            position: 0,
-                            value: ByteString::from("bar"),
-                        })),
-                        generics: None,
-                        arguments: ArgumentListExpression {
-                            comments: CommentGroup { comments: vec![] },
-                            left_parenthesis: 0,
-                            arguments: CommaSeparated {
-                                inner: vec![ArgumentExpression::Value {
-                                    comments: CommentGroup { comments: vec![] },
-                                    value: Expression::Literal(Integer(LiteralInteger {
-                                        comments: CommentGroup { comments: vec![] },
-                                        position: 0,
-                                        value: ByteString::from("1"),
-                                    })),
-                                }],
-                                commas: vec![],
+                    expression: Expression::FunctionOperation(Box::new(
+                        FunctionOperationExpression::Call {
+                            comments: CommentGroup::default(),
+                            function: Box::new(Expression::Identifier(Identifier {
+                                position: 0,
+                                value: ByteString::from("bar"),
+                            })),
+                            generics: None,
+                            arguments: ArgumentListExpression {
+                                comments: CommentGroup::default(),
+                                left_parenthesis: 0,
+                                arguments: CommaSeparated {
+                                    inner: vec![ArgumentExpression::Value {
+                                        comments: CommentGroup::default(),
+                                        value: Expression::Literal(Integer(LiteralInteger {
+                                            comments: CommentGroup::default(),
+                                            position: 0,
+                                            value: ByteString::from("1"),
+                                        })),
+                                    }],
+                                    commas: smallvec![],
+                                },
+                                right_parenthesis: 0,
                             },
-                            right_parenthesis: 0,
                         },
-                    }),
+                    )),
                 }],
-                commas: vec![],
+                commas: smallvec![],
             },
             block: BlockStatement {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 left_brace: 0,
                 statements: vec![],
                 right_brace: 0,
             },
             if_clause: Some(UsingIfClauseStatement {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 r#if: Keyword::new(ByteString::from("if"), 0),
                 condition: Expression::ComparisonOperation(
                     ComparisonOperationExpression::LessThan {
-                        comments: CommentGroup { comments: vec![] },
+                        comments: CommentGroup::default(),
                         left: Box::from(Expression::Variable(Variable {
                             position: 0,
                             name: ByteString::from("foo"),
                         })),
                         right: Box::from(Expression::Literal(Integer(LiteralInteger {
-                            comments: CommentGroup { comments: vec![] },
+                            comments: CommentGroup::default(),
                             position: 0,
                             value: ByteString::from("10"),
                         }))),
@@ -6,6 +6,8 @@ use serde::Serialize;
 
 use crate::tree::comment::CommentGroup;
 use crate::tree::expression::Expression;
+use crate::tree::kind::NodeKind;
+use crate::tree::span::Span;
 use crate::tree::Node;
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
@@ -21,16 +23,26 @@ impl Node for ExpressionStatement {
         Some(&self.comments)
     }
 
-    fn initial_position(&self) -> usize {
-        self.expression.initial_position()
+    fn span(&self) -> Span {
+        Span::new(self.expression.initial_position(), self.semicolon + 1)
     }
 
-    fn final_position(&self) -> usize {
-        self.semicolon + 1
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("expression", &self.expression)]
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        vec![&self.expression]
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        vec![&mut self.expression]
+    }
+
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        let mut positions: Vec<&mut usize> = vec![&mut self.semicolon];
+        positions.extend(self.comments.positions_mut());
+        positions
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::ExpressionStatement
     }
 
     fn get_description(&self) -> String {
@@ -7,9 +7,12 @@ use serde::Serialize;
 use crate::tree::comment::CommentGroup;
 use crate::tree::expression::literal::LiteralInteger;
 use crate::tree::expression::Expression;
+use crate::tree::kind::NodeKind;
+use crate::tree::span::Span;
 use crate::tree::statement::block::BlockStatement;
 use crate::tree::token::Keyword;
 use crate::tree::utils::CommaSeparated;
+use crate::tree::utils::ListView;
 use crate::tree::variable::Variable;
 use crate::tree::Node;
 
@@ -42,7 +45,7 @@ pub enum ForeachIteratorStatement {
     KeyAndValue {
         expression: Expression,
         r#as: Keyword,
-        key: Variable,
+        key: ForeachIteratorKeyStatement,
         double_arrow: usize,
         value: Variable,
     },
@@ -50,13 +53,26 @@ pub enum ForeachIteratorStatement {
         left_parenthesis: usize,
         expression: Expression,
         r#as: Keyword,
-        key: Variable,
+        key: ForeachIteratorKeyStatement,
         double_arrow: usize,
         value: Variable,
         right_parenthesis: usize,
     },
 }
 
+/// The key of a `foreach` iterator, either a variable, or a tuple pattern destructuring a tuple
+/// key, e.g. `($a, $b)` in `foreach $map as ($a, $b) => $value`.
+#[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
+#[serde(rename_all = "snake_case", tag = "type", content = "value")]
+pub enum ForeachIteratorKeyStatement {
+    Variable(Variable),
+    Tuple {
+        left_parenthesis: usize,
+        elements: CommaSeparated<ForeachIteratorKeyStatement>,
+        right_parenthesis: usize,
+    },
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub struct ForStatement {
@@ -135,7 +151,7 @@ impl ForeachIteratorStatement {
         }
     }
 
-    pub fn key(&self) -> Option<&Variable> {
+    pub fn key(&self) -> Option<&ForeachIteratorKeyStatement> {
         match &self {
             Self::Value { .. } => None,
             Self::ParenthesizedValue { .. } => None,
@@ -154,45 +170,158 @@ impl ForeachIteratorStatement {
     }
 }
 
+impl ForeachIteratorKeyStatement {
+    /// The variables of the key, in order, e.g. `$a`, and `$b`, for `($a, $b)`.
+    pub fn variables(&self) -> Vec<&Variable> {
+        match &self {
+            Self::Variable(variable) => vec![variable],
+            Self::Tuple { elements, .. } => elements
+                .inner
+                .iter()
+                .flat_map(|element| element.variables())
+                .collect(),
+        }
+    }
+}
+
 impl Node for ForeachStatement {
     fn comments(&self) -> Option<&CommentGroup> {
         Some(&self.comments)
     }
 
-    fn initial_position(&self) -> usize {
-        self.foreach.initial_position()
-    }
+    fn span(&self) -> Span {
+        let final_position = match &self.else_block {
+            Some(else_block) => else_block.final_position(),
+            None => self.block.final_position(),
+        };
 
-    fn final_position(&self) -> usize {
-        self.block.final_position()
+        Span::new(self.foreach.initial_position(), final_position)
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        let mut children: Vec<&dyn Node> = vec![&self.foreach, &self.iterator, &self.block];
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut children: Vec<(&'static str, &dyn Node)> = vec![
+            ("foreach", &self.foreach),
+            ("iterator", &self.iterator),
+            ("block", &self.block),
+        ];
 
         if let Some(r#else) = &self.r#else {
-            children.push(r#else);
+            children.push(("else", r#else));
         }
 
         if let Some(else_block) = &self.else_block {
+            children.push(("else_block", else_block));
+        }
+
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> =
+            vec![&mut self.foreach, &mut self.iterator, &mut self.block];
+
+        if let Some(r#else) = &mut self.r#else {
+            children.push(r#else);
+        }
+
+        if let Some(else_block) = &mut self.else_block {
             children.push(else_block);
         }
 
         children
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        self.comments.positions_mut().collect()
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::ForeachStatement
+    }
+
     fn get_description(&self) -> String {
         "foreach statement".to_string()
     }
 }
 
+impl Node for ForeachIteratorKeyStatement {
+    fn span(&self) -> Span {
+        let initial_position = match &self {
+            Self::Variable(variable) => variable.initial_position(),
+            Self::Tuple {
+                left_parenthesis, ..
+            } => *left_parenthesis,
+        };
+        let final_position = match &self {
+            Self::Variable(variable) => variable.final_position(),
+            Self::Tuple {
+                right_parenthesis, ..
+            } => right_parenthesis + 1,
+        };
+
+        Span::new(initial_position, final_position)
+    }
+
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        match &self {
+            Self::Variable(variable) => vec![("variable", variable)],
+            Self::Tuple { elements, .. } => elements
+                .inner
+                .iter()
+                .map(|element| ("elements", element as &dyn Node))
+                .collect(),
+        }
+    }
+
+    fn lists(&self) -> Vec<ListView<'_>> {
+        match &self {
+            Self::Tuple { elements, .. } => vec![elements.view()],
+            _ => vec![],
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
+            Self::Variable(variable) => vec![variable],
+            Self::Tuple { elements, .. } => elements
+                .inner
+                .iter_mut()
+                .map(|element| element as &mut dyn Node)
+                .collect(),
+        }
+    }
+
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        match self {
+            Self::Variable(_) => vec![],
+            Self::Tuple {
+                left_parenthesis,
+                elements,
+                right_parenthesis,
+            } => {
+                let mut positions: Vec<&mut usize> = vec![left_parenthesis, right_parenthesis];
+                positions.extend(elements.commas.iter_mut());
+                positions
+            }
+        }
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::ForeachIteratorKeyStatement
+    }
+
+    fn get_description(&self) -> String {
+        "foreach iterator key".to_string()
+    }
+}
+
 impl Node for ForeachIteratorStatement {
     fn comments(&self) -> Option<&CommentGroup> {
         None
     }
 
-    fn initial_position(&self) -> usize {
-        match &self {
+    fn span(&self) -> Span {
+        let initial_position = match &self {
             Self::Value { expression, .. } | Self::KeyAndValue { expression, .. } => {
                 expression.initial_position()
             }
@@ -202,11 +331,8 @@ impl Node for ForeachIteratorStatement {
             | Self::ParenthesizedKeyAndValue {
                 left_parenthesis, ..
             } => *left_parenthesis,
-        }
-    }
-
-    fn final_position(&self) -> usize {
-        match &self {
+        };
+        let final_position = match &self {
             Self::Value { value, .. } | Self::KeyAndValue { value, .. } => value.final_position(),
             Self::ParenthesizedValue {
                 right_parenthesis, ..
@@ -214,11 +340,53 @@ impl Node for ForeachIteratorStatement {
             | Self::ParenthesizedKeyAndValue {
                 right_parenthesis, ..
             } => right_parenthesis + 1,
-        }
+        };
+
+        Span::new(initial_position, final_position)
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
         match &self {
+            Self::Value {
+                expression,
+                r#as,
+                value,
+                ..
+            }
+            | Self::ParenthesizedValue {
+                expression,
+                r#as,
+                value,
+                ..
+            } => {
+                vec![("expression", expression), ("as", r#as), ("value", value)]
+            }
+            Self::KeyAndValue {
+                expression,
+                r#as,
+                key,
+                value,
+                ..
+            }
+            | Self::ParenthesizedKeyAndValue {
+                expression,
+                r#as,
+                key,
+                value,
+                ..
+            } => {
+                vec![
+                    ("expression", expression),
+                    ("as", r#as),
+                    ("key", key),
+                    ("value", value),
+                ]
+            }
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
             Self::Value {
                 expression,
                 r#as,
@@ -252,6 +420,28 @@ impl Node for ForeachIteratorStatement {
         }
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        match self {
+            Self::ParenthesizedValue {
+                left_parenthesis,
+                right_parenthesis,
+                ..
+            } => vec![left_parenthesis, right_parenthesis],
+            Self::KeyAndValue { double_arrow, .. } => vec![double_arrow],
+            Self::ParenthesizedKeyAndValue {
+                left_parenthesis,
+                double_arrow,
+                right_parenthesis,
+                ..
+            } => vec![left_parenthesis, double_arrow, right_parenthesis],
+            _ => vec![],
+        }
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::ForeachIteratorStatement
+    }
+
     fn get_description(&self) -> String {
         "foreach iterator".to_string()
     }
@@ -262,16 +452,28 @@ impl Node for ForStatement {
         Some(&self.comments)
     }
 
-    fn initial_position(&self) -> usize {
-        self.r#for.initial_position()
+    fn span(&self) -> Span {
+        Span::new(self.r#for.initial_position(), self.block.final_position())
+    }
+
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![
+            ("for", &self.r#for),
+            ("iterator", &self.iterator),
+            ("block", &self.block),
+        ]
     }
 
-    fn final_position(&self) -> usize {
-        self.block.final_position()
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        vec![&mut self.r#for, &mut self.iterator, &mut self.block]
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        vec![&self.r#for, &self.iterator, &self.block]
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        self.comments.positions_mut().collect()
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::ForStatement
     }
 
     fn get_description(&self) -> String {
@@ -284,8 +486,8 @@ impl Node for ForIteratorStatement {
         None
     }
 
-    fn initial_position(&self) -> usize {
-        match &self {
+    fn span(&self) -> Span {
+        let initial_position = match &self {
             Self::Standalone {
                 initializations,
                 initializations_semicolon,
@@ -300,11 +502,8 @@ impl Node for ForIteratorStatement {
             Self::Parenthesized {
                 left_parenthesis, ..
             } => *left_parenthesis,
-        }
-    }
-
-    fn final_position(&self) -> usize {
-        match &self {
+        };
+        let final_position = match &self {
             Self::Standalone {
                 conditions_semicolon,
                 r#loop,
@@ -319,10 +518,12 @@ impl Node for ForIteratorStatement {
             Self::Parenthesized {
                 right_parenthesis, ..
             } => right_parenthesis + 1,
-        }
+        };
+
+        Span::new(initial_position, final_position)
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
         match &self {
             Self::Standalone {
                 initializations,
@@ -336,17 +537,67 @@ impl Node for ForIteratorStatement {
                 r#loop,
                 ..
             } => {
-                let mut children: Vec<&dyn Node> = vec![];
+                let mut children: Vec<(&'static str, &dyn Node)> = vec![];
 
                 for expression in &initializations.inner {
-                    children.push(expression);
+                    children.push(("initializations", expression));
                 }
 
                 for expression in &conditions.inner {
-                    children.push(expression);
+                    children.push(("conditions", expression));
                 }
 
                 for expression in &r#loop.inner {
+                    children.push(("loop", expression));
+                }
+
+                children
+            }
+        }
+    }
+
+    fn lists(&self) -> Vec<ListView<'_>> {
+        match &self {
+            Self::Standalone {
+                initializations,
+                conditions,
+                r#loop,
+                ..
+            }
+            | Self::Parenthesized {
+                initializations,
+                conditions,
+                r#loop,
+                ..
+            } => vec![initializations.view(), conditions.view(), r#loop.view()],
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
+            Self::Standalone {
+                initializations,
+                conditions,
+                r#loop,
+                ..
+            }
+            | Self::Parenthesized {
+                initializations,
+                conditions,
+                r#loop,
+                ..
+            } => {
+                let mut children: Vec<&mut dyn Node> = vec![];
+
+                for expression in &mut initializations.inner {
+                    children.push(expression);
+                }
+
+                for expression in &mut conditions.inner {
+                    children.push(expression);
+                }
+
+                for expression in &mut r#loop.inner {
                     children.push(expression);
                 }
 
@@ -355,6 +606,51 @@ impl Node for ForIteratorStatement {
         }
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        match self {
+            Self::Standalone {
+                initializations,
+                initializations_semicolon,
+                conditions,
+                conditions_semicolon,
+                r#loop,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> =
+                    vec![initializations_semicolon, conditions_semicolon];
+                positions.extend(initializations.commas.iter_mut());
+                positions.extend(conditions.commas.iter_mut());
+                positions.extend(r#loop.commas.iter_mut());
+                positions
+            }
+            Self::Parenthesized {
+                left_parenthesis,
+                initializations,
+                initializations_semicolon,
+                conditions,
+                conditions_semicolon,
+                r#loop,
+                right_parenthesis,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![
+                    left_parenthesis,
+                    initializations_semicolon,
+                    conditions_semicolon,
+                    right_parenthesis,
+                ];
+                positions.extend(initializations.commas.iter_mut());
+                positions.extend(conditions.commas.iter_mut());
+                positions.extend(r#loop.commas.iter_mut());
+                positions
+            }
+        }
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::ForIteratorStatement
+    }
+
     fn get_description(&self) -> String {
         "for iterator statement".to_string()
     }
@@ -365,24 +661,50 @@ impl Node for DoWhileStatement {
         Some(&self.comments)
     }
 
-    fn initial_position(&self) -> usize {
-        self.r#do.initial_position()
+    fn span(&self) -> Span {
+        Span::new(self.r#do.initial_position(), self.semicolon + 1)
     }
 
-    fn final_position(&self) -> usize {
-        self.semicolon + 1
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut children: Vec<(&'static str, &dyn Node)> = vec![
+            ("do", &self.r#do),
+            ("block", &self.block),
+            ("while", &self.r#while),
+        ];
+
+        for condition in &self.conditions.inner {
+            children.push(("conditions", condition));
+        }
+
+        children
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        let mut children: Vec<&dyn Node> = vec![&self.r#do, &self.block, &self.r#while];
+    fn lists(&self) -> Vec<ListView<'_>> {
+        vec![self.conditions.view()]
+    }
 
-        for condition in &self.conditions.inner {
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> =
+            vec![&mut self.r#do, &mut self.block, &mut self.r#while];
+
+        for condition in &mut self.conditions.inner {
             children.push(condition);
         }
 
         children
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        let mut positions: Vec<&mut usize> = vec![&mut self.semicolon];
+        positions.extend(self.comments.positions_mut());
+        positions.extend(self.conditions.commas.iter_mut());
+        positions
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::DoWhileStatement
+    }
+
     fn get_description(&self) -> String {
         "do-while statement".to_string()
     }
@@ -393,24 +715,49 @@ impl Node for WhileStatement {
         Some(&self.comments)
     }
 
-    fn initial_position(&self) -> usize {
-        self.r#while.initial_position()
+    fn span(&self) -> Span {
+        Span::new(self.r#while.initial_position(), self.block.final_position())
     }
 
-    fn final_position(&self) -> usize {
-        self.block.final_position()
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut children: Vec<(&'static str, &dyn Node)> = vec![("while", &self.r#while)];
+
+        for condition in &self.conditions.inner {
+            children.push(("conditions", condition));
+        }
+
+        children.push(("block", &self.block));
+
+        children
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        let mut children: Vec<&dyn Node> = vec![&self.r#while, &self.block];
+    fn lists(&self) -> Vec<ListView<'_>> {
+        vec![self.conditions.view()]
+    }
 
-        for condition in &self.conditions.inner {
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = vec![&mut self.r#while];
+
+        for condition in &mut self.conditions.inner {
             children.push(condition);
         }
 
+        children.push(&mut self.block);
+
         children
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        let mut positions: Vec<&mut usize> = vec![];
+        positions.extend(self.comments.positions_mut());
+        positions.extend(self.conditions.commas.iter_mut());
+        positions
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::WhileStatement
+    }
+
     fn get_description(&self) -> String {
         "while statement".to_string()
     }
@@ -421,22 +768,36 @@ impl Node for BreakStatement {
         Some(&self.comments)
     }
 
-    fn initial_position(&self) -> usize {
-        self.r#break.initial_position()
+    fn span(&self) -> Span {
+        Span::new(self.r#break.initial_position(), self.semicolon + 1)
     }
 
-    fn final_position(&self) -> usize {
-        self.semicolon + 1
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        if let Some(level) = &self.level {
+            vec![("break", &self.r#break), ("level", level)]
+        } else {
+            vec![("break", &self.r#break)]
+        }
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        if let Some(level) = &self.level {
-            vec![&self.r#break, level]
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        if let Some(level) = &mut self.level {
+            vec![&mut self.r#break, level]
         } else {
-            vec![&self.r#break]
+            vec![&mut self.r#break]
         }
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        let mut positions: Vec<&mut usize> = vec![&mut self.semicolon];
+        positions.extend(self.comments.positions_mut());
+        positions
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::BreakStatement
+    }
+
     fn get_description(&self) -> String {
         "break statement".to_string()
     }
@@ -447,22 +808,36 @@ impl Node for ContinueStatement {
         Some(&self.comments)
     }
 
-    fn initial_position(&self) -> usize {
-        self.r#continue.initial_position()
+    fn span(&self) -> Span {
+        Span::new(self.r#continue.initial_position(), self.semicolon + 1)
     }
 
-    fn final_position(&self) -> usize {
-        self.semicolon + 1
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        if let Some(level) = &self.level {
+            vec![("continue", &self.r#continue), ("level", level)]
+        } else {
+            vec![("continue", &self.r#continue)]
+        }
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        if let Some(level) = &self.level {
-            vec![&self.r#continue, level]
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        if let Some(level) = &mut self.level {
+            vec![&mut self.r#continue, level]
         } else {
-            vec![&self.r#continue]
+            vec![&mut self.r#continue]
         }
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        let mut positions: Vec<&mut usize> = vec![&mut self.semicolon];
+        positions.extend(self.comments.positions_mut());
+        positions
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::ContinueStatement
+    }
+
     fn get_description(&self) -> String {
         "continue statement".to_string()
     }
@@ -480,6 +855,15 @@ impl std::fmt::Display for ForeachStatement {
     }
 }
 
+impl std::fmt::Display for ForeachIteratorKeyStatement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self {
+            Self::Variable(variable) => write!(f, "{}", variable),
+            Self::Tuple { elements, .. } => write!(f, "({})", elements),
+        }
+    }
+}
+
 impl std::fmt::Display for ForeachIteratorStatement {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match &self {
@@ -596,6 +980,7 @@ mod tests {
     use crate::tree::expression::literal::Literal::Integer;
     use crate::tree::expression::operator::ArithmeticOperationExpression;
     use crate::tree::expression::operator::ComparisonOperationExpression;
+    use smallvec::smallvec;
 
     #[test]
     pub fn test_foreach_statement_display() {
@@ -613,14 +998,14 @@ mod tests {
                 },
             },
             block: BlockStatement {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 left_brace: 0,
                 statements: vec![],
                 right_brace: 0,
             },
             r#else: None,
             else_block: None,
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
         };
 
         assert_eq!(foreach.to_string(), "foreach $foo as $bar { /* ... */ }");
@@ -639,19 +1024,19 @@ mod tests {
                 },
             },
             block: BlockStatement {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 left_brace: 0,
                 statements: vec![],
                 right_brace: 0,
             },
             r#else: Some(Keyword::new(ByteString::from("else"), 0)),
             else_block: Some(BlockStatement {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 left_brace: 0,
                 statements: vec![],
                 right_brace: 0,
             }),
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
         };
 
         assert_eq!(
@@ -675,14 +1060,14 @@ mod tests {
                 right_parenthesis: 0,
             },
             block: BlockStatement {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 left_brace: 0,
                 statements: vec![],
                 right_brace: 0,
             },
             r#else: None,
             else_block: None,
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
         };
 
         assert_eq!(
@@ -698,10 +1083,10 @@ mod tests {
                     name: ByteString::from("foo"),
                 }),
                 r#as: Keyword::new(ByteString::from("as"), 0),
-                key: Variable {
+                key: ForeachIteratorKeyStatement::Variable(Variable {
                     position: 0,
                     name: ByteString::from("bar"),
-                },
+                }),
                 double_arrow: 0,
                 value: Variable {
                     position: 0,
@@ -709,14 +1094,14 @@ mod tests {
                 },
             },
             block: BlockStatement {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 left_brace: 0,
                 statements: vec![],
                 right_brace: 0,
             },
             r#else: None,
             else_block: None,
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
         };
 
         assert_eq!(
@@ -732,10 +1117,10 @@ mod tests {
                     name: ByteString::from("foo"),
                 }),
                 r#as: Keyword::new(ByteString::from("as"), 0),
-                key: Variable {
+                key: ForeachIteratorKeyStatement::Variable(Variable {
                     position: 0,
                     name: ByteString::from("bar"),
-                },
+                }),
                 double_arrow: 0,
                 value: Variable {
                     position: 0,
@@ -745,14 +1130,14 @@ mod tests {
                 right_parenthesis: 0,
             },
             block: BlockStatement {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 left_brace: 0,
                 statements: vec![],
                 right_brace: 0,
             },
             r#else: None,
             else_block: None,
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
         };
 
         assert_eq!(
@@ -771,26 +1156,26 @@ mod tests {
                         position: 0,
                         name: ByteString::from("foo"),
                     })],
-                    commas: vec![],
+                    commas: smallvec![],
                 },
                 initializations_semicolon: 0,
                 conditions: CommaSeparated {
                     inner: vec![Expression::ComparisonOperation(
                         ComparisonOperationExpression::LessThan {
-                            comments: CommentGroup { comments: vec![] },
+                            comments: CommentGroup::default(),
                             left: Box::from(Expression::Variable(Variable {
                                 position: 0,
                                 name: ByteString::from("foo"),
                             })),
                             right: Box::from(Expression::Literal(Integer(LiteralInteger {
-                                comments: CommentGroup { comments: vec![] },
+                                comments: CommentGroup::default(),
                                 position: 0,
                                 value: ByteString::from("10"),
                             }))),
                             less_than: 0,
                         },
                     )],
-                    commas: vec![],
+                    commas: smallvec![],
                 },
                 conditions_semicolon: 0,
                 r#loop: CommaSeparated {
@@ -803,16 +1188,16 @@ mod tests {
                             increment: 0,
                         },
                     )],
-                    commas: vec![],
+                    commas: smallvec![],
                 },
             },
             block: BlockStatement {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 left_brace: 0,
                 statements: vec![],
                 right_brace: 0,
             },
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
         };
 
         assert_eq!(
@@ -829,26 +1214,26 @@ mod tests {
                         position: 0,
                         name: ByteString::from("foo"),
                     })],
-                    commas: vec![],
+                    commas: smallvec![],
                 },
                 initializations_semicolon: 0,
                 conditions: CommaSeparated {
                     inner: vec![Expression::ComparisonOperation(
                         ComparisonOperationExpression::LessThan {
-                            comments: CommentGroup { comments: vec![] },
+                            comments: CommentGroup::default(),
                             left: Box::from(Expression::Variable(Variable {
                                 position: 0,
                                 name: ByteString::from("foo"),
                             })),
                             right: Box::from(Expression::Literal(Integer(LiteralInteger {
-                                comments: CommentGroup { comments: vec![] },
+                                comments: CommentGroup::default(),
                                 position: 0,
                                 value: ByteString::from("10"),
                             }))),
                             less_than: 0,
                         },
                     )],
-                    commas: vec![],
+                    commas: smallvec![],
                 },
                 conditions_semicolon: 0,
                 r#loop: CommaSeparated {
@@ -861,17 +1246,17 @@ mod tests {
                             increment: 0,
                         },
                     )],
-                    commas: vec![],
+                    commas: smallvec![],
                 },
                 right_parenthesis: 0,
             },
             block: BlockStatement {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 left_brace: 0,
                 statements: vec![],
                 right_brace: 0,
             },
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
         };
 
         assert_eq!(
@@ -885,29 +1270,29 @@ mod tests {
         let while_statement = WhileStatement {
             r#while: Keyword::new(ByteString::from("while"), 0),
             block: BlockStatement {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 left_brace: 0,
                 statements: vec![],
                 right_brace: 0,
             },
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             conditions: CommaSeparated {
                 inner: vec![Expression::ComparisonOperation(
                     ComparisonOperationExpression::LessThan {
-                        comments: CommentGroup { comments: vec![] },
+                        comments: CommentGroup::default(),
                         left: Box::from(Expression::Variable(Variable {
                             position: 0,
                             name: ByteString::from("foo"),
                         })),
                         right: Box::from(Expression::Literal(Integer(LiteralInteger {
-                            comments: CommentGroup { comments: vec![] },
+                            comments: CommentGroup::default(),
                             position: 0,
                             value: ByteString::from("10"),
                         }))),
                         less_than: 0,
                     },
                 )],
-                commas: vec![],
+                commas: smallvec![],
             },
         };
 
@@ -919,30 +1304,30 @@ mod tests {
         let do_while_statement = DoWhileStatement {
             r#do: Keyword::new(ByteString::from("do"), 0),
             block: BlockStatement {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 left_brace: 0,
                 statements: vec![],
                 right_brace: 0,
             },
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             r#while: Keyword::new(ByteString::from("while"), 0),
             conditions: CommaSeparated {
                 inner: vec![Expression::ComparisonOperation(
                     ComparisonOperationExpression::LessThan {
-                        comments: CommentGroup { comments: vec![] },
+                        comments: CommentGroup::default(),
                         left: Box::from(Expression::Variable(Variable {
                             position: 0,
                             name: ByteString::from("foo"),
                         })),
                         right: Box::from(Expression::Literal(Integer(LiteralInteger {
-                            comments: CommentGroup { comments: vec![] },
+                            comments: CommentGroup::default(),
                             position: 0,
                             value: ByteString::from("10"),
                         }))),
                         less_than: 0,
                     },
                 )],
-                commas: vec![],
+                commas: smallvec![],
             },
             semicolon: 0,
         };
@@ -959,7 +1344,7 @@ mod tests {
             r#continue: Keyword::new(ByteString::from("continue"), 0),
             level: None,
             semicolon: 0,
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
         };
 
         assert_eq!(continue_statement.to_string(), "continue;");
@@ -967,12 +1352,12 @@ mod tests {
         let continue_statement_with_level = ContinueStatement {
             r#continue: Keyword::new(ByteString::from("continue"), 0),
             level: Some(LiteralInteger {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 position: 0,
                 value: ByteString::from("2"),
             }),
             semicolon: 0,
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
         };
 
         assert_eq!(continue_statement_with_level.to_string(), "continue 2;");
@@ -984,7 +1369,7 @@ mod tests {
             r#break: Keyword::new(ByteString::from("break"), 0),
             level: None,
             semicolon: 0,
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
         };
 
         assert_eq!(break_statement.to_string(), "break;");
@@ -992,12 +1377,12 @@ mod tests {
         let break_statement_with_level = BreakStatement {
             r#break: Keyword::new(ByteString::from("break"), 0),
             level: Some(LiteralInteger {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 position: 0,
                 value: ByteString::from("2"),
             }),
             semicolon: 0,
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
         };
 
         assert_eq!(break_statement_with_level.to_string(), "break 2;");
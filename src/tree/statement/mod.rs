@@ -5,6 +5,8 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use crate::tree::comment::CommentGroup;
+use crate::tree::kind::NodeKind;
+use crate::tree::span::Span;
 use crate::tree::statement::block::BlockStatement;
 use crate::tree::statement::control_flow::IfStatement;
 use crate::tree::statement::control_flow::UsingStatement;
@@ -49,60 +51,71 @@ impl Node for Statement {
         None
     }
 
-    fn initial_position(&self) -> usize {
+    fn span(&self) -> Span {
         match &self {
-            Self::DoWhile(statement) => statement.initial_position(),
-            Self::While(statement) => statement.initial_position(),
-            Self::For(statement) => statement.initial_position(),
-            Self::Foreach(statement) => statement.initial_position(),
-            Self::Break(statement) => statement.initial_position(),
-            Self::Continue(statement) => statement.initial_position(),
-            Self::If(statement) => statement.initial_position(),
-            Self::Using(statement) => statement.initial_position(),
-            Self::Try(statement) => statement.initial_position(),
-            Self::Expression(statement) => statement.initial_position(),
-            Self::Return(statement) => statement.initial_position(),
-            Self::Block(statement) => statement.initial_position(),
-            Self::Empty(position) => *position,
+            Self::DoWhile(statement) => statement.span(),
+            Self::While(statement) => statement.span(),
+            Self::For(statement) => statement.span(),
+            Self::Foreach(statement) => statement.span(),
+            Self::Break(statement) => statement.span(),
+            Self::Continue(statement) => statement.span(),
+            Self::If(statement) => statement.span(),
+            Self::Using(statement) => statement.span(),
+            Self::Try(statement) => statement.span(),
+            Self::Expression(statement) => statement.span(),
+            Self::Return(statement) => statement.span(),
+            Self::Block(statement) => statement.span(),
+            Self::Empty(position) => Span::new(*position, *position + 1),
         }
     }
 
-    fn final_position(&self) -> usize {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
         match &self {
-            Self::DoWhile(statement) => statement.final_position(),
-            Self::While(statement) => statement.final_position(),
-            Self::For(statement) => statement.final_position(),
-            Self::Foreach(statement) => statement.final_position(),
-            Self::Break(statement) => statement.final_position(),
-            Self::Continue(statement) => statement.final_position(),
-            Self::If(statement) => statement.final_position(),
-            Self::Using(statement) => statement.final_position(),
-            Self::Try(statement) => statement.final_position(),
-            Self::Expression(statement) => statement.final_position(),
-            Self::Return(statement) => statement.final_position(),
-            Self::Block(statement) => statement.final_position(),
-            Self::Empty(position) => *position + 1,
+            Self::DoWhile(statement) => vec![("statement", statement.as_ref())],
+            Self::While(statement) => vec![("statement", statement.as_ref())],
+            Self::For(statement) => vec![("statement", statement.as_ref())],
+            Self::Foreach(statement) => vec![("statement", statement.as_ref())],
+            Self::Break(statement) => vec![("statement", statement.as_ref())],
+            Self::Continue(statement) => vec![("statement", statement.as_ref())],
+            Self::If(statement) => vec![("statement", statement.as_ref())],
+            Self::Using(statement) => vec![("statement", statement.as_ref())],
+            Self::Try(statement) => vec![("statement", statement.as_ref())],
+            Self::Expression(statement) => vec![("statement", statement.as_ref())],
+            Self::Return(statement) => vec![("statement", statement.as_ref())],
+            Self::Block(statement) => vec![("statement", statement.as_ref())],
+            Self::Empty(_) => vec![],
         }
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        match &self {
-            Self::DoWhile(statement) => vec![statement.as_ref()],
-            Self::While(statement) => vec![statement.as_ref()],
-            Self::For(statement) => vec![statement.as_ref()],
-            Self::Foreach(statement) => vec![statement.as_ref()],
-            Self::Break(statement) => vec![statement.as_ref()],
-            Self::Continue(statement) => vec![statement.as_ref()],
-            Self::If(statement) => vec![statement.as_ref()],
-            Self::Using(statement) => vec![statement.as_ref()],
-            Self::Try(statement) => vec![statement.as_ref()],
-            Self::Expression(statement) => vec![statement.as_ref()],
-            Self::Return(statement) => vec![statement.as_ref()],
-            Self::Block(statement) => vec![statement.as_ref()],
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
+            Self::DoWhile(statement) => vec![statement.as_mut()],
+            Self::While(statement) => vec![statement.as_mut()],
+            Self::For(statement) => vec![statement.as_mut()],
+            Self::Foreach(statement) => vec![statement.as_mut()],
+            Self::Break(statement) => vec![statement.as_mut()],
+            Self::Continue(statement) => vec![statement.as_mut()],
+            Self::If(statement) => vec![statement.as_mut()],
+            Self::Using(statement) => vec![statement.as_mut()],
+            Self::Try(statement) => vec![statement.as_mut()],
+            Self::Expression(statement) => vec![statement.as_mut()],
+            Self::Return(statement) => vec![statement.as_mut()],
+            Self::Block(statement) => vec![statement.as_mut()],
             Self::Empty(_) => vec![],
         }
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        match self {
+            Self::Empty(position) => vec![position],
+            _ => vec![],
+        }
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::Statement
+    }
+
     fn get_description(&self) -> String {
         match &self {
             Self::DoWhile(statement) => statement.get_description(),
@@ -6,6 +6,8 @@ use serde::Serialize;
 
 use crate::tree::comment::CommentGroup;
 use crate::tree::expression::Expression;
+use crate::tree::kind::NodeKind;
+use crate::tree::span::Span;
 use crate::tree::token::Keyword;
 use crate::tree::Node;
 
@@ -32,22 +34,38 @@ impl Node for ReturnStatement {
         }
     }
 
-    fn initial_position(&self) -> usize {
-        match &self {
+    fn span(&self) -> Span {
+        let initial_position = match &self {
             Self::Explicit { r#return, .. } => r#return.initial_position(),
             Self::Implicit { expression, .. } => expression.initial_position(),
-        }
+        };
+        let final_position = match &self {
+            Self::Explicit { semicolon, .. } => semicolon + 1,
+            Self::Implicit { expression, .. } => expression.final_position(),
+        };
+
+        Span::new(initial_position, final_position)
     }
 
-    fn final_position(&self) -> usize {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
         match &self {
-            Self::Explicit { semicolon, .. } => semicolon + 1,
-            Self::Implicit { expression, .. } => expression.final_position(),
+            Self::Explicit {
+                r#return,
+                expression,
+                ..
+            } => {
+                if let Some(expression) = expression {
+                    vec![("return", r#return), ("expression", expression)]
+                } else {
+                    vec![("return", r#return)]
+                }
+            }
+            Self::Implicit { expression, .. } => vec![("expression", expression)],
         }
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        match &self {
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
             Self::Explicit {
                 r#return,
                 expression,
@@ -63,6 +81,25 @@ impl Node for ReturnStatement {
         }
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        match self {
+            Self::Explicit {
+                comments,
+                semicolon,
+                ..
+            } => {
+                let mut positions: Vec<&mut usize> = vec![semicolon];
+                positions.extend(comments.positions_mut());
+                positions
+            }
+            Self::Implicit { comments, .. } => comments.positions_mut().collect(),
+        }
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::ReturnStatement
+    }
+
     fn get_description(&self) -> String {
         match &self {
             Self::Explicit { .. } => "explicit return statement".to_string(),
@@ -100,10 +137,10 @@ mod tests {
     #[test]
     pub fn test_return_statement_display() {
         let explicit_return_statement = ReturnStatement::Explicit {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             r#return: Keyword::new(ByteString::from("return"), 0),
             expression: Some(Expression::Literal(Integer(LiteralInteger {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 position: 0,
                 value: ByteString::from("10"),
             }))),
@@ -113,9 +150,9 @@ mod tests {
         assert_eq!(explicit_return_statement.to_string(), "return 10;");
 
         let implicit_return_statement = ReturnStatement::Implicit {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             expression: Expression::Literal(Integer(LiteralInteger {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 position: 0,
                 value: ByteString::from("10"),
             })),
@@ -6,6 +6,8 @@ use serde::Serialize;
 
 use crate::tree::comment::CommentGroup;
 use crate::tree::identifier::Identifier;
+use crate::tree::kind::NodeKind;
+use crate::tree::span::Span;
 use crate::tree::statement::block::BlockStatement;
 use crate::tree::token::Keyword;
 use crate::tree::variable::Variable;
@@ -53,28 +55,53 @@ impl Node for TryStatement {
         Some(&self.comments)
     }
 
-    fn initial_position(&self) -> usize {
-        self.r#try.initial_position()
-    }
+    fn span(&self) -> Span {
+        let final_position = match (&self.finally, self.catches.last()) {
+            (Some(finally), _) => finally.final_position(),
+            (None, Some(catch)) => catch.final_position(),
+            (None, None) => self.block.final_position(),
+        };
 
-    fn final_position(&self) -> usize {
-        self.block.final_position()
+        Span::new(self.r#try.initial_position(), final_position)
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        let mut children: Vec<&dyn Node> = vec![&self.r#try, &self.block];
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut children: Vec<(&'static str, &dyn Node)> =
+            vec![("try", &self.r#try), ("block", &self.block)];
 
         for catch in &self.catches {
-            children.push(catch);
+            children.push(("catches", catch));
         }
 
         if let Some(finally) = &self.finally {
+            children.push(("finally", finally));
+        }
+
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = vec![&mut self.r#try, &mut self.block];
+
+        for catch in &mut self.catches {
+            children.push(catch);
+        }
+
+        if let Some(finally) = &mut self.finally {
             children.push(finally);
         }
 
         children
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        self.comments.positions_mut().collect()
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::TryStatement
+    }
+
     fn get_description(&self) -> String {
         "try statement".to_string()
     }
@@ -85,26 +112,46 @@ impl Node for TryCatchBlockStatement {
         Some(&self.comments)
     }
 
-    fn initial_position(&self) -> usize {
-        self.catch.initial_position()
+    fn span(&self) -> Span {
+        Span::new(self.catch.initial_position(), self.block.final_position())
     }
 
-    fn final_position(&self) -> usize {
-        self.block.final_position()
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        let mut children: Vec<(&'static str, &dyn Node)> =
+            vec![("catch", &self.catch), ("types", &self.types)];
+
+        if let Some(variable) = &self.variable {
+            children.push(("variable", variable));
+        }
+
+        children.push(("block", &self.block));
+
+        children
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        let mut children: Vec<&dyn Node> = vec![&self.catch, &self.types];
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        let mut children: Vec<&mut dyn Node> = vec![&mut self.catch, &mut self.types];
 
-        if let Some(variable) = &self.variable {
+        if let Some(variable) = &mut self.variable {
             children.push(variable);
         }
 
-        children.push(&self.block);
+        children.push(&mut self.block);
 
         children
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        let mut positions: Vec<&mut usize> =
+            vec![&mut self.left_parenthesis, &mut self.right_parenthesis];
+        positions.extend(self.comments.positions_mut());
+        positions
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::TryCatchBlockStatement
+    }
+
     fn get_description(&self) -> String {
         "try catch block statement".to_string()
     }
@@ -115,16 +162,24 @@ impl Node for TryFinallyBlockStatement {
         Some(&self.comments)
     }
 
-    fn initial_position(&self) -> usize {
-        self.finally.initial_position()
+    fn span(&self) -> Span {
+        Span::new(self.finally.initial_position(), self.block.final_position())
+    }
+
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![("finally", &self.finally), ("block", &self.block)]
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        vec![&mut self.finally, &mut self.block]
     }
 
-    fn final_position(&self) -> usize {
-        self.block.final_position()
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        self.comments.positions_mut().collect()
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        vec![&self.finally, &self.block]
+    fn kind(&self) -> NodeKind {
+        NodeKind::TryFinallyBlockStatement
     }
 
     fn get_description(&self) -> String {
@@ -137,30 +192,44 @@ impl Node for TryCatchTypeStatement {
         None
     }
 
-    fn initial_position(&self) -> usize {
+    fn span(&self) -> Span {
         match &self {
-            Self::Identifier(identifier) => identifier.initial_position(),
-            Self::Union(identifiers) => identifiers[0].initial_position(),
+            Self::Identifier(identifier) => identifier.span(),
+            Self::Union(identifiers) => Span::new(
+                identifiers[0].initial_position(),
+                identifiers[identifiers.len() - 1].final_position(),
+            ),
         }
     }
 
-    fn final_position(&self) -> usize {
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
         match &self {
-            Self::Identifier(identifier) => identifier.final_position(),
-            Self::Union(identifiers) => identifiers[identifiers.len() - 1].final_position(),
+            Self::Identifier(identifier) => vec![("identifier", identifier)],
+            Self::Union(identifiers) => identifiers
+                .iter()
+                .map(|identifier| ("identifiers", identifier as &dyn Node))
+                .collect(),
         }
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
-        match &self {
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
+        match self {
             Self::Identifier(identifier) => vec![identifier],
             Self::Union(identifiers) => identifiers
-                .iter()
-                .map(|identifier| identifier as &dyn Node)
+                .iter_mut()
+                .map(|identifier| identifier as &mut dyn Node)
                 .collect(),
         }
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        vec![]
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::TryCatchTypeStatement
+    }
+
     fn get_description(&self) -> String {
         "try catch type statement".to_string()
     }
@@ -225,17 +294,17 @@ mod tests {
     #[test]
     pub fn test_try_statement_display() {
         let try_statement = TryStatement {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             r#try: Keyword::new(ByteString::from("try"), 0),
             block: BlockStatement {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 left_brace: 0,
                 statements: vec![],
                 right_brace: 0,
             },
             catches: vec![
                 TryCatchBlockStatement {
-                    comments: CommentGroup { comments: vec![] },
+                    comments: CommentGroup::default(),
                     catch: Keyword::new(ByteString::from("catch"), 0),
                     left_parenthesis: 0,
                     types: TryCatchTypeStatement::Identifier(Identifier {
@@ -248,14 +317,14 @@ mod tests {
                     }),
                     right_parenthesis: 0,
                     block: BlockStatement {
-                        comments: CommentGroup { comments: vec![] },
+                        comments: CommentGroup::default(),
                         left_brace: 0,
                         statements: vec![],
                         right_brace: 0,
                     },
                 },
                 TryCatchBlockStatement {
-                    comments: CommentGroup { comments: vec![] },
+                    comments: CommentGroup::default(),
                     catch: Keyword::new(ByteString::from("catch"), 0),
                     left_parenthesis: 0,
                     types: TryCatchTypeStatement::Identifier(Identifier {
@@ -268,7 +337,7 @@ mod tests {
                     }),
                     right_parenthesis: 0,
                     block: BlockStatement {
-                        comments: CommentGroup { comments: vec![] },
+                        comments: CommentGroup::default(),
                         left_brace: 0,
                         statements: vec![],
                         right_brace: 0,
@@ -276,10 +345,10 @@ mod tests {
                 },
             ],
             finally: Some(TryFinallyBlockStatement {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 finally: Keyword::new(ByteString::from("finally"), 0),
                 block: BlockStatement {
-                    comments: CommentGroup { comments: vec![] },
+                    comments: CommentGroup::default(),
                     left_brace: 0,
                     statements: vec![],
                     right_brace: 0,
@@ -290,16 +359,16 @@ mod tests {
         assert_eq!(try_statement.to_string(), "try { /* ... */ } catch (Exception $e) { /* ... */ } catch (CustomException $e) { /* ... */ } finally { /* ... */ }");
 
         let try_statement_with_union_catch = TryStatement {
-            comments: CommentGroup { comments: vec![] },
+            comments: CommentGroup::default(),
             r#try: Keyword::new(ByteString::from("try"), 0),
             block: BlockStatement {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 left_brace: 0,
                 statements: vec![],
                 right_brace: 0,
             },
             catches: vec![TryCatchBlockStatement {
-                comments: CommentGroup { comments: vec![] },
+                comments: CommentGroup::default(),
                 catch: Keyword::new(ByteString::from("catch"), 0),
                 left_parenthesis: 0,
                 types: TryCatchTypeStatement::Union(vec![
@@ -318,7 +387,7 @@ mod tests {
                 }),
                 right_parenthesis: 0,
                 block: BlockStatement {
-                    comments: CommentGroup { comments: vec![] },
+                    comments: CommentGroup::default(),
                     left_brace: 0,
                     statements: vec![],
                     right_brace: 0,
@@ -8,6 +8,8 @@ use std::fmt::Display;
 
 use crate::lexer::byte_string::ByteString;
 use crate::tree::comment::CommentGroup;
+use crate::tree::kind::NodeKind;
+use crate::tree::span::Span;
 use crate::tree::Node;
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
@@ -28,18 +30,30 @@ impl Node for Keyword {
         None
     }
 
-    fn initial_position(&self) -> usize {
-        self.position
+    fn span(&self) -> Span {
+        Span::new(self.position, self.position + self.value.len())
     }
 
-    fn final_position(&self) -> usize {
-        self.position + self.value.len()
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![]
+    }
+
+    fn tokens(&self) -> Vec<&[u8]> {
+        vec![self.value.as_bytes()]
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![]
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        vec![&mut self.position]
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::Keyword
+    }
+
     fn get_description(&self) -> String {
         "keyword".to_string()
     }
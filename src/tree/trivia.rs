@@ -0,0 +1,140 @@
+use bincode::Decode;
+use bincode::Encode;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::lexer::byte_string::ByteString;
+use crate::tree::span::Span;
+use crate::tree::Node;
+
+#[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub enum TriviaKind {
+    Whitespace,
+    SingleLineComment,
+    MultiLineComment,
+    HashMarkComment,
+    DocumentComment,
+}
+
+/// A piece of source code that is not part of the tree, such as whitespace or a comment.
+#[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct Trivia {
+    pub kind: TriviaKind,
+    pub position: usize,
+    pub value: ByteString,
+}
+
+/// A token, with its original text and the trivia preceding it.
+#[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct TriviaToken {
+    pub position: usize,
+    pub value: ByteString,
+    pub leading: Vec<Trivia>,
+}
+
+/// The tokens and trivia of a source, as captured by a lossless parse.
+///
+/// Writing out every token along with its leading trivia, followed by the trailing trivia,
+/// reproduces the parsed source byte-for-byte.
+#[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct TriviaTable {
+    pub tokens: Vec<TriviaToken>,
+    pub trailing: Vec<Trivia>,
+}
+
+impl TriviaTable {
+    /// The token starting at the given position, if any.
+    pub fn token(&self, position: usize) -> Option<&TriviaToken> {
+        self.tokens
+            .binary_search_by_key(&position, |token| token.position)
+            .ok()
+            .map(|index| &self.tokens[index])
+    }
+
+    /// The trivia preceding the first token of the given node.
+    pub fn leading(&self, node: &dyn Node) -> &[Trivia] {
+        self.token(node.initial_position())
+            .map(|token| token.leading.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// The trivia lying between the given positions, in order, e.g. the whitespace, and
+    /// comments, between the end of a token and the start of the next one.
+    pub fn between(&self, from: usize, to: usize) -> Vec<&Trivia> {
+        let start = self.tokens.partition_point(|token| token.position <= from);
+        let end = self.tokens.partition_point(|token| token.position < to);
+
+        // the trivia preceding the first token at, or after, `to` may lie before `to`.
+        let tokens = &self.tokens[start..(end + 1).min(self.tokens.len())];
+
+        tokens
+            .iter()
+            .flat_map(|token| &token.leading)
+            .chain(if end == self.tokens.len() {
+                self.trailing.as_slice()
+            } else {
+                &[]
+            })
+            .filter(|trivia| trivia.position >= from && trivia.position < to)
+            .collect()
+    }
+
+    /// The original source code between the given adjacent nodes, made only of trivia, such as
+    /// the whitespace, and comments, a formatter has to preserve between them.
+    pub fn gap(&self, left: &dyn Node, right: &dyn Node) -> ByteString {
+        let mut bytes = vec![];
+        for trivia in self.between(left.final_position(), right.initial_position()) {
+            bytes.extend_from_slice(&trivia.value);
+        }
+
+        ByteString::new(bytes)
+    }
+
+    /// The original source code of the tokens within the given span, including the trivia
+    /// between them, but not the trivia preceding the first one.
+    pub fn text(&self, span: Span) -> ByteString {
+        let start = self
+            .tokens
+            .partition_point(|token| token.position < span.start);
+
+        let mut bytes = vec![];
+        for (index, token) in self.tokens[start..]
+            .iter()
+            .take_while(|token| token.position < span.end)
+            .enumerate()
+        {
+            if index > 0 {
+                for trivia in &token.leading {
+                    bytes.extend_from_slice(&trivia.value);
+                }
+            }
+
+            bytes.extend_from_slice(&token.value);
+        }
+
+        ByteString::new(bytes)
+    }
+
+    /// The original source code.
+    pub fn source(&self) -> ByteString {
+        let mut bytes = vec![];
+        for token in &self.tokens {
+            for trivia in &token.leading {
+                bytes.extend_from_slice(&trivia.value);
+            }
+
+            bytes.extend_from_slice(&token.value);
+        }
+
+        for trivia in &self.trailing {
+            bytes.extend_from_slice(&trivia.value);
+        }
+
+        ByteString::new(bytes)
+    }
+}
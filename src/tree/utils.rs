@@ -1,16 +1,73 @@
+use bincode::de::BorrowDecoder;
+use bincode::de::Decoder;
+use bincode::enc::Encoder;
+use bincode::error::DecodeError;
+use bincode::error::EncodeError;
+use bincode::BorrowDecode;
 use bincode::Decode;
 use bincode::Encode;
 use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
+use smallvec::SmallVec;
 
 use crate::tree::Node;
 
-#[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
+/// A comma separated list of nodes.
+///
+/// Most lists hold no more than a couple of nodes, the first two commas are stored inline, without
+/// allocating, the nodes themselves can not be, as nodes may hold lists of nodes of the same kind.
+#[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub struct CommaSeparated<T: Node> {
     pub inner: Vec<T>,
-    pub commas: Vec<usize>, // `,`
+    pub commas: SmallVec<[usize; 2]>, // `,`
+}
+
+/// A comma separated list held by a node, regardless of the kind of its items, see `Node::lists`.
+#[derive(Clone)]
+pub struct ListView<'a> {
+    pub items: Vec<&'a dyn Node>,
+    pub commas: &'a [usize],
+}
+
+impl<T: Node> CommaSeparated<T> {
+    pub fn view(&self) -> ListView<'_> {
+        ListView {
+            items: self.inner.iter().map(|item| item as &dyn Node).collect(),
+            commas: &self.commas,
+        }
+    }
+}
+
+// commas are encoded as vectors are, small vectors are not supported by bincode.
+impl<T: Node + Encode> Encode for CommaSeparated<T> {
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        self.inner.encode(encoder)?;
+        self.commas.as_slice().encode(encoder)
+    }
+}
+
+impl<Context, T: Node + Decode<Context>> Decode<Context> for CommaSeparated<T> {
+    fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        Ok(Self {
+            inner: Vec::decode(decoder)?,
+            commas: SmallVec::from_vec(Vec::decode(decoder)?),
+        })
+    }
+}
+
+impl<'de, Context, T: Node + BorrowDecode<'de, Context>> BorrowDecode<'de, Context>
+    for CommaSeparated<T>
+{
+    fn borrow_decode<D: BorrowDecoder<'de, Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, DecodeError> {
+        Ok(Self {
+            inner: Vec::borrow_decode(decoder)?,
+            commas: SmallVec::from_vec(Vec::borrow_decode(decoder)?),
+        })
+    }
 }
 
 impl<T: Node + std::fmt::Display> std::fmt::Display for CommaSeparated<T> {
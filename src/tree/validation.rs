@@ -0,0 +1,125 @@
+use std::fmt::Display;
+
+use crate::tree::downcast;
+use crate::tree::identifier::Identifier;
+use crate::tree::kind::NodeKind;
+use crate::tree::utils::ListView;
+use crate::tree::variable::Variable;
+use crate::tree::Node;
+
+/// A structural invariant of the tree that does not hold.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ValidationError {
+    /// The position in the source at which the invariant does not hold.
+    pub position: usize,
+    pub message: String,
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} at position {}", self.message, self.position)
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// Check the invariants of the given node, and of its relation to its children.
+pub(crate) fn node(node: &dyn Node, errors: &mut Vec<ValidationError>) {
+    let span = node.span();
+    let mut error = |position: usize, message: String| {
+        errors.push(ValidationError { position, message });
+    };
+
+    if span.end < span.start {
+        error(
+            span.start,
+            format!(
+                "{} ends at position {}, before it starts",
+                node.get_description(),
+                span.end
+            ),
+        );
+    }
+
+    let children = node.children();
+    for child in &children {
+        let child_span = child.span();
+        if child_span.start < span.start || span.end < child_span.end {
+            error(
+                child_span.start,
+                format!(
+                    "{} lies outside of its parent {}",
+                    child.get_description(),
+                    node.get_description()
+                ),
+            );
+        }
+    }
+
+    for pair in children.windows(2) {
+        if pair[1].initial_position() < pair[0].final_position() {
+            error(
+                pair[1].initial_position(),
+                format!(
+                    "{} does not follow the preceding {}",
+                    pair[1].get_description(),
+                    pair[0].get_description()
+                ),
+            );
+        }
+    }
+
+    let empty = match node.kind() {
+        NodeKind::Identifier => downcast::<Identifier>(node).is_some_and(|n| n.value.is_empty()),
+        NodeKind::Variable => downcast::<Variable>(node).is_some_and(|n| n.name.len() < 2),
+        _ => false,
+    };
+
+    if empty {
+        error(span.start, format!("{} is empty", node.get_description()));
+    }
+
+    for list in node.lists() {
+        self::list(&list, span.start, errors);
+    }
+}
+
+/// Check that a comma separated list has a comma between each pair of its items, and at most
+/// one trailing comma.
+fn list(list: &ListView, position: usize, errors: &mut Vec<ValidationError>) {
+    let position = list
+        .commas
+        .first()
+        .copied()
+        .or_else(|| list.items.first().map(|item| item.initial_position()))
+        .unwrap_or(position);
+
+    let expected = list.items.len().saturating_sub(1)..=list.items.len();
+    if !expected.contains(&list.commas.len()) {
+        errors.push(ValidationError {
+            position,
+            message: format!(
+                "comma separated list of {} items has {} commas",
+                list.items.len(),
+                list.commas.len()
+            ),
+        });
+
+        return;
+    }
+
+    let separated = list.commas.iter().enumerate().all(|(index, comma)| {
+        list.items[index].final_position() <= *comma
+            && list
+                .items
+                .get(index + 1)
+                .is_none_or(|item| *comma < item.initial_position())
+    });
+
+    if !separated {
+        errors.push(ValidationError {
+            position,
+            message: "commas of comma separated list are out of order".to_string(),
+        });
+    }
+}
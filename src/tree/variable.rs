@@ -7,6 +7,8 @@ use serde::Serialize;
 use std::fmt::Display;
 
 use crate::lexer::byte_string::ByteString;
+use crate::tree::kind::NodeKind;
+use crate::tree::span::Span;
 use crate::tree::Node;
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash, Deserialize, Serialize, Encode, Decode, JsonSchema)]
@@ -17,18 +19,30 @@ pub struct Variable {
 }
 
 impl Node for Variable {
-    fn initial_position(&self) -> usize {
-        self.position
+    fn span(&self) -> Span {
+        Span::new(self.position, self.position + self.name.len())
     }
 
-    fn final_position(&self) -> usize {
-        self.position + self.name.len()
+    fn named_children(&self) -> Vec<(&'static str, &dyn Node)> {
+        vec![]
+    }
+
+    fn tokens(&self) -> Vec<&[u8]> {
+        vec![self.name.as_bytes()]
     }
 
-    fn children(&self) -> Vec<&dyn Node> {
+    fn children_mut(&mut self) -> Vec<&mut dyn Node> {
         vec![]
     }
 
+    fn positions_mut(&mut self) -> Vec<&mut usize> {
+        vec![&mut self.position]
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::Variable
+    }
+
     fn get_description(&self) -> String {
         "variable".to_string()
     }
@@ -0,0 +1,33 @@
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::bindings;
+use crate::parser;
+
+/// Parse the given code, as a source with the given name, returning the tree and the issues
+/// reported while parsing it as a JSON object of the form `{"tree": ..., "issues": [...]}`.
+///
+/// Parsing recovers from as many issues as possible, see `parser::parse_lenient`, so that the
+/// tree of a snippet that is still being written can be shown along with its issues.
+#[wasm_bindgen]
+pub fn parse(name: &str, code: &str) -> String {
+    bindings::parse(&parser::source(name, code))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    #[test]
+    fn test_parse() {
+        let output: Value =
+            serde_json::from_str(&parse("foo.ara", "function foo(): void {}")).unwrap();
+        assert_eq!(output["tree"]["source"], "foo.ara");
+        assert_eq!(output["issues"], Value::Array(vec![]));
+
+        let output: Value =
+            serde_json::from_str(&parse("foo.ara", "function foo(): void { $a = ; }")).unwrap();
+        assert!(!output["tree"].is_null());
+        assert_eq!(output["issues"][0]["severity"]["type"], "error");
+    }
+}
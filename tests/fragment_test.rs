@@ -0,0 +1,141 @@
+use ara_parser::parser;
+use ara_parser::parser::options::ParserOptions;
+use ara_parser::printer;
+use ara_parser::printer::PrinterOptions;
+use ara_parser::tree::definition::r#type::TypeDefinition;
+use ara_parser::tree::definition::Definition;
+use ara_parser::tree::expression::Expression;
+use ara_parser::tree::statement::Statement;
+use ara_reporting::issue::IssueSeverity;
+use ara_source::source::Source;
+use ara_source::source::SourceKind;
+
+#[test]
+fn test_parse_fragments() {
+    assert!(matches!(
+        parser::parse_definition("function foo(): void {}")
+            .unwrap()
+            .node,
+        Definition::Function(_)
+    ));
+
+    assert!(matches!(
+        parser::parse_statement("return $a;").unwrap().node,
+        Statement::Return(_)
+    ));
+
+    assert!(matches!(
+        parser::parse_expression("$a + 1").unwrap().node,
+        Expression::ArithmeticOperation(_)
+    ));
+
+    assert!(matches!(
+        parser::parse_type("int|string").unwrap().node,
+        TypeDefinition::Union(_)
+    ));
+
+    let statement = parser::parse_statement("if ($a) {} elseif ($b) {}").unwrap();
+    assert!(matches!(statement.node, Statement::If(_)));
+    assert_eq!(statement.warnings.len(), 1);
+    assert_eq!(statement.warnings[0].severity, IssueSeverity::Warning);
+
+    let report = parser::parse_expression("$a +").unwrap_err();
+    assert_eq!(report.issues.len(), 1);
+
+    let report = parser::parse_type("int string").unwrap_err();
+    assert_eq!(report.issues.len(), 1);
+}
+
+#[test]
+fn test_print_fragment_comments() {
+    let fragment = parser::parse_definition(
+        "// the answer.\nfunction answer(): int {\n    // always.\n    return 42;\n}",
+    )
+    .unwrap();
+
+    assert_eq!(fragment.comments.comments.len(), 2);
+
+    let code = printer::print_node(
+        &fragment.node,
+        &fragment.comments,
+        PrinterOptions::default(),
+    );
+    assert!(code.contains("// the answer."), "{code}");
+    assert!(code.contains("// always."), "{code}");
+}
+
+#[test]
+fn test_parse_str() {
+    let tree = parser::parse_str("foo.ara", "function foo(): void {}").unwrap();
+    assert_eq!(tree.source, "foo.ara");
+    assert_eq!(tree.definitions.definitions.len(), 1);
+
+    let code = "function foo<...T>(): void {}";
+    assert!(parser::parse_str("foo.ara", code).is_err());
+    assert!(parser::parse_str("foo.d.ara", code).is_ok());
+}
+
+#[test]
+fn test_parse_deeply_nested_fragments() {
+    let nested = |depth: usize| format!("{}1{}", "(".repeat(depth), ")".repeat(depth));
+
+    assert!(parser::parse_expression(&nested(1000)).is_ok());
+
+    let report = parser::parse_expression(&nested(2000)).unwrap_err();
+    assert_eq!(report.issues.len(), 1);
+    assert_eq!(report.issues[0].code.as_deref(), Some("P0017"));
+
+    // the stack grows as needed, even when parsing on a thread with a small stack.
+    let code = nested(1000);
+    std::thread::Builder::new()
+        .stack_size(256 * 1024)
+        .spawn(move || assert!(parser::parse_expression(&code).is_ok()))
+        .unwrap()
+        .join()
+        .unwrap();
+}
+
+#[test]
+fn test_parse_lenient() {
+    let source = |code: &str| Source::inline(SourceKind::Script, code);
+
+    let (tree, issues) = parser::parse_lenient(&source("function foo(): void {}"));
+    assert_eq!(tree.definitions.definitions.len(), 1);
+    assert!(issues.is_empty());
+
+    let (tree, issues) = parser::parse_lenient(&source(
+        "function foo(): void {}\n\nfunction bar(: void {}\n\nfunction baz(): void {}\n",
+    ));
+    assert_eq!(tree.definitions.definitions.len(), 2);
+    assert_eq!(issues.len(), 1);
+
+    let (tree, issues) = parser::parse_lenient(&source("function foo(): string { return 'foo; }"));
+    assert!(tree.definitions.definitions.is_empty());
+    assert_eq!(issues.len(), 1);
+}
+
+#[test]
+fn test_parse_lenient_with_options() {
+    let code = "function foo(: void {}\n".repeat(5) + "function bar(): void {}\n";
+    let source = Source::inline(SourceKind::Script, code);
+
+    // the tree is recovered past the maximum number of issues, which are no longer reported.
+    let options = ParserOptions {
+        max_issues: Some(2),
+        ..ParserOptions::default()
+    };
+    let (tree, issues) = parser::parse_lenient_with_options(&source, options);
+    assert_eq!(tree.definitions.definitions.len(), 1);
+    assert_eq!(issues.len(), 3);
+    assert_eq!(issues[2].code.as_deref(), Some("P0020"));
+
+    let options = ParserOptions {
+        recovery: false,
+        ..ParserOptions::default()
+    };
+    let (tree, issues) = parser::parse_lenient_with_options(&source, options);
+    assert_eq!(tree.definitions.definitions.len(), 1);
+    assert_eq!(issues.len(), 5);
+
+    assert!(parser::parse_with_options(&source, ParserOptions::default()).is_err());
+}
@@ -0,0 +1,113 @@
+use std::env;
+use std::fs::read_dir;
+use std::io;
+use std::path::PathBuf;
+
+use pretty_assertions::assert_str_eq;
+
+use ara_parser::parser;
+use ara_parser::printer;
+use ara_parser::tree::Node;
+use ara_source::loader::FileSourceLoader;
+use ara_source::loader::SourceLoader;
+use ara_source::source::Source;
+
+#[test]
+fn test_printer_round_trip() -> io::Result<()> {
+    let manifest = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let root = format!("{manifest}/tests/samples/");
+
+    let mut entries = read_dir(&root)?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|entry| entry.is_dir())
+        .collect::<Vec<PathBuf>>();
+
+    entries.sort();
+
+    let loader = FileSourceLoader::new(&root);
+    for entry in entries {
+        let code_filename = if entry.join("code.d.ara").exists() {
+            entry.join("code.d.ara")
+        } else {
+            entry.join("code.ara")
+        };
+
+        if !code_filename.exists() || !entry.join("tree.txt").exists() {
+            continue;
+        }
+
+        let source_map = loader.load(&code_filename).unwrap();
+        let source = &source_map.sources[0];
+        let tree = parser::parse(source).unwrap();
+        let printed = printer::print(&tree);
+
+        let reparsed = parser::parse(&Source::inline(source.kind, &printed)).unwrap_or_else(|_| {
+            panic!(
+                "printed code for sample `{}` failed to parse:\n{}",
+                source.name(),
+                printed
+            )
+        });
+
+        assert_str_eq!(
+            printed,
+            printer::print(&reparsed),
+            "printed code for sample `{}` is not stable",
+            source.name()
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_printer_lossless() -> io::Result<()> {
+    let manifest = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let root = format!("{manifest}/tests/samples/");
+
+    let mut entries = read_dir(&root)?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|entry| entry.is_dir())
+        .collect::<Vec<PathBuf>>();
+
+    entries.sort();
+
+    let loader = FileSourceLoader::new(&root);
+    for entry in entries {
+        let code_filename = if entry.join("code.d.ara").exists() {
+            entry.join("code.d.ara")
+        } else {
+            entry.join("code.ara")
+        };
+
+        if !code_filename.exists() || !entry.join("tree.txt").exists() {
+            continue;
+        }
+
+        let source_map = loader.load(&code_filename).unwrap();
+        let source = &source_map.sources[0];
+        let tree = parser::parse_lossless(source).unwrap();
+
+        assert_str_eq!(
+            source.content,
+            printer::print_lossless(&tree).unwrap(),
+            "lossless printed code for sample `{}` does not match the source",
+            source.name()
+        );
+
+        for definition in &tree.definitions.definitions {
+            let span = definition.span();
+
+            assert_str_eq!(
+                source.content[span.start..span.end],
+                printer::print_lossless_node(&tree, definition).unwrap(),
+                "lossless printed definition for sample `{}` does not match the source",
+                source.name()
+            );
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,98 @@
+use std::env;
+use std::fs::read_dir;
+use std::path::PathBuf;
+
+use ara_parser::parser;
+use ara_parser::parser::edit::Edit;
+use ara_parser::parser::options::ParserOptions;
+use ara_source::source::Source;
+use ara_source::source::SourceKind;
+
+#[test]
+fn test_reparse_invalid_edit() {
+    let source = Source::new(SourceKind::Script, "code.ara", "function é(): void {}");
+    let tree = parser::parse(&source).unwrap();
+
+    for edit in [Edit::new(0, 100, ""), Edit::new(10, 11, "")] {
+        let report = parser::reparse(&tree, &[], &source, &edit).unwrap_err();
+        assert_eq!(report.issues.len(), 1);
+    }
+}
+
+#[test]
+fn test_reparse_fixtures() {
+    let manifest = env::var("CARGO_MANIFEST_DIR").unwrap();
+
+    let mut entries = read_dir(format!("{manifest}/tests/samples/"))
+        .unwrap()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|entry| entry.is_dir())
+        .collect::<Vec<PathBuf>>();
+
+    entries.sort();
+
+    for entry in entries {
+        let (kind, filename) = if entry.join("code.d.ara").exists() {
+            (SourceKind::Definition, entry.join("code.d.ara"))
+        } else {
+            (SourceKind::Script, entry.join("code.ara"))
+        };
+
+        let Ok(code) = std::fs::read_to_string(&filename) else {
+            continue;
+        };
+
+        let source = Source::new(kind, filename.to_string_lossy(), code.as_str());
+        let Ok((tree, warnings)) = parser::parse_with_warnings(&source, ParserOptions::default())
+        else {
+            continue;
+        };
+
+        for position in (0..=code.len()).step_by(17) {
+            if !code.is_char_boundary(position) || !code.is_char_boundary(position + 1) {
+                continue;
+            }
+
+            for edit in [
+                Edit::new(position, position, " "),
+                Edit::new(position, position, "\n// foo\n"),
+                Edit::new(position, position, "x"),
+                Edit::new(position, (position + 1).min(code.len()), ""),
+            ] {
+                let edited = Source::new(kind, source.name(), edit.apply(&code).unwrap());
+
+                match (
+                    parser::reparse(&tree, &warnings, &source, &edit),
+                    parser::parse_with_warnings(&edited, ParserOptions::default()),
+                ) {
+                    (Ok((result, result_warnings)), Ok((expected, expected_warnings))) => {
+                        assert_eq!(
+                            result.definitions, expected.definitions,
+                            "{edit:?} of {filename:?}"
+                        );
+                        assert_eq!(
+                            result.comments, expected.comments,
+                            "{edit:?} of {filename:?}"
+                        );
+
+                        assert_eq!(
+                            result_warnings, expected_warnings,
+                            "{edit:?} of {filename:?}"
+                        );
+
+                        if position % 5 == 0 {
+                            for (id, node) in result.nodes() {
+                                assert_eq!(result.id(node), Some(id), "{edit:?} of {filename:?}");
+                            }
+                        }
+                    }
+                    (Err(result), Err(expected)) => {
+                        assert_eq!(result, expected, "{edit:?} of {filename:?}");
+                    }
+                    (result, _) => panic!("{edit:?} of {filename:?}: {}", result.is_ok()),
+                }
+            }
+        }
+    }
+}
@@ -6,6 +6,7 @@ use std::path::PathBuf;
 use pretty_assertions::assert_str_eq;
 
 use ara_parser::parser;
+use ara_parser::tree;
 use ara_reporting::builder::CharSet;
 use ara_reporting::builder::ColorChoice;
 use ara_reporting::builder::ReportBuilder;
@@ -27,7 +28,11 @@ fn test_fixtures() -> io::Result<()> {
 
     let loader = FileSourceLoader::new(&root);
     for entry in entries {
-        let code_filename = entry.join("code.ara");
+        let code_filename = if entry.join("code.d.ara").exists() {
+            entry.join("code.d.ara")
+        } else {
+            entry.join("code.ara")
+        };
         let tree_filename = entry.join("tree.txt");
         let error_filename = entry.join("error.txt");
 
@@ -52,6 +57,13 @@ fn test_fixtures() -> io::Result<()> {
                     "found `error.txt` for `{}` but was expected.",
                     source_map.sources[0].name()
                 );
+
+                assert_eq!(
+                    tree::validate(&tree),
+                    Ok(()),
+                    "invalid tree for sample `{}`",
+                    source_map.sources[0].name()
+                );
             }
             Err(report) => {
                 let builder = ReportBuilder::new(&source_map)